@@ -0,0 +1,154 @@
+//! ERP account list parsing and import.
+
+use std::path::Path;
+
+use anyhow::Context;
+
+use crate::csv_columns::{column, optional_field, required_column};
+
+const ACCOUNT_NUMBER_HEADERS: &[&str] = &[
+    "account_number",
+    "account_no",
+    "account_id",
+    "account",
+    "acct_no",
+    "customer_number",
+    "customer_id",
+];
+const NAME_HEADERS: &[&str] = &["name", "account_name", "customer_name", "dba"];
+const ADDRESS_HEADERS: &[&str] = &[
+    "address_line1",
+    "address1",
+    "address",
+    "street",
+    "street_address",
+];
+const CITY_HEADERS: &[&str] = &["city"];
+const STATE_HEADERS: &[&str] = &["state", "st"];
+const ZIP_HEADERS: &[&str] = &["zip", "zip_code", "zipcode", "postal_code"];
+
+/// Parse an ERP account export.
+///
+/// Account number and name columns are required; address columns are read
+/// when present. Blank rows are skipped.
+///
+/// # Errors
+///
+/// Returns an error if a required column is missing or a row has no account
+/// number or name.
+pub(crate) fn parse_accounts_csv<R: std::io::Read>(
+    reader: R,
+) -> anyhow::Result<Vec<scbdb_db::NewAccount>> {
+    let mut rdr = csv::Reader::from_reader(reader);
+    let headers = rdr.headers()?.clone();
+    let number_idx = required_column(&headers, ACCOUNT_NUMBER_HEADERS)?;
+    let name_idx = required_column(&headers, NAME_HEADERS)?;
+    let address_idx = column(&headers, ADDRESS_HEADERS);
+    let city_idx = column(&headers, CITY_HEADERS);
+    let state_idx = column(&headers, STATE_HEADERS);
+    let zip_idx = column(&headers, ZIP_HEADERS);
+
+    let mut accounts = Vec::new();
+    for (line, record) in rdr.records().enumerate() {
+        let record = record?;
+        let row = line + 2; // 1-based, after the header
+        if record.iter().all(|field| field.trim().is_empty()) {
+            continue;
+        }
+        let account_number = optional_field(&record, Some(number_idx))
+            .ok_or_else(|| anyhow::anyhow!("row {row}: missing account number"))?;
+        let name = optional_field(&record, Some(name_idx))
+            .ok_or_else(|| anyhow::anyhow!("row {row}: missing account name"))?;
+        accounts.push(scbdb_db::NewAccount {
+            account_number,
+            name,
+            address_line1: optional_field(&record, address_idx),
+            city: optional_field(&record, city_idx),
+            state: optional_field(&record, state_idx).map(|s| s.to_uppercase()),
+            zip: optional_field(&record, zip_idx),
+        });
+    }
+    Ok(accounts)
+}
+
+/// Import accounts from `path` and report how many match stored locations.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or parsed, or the import fails.
+pub(crate) async fn run_accounts_import(
+    pool: &sqlx::PgPool,
+    path: &Path,
+    deactivate_missing: bool,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let file =
+        std::fs::File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    let accounts =
+        parse_accounts_csv(file).with_context(|| format!("failed to parse {}", path.display()))?;
+
+    if dry_run {
+        let unmatchable = accounts
+            .iter()
+            .filter(|a| {
+                scbdb_core::address::address_match_key(a.address_line1.as_deref(), a.zip.as_deref())
+                    .is_none()
+            })
+            .count();
+        println!(
+            "[dry-run] parsed {} account(s); {unmatchable} lack a street address and ZIP to match on",
+            accounts.len()
+        );
+        return Ok(());
+    }
+
+    let summary = scbdb_db::import_accounts(pool, &accounts, deactivate_missing).await?;
+    println!(
+        "imported {} account(s): {} new, {} updated, {} deactivated",
+        accounts.len(),
+        summary.inserted,
+        summary.updated,
+        summary.deactivated
+    );
+    if summary.unmatchable > 0 {
+        println!(
+            "{} account(s) lack a street address and ZIP and cannot be matched",
+            summary.unmatchable
+        );
+    }
+    println!(
+        "{} active account(s) match a store locator listing",
+        summary.matched
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_erp_export_with_aliased_headers() {
+        let csv = "Acct_No,Account_Name,Address1,City,State,Zip\n\
+                   A-100,Green's Beverage,\"500 Harbison Blvd, Ste 4\",Columbia,sc,29212\n\
+                   ,,,,,\n\
+                   A-101,Bottle Shop,,Charleston,SC,\n";
+        let accounts = parse_accounts_csv(csv.as_bytes()).expect("parse");
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].account_number, "A-100");
+        assert_eq!(
+            accounts[0].address_line1.as_deref(),
+            Some("500 Harbison Blvd, Ste 4")
+        );
+        assert_eq!(accounts[0].state.as_deref(), Some("SC"));
+        assert_eq!(accounts[1].address_line1, None);
+        assert_eq!(accounts[1].zip, None);
+    }
+
+    #[test]
+    fn rejects_rows_without_account_number() {
+        let csv = "account_number,name\n,Bottle Shop\n";
+        let err = parse_accounts_csv(csv.as_bytes()).expect_err("should fail");
+        assert!(err.to_string().contains("row 2"));
+    }
+}
@@ -0,0 +1,40 @@
+//! Retailer account command handlers for the CLI.
+//!
+//! Accounts come from the distributor ERP export and are matched against
+//! stored locator data by normalized street address + ZIP.
+
+mod import;
+mod report;
+
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+pub(crate) use import::run_accounts_import;
+pub(crate) use report::run_accounts_report;
+
+/// Sub-commands available under `accounts`.
+#[derive(Debug, Subcommand)]
+pub enum AccountsCommands {
+    /// Import our retailer accounts from an ERP CSV export
+    Import {
+        /// CSV with account number, name, street address, city, state and ZIP columns
+        #[arg(long)]
+        file: PathBuf,
+        /// Mark accounts not present in the file as inactive (full exports only)
+        #[arg(long)]
+        deactivate_missing: bool,
+        /// Parse and validate the file without writing to the database
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Report competitor presence at our accounts and competitor stores we do not sell to
+    Report {
+        /// Restrict the report to a two-letter state code
+        #[arg(long)]
+        state: Option<String>,
+        /// Maximum rows per report section
+        #[arg(long, default_value_t = 25)]
+        limit: u32,
+    },
+}
@@ -0,0 +1,96 @@
+//! Markdown report of competitor presence at our accounts and competitor
+//! storefronts that are not accounts yet.
+
+use chrono::Utc;
+
+fn cell(value: Option<&str>) -> String {
+    value.unwrap_or("\u{2014}").replace('|', "\\|")
+}
+
+fn brand_list(brands: &[String]) -> String {
+    if brands.is_empty() {
+        "\u{2014}".to_string()
+    } else {
+        brands.join(", ")
+    }
+}
+
+/// Print the account coverage report for `state_filter` (or all states).
+///
+/// # Errors
+///
+/// Returns an error if either report query fails.
+pub(crate) async fn run_accounts_report(
+    pool: &sqlx::PgPool,
+    state_filter: Option<&str>,
+    limit: i64,
+) -> anyhow::Result<()> {
+    let state = state_filter.map(str::to_uppercase);
+    let presence = scbdb_db::list_account_competitor_presence(
+        pool,
+        &scbdb_db::AccountPresenceFilters {
+            state: state.as_deref(),
+            with_competitors_only: true,
+            ..scbdb_db::AccountPresenceFilters::default()
+        },
+        limit,
+    )
+    .await?;
+    let prospects = scbdb_db::list_competitor_stores_without_account(
+        pool,
+        &scbdb_db::ProspectFilters {
+            state: state.as_deref(),
+            ..scbdb_db::ProspectFilters::default()
+        },
+        limit,
+    )
+    .await?;
+
+    let now = Utc::now().format("%Y-%m-%d %H:%M UTC");
+    println!("# Account Coverage Report");
+    println!();
+    println!("**Generated**: {now}");
+    println!("**Jurisdiction**: {}", state.as_deref().unwrap_or("All"));
+    println!();
+    println!("## Competitors on shelf at our accounts");
+    println!();
+    if presence.is_empty() {
+        println!("No competitor listings at imported accounts.");
+    } else {
+        println!("| Account | Name | City | State | Competitors | Our brands listed |");
+        println!("|---------|------|------|-------|-------------|-------------------|");
+        for row in &presence {
+            println!(
+                "| {} | {} | {} | {} | {} | {} |",
+                cell(Some(&row.account_number)),
+                cell(Some(&row.name)),
+                cell(row.city.as_deref()),
+                cell(row.state.as_deref()),
+                brand_list(&row.competitor_brands),
+                brand_list(&row.portfolio_brands),
+            );
+        }
+    }
+
+    println!();
+    println!("## Competitor stores that are not our accounts");
+    println!();
+    if prospects.is_empty() {
+        println!("Every competitor storefront with an address is already an account.");
+    } else {
+        println!("| Store | Address | City | State | Channel | Competitors |");
+        println!("|-------|---------|------|-------|---------|-------------|");
+        for row in &prospects {
+            println!(
+                "| {} | {} | {} | {} | {} | {} |",
+                cell(Some(&row.name)),
+                cell(row.address_line1.as_deref()),
+                cell(row.city.as_deref()),
+                cell(row.state.as_deref()),
+                cell(row.retail_channel.as_deref()),
+                brand_list(&row.competitor_brands),
+            );
+        }
+    }
+    Ok(())
+}
@@ -72,17 +72,30 @@ async fn run_collect_products_dry_run_writes_zero_db_rows(pool: sqlx::PgPool) {
         bind_addr: "0.0.0.0:3000".parse().unwrap(),
         log_level: "info".to_string(),
         brands_path: std::path::PathBuf::from("config/brands.yaml"),
+        bill_topics_path: std::path::PathBuf::from("config/bill_topics.yaml"),
         legiscan_api_key: None,
         db_max_connections: 10,
         db_min_connections: 1,
         db_acquire_timeout_secs: 10,
         scraper_request_timeout_secs: 30,
         legiscan_request_timeout_secs: 30,
+        legiscan_monthly_budget: 30_000,
         scraper_user_agent: "scbdb/0.1 (test)".to_string(),
         scraper_max_concurrent_brands: 1,
         scraper_inter_request_delay_ms: 0,
         scraper_max_retries: 3,
         scraper_retry_backoff_base_secs: 5,
+        location_max_drop_pct: 50,
+        location_drop_min_baseline: 20,
+        location_miss_threshold: 2,
+        location_miss_thresholds: std::collections::BTreeMap::new(),
+        location_miss_max_days: 14,
+        notify_webhook_url: None,
+        notify_slack_webhook_url: None,
+        notify_smtp_url: None,
+        notify_email_from: None,
+        notify_email_to: Vec::new(),
+        notify_min_relevance: scbdb_core::DEFAULT_NOTIFY_MIN_RELEVANCE,
     };
 
     let result = run_collect_products(&pool, &config, None, true).await;
@@ -0,0 +1,149 @@
+//! Brand auto-discovery: harvest unknown brand names into the review queue.
+//!
+//! Scans recent brand signals and sentiment evidence for names mentioned next
+//! to a category term, the brand lists of stores scraped from multi-brand
+//! locators, and any retailer pages given on the command line. Every sighting
+//! is recorded as evidence against a `brand_candidates` row.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, Utc};
+
+/// One harvested name, owned until it is handed to the database.
+struct Sighting {
+    name: String,
+    source: &'static str,
+    source_ref: String,
+    context: Option<String>,
+    observed_at: DateTime<Utc>,
+}
+
+/// Harvest candidate brand names and record them for review.
+///
+/// Text sources are limited to the last `since_days` days; locator payloads
+/// cover every active multi-brand store. With `dry_run` the most frequently
+/// seen names are printed and nothing is written.
+///
+/// # Errors
+///
+/// Returns an error if a source query or the final write fails. Retailer
+/// pages that cannot be fetched are logged and skipped.
+pub(crate) async fn run_collect_brand_candidates(
+    pool: &sqlx::PgPool,
+    config: &scbdb_core::AppConfig,
+    retailer_urls: &[String],
+    since_days: u32,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let since = Utc::now() - Duration::days(i64::from(since_days));
+    let mut sightings = Vec::new();
+
+    let texts = scbdb_db::list_discovery_texts(pool, since).await?;
+    for row in &texts {
+        let source = if row.source == "sentiment" {
+            "sentiment"
+        } else {
+            "brand_signal"
+        };
+        for mention in scbdb_scraper::discovery::brand_mentions_in_text(&row.text) {
+            sightings.push(Sighting {
+                name: mention.name,
+                source,
+                source_ref: row.source_ref.clone(),
+                context: Some(mention.context),
+                observed_at: row.observed_at,
+            });
+        }
+    }
+
+    let payloads = scbdb_db::list_multi_brand_locator_payloads(pool).await?;
+    for row in &payloads {
+        for name in scbdb_scraper::discovery::brand_names_in_locator_payload(&row.raw_data) {
+            sightings.push(Sighting {
+                name,
+                source: "locator",
+                source_ref: row.location_key.clone(),
+                context: Some(format!("carried at {}", row.store_name)),
+                observed_at: row.last_seen_at,
+            });
+        }
+    }
+
+    if !retailer_urls.is_empty() {
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(
+                config.scraper_request_timeout_secs,
+            ))
+            .user_agent(&config.scraper_user_agent)
+            .build()?;
+        for url in retailer_urls {
+            match fetch_page(&client, url).await {
+                Ok(html) => {
+                    let observed_at = Utc::now();
+                    for name in scbdb_scraper::discovery::brand_names_in_retailer_page(&html) {
+                        sightings.push(Sighting {
+                            name,
+                            source: "retailer_page",
+                            source_ref: url.clone(),
+                            context: None,
+                            observed_at,
+                        });
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(url = %url, error = %e, "retailer page fetch failed");
+                    println!("  \u{2717} {url}: {e:#}");
+                }
+            }
+        }
+    }
+
+    println!(
+        "scanned {} texts, {} locator stores, {} retailer pages: {} sightings",
+        texts.len(),
+        payloads.len(),
+        retailer_urls.len(),
+        sightings.len()
+    );
+
+    if dry_run {
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for s in &sightings {
+            *counts.entry(s.name.clone()).or_default() += 1;
+        }
+        let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        for (name, count) in counts.iter().take(25) {
+            println!("  {name:<30} {count:>5}");
+        }
+        println!("dry-run: no changes written (known brands are filtered on write)");
+        return Ok(());
+    }
+
+    let evidence: Vec<scbdb_db::NewBrandCandidateEvidence<'_>> = sightings
+        .iter()
+        .map(|s| scbdb_db::NewBrandCandidateEvidence {
+            name: &s.name,
+            source: s.source,
+            source_ref: &s.source_ref,
+            context: s.context.as_deref(),
+            observed_at: s.observed_at,
+        })
+        .collect();
+    let summary = scbdb_db::record_brand_candidate_evidence(pool, &evidence).await?;
+    println!(
+        "{} new candidates, {} new evidence rows, {} sightings of known brands skipped",
+        summary.candidates_created, summary.evidence_added, summary.known_brands_skipped
+    );
+    Ok(())
+}
+
+async fn fetch_page(client: &reqwest::Client, url: &str) -> anyhow::Result<String> {
+    Ok(client
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?)
+}
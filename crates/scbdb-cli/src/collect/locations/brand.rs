@@ -1,6 +1,8 @@
 //! Per-brand location collection logic.
 
-use helpers::{log_location_changeset, raw_to_new_location, record_brand_failure};
+use helpers::{
+    log_location_changeset, raw_to_new_location, record_brand_failure, record_brand_skipped,
+};
 use url::resolve_locator_url;
 
 use super::helpers;
@@ -12,7 +14,7 @@ use super::BrandLocationOutcome;
 /// Always returns a `BrandLocationOutcome`; errors are captured inside
 /// the outcome rather than propagated, so a failing brand does not abort
 /// the whole run.
-#[allow(clippy::too_many_lines)] // Orchestration function: URL resolve, scrape, upsert, deactivate, audit
+#[allow(clippy::too_many_lines)] // Orchestration function: URL resolve, scrape, drop guard, upsert, misses, events, audit
 pub(super) async fn collect_brand_locations(
     pool: &sqlx::PgPool,
     client: &reqwest::Client,
@@ -92,12 +94,57 @@ pub(super) async fn collect_brand_locations(
         .map(|l| l.location_key.clone())
         .collect();
 
-    // Snapshot active keys before upsert for diff logging.
-    let prev_keys: Option<std::collections::HashSet<String>> =
-        match scbdb_db::get_active_location_keys_for_brand(pool, brand.id).await {
-            Ok(keys) => Some(keys),
+    // Hold back scrapes that would wipe out most of the footprint for review.
+    let guard = scbdb_db::LocationDropGuard::from_app_config(config);
+    match scbdb_db::quarantine_location_scrape_on_drop(
+        pool,
+        brand.id,
+        Some(run_id),
+        &new_locations,
+        &guard,
+    )
+    .await
+    {
+        Ok(None) => {}
+        Ok(Some(held)) => {
+            let a = held.assessment;
+            let err_msg = format!(
+                "quarantined {}: {} locations vs baseline {} ({:.1}% drop); \
+                 review with `locations quarantine list`",
+                held.public_id, a.new_count, a.baseline_count, a.drop_pct
+            );
+            tracing::warn!(brand = %brand.slug, quarantine = %held.public_id, "{err_msg}");
+            record_brand_skipped(pool, run_id, brand, &err_msg).await;
+            return BrandLocationOutcome {
+                active: a.active_count,
+                new: 0,
+                lost: 0,
+                source,
+                succeeded: false,
+                error: Some(err_msg),
+            };
+        }
+        Err(e) => {
+            let err_msg = format!("db error checking location drop: {e:#}");
+            tracing::error!(brand = %brand.slug, error = %e, "location drop check failed");
+            record_brand_failure(pool, run_id, brand, &err_msg).await;
+            return BrandLocationOutcome {
+                active: 0,
+                new: 0,
+                lost: 0,
+                source,
+                succeeded: false,
+                error: Some(err_msg),
+            };
+        }
+    }
+
+    // Snapshot all stored locations before upsert for change-event diffing.
+    let prev_snapshot: Option<Vec<scbdb_db::LocationSnapshotRow>> =
+        match scbdb_db::list_location_snapshots_for_brand(pool, brand.id).await {
+            Ok(rows) => Some(rows),
             Err(e) => {
-                tracing::warn!(brand = %brand.slug, error = %e, "could not snapshot active keys; diff logging skipped");
+                tracing::warn!(brand = %brand.slug, error = %e, "could not snapshot locations; change events skipped");
                 None
             }
         };
@@ -120,18 +167,50 @@ pub(super) async fn collect_brand_locations(
             }
         };
 
-    let lost_count = match scbdb_db::deactivate_missing_locations(pool, brand.id, &active_keys)
-        .await
+    // A store only counts as closed after repeated misses (see LocationMissPolicy).
+    let mut events = prev_snapshot
+        .as_deref()
+        .map(|prev| scbdb_db::diff_location_snapshots(prev, &new_locations))
+        .unwrap_or_default();
+    let policy = scbdb_db::LocationMissPolicy::from_app_config(config);
+    let lost_count = match scbdb_db::record_location_misses(
+        pool,
+        brand.id,
+        &active_keys,
+        &events,
+        &policy,
+    )
+    .await
     {
-        Ok(n) => n,
+        Ok(misses) => {
+            if misses.pending > 0 {
+                tracing::info!(
+                    brand = %brand.slug,
+                    pending = misses.pending,
+                    "missing locations kept active until the miss threshold is reached"
+                );
+            }
+            misses.retain_confirmed_removals(&mut events);
+            misses.deactivated()
+        }
         Err(e) => {
-            tracing::warn!(brand = %brand.slug, error = %e, "failed to deactivate missing locations");
+            tracing::warn!(brand = %brand.slug, error = %e, "failed to record missing locations");
+            events.retain(|e| e.event_type != scbdb_db::LocationEventType::Removed);
             0
         }
     };
 
-    if let Some(prev) = &prev_keys {
-        log_location_changeset(&brand.slug, prev, &active_keys);
+    if let Err(e) = scbdb_db::assign_location_geographies(pool, Some(brand.id)).await {
+        tracing::warn!(brand = %brand.slug, error = %e, "failed to assign county/DMA to locations");
+    }
+
+    if prev_snapshot.is_some() {
+        log_location_changeset(&brand.slug, &events);
+        if let Err(e) =
+            scbdb_db::insert_store_location_events(pool, brand.id, Some(run_id), &events).await
+        {
+            tracing::warn!(brand = %brand.slug, error = %e, "failed to record store location events");
+        }
     }
 
     let total_active = new_count.saturating_add(kept_count);
@@ -0,0 +1,153 @@
+//! SKU-level distribution collection.
+//!
+//! For brands whose locator is product-level (Destini, `VTInfo`), searches
+//! the locator one product at a time — only provider products matched to
+//! our stored `products` — and records which stores carry each SKU.
+
+use super::helpers::load_brands_for_locations;
+use super::url::resolve_locator_url;
+
+/// Sweep product-level locators and record store × product availability.
+///
+/// Brands without stored products, or whose locator is not product-level,
+/// are reported and skipped. Stores returned for a product that are not yet
+/// in the brand's stored footprint are counted but not linked; run
+/// `collect locations` first.
+///
+/// # Errors
+///
+/// Returns an error if the brand filter does not resolve or the brand list
+/// cannot be loaded. Per-brand failures are logged and skipped.
+pub(crate) async fn run_collect_distribution(
+    pool: &sqlx::PgPool,
+    config: &scbdb_core::AppConfig,
+    brand_filter: Option<&str>,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let brands = load_brands_for_locations(pool, brand_filter).await?;
+    if brands.is_empty() {
+        anyhow::bail!("no eligible brands found for distribution collection");
+    }
+
+    let http_client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(
+            config.scraper_request_timeout_secs,
+        ))
+        .build()
+        .expect("failed to build HTTP client");
+
+    let mut total_linked: u64 = 0;
+    for brand in &brands {
+        match collect_brand_distribution(pool, &http_client, config, brand, dry_run).await {
+            Ok(linked) => total_linked += linked,
+            Err(e) => {
+                tracing::warn!(brand = %brand.slug, error = %e, "distribution sweep failed");
+                println!("  \u{2717} {:<20} {e:#}", brand.slug);
+            }
+        }
+    }
+
+    if dry_run {
+        println!("dry-run: no changes written");
+    } else {
+        println!("Run complete: {total_linked} store \u{d7} product links recorded");
+    }
+    Ok(())
+}
+
+/// Sweep one brand's locator; returns the number of store × product links.
+async fn collect_brand_distribution(
+    pool: &sqlx::PgPool,
+    client: &reqwest::Client,
+    config: &scbdb_core::AppConfig,
+    brand: &scbdb_db::BrandRow,
+    dry_run: bool,
+) -> anyhow::Result<u64> {
+    let candidates = scbdb_db::list_product_match_candidates(pool, brand.id).await?;
+    if candidates.is_empty() {
+        println!(
+            "  - {:<20} no stored products; run `collect products` first",
+            brand.slug
+        );
+        return Ok(0);
+    }
+    let Some(locator_url) = resolve_locator_url(brand, config).await else {
+        println!(
+            "  - {:<20} no locator URL configured or discovered",
+            brand.slug
+        );
+        return Ok(0);
+    };
+
+    let targets: Vec<scbdb_scraper::ProductMatchTarget> = candidates
+        .into_iter()
+        .map(|c| scbdb_scraper::ProductMatchTarget {
+            product_id: c.product_id,
+            name: c.name,
+            skus: c.skus,
+        })
+        .collect();
+    let lists = scbdb_scraper::fetch_product_store_locations(
+        client,
+        &locator_url,
+        config.scraper_request_timeout_secs,
+        &config.scraper_user_agent,
+        &targets,
+    )
+    .await?;
+    if lists.is_empty() {
+        println!(
+            "  - {:<20} no product-level locator or no matching products",
+            brand.slug
+        );
+        return Ok(0);
+    }
+
+    let mut linked: u64 = 0;
+    let mut unknown: u64 = 0;
+    for list in &lists {
+        let location_keys: Vec<String> = list
+            .stores
+            .iter()
+            .map(|loc| scbdb_scraper::make_location_key(brand.id, loc))
+            .collect();
+        if dry_run {
+            println!(
+                "    {:<40} {:>5} stores",
+                list.product
+                    .name
+                    .as_deref()
+                    .unwrap_or(&list.product.provider_product_id),
+                location_keys.len()
+            );
+            continue;
+        }
+
+        let product_ref = list
+            .product
+            .upc
+            .as_deref()
+            .unwrap_or(&list.product.provider_product_id);
+        let outcome = scbdb_db::record_product_sweep(
+            pool,
+            brand.id,
+            &scbdb_db::ProductSweepInput {
+                product_id: list.product_id,
+                product_ref,
+                product_name: list.product.name.as_deref(),
+                upc: list.product.upc.as_deref(),
+                location_keys: &location_keys,
+            },
+        )
+        .await?;
+        linked += outcome.linked;
+        unknown += outcome.unknown_stores;
+    }
+
+    println!(
+        "  \u{2713} {:<20} {:>3} products, {linked} store links ({unknown} stores not yet collected)",
+        brand.slug,
+        lists.len()
+    );
+    Ok(linked)
+}
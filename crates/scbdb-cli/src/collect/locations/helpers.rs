@@ -45,39 +45,63 @@ pub(super) async fn record_brand_failure(
     }
 }
 
-/// Log added/removed store location counts after upsert+deactivate.
-///
-/// `prev` is the active key set snapshotted **before** the upsert (`is_active = TRUE`
-/// only). `curr_keys` is the list of keys from the current scrape.
-///
-/// **Note:** a location that was previously deactivated and reappears in the current
-/// scrape is not in `prev` (inactive rows are excluded from the snapshot), so it is
-/// logged as "new store locations detected" rather than "reactivated". The DB state is
-/// correct; only the log message is imprecise. Distinguishing the two cases would
-/// require a second query for all-time keys including inactive rows.
-pub(super) fn log_location_changeset(
-    brand_slug: &str,
-    prev: &std::collections::HashSet<String>,
-    curr_keys: &[String],
+/// Record a brand whose scrape was quarantined as `skipped`, best-effort.
+pub(super) async fn record_brand_skipped(
+    pool: &sqlx::PgPool,
+    run_id: i64,
+    brand: &scbdb_db::BrandRow,
+    reason: &str,
 ) {
-    let curr: std::collections::HashSet<_> = curr_keys.iter().cloned().collect();
-    let added = curr.difference(prev).count();
-    let removed = prev.difference(&curr).count();
-    if added > 0 {
-        tracing::info!(brand = %brand_slug, count = added, "new store locations detected");
+    if let Err(e) =
+        scbdb_db::upsert_collection_run_brand(pool, run_id, brand.id, "skipped", None, Some(reason))
+            .await
+    {
+        tracing::error!(
+            run_id,
+            brand = %brand.slug,
+            error = %e,
+            "failed to record skipped brand in collection_run_brands"
+        );
     }
-    if removed > 0 {
-        tracing::info!(brand = %brand_slug, count = removed, "store locations deactivated");
+}
+
+/// Log per-type counts of the change events detected for one brand's scrape.
+///
+/// `events` comes from [`scbdb_db::diff_location_snapshots`], which compares the
+/// full pre-upsert snapshot (active and inactive rows), so reactivations and
+/// renames are reported as such rather than as new locations.
+pub(super) fn log_location_changeset(brand_slug: &str, events: &[scbdb_db::NewLocationEvent]) {
+    for event_type in [
+        scbdb_db::LocationEventType::Added,
+        scbdb_db::LocationEventType::Reactivated,
+        scbdb_db::LocationEventType::Removed,
+        scbdb_db::LocationEventType::Moved,
+        scbdb_db::LocationEventType::Renamed,
+    ] {
+        let count = events.iter().filter(|e| e.event_type == event_type).count();
+        if count > 0 {
+            tracing::info!(
+                brand = %brand_slug,
+                event = event_type.as_str(),
+                count,
+                "store location changes detected"
+            );
+        }
     }
 }
 
 /// Convert a [`scbdb_scraper::RawStoreLocation`] to a [`scbdb_db::NewStoreLocation`].
 ///
-/// `country` defaults to `"US"` when not present in the raw record.
+/// `country`, `state` and `zip` arrive normalized from the locator. The
+/// retailer chain and channel are classified from the store name and the
+/// provider's `raw_data` hints; hours, website, email, store type and carried
+/// products are mapped from `raw_data` by the provider's field mapper.
 pub(super) fn raw_to_new_location(
     loc: &scbdb_scraper::RawStoreLocation,
     location_key: String,
 ) -> scbdb_db::NewStoreLocation {
+    let class = scbdb_scraper::classify_retailer(&loc.name, &loc.raw_data);
+    let details = scbdb_scraper::extract_location_details(Some(&loc.locator_source), &loc.raw_data);
     scbdb_db::NewStoreLocation {
         location_key,
         name: loc.name.clone(),
@@ -85,12 +109,33 @@ pub(super) fn raw_to_new_location(
         city: loc.city.clone(),
         state: loc.state.clone(),
         zip: loc.zip.clone(),
-        country: loc.country.clone().or_else(|| Some("US".to_string())),
+        country: loc.country.clone(),
         latitude: loc.latitude,
         longitude: loc.longitude,
         phone: loc.phone.clone(),
         external_id: loc.external_id.clone(),
         locator_source: Some(loc.locator_source.clone()),
         raw_data: loc.raw_data.clone(),
+        retail_chain: class.chain.map(str::to_string),
+        retail_channel: class.channel.map(|c| c.as_str().to_string()),
+        carried_products: to_new_carried_products(&details.products),
+        hours: details.hours,
+        website: details.website,
+        email: details.email,
+        store_type: details.store_type,
     }
 }
+
+/// Convert mapped carried products to their database representation.
+pub(super) fn to_new_carried_products(
+    products: &[scbdb_scraper::CarriedProduct],
+) -> Vec<scbdb_db::NewCarriedProduct> {
+    products
+        .iter()
+        .map(|p| scbdb_db::NewCarriedProduct {
+            product_ref: p.product_ref.clone(),
+            product_name: p.name.clone(),
+            upc: p.upc.clone(),
+        })
+        .collect()
+}
@@ -6,6 +6,7 @@
 //! persists active locations while deactivating stale ones.
 
 mod brand;
+mod distribution;
 mod helpers;
 mod url;
 
@@ -13,6 +14,7 @@ use futures::stream::{self, StreamExt};
 
 use crate::fail_run_best_effort;
 use brand::collect_brand_locations;
+pub(crate) use distribution::run_collect_distribution;
 use helpers::load_brands_for_locations;
 
 // ---------------------------------------------------------------------------
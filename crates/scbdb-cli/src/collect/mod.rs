@@ -5,6 +5,7 @@
 //! propagated so a single bad brand does not abort the full run.
 
 mod brand;
+mod discovery;
 mod locations;
 mod runner;
 mod verify_images;
@@ -13,7 +14,8 @@ use clap::Subcommand;
 
 use runner::{load_brands_for_collect, run_collection, BrandOutcome};
 
-pub(crate) use locations::run_collect_locations;
+pub(crate) use discovery::run_collect_brand_candidates;
+pub(crate) use locations::{run_collect_distribution, run_collect_locations};
 
 /// Sub-commands available under `collect`.
 #[derive(Debug, Subcommand)]
@@ -52,6 +54,27 @@ pub enum CollectCommands {
         #[arg(long)]
         dry_run: bool,
     },
+    /// Sweep product-level locators (Destini, `VTInfo`) per SKU and record which stores carry it
+    Distribution {
+        /// Restrict collection to a specific brand (by slug)
+        #[arg(long)]
+        brand: Option<String>,
+        /// Print per-product store counts without writing to the database
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Harvest unknown brand names from news, sentiment, multi-brand locators and retailer pages
+    BrandCandidates {
+        /// Retailer product or category page to scan for brand names (repeatable)
+        #[arg(long = "retailer-url")]
+        retailer_urls: Vec<String>,
+        /// How many days of signals and sentiment text to scan
+        #[arg(long, default_value_t = 30)]
+        since_days: u32,
+        /// Print the most frequently seen names without writing to the database
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 /// Collect full product catalog and variant data from Shopify storefronts,
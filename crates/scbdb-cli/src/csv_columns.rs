@@ -0,0 +1,27 @@
+//! Header lookup helpers shared by the CLI's CSV importers.
+//!
+//! Exports from spreadsheets and ERPs rarely agree on column names, so each
+//! field is located by a list of case-insensitive aliases.
+
+/// Index of the first header matching any alias (case-insensitive).
+pub(crate) fn column(headers: &csv::StringRecord, aliases: &[&str]) -> Option<usize> {
+    headers
+        .iter()
+        .position(|h| aliases.iter().any(|a| h.trim().eq_ignore_ascii_case(a)))
+}
+
+pub(crate) fn required_column(
+    headers: &csv::StringRecord,
+    aliases: &[&str],
+) -> anyhow::Result<usize> {
+    column(headers, aliases)
+        .ok_or_else(|| anyhow::anyhow!("missing column (expected one of: {})", aliases.join(", ")))
+}
+
+/// Trimmed field value; `None` when the column is absent or the cell is blank.
+pub(crate) fn optional_field(record: &csv::StringRecord, idx: Option<usize>) -> Option<String> {
+    idx.and_then(|i| record.get(i))
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+}
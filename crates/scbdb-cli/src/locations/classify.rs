@@ -0,0 +1,71 @@
+//! Retailer chain/channel classification backfill.
+
+use std::collections::BTreeMap;
+
+/// Re-run the retailer classifier over stored locations.
+///
+/// New scrapes are classified at upsert time; this backfills rows collected
+/// before classification existed and picks up changes to the chain catalog.
+///
+/// # Errors
+///
+/// Returns an error if the brand filter does not resolve or a database
+/// query fails.
+pub(crate) async fn run_locations_classify(
+    pool: &sqlx::PgPool,
+    brand_filter: Option<&str>,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let brand_id = if let Some(slug) = brand_filter {
+        let brand = scbdb_db::get_brand_by_slug(pool, slug)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("brand '{slug}' not found"))?;
+        Some(brand.id)
+    } else {
+        None
+    };
+
+    let rows = scbdb_db::list_locations_for_classification(pool, brand_id).await?;
+    if rows.is_empty() {
+        println!("no stored locations to classify; run `collect locations` first");
+        return Ok(());
+    }
+
+    let mut channel_counts: BTreeMap<&'static str, usize> = BTreeMap::new();
+    let mut chain_matches = 0usize;
+    let updates: Vec<scbdb_db::LocationClassificationUpdate> = rows
+        .iter()
+        .map(|row| {
+            let raw = row.raw_data.clone().unwrap_or(serde_json::Value::Null);
+            let class = scbdb_scraper::classify_retailer(&row.name, &raw);
+            let channel_label = class.channel.map_or("unknown", |c| c.as_str());
+            *channel_counts.entry(channel_label).or_default() += 1;
+            if class.chain.is_some() {
+                chain_matches += 1;
+            }
+            scbdb_db::LocationClassificationUpdate {
+                id: row.id,
+                retail_chain: class.chain.map(str::to_string),
+                retail_channel: class.channel.map(|c| c.as_str().to_string()),
+            }
+        })
+        .collect();
+
+    println!(
+        "{} locations classified ({chain_matches} matched a known chain):",
+        updates.len()
+    );
+    for (channel, count) in &channel_counts {
+        println!("  {channel:<12} {count:>6}");
+    }
+
+    if dry_run {
+        println!("dry-run: no changes written");
+        return Ok(());
+    }
+
+    let changed = scbdb_db::update_location_classifications(pool, &updates).await?;
+    println!("{changed} location(s) updated");
+
+    Ok(())
+}
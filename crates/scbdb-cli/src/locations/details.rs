@@ -0,0 +1,86 @@
+//! Backfill of structured store details (hours, website, email, store type,
+//! carried products) from stored locator `raw_data`.
+
+/// Re-run the provider field mappers over stored locations.
+///
+/// New scrapes are mapped at upsert time; this backfills rows collected
+/// before the mappers existed and picks up mapper changes.
+///
+/// # Errors
+///
+/// Returns an error if the brand filter does not resolve or a database
+/// query fails.
+pub(crate) async fn run_locations_details(
+    pool: &sqlx::PgPool,
+    brand_filter: Option<&str>,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let brand_id = if let Some(slug) = brand_filter {
+        let brand = scbdb_db::get_brand_by_slug(pool, slug)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("brand '{slug}' not found"))?;
+        Some(brand.id)
+    } else {
+        None
+    };
+
+    let rows = scbdb_db::list_locations_for_details(pool, brand_id).await?;
+    if rows.is_empty() {
+        println!("no stored locations to map; run `collect locations` first");
+        return Ok(());
+    }
+
+    let (mut with_hours, mut with_website, mut with_email, mut with_type) = (0, 0, 0, 0);
+    let mut with_products = 0usize;
+    let mut product_rows = 0usize;
+    let updates: Vec<scbdb_db::LocationDetailsUpdate> = rows
+        .iter()
+        .map(|row| {
+            let raw = row.raw_data.clone().unwrap_or(serde_json::Value::Null);
+            let details =
+                scbdb_scraper::extract_location_details(row.locator_source.as_deref(), &raw);
+            with_hours += usize::from(details.hours.is_some());
+            with_website += usize::from(details.website.is_some());
+            with_email += usize::from(details.email.is_some());
+            with_type += usize::from(details.store_type.is_some());
+            with_products += usize::from(!details.products.is_empty());
+            product_rows += details.products.len();
+            scbdb_db::LocationDetailsUpdate {
+                id: row.id,
+                carried_products: details
+                    .products
+                    .into_iter()
+                    .map(|p| scbdb_db::NewCarriedProduct {
+                        product_ref: p.product_ref,
+                        product_name: p.name,
+                        upc: p.upc,
+                    })
+                    .collect(),
+                hours: details.hours,
+                website: details.website,
+                email: details.email,
+                store_type: details.store_type,
+            }
+        })
+        .collect();
+
+    println!("{} locations mapped:", updates.len());
+    println!("  {:<12} {with_hours:>6}", "hours");
+    println!("  {:<12} {with_website:>6}", "website");
+    println!("  {:<12} {with_email:>6}", "email");
+    println!("  {:<12} {with_type:>6}", "store type");
+    println!(
+        "  {:<12} {with_products:>6} ({product_rows} product rows)",
+        "products"
+    );
+
+    if dry_run {
+        println!("dry-run: no changes written");
+        return Ok(());
+    }
+
+    let changed = scbdb_db::update_location_details(pool, &updates).await?;
+    println!("{changed} location(s) updated");
+
+    Ok(())
+}
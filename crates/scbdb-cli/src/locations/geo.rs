@@ -0,0 +1,294 @@
+//! County / DMA reference data loading and location assignment.
+//!
+//! `locations load-geo` with no arguments loads the bundled seed in
+//! `config/geo/`: Census county names and population estimates and a
+//! ZIP→county crosswalk from the Census ZCTA relationship file, all public
+//! domain and regenerated with `scripts/fetch-geo-reference.sh`. Operators
+//! may load their own files instead (e.g. the HUD USPS ZIP-COUNTY
+//! crosswalk). County→DMA assignments are licensed Nielsen data and are not
+//! bundled, so the DMA columns are optional and rollups fall back to
+//! counties without them.
+//!
+//! - **Counties** — one row per county: `fips`, `name`, `state`,
+//!   `population`, and optionally `dma_code` and `dma_name`.
+//! - **ZIP→county crosswalk** — the HUD USPS ZIP-COUNTY file (`ZIP`,
+//!   `COUNTY`, `RES_RATIO`) or any CSV with `zip`, `county_fips` and an
+//!   optional `res_ratio`.
+//!
+//! Header names are matched case-insensitively against a few common aliases,
+//! and FIPS/ZIP values stripped of leading zeros by spreadsheets are re-padded.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use anyhow::Context;
+
+use crate::csv_columns::{column, optional_field, required_column};
+
+const FIPS_HEADERS: &[&str] = &["fips", "county_fips", "geoid"];
+const COUNTY_NAME_HEADERS: &[&str] = &["name", "county_name", "ctyname"];
+const STATE_HEADERS: &[&str] = &["state", "stusab", "state_abbr"];
+const POPULATION_HEADERS: &[&str] = &["population", "pop", "popestimate"];
+const DMA_CODE_HEADERS: &[&str] = &["dma_code", "dma"];
+const DMA_NAME_HEADERS: &[&str] = &["dma_name"];
+const ZIP_HEADERS: &[&str] = &["zip", "zip_code", "zcta"];
+const ZIP_COUNTY_HEADERS: &[&str] = &["county_fips", "county", "fips"];
+const RES_RATIO_HEADERS: &[&str] = &["res_ratio", "ratio"];
+
+/// Bundled Census county seed loaded when `--counties` is omitted.
+const SEED_COUNTIES_PATH: &str = "./config/geo/counties.csv";
+/// Bundled ZIP→county seed loaded alongside [`SEED_COUNTIES_PATH`].
+const SEED_ZIP_COUNTIES_PATH: &str = "./config/geo/zip_counties.csv";
+
+/// Left-pad a numeric code to `width` digits; `None` if it is not all digits
+/// or too long.
+fn pad_code(value: &str, width: usize) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() || value.len() > width || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(format!("{value:0>width$}"))
+}
+
+/// Parse the county reference CSV.
+///
+/// # Errors
+///
+/// Returns an error if a required column is missing, a row has an invalid
+/// FIPS code, state or population, or a DMA code is never given a name.
+pub(crate) fn parse_counties_csv<R: std::io::Read>(
+    reader: R,
+) -> anyhow::Result<Vec<scbdb_db::NewGeoCounty>> {
+    let mut rdr = csv::Reader::from_reader(reader);
+    let headers = rdr.headers()?.clone();
+    let fips_idx = required_column(&headers, FIPS_HEADERS)?;
+    let name_idx = required_column(&headers, COUNTY_NAME_HEADERS)?;
+    let state_idx = required_column(&headers, STATE_HEADERS)?;
+    let population_idx = column(&headers, POPULATION_HEADERS);
+    let dma_code_idx = column(&headers, DMA_CODE_HEADERS);
+    let dma_name_idx = column(&headers, DMA_NAME_HEADERS);
+
+    let mut counties = Vec::new();
+    for (line, record) in rdr.records().enumerate() {
+        let record = record?;
+        let row = line + 2; // 1-based, after the header
+        let fips = pad_code(record.get(fips_idx).unwrap_or(""), 5)
+            .ok_or_else(|| anyhow::anyhow!("row {row}: invalid county FIPS code"))?;
+        let state = record.get(state_idx).unwrap_or("").trim().to_uppercase();
+        if state.len() != 2 {
+            anyhow::bail!("row {row}: state must be a 2-letter code, got '{state}'");
+        }
+        let population = optional_field(&record, population_idx)
+            .map(|p| p.replace(',', "").parse::<i32>())
+            .transpose()
+            .with_context(|| format!("row {row}: invalid population"))?;
+        counties.push(scbdb_db::NewGeoCounty {
+            fips,
+            name: record.get(name_idx).unwrap_or("").trim().to_string(),
+            state,
+            population,
+            dma_code: optional_field(&record, dma_code_idx),
+            dma_name: optional_field(&record, dma_name_idx),
+        });
+    }
+
+    // A DMA is stored from the rows that name it; a code no row names would
+    // leave its counties pointing at a missing DMA.
+    let named: HashSet<&str> = counties
+        .iter()
+        .filter(|c| c.dma_name.is_some())
+        .filter_map(|c| c.dma_code.as_deref())
+        .collect();
+    for (i, county) in counties.iter().enumerate() {
+        if let Some(code) = county.dma_code.as_deref() {
+            if !named.contains(code) {
+                anyhow::bail!(
+                    "row {}: dma_code '{code}' has no dma_name on any row",
+                    i + 2
+                );
+            }
+        }
+    }
+    Ok(counties)
+}
+
+/// Parse a ZIP→county crosswalk CSV.
+///
+/// Rows with a ZIP or county code that is not numeric are skipped (HUD files
+/// carry a handful of placeholder rows); a missing ratio defaults to 1.
+///
+/// # Errors
+///
+/// Returns an error if a required column is missing or a ratio is not a number.
+pub(crate) fn parse_zip_counties_csv<R: std::io::Read>(
+    reader: R,
+) -> anyhow::Result<Vec<scbdb_db::NewZipCounty>> {
+    let mut rdr = csv::Reader::from_reader(reader);
+    let headers = rdr.headers()?.clone();
+    let zip_idx = required_column(&headers, ZIP_HEADERS)?;
+    let county_idx = required_column(&headers, ZIP_COUNTY_HEADERS)?;
+    let ratio_idx = column(&headers, RES_RATIO_HEADERS);
+
+    let mut rows = Vec::new();
+    for (line, record) in rdr.records().enumerate() {
+        let record = record?;
+        let row = line + 2;
+        let (Some(zip), Some(county_fips)) = (
+            pad_code(record.get(zip_idx).unwrap_or(""), 5),
+            pad_code(record.get(county_idx).unwrap_or(""), 5),
+        ) else {
+            continue;
+        };
+        let res_ratio = optional_field(&record, ratio_idx)
+            .map(|r| r.parse::<f64>())
+            .transpose()
+            .with_context(|| format!("row {row}: invalid res_ratio"))?
+            .unwrap_or(1.0);
+        rows.push(scbdb_db::NewZipCounty {
+            zip,
+            county_fips,
+            res_ratio,
+        });
+    }
+    Ok(rows)
+}
+
+fn open_csv(path: &Path) -> anyhow::Result<std::fs::File> {
+    std::fs::File::open(path).with_context(|| format!("failed to open {}", path.display()))
+}
+
+/// Open a bundled seed file, pointing at the generator script when absent.
+fn open_seed(path: &Path) -> anyhow::Result<std::fs::File> {
+    if !path.exists() {
+        anyhow::bail!(
+            "bundled geo seed {} not found; run scripts/fetch-geo-reference.sh or pass --counties",
+            path.display()
+        );
+    }
+    open_csv(path)
+}
+
+/// Load county/DMA reference data and re-assign every stored location.
+///
+/// Without `counties_path` the bundled seed is loaded: its counties, plus
+/// its ZIP crosswalk unless `zip_counties_path` overrides it.
+///
+/// # Errors
+///
+/// Returns an error if a file cannot be read or parsed, or a database
+/// operation fails.
+pub(crate) async fn run_locations_load_geo(
+    pool: &sqlx::PgPool,
+    counties_path: Option<&Path>,
+    zip_counties_path: Option<&Path>,
+) -> anyhow::Result<()> {
+    let counties = match counties_path {
+        Some(path) => parse_counties_csv(open_csv(path)?)
+            .with_context(|| format!("failed to parse {}", path.display()))?,
+        None => parse_counties_csv(open_seed(Path::new(SEED_COUNTIES_PATH))?)
+            .with_context(|| format!("failed to parse {SEED_COUNTIES_PATH}"))?,
+    };
+    let zip_counties = match zip_counties_path {
+        Some(path) => parse_zip_counties_csv(open_csv(path)?)
+            .with_context(|| format!("failed to parse {}", path.display()))?,
+        None if counties_path.is_none() => {
+            parse_zip_counties_csv(open_seed(Path::new(SEED_ZIP_COUNTIES_PATH))?)
+                .with_context(|| format!("failed to parse {SEED_ZIP_COUNTIES_PATH}"))?
+        }
+        None => Vec::new(),
+    };
+
+    let summary = scbdb_db::load_geo_reference(pool, &counties, &zip_counties).await?;
+    println!(
+        "loaded {} counties, {} DMAs, {} ZIP→county rows",
+        summary.counties, summary.dmas, summary.zip_counties
+    );
+
+    let assigned = scbdb_db::assign_location_geographies(pool, None).await?;
+    println!("{assigned} location(s) assigned a county/DMA");
+    Ok(())
+}
+
+/// Re-assign county/DMA for stored locations from the loaded reference data.
+///
+/// # Errors
+///
+/// Returns an error if the brand filter does not resolve or the update fails.
+pub(crate) async fn run_locations_assign_geo(
+    pool: &sqlx::PgPool,
+    brand_filter: Option<&str>,
+) -> anyhow::Result<()> {
+    let brand_id = if let Some(slug) = brand_filter {
+        let brand = scbdb_db::get_brand_by_slug(pool, slug)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("brand '{slug}' not found"))?;
+        Some(brand.id)
+    } else {
+        None
+    };
+
+    let assigned = scbdb_db::assign_location_geographies(pool, brand_id).await?;
+    println!("{assigned} location(s) updated");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_counties_with_aliases_and_padding() {
+        let csv = "GEOID,County_Name,STUSAB,Population,DMA_Code,DMA_Name\n\
+                   45079,Richland County,sc,\"416,147\",546,Columbia SC\n\
+                   1001,Autauga County,AL,,,\n";
+        let counties = parse_counties_csv(csv.as_bytes()).expect("parse");
+        assert_eq!(counties.len(), 2);
+        assert_eq!(counties[0].fips, "45079");
+        assert_eq!(counties[0].state, "SC");
+        assert_eq!(counties[0].population, Some(416_147));
+        assert_eq!(counties[0].dma_code.as_deref(), Some("546"));
+        assert_eq!(counties[1].fips, "01001");
+        assert_eq!(counties[1].population, None);
+        assert_eq!(counties[1].dma_name, None);
+    }
+
+    #[test]
+    fn dma_code_needs_a_name_on_some_row() {
+        let named_once = "fips,name,state,dma_code,dma_name\n\
+                          45079,Richland County,SC,546,\n\
+                          45063,Lexington County,SC,546,Columbia SC\n";
+        assert!(parse_counties_csv(named_once.as_bytes()).is_ok());
+
+        let unnamed = "fips,name,state,dma_code,dma_name\n\
+                       45079,Richland County,SC,546,Columbia SC\n\
+                       45019,Charleston County,SC,519,\n";
+        let err = parse_counties_csv(unnamed.as_bytes()).expect_err("should fail");
+        assert!(err.to_string().contains("row 3: dma_code '519'"), "{err}");
+    }
+
+    #[test]
+    fn rejects_counties_without_fips_column() {
+        let csv = "name,state\nRichland County,SC\n";
+        let err = parse_counties_csv(csv.as_bytes()).expect_err("should fail");
+        assert!(err.to_string().contains("fips"));
+    }
+
+    #[test]
+    fn parses_hud_zip_county_crosswalk() {
+        let csv = "ZIP,COUNTY,USPS_ZIP_PREF_CITY,RES_RATIO\n\
+                   29201,45079,COLUMBIA,1\n\
+                   501,36103,HOLTSVILLE,0.75\n\
+                   99999,,UNKNOWN,0\n";
+        let rows = parse_zip_counties_csv(csv.as_bytes()).expect("parse");
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].zip, "00501");
+        assert!((rows[1].res_ratio - 0.75).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn pad_code_rejects_non_numeric_and_overlong() {
+        assert_eq!(pad_code("45079", 5).as_deref(), Some("45079"));
+        assert_eq!(pad_code("123456", 5), None);
+        assert_eq!(pad_code("45-79", 5), None);
+    }
+}
@@ -0,0 +1,105 @@
+//! Store location maintenance command handlers for the CLI.
+//!
+//! These operate on locations already stored by `collect locations`; they
+//! never contact store locator providers.
+
+mod classify;
+mod details;
+mod geo;
+mod quarantine;
+mod rekey;
+
+use std::path::PathBuf;
+
+use clap::Subcommand;
+use uuid::Uuid;
+
+pub(crate) use classify::run_locations_classify;
+pub(crate) use details::run_locations_details;
+pub(crate) use geo::{run_locations_assign_geo, run_locations_load_geo};
+pub(crate) use quarantine::{run_quarantine_approve, run_quarantine_list, run_quarantine_reject};
+pub(crate) use rekey::run_locations_rekey;
+
+/// Sub-commands available under `locations`.
+#[derive(Debug, Subcommand)]
+pub enum LocationsCommands {
+    /// Re-classify stored locations by retailer chain and channel
+    Classify {
+        /// Restrict classification to a specific brand (by slug)
+        #[arg(long)]
+        brand: Option<String>,
+        /// Print the resulting channel mix without writing to the database
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Re-map hours, website, email, store type and carried products from stored `raw_data`
+    Details {
+        /// Restrict the backfill to a specific brand (by slug)
+        #[arg(long)]
+        brand: Option<String>,
+        /// Print what would be mapped without writing to the database
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Recompute location keys with the current address normalisation and merge duplicates
+    Rekey {
+        /// Restrict re-keying to a specific brand (by slug)
+        #[arg(long)]
+        brand: Option<String>,
+        /// Print how many keys change without writing to the database
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Load operator-supplied county/DMA reference CSVs and assign every location a county and DMA
+    LoadGeo {
+        /// County CSV with FIPS code, name, state, population and optional DMA code/name
+        /// (defaults to the bundled Census seed)
+        #[arg(long)]
+        counties: Option<PathBuf>,
+        /// ZIP→county crosswalk CSV (HUD USPS ZIP-COUNTY format accepted; defaults to the
+        /// bundled seed when `--counties` is omitted)
+        #[arg(long)]
+        zip_counties: Option<PathBuf>,
+    },
+    /// Re-assign county/DMA for stored locations from loaded reference data
+    AssignGeo {
+        /// Restrict assignment to a specific brand (by slug)
+        #[arg(long)]
+        brand: Option<String>,
+    },
+    /// Review location scrapes held back by the mass-deactivation guardrail
+    Quarantine {
+        #[command(subcommand)]
+        command: QuarantineCommands,
+    },
+}
+
+/// Sub-commands available under `locations quarantine`.
+#[derive(Debug, Subcommand)]
+pub enum QuarantineCommands {
+    /// List quarantined scrapes, newest first
+    List {
+        /// Filter by status: pending, approved, rejected or superseded
+        #[arg(long)]
+        status: Option<String>,
+        /// Maximum rows to print
+        #[arg(long, default_value_t = 50)]
+        limit: u32,
+    },
+    /// Apply a quarantined scrape (upsert it and deactivate missing locations)
+    Approve {
+        /// Quarantine id from `locations quarantine list`
+        id: Uuid,
+        /// Reviewer note stored with the decision
+        #[arg(long)]
+        note: Option<String>,
+    },
+    /// Discard a quarantined scrape, keeping the stored locations as they are
+    Reject {
+        /// Quarantine id from `locations quarantine list`
+        id: Uuid,
+        /// Reviewer note stored with the decision
+        #[arg(long)]
+        note: Option<String>,
+    },
+}
@@ -0,0 +1,73 @@
+//! Review of location scrapes held back by the mass-deactivation guardrail.
+
+use uuid::Uuid;
+
+/// Print quarantined scrapes, newest first.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub(crate) async fn run_quarantine_list(
+    pool: &sqlx::PgPool,
+    status: Option<&str>,
+    limit: i64,
+) -> anyhow::Result<()> {
+    let rows = scbdb_db::list_location_quarantines(pool, status, limit).await?;
+    if rows.is_empty() {
+        println!("no quarantined location scrapes");
+        return Ok(());
+    }
+
+    println!(
+        "{:<36}  {:<20} {:<10} {:>7} {:>8} {:>7}  created",
+        "id", "brand", "status", "scraped", "baseline", "drop %"
+    );
+    for row in &rows {
+        println!(
+            "{:<36}  {:<20} {:<10} {:>7} {:>8} {:>7}  {}",
+            row.public_id,
+            row.brand_slug,
+            row.status,
+            row.new_count,
+            row.baseline_count,
+            row.drop_pct,
+            row.created_at.format("%Y-%m-%d %H:%M"),
+        );
+    }
+    Ok(())
+}
+
+/// Apply a quarantined scrape.
+///
+/// # Errors
+///
+/// Returns an error if the quarantine does not exist, is no longer pending,
+/// or a write fails.
+pub(crate) async fn run_quarantine_approve(
+    pool: &sqlx::PgPool,
+    id: Uuid,
+    note: Option<&str>,
+) -> anyhow::Result<()> {
+    let summary = scbdb_db::approve_location_quarantine(pool, id, note).await?;
+    println!(
+        "applied quarantine {id}: {} new, {} updated, {} deactivated, {} change event(s)",
+        summary.new, summary.updated, summary.deactivated, summary.events
+    );
+    Ok(())
+}
+
+/// Discard a quarantined scrape.
+///
+/// # Errors
+///
+/// Returns an error if the quarantine does not exist, is no longer pending,
+/// or the update fails.
+pub(crate) async fn run_quarantine_reject(
+    pool: &sqlx::PgPool,
+    id: Uuid,
+    note: Option<&str>,
+) -> anyhow::Result<()> {
+    scbdb_db::reject_location_quarantine(pool, id, note).await?;
+    println!("rejected quarantine {id}; stored locations unchanged");
+    Ok(())
+}
@@ -0,0 +1,133 @@
+//! Re-key stored locations with the scraper's current key normalisation.
+//!
+//! Country detection changed what goes into `location_key`: Canadian and
+//! Puerto Rican stores gained a country suffix, and US state names and
+//! ZIP+4 codes are normalised before keying. Rows stored earlier keep their
+//! old keys, so the next scrape would insert them again as new stores and
+//! retire the originals. Running this once after upgrading brings stored
+//! keys in line and merges any duplicates already created.
+
+use scbdb_scraper::RawStoreLocation;
+
+/// The key and normalised fields a fresh scrape of `row` would produce.
+fn rekey(row: &scbdb_db::LocationKeyInput) -> scbdb_db::LocationRekey {
+    let mut loc = RawStoreLocation {
+        external_id: None,
+        name: row.name.clone(),
+        address_line1: row.address_line1.clone(),
+        city: row.city.clone(),
+        state: row.state.clone(),
+        zip: row.zip.clone(),
+        // Rows stored before detection defaulted to 'US'; detect them afresh
+        // from the region and postal code as a new scrape would.
+        country: (row.country != "US").then(|| row.country.clone()),
+        latitude: None,
+        longitude: None,
+        phone: None,
+        locator_source: String::new(),
+        raw_data: serde_json::Value::Null,
+    };
+    scbdb_scraper::normalize_store_location(&mut loc);
+    scbdb_db::LocationRekey {
+        id: row.id,
+        location_key: scbdb_scraper::make_location_key(row.brand_id, &loc),
+        state: loc.state,
+        zip: loc.zip,
+        country: loc.country.unwrap_or_else(|| "US".to_string()),
+    }
+}
+
+/// Recompute `location_key` for stored locations and merge rows that now
+/// share a key.
+///
+/// # Errors
+///
+/// Returns an error if the brand filter does not resolve or a database
+/// query fails.
+pub(crate) async fn run_locations_rekey(
+    pool: &sqlx::PgPool,
+    brand_filter: Option<&str>,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let brand_id = if let Some(slug) = brand_filter {
+        let brand = scbdb_db::get_brand_by_slug(pool, slug)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("brand '{slug}' not found"))?;
+        Some(brand.id)
+    } else {
+        None
+    };
+
+    let rows = scbdb_db::list_locations_for_rekey(pool, brand_id).await?;
+    if rows.is_empty() {
+        println!("no stored locations to re-key");
+        return Ok(());
+    }
+
+    let rekeys: Vec<scbdb_db::LocationRekey> = rows.iter().map(rekey).collect();
+    let changed_keys = rows
+        .iter()
+        .zip(&rekeys)
+        .filter(|(row, r)| row.location_key != r.location_key)
+        .count();
+    let distinct_keys = rows
+        .iter()
+        .zip(&rekeys)
+        .map(|(row, r)| (row.brand_id, r.location_key.as_str()))
+        .collect::<std::collections::HashSet<_>>()
+        .len();
+    println!(
+        "{} locations: {changed_keys} new key(s), {} duplicate(s) to merge",
+        rows.len(),
+        rows.len() - distinct_keys
+    );
+
+    if dry_run {
+        println!("dry-run: no changes written");
+        return Ok(());
+    }
+
+    let summary = scbdb_db::rekey_store_locations(pool, &rekeys).await?;
+    println!(
+        "{} location(s) updated, {} duplicate(s) merged",
+        summary.rekeyed, summary.merged
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(state: &str, zip: &str, country: &str) -> scbdb_db::LocationKeyInput {
+        scbdb_db::LocationKeyInput {
+            id: 1,
+            brand_id: 7,
+            location_key: "old".to_string(),
+            name: "Corner Store".to_string(),
+            address_line1: Some("1 Main St".to_string()),
+            city: Some("Columbia".to_string()),
+            state: Some(state.to_string()),
+            zip: Some(zip.to_string()),
+            country: country.to_string(),
+            last_seen_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn rekey_matches_a_normalised_scrape() {
+        let legacy = rekey(&row("South Carolina", "292011234", "US"));
+        let current = rekey(&row("SC", "29201-1234", "US"));
+        assert_eq!(legacy.state.as_deref(), Some("SC"));
+        assert_eq!(legacy.zip.as_deref(), Some("29201-1234"));
+        assert_eq!(legacy.location_key, current.location_key);
+    }
+
+    #[test]
+    fn rekey_detects_countries_defaulted_to_us() {
+        let ontario = rekey(&row("Ontario", "m5v2t6", "US"));
+        assert_eq!(ontario.country, "CA");
+        assert_eq!(ontario.state.as_deref(), Some("ON"));
+        assert_eq!(ontario.zip.as_deref(), Some("M5V 2T6"));
+    }
+}
@@ -1,12 +1,17 @@
+mod accounts;
 mod collect;
+mod csv_columns;
+mod locations;
 mod regs;
 mod sentiment;
 
 use clap::{Parser, Subcommand};
 use tracing_subscriber::EnvFilter;
 
+use accounts::AccountsCommands;
 use collect::CollectCommands;
-use regs::RegsCommands;
+use locations::{LocationsCommands, QuarantineCommands};
+use regs::{RegsCommands, RulesCommands, WatchCommands};
 use sentiment::SentimentCommands;
 
 #[derive(Debug, Parser)]
@@ -24,6 +29,16 @@ enum Commands {
         #[command(subcommand)]
         command: CollectCommands,
     },
+    /// Import our retailer accounts and report competitor presence at them
+    Accounts {
+        #[command(subcommand)]
+        command: AccountsCommands,
+    },
+    /// Maintain stored store locations (classification, detail backfill, county/DMA reference data, quarantine review)
+    Locations {
+        #[command(subcommand)]
+        command: LocationsCommands,
+    },
     /// Track regulatory filings and legislative activity
     Regs {
         #[command(subcommand)]
@@ -84,43 +99,32 @@ async fn main() -> anyhow::Result<()> {
                 let pool = connect_or_exit().await;
                 collect::run_collect_locations(&pool, &config, brand.as_deref(), dry_run).await?;
             }
-        },
-        Some(Commands::Regs { command }) => match command {
-            RegsCommands::Ingest {
-                state,
-                keyword,
-                max_pages,
-                max_requests,
-                all_sessions,
+            CollectCommands::Distribution { brand, dry_run } => {
+                let config = load_config_or_exit();
+                let pool = connect_or_exit().await;
+                collect::run_collect_distribution(&pool, &config, brand.as_deref(), dry_run)
+                    .await?;
+            }
+            CollectCommands::BrandCandidates {
+                retailer_urls,
+                since_days,
                 dry_run,
             } => {
                 let config = load_config_or_exit();
                 let pool = connect_or_exit().await;
-                regs::run_regs_ingest(
+                collect::run_collect_brand_candidates(
                     &pool,
                     &config,
-                    &state,
-                    &keyword,
-                    max_pages,
-                    max_requests,
-                    all_sessions,
+                    &retailer_urls,
+                    since_days,
                     dry_run,
                 )
                 .await?;
             }
-            RegsCommands::Status { state, limit } => {
-                let pool = connect_or_exit().await;
-                regs::run_regs_status(&pool, state.as_deref(), i64::from(limit)).await?;
-            }
-            RegsCommands::Timeline { state, bill } => {
-                let pool = connect_or_exit().await;
-                regs::run_regs_timeline(&pool, &state, &bill).await?;
-            }
-            RegsCommands::Report { state } => {
-                let pool = connect_or_exit().await;
-                regs::run_regs_report(&pool, state.as_deref()).await?;
-            }
         },
+        Some(Commands::Accounts { command }) => run_accounts_command(command).await?,
+        Some(Commands::Locations { command }) => run_locations_command(command).await?,
+        Some(Commands::Regs { command }) => run_regs_command(command).await?,
         Some(Commands::Sentiment { command }) => match command {
             SentimentCommands::Collect { brand, dry_run } => {
                 let pool = connect_or_exit().await;
@@ -150,6 +154,235 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[allow(clippy::too_many_lines)] // Flat dispatch; one arm per subcommand.
+async fn run_regs_command(command: RegsCommands) -> anyhow::Result<()> {
+    match command {
+        RegsCommands::Ingest {
+            state,
+            keyword,
+            max_pages,
+            max_requests,
+            min_relevance,
+            all_sessions,
+            dry_run,
+        } => {
+            let config = load_config_or_exit();
+            let pool = connect_or_exit().await;
+            regs::run_regs_ingest(
+                &pool,
+                &config,
+                &state,
+                &keyword,
+                max_pages,
+                max_requests,
+                min_relevance,
+                all_sessions,
+                dry_run,
+            )
+            .await?;
+        }
+        RegsCommands::ImportDataset {
+            state,
+            keyword,
+            min_relevance,
+            max_requests,
+            force,
+            dry_run,
+        } => {
+            let config = load_config_or_exit();
+            let pool = connect_or_exit().await;
+            regs::run_regs_import_dataset(
+                &pool,
+                &config,
+                &state,
+                &keyword,
+                min_relevance,
+                max_requests,
+                force,
+                dry_run,
+            )
+            .await?;
+        }
+        RegsCommands::Texts {
+            state,
+            limit,
+            max_requests,
+            retry_failed,
+            dry_run,
+        } => {
+            let config = load_config_or_exit();
+            let pool = connect_or_exit().await;
+            regs::run_regs_texts(
+                &pool,
+                &config,
+                state.as_deref(),
+                i64::from(limit),
+                max_requests,
+                retry_failed,
+                dry_run,
+            )
+            .await?;
+        }
+        RegsCommands::Score { state } => {
+            let config = load_config_or_exit();
+            let pool = connect_or_exit().await;
+            regs::run_regs_score(&pool, &config, state.as_deref()).await?;
+        }
+        RegsCommands::Status { state, limit } => {
+            let config = load_config_or_exit();
+            let pool = connect_or_exit().await;
+            regs::run_regs_status(&pool, &config, state.as_deref(), i64::from(limit)).await?;
+        }
+        RegsCommands::Timeline { state, bill } => {
+            let pool = connect_or_exit().await;
+            regs::run_regs_timeline(&pool, &state, &bill).await?;
+        }
+        RegsCommands::Upcoming { state, days } => {
+            let pool = connect_or_exit().await;
+            regs::run_regs_upcoming(&pool, state.as_deref(), days).await?;
+        }
+        RegsCommands::Report { state } => {
+            let pool = connect_or_exit().await;
+            regs::run_regs_report(&pool, state.as_deref()).await?;
+        }
+        RegsCommands::Compliance {
+            brand,
+            state,
+            severity,
+            as_of,
+        } => {
+            let pool = connect_or_exit().await;
+            regs::run_regs_compliance(
+                &pool,
+                brand.as_deref(),
+                state.as_deref(),
+                severity.as_deref(),
+                as_of,
+            )
+            .await?;
+        }
+        RegsCommands::Exposure {
+            brand,
+            as_of,
+            dry_run,
+        } => {
+            let config = load_config_or_exit();
+            let pool = connect_or_exit().await;
+            regs::run_regs_exposure(&pool, &config, brand.as_deref(), as_of, dry_run).await?;
+        }
+        RegsCommands::Rules { command } => {
+            let pool = connect_or_exit().await;
+            run_rules_command(&pool, command).await?;
+        }
+        RegsCommands::Watch { command } => {
+            let pool = connect_or_exit().await;
+            run_watch_command(&pool, command).await?;
+        }
+    }
+    Ok(())
+}
+
+async fn run_rules_command(pool: &sqlx::PgPool, command: RulesCommands) -> anyhow::Result<()> {
+    match command {
+        RulesCommands::Add {
+            state,
+            kind,
+            value,
+            channel,
+            effective_from,
+            effective_to,
+            bill,
+            source_text,
+            source_url,
+            notes,
+        } => {
+            regs::run_rules_add(
+                pool,
+                &regs::RuleInput {
+                    state: &state,
+                    kind: &kind,
+                    value,
+                    channels: &channel,
+                    effective_from,
+                    effective_to,
+                    bill: bill.as_deref(),
+                    source_text: source_text.as_deref(),
+                    source_url: source_url.as_deref(),
+                    notes: notes.as_deref(),
+                },
+            )
+            .await
+        }
+        RulesCommands::List {
+            state,
+            kind,
+            history,
+        } => regs::run_rules_list(pool, state.as_deref(), kind.as_deref(), history).await,
+        RulesCommands::Compare { as_of } => regs::run_rules_compare(pool, as_of).await,
+        RulesCommands::Remove { id } => regs::run_rules_remove(pool, id).await,
+    }
+}
+
+async fn run_watch_command(pool: &sqlx::PgPool, command: WatchCommands) -> anyhow::Result<()> {
+    match command {
+        WatchCommands::Add { state, bill, note } => {
+            regs::run_watch_add(pool, &state, &bill, note.as_deref()).await
+        }
+        WatchCommands::Remove { state, bill } => regs::run_watch_remove(pool, &state, &bill).await,
+        WatchCommands::List { state } => regs::run_watch_list(pool, state.as_deref()).await,
+    }
+}
+
+async fn run_accounts_command(command: AccountsCommands) -> anyhow::Result<()> {
+    let pool = connect_or_exit().await;
+    match command {
+        AccountsCommands::Import {
+            file,
+            deactivate_missing,
+            dry_run,
+        } => accounts::run_accounts_import(&pool, &file, deactivate_missing, dry_run).await,
+        AccountsCommands::Report { state, limit } => {
+            accounts::run_accounts_report(&pool, state.as_deref(), i64::from(limit)).await
+        }
+    }
+}
+
+async fn run_locations_command(command: LocationsCommands) -> anyhow::Result<()> {
+    let pool = connect_or_exit().await;
+    match command {
+        LocationsCommands::Classify { brand, dry_run } => {
+            locations::run_locations_classify(&pool, brand.as_deref(), dry_run).await
+        }
+        LocationsCommands::Details { brand, dry_run } => {
+            locations::run_locations_details(&pool, brand.as_deref(), dry_run).await
+        }
+        LocationsCommands::Rekey { brand, dry_run } => {
+            locations::run_locations_rekey(&pool, brand.as_deref(), dry_run).await
+        }
+        LocationsCommands::LoadGeo {
+            counties,
+            zip_counties,
+        } => {
+            locations::run_locations_load_geo(&pool, counties.as_deref(), zip_counties.as_deref())
+                .await
+        }
+        LocationsCommands::AssignGeo { brand } => {
+            locations::run_locations_assign_geo(&pool, brand.as_deref()).await
+        }
+        LocationsCommands::Quarantine { command } => match command {
+            QuarantineCommands::List { status, limit } => {
+                locations::run_quarantine_list(&pool, status.as_deref(), i64::from(limit)).await
+            }
+            QuarantineCommands::Approve { id, note } => {
+                locations::run_quarantine_approve(&pool, id, note.as_deref()).await
+            }
+            QuarantineCommands::Reject { id, note } => {
+                locations::run_quarantine_reject(&pool, id, note.as_deref()).await
+            }
+        },
+    }
+}
+
 async fn run_db_health_check() -> anyhow::Result<()> {
     let pool = connect_or_exit().await;
     scbdb_db::health_check(&pool).await?;
@@ -0,0 +1,77 @@
+//! Product compliance check against the state rules registry
+//! (`regs compliance`).
+
+use chrono::{NaiveDate, Utc};
+use scbdb_core::Severity;
+
+/// Print the violations of the state rules in effect on `as_of` (default
+/// today), most severe first.
+///
+/// # Errors
+///
+/// Returns an error if `severity` is unknown or a database query fails.
+pub(crate) async fn run_regs_compliance(
+    pool: &sqlx::PgPool,
+    brand: Option<&str>,
+    state: Option<&str>,
+    severity: Option<&str>,
+    as_of: Option<NaiveDate>,
+) -> anyhow::Result<()> {
+    let min_severity = match severity {
+        Some(value) => Severity::parse(value).ok_or_else(|| {
+            anyhow::anyhow!("unknown severity '{value}'; expected critical, warning or info")
+        })?,
+        None => Severity::Info,
+    };
+    let as_of = as_of.unwrap_or_else(|| Utc::now().date_naive());
+    let state = state.map(str::to_uppercase);
+
+    let report = scbdb_db::check_compliance(
+        pool,
+        &scbdb_db::ComplianceFilters {
+            brand_slug: brand,
+            jurisdiction: state.as_deref(),
+        },
+        as_of,
+    )
+    .await?;
+
+    println!(
+        "Checked {} variants against the rules of {} states in effect on {as_of} \
+         ({} variants have no THC dose)",
+        report.variants_checked, report.states_with_rules, report.variants_without_dosage
+    );
+    if report.states_with_rules == 0 {
+        println!("no state rules in effect; add them with `regs rules add`");
+        return Ok(());
+    }
+
+    let violations: Vec<_> = report
+        .violations
+        .iter()
+        .filter(|v| v.severity <= min_severity)
+        .collect();
+    if violations.is_empty() {
+        println!("no violations found");
+        return Ok(());
+    }
+    println!(
+        "{:<10}{:<6}{:<26}{:<20}MESSAGE",
+        "SEVERITY", "STATE", "RULE", "BRAND"
+    );
+    for violation in &violations {
+        println!(
+            "{:<10}{:<6}{:<26}{:<20}{}",
+            violation.severity,
+            violation.jurisdiction,
+            violation.rule_kind,
+            violation.brand_slug,
+            violation.message
+        );
+    }
+    for severity in Severity::ALL.into_iter().filter(|s| *s <= min_severity) {
+        let count = violations.iter().filter(|v| v.severity == severity).count();
+        println!("{severity}: {count}");
+    }
+    Ok(())
+}
@@ -0,0 +1,287 @@
+//! Bulk import of `LegiScan` weekly datasets for `regs import-dataset`.
+//!
+//! A dataset holds every bill, roll call and legislator of one session in a
+//! single ZIP, so one `getDataset` request stands in for a `getBill` per bill
+//! and a `getRollCall` per roll call. Imports are keyed on `dataset_hash`: a
+//! session whose dataset has not changed since its last import is skipped
+//! without being downloaded.
+
+use std::collections::HashSet;
+
+use scbdb_legiscan::{
+    normalize::parse_date, normalize_person, normalize_roll_call_votes, read_dataset,
+    types::DatasetInfo, DatasetContents, LegiscanClient, LegiscanError,
+};
+
+use super::discovery::CandidateFilter;
+
+/// Import the bulk datasets of the given states.
+///
+/// Calls `getDatasetList` once per state, then `getDataset` for each session
+/// whose `dataset_hash` differs from the last import (or every session with
+/// `force`). Bills passing the same keyword/relevance filter as `regs ingest`
+/// are stored with their events, text versions, hearings, sponsors and roll
+/// calls, together with their `change_hash` so later ingests skip them until
+/// they change. Votes of those roll calls and the session's legislators are
+/// stored as well; imported bills are scored against the taxonomy.
+///
+/// A session is recorded as imported only once all of its bills are stored,
+/// so an interrupted import is retried on the next run.
+///
+/// When `dry_run` is `true` the datasets are listed (one request per state)
+/// and nothing is downloaded or written.
+///
+/// # Errors
+///
+/// Returns an error if the API key is missing, the taxonomy or client cannot
+/// be loaded, a database query fails, or the API quota is exhausted.
+/// Datasets that fail to download or unpack are logged and skipped.
+#[allow(clippy::too_many_lines, clippy::too_many_arguments)] // Orchestration: listing, hash-check, download, and collection-run lifecycle
+pub(crate) async fn run_regs_import_dataset(
+    pool: &sqlx::PgPool,
+    config: &scbdb_core::AppConfig,
+    states: &[String],
+    keywords: &[String],
+    min_relevance: f64,
+    max_requests: u32,
+    force: bool,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let api_key = config.legiscan_api_key.as_deref().ok_or_else(|| {
+        anyhow::anyhow!("LEGISCAN_API_KEY is not set; cannot run regs import-dataset")
+    })?;
+
+    let default_kw = vec!["hemp".to_string()];
+    let keywords = if keywords.is_empty() {
+        &default_kw
+    } else {
+        keywords
+    };
+
+    let taxonomy = super::topics::load_taxonomy(config)?;
+    let filter = CandidateFilter {
+        keywords,
+        taxonomy: &taxonomy,
+        min_relevance,
+    };
+
+    let client = super::ledger::client(pool, config, api_key, max_requests)?;
+
+    if dry_run {
+        for state in states {
+            let datasets = list_datasets(&client, state).await?.unwrap_or_default();
+            let ids: Vec<i64> = datasets.iter().map(|d| d.session_id).collect();
+            let stored = scbdb_db::get_dataset_hashes(pool, &ids).await?;
+            for dataset in &datasets {
+                let unchanged = stored.get(&dataset.session_id) == Some(&dataset.dataset_hash);
+                println!(
+                    "dry-run: {} {} (session {}, {} bytes){}",
+                    state.to_uppercase(),
+                    dataset.session_name,
+                    dataset.session_id,
+                    dataset.dataset_size,
+                    if unchanged && !force {
+                        " — unchanged, would skip"
+                    } else {
+                        ""
+                    }
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    let run = scbdb_db::create_collection_run(pool, "regs", "cli").await?;
+    if let Err(e) = scbdb_db::start_collection_run(pool, run.id).await {
+        crate::fail_run_best_effort(pool, run.id, "regs", format!("{e:#}")).await;
+        return Err(e.into());
+    }
+
+    let result: anyhow::Result<(i32, usize)> = async {
+        let mut total_bills: i32 = 0;
+        let mut sessions_imported = 0usize;
+        let mut imported_ids: Vec<i64> = Vec::new();
+
+        'states: for state in states {
+            let jurisdiction = state.to_uppercase();
+            let Some(datasets) = list_datasets(&client, state).await? else {
+                break;
+            };
+            let ids: Vec<i64> = datasets.iter().map(|d| d.session_id).collect();
+            let stored = scbdb_db::get_dataset_hashes(pool, &ids).await?;
+
+            for dataset in &datasets {
+                if !force && stored.get(&dataset.session_id) == Some(&dataset.dataset_hash) {
+                    tracing::info!(
+                        session_id = dataset.session_id,
+                        "dataset unchanged — skipping"
+                    );
+                    continue;
+                }
+
+                let archive = match client
+                    .get_dataset(dataset.session_id, &dataset.access_key)
+                    .await
+                {
+                    Ok(archive) => archive,
+                    Err(LegiscanError::BudgetExceeded { used, limit }) => {
+                        tracing::warn!(
+                            used,
+                            limit,
+                            "request budget reached — stopping dataset import early"
+                        );
+                        break 'states;
+                    }
+                    Err(LegiscanError::QuotaExceeded(ref msg)) => {
+                        return Err(anyhow::anyhow!(
+                            "LegiScan quota exhausted during getDataset(id={}): {msg}",
+                            dataset.session_id
+                        ));
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            session_id = dataset.session_id,
+                            error = %e,
+                            "skipping dataset — failed to download"
+                        );
+                        continue;
+                    }
+                };
+                let contents = match read_dataset(&archive) {
+                    Ok(contents) => contents,
+                    Err(e) => {
+                        tracing::warn!(
+                            session_id = dataset.session_id,
+                            error = %e,
+                            "skipping dataset — failed to unpack"
+                        );
+                        continue;
+                    }
+                };
+
+                let bill_ids = store_contents(pool, &jurisdiction, &contents, &filter).await?;
+                let bills_imported = i32::try_from(bill_ids.len()).unwrap_or(i32::MAX);
+                scbdb_db::record_dataset_import(
+                    pool,
+                    &scbdb_db::NewDatasetImport {
+                        session_id: dataset.session_id,
+                        jurisdiction: &jurisdiction,
+                        session_name: &dataset.session_name,
+                        dataset_hash: &archive.dataset_hash,
+                        dataset_date: archive.dataset_date.as_deref().and_then(parse_date),
+                        bills_imported,
+                    },
+                )
+                .await?;
+                tracing::info!(
+                    session_id = dataset.session_id,
+                    bills = bills_imported,
+                    "dataset imported"
+                );
+
+                imported_ids.extend(bill_ids);
+                total_bills = total_bills.saturating_add(bills_imported);
+                sessions_imported += 1;
+            }
+        }
+
+        if !imported_ids.is_empty() {
+            super::topics::score_bills(pool, &taxonomy, None, Some(&imported_ids)).await?;
+        }
+        Ok((total_bills, sessions_imported))
+    }
+    .await;
+
+    match result {
+        Ok((total_bills, sessions)) => {
+            if let Err(err) = scbdb_db::complete_collection_run(pool, run.id, total_bills).await {
+                let message = format!("{err:#}");
+                crate::fail_run_best_effort(pool, run.id, "regs", message).await;
+                return Err(err.into());
+            }
+            println!(
+                "imported {total_bills} bills from {sessions} datasets \
+                 ({} API requests used of {max_requests} allowed)",
+                client.requests_used()
+            );
+            Ok(())
+        }
+        Err(err) => {
+            crate::fail_run_best_effort(pool, run.id, "regs", format!("{err:#}")).await;
+            Err(err)
+        }
+    }
+}
+
+/// `getDatasetList` for one state. Returns `None` once the request budget is
+/// reached; other failures are logged and yield an empty list.
+async fn list_datasets(
+    client: &LegiscanClient,
+    state: &str,
+) -> anyhow::Result<Option<Vec<DatasetInfo>>> {
+    match client.get_dataset_list(Some(state)).await {
+        Ok(datasets) => Ok(Some(datasets)),
+        Err(LegiscanError::BudgetExceeded { used, limit }) => {
+            tracing::warn!(
+                used,
+                limit,
+                state,
+                "budget reached — stopping dataset listing"
+            );
+            Ok(None)
+        }
+        Err(LegiscanError::QuotaExceeded(ref msg)) => Err(anyhow::anyhow!(
+            "LegiScan quota exhausted during getDatasetList(state={state}): {msg}"
+        )),
+        Err(e) => {
+            tracing::warn!(state, error = %e, "getDatasetList failed — skipping state");
+            Ok(Some(Vec::new()))
+        }
+    }
+}
+
+/// Store the matching bills of an unpacked dataset, the session's
+/// legislators and the votes of the stored bills' roll calls. Returns the
+/// internal ids of the stored bills.
+async fn store_contents(
+    pool: &sqlx::PgPool,
+    jurisdiction: &str,
+    contents: &DatasetContents,
+    filter: &CandidateFilter<'_>,
+) -> anyhow::Result<Vec<i64>> {
+    for person in &contents.people {
+        super::votes::upsert_legislator(pool, jurisdiction, &normalize_person(person)).await?;
+    }
+
+    let mut bill_ids = Vec::new();
+    let mut legiscan_bill_ids = HashSet::new();
+    for detail in &contents.bills {
+        if !filter.matches(&detail.title, detail.description.as_deref()) {
+            continue;
+        }
+        let (bill_id, _events) =
+            super::store::store_bill(pool, detail, detail.change_hash.as_deref()).await?;
+        bill_ids.push(bill_id);
+        legiscan_bill_ids.insert(detail.bill_id);
+    }
+
+    let roll_calls: Vec<_> = contents
+        .roll_calls
+        .iter()
+        .filter(|rc| legiscan_bill_ids.contains(&rc.bill_id))
+        .collect();
+    let legiscan_ids: Vec<i64> = roll_calls.iter().map(|rc| rc.roll_call_id).collect();
+    let stored = scbdb_db::get_roll_call_ids(pool, &legiscan_ids).await?;
+    for roll_call in roll_calls {
+        let Some(&roll_call_id) = stored.get(&roll_call.roll_call_id) else {
+            continue;
+        };
+        let votes: Vec<(i64, &str)> = normalize_roll_call_votes(roll_call)
+            .iter()
+            .map(|v| (v.legiscan_people_id, v.vote))
+            .collect();
+        scbdb_db::record_roll_call_votes(pool, roll_call_id, &votes).await?;
+    }
+
+    Ok(bill_ids)
+}
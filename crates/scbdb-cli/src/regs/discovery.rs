@@ -6,6 +6,7 @@
 
 use std::collections::HashMap;
 
+use scbdb_core::{BillDocument, BillTopicsFile};
 use scbdb_legiscan::{types::MasterListEntry, LegiscanClient, LegiscanError};
 
 /// Candidates map: `legiscan_bill_id → (change_hash, MasterListEntry)`.
@@ -19,8 +20,8 @@ pub(super) type CandidateMap = HashMap<i64, (String, MasterListEntry)>;
 /// - **Backfill mode** (`all_sessions = true`): calls `getSessionList(state)` first (1 request),
 ///   then `getMasterList(session_id)` for every session on record. Covers all historical sessions.
 ///
-/// In both modes, entries are filtered locally by keyword match on bill title. No extra API
-/// requests are consumed by the keyword filter.
+/// In both modes, entries are filtered locally (see [`is_candidate`]). No extra API requests
+/// are consumed by the filter.
 ///
 /// Returns `(candidates, budget_hit)`.  `budget_hit` is `true` when the session request budget
 /// was reached before all states/sessions were processed.
@@ -32,7 +33,7 @@ pub(super) type CandidateMap = HashMap<i64, (String, MasterListEntry)>;
 pub(super) async fn discover_candidates(
     client: &LegiscanClient,
     states: &[String],
-    keywords: &[String],
+    filter: &CandidateFilter<'_>,
     all_sessions: bool,
 ) -> anyhow::Result<(CandidateMap, bool)> {
     let mut candidates: CandidateMap = HashMap::new();
@@ -93,7 +94,7 @@ pub(super) async fn discover_candidates(
                         continue;
                     }
                 };
-                collect_matching(entries, keywords, &mut candidates);
+                collect_matching(entries, filter, &mut candidates);
             }
         } else {
             tracing::info!(state, "getMasterList");
@@ -114,31 +115,109 @@ pub(super) async fn discover_candidates(
                     continue;
                 }
             };
-            collect_matching(entries, keywords, &mut candidates);
+            collect_matching(entries, filter, &mut candidates);
         }
     }
 
     Ok((candidates, budget_hit))
 }
 
-/// Inserts entries whose titles match any keyword into `candidates`.
+/// How master-list entries are filtered before any `getBill` spend.
+pub(super) struct CandidateFilter<'a> {
+    pub keywords: &'a [String],
+    pub taxonomy: &'a BillTopicsFile,
+    /// Entries scoring at least this relevance are kept without a keyword match.
+    pub min_relevance: f64,
+}
+
+impl CandidateFilter<'_> {
+    /// Whether a bill with this title and description is worth storing.
+    ///
+    /// The text is scored against the topic taxonomy. The bill is kept when
+    /// the score reaches `min_relevance`, or when a keyword matches its title
+    /// and the taxonomy does not mark it off-topic (agricultural or
+    /// industrial hemp).
+    pub(super) fn matches(&self, title: &str, description: Option<&str>) -> bool {
+        let relevance = self.taxonomy.score(&BillDocument {
+            title,
+            description,
+            text: None,
+        });
+        if relevance.score >= self.min_relevance {
+            return true;
+        }
+        let title_lower = title.to_lowercase();
+        !relevance.is_off_topic()
+            && self
+                .keywords
+                .iter()
+                .any(|kw| title_lower.contains(kw.to_lowercase().as_str()))
+    }
+}
+
+/// Whether a master-list entry is worth fetching, judged on its title and
+/// last action.
+fn is_candidate(entry: &MasterListEntry, filter: &CandidateFilter<'_>) -> bool {
+    filter.matches(&entry.title, entry.last_action.as_deref())
+}
+
+/// Inserts entries that pass [`is_candidate`] into `candidates`.
 ///
 /// Uses `entry()` so a bill seen in multiple sessions (backfill) keeps the
 /// first-seen hash without overwriting.
 fn collect_matching(
     entries: Vec<MasterListEntry>,
-    keywords: &[String],
+    filter: &CandidateFilter<'_>,
     candidates: &mut CandidateMap,
 ) {
     for entry in entries {
-        let title_lower = entry.title.to_lowercase();
-        if keywords
-            .iter()
-            .any(|kw| title_lower.contains(kw.to_lowercase().as_str()))
-        {
+        if is_candidate(&entry, filter) {
             candidates
                 .entry(entry.bill_id)
                 .or_insert_with(|| (entry.change_hash.clone(), entry));
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(title: &str) -> MasterListEntry {
+        MasterListEntry {
+            bill_id: 1,
+            number: "H1".to_string(),
+            title: title.to_string(),
+            status: 1,
+            status_date: None,
+            last_action_date: None,
+            last_action: None,
+            url: None,
+            change_hash: "h".to_string(),
+        }
+    }
+
+    #[test]
+    fn relevance_keeps_unkeyworded_bills_and_drops_agricultural_hemp() {
+        let path =
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../config/bill_topics.yaml");
+        let taxonomy = scbdb_core::load_bill_topics(&path).expect("taxonomy");
+        let keywords = vec!["hemp".to_string()];
+        let filter = CandidateFilter {
+            keywords: &keywords,
+            taxonomy: &taxonomy,
+            min_relevance: 25.0,
+        };
+
+        assert!(is_candidate(
+            &entry("Alcoholic beverages; THC beverages excise tax"),
+            &filter
+        ));
+        assert!(is_candidate(&entry("Hemp-derived beverages"), &filter));
+        assert!(!is_candidate(
+            &entry("Industrial hemp cultivation and hemp grower licensing"),
+            &filter
+        ));
+        assert!(!is_candidate(&entry("Motor vehicle registration"), &filter));
+    }
+}
@@ -0,0 +1,76 @@
+//! Brand regulatory exposure snapshots (`regs exposure`).
+
+use chrono::{NaiveDate, Utc};
+
+use super::topics::load_taxonomy;
+
+/// States listed per brand in the printed ranking.
+const TOP_STATES: usize = 3;
+
+/// Compute each brand's regulatory exposure on `as_of` (default today),
+/// print the ranking, and record it as that day's snapshot unless `dry_run`.
+///
+/// Scores use the current store footprint and bill statuses, so only today's
+/// snapshot is recorded; another `as_of` is a preview and needs `dry_run`.
+///
+/// # Errors
+///
+/// Returns an error if `as_of` is not today without `dry_run`, the bill topic
+/// taxonomy cannot be loaded, or a database query fails.
+pub(crate) async fn run_regs_exposure(
+    pool: &sqlx::PgPool,
+    config: &scbdb_core::AppConfig,
+    brand: Option<&str>,
+    as_of: Option<NaiveDate>,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let today = Utc::now().date_naive();
+    let as_of = as_of.unwrap_or(today);
+    if as_of != today && !dry_run {
+        anyhow::bail!(
+            "--as-of {as_of} would record today's footprint and bills as that day's snapshot; \
+             add --dry-run to preview it"
+        );
+    }
+    let taxonomy = load_taxonomy(config)?;
+    let rows = scbdb_db::compute_regulatory_exposure(pool, &taxonomy, as_of, brand).await?;
+    if rows.is_empty() {
+        println!("no active store locations; run `collect locations` first");
+        return Ok(());
+    }
+
+    // Rows come grouped by brand, largest contribution first.
+    let mut brands: Vec<(&str, f64, i32, Vec<String>)> = Vec::new();
+    for row in &rows {
+        if brands.last().is_none_or(|b| b.0 != row.brand_slug) {
+            brands.push((&row.brand_slug, 0.0, 0, Vec::new()));
+        }
+        if let Some(entry) = brands.last_mut() {
+            entry.1 += row.contribution;
+            entry.2 += row.store_count;
+            if entry.3.len() < TOP_STATES && row.contribution > 0.0 {
+                entry
+                    .3
+                    .push(format!("{} {:.1}", row.state, row.state_exposure));
+            }
+        }
+    }
+    brands.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    println!("Regulatory exposure on {as_of}");
+    println!("{:<24}{:>7}{:>8}  TOP STATES", "BRAND", "SCORE", "STORES");
+    for (slug, score, stores, states) in &brands {
+        println!("{slug:<24}{score:>7.1}{stores:>8}  {}", states.join(", "));
+    }
+
+    if dry_run {
+        println!("dry run: snapshot not recorded");
+    } else {
+        let stored = scbdb_db::record_regulatory_exposure(pool, as_of, &rows).await?;
+        println!(
+            "recorded {stored} state rows for {} brands on {as_of}",
+            brands.len()
+        );
+    }
+    Ok(())
+}
@@ -1,11 +1,9 @@
-use scbdb_legiscan::{
-    normalize_bill, normalize_bill_events, normalize_bill_texts, LegiscanClient, LegiscanError,
-};
+use scbdb_legiscan::LegiscanError;
 
 /// Ingest bills from the `LegiScan` API for one or more states and keywords.
 ///
 /// Uses `getMasterList` (1 request per state) to discover bills, then filters
-/// locally by keyword match on title. Cross-references incoming `change_hash`
+/// locally by topic-taxonomy relevance and keyword match on title. Cross-references incoming `change_hash`
 /// values against stored hashes in a single batch DB query, and only calls
 /// `getBill` for new or changed bills. This reduces steady-state API spend
 /// from `N_pages + N_bills` to `N_states + N_changed_bills`.
@@ -16,6 +14,17 @@ use scbdb_legiscan::{
 /// [`LegiscanError::QuotaExceeded`] (API-level quota exhausted) aborts the
 /// run immediately and is treated as an error.
 ///
+/// Every fetched bill is scored against the taxonomy afterwards (title,
+/// description and any extracted text), storing its relevance and topics.
+/// Hearings (`calendar`), sponsors and roll call summaries are stored with
+/// each bill; individual votes (`getRollCall`) and unknown voters
+/// (`getPerson`) are fetched last from whatever budget remains (see
+/// `votes.rs`).
+///
+/// Once the run completes, status changes and new events on watched and
+/// highly relevant bills are sent to the configured notifiers (see
+/// `watch.rs`).
+///
 /// When `dry_run` is `true` the function prints what would be ingested and
 /// returns without touching the database.
 ///
@@ -32,6 +41,7 @@ pub(crate) async fn run_regs_ingest(
     keywords: &[String],
     _max_pages: u32,
     max_requests: u32,
+    min_relevance: f64,
     all_sessions: bool,
     dry_run: bool,
 ) -> anyhow::Result<()> {
@@ -48,17 +58,18 @@ pub(crate) async fn run_regs_ingest(
         keywords
     };
 
+    let taxonomy = super::topics::load_taxonomy(config)?;
+
     if dry_run {
         println!(
-            "dry-run: would ingest bills for states [{}] keywords [{}] all_sessions={all_sessions} (max_requests={max_requests})",
+            "dry-run: would ingest bills for states [{}] keywords [{}] min_relevance={min_relevance} all_sessions={all_sessions} (max_requests={max_requests})",
             states.join(", "),
             keywords.join(", "),
         );
         return Ok(());
     }
 
-    let client = LegiscanClient::new(api_key, config.legiscan_request_timeout_secs, max_requests)
-        .map_err(|e| anyhow::anyhow!("failed to build LegiScan client: {e}"))?;
+    let client = super::ledger::client(pool, config, api_key, max_requests)?;
 
     let run = scbdb_db::create_collection_run(pool, "regs", "cli").await?;
     if let Err(e) = scbdb_db::start_collection_run(pool, run.id).await {
@@ -69,8 +80,13 @@ pub(crate) async fn run_regs_ingest(
     let result: anyhow::Result<(i32, i32)> = async {
         // ── Phase 1: Discover bills via getMasterList ─────────────────────────
         // See discovery.rs for the two strategies (current-session / all-sessions).
+        let filter = super::discovery::CandidateFilter {
+            keywords,
+            taxonomy: &taxonomy,
+            min_relevance,
+        };
         let (candidates, budget_hit) =
-            super::discovery::discover_candidates(&client, states, keywords, all_sessions).await?;
+            super::discovery::discover_candidates(&client, states, &filter, all_sessions).await?;
 
         tracing::info!(
             candidates = candidates.len(),
@@ -103,6 +119,7 @@ pub(crate) async fn run_regs_ingest(
         // ── Phase 3: Fetch + upsert changed/new bills ─────────────────────────
         let mut total_bills: i32 = 0;
         let mut total_events: i32 = 0;
+        let mut fetched_ids: Vec<i64> = Vec::new();
 
         for bill_id in &to_fetch {
             let (incoming_hash, _entry) = candidates
@@ -135,58 +152,29 @@ pub(crate) async fn run_regs_ingest(
                 }
             };
 
-            let normalized = normalize_bill(&detail);
-            let events = normalize_bill_events(&detail);
-            let texts = normalize_bill_texts(&detail);
-
-            let db_bill_id = scbdb_db::upsert_bill(
-                pool,
-                &normalized.jurisdiction,
-                &normalized.bill_number,
-                &normalized.title,
-                normalized.summary.as_deref(),
-                &normalized.status,
-                normalized.status_date,
-                normalized.introduced_date,
-                normalized.last_action_date,
-                normalized.session.as_deref(),
-                normalized.source_url.as_deref(),
-                Some(*bill_id),
-                Some(incoming_hash.as_str()),
-            )
-            .await?;
-
-            for event in &events {
-                scbdb_db::upsert_bill_event(
-                    pool,
-                    db_bill_id,
-                    event.event_date,
-                    event.event_type.as_deref(),
-                    event.chamber.as_deref(),
-                    &event.description,
-                    event.source_url.as_deref(),
-                )
-                .await?;
-            }
-
-            for text in &texts {
-                scbdb_db::upsert_bill_text(
-                    pool,
-                    db_bill_id,
-                    text.legiscan_text_id,
-                    text.text_date,
-                    &text.text_type,
-                    &text.mime,
-                    text.legiscan_url.as_deref(),
-                )
-                .await?;
-            }
+            let (db_bill_id, event_count) =
+                super::store::store_bill(pool, &detail, Some(incoming_hash.as_str())).await?;
 
+            fetched_ids.push(db_bill_id);
             total_bills = total_bills.saturating_add(1);
             total_events =
-                total_events.saturating_add(i32::try_from(events.len()).unwrap_or(i32::MAX));
+                total_events.saturating_add(i32::try_from(event_count).unwrap_or(i32::MAX));
         }
 
+        // ── Phase 4: Score fetched bills against the topic taxonomy ──────────
+        if !fetched_ids.is_empty() {
+            super::topics::score_bills(pool, &taxonomy, None, Some(&fetched_ids)).await?;
+        }
+
+        // ── Phase 5: Roll call votes and voters, from the remaining budget ───
+        let (roll_calls, legislators) = super::votes::fetch_votes_and_voters(pool, &client).await?;
+        tracing::info!(
+            roll_calls,
+            legislators,
+            requests_used = client.requests_used(),
+            "vote phase complete"
+        );
+
         Ok((total_bills, total_events))
     }
     .await;
@@ -203,6 +191,10 @@ pub(crate) async fn run_regs_ingest(
                  ({} API requests used of {max_requests} allowed)",
                 client.requests_used()
             );
+            // The run's data is stored; a notification failure only delays alerts.
+            if let Err(err) = super::watch::notify_bill_changes(pool, config).await {
+                tracing::warn!(error = %format!("{err:#}"), "bill change notifications failed");
+            }
             Ok(())
         }
         Err(err) => {
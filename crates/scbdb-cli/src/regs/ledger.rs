@@ -0,0 +1,94 @@
+//! Monthly `LegiScan` request budget shared by every run using the same API
+//! key, kept in the `legiscan_request_ledger` table.
+
+use std::sync::Arc;
+
+use chrono::{Datelike, NaiveDate, Utc};
+use scbdb_legiscan::{LedgerFuture, LegiscanClient, LegiscanError, RequestLedger};
+use sha2::{Digest, Sha256};
+
+/// Counts requests in the database against `monthly_budget`.
+struct DbLedger {
+    pool: sqlx::PgPool,
+    key_fingerprint: String,
+    monthly_budget: u32,
+}
+
+impl RequestLedger for DbLedger {
+    fn reserve(&self) -> LedgerFuture<'_> {
+        Box::pin(async move {
+            let month = current_month();
+            let budget = i32::try_from(self.monthly_budget).unwrap_or(i32::MAX);
+            let reserved = scbdb_db::reserve_legiscan_request(
+                &self.pool,
+                &self.key_fingerprint,
+                month,
+                budget,
+            )
+            .await
+            .map_err(|e| LegiscanError::Ledger(e.to_string()))?;
+            if reserved.is_some() {
+                return Ok(());
+            }
+            let used = scbdb_db::get_legiscan_usage(&self.pool, &self.key_fingerprint, month)
+                .await
+                .map_err(|e| LegiscanError::Ledger(e.to_string()))?
+                .map_or(0, |row| u32::try_from(row.requests).unwrap_or(0));
+            Err(LegiscanError::BudgetExceeded {
+                used,
+                limit: self.monthly_budget,
+            })
+        })
+    }
+}
+
+/// Build a client limited to `max_requests` this run and to the configured
+/// monthly budget of `api_key` across all runs.
+pub(super) fn client(
+    pool: &sqlx::PgPool,
+    config: &scbdb_core::AppConfig,
+    api_key: &str,
+    max_requests: u32,
+) -> anyhow::Result<LegiscanClient> {
+    let client = LegiscanClient::new(api_key, config.legiscan_request_timeout_secs, max_requests)
+        .map_err(|e| anyhow::anyhow!("failed to build LegiScan client: {e}"))?;
+    Ok(client.with_ledger(Arc::new(DbLedger {
+        pool: pool.clone(),
+        key_fingerprint: key_fingerprint(api_key),
+        monthly_budget: config.legiscan_monthly_budget,
+    })))
+}
+
+/// Print this month's usage of the configured API key.
+pub(super) async fn print_quota(
+    pool: &sqlx::PgPool,
+    config: &scbdb_core::AppConfig,
+) -> anyhow::Result<()> {
+    let Some(api_key) = config.legiscan_api_key.as_deref() else {
+        return Ok(());
+    };
+    let month = current_month();
+    let used = scbdb_db::get_legiscan_usage(pool, &key_fingerprint(api_key), month)
+        .await?
+        .map_or(0, |row| u32::try_from(row.requests).unwrap_or(0));
+    let budget = config.legiscan_monthly_budget;
+    println!(
+        "LegiScan quota {}: {used} of {budget} requests used, {} remaining",
+        month.format("%Y-%m"),
+        budget.saturating_sub(used)
+    );
+    Ok(())
+}
+
+/// Identifies an API key in the ledger without storing it: the first 12 hex
+/// digits of its SHA-256 hash.
+fn key_fingerprint(api_key: &str) -> String {
+    let hash = format!("{:x}", Sha256::digest(api_key.as_bytes()));
+    hash[..12].to_string()
+}
+
+/// First day of the current UTC month.
+fn current_month() -> NaiveDate {
+    let today = Utc::now().date_naive();
+    today.with_day(1).unwrap_or(today)
+}
@@ -2,17 +2,42 @@
 //!
 //! These are called from `main` after the database pool and config are
 //! established. The `ingest` subcommand fetches bills from the `LegiScan` API
-//! and persists them; the remaining subcommands are read-only queries.
+//! and persists them, `texts` fetches and extracts the full text of their
+//! versions, `score` re-applies the bill topic taxonomy, `exposure` records
+//! brand regulatory exposure snapshots, `rules` curates the state rules
+//! registry and `watch` the bill watchlist; the remaining subcommands are
+//! read-only queries.
 
+mod compliance;
+mod dataset;
 mod discovery;
+mod exposure;
 mod ingest;
+mod ledger;
+mod notify;
 mod query;
+mod rules;
+mod store;
+mod texts;
+mod topics;
+mod votes;
+mod watch;
 
 use chrono::NaiveDate;
 use clap::Subcommand;
+use uuid::Uuid;
 
+pub(crate) use compliance::run_regs_compliance;
+pub(crate) use dataset::run_regs_import_dataset;
+pub(crate) use exposure::run_regs_exposure;
 pub(crate) use ingest::run_regs_ingest;
-pub(crate) use query::{run_regs_report, run_regs_status, run_regs_timeline};
+pub(crate) use query::{run_regs_report, run_regs_status, run_regs_timeline, run_regs_upcoming};
+pub(crate) use rules::{
+    run_rules_add, run_rules_compare, run_rules_list, run_rules_remove, RuleInput,
+};
+pub(crate) use texts::run_regs_texts;
+pub(crate) use topics::run_regs_score;
+pub(crate) use watch::{run_watch_add, run_watch_list, run_watch_remove};
 
 /// Sub-commands available under `regs`.
 #[derive(Debug, Subcommand)]
@@ -35,15 +60,21 @@ pub enum RegsCommands {
         max_pages: u32,
 
         /// Hard ceiling on `LegiScan` API requests for this run.
-        /// Protects the 30 000/month quota. Default of 5 000 supports daily
-        /// runs (~150/month remaining for ad-hoc use). Each search page and
-        /// each `getBill` call counts as one request.
+        /// Each search page, `getBill`, `getRollCall` and `getPerson` call
+        /// counts as one request. Runs also stop once the API key's monthly
+        /// budget (`SCBDB_LEGISCAN_MONTHLY_BUDGET`) is spent.
         #[arg(long, default_value = "5000")]
         max_requests: u32,
 
+        /// Keep bills whose title scores at least this relevance (0–100)
+        /// against `config/bill_topics.yaml`, even without a keyword match.
+        #[arg(long, default_value = "25")]
+        min_relevance: f64,
+
         /// Backfill all historical sessions, not just the current active session.
         /// Calls `getSessionList` + `getMasterList(session_id)` for every session.
         /// Each historical session costs 1 additional API request.
+        /// `import-dataset` backfills whole sessions far more cheaply.
         #[arg(long)]
         all_sessions: bool,
 
@@ -51,7 +82,65 @@ pub enum RegsCommands {
         #[arg(long)]
         dry_run: bool,
     },
-    /// Show current status of tracked bills
+    /// Import bills from `LegiScan`'s weekly bulk datasets, one request per session
+    ImportDataset {
+        /// States to import (repeat for multiple: --state SC --state US).
+        #[arg(long, default_value = "SC")]
+        state: Vec<String>,
+
+        /// Keywords to match (repeat for multiple). Defaults to "hemp".
+        #[arg(long)]
+        keyword: Vec<String>,
+
+        /// Keep bills whose title and description score at least this
+        /// relevance (0–100), even without a keyword match.
+        #[arg(long, default_value = "25")]
+        min_relevance: f64,
+
+        /// Hard ceiling on `LegiScan` API requests for this run.
+        /// Each state costs one `getDatasetList` and each changed session one
+        /// `getDataset` request.
+        #[arg(long, default_value = "50")]
+        max_requests: u32,
+
+        /// Re-import sessions whose dataset has not changed since the last import
+        #[arg(long)]
+        force: bool,
+
+        /// List the available datasets without downloading or writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Fetch and extract full text for bill versions that have none yet
+    Texts {
+        /// Filter by state (e.g., SC)
+        #[arg(long)]
+        state: Option<String>,
+
+        /// Maximum number of versions to process this run
+        #[arg(long, default_value = "100")]
+        limit: u32,
+
+        /// Hard ceiling on `LegiScan` API requests for this run.
+        /// Each version costs one `getBillText` request.
+        #[arg(long, default_value = "500")]
+        max_requests: u32,
+
+        /// Retry versions whose previous extraction failed
+        #[arg(long)]
+        retry_failed: bool,
+
+        /// List the versions that would be fetched without calling the API
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Re-score stored bills against the bill topic taxonomy
+    Score {
+        /// Filter by state (e.g., SC)
+        #[arg(long)]
+        state: Option<String>,
+    },
+    /// Show the monthly `LegiScan` quota and current status of tracked bills
     Status {
         /// Filter by state (e.g., SC)
         #[arg(long)]
@@ -69,12 +158,153 @@ pub enum RegsCommands {
         #[arg(long)]
         bill: String,
     },
+    /// Show committee hearings and calendar entries coming up soon
+    Upcoming {
+        /// Filter by state (e.g., SC)
+        #[arg(long)]
+        state: Option<String>,
+        /// How many days ahead to look, counting today
+        #[arg(long, default_value = "14", value_parser = clap::value_parser!(u32).range(1..))]
+        days: u32,
+    },
     /// Generate a markdown regulatory report
     Report {
         /// Filter by state (e.g., SC)
         #[arg(long)]
         state: Option<String>,
     },
+    /// Check products and store footprints against the state rules in effect
+    Compliance {
+        /// Filter by brand slug
+        #[arg(long)]
+        brand: Option<String>,
+        /// Filter by state (e.g., SC)
+        #[arg(long)]
+        state: Option<String>,
+        /// Only violations at least this severe: critical, warning or info
+        #[arg(long)]
+        severity: Option<String>,
+        /// Date to check on (YYYY-MM-DD); defaults to today
+        #[arg(long)]
+        as_of: Option<NaiveDate>,
+    },
+    /// Score each brand's exposure to pending bills across its store footprint
+    Exposure {
+        /// Filter by brand slug
+        #[arg(long)]
+        brand: Option<String>,
+        /// Date to score on (YYYY-MM-DD); defaults to today. Other dates
+        /// need --dry-run
+        #[arg(long)]
+        as_of: Option<NaiveDate>,
+        /// Print the scores without recording a snapshot
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Curate the state hemp-beverage rules registry
+    Rules {
+        #[command(subcommand)]
+        command: RulesCommands,
+    },
+    /// Manage the watchlist of bills notified on every status change or new event
+    Watch {
+        #[command(subcommand)]
+        command: WatchCommands,
+    },
+}
+
+/// Sub-commands available under `regs rules`.
+#[derive(Debug, Subcommand)]
+pub enum RulesCommands {
+    /// Add a rule version; the version it replaces ends on its effective date
+    Add {
+        /// State abbreviation (e.g., SC)
+        #[arg(long)]
+        state: String,
+        /// `max_thc_mg_per_serving`, `max_thc_mg_per_container`, `min_age`,
+        /// `retail_channels` or `tax_rate`
+        #[arg(long)]
+        kind: String,
+        /// Limit in mg, age in years or tax rate in percent
+        #[arg(long)]
+        value: Option<f64>,
+        /// Allowed retail channel for `retail_channels` rules (repeat for multiple)
+        #[arg(long)]
+        channel: Vec<String>,
+        /// First day the rule applies (YYYY-MM-DD)
+        #[arg(long)]
+        effective_from: NaiveDate,
+        /// First day the rule no longer applies; defaults to the next version's start
+        #[arg(long)]
+        effective_to: Option<NaiveDate>,
+        /// Number of the enacting bill in the same state (e.g., H3001)
+        #[arg(long)]
+        bill: Option<String>,
+        /// Excerpt of the statute or bill text the rule comes from
+        #[arg(long)]
+        source_text: Option<String>,
+        /// Link to the source
+        #[arg(long)]
+        source_url: Option<String>,
+        /// Curator notes
+        #[arg(long)]
+        notes: Option<String>,
+    },
+    /// List the rules in effect today
+    List {
+        /// Filter by state (e.g., SC)
+        #[arg(long)]
+        state: Option<String>,
+        /// Filter by rule kind
+        #[arg(long)]
+        kind: Option<String>,
+        /// Show every version instead of those in effect
+        #[arg(long)]
+        history: bool,
+    },
+    /// Compare the rules in effect across states
+    Compare {
+        /// Date to compare on (YYYY-MM-DD); defaults to today
+        #[arg(long)]
+        as_of: Option<NaiveDate>,
+    },
+    /// Remove a rule version entered in error
+    Remove {
+        /// Rule id from `regs rules list`
+        id: Uuid,
+    },
+}
+
+/// Sub-commands available under `regs watch`.
+#[derive(Debug, Subcommand)]
+pub enum WatchCommands {
+    /// Watch a bill, or replace the note of a watched one
+    Add {
+        /// State abbreviation (e.g., SC)
+        #[arg(long)]
+        state: String,
+        /// Bill number (e.g., HB1234)
+        #[arg(long)]
+        bill: String,
+        /// Why the bill is watched
+        #[arg(long)]
+        note: Option<String>,
+    },
+    /// Stop watching a bill
+    Remove {
+        /// State abbreviation (e.g., SC)
+        #[arg(long)]
+        state: String,
+        /// Bill number (e.g., HB1234)
+        #[arg(long)]
+        bill: String,
+    },
+    /// List watched bills
+    List {
+        /// Filter by state (e.g., SC)
+        #[arg(long)]
+        state: Option<String>,
+    },
 }
 
 /// Format an optional date for display, returning `"—"` when `None`.
@@ -0,0 +1,209 @@
+//! Bill change notifiers: a generic JSON webhook, a Slack-compatible
+//! incoming webhook and SMTP email, each enabled by its `SCBDB_NOTIFY_*`
+//! variables.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use anyhow::Context;
+use lettre::message::{header::ContentType, Mailbox};
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use scbdb_core::{alerts_subject, render_alerts_text, BillAlert};
+
+/// Timeout of webhook requests.
+const WEBHOOK_TIMEOUT_SECS: u64 = 15;
+
+/// Future returned by [`Notifier::notify`].
+pub(super) type NotifyFuture<'a> = Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send + 'a>>;
+
+/// A channel bill change notifications are delivered to.
+pub(super) trait Notifier: Send + Sync {
+    /// Channel name for logs.
+    fn name(&self) -> &'static str;
+
+    /// Delivers one notification covering every alert.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the channel rejects or cannot be reached.
+    fn notify<'a>(&'a self, alerts: &'a [BillAlert]) -> NotifyFuture<'a>;
+}
+
+/// POSTs `{"event": "bill_changes", "count": n, "bills": [...]}`.
+pub(super) struct WebhookNotifier {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl WebhookNotifier {
+    pub(super) fn new(client: reqwest::Client, url: &str) -> Self {
+        Self {
+            client,
+            url: url.to_string(),
+        }
+    }
+}
+
+impl Notifier for WebhookNotifier {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    fn notify<'a>(&'a self, alerts: &'a [BillAlert]) -> NotifyFuture<'a> {
+        Box::pin(async move {
+            let body = serde_json::json!({
+                "event": "bill_changes",
+                "count": alerts.len(),
+                "bills": alerts,
+            });
+            post_json(&self.client, &self.url, &body).await
+        })
+    }
+}
+
+/// POSTs `{"text": ...}`, the payload Slack incoming webhooks and their
+/// look-alikes (Mattermost, Discord's `/slack` endpoint) accept.
+pub(super) struct SlackNotifier {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl SlackNotifier {
+    pub(super) fn new(client: reqwest::Client, url: &str) -> Self {
+        Self {
+            client,
+            url: url.to_string(),
+        }
+    }
+}
+
+impl Notifier for SlackNotifier {
+    fn name(&self) -> &'static str {
+        "slack"
+    }
+
+    fn notify<'a>(&'a self, alerts: &'a [BillAlert]) -> NotifyFuture<'a> {
+        Box::pin(async move {
+            let text = format!(
+                "*{}*\n```\n{}\n```",
+                alerts_subject(alerts),
+                render_alerts_text(alerts)
+            );
+            post_json(
+                &self.client,
+                &self.url,
+                &serde_json::json!({ "text": text }),
+            )
+            .await
+        })
+    }
+}
+
+/// Sends a plain-text email through the server at an `smtp://` or
+/// `smtps://` URL.
+pub(super) struct SmtpNotifier {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    to: Vec<Mailbox>,
+}
+
+impl SmtpNotifier {
+    /// # Errors
+    ///
+    /// Returns an error if the URL or an address does not parse, or if `to`
+    /// is empty. No connection is made until the first notification.
+    pub(super) fn new(url: &str, from: &str, to: &[String]) -> anyhow::Result<Self> {
+        if to.is_empty() {
+            anyhow::bail!("SCBDB_NOTIFY_EMAIL_TO is required when SCBDB_NOTIFY_SMTP_URL is set");
+        }
+        let transport = AsyncSmtpTransport::<Tokio1Executor>::from_url(url)
+            .context("invalid SCBDB_NOTIFY_SMTP_URL")?
+            .build();
+        let from = from
+            .parse::<Mailbox>()
+            .with_context(|| format!("invalid SCBDB_NOTIFY_EMAIL_FROM '{from}'"))?;
+        let to = to
+            .iter()
+            .map(|addr| {
+                addr.parse::<Mailbox>()
+                    .with_context(|| format!("invalid SCBDB_NOTIFY_EMAIL_TO address '{addr}'"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(Self {
+            transport,
+            from,
+            to,
+        })
+    }
+}
+
+impl Notifier for SmtpNotifier {
+    fn name(&self) -> &'static str {
+        "email"
+    }
+
+    fn notify<'a>(&'a self, alerts: &'a [BillAlert]) -> NotifyFuture<'a> {
+        Box::pin(async move {
+            let mut builder = Message::builder()
+                .from(self.from.clone())
+                .subject(alerts_subject(alerts))
+                .header(ContentType::TEXT_PLAIN);
+            for to in &self.to {
+                builder = builder.to(to.clone());
+            }
+            let message = builder.body(render_alerts_text(alerts))?;
+            self.transport.send(message).await?;
+            Ok(())
+        })
+    }
+}
+
+async fn post_json(
+    client: &reqwest::Client,
+    url: &str,
+    body: &serde_json::Value,
+) -> anyhow::Result<()> {
+    client
+        .post(url)
+        .json(body)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+
+/// The notifiers enabled in `config`; empty when none is configured.
+///
+/// # Errors
+///
+/// Returns an error if the HTTP client cannot be built or the SMTP settings
+/// do not parse or name no recipients.
+pub(super) fn configured_notifiers(
+    config: &scbdb_core::AppConfig,
+) -> anyhow::Result<Vec<Box<dyn Notifier>>> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(WEBHOOK_TIMEOUT_SECS))
+        .user_agent(&config.scraper_user_agent)
+        .build()?;
+
+    let mut notifiers: Vec<Box<dyn Notifier>> = Vec::new();
+    if let Some(url) = &config.notify_webhook_url {
+        notifiers.push(Box::new(WebhookNotifier::new(client.clone(), url)));
+    }
+    if let Some(url) = &config.notify_slack_webhook_url {
+        notifiers.push(Box::new(SlackNotifier::new(client, url)));
+    }
+    if let (Some(url), Some(from)) = (&config.notify_smtp_url, &config.notify_email_from) {
+        notifiers.push(Box::new(SmtpNotifier::new(
+            url,
+            from,
+            &config.notify_email_to,
+        )?));
+    }
+    Ok(notifiers)
+}
+
+#[cfg(test)]
+#[path = "notify_test.rs"]
+mod tests;
@@ -0,0 +1,84 @@
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::*;
+
+fn alert() -> BillAlert {
+    BillAlert {
+        bill_id: uuid::Uuid::nil(),
+        jurisdiction: "SC".to_string(),
+        bill_number: "H3001".to_string(),
+        title: "Hemp beverage serving limits".to_string(),
+        previous_status: Some("introduced".to_string()),
+        status: "engrossed".to_string(),
+        relevance_score: Some(82.0),
+        watched: true,
+        source_url: None,
+        events: Vec::new(),
+    }
+}
+
+fn client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn webhook_posts_the_alerts_as_json() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .and(body_partial_json(serde_json::json!({
+            "event": "bill_changes",
+            "count": 1,
+            "bills": [{"bill_number": "H3001", "status": "engrossed", "watched": true}],
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let notifier = WebhookNotifier::new(client(), &format!("{}/hook", server.uri()));
+    notifier.notify(&[alert()]).await.expect("webhook failed");
+}
+
+#[tokio::test]
+async fn slack_posts_the_rendered_text() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(serde_json::json!({
+            "text": "*Bill update: SC H3001: introduced → engrossed*\n```\n\
+                     SC H3001: introduced → engrossed [watched]\n  \
+                     Hemp beverage serving limits\n```",
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let notifier = SlackNotifier::new(client(), &server.uri());
+    notifier.notify(&[alert()]).await.expect("slack failed");
+}
+
+#[tokio::test]
+async fn rejected_webhook_is_an_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let notifier = WebhookNotifier::new(client(), &server.uri());
+    assert!(notifier.notify(&[alert()]).await.is_err());
+}
+
+#[test]
+fn smtp_settings_are_validated_up_front() {
+    let to = vec!["ops@example.com".to_string()];
+    assert!(SmtpNotifier::new("smtp://localhost:2525", "scbdb@example.com", &to).is_ok());
+    assert!(SmtpNotifier::new("smtp://localhost:2525", "not an address", &to).is_err());
+    assert!(SmtpNotifier::new("ftp://localhost", "scbdb@example.com", &to).is_err());
+    assert!(SmtpNotifier::new("smtp://localhost:2525", "scbdb@example.com", &[]).is_err());
+}
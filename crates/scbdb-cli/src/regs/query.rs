@@ -1,6 +1,7 @@
-use chrono::Utc;
+use chrono::{Days, Utc};
 
-/// Show current status of tracked bills.
+/// Show this month's `LegiScan` quota and the current status of tracked
+/// bills.
 ///
 /// Prints a table of bills optionally filtered by state, up to `limit` rows.
 ///
@@ -9,9 +10,11 @@ use chrono::Utc;
 /// Returns an error if the database query fails.
 pub(crate) async fn run_regs_status(
     pool: &sqlx::PgPool,
+    config: &scbdb_core::AppConfig,
     state_filter: Option<&str>,
     limit: i64,
 ) -> anyhow::Result<()> {
+    super::ledger::print_quota(pool, config).await?;
     let bills = scbdb_db::list_bills(pool, state_filter, limit).await?;
 
     if bills.is_empty() {
@@ -84,6 +87,62 @@ pub(crate) async fn run_regs_timeline(
     Ok(())
 }
 
+/// Show hearings and calendar entries scheduled in the next `days` days,
+/// counting today.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub(crate) async fn run_regs_upcoming(
+    pool: &sqlx::PgPool,
+    state_filter: Option<&str>,
+    days: u32,
+) -> anyhow::Result<()> {
+    const UPCOMING_LIMIT: i64 = 200;
+    let today = Utc::now().date_naive();
+    let until = today
+        .checked_add_days(Days::new(u64::from(days.saturating_sub(1))))
+        .unwrap_or(today);
+    let filters = scbdb_db::HearingFilters {
+        jurisdiction: state_filter,
+        ..scbdb_db::HearingFilters::default()
+    };
+    let hearings =
+        scbdb_db::list_upcoming_hearings(pool, today, until, &filters, UPCOMING_LIMIT).await?;
+
+    if hearings.is_empty() {
+        println!("no hearings scheduled in the next {days} days");
+        return Ok(());
+    }
+
+    let header = format!(
+        "{:<12}{:<7}{:<14}{:<11}{:<20}{:<20}TITLE",
+        "DATE", "TIME", "JURISDICTION", "BILL", "EVENT", "COMMITTEE"
+    );
+    println!("{header}");
+    for hearing in &hearings {
+        let time = hearing
+            .hearing_time
+            .map_or_else(|| "\u{2014}".to_string(), |t| t.format("%H:%M").to_string());
+        let committee = hearing.committee.as_deref().unwrap_or("\u{2014}");
+        println!(
+            "{:<12}{:<7}{:<14}{:<11}{:<20}{:<20}{}",
+            hearing.hearing_date.format("%Y-%m-%d"),
+            time,
+            hearing.jurisdiction,
+            hearing.bill_number,
+            hearing.event_type,
+            committee,
+            hearing.title
+        );
+        if let Some(location) = &hearing.location {
+            println!("{:<19}at {location}", "");
+        }
+    }
+
+    Ok(())
+}
+
 /// Generate a markdown regulatory report to stdout.
 ///
 /// Lists all tracked bills (optionally filtered by state), including their
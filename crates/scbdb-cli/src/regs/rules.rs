@@ -0,0 +1,222 @@
+//! Curation of the state hemp-beverage rules registry (`regs rules`).
+
+use chrono::{NaiveDate, Utc};
+use scbdb_core::RuleKind;
+use uuid::Uuid;
+
+/// Fields of `regs rules add`.
+#[derive(Debug)]
+pub(crate) struct RuleInput<'a> {
+    pub state: &'a str,
+    pub kind: &'a str,
+    pub value: Option<f64>,
+    pub channels: &'a [String],
+    pub effective_from: NaiveDate,
+    pub effective_to: Option<NaiveDate>,
+    /// Bill number of the enacting bill in `state`.
+    pub bill: Option<&'a str>,
+    pub source_text: Option<&'a str>,
+    pub source_url: Option<&'a str>,
+    pub notes: Option<&'a str>,
+}
+
+/// Add a rule version, closing the version it replaces.
+///
+/// # Errors
+///
+/// Returns an error if the rule is invalid, the bill is not stored, a
+/// version already starts on the same date, an explicit `effective_to` runs
+/// into the next version, or a database query fails.
+pub(crate) async fn run_rules_add(
+    pool: &sqlx::PgPool,
+    input: &RuleInput<'_>,
+) -> anyhow::Result<()> {
+    let kind = parse_kind(input.kind)?;
+    let state =
+        scbdb_core::normalize_jurisdiction(input.state).map_err(|msg| anyhow::anyhow!(msg))?;
+    let channels: Vec<String> = input.channels.iter().map(|c| c.to_lowercase()).collect();
+    kind.validate(input.value, &channels)
+        .map_err(|msg| anyhow::anyhow!(msg))?;
+    if input
+        .effective_to
+        .is_some_and(|to| to <= input.effective_from)
+    {
+        anyhow::bail!("--effective-to must be after --effective-from");
+    }
+
+    let bill_id = match input.bill {
+        Some(number) => Some(
+            scbdb_db::get_bill_by_jurisdiction_number(pool, &state, number)
+                .await?
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "bill '{number}' in state '{state}' not found; run `regs ingest` first"
+                    )
+                })?
+                .id,
+        ),
+        None => None,
+    };
+
+    let row = scbdb_db::insert_state_rule(
+        pool,
+        &scbdb_db::NewStateRule {
+            jurisdiction: &state,
+            rule_kind: kind.as_str(),
+            value: input.value,
+            channels: &channels,
+            unit: kind.unit(),
+            effective_from: input.effective_from,
+            effective_to: input.effective_to,
+            bill_id,
+            source_text: input.source_text,
+            source_url: input.source_url,
+            notes: input.notes,
+        },
+    )
+    .await?;
+
+    println!(
+        "added {} {} = {} effective {} ({})",
+        row.jurisdiction,
+        row.rule_kind,
+        fmt_rule_value(&row),
+        fmt_range(&row),
+        row.public_id
+    );
+    Ok(())
+}
+
+/// List the rules in effect today, or every version with `history`.
+///
+/// # Errors
+///
+/// Returns an error if `kind` is unknown or the database query fails.
+pub(crate) async fn run_rules_list(
+    pool: &sqlx::PgPool,
+    state: Option<&str>,
+    kind: Option<&str>,
+    history: bool,
+) -> anyhow::Result<()> {
+    if let Some(kind) = kind {
+        parse_kind(kind)?;
+    }
+    let state = state.map(str::to_uppercase);
+    let rows = scbdb_db::list_state_rules(
+        pool,
+        &scbdb_db::StateRuleFilters {
+            jurisdiction: state.as_deref(),
+            rule_kind: kind,
+            as_of: (!history).then(|| Utc::now().date_naive()),
+        },
+    )
+    .await?;
+
+    if rows.is_empty() {
+        println!("no state rules found; add them with `regs rules add`");
+        return Ok(());
+    }
+    println!(
+        "{:<6}{:<26}{:<28}{:<25}{:<10}ID",
+        "STATE", "RULE", "VALUE", "EFFECTIVE", "BILL"
+    );
+    for row in &rows {
+        println!(
+            "{:<6}{:<26}{:<28}{:<25}{:<10}{}",
+            row.jurisdiction,
+            row.rule_kind,
+            fmt_rule_value(row),
+            fmt_range(row),
+            row.bill_number.as_deref().unwrap_or("\u{2014}"),
+            row.public_id
+        );
+    }
+    Ok(())
+}
+
+/// Print the rules in effect on `as_of` (default today) as a state × rule
+/// matrix.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub(crate) async fn run_rules_compare(
+    pool: &sqlx::PgPool,
+    as_of: Option<NaiveDate>,
+) -> anyhow::Result<()> {
+    let as_of = as_of.unwrap_or_else(|| Utc::now().date_naive());
+    let rows = scbdb_db::list_state_rules(
+        pool,
+        &scbdb_db::StateRuleFilters {
+            as_of: Some(as_of),
+            ..scbdb_db::StateRuleFilters::default()
+        },
+    )
+    .await?;
+
+    let mut states: Vec<&str> = rows.iter().map(|r| r.jurisdiction.as_str()).collect();
+    states.dedup();
+    if states.is_empty() {
+        println!("no state rules in effect on {as_of}");
+        return Ok(());
+    }
+
+    print!("{:<6}", "STATE");
+    for kind in RuleKind::ALL {
+        print!("{:<28}", kind.as_str());
+    }
+    println!();
+    for state in states {
+        print!("{state:<6}");
+        for kind in RuleKind::ALL {
+            let cell = rows
+                .iter()
+                .find(|r| r.jurisdiction == state && r.rule_kind == kind.as_str())
+                .map_or_else(|| "\u{2014}".to_string(), fmt_rule_value);
+            print!("{cell:<28}");
+        }
+        println!();
+    }
+    Ok(())
+}
+
+/// Remove a rule version entered in error.
+///
+/// # Errors
+///
+/// Returns an error if the rule does not exist or the database query fails.
+pub(crate) async fn run_rules_remove(pool: &sqlx::PgPool, id: Uuid) -> anyhow::Result<()> {
+    match scbdb_db::delete_state_rule(pool, id).await {
+        Ok(()) => {
+            println!("removed state rule {id}");
+            Ok(())
+        }
+        Err(scbdb_db::DbError::NotFound) => Err(anyhow::anyhow!("state rule {id} not found")),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn parse_kind(value: &str) -> anyhow::Result<RuleKind> {
+    RuleKind::parse(value).ok_or_else(|| {
+        let kinds: Vec<&str> = RuleKind::ALL.iter().map(|k| k.as_str()).collect();
+        anyhow::anyhow!(
+            "unknown rule kind '{value}'; expected one of {}",
+            kinds.join(", ")
+        )
+    })
+}
+
+fn fmt_rule_value(row: &scbdb_db::StateRuleRow) -> String {
+    match (row.value, row.unit.as_deref()) {
+        (Some(value), Some(unit)) => format!("{value} {unit}"),
+        (Some(value), None) => value.to_string(),
+        (None, _) => row.channels.join(","),
+    }
+}
+
+fn fmt_range(row: &scbdb_db::StateRuleRow) -> String {
+    match row.effective_to {
+        Some(to) => format!("{}..{to}", row.effective_from),
+        None => format!("{}..", row.effective_from),
+    }
+}
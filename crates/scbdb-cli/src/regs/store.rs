@@ -0,0 +1,83 @@
+//! Persistence of one `getBill`-shaped bill, shared by `regs ingest` and
+//! `regs import-dataset`.
+
+use scbdb_legiscan::{
+    normalize_bill, normalize_bill_events, normalize_bill_hearings, normalize_bill_texts,
+    types::BillDetail,
+};
+
+/// Upsert a bill with its events, text versions, hearings, sponsors and roll
+/// call summaries. Returns `(internal bill id, events stored)`.
+///
+/// `change_hash` is stored so later ingests skip the bill until it changes.
+pub(super) async fn store_bill(
+    pool: &sqlx::PgPool,
+    detail: &BillDetail,
+    change_hash: Option<&str>,
+) -> anyhow::Result<(i64, usize)> {
+    let normalized = normalize_bill(detail);
+    let events = normalize_bill_events(detail);
+    let texts = normalize_bill_texts(detail);
+
+    let db_bill_id = scbdb_db::upsert_bill(
+        pool,
+        &normalized.jurisdiction,
+        &normalized.bill_number,
+        &normalized.title,
+        normalized.summary.as_deref(),
+        &normalized.status,
+        normalized.status_date,
+        normalized.introduced_date,
+        normalized.last_action_date,
+        normalized.session.as_deref(),
+        normalized.source_url.as_deref(),
+        Some(detail.bill_id),
+        change_hash,
+    )
+    .await?;
+
+    for event in &events {
+        scbdb_db::upsert_bill_event(
+            pool,
+            db_bill_id,
+            event.event_date,
+            event.event_type.as_deref(),
+            event.chamber.as_deref(),
+            &event.description,
+            event.source_url.as_deref(),
+        )
+        .await?;
+    }
+
+    for text in &texts {
+        scbdb_db::upsert_bill_text(
+            pool,
+            db_bill_id,
+            text.legiscan_text_id,
+            text.text_date,
+            &text.text_type,
+            &text.mime,
+            text.legiscan_url.as_deref(),
+        )
+        .await?;
+    }
+
+    let hearings = normalize_bill_hearings(detail, chrono::Utc::now().date_naive());
+    let hearing_rows: Vec<scbdb_db::NewBillHearing<'_>> = hearings
+        .iter()
+        .map(|h| scbdb_db::NewBillHearing {
+            hearing_date: h.hearing_date,
+            hearing_time: h.hearing_time,
+            event_type: &h.event_type,
+            committee: h.committee.as_deref(),
+            chamber: h.chamber.as_deref(),
+            location: h.location.as_deref(),
+            description: h.description.as_deref(),
+        })
+        .collect();
+    scbdb_db::replace_bill_hearings(pool, db_bill_id, &hearing_rows).await?;
+
+    super::votes::store_bill_people(pool, db_bill_id, detail).await?;
+
+    Ok((db_bill_id, events.len()))
+}
@@ -0,0 +1,118 @@
+use scbdb_legiscan::{extract_document_text, LegiscanError};
+
+/// Fetch and extract plain text for bill versions that have none yet.
+///
+/// Each pending version costs one `getBillText` request. The extracted text,
+/// or the reason extraction failed (unsupported format, scanned PDF), is
+/// stored on the `bill_texts` row so it is not fetched again; pass
+/// `retry_failed` to revisit failed versions. Bills that gained text are
+/// re-scored against the topic taxonomy.
+///
+/// **Request budget:** stops early, without error, once `max_requests` is
+/// reached. [`LegiscanError::QuotaExceeded`] aborts the run.
+///
+/// # Errors
+///
+/// Returns an error if the API key is missing, the client cannot be built,
+/// a database query fails, or the API quota is exhausted. Individual fetch
+/// failures are logged and skipped.
+pub(crate) async fn run_regs_texts(
+    pool: &sqlx::PgPool,
+    config: &scbdb_core::AppConfig,
+    state: Option<&str>,
+    limit: i64,
+    max_requests: u32,
+    retry_failed: bool,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let pending =
+        scbdb_db::list_bill_texts_pending_extraction(pool, state, retry_failed, limit).await?;
+
+    if dry_run {
+        println!(
+            "dry-run: would fetch text for {} bill versions (max_requests={max_requests})",
+            pending.len()
+        );
+        for text in &pending {
+            println!(
+                "  {} {} — {} (text {})",
+                text.jurisdiction, text.bill_number, text.text_type, text.legiscan_text_id
+            );
+        }
+        return Ok(());
+    }
+    if pending.is_empty() {
+        println!("no bill text versions pending extraction");
+        return Ok(());
+    }
+
+    let api_key = config
+        .legiscan_api_key
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("LEGISCAN_API_KEY is not set; cannot run regs texts"))?;
+    let taxonomy = super::topics::load_taxonomy(config)?;
+    let client = super::ledger::client(pool, config, api_key, max_requests)?;
+
+    let mut extracted = 0usize;
+    let mut failed = 0usize;
+    let mut rescore: Vec<i64> = Vec::new();
+    for text in &pending {
+        let document = match client.get_bill_text(text.legiscan_text_id).await {
+            Ok(document) => document,
+            Err(LegiscanError::BudgetExceeded { used, limit }) => {
+                tracing::warn!(
+                    used,
+                    limit,
+                    "request budget reached — stopping text fetch early"
+                );
+                break;
+            }
+            Err(LegiscanError::QuotaExceeded(ref msg)) => {
+                return Err(anyhow::anyhow!(
+                    "LegiScan quota exhausted during getBillText(id={}): {msg}",
+                    text.legiscan_text_id
+                ));
+            }
+            Err(e) => {
+                tracing::warn!(
+                    doc_id = text.legiscan_text_id,
+                    error = %e,
+                    "skipping bill text — failed to fetch document"
+                );
+                continue;
+            }
+        };
+
+        match extract_document_text(&document) {
+            Ok(content) => {
+                scbdb_db::record_bill_text_extraction(pool, text.id, Some(&content), None).await?;
+                extracted += 1;
+                rescore.push(text.bill_id);
+            }
+            Err(e) => {
+                tracing::warn!(
+                    doc_id = text.legiscan_text_id,
+                    mime = %document.mime,
+                    error = %e,
+                    "bill text extraction failed"
+                );
+                let message = e.to_string();
+                scbdb_db::record_bill_text_extraction(pool, text.id, None, Some(&message)).await?;
+                failed += 1;
+            }
+        }
+    }
+
+    if !rescore.is_empty() {
+        rescore.sort_unstable();
+        rescore.dedup();
+        super::topics::score_bills(pool, &taxonomy, None, Some(&rescore)).await?;
+    }
+
+    println!(
+        "extracted {extracted} bill text versions, {failed} failed \
+         ({} API requests used of {max_requests} allowed)",
+        client.requests_used()
+    );
+    Ok(())
+}
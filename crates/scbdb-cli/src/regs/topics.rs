@@ -0,0 +1,64 @@
+use std::collections::BTreeMap;
+
+use scbdb_core::{BillDocument, BillTopicsFile};
+
+/// Load the bill topic taxonomy named by the config.
+pub(super) fn load_taxonomy(config: &scbdb_core::AppConfig) -> anyhow::Result<BillTopicsFile> {
+    scbdb_core::load_bill_topics(&config.bill_topics_path).map_err(|e| {
+        anyhow::anyhow!(
+            "failed to load bill topics from {}: {e}",
+            config.bill_topics_path.display()
+        )
+    })
+}
+
+/// Score bills against the taxonomy and store their relevance and topics.
+///
+/// Returns how many bills were tagged with each topic.
+pub(super) async fn score_bills(
+    pool: &sqlx::PgPool,
+    taxonomy: &BillTopicsFile,
+    jurisdiction: Option<&str>,
+    bill_ids: Option<&[i64]>,
+) -> anyhow::Result<(usize, BTreeMap<String, usize>)> {
+    let bills = scbdb_db::list_bills_for_scoring(pool, jurisdiction, bill_ids).await?;
+    let mut tagged: BTreeMap<String, usize> = BTreeMap::new();
+    for bill in &bills {
+        let relevance = taxonomy.score(&BillDocument {
+            title: &bill.title,
+            description: bill.summary.as_deref(),
+            text: bill.text.as_deref(),
+        });
+        let topics: Vec<(&str, f64)> = relevance
+            .topics
+            .iter()
+            .map(|t| (t.slug.as_str(), t.score))
+            .collect();
+        scbdb_db::replace_bill_topics(pool, bill.id, relevance.score, &topics).await?;
+        for topic in &relevance.topics {
+            *tagged.entry(topic.slug.clone()).or_default() += 1;
+        }
+    }
+    Ok((bills.len(), tagged))
+}
+
+/// Re-score every stored bill, e.g. after editing `config/bill_topics.yaml`.
+///
+/// # Errors
+///
+/// Returns an error if the taxonomy cannot be loaded or a database query fails.
+pub(crate) async fn run_regs_score(
+    pool: &sqlx::PgPool,
+    config: &scbdb_core::AppConfig,
+    state: Option<&str>,
+) -> anyhow::Result<()> {
+    let taxonomy = load_taxonomy(config)?;
+    let (scored, tagged) = score_bills(pool, &taxonomy, state, None).await?;
+
+    println!("scored {scored} bills");
+    for topic in &taxonomy.topics {
+        let count = tagged.get(&topic.slug).copied().unwrap_or(0);
+        println!("  {:<28}{count}", topic.slug);
+    }
+    Ok(())
+}
@@ -0,0 +1,184 @@
+//! Sponsor, roll call and legislator ingestion for `regs ingest`.
+//!
+//! Sponsors and roll call summaries arrive with `getBill` at no extra cost.
+//! Individual votes cost one `getRollCall` per roll call, and voters who
+//! never sponsored a stored bill cost one `getPerson` each; both are fetched
+//! after the bills, from whatever remains of the request budget, and resume
+//! on the next run where they stopped. A lookup that keeps failing is given
+//! up after [`MAX_FETCH_ATTEMPTS`] runs.
+
+use scbdb_legiscan::{
+    normalize_bill_roll_calls, normalize_bill_sponsors, normalize_person,
+    normalize_roll_call_votes, types::BillDetail, LegiscanClient, LegiscanError,
+    NormalizedLegislator,
+};
+
+/// Roll calls and unknown voters looked up per run, at most.
+const MAX_PENDING_LOOKUPS: i64 = 1_000;
+
+/// Failed lookups of a roll call or voter before it is no longer retried.
+const MAX_FETCH_ATTEMPTS: i32 = 3;
+
+/// Store the sponsors (and their legislator records) and roll call
+/// summaries listed in a `getBill` response.
+pub(super) async fn store_bill_people(
+    pool: &sqlx::PgPool,
+    bill_id: i64,
+    detail: &BillDetail,
+) -> anyhow::Result<()> {
+    let sponsors = normalize_bill_sponsors(detail);
+    for sponsor in &sponsors {
+        upsert_legislator(pool, &detail.state, &sponsor.legislator).await?;
+    }
+    let rows: Vec<scbdb_db::NewBillSponsor<'_>> = sponsors
+        .iter()
+        .map(|s| scbdb_db::NewBillSponsor {
+            legiscan_people_id: s.legislator.legiscan_people_id,
+            sponsor_type: s.sponsor_type,
+            sponsor_order: s.sponsor_order,
+        })
+        .collect();
+    scbdb_db::replace_bill_sponsors(pool, bill_id, &rows).await?;
+
+    for roll_call in normalize_bill_roll_calls(detail) {
+        scbdb_db::upsert_roll_call(
+            pool,
+            bill_id,
+            &scbdb_db::NewRollCall {
+                legiscan_roll_call_id: roll_call.legiscan_roll_call_id,
+                vote_date: roll_call.vote_date,
+                chamber: roll_call.chamber.as_deref(),
+                description: &roll_call.description,
+                yea: roll_call.yea,
+                nay: roll_call.nay,
+                nv: roll_call.nv,
+                absent: roll_call.absent,
+                total: roll_call.total,
+                passed: roll_call.passed,
+            },
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Fetch votes for roll calls that have none, then the legislator records of
+/// voters not seen before. Returns `(roll calls fetched, legislators added)`.
+///
+/// Stops early, without error, once the client's request budget is reached.
+///
+/// # Errors
+///
+/// Returns an error if a database query fails or the API quota is exhausted.
+pub(super) async fn fetch_votes_and_voters(
+    pool: &sqlx::PgPool,
+    client: &LegiscanClient,
+) -> anyhow::Result<(usize, usize)> {
+    let mut roll_calls = 0usize;
+    let pending = scbdb_db::list_roll_calls_pending_votes(
+        pool,
+        None,
+        MAX_FETCH_ATTEMPTS,
+        MAX_PENDING_LOOKUPS,
+    )
+    .await?;
+    for pending in &pending {
+        let roll_call = match client.get_roll_call(pending.legiscan_roll_call_id).await {
+            Ok(roll_call) => roll_call,
+            Err(LegiscanError::BudgetExceeded { used, limit }) => {
+                tracing::warn!(
+                    used,
+                    limit,
+                    "request budget reached — stopping roll call fetch early"
+                );
+                return Ok((roll_calls, 0));
+            }
+            Err(LegiscanError::QuotaExceeded(ref msg)) => {
+                return Err(anyhow::anyhow!(
+                    "LegiScan quota exhausted during getRollCall(id={}): {msg}",
+                    pending.legiscan_roll_call_id
+                ));
+            }
+            Err(e) => {
+                tracing::warn!(
+                    roll_call_id = pending.legiscan_roll_call_id,
+                    bill = %format!("{} {}", pending.jurisdiction, pending.bill_number),
+                    error = %e,
+                    "skipping roll call — failed to fetch votes"
+                );
+                scbdb_db::record_roll_call_fetch_failure(pool, pending.id, &e.to_string()).await?;
+                continue;
+            }
+        };
+
+        let votes: Vec<(i64, &str)> = normalize_roll_call_votes(&roll_call)
+            .iter()
+            .map(|v| (v.legiscan_people_id, v.vote))
+            .collect();
+        scbdb_db::record_roll_call_votes(pool, pending.id, &votes).await?;
+        roll_calls += 1;
+    }
+
+    let mut legislators = 0usize;
+    let unknown =
+        scbdb_db::list_unknown_voters(pool, None, MAX_FETCH_ATTEMPTS, MAX_PENDING_LOOKUPS).await?;
+    for voter in &unknown {
+        let person = match client.get_person(voter.legiscan_people_id).await {
+            Ok(person) => person,
+            Err(LegiscanError::BudgetExceeded { used, limit }) => {
+                tracing::warn!(
+                    used,
+                    limit,
+                    "request budget reached — stopping legislator fetch early"
+                );
+                break;
+            }
+            Err(LegiscanError::QuotaExceeded(ref msg)) => {
+                return Err(anyhow::anyhow!(
+                    "LegiScan quota exhausted during getPerson(id={}): {msg}",
+                    voter.legiscan_people_id
+                ));
+            }
+            Err(e) => {
+                tracing::warn!(
+                    people_id = voter.legiscan_people_id,
+                    error = %e,
+                    "skipping legislator — failed to fetch person"
+                );
+                scbdb_db::record_voter_fetch_failure(
+                    pool,
+                    voter.legiscan_people_id,
+                    &e.to_string(),
+                )
+                .await?;
+                continue;
+            }
+        };
+        upsert_legislator(pool, &voter.jurisdiction, &normalize_person(&person)).await?;
+        legislators += 1;
+    }
+
+    Ok((roll_calls, legislators))
+}
+
+pub(super) async fn upsert_legislator(
+    pool: &sqlx::PgPool,
+    jurisdiction: &str,
+    legislator: &NormalizedLegislator,
+) -> anyhow::Result<()> {
+    scbdb_db::upsert_legislator(
+        pool,
+        &scbdb_db::NewLegislator {
+            legiscan_people_id: legislator.legiscan_people_id,
+            jurisdiction,
+            name: &legislator.name,
+            first_name: legislator.first_name.as_deref(),
+            last_name: legislator.last_name.as_deref(),
+            party: legislator.party.as_deref(),
+            role: legislator.role.as_deref(),
+            district: legislator.district.as_deref(),
+        },
+    )
+    .await?;
+    Ok(())
+}
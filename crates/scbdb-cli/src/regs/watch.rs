@@ -0,0 +1,218 @@
+//! Bill watchlist (`regs watch`) and the change notifications sent after
+//! each `regs ingest`.
+
+use scbdb_core::{BillAlert, BillAlertEvent};
+
+use super::notify::{configured_notifiers, Notifier};
+
+/// Cursor channel for changes that are only printed, with no notifier
+/// configured.
+const CONSOLE_CHANNEL: &str = "console";
+
+/// Add a bill to the watchlist, or replace its note when already watched.
+///
+/// # Errors
+///
+/// Returns an error if the bill is unknown or a database query fails.
+pub(crate) async fn run_watch_add(
+    pool: &sqlx::PgPool,
+    state: &str,
+    bill_number: &str,
+    note: Option<&str>,
+) -> anyhow::Result<()> {
+    let bill = find_bill(pool, state, bill_number).await?;
+    let row = scbdb_db::watch_bill(pool, bill.id, note).await?;
+    println!(
+        "watching {} {} ({}): {}",
+        row.jurisdiction, row.bill_number, row.status, row.title
+    );
+    Ok(())
+}
+
+/// Remove a bill from the watchlist.
+///
+/// # Errors
+///
+/// Returns an error if the bill is unknown or not watched, or a database
+/// query fails.
+pub(crate) async fn run_watch_remove(
+    pool: &sqlx::PgPool,
+    state: &str,
+    bill_number: &str,
+) -> anyhow::Result<()> {
+    let bill = find_bill(pool, state, bill_number).await?;
+    match scbdb_db::unwatch_bill(pool, bill.id).await {
+        Ok(()) => {
+            println!(
+                "stopped watching {} {}",
+                bill.jurisdiction, bill.bill_number
+            );
+            Ok(())
+        }
+        Err(scbdb_db::DbError::NotFound) => Err(anyhow::anyhow!(
+            "{} {} is not watched",
+            bill.jurisdiction,
+            bill.bill_number
+        )),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// List watched bills with their current status.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub(crate) async fn run_watch_list(pool: &sqlx::PgPool, state: Option<&str>) -> anyhow::Result<()> {
+    let state = state.map(str::to_uppercase);
+    let rows = scbdb_db::list_watched_bills(pool, state.as_deref()).await?;
+    if rows.is_empty() {
+        println!("no watched bills; add one with `regs watch add`");
+        return Ok(());
+    }
+
+    println!(
+        "{:<6}{:<12}{:<13}{:<12}TITLE",
+        "STATE", "BILL", "STATUS", "LAST ACTION"
+    );
+    for row in &rows {
+        println!(
+            "{:<6}{:<12}{:<13}{:<12}{}",
+            row.jurisdiction,
+            row.bill_number,
+            row.status,
+            super::fmt_date(row.last_action_date),
+            row.title
+        );
+        if let Some(note) = &row.note {
+            println!("{:<43}note: {note}", "");
+        }
+    }
+    Ok(())
+}
+
+/// Report status changes and new events on watched bills and bills scoring
+/// at least `SCBDB_NOTIFY_MIN_RELEVANCE` to every configured notifier.
+///
+/// Each notifier keeps its own cursors: a change it delivered is not reported
+/// to it again, and a change it failed to deliver stays pending for it until
+/// the next run, whatever the other notifiers did. With no notifier
+/// configured, changes are only printed. A notifier's first run starts from
+/// the current state of every tracked bill without a notification; bills
+/// tracked after that are reported as new bills with their events.
+///
+/// # Errors
+///
+/// Returns an error if the notifiers cannot be built or a database query
+/// fails.
+pub(super) async fn notify_bill_changes(
+    pool: &sqlx::PgPool,
+    config: &scbdb_core::AppConfig,
+) -> anyhow::Result<()> {
+    let min_relevance = config.notify_min_relevance;
+    let notifiers = configured_notifiers(config)?;
+
+    if notifiers.is_empty() {
+        notify_channel(pool, CONSOLE_CHANNEL, None, min_relevance).await?;
+    }
+    for notifier in &notifiers {
+        notify_channel(
+            pool,
+            notifier.name(),
+            Some(notifier.as_ref()),
+            min_relevance,
+        )
+        .await?;
+    }
+    tracing::info!(
+        notifiers = notifiers.len(),
+        "bill change notifications complete"
+    );
+    Ok(())
+}
+
+/// Report the changes pending for one channel and advance its cursors once
+/// delivered; `notifier` is `None` for [`CONSOLE_CHANNEL`].
+async fn notify_channel(
+    pool: &sqlx::PgPool,
+    channel: &str,
+    notifier: Option<&dyn Notifier>,
+    min_relevance: f64,
+) -> anyhow::Result<()> {
+    if let Some(baselined) =
+        scbdb_db::start_bill_alert_channel(pool, channel, min_relevance).await?
+    {
+        tracing::info!(
+            channel,
+            baselined,
+            "bill change notifications start from the current bill states"
+        );
+        return Ok(());
+    }
+
+    let changes = scbdb_db::list_bill_changes(pool, channel, min_relevance).await?;
+    if changes.is_empty() {
+        return Ok(());
+    }
+    let alerts: Vec<BillAlert> = changes.iter().map(to_alert).collect();
+    for alert in &alerts {
+        println!("bill update ({channel}): {}", alert.headline());
+    }
+
+    if let Some(notifier) = notifier {
+        if let Err(e) = notifier.notify(&alerts).await {
+            tracing::warn!(
+                channel,
+                bills = alerts.len(),
+                error = %format!("{e:#}"),
+                "bill change notification failed; changes stay pending for the next run"
+            );
+            return Ok(());
+        }
+    }
+    scbdb_db::advance_bill_alert_cursors(pool, channel, &changes).await?;
+    tracing::info!(
+        channel,
+        bills = alerts.len(),
+        "bill change notification delivered"
+    );
+    Ok(())
+}
+
+async fn find_bill(
+    pool: &sqlx::PgPool,
+    state: &str,
+    bill_number: &str,
+) -> anyhow::Result<scbdb_db::BillRow> {
+    let state = state.to_uppercase();
+    scbdb_db::get_bill_by_jurisdiction_number(pool, &state, bill_number)
+        .await?
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "bill '{bill_number}' in state '{state}' not found; run `regs ingest` first"
+            )
+        })
+}
+
+fn to_alert(change: &scbdb_db::BillChange) -> BillAlert {
+    BillAlert {
+        bill_id: change.public_id,
+        jurisdiction: change.jurisdiction.clone(),
+        bill_number: change.bill_number.clone(),
+        title: change.title.clone(),
+        previous_status: change.previous_status.clone(),
+        status: change.status.clone(),
+        relevance_score: change.relevance_score,
+        watched: change.watched,
+        source_url: change.source_url.clone(),
+        events: change
+            .events
+            .iter()
+            .map(|e| BillAlertEvent {
+                event_date: e.event_date,
+                chamber: e.chamber.clone(),
+                description: e.description.clone(),
+            })
+            .collect(),
+    }
+}
@@ -164,6 +164,20 @@ fn collect_products_brand_and_dry_run_together() {
     ));
 }
 
+#[test]
+fn parses_collect_distribution() {
+    let cli = Cli::try_parse_from(["scbdb", "collect", "distribution", "--brand", "cann"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Collect {
+            command: CollectCommands::Distribution {
+                brand: Some(ref b),
+                dry_run: false,
+            }
+        }) if b == "cann"
+    ));
+}
+
 #[test]
 fn parses_regs_ingest_defaults() {
     let cli = Cli::try_parse_from(["scbdb-cli", "regs", "ingest"]).unwrap();
@@ -214,6 +228,18 @@ fn parses_regs_ingest_with_state_and_keyword() {
     }
 }
 
+#[test]
+fn regs_upcoming_rejects_zero_days() {
+    assert!(Cli::try_parse_from(["scbdb-cli", "regs", "upcoming", "--days", "0"]).is_err());
+    let cli = Cli::try_parse_from(["scbdb-cli", "regs", "upcoming", "--days", "1"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Regs {
+            command: RegsCommands::Upcoming { days: 1, .. }
+        })
+    ));
+}
+
 #[test]
 fn parses_regs_ingest_dry_run() {
     let cli = Cli::try_parse_from(["scbdb-cli", "regs", "ingest", "--dry-run"]).unwrap();
@@ -286,3 +312,193 @@ fn parses_regs_report_no_args() {
         })
     ));
 }
+
+#[test]
+fn parses_regs_compliance_with_filters() {
+    let cli = Cli::try_parse_from([
+        "scbdb-cli",
+        "regs",
+        "compliance",
+        "--brand",
+        "cann",
+        "--severity",
+        "critical",
+    ])
+    .unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Regs {
+            command: RegsCommands::Compliance {
+                brand: Some(ref brand),
+                state: None,
+                severity: Some(ref severity),
+                as_of: None,
+            }
+        }) if brand == "cann" && severity == "critical"
+    ));
+}
+
+#[test]
+fn parses_regs_exposure_dry_run() {
+    let cli = Cli::try_parse_from(["scbdb-cli", "regs", "exposure", "--dry-run"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Regs {
+            command: RegsCommands::Exposure {
+                brand: None,
+                as_of: None,
+                dry_run: true,
+            }
+        })
+    ));
+}
+
+#[test]
+fn parses_regs_watch_add_with_note() {
+    let cli = Cli::try_parse_from([
+        "scbdb-cli",
+        "regs",
+        "watch",
+        "add",
+        "--state",
+        "SC",
+        "--bill",
+        "H3001",
+        "--note",
+        "serving caps",
+    ])
+    .unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Regs {
+            command: RegsCommands::Watch {
+                command: WatchCommands::Add {
+                    ref state,
+                    ref bill,
+                    note: Some(ref note),
+                }
+            }
+        }) if state == "SC" && bill == "H3001" && note == "serving caps"
+    ));
+}
+
+#[test]
+fn parses_locations_details_backfill() {
+    let cli = Cli::try_parse_from([
+        "scbdb-cli",
+        "locations",
+        "details",
+        "--brand",
+        "cann",
+        "--dry-run",
+    ])
+    .unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Locations {
+            command: LocationsCommands::Details {
+                brand: Some(ref b),
+                dry_run: true,
+            }
+        }) if b == "cann"
+    ));
+}
+
+#[test]
+fn parses_locations_rekey() {
+    let cli = Cli::try_parse_from(["scbdb-cli", "locations", "rekey", "--dry-run"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Locations {
+            command: LocationsCommands::Rekey {
+                brand: None,
+                dry_run: true,
+            }
+        })
+    ));
+}
+
+#[test]
+fn parses_locations_load_geo() {
+    let cli = Cli::try_parse_from([
+        "scbdb-cli",
+        "locations",
+        "load-geo",
+        "--counties",
+        "counties.csv",
+        "--zip-counties",
+        "ZIP_COUNTY.csv",
+    ])
+    .unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Locations {
+            command: LocationsCommands::LoadGeo {
+                counties: Some(ref counties),
+                zip_counties: Some(ref zips),
+            }
+        }) if counties.as_os_str() == "counties.csv" && zips.as_os_str() == "ZIP_COUNTY.csv"
+    ));
+
+    let cli = Cli::try_parse_from(["scbdb-cli", "locations", "load-geo"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Locations {
+            command: LocationsCommands::LoadGeo {
+                counties: None,
+                zip_counties: None,
+            }
+        })
+    ));
+}
+
+#[test]
+fn parses_accounts_import() {
+    let cli = Cli::try_parse_from([
+        "scbdb-cli",
+        "accounts",
+        "import",
+        "--file",
+        "accounts.csv",
+        "--deactivate-missing",
+    ])
+    .unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Accounts {
+            command: AccountsCommands::Import {
+                ref file,
+                deactivate_missing: true,
+                dry_run: false,
+            }
+        }) if file.as_os_str() == "accounts.csv"
+    ));
+}
+
+#[test]
+fn parses_locations_quarantine_approve() {
+    let cli = Cli::try_parse_from([
+        "scbdb-cli",
+        "locations",
+        "quarantine",
+        "approve",
+        "7f6c1d2e-3b4a-4c5d-8e9f-0a1b2c3d4e5f",
+        "--note",
+        "provider outage confirmed",
+    ])
+    .unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Locations {
+            command: LocationsCommands::Quarantine {
+                command: QuarantineCommands::Approve { ref note, .. }
+            }
+        }) if note.as_deref() == Some("provider outage confirmed")
+    ));
+}
+
+#[test]
+fn rejects_locations_quarantine_with_invalid_id() {
+    let result = Cli::try_parse_from(["scbdb-cli", "locations", "quarantine", "reject", "42"]);
+    assert!(result.is_err());
+}
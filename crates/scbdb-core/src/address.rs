@@ -0,0 +1,118 @@
+//! Street address normalization used to match records from different sources
+//! (distributor account lists, store locator feeds) to the same storefront.
+
+/// Street suffixes and directionals collapsed to their USPS abbreviation.
+const ABBREVIATIONS: &[(&str, &str)] = &[
+    ("street", "st"),
+    ("avenue", "ave"),
+    ("av", "ave"),
+    ("road", "rd"),
+    ("boulevard", "blvd"),
+    ("drive", "dr"),
+    ("lane", "ln"),
+    ("highway", "hwy"),
+    ("parkway", "pkwy"),
+    ("court", "ct"),
+    ("place", "pl"),
+    ("circle", "cir"),
+    ("square", "sq"),
+    ("terrace", "ter"),
+    ("trail", "trl"),
+    ("center", "ctr"),
+    ("centre", "ctr"),
+    ("plaza", "plz"),
+    ("expressway", "expy"),
+    ("freeway", "fwy"),
+    ("route", "rte"),
+    ("north", "n"),
+    ("south", "s"),
+    ("east", "e"),
+    ("west", "w"),
+    ("northeast", "ne"),
+    ("northwest", "nw"),
+    ("southeast", "se"),
+    ("southwest", "sw"),
+];
+
+/// Secondary-unit designators; the designator and the token after it are
+/// dropped so "Suite 4" and "Ste 4" both match the bare street address.
+const UNIT_DESIGNATORS: &[&str] = &[
+    "suite", "ste", "unit", "apt", "bldg", "building", "rm", "room", "fl", "floor", "spc", "space",
+    "lot",
+];
+
+/// Normalize a street address line for matching.
+///
+/// Lower-cases, strips punctuation, abbreviates common suffixes and
+/// directionals, and drops secondary-unit designators (`Suite 4`, `#12`).
+/// Returns an empty string when nothing meaningful remains.
+#[must_use]
+pub fn normalize_street_address(address: &str) -> String {
+    let cleaned: String = address
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '#' {
+                c
+            } else {
+                ' '
+            }
+        })
+        .collect();
+
+    let mut out: Vec<&str> = Vec::new();
+    let mut tokens = cleaned.split_whitespace();
+    while let Some(token) = tokens.next() {
+        if let Some(unit) = token.strip_prefix('#') {
+            // "#12" carries its own number; a bare "#" takes the next token.
+            if unit.is_empty() {
+                tokens.next();
+            }
+            continue;
+        }
+        if UNIT_DESIGNATORS.contains(&token) {
+            tokens.next();
+            continue;
+        }
+        let token = ABBREVIATIONS
+            .iter()
+            .find(|(long, _)| *long == token)
+            .map_or(token, |(_, short)| short);
+        out.push(token);
+    }
+    out.join(" ")
+}
+
+/// First five digits of a US ZIP or ZIP+4, re-padding codes that lost their
+/// leading zeros in a spreadsheet.
+#[must_use]
+pub fn normalize_zip5(zip: &str) -> Option<String> {
+    let digits = zip.trim().split('-').next().unwrap_or("");
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    match digits.len() {
+        1..=5 => Some(format!("{digits:0>5}")),
+        9 => Some(digits[..5].to_string()),
+        _ => None,
+    }
+}
+
+/// Key identifying a physical storefront: normalized street address plus
+/// five-digit ZIP (`"123 main st|29201"`).
+///
+/// `None` when either part is missing, since a street without a ZIP (or a ZIP
+/// alone) is too ambiguous to match on.
+#[must_use]
+pub fn address_match_key(address_line1: Option<&str>, zip: Option<&str>) -> Option<String> {
+    let street = normalize_street_address(address_line1?);
+    let zip = normalize_zip5(zip?)?;
+    if street.is_empty() {
+        return None;
+    }
+    Some(format!("{street}|{zip}"))
+}
+
+#[cfg(test)]
+#[path = "address_test.rs"]
+mod tests;
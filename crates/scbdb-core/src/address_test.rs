@@ -0,0 +1,49 @@
+use super::*;
+
+#[test]
+fn normalizes_suffixes_directionals_and_punctuation() {
+    assert_eq!(
+        normalize_street_address("1234 North Main Street."),
+        "1234 n main st"
+    );
+    assert_eq!(
+        normalize_street_address("1234 N. Main St"),
+        normalize_street_address("1234 north main street")
+    );
+}
+
+#[test]
+fn drops_secondary_unit_designators() {
+    assert_eq!(
+        normalize_street_address("500 Harbison Blvd, Suite 110"),
+        "500 harbison blvd"
+    );
+    assert_eq!(
+        normalize_street_address("500 Harbison Blvd #110"),
+        "500 harbison blvd"
+    );
+    assert_eq!(
+        normalize_street_address("500 Harbison Blvd # 110"),
+        "500 harbison blvd"
+    );
+}
+
+#[test]
+fn normalizes_zip_plus_four_and_lost_leading_zeros() {
+    assert_eq!(normalize_zip5("29201-1234").as_deref(), Some("29201"));
+    assert_eq!(normalize_zip5("292011234").as_deref(), Some("29201"));
+    assert_eq!(normalize_zip5("501").as_deref(), Some("00501"));
+    assert_eq!(normalize_zip5("M5V 2T6"), None);
+    assert_eq!(normalize_zip5(""), None);
+}
+
+#[test]
+fn match_key_requires_street_and_zip() {
+    assert_eq!(
+        address_match_key(Some("10 Gervais St"), Some("29201")).as_deref(),
+        Some("10 gervais st|29201")
+    );
+    assert_eq!(address_match_key(Some("10 Gervais St"), None), None);
+    assert_eq!(address_match_key(Some("  "), Some("29201")), None);
+    assert_eq!(address_match_key(None, Some("29201")), None);
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::net::SocketAddr;
 use std::path::PathBuf;
 
@@ -25,17 +26,46 @@ pub struct AppConfig {
     pub bind_addr: SocketAddr,
     pub log_level: String,
     pub brands_path: PathBuf,
+    /// Bill topic taxonomy used for relevance scoring.
+    pub bill_topics_path: PathBuf,
     pub legiscan_api_key: Option<String>,
     pub db_max_connections: u32,
     pub db_min_connections: u32,
     pub db_acquire_timeout_secs: u64,
     pub scraper_request_timeout_secs: u64,
     pub legiscan_request_timeout_secs: u64,
+    /// `LegiScan` requests allowed per API key and calendar month, counted
+    /// across every process sharing the key.
+    pub legiscan_monthly_budget: u32,
     pub scraper_user_agent: String,
     pub scraper_max_concurrent_brands: usize,
     pub scraper_inter_request_delay_ms: u64,
     pub scraper_max_retries: u32,
     pub scraper_retry_backoff_base_secs: u64,
+    /// Largest drop (percent) in a brand's location count applied without review.
+    pub location_max_drop_pct: u32,
+    /// Brands with fewer active locations than this skip the drop check.
+    pub location_drop_min_baseline: u32,
+    /// Consecutive trusted scrapes a location may be missing from before it
+    /// is marked inactive.
+    pub location_miss_threshold: u32,
+    /// Per-`locator_source` overrides of `location_miss_threshold`.
+    pub location_miss_thresholds: BTreeMap<String, u32>,
+    /// Days since a location was last seen after which a single miss marks it
+    /// inactive; `0` disables the age rule.
+    pub location_miss_max_days: u32,
+    /// Generic JSON webhook receiving bill change notifications.
+    pub notify_webhook_url: Option<String>,
+    /// Slack-compatible incoming webhook receiving bill change notifications.
+    pub notify_slack_webhook_url: Option<String>,
+    /// `smtp://` or `smtps://` URL of the mail server sending bill change
+    /// notifications, credentials included.
+    pub notify_smtp_url: Option<String>,
+    pub notify_email_from: Option<String>,
+    pub notify_email_to: Vec<String>,
+    /// Unwatched bills notify when their relevance score (0–100) is at
+    /// least this.
+    pub notify_min_relevance: f64,
 }
 
 impl std::fmt::Debug for AppConfig {
@@ -45,6 +75,7 @@ impl std::fmt::Debug for AppConfig {
             .field("bind_addr", &self.bind_addr)
             .field("log_level", &self.log_level)
             .field("brands_path", &self.brands_path)
+            .field("bill_topics_path", &self.bill_topics_path)
             .field("database_url", &"[redacted]")
             .field(
                 "legiscan_api_key",
@@ -61,6 +92,7 @@ impl std::fmt::Debug for AppConfig {
                 "legiscan_request_timeout_secs",
                 &self.legiscan_request_timeout_secs,
             )
+            .field("legiscan_monthly_budget", &self.legiscan_monthly_budget)
             .field("scraper_user_agent", &self.scraper_user_agent)
             .field(
                 "scraper_max_concurrent_brands",
@@ -75,6 +107,29 @@ impl std::fmt::Debug for AppConfig {
                 "scraper_retry_backoff_base_secs",
                 &self.scraper_retry_backoff_base_secs,
             )
+            .field("location_max_drop_pct", &self.location_max_drop_pct)
+            .field(
+                "location_drop_min_baseline",
+                &self.location_drop_min_baseline,
+            )
+            .field("location_miss_threshold", &self.location_miss_threshold)
+            .field("location_miss_thresholds", &self.location_miss_thresholds)
+            .field("location_miss_max_days", &self.location_miss_max_days)
+            .field(
+                "notify_webhook_url",
+                &self.notify_webhook_url.as_ref().map(|_| "[redacted]"),
+            )
+            .field(
+                "notify_slack_webhook_url",
+                &self.notify_slack_webhook_url.as_ref().map(|_| "[redacted]"),
+            )
+            .field(
+                "notify_smtp_url",
+                &self.notify_smtp_url.as_ref().map(|_| "[redacted]"),
+            )
+            .field("notify_email_from", &self.notify_email_from)
+            .field("notify_email_to", &self.notify_email_to)
+            .field("notify_min_relevance", &self.notify_min_relevance)
             .finish()
     }
 }
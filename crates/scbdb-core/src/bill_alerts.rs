@@ -0,0 +1,116 @@
+//! Bill change notifications: what changed on a watched or highly relevant
+//! bill since it was last reported, and its plain-text rendering shared by
+//! the Slack and email notifiers.
+//!
+//! Detection lives in `scbdb-db` (`bill_alerts`); delivery in the CLI.
+
+use chrono::NaiveDate;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Default `SCBDB_NOTIFY_MIN_RELEVANCE`: unwatched bills notify at this
+/// relevance score (0–100) and above.
+pub const DEFAULT_NOTIFY_MIN_RELEVANCE: f64 = 70.0;
+
+/// One bill's changes since it was last reported.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BillAlert {
+    pub bill_id: Uuid,
+    pub jurisdiction: String,
+    pub bill_number: String,
+    pub title: String,
+    /// Status last reported; equal to `status` when only events are new, and
+    /// `None` for a bill newly watched or above the relevance threshold.
+    pub previous_status: Option<String>,
+    pub status: String,
+    pub relevance_score: Option<f64>,
+    /// On the watchlist, as opposed to notified for its relevance.
+    pub watched: bool,
+    pub source_url: Option<String>,
+    /// Events recorded since, oldest first.
+    pub events: Vec<BillAlertEvent>,
+}
+
+/// A legislative action in a [`BillAlert`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BillAlertEvent {
+    pub event_date: Option<NaiveDate>,
+    pub chamber: Option<String>,
+    pub description: String,
+}
+
+impl BillAlert {
+    /// Not reported before: newly watched or above the relevance threshold.
+    #[must_use]
+    pub fn is_new(&self) -> bool {
+        self.previous_status.is_none()
+    }
+
+    #[must_use]
+    pub fn status_changed(&self) -> bool {
+        self.previous_status
+            .as_deref()
+            .is_some_and(|previous| previous != self.status)
+    }
+
+    /// One-line summary, e.g. `SC H3001: introduced → engrossed (2 new events)`
+    /// or `SC H3002: new bill (introduced) (1 new event)`.
+    #[must_use]
+    pub fn headline(&self) -> String {
+        let change = match self.previous_status.as_deref() {
+            None => format!(": new bill ({})", self.status),
+            Some(previous) if previous != self.status => {
+                format!(": {previous} → {}", self.status)
+            }
+            Some(_) => format!(" ({})", self.status),
+        };
+        let events = match self.events.len() {
+            0 => String::new(),
+            1 => " (1 new event)".to_string(),
+            n => format!(" ({n} new events)"),
+        };
+        format!("{} {}{change}{events}", self.jurisdiction, self.bill_number)
+    }
+}
+
+/// Subject line for a batch of alerts.
+#[must_use]
+pub fn alerts_subject(alerts: &[BillAlert]) -> String {
+    match alerts {
+        [one] => format!("Bill update: {}", one.headline()),
+        many => format!("{} bill updates", many.len()),
+    }
+}
+
+/// Plain-text body listing each bill's headline, title, events and link.
+#[must_use]
+pub fn render_alerts_text(alerts: &[BillAlert]) -> String {
+    let blocks: Vec<String> = alerts
+        .iter()
+        .map(|alert| {
+            let watched = if alert.watched { " [watched]" } else { "" };
+            let mut lines = vec![
+                format!("{}{watched}", alert.headline()),
+                format!("  {}", alert.title),
+            ];
+            for event in &alert.events {
+                let date = event
+                    .event_date
+                    .map_or_else(|| "undated".to_string(), |d| d.to_string());
+                lines.push(match event.chamber.as_deref() {
+                    Some(chamber) => format!("  - {date} [{chamber}] {}", event.description),
+                    None => format!("  - {date} {}", event.description),
+                });
+            }
+            if let Some(url) = &alert.source_url {
+                lines.push(format!("  {url}"));
+            }
+            lines.join("\n")
+        })
+        .collect();
+    blocks.join("\n\n")
+}
+
+#[cfg(test)]
+#[path = "bill_alerts_test.rs"]
+mod tests;
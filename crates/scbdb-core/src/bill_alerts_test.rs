@@ -0,0 +1,77 @@
+use super::*;
+
+fn alert(previous_status: &str, status: &str, events: usize) -> BillAlert {
+    BillAlert {
+        bill_id: Uuid::nil(),
+        jurisdiction: "SC".to_string(),
+        bill_number: "H3001".to_string(),
+        title: "Hemp beverage serving limits".to_string(),
+        previous_status: Some(previous_status.to_string()),
+        status: status.to_string(),
+        relevance_score: Some(82.0),
+        watched: true,
+        source_url: Some("https://example.com/H3001".to_string()),
+        events: (0..events)
+            .map(|i| BillAlertEvent {
+                event_date: NaiveDate::from_ymd_opt(2026, 3, 1 + u32::try_from(i).unwrap()),
+                chamber: Some("H".to_string()),
+                description: format!("Event {i}"),
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn headline_shows_the_transition_and_event_count() {
+    assert_eq!(
+        alert("introduced", "engrossed", 2).headline(),
+        "SC H3001: introduced → engrossed (2 new events)"
+    );
+    assert_eq!(
+        alert("introduced", "introduced", 1).headline(),
+        "SC H3001 (introduced) (1 new event)"
+    );
+    assert!(!alert("introduced", "introduced", 1).status_changed());
+}
+
+#[test]
+fn headline_marks_newly_tracked_bills() {
+    let mut new = alert("introduced", "introduced", 2);
+    new.previous_status = None;
+    assert!(new.is_new());
+    assert!(!new.status_changed());
+    assert_eq!(
+        new.headline(),
+        "SC H3001: new bill (introduced) (2 new events)"
+    );
+}
+
+#[test]
+fn subject_names_a_single_bill_and_counts_several() {
+    let one = alert("introduced", "engrossed", 0);
+    assert_eq!(
+        alerts_subject(std::slice::from_ref(&one)),
+        "Bill update: SC H3001: introduced → engrossed"
+    );
+    assert_eq!(alerts_subject(&[one.clone(), one]), "2 bill updates");
+}
+
+#[test]
+fn text_lists_events_oldest_first_with_the_link() {
+    let mut undated = alert("engrossed", "enrolled", 1);
+    undated.events[0].event_date = None;
+    undated.events[0].chamber = None;
+    undated.watched = false;
+    let text = render_alerts_text(&[alert("introduced", "engrossed", 2), undated]);
+
+    let expected = "SC H3001: introduced → engrossed (2 new events) [watched]\n  \
+                    Hemp beverage serving limits\n  \
+                    - 2026-03-01 [H] Event 0\n  \
+                    - 2026-03-02 [H] Event 1\n  \
+                    https://example.com/H3001\n\n\
+                    SC H3001: engrossed → enrolled (1 new event)\n  \
+                    Hemp beverage serving limits\n  \
+                    - undated Event 0\n  \
+                    https://example.com/H3001";
+    assert_eq!(text, expected);
+}
@@ -0,0 +1,333 @@
+//! Section-level diffs between bill text versions.
+//!
+//! Bill text is split into numbered sections ("SECTION 1.", "Sec. 2.",
+//! "§ 3."); sections are matched by number between two versions and every
+//! modified section gets a word-level diff, so an amendment that moves a THC
+//! cap from 5 mg to 10 mg shows up as one deleted and one inserted word with
+//! a little surrounding context.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+/// Key of the text before the first section heading.
+pub const PREAMBLE_KEY: &str = "preamble";
+
+/// Unchanged words kept on each side of a change.
+const CONTEXT_WORDS: usize = 12;
+
+/// Largest word-level LCS table (`old words × new words`) computed; larger
+/// changes are reported as one deletion followed by one insertion.
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+/// Words that open a section heading.
+const SECTION_MARKERS: &[&str] = &["section", "sec.", "sec", "§"];
+
+/// One numbered section of a bill text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BillSection {
+    /// Normalized section number ("1", "2a"); [`PREAMBLE_KEY`] before the
+    /// first heading. Repeated numbers get a `#2`, `#3` suffix.
+    pub key: String,
+    /// First line of the section.
+    pub heading: String,
+    pub text: String,
+}
+
+/// How a section changed between versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SectionChange {
+    Added,
+    Removed,
+    Modified,
+    Unchanged,
+}
+
+/// Kind of a diff span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffOp {
+    Equal,
+    Insert,
+    Delete,
+}
+
+/// A run of words that were kept, inserted or deleted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffSpan {
+    pub op: DiffOp,
+    pub text: String,
+}
+
+/// Diff of one section.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SectionDiff {
+    pub key: String,
+    pub heading: String,
+    pub change: SectionChange,
+    /// Word-level changes with surrounding context; empty when unchanged.
+    pub spans: Vec<DiffSpan>,
+}
+
+/// Section-by-section comparison of two bill text versions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BillTextDiff {
+    pub added: usize,
+    pub removed: usize,
+    pub modified: usize,
+    pub unchanged: usize,
+    /// Sections in the order of the newer version, removed sections placed
+    /// where they used to be.
+    pub sections: Vec<SectionDiff>,
+}
+
+/// Split bill text into sections at lines starting with a section heading.
+#[must_use]
+pub fn split_sections(text: &str) -> Vec<BillSection> {
+    let mut sections: Vec<BillSection> = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut current = BillSection {
+        key: PREAMBLE_KEY.to_string(),
+        heading: String::new(),
+        text: String::new(),
+    };
+    for line in text.lines() {
+        if let Some(number) = section_number(line) {
+            if !current.text.trim().is_empty() {
+                sections.push(current);
+            }
+            let count = seen.entry(number.clone()).or_insert(0);
+            *count += 1;
+            let key = if *count == 1 {
+                number
+            } else {
+                format!("{number}#{count}")
+            };
+            current = BillSection {
+                key,
+                heading: line.trim().to_string(),
+                text: String::new(),
+            };
+        } else if current.heading.is_empty() && !line.trim().is_empty() {
+            current.heading = line.trim().to_string();
+        }
+        current.text.push_str(line);
+        current.text.push('\n');
+    }
+    if !current.text.trim().is_empty() {
+        sections.push(current);
+    }
+    sections
+}
+
+/// Section number when `line` opens a section: a marker word followed by a
+/// short number ("1", "2A", "3.1"), optionally ending in `.` or `:`.
+fn section_number(line: &str) -> Option<String> {
+    let mut words = line.split_whitespace();
+    let first = words.next()?.to_lowercase();
+    let number = if let Some(rest) = first.strip_prefix('§').filter(|r| !r.is_empty()) {
+        rest.to_string()
+    } else if SECTION_MARKERS.contains(&first.as_str()) {
+        words.next()?.to_lowercase()
+    } else {
+        return None;
+    };
+    let number = number.trim_end_matches(['.', ':']);
+    let valid = number.chars().next().is_some_and(|c| c.is_ascii_digit())
+        && number.len() <= 6
+        && number
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.');
+    valid.then(|| number.to_string())
+}
+
+/// Compare two versions of a bill text section by section.
+#[must_use]
+pub fn diff_bill_texts(from: &str, to: &str) -> BillTextDiff {
+    let old = split_sections(from);
+    let new = split_sections(to);
+    let old_index: HashMap<&str, usize> = old
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (s.key.as_str(), i))
+        .collect();
+
+    let mut sections = Vec::new();
+    let mut emitted = vec![false; old.len()];
+    let mut next_old = 0;
+    for section in &new {
+        let Some(&i) = old_index.get(section.key.as_str()) else {
+            sections.push(whole_section(section, SectionChange::Added));
+            continue;
+        };
+        for (j, removed) in old.iter().enumerate().take(i).skip(next_old) {
+            if !emitted[j] && !new.iter().any(|n| n.key == removed.key) {
+                sections.push(whole_section(removed, SectionChange::Removed));
+                emitted[j] = true;
+            }
+        }
+        next_old = next_old.max(i + 1);
+        emitted[i] = true;
+        sections.push(compare_sections(&old[i], section));
+    }
+    for (j, removed) in old.iter().enumerate() {
+        if !emitted[j] && !new.iter().any(|n| n.key == removed.key) {
+            sections.push(whole_section(removed, SectionChange::Removed));
+        }
+    }
+
+    let count = |change| sections.iter().filter(|s| s.change == change).count();
+    BillTextDiff {
+        added: count(SectionChange::Added),
+        removed: count(SectionChange::Removed),
+        modified: count(SectionChange::Modified),
+        unchanged: count(SectionChange::Unchanged),
+        sections,
+    }
+}
+
+fn whole_section(section: &BillSection, change: SectionChange) -> SectionDiff {
+    let op = if change == SectionChange::Added {
+        DiffOp::Insert
+    } else {
+        DiffOp::Delete
+    };
+    SectionDiff {
+        key: section.key.clone(),
+        heading: section.heading.clone(),
+        change,
+        spans: vec![DiffSpan {
+            op,
+            text: words(&section.text).join(" "),
+        }],
+    }
+}
+
+fn compare_sections(old: &BillSection, new: &BillSection) -> SectionDiff {
+    let old_words = words(&old.text);
+    let new_words = words(&new.text);
+    if old_words == new_words {
+        return SectionDiff {
+            key: new.key.clone(),
+            heading: new.heading.clone(),
+            change: SectionChange::Unchanged,
+            spans: Vec::new(),
+        };
+    }
+    SectionDiff {
+        key: new.key.clone(),
+        heading: new.heading.clone(),
+        change: SectionChange::Modified,
+        spans: with_context(diff_words(&old_words, &new_words)),
+    }
+}
+
+fn words(text: &str) -> Vec<&str> {
+    text.split_whitespace().collect()
+}
+
+/// Word diff: common prefix/suffix trimmed, LCS on the middle.
+fn diff_words(old: &[&str], new: &[&str]) -> Vec<DiffSpan> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let mut ops: Vec<(DiffOp, &str)> = old[..prefix].iter().map(|w| (DiffOp::Equal, *w)).collect();
+    if old_mid.len().saturating_mul(new_mid.len()) <= MAX_LCS_CELLS {
+        ops.extend(lcs_ops(old_mid, new_mid));
+    } else {
+        ops.extend(old_mid.iter().map(|w| (DiffOp::Delete, *w)));
+        ops.extend(new_mid.iter().map(|w| (DiffOp::Insert, *w)));
+    }
+    ops.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|w| (DiffOp::Equal, *w)),
+    );
+
+    let mut spans: Vec<DiffSpan> = Vec::new();
+    for (op, word) in ops {
+        match spans.last_mut() {
+            Some(span) if span.op == op => {
+                span.text.push(' ');
+                span.text.push_str(word);
+            }
+            _ => spans.push(DiffSpan {
+                op,
+                text: word.to_string(),
+            }),
+        }
+    }
+    spans
+}
+
+/// Edit script from a longest-common-subsequence table; deletions come
+/// before insertions within a change.
+fn lcs_ops<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(DiffOp, &'a str)> {
+    let (n, m) = (old.len(), new.len());
+    let mut table = vec![0u32; (n + 1) * (m + 1)];
+    let at = |i: usize, j: usize| i * (m + 1) + j;
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            table[at(i, j)] = if old[i] == new[j] {
+                table[at(i + 1, j + 1)] + 1
+            } else {
+                table[at(i + 1, j)].max(table[at(i, j + 1)])
+            };
+        }
+    }
+    let mut ops = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && old[i] == new[j] {
+            ops.push((DiffOp::Equal, old[i]));
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || table[at(i + 1, j)] >= table[at(i, j + 1)]) {
+            ops.push((DiffOp::Delete, old[i]));
+            i += 1;
+        } else {
+            ops.push((DiffOp::Insert, new[j]));
+            j += 1;
+        }
+    }
+    ops
+}
+
+/// Shorten long unchanged runs to [`CONTEXT_WORDS`] on each side of a change.
+fn with_context(spans: Vec<DiffSpan>) -> Vec<DiffSpan> {
+    let last = spans.len().saturating_sub(1);
+    spans
+        .into_iter()
+        .enumerate()
+        .map(|(i, span)| {
+            if span.op != DiffOp::Equal {
+                return span;
+            }
+            let words: Vec<&str> = span.text.split(' ').collect();
+            let keep_head = if i == 0 { 0 } else { CONTEXT_WORDS };
+            let keep_tail = if i == last { 0 } else { CONTEXT_WORDS };
+            if words.len() <= keep_head + keep_tail + 1 {
+                return span;
+            }
+            let mut parts: Vec<&str> = words[..keep_head].to_vec();
+            parts.push("…");
+            parts.extend(&words[words.len() - keep_tail..]);
+            DiffSpan {
+                op: DiffOp::Equal,
+                text: parts.join(" "),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "bill_diff_test.rs"]
+mod tests;
@@ -0,0 +1,96 @@
+use super::*;
+
+const INTRODUCED: &str = "A BILL to regulate hemp-derived beverages.\n\
+    SECTION 1. A hemp beverage may contain no more than 5 milligrams of THC per serving.\n\
+    SECTION 2. Retailers must verify age.\n\
+    SECTION 3. Local governments may not impose additional limits.\n\
+    SECTION 4. This act takes effect upon approval.";
+
+const AMENDED: &str = "A BILL to regulate hemp-derived beverages.\n\
+    SECTION 1. A hemp beverage may contain no more than 10 milligrams of THC per serving.\n\
+    SECTION 2. Retailers must verify age.\n\
+    SECTION 2A. Beverages may not be sold within 500 feet of a school.\n\
+    SECTION 4. This act takes effect upon approval.";
+
+#[test]
+fn sections_split_on_common_heading_styles() {
+    let text = "Preamble line\nSection 1. One.\nmore of one\nSEC. 2. Two.\n§3. Three.\n\
+        Sec 4: Four.\nSection 44-53-190 of the 1976 Code is amended\nSECTION 1. Again.";
+    let keys: Vec<String> = split_sections(text).into_iter().map(|s| s.key).collect();
+    assert_eq!(keys, vec![PREAMBLE_KEY, "1", "2", "3", "4", "1#2"]);
+
+    let sections = split_sections(text);
+    assert_eq!(sections[1].heading, "Section 1. One.");
+    assert!(sections[4].text.contains("Section 44-53-190"));
+}
+
+#[test]
+fn amended_thc_cap_is_a_word_level_change() {
+    let diff = diff_bill_texts(INTRODUCED, AMENDED);
+    assert_eq!(
+        (diff.added, diff.removed, diff.modified, diff.unchanged),
+        (1, 1, 1, 3)
+    );
+
+    let keys: Vec<(&str, SectionChange)> = diff
+        .sections
+        .iter()
+        .map(|s| (s.key.as_str(), s.change))
+        .collect();
+    assert_eq!(
+        keys,
+        vec![
+            (PREAMBLE_KEY, SectionChange::Unchanged),
+            ("1", SectionChange::Modified),
+            ("2", SectionChange::Unchanged),
+            ("2a", SectionChange::Added),
+            ("3", SectionChange::Removed),
+            ("4", SectionChange::Unchanged),
+        ]
+    );
+
+    let changed: Vec<(DiffOp, &str)> = diff.sections[1]
+        .spans
+        .iter()
+        .map(|s| (s.op, s.text.as_str()))
+        .collect();
+    assert_eq!(
+        changed,
+        vec![
+            (
+                DiffOp::Equal,
+                "SECTION 1. A hemp beverage may contain no more than"
+            ),
+            (DiffOp::Delete, "5"),
+            (DiffOp::Insert, "10"),
+            (DiffOp::Equal, "milligrams of THC per serving."),
+        ]
+    );
+    assert_eq!(diff.sections[4].spans[0].op, DiffOp::Delete);
+}
+
+#[test]
+fn long_unchanged_runs_are_trimmed_to_context() {
+    let filler: Vec<String> = (0..40).map(|i| format!("w{i}")).collect();
+    let filler = filler.join(" ");
+    let from = format!("SECTION 1. {filler} old {filler}");
+    let to = format!("SECTION 1. {filler} new {filler}");
+    let diff = diff_bill_texts(&from, &to);
+    let spans = &diff.sections[0].spans;
+    assert_eq!(spans.len(), 4);
+    assert!(spans[0].text.starts_with('…'));
+    assert_eq!(spans[0].text.split(' ').count(), CONTEXT_WORDS + 1);
+    assert!(spans[3].text.ends_with('…'));
+}
+
+#[test]
+fn identical_texts_have_no_changes_and_text_without_sections_still_diffs() {
+    let diff = diff_bill_texts(INTRODUCED, INTRODUCED);
+    assert_eq!((diff.modified, diff.unchanged), (0, 5));
+    assert!(diff.sections.iter().all(|s| s.spans.is_empty()));
+
+    let diff = diff_bill_texts("Hemp is legal.", "Hemp is regulated.");
+    assert_eq!(diff.sections.len(), 1);
+    assert_eq!(diff.sections[0].key, PREAMBLE_KEY);
+    assert_eq!(diff.sections[0].change, SectionChange::Modified);
+}
@@ -0,0 +1,287 @@
+//! Topic taxonomy and relevance scoring for bills.
+//!
+//! A title keyword match misses hemp beverage provisions buried in tax or
+//! alcohol bills and keeps agricultural hemp bills nobody here cares about.
+//! The taxonomy in `config/bill_topics.yaml` instead scores a bill's title,
+//! description and text against weighted term and phrase rules per topic
+//! (serving caps, age limits, excise tax, …), subtracts off-topic rules
+//! (cultivation, fiber, seed certification), and maps the total onto a
+//! 0–100 relevance score.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ConfigError;
+
+/// Weight of a match in the bill title, description and body text.
+const TITLE_WEIGHT: f64 = 3.0;
+const DESCRIPTION_WEIGHT: f64 = 2.0;
+const TEXT_WEIGHT: f64 = 1.0;
+
+/// Matches of one rule counted per field; long texts repeat terms.
+const MAX_MATCHES_PER_FIELD: usize = 3;
+
+/// Points per field weight when any anchor term appears in that field.
+const ANCHOR_POINTS: f64 = 2.0;
+
+/// Raw score at which relevance reaches ~63/100.
+const RELEVANCE_SCALE: f64 = 20.0;
+
+fn default_min_score() -> f64 {
+    3.0
+}
+
+fn default_exposure_weight() -> f64 {
+    1.0
+}
+
+/// A weighted term or phrase. Matching is case-insensitive on whole words;
+/// punctuation is ignored, so `"21 years of age"` matches "21 years of age,".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopicRule {
+    pub term: String,
+    pub weight: f64,
+}
+
+/// One topic of the taxonomy.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopicConfig {
+    /// Stable tag stored per bill and accepted by the API `topic` filter.
+    pub slug: String,
+    pub name: String,
+    /// Raw score a bill needs on this topic to be tagged with it.
+    #[serde(default = "default_min_score")]
+    pub min_score: f64,
+    /// How much a pending bill on this topic weighs in a brand's regulatory
+    /// exposure, relative to other topics.
+    #[serde(default = "default_exposure_weight")]
+    pub exposure_weight: f64,
+    pub rules: Vec<TopicRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BillTopicsFile {
+    /// Terms that make a bill about hemp/cannabinoids at all; a bill
+    /// mentioning none of them scores zero.
+    pub anchors: Vec<String>,
+    /// Rules whose weight is subtracted (agricultural hemp, industrial uses).
+    #[serde(default)]
+    pub off_topic: Vec<TopicRule>,
+    pub topics: Vec<TopicConfig>,
+}
+
+/// The parts of a bill scored against the taxonomy.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BillDocument<'a> {
+    pub title: &'a str,
+    pub description: Option<&'a str>,
+    pub text: Option<&'a str>,
+}
+
+/// A topic a bill was tagged with.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TopicMatch {
+    pub slug: String,
+    pub score: f64,
+}
+
+/// Relevance of one bill.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BillRelevance {
+    /// 0–100, rounded to one decimal.
+    pub score: f64,
+    /// Anchor plus topic points minus off-topic points; negative means the
+    /// bill is dominated by off-topic language.
+    pub raw: f64,
+    /// Tagged topics, highest score first.
+    pub topics: Vec<TopicMatch>,
+}
+
+impl BillRelevance {
+    /// Whether off-topic rules outweigh everything the bill matched.
+    #[must_use]
+    pub fn is_off_topic(&self) -> bool {
+        self.raw < 0.0
+    }
+}
+
+/// Load and validate the bill topic taxonomy from a YAML file.
+///
+/// # Errors
+///
+/// Returns `ConfigError` if the file cannot be read, parsed, or fails validation.
+pub fn load_bill_topics(path: &Path) -> Result<BillTopicsFile, ConfigError> {
+    let content = std::fs::read_to_string(path).map_err(|e| ConfigError::BillTopicsFileIo {
+        path: path.display().to_string(),
+        source: e,
+    })?;
+
+    let file: BillTopicsFile =
+        serde_yaml::from_str(&content).map_err(ConfigError::BillTopicsFileParse)?;
+
+    validate_bill_topics(&file)?;
+
+    Ok(file)
+}
+
+fn validate_bill_topics(file: &BillTopicsFile) -> Result<(), ConfigError> {
+    if file.anchors.iter().all(|a| normalize(a).is_empty()) {
+        return Err(ConfigError::Validation(
+            "bill topics need at least one anchor term".to_string(),
+        ));
+    }
+
+    let mut seen = HashSet::new();
+    for topic in &file.topics {
+        let valid_slug = !topic.slug.is_empty()
+            && topic
+                .slug
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if !valid_slug {
+            return Err(ConfigError::Validation(format!(
+                "topic slug '{}' must be lowercase letters, digits and underscores",
+                topic.slug
+            )));
+        }
+        if !seen.insert(topic.slug.as_str()) {
+            return Err(ConfigError::Validation(format!(
+                "duplicate topic slug: '{}'",
+                topic.slug
+            )));
+        }
+        if !topic.exposure_weight.is_finite() || topic.exposure_weight < 0.0 {
+            return Err(ConfigError::Validation(format!(
+                "topic '{}' needs a non-negative exposure_weight",
+                topic.slug
+            )));
+        }
+        if topic.rules.is_empty() {
+            return Err(ConfigError::Validation(format!(
+                "topic '{}' has no rules",
+                topic.slug
+            )));
+        }
+    }
+
+    let rules = file
+        .topics
+        .iter()
+        .flat_map(|t| t.rules.iter())
+        .chain(&file.off_topic);
+    for rule in rules {
+        if normalize(&rule.term).is_empty() || !rule.weight.is_finite() || rule.weight <= 0.0 {
+            return Err(ConfigError::Validation(format!(
+                "rule '{}' needs a non-empty term and a positive weight",
+                rule.term
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+impl BillTopicsFile {
+    /// Whether `slug` names a topic of this taxonomy.
+    #[must_use]
+    pub fn has_topic(&self, slug: &str) -> bool {
+        self.topics.iter().any(|t| t.slug == slug)
+    }
+
+    /// Score a bill against the taxonomy.
+    #[must_use]
+    pub fn score(&self, bill: &BillDocument<'_>) -> BillRelevance {
+        let fields: Vec<(String, f64)> = [
+            (Some(bill.title), TITLE_WEIGHT),
+            (bill.description, DESCRIPTION_WEIGHT),
+            (bill.text, TEXT_WEIGHT),
+        ]
+        .into_iter()
+        .filter_map(|(text, weight)| text.map(|t| (format!(" {} ", normalize(t)), weight)))
+        .collect();
+
+        let anchors: Vec<String> = self.anchors.iter().map(|a| normalize(a)).collect();
+        let anchor_points: f64 = fields
+            .iter()
+            .filter(|(text, _)| anchors.iter().any(|a| count_term(text, a) > 0))
+            .map(|(_, weight)| weight * ANCHOR_POINTS)
+            .sum();
+        if anchor_points == 0.0 {
+            return BillRelevance {
+                score: 0.0,
+                raw: 0.0,
+                topics: Vec::new(),
+            };
+        }
+
+        let rules_points = |rules: &[TopicRule]| -> f64 {
+            rules
+                .iter()
+                .map(|rule| {
+                    let term = normalize(&rule.term);
+                    let hits: f64 = fields
+                        .iter()
+                        .map(|(text, weight)| {
+                            let count = count_term(text, &term).min(MAX_MATCHES_PER_FIELD);
+                            weight * f64::from(u32::try_from(count).unwrap_or(u32::MAX))
+                        })
+                        .sum();
+                    rule.weight * hits
+                })
+                .sum()
+        };
+
+        let mut topics: Vec<TopicMatch> = Vec::new();
+        let mut topic_points = 0.0;
+        for topic in &self.topics {
+            let points = rules_points(&topic.rules);
+            topic_points += points;
+            if points >= topic.min_score {
+                topics.push(TopicMatch {
+                    slug: topic.slug.clone(),
+                    score: round1(points),
+                });
+            }
+        }
+        topics.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        let raw = anchor_points + topic_points - rules_points(&self.off_topic);
+        BillRelevance {
+            score: round1(100.0 * (1.0 - (-raw.max(0.0) / RELEVANCE_SCALE).exp())),
+            raw: round1(raw),
+            topics,
+        }
+    }
+}
+
+/// Lowercase, with every run of non-alphanumeric characters collapsed to a
+/// single space.
+fn normalize(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            out.push(c);
+        } else if !out.ends_with(' ') {
+            out.push(' ');
+        }
+    }
+    out.trim().to_string()
+}
+
+/// Whole-word occurrences of a normalized term in ` padded ` normalized text.
+fn count_term(padded: &str, term: &str) -> usize {
+    if term.is_empty() {
+        return 0;
+    }
+    padded.matches(&format!(" {term} ")).count()
+}
+
+fn round1(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+#[cfg(test)]
+#[path = "bill_topics_test.rs"]
+mod tests;
@@ -0,0 +1,95 @@
+use super::*;
+
+fn taxonomy() -> BillTopicsFile {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("..")
+        .join("config")
+        .join("bill_topics.yaml");
+    load_bill_topics(&path).expect("failed to load bill_topics.yaml")
+}
+
+fn slugs(relevance: &BillRelevance) -> Vec<&str> {
+    relevance.topics.iter().map(|t| t.slug.as_str()).collect()
+}
+
+#[test]
+fn beverage_provisions_in_a_tax_bill_are_tagged() {
+    let relevance = taxonomy().score(&BillDocument {
+        title: "Relating to taxation; amending the alcoholic beverage code",
+        description: Some(
+            "Imposes an excise tax on hemp-derived beverages and limits them to \
+             5 milligrams of total THC per serving.",
+        ),
+        text: Some(
+            "SECTION 1. A hemp-derived beverage may not contain more than 5 milligrams \
+             of total THC per serving and may not be sold to a person under the age of 21.",
+        ),
+    });
+    let topics = slugs(&relevance);
+    assert!(topics.contains(&"excise_tax"), "{relevance:?}");
+    assert!(topics.contains(&"thc_serving_caps"), "{relevance:?}");
+    assert!(topics.contains(&"alcohol_channel"), "{relevance:?}");
+    assert!(topics.contains(&"age_limits"), "{relevance:?}");
+    assert!(relevance.score > 80.0, "{relevance:?}");
+    assert!(!relevance.is_off_topic());
+}
+
+#[test]
+fn agricultural_hemp_bills_score_off_topic() {
+    let relevance = taxonomy().score(&BillDocument {
+        title: "Industrial hemp cultivation; hemp grower licensing",
+        description: Some(
+            "Updates seed certification requirements for industrial hemp crop fiber.",
+        ),
+        text: None,
+    });
+    assert!(relevance.is_off_topic(), "{relevance:?}");
+    assert!(relevance.score.abs() < f64::EPSILON);
+}
+
+#[test]
+fn bills_without_anchor_terms_score_zero() {
+    let relevance = taxonomy().score(&BillDocument {
+        title: "Alcoholic beverage excise tax; beer wholesaler franchise",
+        ..BillDocument::default()
+    });
+    assert!(relevance.score.abs() < f64::EPSILON);
+    assert!(relevance.topics.is_empty());
+}
+
+#[test]
+fn terms_match_whole_words_ignoring_punctuation() {
+    let padded = format!(" {} ", normalize("Delta-9 THC, 21 years of age."));
+    assert_eq!(count_term(&padded, &normalize("delta-9")), 1);
+    assert_eq!(count_term(&padded, &normalize("21 years of age")), 1);
+    assert_eq!(count_term(&padded, "th"), 0);
+}
+
+#[test]
+fn validation_rejects_bad_slugs_duplicates_and_weights() {
+    let mut file = taxonomy();
+    assert!(validate_bill_topics(&file).is_ok());
+    assert!(file.has_topic("excise_tax"));
+
+    file.topics[1].slug = file.topics[0].slug.clone();
+    assert!(validate_bill_topics(&file)
+        .unwrap_err()
+        .to_string()
+        .contains("duplicate topic slug"));
+
+    let mut file = taxonomy();
+    file.topics[0].slug = "Serving Caps".to_string();
+    assert!(validate_bill_topics(&file).is_err());
+
+    let mut file = taxonomy();
+    file.off_topic[0].weight = 0.0;
+    assert!(validate_bill_topics(&file).is_err());
+
+    let mut file = taxonomy();
+    file.topics[0].exposure_weight = -1.0;
+    assert!(validate_bill_topics(&file)
+        .unwrap_err()
+        .to_string()
+        .contains("exposure_weight"));
+}
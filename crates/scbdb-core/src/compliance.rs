@@ -0,0 +1,149 @@
+//! Building blocks of the product compliance check against the `state_rules`
+//! registry.
+//!
+//! A variant's `dosage_mg` is the THC in one can or bottle, which is checked
+//! against the per-serving cap. The per-container cap applies to the whole
+//! retail package: one can, or every can of a multi-pack, with the pack size
+//! taken from the variant title by [`parse_pack_count`]. How severe a
+//! violation is depends on whether the store locators show the product on
+//! shelf in the state; see [`Severity::from_presence`].
+
+use serde::Serialize;
+
+use crate::RuleKind;
+
+/// How urgent a compliance violation is.
+///
+/// Ordered most severe first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// The product is on shelf in the state.
+    Critical,
+    /// The brand is on shelf in the state; whether this product is, is unknown.
+    Warning,
+    /// The brand has no known stores in the state.
+    Info,
+}
+
+impl Severity {
+    /// Every severity, most severe first.
+    pub const ALL: [Severity; 3] = [Severity::Critical, Severity::Warning, Severity::Info];
+
+    /// Severity of a violation in a state from the active stores there that
+    /// carry the product (`product_stores`) and the brand (`brand_stores`).
+    #[must_use]
+    pub fn from_presence(product_stores: i64, brand_stores: i64) -> Severity {
+        if product_stores > 0 {
+            Severity::Critical
+        } else if brand_stores > 0 {
+            Severity::Warning
+        } else {
+            Severity::Info
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Severity::Critical => "critical",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        }
+    }
+
+    /// Parses `critical`, `warning` or `info` (case-insensitive).
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Severity> {
+        let value = value.trim().to_ascii_lowercase();
+        Severity::ALL.into_iter().find(|s| s.as_str() == value)
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// THC in milligrams a variant is checked with against a rule of `kind`, or
+/// `None` for kinds that do not cap THC.
+#[must_use]
+pub fn thc_mg_for(kind: RuleKind, dosage_mg: f64, pack_count: u32) -> Option<f64> {
+    match kind {
+        RuleKind::MaxThcMgPerServing => Some(dosage_mg),
+        RuleKind::MaxThcMgPerContainer => Some(dosage_mg * f64::from(pack_count)),
+        RuleKind::MinAge | RuleKind::RetailChannels | RuleKind::TaxRate => None,
+    }
+}
+
+/// Attempts to parse the number of cans in a multi-pack from a variant title.
+///
+/// Recognizes (case-insensitive) `"12-Pack"`, `"4 pack"`, `"4pk"`, `"24ct"`,
+/// `"6 count"`, `"Pack of 4"` and `"Case of 24"`. Returns `None` when no pack
+/// size is found, i.e. the variant is a single can.
+#[must_use]
+pub fn parse_pack_count(title: &str) -> Option<u32> {
+    let lower = title.to_lowercase();
+    let tokens = tokenize(&lower);
+
+    for (i, window) in tokens.windows(2).enumerate() {
+        if let (Token::Number(n), Token::Word(word)) = (&window[0], &window[1]) {
+            if matches!(*word, "pack" | "packs" | "pk" | "ct" | "count") && *n > 0 {
+                return Some(*n);
+            }
+        }
+        if let (Token::Word("pack" | "case"), Token::Word("of")) = (&window[0], &window[1]) {
+            if let Some(Token::Number(n)) = tokens.get(i + 2) {
+                if *n > 0 {
+                    return Some(*n);
+                }
+            }
+        }
+    }
+    None
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Number(u32),
+    Word(&'a str),
+}
+
+/// Splits `text` into runs of ASCII digits and runs of letters, dropping
+/// everything else (`"12-pack"` → `12`, `pack`; `"4pk"` → `4`, `pk`).
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, ch)) = chars.next() {
+        let is_digit = ch.is_ascii_digit();
+        if !is_digit && !ch.is_alphabetic() {
+            continue;
+        }
+        let mut end = start + ch.len_utf8();
+        while let Some(&(i, next)) = chars.peek() {
+            let same = if is_digit {
+                next.is_ascii_digit()
+            } else {
+                next.is_alphabetic()
+            };
+            if !same {
+                break;
+            }
+            end = i + next.len_utf8();
+            chars.next();
+        }
+        let run = &text[start..end];
+        tokens.push(if is_digit {
+            // Digit runs too long for a pack size are not one.
+            Token::Number(run.parse().unwrap_or(0))
+        } else {
+            Token::Word(run)
+        });
+    }
+    tokens
+}
+
+#[cfg(test)]
+#[path = "compliance_test.rs"]
+mod tests;
@@ -0,0 +1,43 @@
+use super::*;
+
+#[test]
+fn pack_counts_are_parsed_from_variant_titles() {
+    assert_eq!(parse_pack_count("12oz / 5mg THC / 12-Pack"), Some(12));
+    assert_eq!(parse_pack_count("4 pack"), Some(4));
+    assert_eq!(parse_pack_count("10mg 4PK"), Some(4));
+    assert_eq!(parse_pack_count("24ct"), Some(24));
+    assert_eq!(parse_pack_count("Pack of 6"), Some(6));
+    assert_eq!(parse_pack_count("Case of 24 - 12oz"), Some(24));
+}
+
+#[test]
+fn titles_without_a_pack_size_are_single_cans() {
+    assert_eq!(parse_pack_count("12oz / 5mg THC"), None);
+    assert_eq!(parse_pack_count("Default Title"), None);
+    assert_eq!(parse_pack_count("Variety Pack"), None);
+    assert_eq!(parse_pack_count("0-pack"), None);
+}
+
+#[test]
+fn container_caps_cover_the_whole_pack() {
+    assert_eq!(thc_mg_for(RuleKind::MaxThcMgPerServing, 5.0, 4), Some(5.0));
+    assert_eq!(
+        thc_mg_for(RuleKind::MaxThcMgPerContainer, 5.0, 4),
+        Some(20.0)
+    );
+    assert_eq!(
+        thc_mg_for(RuleKind::MaxThcMgPerContainer, 5.0, 1),
+        Some(5.0)
+    );
+    assert_eq!(thc_mg_for(RuleKind::MinAge, 5.0, 1), None);
+}
+
+#[test]
+fn severity_follows_store_presence() {
+    assert_eq!(Severity::from_presence(37, 40), Severity::Critical);
+    assert_eq!(Severity::from_presence(0, 40), Severity::Warning);
+    assert_eq!(Severity::from_presence(0, 0), Severity::Info);
+    assert!(Severity::Critical < Severity::Warning);
+    assert_eq!(Severity::parse("WARNING"), Some(Severity::Warning));
+    assert_eq!(Severity::parse("fatal"), None);
+}
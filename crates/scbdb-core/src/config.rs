@@ -1,5 +1,7 @@
+use std::collections::BTreeMap;
+
 use crate::app_config::{AppConfig, Environment};
-use crate::ConfigError;
+use crate::{ConfigError, DEFAULT_NOTIFY_MIN_RELEVANCE};
 
 /// Load application configuration from environment variables.
 ///
@@ -29,6 +31,7 @@ pub fn load_app_config_from_env() -> Result<AppConfig, ConfigError> {
 ///
 /// This is the core parsing/validation logic, decoupled from the actual environment
 /// so it can be tested with a pure `HashMap` lookup — no `set_var`/`remove_var` needed.
+#[allow(clippy::too_many_lines)] // One binding per env var; splitting it scatters the defaults
 fn build_app_config<F>(lookup: F) -> Result<AppConfig, ConfigError>
 where
     F: Fn(&str) -> Result<String, std::env::VarError>,
@@ -85,6 +88,10 @@ where
     let bind_addr = parse("SCBDB_BIND_ADDR", "0.0.0.0:3000")?;
     let log_level = or_default("SCBDB_LOG_LEVEL", "info");
     let brands_path = PathBuf::from(or_default("SCBDB_BRANDS_PATH", "./config/brands.yaml"));
+    let bill_topics_path = PathBuf::from(or_default(
+        "SCBDB_BILL_TOPICS_PATH",
+        "./config/bill_topics.yaml",
+    ));
     let legiscan_api_key = lookup("LEGISCAN_API_KEY").ok();
 
     let db_max_connections = parse_u32("SCBDB_DB_MAX_CONNECTIONS", "10")?;
@@ -93,6 +100,7 @@ where
 
     let scraper_request_timeout_secs = parse_u64("SCBDB_SCRAPER_REQUEST_TIMEOUT_SECS", "30")?;
     let legiscan_request_timeout_secs = parse_u64("SCBDB_LEGISCAN_REQUEST_TIMEOUT_SECS", "30")?;
+    let legiscan_monthly_budget = parse_u32("SCBDB_LEGISCAN_MONTHLY_BUDGET", "30000")?;
     let scraper_user_agent = or_default(
         "SCBDB_SCRAPER_USER_AGENT",
         "scbdb/0.1 (product-intelligence)",
@@ -101,6 +109,36 @@ where
     let scraper_inter_request_delay_ms = parse_u64("SCBDB_SCRAPER_INTER_REQUEST_DELAY_MS", "250")?;
     let scraper_max_retries = parse_u32("SCBDB_SCRAPER_MAX_RETRIES", "3")?;
     let scraper_retry_backoff_base_secs = parse_u64("SCBDB_SCRAPER_RETRY_BACKOFF_BASE_SECS", "5")?;
+    let location_max_drop_pct = parse_u32("SCBDB_LOCATION_MAX_DROP_PCT", "50")?;
+    let location_drop_min_baseline = parse_u32("SCBDB_LOCATION_DROP_MIN_BASELINE", "20")?;
+    let location_miss_threshold = parse_u32("SCBDB_LOCATION_MISS_THRESHOLD", "2")?;
+    let location_miss_thresholds = parse_source_thresholds(
+        "SCBDB_LOCATION_MISS_THRESHOLDS",
+        &or_default("SCBDB_LOCATION_MISS_THRESHOLDS", ""),
+    )?;
+    let location_miss_max_days = parse_u32("SCBDB_LOCATION_MISS_MAX_DAYS", "14")?;
+
+    let optional = |var: &str| lookup(var).ok().filter(|v| !v.trim().is_empty());
+    let notify_webhook_url = optional("SCBDB_NOTIFY_WEBHOOK_URL");
+    let notify_slack_webhook_url = optional("SCBDB_NOTIFY_SLACK_WEBHOOK_URL");
+    let notify_smtp_url = optional("SCBDB_NOTIFY_SMTP_URL");
+    let notify_email_from = optional("SCBDB_NOTIFY_EMAIL_FROM");
+    let notify_email_to: Vec<String> = or_default("SCBDB_NOTIFY_EMAIL_TO", "")
+        .split(',')
+        .map(str::trim)
+        .filter(|a| !a.is_empty())
+        .map(str::to_string)
+        .collect();
+    let notify_min_relevance = match optional("SCBDB_NOTIFY_MIN_RELEVANCE") {
+        Some(raw) => raw
+            .trim()
+            .parse::<f64>()
+            .map_err(|e| ConfigError::InvalidEnvVar {
+                var: "SCBDB_NOTIFY_MIN_RELEVANCE".to_string(),
+                reason: e.to_string(),
+            })?,
+        None => DEFAULT_NOTIFY_MIN_RELEVANCE,
+    };
 
     if db_min_connections > db_max_connections {
         return Err(ConfigError::InvalidEnvVar {
@@ -111,26 +149,122 @@ where
         });
     }
 
+    validate_location_guards(location_max_drop_pct, location_miss_threshold)?;
+    validate_notify(
+        notify_smtp_url.is_some(),
+        notify_email_from.is_some(),
+        notify_email_to.len(),
+        notify_min_relevance,
+    )?;
+
     Ok(AppConfig {
         database_url,
         env,
         bind_addr,
         log_level,
         brands_path,
+        bill_topics_path,
         legiscan_api_key,
         db_max_connections,
         db_min_connections,
         db_acquire_timeout_secs,
         scraper_request_timeout_secs,
         legiscan_request_timeout_secs,
+        legiscan_monthly_budget,
         scraper_user_agent,
         scraper_max_concurrent_brands,
         scraper_inter_request_delay_ms,
         scraper_max_retries,
         scraper_retry_backoff_base_secs,
+        location_max_drop_pct,
+        location_drop_min_baseline,
+        location_miss_threshold,
+        location_miss_thresholds,
+        location_miss_max_days,
+        notify_webhook_url,
+        notify_slack_webhook_url,
+        notify_smtp_url,
+        notify_email_from,
+        notify_email_to,
+        notify_min_relevance,
     })
 }
 
+/// Range checks for the location collection guardrails.
+fn validate_location_guards(max_drop_pct: u32, miss_threshold: u32) -> Result<(), ConfigError> {
+    if !(1..=100).contains(&max_drop_pct) {
+        return Err(ConfigError::InvalidEnvVar {
+            var: "SCBDB_LOCATION_MAX_DROP_PCT".to_string(),
+            reason: format!("must be between 1 and 100, got {max_drop_pct}"),
+        });
+    }
+    if miss_threshold == 0 {
+        return Err(ConfigError::InvalidEnvVar {
+            var: "SCBDB_LOCATION_MISS_THRESHOLD".to_string(),
+            reason: "must be at least 1".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Email notifications need a sender and recipients; the relevance
+/// threshold is a 0–100 score.
+fn validate_notify(
+    has_smtp: bool,
+    has_from: bool,
+    recipients: usize,
+    min_relevance: f64,
+) -> Result<(), ConfigError> {
+    if has_smtp && !has_from {
+        return Err(ConfigError::InvalidEnvVar {
+            var: "SCBDB_NOTIFY_EMAIL_FROM".to_string(),
+            reason: "required when SCBDB_NOTIFY_SMTP_URL is set".to_string(),
+        });
+    }
+    if has_smtp && recipients == 0 {
+        return Err(ConfigError::InvalidEnvVar {
+            var: "SCBDB_NOTIFY_EMAIL_TO".to_string(),
+            reason: "required when SCBDB_NOTIFY_SMTP_URL is set".to_string(),
+        });
+    }
+    if !(0.0..=100.0).contains(&min_relevance) {
+        return Err(ConfigError::InvalidEnvVar {
+            var: "SCBDB_NOTIFY_MIN_RELEVANCE".to_string(),
+            reason: format!("must be between 0 and 100, got {min_relevance}"),
+        });
+    }
+    Ok(())
+}
+
+/// Parse `source=N` pairs separated by commas (`"destini=3,locally=3"`).
+///
+/// Sources are lower-cased; every threshold must be at least 1.
+fn parse_source_thresholds(var: &str, raw: &str) -> Result<BTreeMap<String, u32>, ConfigError> {
+    let invalid = |reason: String| ConfigError::InvalidEnvVar {
+        var: var.to_string(),
+        reason,
+    };
+
+    let mut thresholds = BTreeMap::new();
+    for pair in raw.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (source, value) = pair
+            .split_once('=')
+            .ok_or_else(|| invalid(format!("expected source=N, got '{pair}'")))?;
+        let source = source.trim().to_ascii_lowercase();
+        let value = value
+            .trim()
+            .parse::<u32>()
+            .map_err(|e| invalid(format!("'{pair}': {e}")))?;
+        if source.is_empty() || value == 0 {
+            return Err(invalid(format!(
+                "'{pair}': source must be non-empty and N at least 1"
+            )));
+        }
+        thresholds.insert(source, value);
+    }
+    Ok(thresholds)
+}
+
 /// Parse a string into an `Environment` variant.
 fn parse_environment(s: &str) -> Result<Environment, ConfigError> {
     match s.to_ascii_lowercase().as_str() {
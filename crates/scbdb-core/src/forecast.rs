@@ -0,0 +1,256 @@
+//! Trend forecasting for weekly store counts.
+//!
+//! Two deliberately simple models: an ordinary least-squares line, and Holt's
+//! linear exponential smoothing (level + trend) with parameters picked by a
+//! grid search on one-step-ahead error. Both produce point forecasts with
+//! approximate 95% prediction intervals. [`detect_growth_change`] compares
+//! the recent growth rate against the earlier history — a sudden step up is
+//! usually the first sign of a new distributor deal.
+
+use serde::Serialize;
+
+/// Two-sided 95% normal quantile.
+const Z_95: f64 = 1.96;
+
+/// Fewest weekly observations a forecast is fitted on.
+pub const MIN_FORECAST_POINTS: usize = 4;
+
+/// Smallest change in weekly growth (stores/week) reported as significant.
+const MIN_RATE_CHANGE: f64 = 0.5;
+
+/// Smallest t-statistic for a growth change to count as significant.
+const MIN_CHANGE_T: f64 = 2.0;
+
+/// Trend model used for a forecast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ForecastModel {
+    Linear,
+    Holt,
+}
+
+impl ForecastModel {
+    /// Parse `linear` or `holt` (case-insensitive).
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "linear" => Some(Self::Linear),
+            "holt" => Some(Self::Holt),
+            _ => None,
+        }
+    }
+}
+
+/// One forecast step.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ForecastPoint {
+    /// Steps (weeks) past the last observation, starting at 1.
+    pub step: u32,
+    pub value: f64,
+    /// Lower 95% bound, never below zero.
+    pub lower: f64,
+    pub upper: f64,
+}
+
+/// A fitted model and its forecast.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Forecast {
+    pub model: ForecastModel,
+    /// Fitted growth per step at the end of the history.
+    pub trend: f64,
+    pub points: Vec<ForecastPoint>,
+}
+
+/// Ordinary least-squares fit of `y` against `0..n`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearFit {
+    pub intercept: f64,
+    pub slope: f64,
+    /// Residual standard deviation.
+    pub residual_sd: f64,
+    /// Standard error of the slope.
+    pub slope_se: f64,
+    n: usize,
+    x_mean: f64,
+    sxx: f64,
+}
+
+/// Fit a least-squares line; `None` for fewer than two points.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn fit_linear(series: &[f64]) -> Option<LinearFit> {
+    let n = series.len();
+    if n < 2 {
+        return None;
+    }
+    let nf = n as f64;
+    let x_mean = (nf - 1.0) / 2.0;
+    let y_mean = series.iter().sum::<f64>() / nf;
+    let (mut sxx, mut sxy) = (0.0, 0.0);
+    for (i, y) in series.iter().enumerate() {
+        let dx = i as f64 - x_mean;
+        sxx += dx * dx;
+        sxy += dx * (y - y_mean);
+    }
+    let slope = sxy / sxx;
+    let intercept = y_mean - slope * x_mean;
+    let sse: f64 = series
+        .iter()
+        .enumerate()
+        .map(|(i, y)| (y - (intercept + slope * i as f64)).powi(2))
+        .sum();
+    let residual_sd = if n > 2 {
+        (sse / (nf - 2.0)).sqrt()
+    } else {
+        0.0
+    };
+    Some(LinearFit {
+        intercept,
+        slope,
+        residual_sd,
+        slope_se: residual_sd / sxx.sqrt(),
+        n,
+        x_mean,
+        sxx,
+    })
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn forecast_linear(series: &[f64], horizon: u32) -> Option<Forecast> {
+    let fit = fit_linear(series)?;
+    let nf = fit.n as f64;
+    let points = (1..=horizon)
+        .map(|step| {
+            let x = nf - 1.0 + f64::from(step);
+            let value = fit.intercept + fit.slope * x;
+            let se = fit.residual_sd * (1.0 + 1.0 / nf + (x - fit.x_mean).powi(2) / fit.sxx).sqrt();
+            point(step, value, Z_95 * se)
+        })
+        .collect();
+    Some(Forecast {
+        model: ForecastModel::Linear,
+        trend: fit.slope,
+        points,
+    })
+}
+
+/// Final level, trend and one-step-ahead squared error of a Holt fit.
+fn holt_pass(series: &[f64], alpha: f64, beta: f64) -> (f64, f64, f64) {
+    let mut level = series[0];
+    let mut trend = series[1] - series[0];
+    let mut sse = 0.0;
+    for y in &series[1..] {
+        let predicted = level + trend;
+        sse += (y - predicted).powi(2);
+        let next_level = alpha * y + (1.0 - alpha) * predicted;
+        trend = beta * (next_level - level) + (1.0 - beta) * trend;
+        level = next_level;
+    }
+    (level, trend, sse)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn forecast_holt(series: &[f64], horizon: u32) -> Option<Forecast> {
+    if series.len() < 3 {
+        return None;
+    }
+    let mut best: Option<(f64, f64, (f64, f64, f64))> = None;
+    for a in 1..=9 {
+        for b in 1..=10 {
+            let (alpha, beta) = (f64::from(a) / 10.0, f64::from(b) / 20.0);
+            let fit = holt_pass(series, alpha, beta);
+            if best.is_none_or(|(_, _, (_, _, sse))| fit.2 < sse) {
+                best = Some((alpha, beta, fit));
+            }
+        }
+    }
+    let (alpha, beta, (level, trend, sse)) = best?;
+    let sigma = (sse / (series.len() - 1) as f64).sqrt();
+
+    let mut spread = 0.0;
+    let points = (1..=horizon)
+        .map(|step| {
+            // Var(h) = sigma^2 * (1 + sum_{j<h} alpha^2 (1 + j beta)^2)
+            if step > 1 {
+                spread += (alpha * (1.0 + f64::from(step - 1) * beta)).powi(2);
+            }
+            let value = level + f64::from(step) * trend;
+            point(step, value, Z_95 * sigma * (1.0 + spread).sqrt())
+        })
+        .collect();
+    Some(Forecast {
+        model: ForecastModel::Holt,
+        trend,
+        points,
+    })
+}
+
+fn point(step: u32, value: f64, half_width: f64) -> ForecastPoint {
+    ForecastPoint {
+        step,
+        value: value.max(0.0),
+        lower: (value - half_width).max(0.0),
+        upper: (value + half_width).max(0.0),
+    }
+}
+
+/// Forecast `horizon` steps past the end of `series`.
+///
+/// Returns `None` when the series is shorter than [`MIN_FORECAST_POINTS`].
+#[must_use]
+pub fn forecast(series: &[f64], model: ForecastModel, horizon: u32) -> Option<Forecast> {
+    if series.len() < MIN_FORECAST_POINTS {
+        return None;
+    }
+    match model {
+        ForecastModel::Linear => forecast_linear(series, horizon),
+        ForecastModel::Holt => forecast_holt(series, horizon),
+    }
+}
+
+/// Change in growth rate between the earlier and the recent part of a series.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct GrowthChange {
+    /// Least-squares growth per step before the recent window.
+    pub prior_rate: f64,
+    /// Least-squares growth per step within the recent window.
+    pub recent_rate: f64,
+    /// `recent_rate - prior_rate`.
+    pub rate_change: f64,
+    /// `rate_change` over its standard error; `None` when both fits are exact.
+    pub t_stat: Option<f64>,
+    /// The change is at least 0.5 steps/step and, when the fits are noisy,
+    /// at least two standard errors.
+    pub significant: bool,
+}
+
+/// Compare the growth rate over the last `recent` points with the rate before.
+///
+/// The windows share their boundary point so a step change at the boundary
+/// is attributed to the recent window. Needs at least three points on each
+/// side.
+#[must_use]
+pub fn detect_growth_change(series: &[f64], recent: usize) -> Option<GrowthChange> {
+    if recent < 3 || series.len() < recent + 3 {
+        return None;
+    }
+    let split = series.len() - recent;
+    let prior = fit_linear(&series[..split])?;
+    let latest = fit_linear(&series[split - 1..])?;
+    let rate_change = latest.slope - prior.slope;
+    let se = prior.slope_se.hypot(latest.slope_se);
+    let t_stat = (se > f64::EPSILON).then(|| rate_change / se);
+    let significant =
+        rate_change.abs() >= MIN_RATE_CHANGE && t_stat.is_none_or(|t| t.abs() >= MIN_CHANGE_T);
+    Some(GrowthChange {
+        prior_rate: prior.slope,
+        recent_rate: latest.slope,
+        rate_change,
+        t_stat,
+        significant,
+    })
+}
+
+#[cfg(test)]
+#[path = "forecast_test.rs"]
+mod tests;
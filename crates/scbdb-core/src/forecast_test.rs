@@ -0,0 +1,82 @@
+use super::*;
+
+fn line(n: u32, start: f64, rate: f64) -> Vec<f64> {
+    (0..n).map(|i| start + rate * f64::from(i)).collect()
+}
+
+#[test]
+fn linear_fit_recovers_exact_line() {
+    let fit = fit_linear(&line(10, 5.0, 2.0)).expect("fit");
+    assert!((fit.slope - 2.0).abs() < 1e-9);
+    assert!((fit.intercept - 5.0).abs() < 1e-9);
+    assert!(fit.residual_sd < 1e-9);
+    assert!(fit_linear(&[1.0]).is_none());
+}
+
+#[test]
+fn forecasts_extend_the_trend_with_widening_intervals() {
+    let series = [10.0, 12.0, 13.0, 15.0, 16.0, 18.0, 20.0, 21.0, 23.0, 24.0];
+    for model in [ForecastModel::Linear, ForecastModel::Holt] {
+        let forecast = forecast(&series, model, 8).expect("forecast");
+        assert_eq!(forecast.model, model);
+        assert_eq!(forecast.points.len(), 8);
+        assert!(forecast.trend > 1.0 && forecast.trend < 2.0, "{forecast:?}");
+
+        let first = &forecast.points[0];
+        let last = &forecast.points[7];
+        assert_eq!((first.step, last.step), (1, 8));
+        assert!(
+            first.value > 24.0 && last.value > first.value,
+            "{forecast:?}"
+        );
+        assert!(first.lower <= first.value && first.value <= first.upper);
+        assert!(last.upper - last.lower > first.upper - first.lower);
+    }
+}
+
+#[test]
+fn forecast_needs_minimum_history_and_never_goes_negative() {
+    assert!(forecast(&[1.0, 2.0, 3.0], ForecastModel::Linear, 4).is_none());
+
+    let shrinking = forecast(&line(6, 5.0, -1.0), ForecastModel::Linear, 12).expect("forecast");
+    assert!(shrinking
+        .points
+        .iter()
+        .all(|p| p.value >= 0.0 && p.lower >= 0.0));
+}
+
+#[test]
+fn model_parse_is_case_insensitive() {
+    assert_eq!(ForecastModel::parse("HOLT"), Some(ForecastModel::Holt));
+    assert_eq!(
+        ForecastModel::parse(" linear "),
+        Some(ForecastModel::Linear)
+    );
+    assert_eq!(ForecastModel::parse("arima"), None);
+}
+
+#[test]
+fn growth_change_flags_a_step_up_in_rate() {
+    // Flat-ish for twelve weeks, then +4 stores a week.
+    let mut series = vec![
+        20.0, 21.0, 20.0, 21.0, 21.0, 22.0, 21.0, 22.0, 22.0, 21.0, 22.0, 22.0,
+    ];
+    let last = *series.last().expect("non-empty");
+    series.extend((1..=6).map(|i| last + 4.0 * f64::from(i)));
+
+    let change = detect_growth_change(&series, 6).expect("change");
+    assert!(change.recent_rate > 3.0, "{change:?}");
+    assert!(change.prior_rate.abs() < 0.5, "{change:?}");
+    assert!(change.significant, "{change:?}");
+}
+
+#[test]
+fn growth_change_ignores_steady_growth_and_short_series() {
+    let steady = [
+        10.0, 11.0, 13.0, 14.0, 15.0, 16.0, 18.0, 19.0, 20.0, 21.0, 23.0, 24.0,
+    ];
+    let change = detect_growth_change(&steady, 6).expect("change");
+    assert!(!change.significant, "{change:?}");
+
+    assert!(detect_growth_change(&steady[..8], 6).is_none());
+}
@@ -1,12 +1,41 @@
+pub mod address;
 pub mod app_config;
+pub mod bill_alerts;
+pub mod bill_diff;
+pub mod bill_topics;
 pub mod brands;
+pub mod compliance;
 pub mod config;
+pub mod forecast;
 pub mod products;
+pub mod regulatory_exposure;
+pub mod state_rules;
 
 pub use app_config::{AppConfig, Environment};
+pub use bill_alerts::{
+    alerts_subject, render_alerts_text, BillAlert, BillAlertEvent, DEFAULT_NOTIFY_MIN_RELEVANCE,
+};
+pub use bill_diff::{
+    diff_bill_texts, split_sections, BillSection, BillTextDiff, DiffOp, DiffSpan, SectionChange,
+    SectionDiff,
+};
+pub use bill_topics::{
+    load_bill_topics, BillDocument, BillRelevance, BillTopicsFile, TopicConfig, TopicMatch,
+    TopicRule,
+};
 pub use brands::{load_brands, BrandConfig, BrandsFile, Relationship};
+pub use compliance::{parse_pack_count, thc_mg_for, Severity};
 pub use config::{load_app_config, load_app_config_from_env};
+pub use forecast::{
+    detect_growth_change, fit_linear, forecast, Forecast, ForecastModel, ForecastPoint,
+    GrowthChange, LinearFit, MIN_FORECAST_POINTS,
+};
 pub use products::{NormalizedImage, NormalizedProduct, NormalizedVariant};
+pub use regulatory_exposure::{
+    bill_pressure, stage_weight, state_exposure, topic_weight, FEDERAL_JURISDICTION,
+    PENDING_ACTIVITY_DAYS,
+};
+pub use state_rules::{normalize_jurisdiction, RuleKind, RETAIL_CHANNELS};
 
 #[derive(Debug, thiserror::Error)]
 pub enum ConfigError {
@@ -26,6 +55,16 @@ pub enum ConfigError {
     #[error("failed to parse brands file: {0}")]
     BrandsFileParse(#[source] serde_yaml::Error),
 
+    #[error("failed to read bill topics file {path}: {source}")]
+    BillTopicsFileIo {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to parse bill topics file: {0}")]
+    BillTopicsFileParse(#[source] serde_yaml::Error),
+
     #[error("validation error: {0}")]
     Validation(String),
 }
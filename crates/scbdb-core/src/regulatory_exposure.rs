@@ -0,0 +1,74 @@
+//! Regulatory exposure of a brand's store footprint to pending bills.
+//!
+//! Each pending bill puts pressure on its state: its stage weight (how close
+//! it is to becoming law) × its relevance score × the heaviest
+//! `exposure_weight` among its topics. A state's pressure is the sum over its
+//! bills plus federal bills, which apply everywhere, saturated into a 0–100
+//! [`state_exposure`]. A brand's exposure is the average of its states'
+//! exposure weighted by the share of its active stores in each.
+
+use crate::BillTopicsFile;
+
+/// Jurisdiction of federal bills, which count in every state.
+pub const FEDERAL_JURISDICTION: &str = "US";
+
+/// Bills without recorded action for this many days are treated as dead.
+pub const PENDING_ACTIVITY_DAYS: i64 = 365;
+
+/// Topic weight of bills not tagged with any topic.
+pub const UNTAGGED_TOPIC_WEIGHT: f64 = 0.5;
+
+/// Relevance assumed for bills not yet scored against the taxonomy.
+const UNSCORED_RELEVANCE: f64 = 50.0;
+
+/// How close a bill at `status` is to becoming law, or `None` once it is no
+/// longer pending (passed, vetoed, failed).
+#[must_use]
+pub fn stage_weight(status: &str) -> Option<f64> {
+    match status {
+        "introduced" => Some(0.3),
+        "engrossed" => Some(0.6),
+        "enrolled" => Some(0.9),
+        _ => None,
+    }
+}
+
+/// Heaviest `exposure_weight` among a bill's topics, or
+/// [`UNTAGGED_TOPIC_WEIGHT`] when it has none the taxonomy knows.
+#[must_use]
+pub fn topic_weight(taxonomy: &BillTopicsFile, topics: &[String]) -> f64 {
+    taxonomy
+        .topics
+        .iter()
+        .filter(|t| topics.contains(&t.slug))
+        .map(|t| t.exposure_weight)
+        .reduce(f64::max)
+        .unwrap_or(UNTAGGED_TOPIC_WEIGHT)
+}
+
+/// Pressure a bill puts on its state, or `None` when it is not pending.
+#[must_use]
+pub fn bill_pressure(status: &str, relevance_score: Option<f64>, topic_weight: f64) -> Option<f64> {
+    let relevance = relevance_score
+        .unwrap_or(UNSCORED_RELEVANCE)
+        .clamp(0.0, 100.0)
+        / 100.0;
+    stage_weight(status).map(|stage| stage * relevance * topic_weight)
+}
+
+/// A state's 0–100 exposure from the summed pressure of its pending bills.
+///
+/// One enrolled, fully relevant bill on a weight-1 topic scores about 59;
+/// further bills add less and less.
+#[must_use]
+pub fn state_exposure(pressure: f64) -> f64 {
+    if pressure <= 0.0 {
+        0.0
+    } else {
+        100.0 * (1.0 - (-pressure).exp())
+    }
+}
+
+#[cfg(test)]
+#[path = "regulatory_exposure_test.rs"]
+mod tests;
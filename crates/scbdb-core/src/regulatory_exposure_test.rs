@@ -0,0 +1,51 @@
+use std::path::Path;
+
+use super::*;
+
+fn taxonomy() -> BillTopicsFile {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("..")
+        .join("config")
+        .join("bill_topics.yaml");
+    crate::load_bill_topics(&path).expect("failed to load bill_topics.yaml")
+}
+
+fn topics(slugs: &[&str]) -> Vec<String> {
+    slugs.iter().map(|s| (*s).to_string()).collect()
+}
+
+#[test]
+fn only_pending_bills_put_pressure_on_a_state() {
+    assert!(stage_weight("enrolled") > stage_weight("engrossed"));
+    assert!(stage_weight("engrossed") > stage_weight("introduced"));
+    for status in ["passed", "vetoed", "failed", "unknown(9)"] {
+        assert_eq!(bill_pressure(status, Some(100.0), 1.0), None, "{status}");
+    }
+    let pressure = bill_pressure("enrolled", Some(50.0), 1.0).unwrap();
+    assert!((pressure - 0.45).abs() < 1e-9, "{pressure}");
+    let unscored = bill_pressure("enrolled", None, 1.0).unwrap();
+    assert!((unscored - pressure).abs() < 1e-9);
+}
+
+#[test]
+fn topic_weight_takes_the_heaviest_known_topic() {
+    let taxonomy = taxonomy();
+    let caps = topic_weight(&taxonomy, &topics(&["thc_serving_caps"]));
+    let age = topic_weight(&taxonomy, &topics(&["age_limits"]));
+    assert!(caps > age);
+    let both = topic_weight(&taxonomy, &topics(&["age_limits", "thc_serving_caps"]));
+    assert!((both - caps).abs() < 1e-9);
+    let untagged = topic_weight(&taxonomy, &topics(&["not_a_topic"]));
+    assert!((untagged - UNTAGGED_TOPIC_WEIGHT).abs() < 1e-9);
+}
+
+#[test]
+fn state_exposure_saturates_below_100() {
+    assert!(state_exposure(0.0).abs() < 1e-9);
+    let one = state_exposure(0.9);
+    let two = state_exposure(1.8);
+    assert!((one - 59.343).abs() < 0.01, "{one}");
+    assert!(two > one && two - one < one);
+    assert!(state_exposure(50.0) <= 100.0);
+}
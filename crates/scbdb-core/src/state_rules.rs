@@ -0,0 +1,149 @@
+//! Kinds and validation of the state hemp-beverage rules curated in the
+//! `state_rules` registry.
+//!
+//! Every rule row holds one [`RuleKind`] for one jurisdiction over an
+//! effective-date range. Numeric kinds carry a `value` in the kind's
+//! [`unit`](RuleKind::unit); [`RuleKind::RetailChannels`] carries a list of
+//! [`RETAIL_CHANNELS`] instead.
+
+use serde::{Deserialize, Serialize};
+
+/// Retail channels a state may allow hemp beverages to be sold through: the
+/// `store_locations.retail_channel` values plus `online`.
+pub const RETAIL_CHANNELS: &[&str] = &[
+    "liquor",
+    "grocery",
+    "convenience",
+    "smoke_vape",
+    "on_premise",
+    "dispensary",
+    "online",
+];
+
+/// Normalizes a user-supplied rule jurisdiction to its two-letter state code.
+///
+/// # Errors
+///
+/// Returns a message when `value` is not two ASCII letters.
+pub fn normalize_jurisdiction(value: &str) -> Result<String, String> {
+    let jurisdiction = value.trim().to_uppercase();
+    if jurisdiction.len() == 2 && jurisdiction.chars().all(|c| c.is_ascii_alphabetic()) {
+        Ok(jurisdiction)
+    } else {
+        Err(format!(
+            "jurisdiction must be a two-letter state code, got '{}'",
+            value.trim()
+        ))
+    }
+}
+
+/// What a state rule regulates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleKind {
+    MaxThcMgPerServing,
+    MaxThcMgPerContainer,
+    MinAge,
+    RetailChannels,
+    TaxRate,
+}
+
+impl RuleKind {
+    /// Every kind, in comparison-matrix column order.
+    pub const ALL: [RuleKind; 5] = [
+        RuleKind::MaxThcMgPerServing,
+        RuleKind::MaxThcMgPerContainer,
+        RuleKind::MinAge,
+        RuleKind::RetailChannels,
+        RuleKind::TaxRate,
+    ];
+
+    /// The `rule_kind` value stored in the database.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RuleKind::MaxThcMgPerServing => "max_thc_mg_per_serving",
+            RuleKind::MaxThcMgPerContainer => "max_thc_mg_per_container",
+            RuleKind::MinAge => "min_age",
+            RuleKind::RetailChannels => "retail_channels",
+            RuleKind::TaxRate => "tax_rate",
+        }
+    }
+
+    /// Parses a stored or user-supplied `rule_kind`.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<RuleKind> {
+        RuleKind::ALL.into_iter().find(|k| k.as_str() == value)
+    }
+
+    /// Unit of the rule's `value`; `None` for channel lists.
+    #[must_use]
+    pub fn unit(self) -> Option<&'static str> {
+        match self {
+            RuleKind::MaxThcMgPerServing | RuleKind::MaxThcMgPerContainer => Some("mg"),
+            RuleKind::MinAge => Some("years"),
+            RuleKind::RetailChannels => None,
+            RuleKind::TaxRate => Some("percent"),
+        }
+    }
+
+    /// Checks that a rule of this kind carries the right kind of value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message describing the first problem found.
+    pub fn validate(self, value: Option<f64>, channels: &[String]) -> Result<(), String> {
+        if self == RuleKind::RetailChannels {
+            if value.is_some() {
+                return Err("retail_channels rules take channels, not a value".to_string());
+            }
+            if channels.is_empty() {
+                return Err("retail_channels rules need at least one channel".to_string());
+            }
+            if let Some(unknown) = channels
+                .iter()
+                .find(|c| !RETAIL_CHANNELS.contains(&c.as_str()))
+            {
+                return Err(format!(
+                    "unknown channel '{unknown}'; expected one of {}",
+                    RETAIL_CHANNELS.join(", ")
+                ));
+            }
+            return Ok(());
+        }
+
+        if !channels.is_empty() {
+            return Err(format!(
+                "{} rules take a value, not channels",
+                self.as_str()
+            ));
+        }
+        let Some(value) = value else {
+            return Err(format!("{} rules need a value", self.as_str()));
+        };
+        let valid = match self {
+            RuleKind::MinAge => (1.0..=99.0).contains(&value) && value.fract() == 0.0,
+            RuleKind::TaxRate => (0.0..=100.0).contains(&value),
+            _ => value.is_finite() && value >= 0.0,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(match self {
+                RuleKind::MinAge => format!("min_age must be a whole number of years, got {value}"),
+                RuleKind::TaxRate => format!("tax_rate must be a percentage 0–100, got {value}"),
+                _ => format!("{} must be zero or more mg, got {value}", self.as_str()),
+            })
+        }
+    }
+}
+
+impl std::fmt::Display for RuleKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+#[path = "state_rules_test.rs"]
+mod tests;
@@ -0,0 +1,53 @@
+use super::*;
+
+fn channels(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| (*v).to_string()).collect()
+}
+
+#[test]
+fn kinds_round_trip_through_their_stored_names() {
+    for kind in RuleKind::ALL {
+        assert_eq!(RuleKind::parse(kind.as_str()), Some(kind));
+    }
+    assert_eq!(RuleKind::parse("max_abv"), None);
+}
+
+#[test]
+fn numeric_kinds_need_a_value_in_range() {
+    assert!(RuleKind::MaxThcMgPerServing
+        .validate(Some(5.0), &[])
+        .is_ok());
+    assert!(RuleKind::MaxThcMgPerServing.validate(None, &[]).is_err());
+    assert!(RuleKind::MaxThcMgPerContainer
+        .validate(Some(-1.0), &[])
+        .is_err());
+    assert!(RuleKind::MinAge.validate(Some(21.0), &[]).is_ok());
+    assert!(RuleKind::MinAge.validate(Some(20.5), &[]).is_err());
+    assert!(RuleKind::TaxRate.validate(Some(10.5), &[]).is_ok());
+    assert!(RuleKind::TaxRate.validate(Some(150.0), &[]).is_err());
+    assert!(RuleKind::TaxRate
+        .validate(Some(5.0), &channels(&["grocery"]))
+        .is_err());
+}
+
+#[test]
+fn retail_channels_need_known_channels_and_no_value() {
+    let kind = RuleKind::RetailChannels;
+    assert!(kind
+        .validate(None, &channels(&["grocery", "liquor"]))
+        .is_ok());
+    assert!(kind.validate(None, &[]).is_err());
+    assert!(kind.validate(Some(1.0), &channels(&["grocery"])).is_err());
+    let err = kind
+        .validate(None, &channels(&["vending_machine"]))
+        .unwrap_err();
+    assert!(err.contains("vending_machine"), "{err}");
+}
+
+#[test]
+fn jurisdictions_normalize_to_two_letter_codes() {
+    assert_eq!(normalize_jurisdiction(" sc ").unwrap(), "SC");
+    assert!(normalize_jurisdiction("S.C.").is_err());
+    assert!(normalize_jurisdiction("South Carolina").is_err());
+    assert!(normalize_jurisdiction("S1").is_err());
+}
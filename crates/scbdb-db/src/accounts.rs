@@ -0,0 +1,365 @@
+//! Our own retailer accounts (`accounts`), imported from the distributor ERP,
+//! and reports matching them against locator data in `store_locations`.
+//!
+//! Accounts and locator rows are matched on `address_key`, the normalized
+//! street address plus five-digit ZIP from
+//! [`scbdb_core::address::address_match_key`].
+
+use std::collections::HashMap;
+
+use sqlx::PgPool;
+
+use crate::DbError;
+
+/// One account row from an ERP export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewAccount {
+    /// ERP account identifier; re-importing the same number updates the row.
+    pub account_number: String,
+    pub name: String,
+    pub address_line1: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub zip: Option<String>,
+}
+
+/// Outcome of [`import_accounts`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AccountImportSummary {
+    pub inserted: u64,
+    pub updated: u64,
+    /// Accounts missing from the file that were marked inactive.
+    pub deactivated: u64,
+    /// Imported accounts with no usable street address + ZIP to match on.
+    pub unmatchable: u64,
+    /// Active accounts that match at least one active store location.
+    pub matched: i64,
+}
+
+/// Upsert accounts by `account_number` in one transaction.
+///
+/// A number repeated within `accounts` keeps its last occurrence. With
+/// `deactivate_missing`, active accounts absent from `accounts` are marked
+/// inactive (use when importing a full ERP export). Store locations collected
+/// before address keys existed are backfilled first so the returned
+/// `matched` count covers the whole footprint.
+///
+/// # Errors
+///
+/// Returns [`DbError::EmptyAccountImport`] when `deactivate_missing` is set
+/// but `accounts` is empty, since an empty or header-only export would
+/// otherwise deactivate every account, or [`DbError::Sqlx`] if any statement
+/// fails. Nothing is written in either case.
+pub async fn import_accounts(
+    pool: &PgPool,
+    accounts: &[NewAccount],
+    deactivate_missing: bool,
+) -> Result<AccountImportSummary, DbError> {
+    if deactivate_missing && accounts.is_empty() {
+        return Err(DbError::EmptyAccountImport);
+    }
+
+    let mut latest: HashMap<&str, &NewAccount> = HashMap::with_capacity(accounts.len());
+    let mut numbers: Vec<&str> = Vec::with_capacity(accounts.len());
+    for account in accounts {
+        if latest
+            .insert(account.account_number.as_str(), account)
+            .is_none()
+        {
+            numbers.push(account.account_number.as_str());
+        }
+    }
+
+    let mut names = Vec::with_capacity(numbers.len());
+    let mut address_line1s = Vec::with_capacity(numbers.len());
+    let mut cities = Vec::with_capacity(numbers.len());
+    let mut states = Vec::with_capacity(numbers.len());
+    let mut zips = Vec::with_capacity(numbers.len());
+    let mut address_keys = Vec::with_capacity(numbers.len());
+    for number in &numbers {
+        let account = latest[number];
+        names.push(account.name.as_str());
+        address_line1s.push(account.address_line1.as_deref());
+        cities.push(account.city.as_deref());
+        states.push(account.state.as_deref());
+        zips.push(account.zip.as_deref());
+        address_keys.push(scbdb_core::address::address_match_key(
+            account.address_line1.as_deref(),
+            account.zip.as_deref(),
+        ));
+    }
+
+    let mut tx = pool.begin().await?;
+    let mut summary = AccountImportSummary {
+        unmatchable: address_keys.iter().filter(|k| k.is_none()).count() as u64,
+        ..AccountImportSummary::default()
+    };
+
+    if !numbers.is_empty() {
+        let is_new: Vec<bool> = sqlx::query_scalar(
+            "INSERT INTO accounts \
+                 (account_number, name, address_line1, city, state, zip, address_key) \
+             SELECT * FROM UNNEST(\
+                 $1::text[], $2::text[], $3::text[], $4::text[], $5::text[], $6::text[], \
+                 $7::text[]) \
+             ON CONFLICT (account_number) DO UPDATE SET \
+                 name = EXCLUDED.name, \
+                 address_line1 = EXCLUDED.address_line1, \
+                 city = EXCLUDED.city, \
+                 state = EXCLUDED.state, \
+                 zip = EXCLUDED.zip, \
+                 address_key = EXCLUDED.address_key, \
+                 is_active = TRUE, \
+                 last_imported_at = NOW(), \
+                 updated_at = NOW() \
+             RETURNING (xmax = 0)",
+        )
+        .bind(&numbers)
+        .bind(&names)
+        .bind(&address_line1s)
+        .bind(&cities)
+        .bind(&states)
+        .bind(&zips)
+        .bind(&address_keys)
+        .fetch_all(&mut *tx)
+        .await?;
+        summary.inserted = is_new.iter().filter(|&&n| n).count() as u64;
+        summary.updated = is_new.len() as u64 - summary.inserted;
+    }
+
+    if deactivate_missing {
+        summary.deactivated = sqlx::query(
+            "UPDATE accounts SET is_active = FALSE, updated_at = NOW() \
+             WHERE is_active = TRUE AND account_number <> ALL($1::text[])",
+        )
+        .bind(&numbers)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+    }
+
+    backfill_location_address_keys(&mut tx).await?;
+
+    summary.matched = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM accounts a \
+         WHERE a.is_active = TRUE \
+           AND EXISTS (\
+               SELECT 1 FROM store_locations sl \
+               WHERE sl.is_active = TRUE AND sl.address_key = a.address_key\
+           )",
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(summary)
+}
+
+/// Compute `address_key` for store locations stored before the column existed.
+async fn backfill_location_address_keys(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> Result<u64, sqlx::Error> {
+    let rows: Vec<(i64, Option<String>, Option<String>)> = sqlx::query_as(
+        "SELECT id, address_line1, zip FROM store_locations \
+         WHERE address_key IS NULL AND address_line1 IS NOT NULL AND zip IS NOT NULL",
+    )
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let mut ids = Vec::new();
+    let mut keys = Vec::new();
+    for (id, address_line1, zip) in rows {
+        if let Some(key) =
+            scbdb_core::address::address_match_key(address_line1.as_deref(), zip.as_deref())
+        {
+            ids.push(id);
+            keys.push(key);
+        }
+    }
+    if ids.is_empty() {
+        return Ok(0);
+    }
+
+    let updated = sqlx::query(
+        "UPDATE store_locations sl SET address_key = u.address_key \
+         FROM UNNEST($1::bigint[], $2::text[]) AS u(id, address_key) \
+         WHERE sl.id = u.id",
+    )
+    .bind(&ids)
+    .bind(&keys)
+    .execute(&mut **tx)
+    .await?
+    .rows_affected();
+    Ok(updated)
+}
+
+/// Filters for [`list_account_competitor_presence`].
+#[derive(Debug, Clone, Default)]
+pub struct AccountPresenceFilters<'a> {
+    pub state: Option<&'a str>,
+    /// Competitor brands to report; empty means every competitor brand.
+    pub competitor_slugs: &'a [String],
+    /// Only return accounts where at least one competitor is listed.
+    pub with_competitors_only: bool,
+}
+
+/// One of our accounts with the brands store locators list at its address.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct AccountCompetitorPresenceRow {
+    pub account_id: i64,
+    pub account_number: String,
+    pub name: String,
+    pub address_line1: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub zip: Option<String>,
+    /// Active locator rows (across all brands) at the account's address.
+    pub matched_store_count: i64,
+    pub competitor_brand_count: i64,
+    pub competitor_brands: Vec<String>,
+    /// Portfolio brands whose own locators list this account.
+    pub portfolio_brands: Vec<String>,
+}
+
+/// Active accounts with the competitor and portfolio brands that store
+/// locators show at the same address, most competitor brands first.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_account_competitor_presence(
+    pool: &PgPool,
+    filters: &AccountPresenceFilters<'_>,
+    limit: i64,
+) -> Result<Vec<AccountCompetitorPresenceRow>, sqlx::Error> {
+    sqlx::query_as::<_, AccountCompetitorPresenceRow>(
+        "SELECT \
+             a.id AS account_id, \
+             a.account_number, \
+             a.name, \
+             a.address_line1, \
+             a.city, \
+             a.state, \
+             a.zip, \
+             COUNT(b.id) AS matched_store_count, \
+             COUNT(DISTINCT b.slug) FILTER (\
+                 WHERE b.relationship = 'competitor' \
+                   AND (cardinality($2::TEXT[]) = 0 OR b.slug = ANY($2))\
+             ) AS competitor_brand_count, \
+             COALESCE(ARRAY_AGG(DISTINCT b.slug ORDER BY b.slug) FILTER (\
+                 WHERE b.relationship = 'competitor' \
+                   AND (cardinality($2::TEXT[]) = 0 OR b.slug = ANY($2))\
+             ), '{}') AS competitor_brands, \
+             COALESCE(ARRAY_AGG(DISTINCT b.slug ORDER BY b.slug) \
+                 FILTER (WHERE b.relationship = 'portfolio'), '{}') AS portfolio_brands \
+         FROM accounts a \
+         LEFT JOIN store_locations sl \
+             ON sl.address_key = a.address_key AND sl.is_active = TRUE \
+         LEFT JOIN brands b \
+             ON b.id = sl.brand_id AND b.is_active = TRUE AND b.deleted_at IS NULL \
+         WHERE a.is_active = TRUE \
+           AND ($1::TEXT IS NULL OR a.state = $1) \
+         GROUP BY a.id \
+         HAVING NOT $3 OR COUNT(DISTINCT b.slug) FILTER (\
+             WHERE b.relationship = 'competitor' \
+               AND (cardinality($2::TEXT[]) = 0 OR b.slug = ANY($2))\
+         ) > 0 \
+         ORDER BY competitor_brand_count DESC, a.name ASC, a.id ASC \
+         LIMIT $4",
+    )
+    .bind(filters.state)
+    .bind(filters.competitor_slugs)
+    .bind(filters.with_competitors_only)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// Filters for [`list_competitor_stores_without_account`].
+#[derive(Debug, Clone, Default)]
+pub struct ProspectFilters<'a> {
+    pub state: Option<&'a str>,
+    /// Retail channel, e.g. `liquor`.
+    pub channel: Option<&'a str>,
+    /// Competitor brands to consider; empty means every competitor brand.
+    pub competitor_slugs: &'a [String],
+}
+
+/// A storefront carrying competitor brands that is not one of our accounts.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ProspectStoreRow {
+    pub address_key: String,
+    pub name: String,
+    pub address_line1: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub zip: Option<String>,
+    pub retail_chain: Option<String>,
+    pub retail_channel: Option<String>,
+    pub competitor_brand_count: i64,
+    pub competitor_brands: Vec<String>,
+    /// Portfolio brands listed there anyway (e.g. via another distributor).
+    pub portfolio_brands: Vec<String>,
+}
+
+/// Storefronts where locators list competitor brands and no active account
+/// shares the address, most competitor brands first.
+///
+/// Locator rows from different brands at the same `address_key` collapse to
+/// one storefront; rows without a street address and ZIP are skipped.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_competitor_stores_without_account(
+    pool: &PgPool,
+    filters: &ProspectFilters<'_>,
+    limit: i64,
+) -> Result<Vec<ProspectStoreRow>, sqlx::Error> {
+    sqlx::query_as::<_, ProspectStoreRow>(
+        "SELECT \
+             sl.address_key, \
+             MIN(sl.name) AS name, \
+             MIN(sl.address_line1) AS address_line1, \
+             MIN(sl.city) AS city, \
+             MIN(sl.state) AS state, \
+             MIN(sl.zip) AS zip, \
+             MIN(sl.retail_chain) AS retail_chain, \
+             MIN(sl.retail_channel) AS retail_channel, \
+             COUNT(DISTINCT b.slug) FILTER (\
+                 WHERE b.relationship = 'competitor' \
+                   AND (cardinality($3::TEXT[]) = 0 OR b.slug = ANY($3))\
+             ) AS competitor_brand_count, \
+             COALESCE(ARRAY_AGG(DISTINCT b.slug ORDER BY b.slug) FILTER (\
+                 WHERE b.relationship = 'competitor' \
+                   AND (cardinality($3::TEXT[]) = 0 OR b.slug = ANY($3))\
+             ), '{}') AS competitor_brands, \
+             COALESCE(ARRAY_AGG(DISTINCT b.slug ORDER BY b.slug) \
+                 FILTER (WHERE b.relationship = 'portfolio'), '{}') AS portfolio_brands \
+         FROM store_locations sl \
+         JOIN brands b ON b.id = sl.brand_id \
+         WHERE sl.is_active = TRUE \
+           AND sl.address_key IS NOT NULL \
+           AND b.is_active = TRUE \
+           AND b.deleted_at IS NULL \
+           AND ($1::TEXT IS NULL OR sl.state = $1) \
+           AND ($2::TEXT IS NULL OR sl.retail_channel = $2) \
+           AND NOT EXISTS (\
+               SELECT 1 FROM accounts a \
+               WHERE a.is_active = TRUE AND a.address_key = sl.address_key\
+           ) \
+         GROUP BY sl.address_key \
+         HAVING COUNT(DISTINCT b.slug) FILTER (\
+             WHERE b.relationship = 'competitor' \
+               AND (cardinality($3::TEXT[]) = 0 OR b.slug = ANY($3))\
+         ) > 0 \
+         ORDER BY competitor_brand_count DESC, sl.address_key ASC \
+         LIMIT $4",
+    )
+    .bind(filters.state)
+    .bind(filters.channel)
+    .bind(filters.competitor_slugs)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
@@ -0,0 +1,209 @@
+//! Bill change detection for notifications, against the per-channel, per-bill
+//! cursors in `bill_alert_cursors`.
+//!
+//! A bill is tracked while it is watched or its relevance score reaches the
+//! notification threshold. Its cursor holds the status and newest event last
+//! reported to a channel; [`list_bill_changes`] returns tracked bills that
+//! moved past it, or have none yet, and [`advance_bill_alert_cursors`] records
+//! them as reported. [`start_bill_alert_channel`] gives a channel's first run
+//! a cursor on every tracked bill without reporting them, so it does not
+//! replay every bill's history.
+
+use std::collections::HashMap;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{BillEventRow, DbError};
+
+/// A tracked bill whose status or events changed since it was last reported.
+#[derive(Debug, Clone)]
+pub struct BillChange {
+    pub bill_id: i64,
+    pub public_id: Uuid,
+    pub jurisdiction: String,
+    pub bill_number: String,
+    pub title: String,
+    /// Status last reported; `None` for a bill tracked since the last run.
+    pub previous_status: Option<String>,
+    pub status: String,
+    pub relevance_score: Option<f64>,
+    pub watched: bool,
+    pub source_url: Option<String>,
+    /// Events recorded since, oldest first; every event of a new bill.
+    pub events: Vec<BillEventRow>,
+    /// Newest event id, reported or not; the cursor's next position.
+    pub last_event_id: i64,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct ChangedBillRow {
+    bill_id: i64,
+    public_id: Uuid,
+    jurisdiction: String,
+    bill_number: String,
+    title: String,
+    previous_status: Option<String>,
+    status: String,
+    relevance_score: Option<f64>,
+    watched: bool,
+    source_url: Option<String>,
+    last_event_id: i64,
+}
+
+/// Tracked bills that changed since `notifier`'s cursor, or that it has no
+/// cursor for, by jurisdiction and number. Empty until the channel is
+/// started with [`start_bill_alert_channel`].
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if a query fails.
+pub async fn list_bill_changes(
+    pool: &PgPool,
+    notifier: &str,
+    min_relevance: f64,
+) -> Result<Vec<BillChange>, DbError> {
+    let rows = sqlx::query_as::<_, ChangedBillRow>(
+        "SELECT b.id AS bill_id, b.public_id, b.jurisdiction, b.bill_number, b.title, \
+                c.status AS previous_status, b.status, b.relevance_score, \
+                (w.bill_id IS NOT NULL) AS watched, b.source_url, \
+                GREATEST(COALESCE(c.last_event_id, 0), \
+                         COALESCE((SELECT MAX(e.id) FROM bill_events e \
+                                   WHERE e.bill_id = b.id), 0)) AS last_event_id \
+         FROM bills b \
+         JOIN bill_alert_channels ch ON ch.notifier = $1 \
+         LEFT JOIN bill_alert_cursors c ON c.notifier = ch.notifier AND c.bill_id = b.id \
+         LEFT JOIN watched_bills w ON w.bill_id = b.id \
+         WHERE b.deleted_at IS NULL \
+           AND (w.bill_id IS NOT NULL OR b.relevance_score >= $2) \
+           AND (c.bill_id IS NULL \
+                OR b.status <> c.status \
+                OR EXISTS (SELECT 1 FROM bill_events e \
+                           WHERE e.bill_id = b.id AND e.id > c.last_event_id)) \
+         ORDER BY b.jurisdiction, b.bill_number",
+    )
+    .bind(notifier)
+    .bind(min_relevance)
+    .fetch_all(pool)
+    .await?;
+
+    let bill_ids: Vec<i64> = rows.iter().map(|r| r.bill_id).collect();
+    let events = sqlx::query_as::<_, BillEventRow>(
+        "SELECT e.id, e.bill_id, e.event_date, e.event_type, e.chamber, e.description, \
+                e.source_url, e.created_at \
+         FROM bill_events e \
+         LEFT JOIN bill_alert_cursors c ON c.notifier = $2 AND c.bill_id = e.bill_id \
+         WHERE e.bill_id = ANY($1::BIGINT[]) AND e.id > COALESCE(c.last_event_id, 0) \
+         ORDER BY e.bill_id, e.event_date NULLS LAST, e.id",
+    )
+    .bind(&bill_ids)
+    .bind(notifier)
+    .fetch_all(pool)
+    .await?;
+
+    let mut events_by_bill: HashMap<i64, Vec<BillEventRow>> = HashMap::new();
+    for event in events {
+        events_by_bill.entry(event.bill_id).or_default().push(event);
+    }
+
+    Ok(rows
+        .into_iter()
+        .map(|row| BillChange {
+            events: events_by_bill.remove(&row.bill_id).unwrap_or_default(),
+            bill_id: row.bill_id,
+            public_id: row.public_id,
+            jurisdiction: row.jurisdiction,
+            bill_number: row.bill_number,
+            title: row.title,
+            previous_status: row.previous_status,
+            status: row.status,
+            relevance_score: row.relevance_score,
+            watched: row.watched,
+            source_url: row.source_url,
+            last_event_id: row.last_event_id,
+        })
+        .collect())
+}
+
+/// Moves `notifier`'s cursors of `changes` to the status and events they
+/// reported, creating those of new bills.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the upsert fails.
+pub async fn advance_bill_alert_cursors(
+    pool: &PgPool,
+    notifier: &str,
+    changes: &[BillChange],
+) -> Result<u64, DbError> {
+    let bill_ids: Vec<i64> = changes.iter().map(|c| c.bill_id).collect();
+    let statuses: Vec<&str> = changes.iter().map(|c| c.status.as_str()).collect();
+    let event_ids: Vec<i64> = changes.iter().map(|c| c.last_event_id).collect();
+
+    let updated = sqlx::query(
+        "INSERT INTO bill_alert_cursors (notifier, bill_id, status, last_event_id) \
+         SELECT $1, u.bill_id, u.status, u.last_event_id \
+         FROM UNNEST($2::BIGINT[], $3::TEXT[], $4::BIGINT[]) AS u(bill_id, status, last_event_id) \
+         ON CONFLICT (notifier, bill_id) DO UPDATE SET \
+             status        = EXCLUDED.status, \
+             last_event_id = GREATEST(bill_alert_cursors.last_event_id, \
+                                      EXCLUDED.last_event_id), \
+             updated_at    = NOW()",
+    )
+    .bind(notifier)
+    .bind(&bill_ids)
+    .bind(&statuses)
+    .bind(&event_ids)
+    .execute(pool)
+    .await?
+    .rows_affected();
+
+    Ok(updated)
+}
+
+/// Starts `notifier` on its first run: records the channel and gives it a
+/// cursor at the current state of every tracked bill, without reporting them.
+///
+/// Returns how many bills were baselined, or `None` when the channel had
+/// already started.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if a statement fails; nothing is written then.
+pub async fn start_bill_alert_channel(
+    pool: &PgPool,
+    notifier: &str,
+    min_relevance: f64,
+) -> Result<Option<u64>, DbError> {
+    let mut tx = pool.begin().await?;
+
+    let started = sqlx::query(
+        "INSERT INTO bill_alert_channels (notifier) VALUES ($1) \
+         ON CONFLICT (notifier) DO NOTHING",
+    )
+    .bind(notifier)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+    if started == 0 {
+        return Ok(None);
+    }
+
+    let inserted = sqlx::query(
+        "INSERT INTO bill_alert_cursors (notifier, bill_id, status, last_event_id) \
+         SELECT $1, b.id, b.status, \
+                COALESCE((SELECT MAX(e.id) FROM bill_events e WHERE e.bill_id = b.id), 0) \
+         FROM bills b \
+         LEFT JOIN watched_bills w ON w.bill_id = b.id \
+         WHERE b.deleted_at IS NULL \
+           AND (w.bill_id IS NOT NULL OR b.relevance_score >= $2)",
+    )
+    .bind(notifier)
+    .bind(min_relevance)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    tx.commit().await?;
+    Ok(Some(inserted))
+}
@@ -0,0 +1,167 @@
+//! Database operations for the `bill_hearings` table.
+
+use chrono::{NaiveDate, NaiveTime};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::DbError;
+
+/// A hearing or calendar entry to store.
+#[derive(Debug, Clone, Copy)]
+pub struct NewBillHearing<'a> {
+    pub hearing_date: NaiveDate,
+    pub hearing_time: Option<NaiveTime>,
+    pub event_type: &'a str,
+    pub committee: Option<&'a str>,
+    pub chamber: Option<&'a str>,
+    pub location: Option<&'a str>,
+    pub description: Option<&'a str>,
+}
+
+/// A row from the `bill_hearings` table.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct BillHearingRow {
+    pub id: i64,
+    pub bill_id: i64,
+    pub hearing_date: NaiveDate,
+    pub hearing_time: Option<NaiveTime>,
+    pub event_type: String,
+    pub committee: Option<String>,
+    pub chamber: Option<String>,
+    pub location: Option<String>,
+    pub description: Option<String>,
+}
+
+/// A scheduled hearing together with the bill it concerns.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UpcomingHearingRow {
+    pub hearing_date: NaiveDate,
+    pub hearing_time: Option<NaiveTime>,
+    pub event_type: String,
+    pub committee: Option<String>,
+    pub chamber: Option<String>,
+    pub location: Option<String>,
+    pub description: Option<String>,
+    pub bill_public_id: Uuid,
+    pub jurisdiction: String,
+    pub bill_number: String,
+    pub title: String,
+    pub status: String,
+    pub relevance_score: Option<f64>,
+}
+
+/// Filters for [`list_upcoming_hearings`]; `None` fields are ignored.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HearingFilters<'a> {
+    pub jurisdiction: Option<&'a str>,
+    /// Bill topic taxonomy slug.
+    pub topic: Option<&'a str>,
+    /// Minimum bill relevance score, 0–100.
+    pub min_relevance: Option<f64>,
+}
+
+/// Replaces the hearings and calendar entries of a bill.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if any statement fails; nothing is written in
+/// that case.
+pub async fn replace_bill_hearings(
+    pool: &PgPool,
+    bill_id: i64,
+    hearings: &[NewBillHearing<'_>],
+) -> Result<(), DbError> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM bill_hearings WHERE bill_id = $1")
+        .bind(bill_id)
+        .execute(&mut *tx)
+        .await?;
+
+    for hearing in hearings {
+        sqlx::query(
+            "INSERT INTO bill_hearings \
+                 (bill_id, hearing_date, hearing_time, event_type, committee, chamber, \
+                  location, description) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+        )
+        .bind(bill_id)
+        .bind(hearing.hearing_date)
+        .bind(hearing.hearing_time)
+        .bind(hearing.event_type)
+        .bind(hearing.committee)
+        .bind(hearing.chamber)
+        .bind(hearing.location)
+        .bind(hearing.description)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Returns the hearings of a non-deleted bill by its public UUID, soonest
+/// first.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_bill_hearings_by_public_id(
+    pool: &PgPool,
+    bill_public_id: Uuid,
+) -> Result<Vec<BillHearingRow>, DbError> {
+    let rows = sqlx::query_as::<_, BillHearingRow>(
+        "SELECT h.id, h.bill_id, h.hearing_date, h.hearing_time, h.event_type, h.committee, \
+                h.chamber, h.location, h.description \
+         FROM bill_hearings h \
+         JOIN bills b ON b.id = h.bill_id \
+         WHERE b.public_id = $1 AND b.deleted_at IS NULL \
+         ORDER BY h.hearing_date, h.hearing_time NULLS LAST, h.id",
+    )
+    .bind(bill_public_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Returns hearings of non-deleted bills dated between `from` and `to`
+/// (inclusive) that match `filters`, soonest first.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_upcoming_hearings(
+    pool: &PgPool,
+    from: NaiveDate,
+    to: NaiveDate,
+    filters: &HearingFilters<'_>,
+    limit: i64,
+) -> Result<Vec<UpcomingHearingRow>, DbError> {
+    let rows = sqlx::query_as::<_, UpcomingHearingRow>(
+        "SELECT h.hearing_date, h.hearing_time, h.event_type, h.committee, h.chamber, \
+                h.location, h.description, b.public_id AS bill_public_id, b.jurisdiction, \
+                b.bill_number, b.title, b.status, b.relevance_score \
+         FROM bill_hearings h \
+         JOIN bills b ON b.id = h.bill_id \
+         WHERE h.hearing_date BETWEEN $1 AND $2 \
+           AND b.deleted_at IS NULL \
+           AND ($3::TEXT IS NULL OR b.jurisdiction = $3) \
+           AND ($4::TEXT IS NULL OR EXISTS ( \
+                 SELECT 1 FROM bill_topics bt WHERE bt.bill_id = b.id AND bt.topic = $4)) \
+           AND ($5::DOUBLE PRECISION IS NULL OR b.relevance_score >= $5) \
+         ORDER BY h.hearing_date, h.hearing_time NULLS LAST, b.jurisdiction, b.bill_number \
+         LIMIT $6",
+    )
+    .bind(from)
+    .bind(to)
+    .bind(filters.jurisdiction)
+    .bind(filters.topic)
+    .bind(filters.min_relevance)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
@@ -18,9 +18,38 @@ pub struct BillTextRow {
     pub text_type: String,
     pub mime: String,
     pub legiscan_url: Option<String>,
+    /// Whether extracted plain text is stored for this version.
+    pub has_content: bool,
+    pub extraction_error: Option<String>,
+    pub extracted_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
 }
 
+/// One bill text version together with its extracted plain text.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct BillTextContentRow {
+    pub id: i64,
+    pub legiscan_text_id: i64,
+    pub text_date: Option<NaiveDate>,
+    pub text_type: String,
+    pub mime: String,
+    pub legiscan_url: Option<String>,
+    pub content: Option<String>,
+    pub extraction_error: Option<String>,
+    pub extracted_at: Option<DateTime<Utc>>,
+}
+
+/// A bill text version whose document has not been fetched and extracted.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PendingBillTextRow {
+    pub id: i64,
+    pub bill_id: i64,
+    pub legiscan_text_id: i64,
+    pub jurisdiction: String,
+    pub bill_number: String,
+    pub text_type: String,
+}
+
 /// Inserts a bill text entry, silently skipping duplicates.
 ///
 /// Deduplication is by `legiscan_text_id` (UNIQUE). Text entries from `LegiScan`
@@ -83,7 +112,7 @@ pub async fn get_bills_stored_hashes(
 }
 
 /// Returns all text entries for a bill identified by its public UUID,
-/// ordered by `text_date DESC NULLS LAST`.
+/// newest first (`text_date DESC NULLS LAST`, then `legiscan_text_id DESC`).
 ///
 /// # Errors
 ///
@@ -94,11 +123,12 @@ pub async fn list_bill_texts_by_public_id(
 ) -> Result<Vec<BillTextRow>, DbError> {
     let rows = sqlx::query_as::<_, BillTextRow>(
         "SELECT bt.id, bt.bill_id, bt.legiscan_text_id, bt.text_date, bt.text_type, \
-                bt.mime, bt.legiscan_url, bt.created_at \
+                bt.mime, bt.legiscan_url, (bt.content IS NOT NULL) AS has_content, \
+                bt.extraction_error, bt.extracted_at, bt.created_at \
          FROM bill_texts bt \
          JOIN bills b ON b.id = bt.bill_id \
          WHERE b.public_id = $1 AND b.deleted_at IS NULL \
-         ORDER BY bt.text_date DESC NULLS LAST",
+         ORDER BY bt.text_date DESC NULLS LAST, bt.legiscan_text_id DESC",
     )
     .bind(public_id)
     .fetch_all(pool)
@@ -106,3 +136,90 @@ pub async fn list_bill_texts_by_public_id(
 
     Ok(rows)
 }
+
+/// Returns text versions still waiting for extraction, oldest first.
+///
+/// With `retry_failed`, versions whose previous extraction failed are
+/// included again. `jurisdiction` narrows to one state.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_bill_texts_pending_extraction(
+    pool: &PgPool,
+    jurisdiction: Option<&str>,
+    retry_failed: bool,
+    limit: i64,
+) -> Result<Vec<PendingBillTextRow>, DbError> {
+    let rows = sqlx::query_as::<_, PendingBillTextRow>(
+        "SELECT bt.id, bt.bill_id, bt.legiscan_text_id, b.jurisdiction, b.bill_number, \
+                bt.text_type \
+         FROM bill_texts bt \
+         JOIN bills b ON b.id = bt.bill_id \
+         WHERE b.deleted_at IS NULL \
+           AND ($1::TEXT IS NULL OR b.jurisdiction = $1) \
+           AND (bt.extracted_at IS NULL OR ($2 AND bt.content IS NULL)) \
+         ORDER BY bt.id \
+         LIMIT $3",
+    )
+    .bind(jurisdiction)
+    .bind(retry_failed)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Records the outcome of extracting one text version: either the plain
+/// text or the reason extraction failed.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the update fails.
+pub async fn record_bill_text_extraction(
+    pool: &PgPool,
+    bill_text_id: i64,
+    content: Option<&str>,
+    extraction_error: Option<&str>,
+) -> Result<(), DbError> {
+    sqlx::query(
+        "UPDATE bill_texts \
+         SET content = $2, extraction_error = $3, extracted_at = NOW() \
+         WHERE id = $1",
+    )
+    .bind(bill_text_id)
+    .bind(content)
+    .bind(extraction_error)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Returns one text version of a bill, with its extracted content, by the
+/// bill's public UUID and the `LegiScan` text id.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn get_bill_text_content(
+    pool: &PgPool,
+    bill_public_id: Uuid,
+    legiscan_text_id: i64,
+) -> Result<Option<BillTextContentRow>, DbError> {
+    let row = sqlx::query_as::<_, BillTextContentRow>(
+        "SELECT bt.id, bt.legiscan_text_id, bt.text_date, bt.text_type, bt.mime, \
+                bt.legiscan_url, bt.content, bt.extraction_error, bt.extracted_at \
+         FROM bill_texts bt \
+         JOIN bills b ON b.id = bt.bill_id \
+         WHERE b.public_id = $1 AND b.deleted_at IS NULL \
+           AND bt.legiscan_text_id = $2",
+    )
+    .bind(bill_public_id)
+    .bind(legiscan_text_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row)
+}
@@ -0,0 +1,129 @@
+//! Database operations for bill topic tags and relevance scores.
+
+use std::collections::HashMap;
+
+use sqlx::PgPool;
+
+use crate::DbError;
+
+/// A row from the `bill_topics` table.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct BillTopicRow {
+    pub bill_id: i64,
+    pub topic: String,
+    pub score: f64,
+}
+
+/// The text a bill is scored on: title, summary and the newest extracted
+/// version of its full text.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct BillScoringRow {
+    pub id: i64,
+    pub title: String,
+    pub summary: Option<String>,
+    pub text: Option<String>,
+}
+
+/// Stores a bill's relevance score and replaces its topic tags.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if any statement fails; nothing is written in
+/// that case.
+pub async fn replace_bill_topics(
+    pool: &PgPool,
+    bill_id: i64,
+    relevance_score: f64,
+    topics: &[(&str, f64)],
+) -> Result<(), DbError> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        "UPDATE bills \
+         SET relevance_score = $2, relevance_scored_at = NOW() \
+         WHERE id = $1",
+    )
+    .bind(bill_id)
+    .bind(relevance_score)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query("DELETE FROM bill_topics WHERE bill_id = $1")
+        .bind(bill_id)
+        .execute(&mut *tx)
+        .await?;
+
+    let (slugs, scores): (Vec<&str>, Vec<f64>) = topics.iter().copied().unzip();
+    sqlx::query(
+        "INSERT INTO bill_topics (bill_id, topic, score) \
+         SELECT $1, t.topic, t.score \
+         FROM UNNEST($2::TEXT[], $3::DOUBLE PRECISION[]) AS t(topic, score)",
+    )
+    .bind(bill_id)
+    .bind(&slugs)
+    .bind(&scores)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Returns the topic tags of each bill in `bill_ids`, highest score first.
+///
+/// Bills without tags are omitted from the map.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_bill_topics_batch(
+    pool: &PgPool,
+    bill_ids: &[i64],
+) -> Result<HashMap<i64, Vec<BillTopicRow>>, DbError> {
+    let rows = sqlx::query_as::<_, BillTopicRow>(
+        "SELECT bill_id, topic, score \
+         FROM bill_topics \
+         WHERE bill_id = ANY($1::bigint[]) \
+         ORDER BY bill_id, score DESC, topic",
+    )
+    .bind(bill_ids)
+    .fetch_all(pool)
+    .await?;
+
+    let mut map: HashMap<i64, Vec<BillTopicRow>> = HashMap::new();
+    for row in rows {
+        map.entry(row.bill_id).or_default().push(row);
+    }
+    Ok(map)
+}
+
+/// Returns the scoring input for non-deleted bills, optionally narrowed to a
+/// jurisdiction and/or a set of bill ids.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_bills_for_scoring(
+    pool: &PgPool,
+    jurisdiction: Option<&str>,
+    bill_ids: Option<&[i64]>,
+) -> Result<Vec<BillScoringRow>, DbError> {
+    let rows = sqlx::query_as::<_, BillScoringRow>(
+        "SELECT b.id, b.title, b.summary, \
+                (SELECT bt.content FROM bill_texts bt \
+                 WHERE bt.bill_id = b.id AND bt.content IS NOT NULL \
+                 ORDER BY bt.text_date DESC NULLS LAST, bt.legiscan_text_id DESC \
+                 LIMIT 1) AS text \
+         FROM bills b \
+         WHERE b.deleted_at IS NULL \
+           AND ($1::TEXT IS NULL OR b.jurisdiction = $1) \
+           AND ($2::BIGINT[] IS NULL OR b.id = ANY($2)) \
+         ORDER BY b.id",
+    )
+    .bind(jurisdiction)
+    .bind(bill_ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
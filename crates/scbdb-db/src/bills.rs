@@ -23,6 +23,8 @@ pub struct BillRow {
     pub source_url: Option<String>,
     pub legiscan_bill_id: Option<i64>,
     pub legiscan_change_hash: Option<String>,
+    /// 0–100 topic-taxonomy relevance; `None` until scored.
+    pub relevance_score: Option<f64>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
@@ -93,6 +95,16 @@ pub async fn upsert_bill(
     Ok(id)
 }
 
+/// Filters for [`list_bills_filtered`]; `None` fields do not filter.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BillFilters<'a> {
+    pub jurisdiction: Option<&'a str>,
+    /// Only bills tagged with this taxonomy topic slug.
+    pub topic: Option<&'a str>,
+    /// Only bills scored at or above this relevance (0–100).
+    pub min_relevance: Option<f64>,
+}
+
 /// Returns non-deleted bills, optionally filtered by jurisdiction.
 ///
 /// Results are ordered by `last_action_date DESC NULLS LAST`, then
@@ -105,18 +117,46 @@ pub async fn list_bills(
     pool: &PgPool,
     jurisdiction: Option<&str>,
     limit: i64,
+) -> Result<Vec<BillRow>, DbError> {
+    list_bills_filtered(
+        pool,
+        &BillFilters {
+            jurisdiction,
+            ..BillFilters::default()
+        },
+        limit,
+    )
+    .await
+}
+
+/// Returns non-deleted bills matching `filters`, in the same order as
+/// [`list_bills`].
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_bills_filtered(
+    pool: &PgPool,
+    filters: &BillFilters<'_>,
+    limit: i64,
 ) -> Result<Vec<BillRow>, DbError> {
     let rows = sqlx::query_as::<_, BillRow>(
         "SELECT id, public_id, jurisdiction, session, bill_number, title, summary, \
                 status, status_date, introduced_date, last_action_date, source_url, \
-                legiscan_bill_id, legiscan_change_hash, created_at, updated_at, deleted_at \
-         FROM bills \
+                legiscan_bill_id, legiscan_change_hash, relevance_score, \
+                created_at, updated_at, deleted_at \
+         FROM bills b \
          WHERE deleted_at IS NULL \
            AND ($1::TEXT IS NULL OR jurisdiction = $1) \
+           AND ($2::TEXT IS NULL OR EXISTS ( \
+                 SELECT 1 FROM bill_topics bt WHERE bt.bill_id = b.id AND bt.topic = $2)) \
+           AND ($3::DOUBLE PRECISION IS NULL OR relevance_score >= $3) \
          ORDER BY last_action_date DESC NULLS LAST, status_date DESC NULLS LAST \
-         LIMIT $2",
+         LIMIT $4",
     )
-    .bind(jurisdiction)
+    .bind(filters.jurisdiction)
+    .bind(filters.topic)
+    .bind(filters.min_relevance)
     .bind(limit)
     .fetch_all(pool)
     .await?;
@@ -138,7 +178,8 @@ pub async fn get_bill_by_jurisdiction_number(
     let row = sqlx::query_as::<_, BillRow>(
         "SELECT id, public_id, jurisdiction, session, bill_number, title, summary, \
                 status, status_date, introduced_date, last_action_date, source_url, \
-                legiscan_bill_id, legiscan_change_hash, created_at, updated_at, deleted_at \
+                legiscan_bill_id, legiscan_change_hash, relevance_score, \
+                created_at, updated_at, deleted_at \
          FROM bills \
          WHERE jurisdiction = $1 AND bill_number = $2 AND deleted_at IS NULL",
     )
@@ -162,7 +203,8 @@ pub async fn get_bill_by_public_id(
     let row = sqlx::query_as::<_, BillRow>(
         "SELECT id, public_id, jurisdiction, session, bill_number, title, summary, \
                 status, status_date, introduced_date, last_action_date, source_url, \
-                legiscan_bill_id, legiscan_change_hash, created_at, updated_at, deleted_at \
+                legiscan_bill_id, legiscan_change_hash, relevance_score, \
+                created_at, updated_at, deleted_at \
          FROM bills \
          WHERE public_id = $1 AND deleted_at IS NULL",
     )
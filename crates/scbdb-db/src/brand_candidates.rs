@@ -0,0 +1,378 @@
+//! Review queue for brands discovered in text, locator and retailer sources.
+//!
+//! Collectors record each sighting of an unknown name as evidence against a
+//! candidate keyed by slug; an operator then promotes the candidate into
+//! `brands` or dismisses it. Names that already match a brand are ignored.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::brands::{insert_brand_row, BrandRow, NewBrand};
+use crate::DbError;
+
+/// Evidence sources accepted by `brand_candidate_evidence.source`.
+pub const BRAND_CANDIDATE_SOURCES: &[&str] =
+    &["sentiment", "brand_signal", "locator", "retailer_page"];
+
+/// One sighting of a candidate brand name.
+#[derive(Debug, Clone)]
+pub struct NewBrandCandidateEvidence<'a> {
+    pub name: &'a str,
+    /// One of [`BRAND_CANDIDATE_SOURCES`].
+    pub source: &'a str,
+    /// URL, signal id or location key; a sighting is recorded once per ref.
+    pub source_ref: &'a str,
+    pub context: Option<&'a str>,
+    pub observed_at: DateTime<Utc>,
+}
+
+/// What [`record_brand_candidate_evidence`] changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BrandCandidateRecordSummary {
+    pub candidates_created: u64,
+    pub evidence_added: u64,
+    /// Sightings of names that already match a brand.
+    pub known_brands_skipped: u64,
+}
+
+/// A candidate with its evidence rolled up.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct BrandCandidateRow {
+    pub id: i64,
+    pub public_id: Uuid,
+    pub name: String,
+    pub slug: String,
+    pub status: String,
+    /// Slug of the brand it was promoted to.
+    pub brand_slug: Option<String>,
+    pub review_note: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub first_seen_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    pub evidence_count: i64,
+    /// Distinct evidence sources, alphabetical.
+    pub sources: Vec<String>,
+    /// Up to three most recent evidence contexts.
+    pub sample_contexts: Vec<String>,
+}
+
+/// Record sightings of candidate brands, creating candidates as needed.
+///
+/// Names whose slug or lowercase name matches an existing brand (deleted
+/// ones included) are skipped, as are repeat sightings of the same
+/// `(source, source_ref)`. Dismissed candidates keep collecting evidence but
+/// stay dismissed.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if a query fails.
+pub async fn record_brand_candidate_evidence(
+    pool: &PgPool,
+    evidence: &[NewBrandCandidateEvidence<'_>],
+) -> Result<BrandCandidateRecordSummary, DbError> {
+    let known: Vec<(String, String)> = sqlx::query_as("SELECT slug, LOWER(name) FROM brands")
+        .fetch_all(pool)
+        .await?;
+    let known: HashSet<String> = known.into_iter().flat_map(|(s, n)| [s, n]).collect();
+
+    let mut summary = BrandCandidateRecordSummary::default();
+    let mut names = Vec::new();
+    let mut slugs = Vec::new();
+    let mut sources = Vec::new();
+    let mut refs = Vec::new();
+    let mut contexts = Vec::new();
+    let mut observed = Vec::new();
+    for item in evidence {
+        let slug = scbdb_core::brands::slug_from_name(item.name);
+        if slug.is_empty() || known.contains(&slug) || known.contains(&item.name.to_lowercase()) {
+            summary.known_brands_skipped += 1;
+            continue;
+        }
+        names.push(item.name);
+        slugs.push(slug);
+        sources.push(item.source);
+        refs.push(item.source_ref);
+        contexts.push(item.context);
+        observed.push(item.observed_at);
+    }
+    if slugs.is_empty() {
+        return Ok(summary);
+    }
+
+    let mut tx = pool.begin().await?;
+
+    // One row per slug, named after its first sighting in `evidence`.
+    let inserted: Vec<bool> = sqlx::query_scalar(
+        "INSERT INTO brand_candidates (name, slug, first_seen_at, last_seen_at) \
+         SELECT (ARRAY_AGG(u.name ORDER BY u.ord))[1], u.slug, \
+                MIN(u.observed_at), MAX(u.observed_at) \
+         FROM UNNEST($1::text[], $2::text[], $3::timestamptz[]) \
+              WITH ORDINALITY AS u(name, slug, observed_at, ord) \
+         GROUP BY u.slug \
+         ON CONFLICT (slug) DO UPDATE SET \
+           first_seen_at = LEAST(brand_candidates.first_seen_at, EXCLUDED.first_seen_at), \
+           last_seen_at = GREATEST(brand_candidates.last_seen_at, EXCLUDED.last_seen_at), \
+           updated_at = NOW() \
+         RETURNING (xmax = 0)",
+    )
+    .bind(&names)
+    .bind(&slugs)
+    .bind(&observed)
+    .fetch_all(&mut *tx)
+    .await?;
+    summary.candidates_created = inserted.iter().filter(|i| **i).count() as u64;
+
+    summary.evidence_added = sqlx::query(
+        "INSERT INTO brand_candidate_evidence \
+           (candidate_id, source, source_ref, context, observed_at) \
+         SELECT c.id, u.source, u.source_ref, u.context, u.observed_at \
+         FROM UNNEST($1::text[], $2::text[], $3::text[], $4::text[], $5::timestamptz[]) \
+              AS u(slug, source, source_ref, context, observed_at) \
+         JOIN brand_candidates c ON c.slug = u.slug \
+         ON CONFLICT (candidate_id, source, source_ref) DO NOTHING",
+    )
+    .bind(&slugs)
+    .bind(&sources)
+    .bind(&refs)
+    .bind(&contexts)
+    .bind(&observed)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    tx.commit().await?;
+    Ok(summary)
+}
+
+const CANDIDATE_SELECT: &str = "\
+    SELECT c.id, c.public_id, c.name, c.slug, c.status, b.slug AS brand_slug, \
+           c.review_note, c.reviewed_at, c.first_seen_at, c.last_seen_at, \
+           (SELECT COUNT(*) FROM brand_candidate_evidence e \
+             WHERE e.candidate_id = c.id) AS evidence_count, \
+           ARRAY(SELECT DISTINCT e.source FROM brand_candidate_evidence e \
+                  WHERE e.candidate_id = c.id ORDER BY e.source) AS sources, \
+           ARRAY(SELECT e.context FROM brand_candidate_evidence e \
+                  WHERE e.candidate_id = c.id AND e.context IS NOT NULL \
+                  ORDER BY e.observed_at DESC, e.id DESC LIMIT 3) AS sample_contexts \
+    FROM brand_candidates c \
+    LEFT JOIN brands b ON b.id = c.brand_id";
+
+/// List candidates with at least `min_evidence` sightings, best-evidenced
+/// first, optionally by status.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_brand_candidates(
+    pool: &PgPool,
+    status: Option<&str>,
+    min_evidence: i64,
+    limit: i64,
+) -> Result<Vec<BrandCandidateRow>, sqlx::Error> {
+    let sql = format!(
+        "SELECT * FROM ({CANDIDATE_SELECT} WHERE ($1::TEXT IS NULL OR c.status = $1)) ranked \
+         WHERE evidence_count >= $2 \
+         ORDER BY evidence_count DESC, last_seen_at DESC, id \
+         LIMIT $3"
+    );
+    sqlx::query_as::<_, BrandCandidateRow>(&sql)
+        .bind(status)
+        .bind(min_evidence)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+}
+
+/// Fetch one candidate by public id.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn get_brand_candidate(
+    pool: &PgPool,
+    public_id: Uuid,
+) -> Result<Option<BrandCandidateRow>, sqlx::Error> {
+    let sql = format!("{CANDIDATE_SELECT} WHERE c.public_id = $1");
+    sqlx::query_as::<_, BrandCandidateRow>(&sql)
+        .bind(public_id)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Why a candidate could not be closed: unknown, or no longer pending.
+async fn candidate_not_pending(
+    conn: &mut PgConnection,
+    public_id: Uuid,
+) -> Result<DbError, DbError> {
+    let status: Option<String> =
+        sqlx::query_scalar("SELECT status FROM brand_candidates WHERE public_id = $1")
+            .bind(public_id)
+            .fetch_optional(conn)
+            .await?;
+    Ok(match status {
+        None => DbError::NotFound,
+        Some(status) => DbError::BrandCandidateNotPending { public_id, status },
+    })
+}
+
+/// Close a pending candidate with `status`, linking `brand_id` if given.
+async fn review_brand_candidate(
+    conn: &mut PgConnection,
+    public_id: Uuid,
+    status: &str,
+    brand_id: Option<i64>,
+    note: Option<&str>,
+) -> Result<(), DbError> {
+    let updated = sqlx::query(
+        "UPDATE brand_candidates \
+         SET status = $2, brand_id = $3, review_note = $4, \
+             reviewed_at = NOW(), updated_at = NOW() \
+         WHERE public_id = $1 AND status = 'pending'",
+    )
+    .bind(public_id)
+    .bind(status)
+    .bind(brand_id)
+    .bind(note)
+    .execute(&mut *conn)
+    .await?
+    .rows_affected();
+    if updated == 0 {
+        return Err(candidate_not_pending(conn, public_id).await?);
+    }
+    Ok(())
+}
+
+/// Create `brand` from a pending candidate and mark the candidate promoted,
+/// in one transaction.
+///
+/// # Errors
+///
+/// Returns [`DbError::NotFound`] for an unknown id,
+/// [`DbError::BrandCandidateNotPending`] if it was already reviewed, or
+/// [`DbError::Sqlx`] if a statement fails (including a brand slug that is
+/// already taken).
+pub async fn promote_brand_candidate(
+    pool: &PgPool,
+    public_id: Uuid,
+    brand: &NewBrand<'_>,
+    note: Option<&str>,
+) -> Result<BrandRow, DbError> {
+    let mut tx = pool.begin().await?;
+    let pending: Option<i64> = sqlx::query_scalar(
+        "SELECT id FROM brand_candidates \
+         WHERE public_id = $1 AND status = 'pending' \
+         FOR UPDATE",
+    )
+    .bind(public_id)
+    .fetch_optional(&mut *tx)
+    .await?;
+    if pending.is_none() {
+        return Err(candidate_not_pending(&mut tx, public_id).await?);
+    }
+
+    let row = insert_brand_row(&mut tx, brand).await?;
+    review_brand_candidate(&mut tx, public_id, "promoted", Some(row.id), note).await?;
+    tx.commit().await?;
+    Ok(row)
+}
+
+/// Dismiss a pending candidate; later sightings no longer resurface it.
+///
+/// # Errors
+///
+/// Returns [`DbError::NotFound`] for an unknown id,
+/// [`DbError::BrandCandidateNotPending`] if it was already reviewed, or
+/// [`DbError::Sqlx`] if the update fails.
+pub async fn dismiss_brand_candidate(
+    pool: &PgPool,
+    public_id: Uuid,
+    note: Option<&str>,
+) -> Result<(), DbError> {
+    review_brand_candidate(
+        &mut *pool.acquire().await?,
+        public_id,
+        "dismissed",
+        None,
+        note,
+    )
+    .await
+}
+
+/// A piece of news or sentiment text to scan for brand names.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DiscoveryTextRow {
+    /// `brand_signal` or `sentiment`.
+    pub source: String,
+    pub source_ref: String,
+    pub text: String,
+    pub observed_at: DateTime<Utc>,
+}
+
+/// Brand signal titles/summaries and sentiment top-signal previews collected
+/// since `since`.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_discovery_texts(
+    pool: &PgPool,
+    since: DateTime<Utc>,
+) -> Result<Vec<DiscoveryTextRow>, sqlx::Error> {
+    sqlx::query_as::<_, DiscoveryTextRow>(
+        "SELECT 'brand_signal' AS source, \
+                COALESCE(bs.source_url, bs.public_id::TEXT) AS source_ref, \
+                CONCAT_WS('. ', bs.title, bs.summary) AS text, \
+                COALESCE(bs.published_at, bs.collected_at) AS observed_at \
+         FROM brand_signals bs \
+         WHERE bs.collected_at >= $1 \
+           AND (bs.title IS NOT NULL OR bs.summary IS NOT NULL) \
+         UNION ALL \
+         SELECT 'sentiment', \
+                COALESCE(sig->>'url', s.id::TEXT || ':' || ord::TEXT), \
+                sig->>'text_preview', \
+                s.captured_at \
+         FROM sentiment_snapshots s \
+         CROSS JOIN LATERAL jsonb_array_elements(\
+             CASE WHEN jsonb_typeof(s.metadata->'top_signals') = 'array' \
+                  THEN s.metadata->'top_signals' ELSE '[]'::jsonb END\
+         ) WITH ORDINALITY AS t(sig, ord) \
+         WHERE s.captured_at >= $1 AND sig->>'text_preview' IS NOT NULL \
+         ORDER BY observed_at",
+    )
+    .bind(since)
+    .fetch_all(pool)
+    .await
+}
+
+/// Raw provider payload of an active store from a multi-brand locator.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LocatorPayloadRow {
+    pub location_key: String,
+    pub store_name: String,
+    pub raw_data: serde_json::Value,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+/// Active stores scraped from multi-brand locators (`BeverageFinder`,
+/// `AskHoodie`) with their raw payloads.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_multi_brand_locator_payloads(
+    pool: &PgPool,
+) -> Result<Vec<LocatorPayloadRow>, sqlx::Error> {
+    sqlx::query_as::<_, LocatorPayloadRow>(
+        "SELECT location_key, name AS store_name, raw_data, last_seen_at \
+         FROM store_locations \
+         WHERE is_active = TRUE \
+           AND raw_data IS NOT NULL \
+           AND locator_source IN ('beveragefinder', 'askhoodie') \
+         ORDER BY id",
+    )
+    .fetch_all(pool)
+    .await
+}
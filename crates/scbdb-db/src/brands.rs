@@ -1,7 +1,7 @@
 //! Database operations for the `brands` table.
 
 use chrono::{DateTime, Utc};
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 
 use crate::DbError;
@@ -136,6 +136,20 @@ pub async fn list_brands_with_locator(pool: &PgPool) -> Result<Vec<BrandRow>, Db
     Ok(rows)
 }
 
+/// Fields of a brand to create.
+#[derive(Debug, Clone, Copy)]
+pub struct NewBrand<'a> {
+    pub name: &'a str,
+    pub slug: &'a str,
+    pub relationship: &'a str,
+    pub tier: i16,
+    pub domain: Option<&'a str>,
+    pub shop_url: Option<&'a str>,
+    pub store_locator_url: Option<&'a str>,
+    pub twitter_handle: Option<&'a str>,
+    pub notes: Option<&'a str>,
+}
+
 /// Creates a new brand row and returns the full inserted row.
 ///
 /// # Errors
@@ -154,7 +168,26 @@ pub async fn create_brand(
     twitter_handle: Option<&str>,
     notes: Option<&str>,
 ) -> Result<BrandRow, DbError> {
-    let row = sqlx::query_as::<_, BrandRow>(
+    let brand = NewBrand {
+        name,
+        slug,
+        relationship,
+        tier,
+        domain,
+        shop_url,
+        store_locator_url,
+        twitter_handle,
+        notes,
+    };
+    Ok(insert_brand_row(&mut *pool.acquire().await?, &brand).await?)
+}
+
+/// Insert `brand` on a caller's connection, typically inside its transaction.
+pub(crate) async fn insert_brand_row(
+    conn: &mut PgConnection,
+    brand: &NewBrand<'_>,
+) -> Result<BrandRow, sqlx::Error> {
+    sqlx::query_as::<_, BrandRow>(
         "INSERT INTO brands \
            (name, slug, relationship, tier, domain, shop_url, store_locator_url, \
             twitter_handle, notes, is_active) \
@@ -162,18 +195,17 @@ pub async fn create_brand(
          RETURNING id, public_id, name, slug, relationship, tier, domain, shop_url, logo_url, \
                    store_locator_url, notes, twitter_handle, is_active, created_at, updated_at, deleted_at",
     )
-    .bind(name)
-    .bind(slug)
-    .bind(relationship)
-    .bind(tier)
-    .bind(domain)
-    .bind(shop_url)
-    .bind(store_locator_url)
-    .bind(twitter_handle)
-    .bind(notes)
-    .fetch_one(pool)
-    .await?;
-    Ok(row)
+    .bind(brand.name)
+    .bind(brand.slug)
+    .bind(brand.relationship)
+    .bind(brand.tier)
+    .bind(brand.domain)
+    .bind(brand.shop_url)
+    .bind(brand.store_locator_url)
+    .bind(brand.twitter_handle)
+    .bind(brand.notes)
+    .fetch_one(conn)
+    .await
 }
 
 /// Updates core metadata fields for an existing brand.
@@ -0,0 +1,357 @@
+//! Product compliance against the `state_rules` registry.
+//!
+//! Joins variant THC doses with the per-serving and per-container caps in
+//! effect in each state, and the brand's stores in each state with the
+//! state's allowed retail channels. Severity follows whether the store
+//! locators show the product on shelf there; see
+//! [`scbdb_core::Severity::from_presence`].
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use scbdb_core::{parse_pack_count, thc_mg_for, RuleKind, Severity};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{DbError, StateRuleFilters, StateRuleRow};
+
+/// Filters for [`check_compliance`]; `None` fields are ignored.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ComplianceFilters<'a> {
+    pub brand_slug: Option<&'a str>,
+    pub jurisdiction: Option<&'a str>,
+}
+
+/// A product variant or a brand's stores breaking a state rule.
+#[derive(Debug, Clone)]
+pub struct ComplianceViolation {
+    pub severity: Severity,
+    pub jurisdiction: String,
+    pub rule_kind: RuleKind,
+    /// `state_rules.public_id` of the rule broken.
+    pub rule_public_id: Uuid,
+    /// Number of the bill that enacted the rule, when linked.
+    pub bill_number: Option<String>,
+    pub brand_slug: String,
+    pub brand_name: String,
+    /// Set for THC cap violations.
+    pub product_public_id: Option<Uuid>,
+    pub product_name: Option<String>,
+    pub variant_public_id: Option<Uuid>,
+    pub variant_title: Option<String>,
+    pub size_value: Option<f64>,
+    pub size_unit: Option<String>,
+    /// Cans in the retail package; 1 unless the title names a multi-pack.
+    pub pack_count: Option<u32>,
+    /// THC cap in mg.
+    pub limit_mg: Option<f64>,
+    /// THC in mg checked against `limit_mg`.
+    pub actual_mg: Option<f64>,
+    /// Set for retail channel violations: the channel the state does not allow.
+    pub retail_channel: Option<String>,
+    /// Active stores in the state the locators link to the product.
+    pub product_store_count: i64,
+    /// Active stores in the state carrying the brand (for channel violations,
+    /// those in `retail_channel`).
+    pub brand_store_count: i64,
+    pub message: String,
+}
+
+/// Output of [`check_compliance`].
+#[derive(Debug, Clone)]
+pub struct ComplianceReport {
+    /// Most severe first, then by state, brand and product.
+    pub violations: Vec<ComplianceViolation>,
+    /// Variants with a THC dose, checked against every state's caps.
+    pub variants_checked: usize,
+    /// Variants without a THC dose, which cannot be checked.
+    pub variants_without_dosage: usize,
+    /// States with at least one rule in effect.
+    pub states_with_rules: usize,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct CheckedVariantRow {
+    brand_id: i64,
+    brand_slug: String,
+    brand_name: String,
+    product_id: i64,
+    product_public_id: Uuid,
+    product_name: String,
+    variant_public_id: Uuid,
+    variant_title: Option<String>,
+    dosage_mg: Option<f64>,
+    size_value: Option<f64>,
+    size_unit: Option<String>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct BrandChannelRow {
+    brand_id: i64,
+    brand_slug: String,
+    brand_name: String,
+    state: String,
+    retail_channel: Option<String>,
+    store_count: i64,
+}
+
+/// Checks live products and store footprints against the state rules in
+/// effect on `as_of`.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if a query fails.
+pub async fn check_compliance(
+    pool: &PgPool,
+    filters: &ComplianceFilters<'_>,
+    as_of: NaiveDate,
+) -> Result<ComplianceReport, DbError> {
+    let rules = crate::list_state_rules(
+        pool,
+        &StateRuleFilters {
+            jurisdiction: filters.jurisdiction,
+            as_of: Some(as_of),
+            ..StateRuleFilters::default()
+        },
+    )
+    .await?;
+
+    let variants = list_checked_variants(pool, filters.brand_slug).await?;
+    let product_stores = count_product_stores(pool, filters.brand_slug).await?;
+    let brand_channels = list_brand_channels(pool, filters.brand_slug).await?;
+
+    let mut brand_stores: HashMap<(i64, &str), i64> = HashMap::new();
+    for row in &brand_channels {
+        *brand_stores.entry((row.brand_id, &row.state)).or_default() += row.store_count;
+    }
+
+    let mut violations = Vec::new();
+    let mut states: Vec<&str> = rules.iter().map(|r| r.jurisdiction.as_str()).collect();
+    states.dedup();
+
+    for rule in &rules {
+        let Some(kind) = RuleKind::parse(&rule.rule_kind) else {
+            continue;
+        };
+        let state = rule.jurisdiction.as_str();
+        match (kind, rule.value) {
+            (RuleKind::MaxThcMgPerServing | RuleKind::MaxThcMgPerContainer, Some(limit)) => {
+                for variant in &variants {
+                    let Some(dosage_mg) = variant.dosage_mg else {
+                        continue;
+                    };
+                    let pack_count = variant
+                        .variant_title
+                        .as_deref()
+                        .and_then(parse_pack_count)
+                        .unwrap_or(1);
+                    let Some(actual) = thc_mg_for(kind, dosage_mg, pack_count) else {
+                        continue;
+                    };
+                    if actual <= limit {
+                        continue;
+                    }
+                    let product_count = product_stores
+                        .get(&(variant.product_id, rule.jurisdiction.clone()))
+                        .copied()
+                        .unwrap_or(0);
+                    let brand_count = brand_stores
+                        .get(&(variant.brand_id, state))
+                        .copied()
+                        .unwrap_or(0);
+                    violations.push(cap_violation(
+                        rule,
+                        kind,
+                        variant,
+                        pack_count,
+                        limit,
+                        actual,
+                        (product_count, brand_count),
+                    ));
+                }
+            }
+            (RuleKind::RetailChannels, _) => {
+                for row in brand_channels.iter().filter(|r| r.state == state) {
+                    let Some(channel) = row.retail_channel.as_deref() else {
+                        continue;
+                    };
+                    if rule.channels.iter().any(|c| c == channel) {
+                        continue;
+                    }
+                    violations.push(channel_violation(rule, row, channel));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    violations.sort_by(|a, b| {
+        (a.severity, &a.jurisdiction, &a.brand_slug, &a.product_name)
+            .cmp(&(b.severity, &b.jurisdiction, &b.brand_slug, &b.product_name))
+            .then_with(|| a.rule_kind.as_str().cmp(b.rule_kind.as_str()))
+    });
+
+    let variants_without_dosage = variants.iter().filter(|v| v.dosage_mg.is_none()).count();
+    Ok(ComplianceReport {
+        violations,
+        variants_checked: variants.len() - variants_without_dosage,
+        variants_without_dosage,
+        states_with_rules: states.len(),
+    })
+}
+
+/// Variants of live products of active brands.
+async fn list_checked_variants(
+    pool: &PgPool,
+    brand_slug: Option<&str>,
+) -> Result<Vec<CheckedVariantRow>, sqlx::Error> {
+    sqlx::query_as::<_, CheckedVariantRow>(
+        "SELECT b.id AS brand_id, b.slug AS brand_slug, b.name AS brand_name, \
+                p.id AS product_id, p.public_id AS product_public_id, p.name AS product_name, \
+                v.public_id AS variant_public_id, v.title AS variant_title, \
+                v.dosage_mg::FLOAT8 AS dosage_mg, v.size_value::FLOAT8 AS size_value, \
+                v.size_unit \
+         FROM product_variants v \
+         JOIN products p ON p.id = v.product_id \
+         JOIN brands b ON b.id = p.brand_id \
+         WHERE p.deleted_at IS NULL AND b.deleted_at IS NULL AND b.is_active = TRUE \
+           AND ($1::TEXT IS NULL OR b.slug = $1) \
+         ORDER BY b.slug, p.name, v.id",
+    )
+    .bind(brand_slug)
+    .fetch_all(pool)
+    .await
+}
+
+/// Active stores the locators link to each product, per state.
+async fn count_product_stores(
+    pool: &PgPool,
+    brand_slug: Option<&str>,
+) -> Result<HashMap<(i64, String), i64>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, (i64, String, i64)>(
+        "SELECT slp.product_id, sl.state, COUNT(DISTINCT sl.id) \
+         FROM store_location_products slp \
+         JOIN store_locations sl ON sl.id = slp.store_location_id \
+         JOIN brands b ON b.id = sl.brand_id \
+         WHERE sl.is_active = TRUE AND sl.state IS NOT NULL \
+           AND slp.product_id IS NOT NULL \
+           AND ($1::TEXT IS NULL OR b.slug = $1) \
+         GROUP BY slp.product_id, sl.state",
+    )
+    .bind(brand_slug)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(product_id, state, count)| ((product_id, state), count))
+        .collect())
+}
+
+/// Active stores of each brand, per state and retail channel.
+async fn list_brand_channels(
+    pool: &PgPool,
+    brand_slug: Option<&str>,
+) -> Result<Vec<BrandChannelRow>, sqlx::Error> {
+    sqlx::query_as::<_, BrandChannelRow>(
+        "SELECT b.id AS brand_id, b.slug AS brand_slug, b.name AS brand_name, \
+                sl.state, sl.retail_channel, COUNT(*) AS store_count \
+         FROM store_locations sl \
+         JOIN brands b ON b.id = sl.brand_id \
+         WHERE sl.is_active = TRUE AND sl.state IS NOT NULL \
+           AND b.deleted_at IS NULL AND b.is_active = TRUE \
+           AND ($1::TEXT IS NULL OR b.slug = $1) \
+         GROUP BY b.id, b.slug, b.name, sl.state, sl.retail_channel \
+         ORDER BY b.slug, sl.state, sl.retail_channel",
+    )
+    .bind(brand_slug)
+    .fetch_all(pool)
+    .await
+}
+
+fn cap_violation(
+    rule: &StateRuleRow,
+    kind: RuleKind,
+    variant: &CheckedVariantRow,
+    pack_count: u32,
+    limit: f64,
+    actual: f64,
+    (product_count, brand_count): (i64, i64),
+) -> ComplianceViolation {
+    let state = &rule.jurisdiction;
+    let severity = Severity::from_presence(product_count, brand_count);
+    let basis = match kind {
+        RuleKind::MaxThcMgPerContainer if pack_count > 1 => format!("per {pack_count}-pack"),
+        RuleKind::MaxThcMgPerContainer => "per container".to_string(),
+        _ => "per serving".to_string(),
+    };
+    let presence = match severity {
+        Severity::Critical => format!("the locator shows it in {product_count} {state} stores"),
+        Severity::Warning => format!("{} is in {brand_count} {state} stores", variant.brand_name),
+        Severity::Info => format!("{} has no known {state} stores", variant.brand_name),
+    };
+    let message = format!(
+        "{} {}: {actual} mg THC {basis} exceeds the {limit} mg cap in {state}; {presence}",
+        variant.brand_name, variant.product_name
+    );
+
+    ComplianceViolation {
+        severity,
+        jurisdiction: state.clone(),
+        rule_kind: kind,
+        rule_public_id: rule.public_id,
+        bill_number: rule.bill_number.clone(),
+        brand_slug: variant.brand_slug.clone(),
+        brand_name: variant.brand_name.clone(),
+        product_public_id: Some(variant.product_public_id),
+        product_name: Some(variant.product_name.clone()),
+        variant_public_id: Some(variant.variant_public_id),
+        variant_title: variant.variant_title.clone(),
+        size_value: variant.size_value,
+        size_unit: variant.size_unit.clone(),
+        pack_count: Some(pack_count),
+        limit_mg: Some(limit),
+        actual_mg: Some(actual),
+        retail_channel: None,
+        product_store_count: product_count,
+        brand_store_count: brand_count,
+        message,
+    }
+}
+
+fn channel_violation(
+    rule: &StateRuleRow,
+    row: &BrandChannelRow,
+    channel: &str,
+) -> ComplianceViolation {
+    let state = &rule.jurisdiction;
+    let message = format!(
+        "{} is in {} {state} {channel} stores; {state} allows {}",
+        row.brand_name,
+        row.store_count,
+        rule.channels.join(", ")
+    );
+
+    ComplianceViolation {
+        severity: Severity::Critical,
+        jurisdiction: state.clone(),
+        rule_kind: RuleKind::RetailChannels,
+        rule_public_id: rule.public_id,
+        bill_number: rule.bill_number.clone(),
+        brand_slug: row.brand_slug.clone(),
+        brand_name: row.brand_name.clone(),
+        product_public_id: None,
+        product_name: None,
+        variant_public_id: None,
+        variant_title: None,
+        size_value: None,
+        size_unit: None,
+        pack_count: None,
+        limit_mg: None,
+        actual_mg: None,
+        retail_channel: Some(channel.to_string()),
+        product_store_count: 0,
+        brand_store_count: row.store_count,
+        message,
+    }
+}
@@ -0,0 +1,189 @@
+//! County / DMA reference data (`geo_counties`, `geo_dmas`, `geo_zip_counties`)
+//! and assignment of store locations to a county and DMA.
+
+use sqlx::{PgConnection, PgPool};
+
+use crate::DbError;
+
+/// One county row from the reference CSV.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewGeoCounty {
+    /// Five-digit state+county FIPS code.
+    pub fips: String,
+    pub name: String,
+    /// Two-letter USPS state code.
+    pub state: String,
+    pub population: Option<i32>,
+    pub dma_code: Option<String>,
+    pub dma_name: Option<String>,
+}
+
+/// One ZIP→county crosswalk row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewZipCounty {
+    pub zip: String,
+    pub county_fips: String,
+    /// Share of the ZIP's residential addresses in this county (0–1).
+    pub res_ratio: f64,
+}
+
+/// Row counts written by [`load_geo_reference`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GeoReferenceLoadSummary {
+    pub dmas: u64,
+    pub counties: u64,
+    pub zip_counties: u64,
+}
+
+/// Load county, DMA and ZIP crosswalk reference data in one transaction.
+///
+/// Counties and DMAs are upserted. When `zip_counties` is non-empty the
+/// crosswalk is replaced wholesale, since HUD publishes it as a full snapshot.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if any statement fails (for example a crosswalk
+/// row referencing a county that is not loaded); nothing is written.
+pub async fn load_geo_reference(
+    pool: &PgPool,
+    counties: &[NewGeoCounty],
+    zip_counties: &[NewZipCounty],
+) -> Result<GeoReferenceLoadSummary, DbError> {
+    let mut tx = pool.begin().await?;
+    let mut summary = GeoReferenceLoadSummary::default();
+
+    let mut dma_codes: Vec<&str> = Vec::new();
+    let mut dma_names: Vec<&str> = Vec::new();
+    for county in counties {
+        if let (Some(code), Some(name)) = (county.dma_code.as_deref(), county.dma_name.as_deref()) {
+            if !dma_codes.contains(&code) {
+                dma_codes.push(code);
+                dma_names.push(name);
+            }
+        }
+    }
+    if !dma_codes.is_empty() {
+        summary.dmas = sqlx::query(
+            "INSERT INTO geo_dmas (code, name) \
+             SELECT * FROM UNNEST($1::text[], $2::text[]) \
+             ON CONFLICT (code) DO UPDATE SET name = EXCLUDED.name, updated_at = NOW()",
+        )
+        .bind(&dma_codes)
+        .bind(&dma_names)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+    }
+
+    if !counties.is_empty() {
+        let fips: Vec<&str> = counties.iter().map(|c| c.fips.as_str()).collect();
+        let names: Vec<&str> = counties.iter().map(|c| c.name.as_str()).collect();
+        let states: Vec<&str> = counties.iter().map(|c| c.state.as_str()).collect();
+        let populations: Vec<Option<i32>> = counties.iter().map(|c| c.population).collect();
+        let dmas: Vec<Option<&str>> = counties.iter().map(|c| c.dma_code.as_deref()).collect();
+        summary.counties = sqlx::query(
+            "INSERT INTO geo_counties (fips, name, state, population, dma_code) \
+             SELECT * FROM UNNEST($1::text[], $2::text[], $3::text[], $4::int[], $5::text[]) \
+             ON CONFLICT (fips) DO UPDATE SET \
+                 name = EXCLUDED.name, \
+                 state = EXCLUDED.state, \
+                 population = EXCLUDED.population, \
+                 dma_code = EXCLUDED.dma_code, \
+                 updated_at = NOW()",
+        )
+        .bind(&fips)
+        .bind(&names)
+        .bind(&states)
+        .bind(&populations)
+        .bind(&dmas)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+    }
+
+    if !zip_counties.is_empty() {
+        sqlx::query("DELETE FROM geo_zip_counties")
+            .execute(&mut *tx)
+            .await?;
+        let zips: Vec<&str> = zip_counties.iter().map(|z| z.zip.as_str()).collect();
+        let county_fips: Vec<&str> = zip_counties
+            .iter()
+            .map(|z| z.county_fips.as_str())
+            .collect();
+        let ratios: Vec<f64> = zip_counties.iter().map(|z| z.res_ratio).collect();
+        summary.zip_counties = sqlx::query(
+            "INSERT INTO geo_zip_counties (zip, county_fips, res_ratio) \
+             SELECT zip, county_fips, res_ratio::numeric \
+             FROM UNNEST($1::text[], $2::text[], $3::float8[]) AS u(zip, county_fips, res_ratio) \
+             ON CONFLICT (zip, county_fips) DO UPDATE SET res_ratio = EXCLUDED.res_ratio",
+        )
+        .bind(&zips)
+        .bind(&county_fips)
+        .bind(&ratios)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+    }
+
+    tx.commit().await?;
+    Ok(summary)
+}
+
+/// Set `county_fips` and `dma_code` on store locations from their ZIP.
+///
+/// A ZIP spanning several counties maps to the one with the highest
+/// residential ratio. Locations whose ZIP is not in the crosswalk have both
+/// columns cleared. Pass `brand_id` to limit the update to one brand.
+/// Returns the number of rows changed.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if either update fails.
+pub async fn assign_location_geographies(
+    pool: &PgPool,
+    brand_id: Option<i64>,
+) -> Result<u64, sqlx::Error> {
+    assign_location_geographies_tx(&mut *pool.acquire().await?, brand_id).await
+}
+
+/// [`assign_location_geographies`] on a caller's connection, typically
+/// inside its transaction.
+pub(crate) async fn assign_location_geographies_tx(
+    conn: &mut PgConnection,
+    brand_id: Option<i64>,
+) -> Result<u64, sqlx::Error> {
+    let assigned = sqlx::query(
+        "UPDATE store_locations sl \
+         SET county_fips = z.county_fips, dma_code = c.dma_code \
+         FROM (\
+             SELECT DISTINCT ON (zip) zip, county_fips \
+             FROM geo_zip_counties \
+             ORDER BY zip, res_ratio DESC, county_fips\
+         ) z \
+         JOIN geo_counties c ON c.fips = z.county_fips \
+         WHERE LEFT(sl.zip, 5) = z.zip \
+           AND ($1::BIGINT IS NULL OR sl.brand_id = $1) \
+           AND (sl.county_fips IS DISTINCT FROM z.county_fips \
+                OR sl.dma_code IS DISTINCT FROM c.dma_code)",
+    )
+    .bind(brand_id)
+    .execute(&mut *conn)
+    .await?
+    .rows_affected();
+
+    let cleared = sqlx::query(
+        "UPDATE store_locations sl \
+         SET county_fips = NULL, dma_code = NULL \
+         WHERE (sl.county_fips IS NOT NULL OR sl.dma_code IS NOT NULL) \
+           AND ($1::BIGINT IS NULL OR sl.brand_id = $1) \
+           AND NOT EXISTS (\
+               SELECT 1 FROM geo_zip_counties z WHERE z.zip = LEFT(sl.zip, 5)\
+           )",
+    )
+    .bind(brand_id)
+    .execute(&mut *conn)
+    .await?
+    .rows_affected();
+
+    Ok(assigned + cleared)
+}
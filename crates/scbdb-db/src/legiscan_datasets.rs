@@ -0,0 +1,77 @@
+//! Database operations for the `legiscan_datasets` table.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::DbError;
+
+/// A bulk dataset import to record.
+#[derive(Debug, Clone, Copy)]
+pub struct NewDatasetImport<'a> {
+    pub session_id: i64,
+    pub jurisdiction: &'a str,
+    pub session_name: &'a str,
+    pub dataset_hash: &'a str,
+    pub dataset_date: Option<NaiveDate>,
+    pub bills_imported: i32,
+}
+
+/// Returns the stored `dataset_hash` of each imported session in
+/// `session_ids`.
+///
+/// Sessions never imported are omitted from the map.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn get_dataset_hashes(
+    pool: &PgPool,
+    session_ids: &[i64],
+) -> Result<HashMap<i64, String>, DbError> {
+    let rows = sqlx::query_as::<_, (i64, String)>(
+        "SELECT session_id, dataset_hash \
+         FROM legiscan_datasets \
+         WHERE session_id = ANY($1::bigint[])",
+    )
+    .bind(session_ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().collect())
+}
+
+/// Records a completed dataset import, replacing any earlier one of the
+/// same session.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the upsert fails.
+pub async fn record_dataset_import(
+    pool: &PgPool,
+    import: &NewDatasetImport<'_>,
+) -> Result<(), DbError> {
+    sqlx::query(
+        "INSERT INTO legiscan_datasets \
+             (session_id, jurisdiction, session_name, dataset_hash, dataset_date, bills_imported) \
+         VALUES ($1, $2, $3, $4, $5, $6) \
+         ON CONFLICT (session_id) DO UPDATE SET \
+             jurisdiction   = EXCLUDED.jurisdiction, \
+             session_name   = EXCLUDED.session_name, \
+             dataset_hash   = EXCLUDED.dataset_hash, \
+             dataset_date   = EXCLUDED.dataset_date, \
+             bills_imported = EXCLUDED.bills_imported, \
+             imported_at    = NOW()",
+    )
+    .bind(import.session_id)
+    .bind(import.jurisdiction)
+    .bind(import.session_name)
+    .bind(import.dataset_hash)
+    .bind(import.dataset_date)
+    .bind(import.bills_imported)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+//! Database operations for the `legiscan_request_ledger` table.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+
+use crate::DbError;
+
+/// A row from the `legiscan_request_ledger` table.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LegiscanUsageRow {
+    pub key_fingerprint: String,
+    /// First day of the month counted.
+    pub month: NaiveDate,
+    pub requests: i32,
+    /// Budget in force at the last request of the month.
+    pub monthly_budget: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Counts one request of a key in `month` unless that would exceed
+/// `monthly_budget`. Returns the month's new count, or `None` when the budget
+/// is spent and nothing was counted.
+///
+/// The check and the increment are one statement, so concurrent processes
+/// sharing the key cannot overshoot the budget.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the upsert fails.
+pub async fn reserve_legiscan_request(
+    pool: &PgPool,
+    key_fingerprint: &str,
+    month: NaiveDate,
+    monthly_budget: i32,
+) -> Result<Option<i32>, DbError> {
+    let requests = sqlx::query_scalar::<_, i32>(
+        "INSERT INTO legiscan_request_ledger (key_fingerprint, month, requests, monthly_budget) \
+         SELECT $1, $2, 1, $3 WHERE $3 > 0 \
+         ON CONFLICT (key_fingerprint, month) DO UPDATE SET \
+             requests       = legiscan_request_ledger.requests + 1, \
+             monthly_budget = EXCLUDED.monthly_budget, \
+             updated_at     = NOW() \
+         WHERE legiscan_request_ledger.requests < EXCLUDED.monthly_budget \
+         RETURNING requests",
+    )
+    .bind(key_fingerprint)
+    .bind(month)
+    .bind(monthly_budget)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(requests)
+}
+
+/// Returns the usage of a key in `month`, if it made any request.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn get_legiscan_usage(
+    pool: &PgPool,
+    key_fingerprint: &str,
+    month: NaiveDate,
+) -> Result<Option<LegiscanUsageRow>, DbError> {
+    let row = sqlx::query_as::<_, LegiscanUsageRow>(
+        "SELECT key_fingerprint, month, requests, monthly_budget, updated_at \
+         FROM legiscan_request_ledger \
+         WHERE key_fingerprint = $1 AND month = $2",
+    )
+    .bind(key_fingerprint)
+    .bind(month)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row)
+}
+
+/// Returns the usage of every key in `month`, most used first.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_legiscan_usage(
+    pool: &PgPool,
+    month: NaiveDate,
+) -> Result<Vec<LegiscanUsageRow>, DbError> {
+    let rows = sqlx::query_as::<_, LegiscanUsageRow>(
+        "SELECT key_fingerprint, month, requests, monthly_budget, updated_at \
+         FROM legiscan_request_ledger \
+         WHERE month = $1 \
+         ORDER BY requests DESC, key_fingerprint",
+    )
+    .bind(month)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
@@ -0,0 +1,361 @@
+//! Database operations for the `legislators` and `bill_sponsors` tables and
+//! a legislator's record on tracked bills.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::DbError;
+
+/// A row from the `legislators` table.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LegislatorRow {
+    pub id: i64,
+    pub public_id: Uuid,
+    pub legiscan_people_id: i64,
+    pub jurisdiction: String,
+    pub name: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub party: Option<String>,
+    pub role: Option<String>,
+    pub district: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A legislator to insert or refresh.
+#[derive(Debug, Clone, Copy)]
+pub struct NewLegislator<'a> {
+    pub legiscan_people_id: i64,
+    pub jurisdiction: &'a str,
+    pub name: &'a str,
+    pub first_name: Option<&'a str>,
+    pub last_name: Option<&'a str>,
+    pub party: Option<&'a str>,
+    pub role: Option<&'a str>,
+    pub district: Option<&'a str>,
+}
+
+/// A bill sponsorship to store.
+#[derive(Debug, Clone, Copy)]
+pub struct NewBillSponsor<'a> {
+    pub legiscan_people_id: i64,
+    /// `"primary"`, `"cosponsor"` or `"joint"`.
+    pub sponsor_type: &'a str,
+    pub sponsor_order: i32,
+}
+
+/// A sponsor of a bill, with the legislator's details when known.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct BillSponsorRow {
+    pub legiscan_people_id: i64,
+    pub legislator_public_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub party: Option<String>,
+    pub role: Option<String>,
+    pub district: Option<String>,
+    pub sponsor_type: String,
+    pub sponsor_order: i32,
+}
+
+/// A tracked bill a legislator sponsored.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LegislatorSponsorshipRow {
+    pub bill_public_id: Uuid,
+    pub jurisdiction: String,
+    pub bill_number: String,
+    pub title: String,
+    pub status: String,
+    pub relevance_score: Option<f64>,
+    pub sponsor_type: String,
+}
+
+/// A legislator's vote on a roll call of a tracked bill.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LegislatorVoteRow {
+    pub bill_public_id: Uuid,
+    pub jurisdiction: String,
+    pub bill_number: String,
+    pub title: String,
+    pub relevance_score: Option<f64>,
+    pub legiscan_roll_call_id: i64,
+    pub vote_date: Option<NaiveDate>,
+    pub chamber: Option<String>,
+    pub description: String,
+    pub passed: bool,
+    pub vote: String,
+}
+
+/// How a legislator voted across roll calls of tracked bills.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, sqlx::FromRow)]
+pub struct LegislatorVoteTally {
+    pub yea: i64,
+    pub nay: i64,
+    pub nv: i64,
+    pub absent: i64,
+}
+
+/// Inserts a legislator or refreshes its details; returns the internal id.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the upsert fails.
+pub async fn upsert_legislator(
+    pool: &PgPool,
+    legislator: &NewLegislator<'_>,
+) -> Result<i64, DbError> {
+    let id = sqlx::query_scalar::<_, i64>(
+        "INSERT INTO legislators \
+             (legiscan_people_id, jurisdiction, name, first_name, last_name, party, role, district) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8) \
+         ON CONFLICT (legiscan_people_id) DO UPDATE SET \
+             jurisdiction = EXCLUDED.jurisdiction, \
+             name         = EXCLUDED.name, \
+             first_name   = COALESCE(EXCLUDED.first_name, legislators.first_name), \
+             last_name    = COALESCE(EXCLUDED.last_name, legislators.last_name), \
+             party        = COALESCE(EXCLUDED.party, legislators.party), \
+             role         = COALESCE(EXCLUDED.role, legislators.role), \
+             district     = COALESCE(EXCLUDED.district, legislators.district), \
+             updated_at   = NOW() \
+         RETURNING id",
+    )
+    .bind(legislator.legiscan_people_id)
+    .bind(legislator.jurisdiction)
+    .bind(legislator.name)
+    .bind(legislator.first_name)
+    .bind(legislator.last_name)
+    .bind(legislator.party)
+    .bind(legislator.role)
+    .bind(legislator.district)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(id)
+}
+
+/// Replaces the sponsor list of a bill.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if any statement fails; nothing is written in
+/// that case.
+pub async fn replace_bill_sponsors(
+    pool: &PgPool,
+    bill_id: i64,
+    sponsors: &[NewBillSponsor<'_>],
+) -> Result<(), DbError> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM bill_sponsors WHERE bill_id = $1")
+        .bind(bill_id)
+        .execute(&mut *tx)
+        .await?;
+
+    let people_ids: Vec<i64> = sponsors.iter().map(|s| s.legiscan_people_id).collect();
+    let types: Vec<&str> = sponsors.iter().map(|s| s.sponsor_type).collect();
+    let orders: Vec<i32> = sponsors.iter().map(|s| s.sponsor_order).collect();
+    sqlx::query(
+        "INSERT INTO bill_sponsors (bill_id, legiscan_people_id, sponsor_type, sponsor_order) \
+         SELECT $1, s.people_id, s.sponsor_type, s.sponsor_order \
+         FROM UNNEST($2::BIGINT[], $3::TEXT[], $4::INTEGER[]) \
+              AS s(people_id, sponsor_type, sponsor_order) \
+         ON CONFLICT (bill_id, legiscan_people_id) DO NOTHING",
+    )
+    .bind(bill_id)
+    .bind(&people_ids)
+    .bind(&types)
+    .bind(&orders)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Returns the sponsors of a non-deleted bill by its public UUID, primary
+/// sponsors first.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_bill_sponsors_by_public_id(
+    pool: &PgPool,
+    bill_public_id: Uuid,
+) -> Result<Vec<BillSponsorRow>, DbError> {
+    let rows = sqlx::query_as::<_, BillSponsorRow>(
+        "SELECT bs.legiscan_people_id, l.public_id AS legislator_public_id, l.name, \
+                l.party, l.role, l.district, bs.sponsor_type, bs.sponsor_order \
+         FROM bill_sponsors bs \
+         JOIN bills b ON b.id = bs.bill_id \
+         LEFT JOIN legislators l ON l.legiscan_people_id = bs.legiscan_people_id \
+         WHERE b.public_id = $1 AND b.deleted_at IS NULL \
+         ORDER BY bs.sponsor_type <> 'primary', bs.sponsor_order, bs.legiscan_people_id",
+    )
+    .bind(bill_public_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Returns legislators, optionally narrowed to a jurisdiction and a
+/// case-insensitive name substring, ordered by name.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_legislators(
+    pool: &PgPool,
+    jurisdiction: Option<&str>,
+    name_query: Option<&str>,
+    limit: i64,
+) -> Result<Vec<LegislatorRow>, DbError> {
+    let rows = sqlx::query_as::<_, LegislatorRow>(
+        "SELECT id, public_id, legiscan_people_id, jurisdiction, name, first_name, last_name, \
+                party, role, district, created_at, updated_at \
+         FROM legislators \
+         WHERE ($1::TEXT IS NULL OR jurisdiction = $1) \
+           AND ($2::TEXT IS NULL OR name ILIKE '%' || $2 || '%') \
+         ORDER BY jurisdiction, name \
+         LIMIT $3",
+    )
+    .bind(jurisdiction)
+    .bind(name_query)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Returns a legislator by public UUID, or `None` if not found.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn get_legislator_by_public_id(
+    pool: &PgPool,
+    public_id: Uuid,
+) -> Result<Option<LegislatorRow>, DbError> {
+    let row = sqlx::query_as::<_, LegislatorRow>(
+        "SELECT id, public_id, legiscan_people_id, jurisdiction, name, first_name, last_name, \
+                party, role, district, created_at, updated_at \
+         FROM legislators \
+         WHERE public_id = $1",
+    )
+    .bind(public_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row)
+}
+
+/// Returns the non-deleted bills a legislator sponsored, optionally narrowed
+/// to a topic tag and a minimum relevance score, most relevant first.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_legislator_sponsorships(
+    pool: &PgPool,
+    legiscan_people_id: i64,
+    topic: Option<&str>,
+    min_relevance: Option<f64>,
+    limit: i64,
+) -> Result<Vec<LegislatorSponsorshipRow>, DbError> {
+    let rows = sqlx::query_as::<_, LegislatorSponsorshipRow>(
+        "SELECT b.public_id AS bill_public_id, b.jurisdiction, b.bill_number, b.title, \
+                b.status, b.relevance_score, bs.sponsor_type \
+         FROM bill_sponsors bs \
+         JOIN bills b ON b.id = bs.bill_id \
+         WHERE bs.legiscan_people_id = $1 \
+           AND b.deleted_at IS NULL \
+           AND ($2::TEXT IS NULL OR EXISTS ( \
+                 SELECT 1 FROM bill_topics bt WHERE bt.bill_id = b.id AND bt.topic = $2)) \
+           AND ($3::DOUBLE PRECISION IS NULL OR b.relevance_score >= $3) \
+         ORDER BY b.relevance_score DESC NULLS LAST, b.last_action_date DESC NULLS LAST \
+         LIMIT $4",
+    )
+    .bind(legiscan_people_id)
+    .bind(topic)
+    .bind(min_relevance)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Returns a legislator's votes on roll calls of non-deleted bills, with the
+/// same filters as [`list_legislator_sponsorships`], newest first.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_legislator_votes(
+    pool: &PgPool,
+    legiscan_people_id: i64,
+    topic: Option<&str>,
+    min_relevance: Option<f64>,
+    limit: i64,
+) -> Result<Vec<LegislatorVoteRow>, DbError> {
+    let rows = sqlx::query_as::<_, LegislatorVoteRow>(
+        "SELECT b.public_id AS bill_public_id, b.jurisdiction, b.bill_number, b.title, \
+                b.relevance_score, rc.legiscan_roll_call_id, rc.vote_date, rc.chamber, \
+                rc.description, rc.passed, v.vote \
+         FROM roll_call_votes v \
+         JOIN roll_calls rc ON rc.id = v.roll_call_id \
+         JOIN bills b ON b.id = rc.bill_id \
+         WHERE v.legiscan_people_id = $1 \
+           AND b.deleted_at IS NULL \
+           AND ($2::TEXT IS NULL OR EXISTS ( \
+                 SELECT 1 FROM bill_topics bt WHERE bt.bill_id = b.id AND bt.topic = $2)) \
+           AND ($3::DOUBLE PRECISION IS NULL OR b.relevance_score >= $3) \
+         ORDER BY rc.vote_date DESC NULLS LAST, rc.legiscan_roll_call_id DESC \
+         LIMIT $4",
+    )
+    .bind(legiscan_people_id)
+    .bind(topic)
+    .bind(min_relevance)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Counts a legislator's votes by kind over the same roll calls as
+/// [`list_legislator_votes`], without its limit.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn tally_legislator_votes(
+    pool: &PgPool,
+    legiscan_people_id: i64,
+    topic: Option<&str>,
+    min_relevance: Option<f64>,
+) -> Result<LegislatorVoteTally, DbError> {
+    let tally = sqlx::query_as::<_, LegislatorVoteTally>(
+        "SELECT COUNT(*) FILTER (WHERE v.vote = 'yea') AS yea, \
+                COUNT(*) FILTER (WHERE v.vote = 'nay') AS nay, \
+                COUNT(*) FILTER (WHERE v.vote = 'nv') AS nv, \
+                COUNT(*) FILTER (WHERE v.vote = 'absent') AS absent \
+         FROM roll_call_votes v \
+         JOIN roll_calls rc ON rc.id = v.roll_call_id \
+         JOIN bills b ON b.id = rc.bill_id \
+         WHERE v.legiscan_people_id = $1 \
+           AND b.deleted_at IS NULL \
+           AND ($2::TEXT IS NULL OR EXISTS ( \
+                 SELECT 1 FROM bill_topics bt WHERE bt.bill_id = b.id AND bt.topic = $2)) \
+           AND ($3::DOUBLE PRECISION IS NULL OR b.relevance_score >= $3)",
+    )
+    .bind(legiscan_people_id)
+    .bind(topic)
+    .bind(min_relevance)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(tally)
+}
@@ -48,6 +48,26 @@ pub enum DbError {
         id: i64,
         expected_status: &'static str,
     },
+    #[error("location quarantine {public_id} is {status}, not pending review")]
+    QuarantineNotPending {
+        public_id: uuid::Uuid,
+        status: String,
+    },
+    #[error("brand candidate {public_id} is {status}, not pending review")]
+    BrandCandidateNotPending {
+        public_id: uuid::Uuid,
+        status: String,
+    },
+    #[error("refusing to deactivate every account: the import contains no accounts")]
+    EmptyAccountImport,
+    #[error(
+        "effective_to {effective_to} runs past the next version of this rule, \
+         which starts on {next_effective_from}"
+    )]
+    StateRuleOverlap {
+        effective_to: chrono::NaiveDate,
+        next_effective_from: chrono::NaiveDate,
+    },
     #[error(transparent)]
     Config(#[from] scbdb_core::ConfigError),
     #[error(transparent)]
@@ -145,21 +165,46 @@ mod tests {
     }
 }
 
+pub mod accounts;
 pub mod api_queries;
+pub mod bill_alerts;
 pub mod bill_events;
+pub mod bill_hearings;
 pub mod bill_texts;
+pub mod bill_topics;
 pub mod bills;
+pub mod brand_candidates;
 pub mod brand_completeness;
 pub mod brand_intel;
 pub mod brand_profiles;
 pub mod brand_signals;
 pub mod brands;
 pub mod collection_runs;
+pub mod compliance;
+pub mod geo_reference;
+pub mod legiscan_datasets;
+pub mod legiscan_ledger;
+pub mod legislators;
 pub mod locations;
 pub mod products;
+pub mod regulatory_exposure;
+pub mod roll_calls;
 pub mod seed;
 pub mod sentiment;
+pub mod state_rules;
+pub mod watched_bills;
 
+pub use accounts::{
+    import_accounts, list_account_competitor_presence, list_competitor_stores_without_account,
+    AccountCompetitorPresenceRow, AccountImportSummary, AccountPresenceFilters, NewAccount,
+    ProspectFilters, ProspectStoreRow,
+};
+pub use brand_candidates::{
+    dismiss_brand_candidate, get_brand_candidate, list_brand_candidates, list_discovery_texts,
+    list_multi_brand_locator_payloads, promote_brand_candidate, record_brand_candidate_evidence,
+    BrandCandidateRecordSummary, BrandCandidateRow, DiscoveryTextRow, LocatorPayloadRow,
+    NewBrandCandidateEvidence, BRAND_CANDIDATE_SOURCES,
+};
 pub use brand_completeness::{
     get_all_brands_completeness, get_brand_completeness, BrandCompletenessScore,
 };
@@ -188,15 +233,29 @@ pub use api_queries::{
     SentimentSnapshotDashboardRow, SentimentSummaryRow,
 };
 
+pub use bill_alerts::{
+    advance_bill_alert_cursors, list_bill_changes, start_bill_alert_channel, BillChange,
+};
 pub use bill_events::{
     list_bill_events, list_bill_events_batch, list_bill_events_by_public_id, upsert_bill_event,
     BillEventRow,
 };
+pub use bill_hearings::{
+    list_bill_hearings_by_public_id, list_upcoming_hearings, replace_bill_hearings, BillHearingRow,
+    HearingFilters, NewBillHearing, UpcomingHearingRow,
+};
 pub use bill_texts::{
-    get_bills_stored_hashes, list_bill_texts_by_public_id, upsert_bill_text, BillTextRow,
+    get_bill_text_content, get_bills_stored_hashes, list_bill_texts_by_public_id,
+    list_bill_texts_pending_extraction, record_bill_text_extraction, upsert_bill_text,
+    BillTextContentRow, BillTextRow, PendingBillTextRow,
+};
+pub use bill_topics::{
+    list_bill_topics_batch, list_bills_for_scoring, replace_bill_topics, BillScoringRow,
+    BillTopicRow,
 };
 pub use bills::{
-    get_bill_by_jurisdiction_number, get_bill_by_public_id, list_bills, upsert_bill, BillRow,
+    get_bill_by_jurisdiction_number, get_bill_by_public_id, list_bills, list_bills_filtered,
+    upsert_bill, BillFilters, BillRow,
 };
 pub use brand_profiles::{
     get_brand_profile, list_brand_social_handles, list_brands_without_profiles,
@@ -206,25 +265,76 @@ pub use brand_profiles::{
 pub use brands::{
     create_brand, deactivate_brand, get_brand_by_slug, list_active_brands,
     list_brands_with_locator, update_brand, update_brand_logo, update_brand_store_locator_url,
-    BrandRow,
+    BrandRow, NewBrand,
 };
 pub use collection_runs::{
     complete_collection_run, create_collection_run, fail_collection_run, get_collection_run,
     list_collection_run_brands, list_collection_runs, start_collection_run,
     upsert_collection_run_brand, CollectionRunBrandRow, CollectionRunRow,
 };
+pub use compliance::{check_compliance, ComplianceFilters, ComplianceReport, ComplianceViolation};
+pub use geo_reference::{
+    assign_location_geographies, load_geo_reference, GeoReferenceLoadSummary, NewGeoCounty,
+    NewZipCounty,
+};
+pub use legiscan_datasets::{get_dataset_hashes, record_dataset_import, NewDatasetImport};
+pub use legiscan_ledger::{
+    get_legiscan_usage, list_legiscan_usage, reserve_legiscan_request, LegiscanUsageRow,
+};
+pub use legislators::{
+    get_legislator_by_public_id, list_bill_sponsors_by_public_id, list_legislator_sponsorships,
+    list_legislator_votes, list_legislators, replace_bill_sponsors, tally_legislator_votes,
+    upsert_legislator, BillSponsorRow, LegislatorRow, LegislatorSponsorshipRow, LegislatorVoteRow,
+    LegislatorVoteTally, NewBillSponsor, NewLegislator,
+};
 pub use locations::{
-    deactivate_missing_locations, get_active_location_keys_for_brand, list_active_location_pins,
-    list_active_locations_by_brand, list_locations_by_state, list_locations_dashboard_summary,
-    list_new_locations_since, upsert_store_locations, LocationPinRow, LocationsByStateRow,
-    LocationsDashboardRow, NewStoreLocation, StoreLocationRow,
+    approve_location_quarantine, bounding_box, deactivate_missing_locations,
+    diff_location_snapshots, get_active_location_keys_for_brand, get_location_data_version,
+    haversine_miles, insert_store_location_events, list_active_location_pins,
+    list_active_locations_by_brand, list_location_density, list_location_geo_rollup,
+    list_location_net_change_weekly, list_location_quarantines, list_location_snapshots_for_brand,
+    list_location_tile_features, list_location_weekly_counts, list_location_whitespace,
+    list_locations_by_state, list_locations_dashboard_summary, list_locations_for_classification,
+    list_locations_for_details, list_locations_for_rekey, list_locations_within_radius,
+    list_nearest_competitor_stores, list_new_locations_since, list_product_match_candidates,
+    list_sku_distribution, list_store_location_events, quarantine_location_scrape_on_drop,
+    record_location_misses, record_product_sweep, reject_location_quarantine,
+    rekey_store_locations, resolve_zip_centroid, update_location_classifications,
+    update_location_details, upsert_store_locations, BoundingBox, DensityGrouping, GeoRollupLevel,
+    LocationClassificationInput, LocationClassificationUpdate, LocationDataVersion,
+    LocationDensityFilters, LocationDensityRow, LocationDetailsInput, LocationDetailsUpdate,
+    LocationDropAssessment, LocationDropGuard, LocationEventFilters, LocationEventType,
+    LocationGeoRollupRow, LocationKeyInput, LocationMissOutcome, LocationMissPolicy,
+    LocationNetChangeRow, LocationPinRow, LocationQuarantineRow, LocationRekey,
+    LocationRekeySummary, LocationSnapshotRow, LocationTileFeatureRow, LocationTileFilters,
+    LocationWeeklyCountRow, LocationsByStateRow, LocationsDashboardRow, NearbyLocationRow,
+    NearestCompetitorRow, NearestCompetitorSearch, NewCarriedProduct, NewLocationEvent,
+    NewStoreLocation, ProductMatchCandidateRow, ProductSweepInput, ProductSweepOutcome,
+    QuarantineApplySummary, QuarantinedScrape, RadiusSearch, SkuDistributionFilters,
+    SkuDistributionRow, StoreLocationEventRow, StoreLocationRow, TileCoord, WhitespaceFilters,
+    WhitespaceGapRow, WhitespaceGrouping, EARTH_RADIUS_MILES, MAX_TILE_ZOOM, TILE_SIZE_PX,
 };
 pub use products::{
     get_last_price_snapshot, insert_price_snapshot_if_changed, upsert_product, upsert_variant,
     PriceSnapshotRow, ProductRow, VariantRow,
 };
+pub use regulatory_exposure::{
+    compute_regulatory_exposure, list_latest_regulatory_exposure, list_regulatory_exposure_series,
+    record_regulatory_exposure, BrandExposurePoint, ExposureStateRow,
+};
+pub use roll_calls::{
+    get_roll_call_ids, list_bill_roll_calls_by_public_id, list_roll_call_votes_batch,
+    list_roll_calls_pending_votes, list_unknown_voters, record_roll_call_fetch_failure,
+    record_roll_call_votes, record_voter_fetch_failure, upsert_roll_call, NewRollCall,
+    PendingRollCallRow, RollCallRow, RollCallVoteRow, UnknownVoterRow,
+};
 pub use seed::{upsert_brand_domains, upsert_brand_social_handles};
 pub use sentiment::{
     get_latest_sentiment_by_brand, insert_sentiment_snapshot, list_sentiment_snapshots,
     SentimentSnapshotRow,
 };
+pub use state_rules::{
+    delete_state_rule, insert_state_rule, list_state_rules, NewStateRule, StateRuleFilters,
+    StateRuleRow,
+};
+pub use watched_bills::{list_watched_bills, unwatch_bill, watch_bill, WatchedBillRow};
@@ -0,0 +1,227 @@
+//! SKU-level distribution: which stores carry which products.
+//!
+//! Rows in `store_location_products` come from two places: the product list
+//! in a store's own locator payload (`source = 'locator'`), and product-level
+//! locator sweeps that search one of our products at a time
+//! (`source = 'product_sweep'`, always linked to `products.id`).
+
+use sqlx::PgPool;
+
+/// One of a brand's products with its variant SKUs, for matching provider
+/// catalog entries.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ProductMatchCandidateRow {
+    pub product_id: i64,
+    pub name: String,
+    pub skus: Vec<String>,
+}
+
+/// Stores a product-level sweep found for one of our products.
+#[derive(Debug, Clone)]
+pub struct ProductSweepInput<'a> {
+    pub product_id: i64,
+    /// UPC or provider product id, in that order of preference.
+    pub product_ref: &'a str,
+    pub product_name: Option<&'a str>,
+    pub upc: Option<&'a str>,
+    /// `location_key`s of the stores returned for the product.
+    pub location_keys: &'a [String],
+}
+
+/// What [`record_product_sweep`] wrote.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProductSweepOutcome {
+    /// Stores linked to the product.
+    pub linked: u64,
+    /// Returned stores not (yet) in the brand's stored footprint.
+    pub unknown_stores: u64,
+    /// Earlier sweep rows for the product whose store no longer carries it.
+    pub removed: u64,
+}
+
+/// Filters for [`list_sku_distribution`].
+#[derive(Debug, Clone, Default)]
+pub struct SkuDistributionFilters<'a> {
+    pub brand_slug: Option<&'a str>,
+    pub product_id: Option<i64>,
+    pub state: Option<&'a str>,
+    /// ISO 3166-1 alpha-2 country code.
+    pub country: Option<&'a str>,
+}
+
+/// Distribution points for one SKU (optionally within one state).
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SkuDistributionRow {
+    pub brand_slug: String,
+    pub brand_name: String,
+    /// `products.id`, when the locator product is linked to one of ours.
+    pub product_id: Option<i64>,
+    /// `products.id` as text for linked rows, otherwise the locator's product ref.
+    pub sku_key: String,
+    pub product_name: String,
+    pub upc: Option<String>,
+    /// Set when grouped by state.
+    pub state: Option<String>,
+    /// Active stores carrying the SKU.
+    pub store_count: i64,
+    pub state_count: i64,
+}
+
+/// List a brand's live products with their variant SKUs.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_product_match_candidates(
+    pool: &PgPool,
+    brand_id: i64,
+) -> Result<Vec<ProductMatchCandidateRow>, sqlx::Error> {
+    sqlx::query_as::<_, ProductMatchCandidateRow>(
+        "SELECT p.id AS product_id, p.name, \
+                COALESCE(ARRAY_AGG(DISTINCT v.sku) \
+                    FILTER (WHERE v.sku IS NOT NULL AND v.sku <> ''), '{}') AS skus \
+         FROM products p \
+         LEFT JOIN product_variants v ON v.product_id = p.id \
+         WHERE p.brand_id = $1 AND p.deleted_at IS NULL \
+         GROUP BY p.id, p.name \
+         ORDER BY p.id",
+    )
+    .bind(brand_id)
+    .fetch_all(pool)
+    .await
+}
+
+/// Record the stores a product-level sweep returned for one product.
+///
+/// Links each known store to the product and deletes this product's earlier
+/// sweep rows for stores no longer returned. A row the locator already
+/// recorded under the same `product_ref` is taken over by the sweep, so a
+/// later scrape without it does not delete a link the sweep confirmed.
+/// Stores that are not in the brand's stored footprint are counted but not
+/// created; run `collect locations` to pick them up. Runs in one transaction.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if a query fails.
+pub async fn record_product_sweep(
+    pool: &PgPool,
+    brand_id: i64,
+    sweep: &ProductSweepInput<'_>,
+) -> Result<ProductSweepOutcome, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let store_ids: Vec<i64> = sqlx::query_scalar::<_, i64>(
+        "SELECT id FROM store_locations \
+         WHERE brand_id = $1 AND location_key = ANY($2::text[])",
+    )
+    .bind(brand_id)
+    .bind(sweep.location_keys)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let removed = sqlx::query(
+        "DELETE FROM store_location_products p \
+         USING store_locations sl \
+         WHERE sl.id = p.store_location_id \
+           AND sl.brand_id = $1 \
+           AND p.source = 'product_sweep' \
+           AND p.product_id = $2 \
+           AND p.store_location_id <> ALL($3::bigint[])",
+    )
+    .bind(brand_id)
+    .bind(sweep.product_id)
+    .bind(&store_ids)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    let linked = sqlx::query(
+        "INSERT INTO store_location_products \
+             (store_location_id, product_ref, product_name, upc, product_id, source) \
+         SELECT u.id, $2, $3, $4, $1, 'product_sweep' \
+         FROM UNNEST($5::bigint[]) AS u(id) \
+         ON CONFLICT (store_location_id, product_ref) DO UPDATE SET \
+             product_id   = EXCLUDED.product_id, \
+             source       = EXCLUDED.source, \
+             product_name = COALESCE(EXCLUDED.product_name, store_location_products.product_name), \
+             upc          = COALESCE(EXCLUDED.upc, store_location_products.upc), \
+             last_seen_at = NOW()",
+    )
+    .bind(sweep.product_id)
+    .bind(sweep.product_ref)
+    .bind(sweep.product_name)
+    .bind(sweep.upc)
+    .bind(&store_ids)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    tx.commit().await?;
+
+    let unknown_stores = (sweep.location_keys.len() as u64).saturating_sub(store_ids.len() as u64);
+    Ok(ProductSweepOutcome {
+        linked,
+        unknown_stores,
+        removed,
+    })
+}
+
+/// Distribution points (active stores carrying the SKU) per SKU, or per SKU
+/// and state when `by_state` is set.
+///
+/// Counts both sweep rows and products listed in locator payloads; payload
+/// products not linked to one of ours are keyed by their locator product ref.
+/// Ranked by store count.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_sku_distribution(
+    pool: &PgPool,
+    filters: &SkuDistributionFilters<'_>,
+    by_state: bool,
+    limit: i64,
+) -> Result<Vec<SkuDistributionRow>, sqlx::Error> {
+    let (state_col, state_group) = if by_state {
+        ("a.state", ", a.state")
+    } else {
+        ("NULL::TEXT", "")
+    };
+    let query = format!(
+        "WITH a AS (\
+            SELECT b.slug AS brand_slug, b.name AS brand_name, \
+                   sl.id AS store_location_id, sl.state, \
+                   slp.product_id, \
+                   COALESCE(slp.product_id::TEXT, slp.product_ref) AS sku_key, \
+                   COALESCE(p.name, slp.product_name, slp.product_ref) AS product_name, \
+                   slp.upc \
+            FROM store_location_products slp \
+            JOIN store_locations sl ON sl.id = slp.store_location_id \
+            JOIN brands b ON b.id = sl.brand_id \
+            LEFT JOIN products p ON p.id = slp.product_id \
+            WHERE sl.is_active = TRUE \
+              AND b.deleted_at IS NULL \
+              AND ($1::TEXT IS NULL OR b.slug = $1) \
+              AND ($2::BIGINT IS NULL OR slp.product_id = $2) \
+              AND ($3::TEXT IS NULL OR sl.state = $3) \
+              AND ($5::TEXT IS NULL OR sl.country = $5)\
+         ) \
+         SELECT a.brand_slug, a.brand_name, a.product_id, a.sku_key, \
+                MIN(a.product_name) AS product_name, MIN(a.upc) AS upc, \
+                {state_col} AS state, \
+                COUNT(DISTINCT a.store_location_id) AS store_count, \
+                COUNT(DISTINCT a.state) AS state_count \
+         FROM a \
+         GROUP BY a.brand_slug, a.brand_name, a.product_id, a.sku_key{state_group} \
+         ORDER BY store_count DESC, a.brand_slug ASC, product_name ASC \
+         LIMIT $4"
+    );
+    sqlx::query_as::<_, SkuDistributionRow>(&query)
+        .bind(filters.brand_slug)
+        .bind(filters.product_id)
+        .bind(filters.state)
+        .bind(limit)
+        .bind(filters.country)
+        .fetch_all(pool)
+        .await
+}
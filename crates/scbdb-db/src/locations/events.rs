@@ -0,0 +1,563 @@
+//! Change history for store locations (`store_location_events`).
+//!
+//! After each scrape the caller snapshots the brand's stored locations
+//! (before upsert), diffs them against the new scrape with
+//! [`diff_location_snapshots`], and persists the result with
+//! [`insert_store_location_events`] once the upsert/deactivate has run.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use super::types::NewStoreLocation;
+
+/// Kind of change recorded in `store_location_events.event_type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocationEventType {
+    /// Location seen for the first time.
+    Added,
+    /// Previously active location absent from the latest scrape.
+    Removed,
+    /// Previously deactivated location seen again.
+    Reactivated,
+    /// Same store (by external id or name) at a new address.
+    Moved,
+    /// Same store (by external id or address) under a new name.
+    Renamed,
+}
+
+impl LocationEventType {
+    /// Stable storage value.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LocationEventType::Added => "added",
+            LocationEventType::Removed => "removed",
+            LocationEventType::Reactivated => "reactivated",
+            LocationEventType::Moved => "moved",
+            LocationEventType::Renamed => "renamed",
+        }
+    }
+}
+
+/// Pre-upsert view of a stored location, used for diffing.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LocationSnapshotRow {
+    pub id: i64,
+    pub location_key: String,
+    pub name: String,
+    pub address_line1: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub zip: Option<String>,
+    pub external_id: Option<String>,
+    pub is_active: bool,
+}
+
+/// A change detected by [`diff_location_snapshots`], keyed by the
+/// `location_key` of the row the event attaches to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewLocationEvent {
+    pub location_key: String,
+    pub event_type: LocationEventType,
+    pub state: Option<String>,
+    /// Previous values for `moved`/`renamed`; empty object otherwise.
+    pub details: serde_json::Value,
+}
+
+/// A row from `store_location_events` joined with brand and store info.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct StoreLocationEventRow {
+    pub id: i64,
+    pub public_id: Uuid,
+    pub brand_name: String,
+    pub brand_slug: String,
+    pub store_location_id: i64,
+    pub store_name: String,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub event_type: String,
+    pub details: serde_json::Value,
+    pub collection_run_id: Option<i64>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// Filters for [`list_store_location_events`].
+#[derive(Debug, Clone, Default)]
+pub struct LocationEventFilters<'a> {
+    pub brand_slug: Option<&'a str>,
+    pub state: Option<&'a str>,
+    /// ISO 3166-1 alpha-2 country code of the store.
+    pub country: Option<&'a str>,
+    pub event_type: Option<&'a str>,
+    pub since: Option<DateTime<Utc>>,
+}
+
+/// Weekly net change in active locations for one brand in one state.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LocationNetChangeRow {
+    /// Monday of the ISO week.
+    pub week_start: NaiveDate,
+    pub brand_name: String,
+    pub brand_slug: String,
+    pub state: String,
+    pub added: i64,
+    pub reactivated: i64,
+    pub removed: i64,
+    pub moved: i64,
+    pub renamed: i64,
+    /// `added + reactivated - removed`.
+    pub net_change: i64,
+    /// The brand's first-ever location in this state was seen during this week.
+    pub new_territory: bool,
+}
+
+/// Snapshot every stored location for a brand (active and inactive).
+///
+/// Call this **before** [`crate::upsert_store_locations`] and pass the result
+/// to [`diff_location_snapshots`].
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_location_snapshots_for_brand<'e>(
+    executor: impl PgExecutor<'e>,
+    brand_id: i64,
+) -> Result<Vec<LocationSnapshotRow>, sqlx::Error> {
+    sqlx::query_as::<_, LocationSnapshotRow>(
+        "SELECT id, location_key, name, address_line1, city, state, zip, \
+                external_id, is_active \
+         FROM store_locations \
+         WHERE brand_id = $1",
+    )
+    .bind(brand_id)
+    .fetch_all(executor)
+    .await
+}
+
+fn norm(value: Option<&str>) -> String {
+    value.unwrap_or("").trim().to_lowercase()
+}
+
+fn same_address(prev: &LocationSnapshotRow, curr: &NewStoreLocation) -> bool {
+    norm(prev.address_line1.as_deref()) == norm(curr.address_line1.as_deref())
+        && norm(prev.city.as_deref()) == norm(curr.city.as_deref())
+        && norm(prev.zip.as_deref()) == norm(curr.zip.as_deref())
+}
+
+fn previous_details(prev: &LocationSnapshotRow) -> serde_json::Value {
+    serde_json::json!({
+        "previous_location_id": prev.id,
+        "previous_name": prev.name,
+        "previous_address_line1": prev.address_line1,
+        "previous_city": prev.city,
+        "previous_state": prev.state,
+        "previous_zip": prev.zip,
+    })
+}
+
+/// Pair a disappeared location with a new one when they describe the same store.
+///
+/// Matching order: shared non-empty `external_id`, then identical street
+/// address + zip (a rename), then identical name + city + state (a move).
+fn find_counterpart<'a>(
+    curr: &NewStoreLocation,
+    removed: &[&'a LocationSnapshotRow],
+    claimed: &HashSet<i64>,
+) -> Option<&'a LocationSnapshotRow> {
+    let unclaimed = || removed.iter().copied().filter(|p| !claimed.contains(&p.id));
+
+    let ext = norm(curr.external_id.as_deref());
+    if !ext.is_empty() {
+        if let Some(p) = unclaimed().find(|p| norm(p.external_id.as_deref()) == ext) {
+            return Some(p);
+        }
+    }
+
+    let addr = norm(curr.address_line1.as_deref());
+    let zip = norm(curr.zip.as_deref());
+    if !addr.is_empty() && !zip.is_empty() {
+        if let Some(p) = unclaimed()
+            .find(|p| norm(p.address_line1.as_deref()) == addr && norm(p.zip.as_deref()) == zip)
+        {
+            return Some(p);
+        }
+    }
+
+    let name = curr.name.trim().to_lowercase();
+    let city = norm(curr.city.as_deref());
+    if !city.is_empty() {
+        return unclaimed().find(|p| {
+            p.name.trim().to_lowercase() == name
+                && norm(p.city.as_deref()) == city
+                && norm(p.state.as_deref()) == norm(curr.state.as_deref())
+        });
+    }
+
+    None
+}
+
+/// Diff a pre-upsert snapshot against a new scrape.
+///
+/// Produces `added`, `reactivated`, `removed`, `moved` and `renamed` events.
+/// A location whose key changed because its name or address changed is
+/// reported once as `renamed`/`moved` on the new row rather than as a
+/// `removed` + `added` pair.
+#[must_use]
+pub fn diff_location_snapshots(
+    prev: &[LocationSnapshotRow],
+    curr: &[NewStoreLocation],
+) -> Vec<NewLocationEvent> {
+    let prev_by_key: HashMap<&str, &LocationSnapshotRow> =
+        prev.iter().map(|p| (p.location_key.as_str(), p)).collect();
+    let curr_keys: HashSet<&str> = curr.iter().map(|c| c.location_key.as_str()).collect();
+
+    let removed: Vec<&LocationSnapshotRow> = prev
+        .iter()
+        .filter(|p| p.is_active && !curr_keys.contains(p.location_key.as_str()))
+        .collect();
+
+    let mut events = Vec::new();
+    let mut claimed: HashSet<i64> = HashSet::new();
+    let mut seen_keys: HashSet<&str> = HashSet::new();
+
+    for loc in curr {
+        if !seen_keys.insert(loc.location_key.as_str()) {
+            continue;
+        }
+        match prev_by_key.get(loc.location_key.as_str()) {
+            Some(p) if p.is_active => {}
+            Some(_) => events.push(NewLocationEvent {
+                location_key: loc.location_key.clone(),
+                event_type: LocationEventType::Reactivated,
+                state: loc.state.clone(),
+                details: serde_json::json!({}),
+            }),
+            None => {
+                let event = if let Some(p) = find_counterpart(loc, &removed, &claimed) {
+                    claimed.insert(p.id);
+                    let event_type = if same_address(p, loc) {
+                        LocationEventType::Renamed
+                    } else {
+                        LocationEventType::Moved
+                    };
+                    NewLocationEvent {
+                        location_key: loc.location_key.clone(),
+                        event_type,
+                        state: loc.state.clone(),
+                        details: previous_details(p),
+                    }
+                } else {
+                    NewLocationEvent {
+                        location_key: loc.location_key.clone(),
+                        event_type: LocationEventType::Added,
+                        state: loc.state.clone(),
+                        details: serde_json::json!({}),
+                    }
+                };
+                events.push(event);
+            }
+        }
+    }
+
+    events.extend(
+        removed
+            .into_iter()
+            .filter(|p| !claimed.contains(&p.id))
+            .map(|p| NewLocationEvent {
+                location_key: p.location_key.clone(),
+                event_type: LocationEventType::Removed,
+                state: p.state.clone(),
+                details: serde_json::json!({}),
+            }),
+    );
+
+    events
+}
+
+/// Persist detected location events for a brand.
+///
+/// Events are resolved to `store_location_id` by `(brand_id, location_key)`,
+/// so this must run **after** the upsert that creates rows for new keys.
+/// Returns the number of events written.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the insert fails.
+pub async fn insert_store_location_events<'e>(
+    executor: impl PgExecutor<'e>,
+    brand_id: i64,
+    collection_run_id: Option<i64>,
+    events: &[NewLocationEvent],
+) -> Result<u64, sqlx::Error> {
+    if events.is_empty() {
+        return Ok(0);
+    }
+
+    let keys: Vec<&str> = events.iter().map(|e| e.location_key.as_str()).collect();
+    let types: Vec<&str> = events.iter().map(|e| e.event_type.as_str()).collect();
+    let states: Vec<Option<&str>> = events.iter().map(|e| e.state.as_deref()).collect();
+    let details: Vec<serde_json::Value> = events.iter().map(|e| e.details.clone()).collect();
+
+    let rows_affected = sqlx::query(
+        "INSERT INTO store_location_events \
+             (brand_id, store_location_id, event_type, state, collection_run_id, details) \
+         SELECT $1, sl.id, u.event_type, u.state, $2, u.details \
+         FROM UNNEST($3::text[], $4::text[], $5::text[], $6::jsonb[]) \
+              AS u(location_key, event_type, state, details) \
+         JOIN store_locations sl \
+           ON sl.brand_id = $1 AND sl.location_key = u.location_key",
+    )
+    .bind(brand_id)
+    .bind(collection_run_id)
+    .bind(&keys)
+    .bind(&types)
+    .bind(&states)
+    .bind(&details)
+    .execute(executor)
+    .await?
+    .rows_affected();
+
+    Ok(rows_affected)
+}
+
+/// Cursor-paginated location change feed, newest first.
+///
+/// `cursor` is the `id` of the last row from the previous page (exclusive).
+/// The caller should request `limit + 1` rows to detect a next page.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_store_location_events(
+    pool: &PgPool,
+    filters: &LocationEventFilters<'_>,
+    limit: i64,
+    cursor: Option<i64>,
+) -> Result<Vec<StoreLocationEventRow>, sqlx::Error> {
+    sqlx::query_as::<_, StoreLocationEventRow>(
+        "SELECT \
+            e.id, e.public_id, \
+            b.name AS brand_name, b.slug AS brand_slug, \
+            e.store_location_id, sl.name AS store_name, sl.city, e.state, \
+            e.event_type, e.details, e.collection_run_id, e.occurred_at \
+         FROM store_location_events e \
+         JOIN brands b ON b.id = e.brand_id \
+         JOIN store_locations sl ON sl.id = e.store_location_id \
+         WHERE b.deleted_at IS NULL \
+           AND ($1::TEXT IS NULL OR b.slug = $1) \
+           AND ($2::TEXT IS NULL OR e.state = $2) \
+           AND ($3::TEXT IS NULL OR e.event_type = $3) \
+           AND ($4::TIMESTAMPTZ IS NULL OR e.occurred_at >= $4) \
+           AND ($5::BIGINT IS NULL OR e.id < $5) \
+           AND ($7::TEXT IS NULL OR sl.country = $7) \
+         ORDER BY e.id DESC \
+         LIMIT $6",
+    )
+    .bind(filters.brand_slug)
+    .bind(filters.state)
+    .bind(filters.event_type)
+    .bind(filters.since)
+    .bind(cursor)
+    .bind(limit)
+    .bind(filters.country)
+    .fetch_all(pool)
+    .await
+}
+
+/// Weekly net change in active locations per brand and state.
+///
+/// Covers the last `weeks` ISO weeks. `new_territory` marks the week in which
+/// a brand's first location in a state was seen — the signal that a
+/// competitor has entered a new distributor territory. Pass `country` to
+/// count only stores in that country. Ordered by `week_start DESC`, then
+/// largest absolute net change first.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_location_net_change_weekly(
+    pool: &PgPool,
+    brand_slug: Option<&str>,
+    state: Option<&str>,
+    country: Option<&str>,
+    weeks: i32,
+) -> Result<Vec<LocationNetChangeRow>, sqlx::Error> {
+    sqlx::query_as::<_, LocationNetChangeRow>(
+        "WITH weekly AS (\
+            SELECT \
+                date_trunc('week', e.occurred_at)::date AS week_start, \
+                e.brand_id, \
+                e.state, \
+                COUNT(*) FILTER (WHERE e.event_type = 'added') AS added, \
+                COUNT(*) FILTER (WHERE e.event_type = 'reactivated') AS reactivated, \
+                COUNT(*) FILTER (WHERE e.event_type = 'removed') AS removed, \
+                COUNT(*) FILTER (WHERE e.event_type = 'moved') AS moved, \
+                COUNT(*) FILTER (WHERE e.event_type = 'renamed') AS renamed \
+            FROM store_location_events e \
+            JOIN store_locations sl ON sl.id = e.store_location_id \
+            WHERE e.occurred_at >= date_trunc('week', NOW()) - make_interval(weeks => $3 - 1) \
+              AND e.state IS NOT NULL AND e.state != '' \
+              AND ($4::TEXT IS NULL OR sl.country = $4) \
+            GROUP BY 1, 2, 3\
+         ), \
+         first_seen AS (\
+            SELECT brand_id, state, MIN(first_seen_at) AS first_seen_at \
+            FROM store_locations \
+            WHERE state IS NOT NULL AND state != '' \
+            GROUP BY brand_id, state\
+         ) \
+         SELECT \
+            w.week_start, \
+            b.name AS brand_name, \
+            b.slug AS brand_slug, \
+            w.state, \
+            w.added, w.reactivated, w.removed, w.moved, w.renamed, \
+            (w.added + w.reactivated - w.removed) AS net_change, \
+            COALESCE(date_trunc('week', fs.first_seen_at)::date = w.week_start, FALSE) \
+                AS new_territory \
+         FROM weekly w \
+         JOIN brands b ON b.id = w.brand_id \
+         LEFT JOIN first_seen fs ON fs.brand_id = w.brand_id AND fs.state = w.state \
+         WHERE b.deleted_at IS NULL \
+           AND ($1::TEXT IS NULL OR b.slug = $1) \
+           AND ($2::TEXT IS NULL OR w.state = $2) \
+         ORDER BY w.week_start DESC, ABS(w.added + w.reactivated - w.removed) DESC, b.slug, w.state",
+    )
+    .bind(brand_slug)
+    .bind(state)
+    .bind(weeks)
+    .bind(country)
+    .fetch_all(pool)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(
+        id: i64,
+        key: &str,
+        name: &str,
+        address: &str,
+        active: bool,
+    ) -> LocationSnapshotRow {
+        LocationSnapshotRow {
+            id,
+            location_key: key.to_string(),
+            name: name.to_string(),
+            address_line1: Some(address.to_string()),
+            city: Some("Columbia".to_string()),
+            state: Some("SC".to_string()),
+            zip: Some("29201".to_string()),
+            external_id: None,
+            is_active: active,
+        }
+    }
+
+    fn scraped(key: &str, name: &str, address: &str) -> NewStoreLocation {
+        NewStoreLocation {
+            location_key: key.to_string(),
+            name: name.to_string(),
+            address_line1: Some(address.to_string()),
+            city: Some("Columbia".to_string()),
+            state: Some("SC".to_string()),
+            zip: Some("29201".to_string()),
+            country: Some("US".to_string()),
+            latitude: None,
+            longitude: None,
+            phone: None,
+            external_id: None,
+            locator_source: Some("locally".to_string()),
+            raw_data: serde_json::json!({}),
+            retail_chain: None,
+            retail_channel: None,
+            hours: None,
+            website: None,
+            email: None,
+            store_type: None,
+            carried_products: Vec::new(),
+        }
+    }
+
+    fn types(events: &[NewLocationEvent]) -> Vec<(&str, LocationEventType)> {
+        let mut out: Vec<_> = events
+            .iter()
+            .map(|e| (e.location_key.as_str(), e.event_type))
+            .collect();
+        out.sort_by_key(|(k, _)| *k);
+        out
+    }
+
+    #[test]
+    fn unchanged_scrape_produces_no_events() {
+        let prev = vec![snapshot(1, "k1", "Store", "1 Main St", true)];
+        let curr = vec![scraped("k1", "Store", "1 Main St")];
+        assert!(diff_location_snapshots(&prev, &curr).is_empty());
+    }
+
+    #[test]
+    fn detects_added_removed_and_reactivated() {
+        let prev = vec![
+            snapshot(1, "kept", "Kept", "1 Main St", true),
+            snapshot(2, "gone", "Gone", "2 Main St", true),
+            snapshot(3, "back", "Back", "3 Main St", false),
+        ];
+        let curr = vec![
+            scraped("kept", "Kept", "1 Main St"),
+            scraped("back", "Back", "3 Main St"),
+            scraped("new", "New", "4 Main St"),
+        ];
+        let events = diff_location_snapshots(&prev, &curr);
+        assert_eq!(
+            types(&events),
+            vec![
+                ("back", LocationEventType::Reactivated),
+                ("gone", LocationEventType::Removed),
+                ("new", LocationEventType::Added),
+            ]
+        );
+    }
+
+    #[test]
+    fn same_address_new_name_is_renamed() {
+        let prev = vec![snapshot(7, "old", "Joe's Liquor", "9 King St", true)];
+        let curr = vec![scraped("new", "Joe's Wine & Spirits", "9 King St")];
+        let events = diff_location_snapshots(&prev, &curr);
+        assert_eq!(types(&events), vec![("new", LocationEventType::Renamed)]);
+        assert_eq!(events[0].details["previous_location_id"], 7);
+        assert_eq!(events[0].details["previous_name"], "Joe's Liquor");
+    }
+
+    #[test]
+    fn same_name_new_address_is_moved() {
+        let prev = vec![snapshot(7, "old", "Joe's Liquor", "9 King St", true)];
+        let curr = vec![scraped("new", "Joe's Liquor", "120 Assembly St")];
+        let events = diff_location_snapshots(&prev, &curr);
+        assert_eq!(types(&events), vec![("new", LocationEventType::Moved)]);
+        assert_eq!(events[0].details["previous_address_line1"], "9 King St");
+    }
+
+    #[test]
+    fn external_id_links_renamed_store_across_keys() {
+        let mut old = snapshot(5, "old", "Store 12", "1 Main St", true);
+        old.external_id = Some("ext-12".to_string());
+        let mut new = scraped("new", "Corner Market 12", "1 Main St");
+        new.external_id = Some("ext-12".to_string());
+        let events = diff_location_snapshots(&[old], &[new]);
+        assert_eq!(types(&events), vec![("new", LocationEventType::Renamed)]);
+    }
+
+    #[test]
+    fn duplicate_keys_in_scrape_emit_one_event() {
+        let curr = vec![
+            scraped("dup", "Dup", "1 Main St"),
+            scraped("dup", "Dup", "1 Main St"),
+        ];
+        let events = diff_location_snapshots(&[], &curr);
+        assert_eq!(events.len(), 1);
+    }
+}
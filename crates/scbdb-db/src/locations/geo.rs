@@ -0,0 +1,573 @@
+//! Geospatial queries over `store_locations.latitude/longitude`.
+//!
+//! Does not require the `PostGIS` extension: every query prefilters with a
+//! lat/lng bounding box (served by `idx_store_locations_active_lat_lng`) and
+//! then ranks by great-circle distance computed with the haversine formula in
+//! SQL.
+
+use sqlx::PgPool;
+
+/// Mean Earth radius in statute miles.
+pub const EARTH_RADIUS_MILES: f64 = 3958.8;
+
+/// Miles per degree of latitude (and of longitude at the equator).
+const MILES_PER_DEGREE: f64 = 69.0;
+
+/// Great-circle distance between two points, in miles.
+#[must_use]
+pub fn haversine_miles(lat1: f64, lng1: f64, lat2: f64, lng2: f64) -> f64 {
+    let d_lat = (lat2 - lat1).to_radians();
+    let d_lng = (lng2 - lng1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (d_lng / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_MILES * a.sqrt().asin()
+}
+
+/// Lat/lng rectangle that fully contains a circle of the given radius.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_lat: f64,
+    pub max_lat: f64,
+    pub min_lng: f64,
+    pub max_lng: f64,
+}
+
+/// Bounding box around `(lat, lng)` covering `radius_miles` in every direction.
+///
+/// Longitude span widens with latitude; the cosine is floored so the box stays
+/// finite near the poles.
+#[must_use]
+pub fn bounding_box(lat: f64, lng: f64, radius_miles: f64) -> BoundingBox {
+    let d_lat = radius_miles / MILES_PER_DEGREE;
+    let d_lng = radius_miles / (MILES_PER_DEGREE * lat.to_radians().cos().max(0.01));
+    BoundingBox {
+        min_lat: lat - d_lat,
+        max_lat: lat + d_lat,
+        min_lng: lng - d_lng,
+        max_lng: lng + d_lng,
+    }
+}
+
+/// SQL expression for the haversine distance in miles between two points.
+fn haversine_sql(lat1: &str, lng1: &str, lat2: &str, lng2: &str) -> String {
+    format!(
+        "({EARTH_RADIUS_MILES} * 2 * ASIN(SQRT(LEAST(1.0, \
+            POWER(SIN(RADIANS({lat2} - {lat1}) / 2), 2) \
+            + COS(RADIANS({lat1})) * COS(RADIANS({lat2})) \
+              * POWER(SIN(RADIANS({lng2} - {lng1}) / 2), 2)))))"
+    )
+}
+
+/// An active store location with its distance from a query point.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct NearbyLocationRow {
+    pub id: i64,
+    pub store_name: String,
+    pub address_line1: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub zip: Option<String>,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub retail_chain: Option<String>,
+    pub retail_channel: Option<String>,
+    pub brand_name: String,
+    pub brand_slug: String,
+    pub brand_relationship: String,
+    pub distance_miles: f64,
+}
+
+/// Parameters for [`list_locations_within_radius`].
+#[derive(Debug, Clone, Copy)]
+pub struct RadiusSearch<'a> {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub radius_miles: f64,
+    pub brand_slug: Option<&'a str>,
+    /// `portfolio` or `competitor`.
+    pub relationship: Option<&'a str>,
+    /// ISO 3166-1 alpha-2 country code.
+    pub country: Option<&'a str>,
+}
+
+/// One portfolio store paired with a nearby competitor store.
+///
+/// Competitor columns are `NULL` when no competitor store lies within range.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct NearestCompetitorRow {
+    pub portfolio_location_id: i64,
+    pub portfolio_store_name: String,
+    pub portfolio_brand_slug: String,
+    pub portfolio_city: Option<String>,
+    pub portfolio_state: Option<String>,
+    pub competitor_location_id: Option<i64>,
+    pub competitor_store_name: Option<String>,
+    pub competitor_brand_name: Option<String>,
+    pub competitor_brand_slug: Option<String>,
+    pub competitor_city: Option<String>,
+    pub competitor_state: Option<String>,
+    pub distance_miles: Option<f64>,
+}
+
+/// Parameters for [`list_nearest_competitor_stores`].
+#[derive(Debug, Clone, Copy)]
+pub struct NearestCompetitorSearch<'a> {
+    /// Restrict portfolio stores to one brand.
+    pub portfolio_brand_slug: Option<&'a str>,
+    /// Restrict competitor stores to one brand.
+    pub competitor_brand_slug: Option<&'a str>,
+    pub max_miles: f64,
+    /// Competitor stores returned per portfolio store.
+    pub per_store: i64,
+    /// Restrict portfolio stores to one country (ISO 3166-1 alpha-2).
+    pub country: Option<&'a str>,
+}
+
+/// Area granularity for [`list_location_density`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DensityGrouping {
+    /// Five-digit ZIP code; Canadian stores group by forward sortation
+    /// area (the first three postal code characters).
+    Zip,
+    /// City within a state.
+    City,
+    /// County FIPS code (requires `locations load-geo`).
+    County,
+}
+
+impl DensityGrouping {
+    /// Parse the API/CLI value (`zip`, `city` or `county`).
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "zip" => Some(Self::Zip),
+            "city" => Some(Self::City),
+            "county" => Some(Self::County),
+            _ => None,
+        }
+    }
+
+    fn area_key_sql(self) -> &'static str {
+        match self {
+            Self::Zip => {
+                "CASE WHEN sl.country = 'CA' THEN LEFT(sl.zip, 3) ELSE LEFT(sl.zip, 5) END"
+            }
+            Self::City => "INITCAP(LOWER(TRIM(sl.city)))",
+            Self::County => "sl.county_fips",
+        }
+    }
+
+    fn area_filter_sql(self) -> &'static str {
+        match self {
+            Self::Zip => {
+                "(sl.zip ~ '^[0-9]{5}' OR (sl.country = 'CA' AND sl.zip ~ '^[A-Z][0-9][A-Z]'))"
+            }
+            Self::City => "NULLIF(TRIM(sl.city), '') IS NOT NULL",
+            Self::County => "sl.county_fips IS NOT NULL",
+        }
+    }
+}
+
+/// Active store counts for one area.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LocationDensityRow {
+    pub area_key: String,
+    pub state: Option<String>,
+    pub location_count: i64,
+    pub brand_count: i64,
+    pub portfolio_count: i64,
+    pub competitor_count: i64,
+    /// Slugs of brands with at least one active store in the area.
+    pub brand_slugs: Vec<String>,
+}
+
+/// Filters for [`list_location_density`].
+#[derive(Debug, Clone, Default)]
+pub struct LocationDensityFilters<'a> {
+    /// Brand set to count; empty means every active brand.
+    pub brand_slugs: &'a [String],
+    pub relationship: Option<&'a str>,
+    pub state: Option<&'a str>,
+    /// ISO 3166-1 alpha-2 country code.
+    pub country: Option<&'a str>,
+}
+
+/// Reference geography for [`list_location_geo_rollup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeoRollupLevel {
+    County,
+    /// Nielsen Designated Market Area.
+    Dma,
+}
+
+impl GeoRollupLevel {
+    /// Parse the API/CLI value (`county` or `dma`).
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "county" => Some(Self::County),
+            "dma" => Some(Self::Dma),
+            _ => None,
+        }
+    }
+}
+
+/// Footprint of a brand set in one county or DMA.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LocationGeoRollupRow {
+    /// County FIPS or DMA code.
+    pub area_code: String,
+    pub area_name: Option<String>,
+    /// County state; `NULL` for DMAs, which can cross state lines.
+    pub state: Option<String>,
+    pub population: Option<i64>,
+    pub location_count: i64,
+    pub portfolio_count: i64,
+    pub competitor_count: i64,
+    pub portfolio_brand_count: i64,
+    pub competitor_brand_count: i64,
+    /// Active stores per 100,000 residents; `NULL` without population data.
+    pub stores_per_100k: Option<f64>,
+    /// Active store count keyed by brand slug.
+    pub brand_counts: serde_json::Value,
+}
+
+/// Active locations within `radius_miles` of a point, nearest first.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_locations_within_radius(
+    pool: &PgPool,
+    search: &RadiusSearch<'_>,
+    limit: i64,
+) -> Result<Vec<NearbyLocationRow>, sqlx::Error> {
+    let bbox = bounding_box(search.latitude, search.longitude, search.radius_miles);
+    let distance = haversine_sql(
+        "$1::float8",
+        "$2::float8",
+        "sl.latitude::float8",
+        "sl.longitude::float8",
+    );
+    let query = format!(
+        "SELECT * FROM (\
+            SELECT \
+                sl.id, sl.name AS store_name, \
+                sl.address_line1, sl.city, sl.state, sl.zip, \
+                sl.latitude::float8 AS latitude, \
+                sl.longitude::float8 AS longitude, \
+                sl.retail_chain, sl.retail_channel, \
+                b.name AS brand_name, b.slug AS brand_slug, \
+                b.relationship AS brand_relationship, \
+                {distance} AS distance_miles \
+            FROM store_locations sl \
+            JOIN brands b ON b.id = sl.brand_id \
+            WHERE sl.is_active = TRUE \
+              AND sl.latitude BETWEEN $4::NUMERIC AND $5::NUMERIC \
+              AND sl.longitude BETWEEN $6::NUMERIC AND $7::NUMERIC \
+              AND b.is_active = TRUE \
+              AND b.deleted_at IS NULL \
+              AND ($8::TEXT IS NULL OR b.slug = $8) \
+              AND ($9::TEXT IS NULL OR b.relationship = $9) \
+              AND ($11::TEXT IS NULL OR sl.country = $11)\
+         ) nearby \
+         WHERE distance_miles <= $3 \
+         ORDER BY distance_miles ASC, id ASC \
+         LIMIT $10"
+    );
+    sqlx::query_as::<_, NearbyLocationRow>(&query)
+        .bind(search.latitude)
+        .bind(search.longitude)
+        .bind(search.radius_miles)
+        .bind(bbox.min_lat)
+        .bind(bbox.max_lat)
+        .bind(bbox.min_lng)
+        .bind(bbox.max_lng)
+        .bind(search.brand_slug)
+        .bind(search.relationship)
+        .bind(limit)
+        .bind(search.country)
+        .fetch_all(pool)
+        .await
+}
+
+/// Approximate a ZIP code's location as the centroid of geocoded stores in it.
+///
+/// Returns `None` when no stored location in that ZIP has coordinates.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn resolve_zip_centroid(
+    pool: &PgPool,
+    zip: &str,
+) -> Result<Option<(f64, f64)>, sqlx::Error> {
+    let (lat, lng) = sqlx::query_as::<_, (Option<f64>, Option<f64>)>(
+        "SELECT AVG(latitude)::float8, AVG(longitude)::float8 \
+         FROM store_locations \
+         WHERE LEFT(zip, 5) = LEFT($1, 5) \
+           AND latitude IS NOT NULL \
+           AND longitude IS NOT NULL",
+    )
+    .bind(zip.trim())
+    .fetch_one(pool)
+    .await?;
+    Ok(lat.zip(lng))
+}
+
+/// For each active, geocoded portfolio store, the nearest competitor stores.
+///
+/// Pages over portfolio stores by `id ASC`; `cursor` is the last
+/// `portfolio_location_id` of the previous page (exclusive) and `limit` is the
+/// number of portfolio stores per page. Each portfolio store yields up to
+/// `per_store` rows (nearest first), or a single row with `NULL` competitor
+/// columns when nothing lies within `max_miles`.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_nearest_competitor_stores(
+    pool: &PgPool,
+    search: &NearestCompetitorSearch<'_>,
+    limit: i64,
+    cursor: Option<i64>,
+) -> Result<Vec<NearestCompetitorRow>, sqlx::Error> {
+    let distance = haversine_sql(
+        "p.lat",
+        "p.lng",
+        "c.latitude::float8",
+        "c.longitude::float8",
+    );
+    let query = format!(
+        "WITH p AS (\
+            SELECT sl.id, sl.name, sl.city, sl.state, b.slug AS brand_slug, \
+                   sl.latitude::float8 AS lat, sl.longitude::float8 AS lng \
+            FROM store_locations sl \
+            JOIN brands b ON b.id = sl.brand_id \
+            WHERE sl.is_active = TRUE \
+              AND sl.latitude IS NOT NULL \
+              AND sl.longitude IS NOT NULL \
+              AND b.relationship = 'portfolio' \
+              AND b.is_active = TRUE \
+              AND b.deleted_at IS NULL \
+              AND ($1::TEXT IS NULL OR b.slug = $1) \
+              AND ($5::BIGINT IS NULL OR sl.id > $5) \
+              AND ($7::TEXT IS NULL OR sl.country = $7) \
+            ORDER BY sl.id ASC \
+            LIMIT $6\
+         ) \
+         SELECT \
+            p.id AS portfolio_location_id, \
+            p.name AS portfolio_store_name, \
+            p.brand_slug AS portfolio_brand_slug, \
+            p.city AS portfolio_city, \
+            p.state AS portfolio_state, \
+            n.id AS competitor_location_id, \
+            n.name AS competitor_store_name, \
+            n.brand_name AS competitor_brand_name, \
+            n.brand_slug AS competitor_brand_slug, \
+            n.city AS competitor_city, \
+            n.state AS competitor_state, \
+            n.distance_miles \
+         FROM p \
+         LEFT JOIN LATERAL (\
+            SELECT * FROM (\
+                SELECT c.id, c.name, c.city, c.state, \
+                       cb.name AS brand_name, cb.slug AS brand_slug, \
+                       {distance} AS distance_miles \
+                FROM store_locations c \
+                JOIN brands cb ON cb.id = c.brand_id \
+                WHERE c.is_active = TRUE \
+                  AND cb.relationship = 'competitor' \
+                  AND cb.is_active = TRUE \
+                  AND cb.deleted_at IS NULL \
+                  AND ($2::TEXT IS NULL OR cb.slug = $2) \
+                  AND c.latitude BETWEEN (p.lat - $3 / 69.0)::NUMERIC \
+                      AND (p.lat + $3 / 69.0)::NUMERIC \
+                  AND c.longitude BETWEEN \
+                      (p.lng - $3 / (69.0 * GREATEST(COS(RADIANS(p.lat)), 0.01)))::NUMERIC \
+                      AND (p.lng + $3 / (69.0 * GREATEST(COS(RADIANS(p.lat)), 0.01)))::NUMERIC\
+            ) candidates \
+            WHERE distance_miles <= $3 \
+            ORDER BY distance_miles ASC, id ASC \
+            LIMIT $4\
+         ) n ON TRUE \
+         ORDER BY p.id ASC, n.distance_miles ASC NULLS LAST"
+    );
+    sqlx::query_as::<_, NearestCompetitorRow>(&query)
+        .bind(search.portfolio_brand_slug)
+        .bind(search.competitor_brand_slug)
+        .bind(search.max_miles)
+        .bind(search.per_store)
+        .bind(cursor)
+        .bind(limit)
+        .bind(search.country)
+        .fetch_all(pool)
+        .await
+}
+
+/// Active store counts per area for a brand set, densest first.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_location_density(
+    pool: &PgPool,
+    grouping: DensityGrouping,
+    filters: &LocationDensityFilters<'_>,
+    limit: i64,
+) -> Result<Vec<LocationDensityRow>, sqlx::Error> {
+    let area_key = grouping.area_key_sql();
+    let area_filter = grouping.area_filter_sql();
+    let query = format!(
+        "SELECT \
+            {area_key} AS area_key, \
+            sl.state, \
+            COUNT(*) AS location_count, \
+            COUNT(DISTINCT sl.brand_id) AS brand_count, \
+            COUNT(*) FILTER (WHERE b.relationship = 'portfolio') AS portfolio_count, \
+            COUNT(*) FILTER (WHERE b.relationship = 'competitor') AS competitor_count, \
+            ARRAY_AGG(DISTINCT b.slug ORDER BY b.slug) AS brand_slugs \
+         FROM store_locations sl \
+         JOIN brands b ON b.id = sl.brand_id \
+         WHERE sl.is_active = TRUE \
+           AND {area_filter} \
+           AND b.is_active = TRUE \
+           AND b.deleted_at IS NULL \
+           AND (cardinality($1::TEXT[]) = 0 OR b.slug = ANY($1)) \
+           AND ($2::TEXT IS NULL OR b.relationship = $2) \
+           AND ($3::TEXT IS NULL OR sl.state = $3) \
+           AND ($5::TEXT IS NULL OR sl.country = $5) \
+         GROUP BY 1, 2 \
+         ORDER BY location_count DESC, area_key ASC \
+         LIMIT $4"
+    );
+    sqlx::query_as::<_, LocationDensityRow>(&query)
+        .bind(filters.brand_slugs)
+        .bind(filters.relationship)
+        .bind(filters.state)
+        .bind(limit)
+        .bind(filters.country)
+        .fetch_all(pool)
+        .await
+}
+
+/// Per-county or per-DMA footprint for a brand set, largest first.
+///
+/// Relies on `store_locations.county_fips`/`dma_code`, populated from the
+/// reference tables by [`crate::assign_location_geographies`]. DMA population
+/// is the sum of its member counties.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_location_geo_rollup(
+    pool: &PgPool,
+    level: GeoRollupLevel,
+    filters: &LocationDensityFilters<'_>,
+    limit: i64,
+) -> Result<Vec<LocationGeoRollupRow>, sqlx::Error> {
+    let (area_column, area_join) = match level {
+        GeoRollupLevel::County => (
+            "sl.county_fips",
+            "LEFT JOIN (\
+                SELECT fips AS code, name, state, population::BIGINT AS population \
+                FROM geo_counties\
+             ) area ON area.code = pb.area_code",
+        ),
+        GeoRollupLevel::Dma => (
+            "sl.dma_code",
+            "LEFT JOIN (\
+                SELECT d.code, d.name, NULL::TEXT AS state, \
+                       SUM(c.population)::BIGINT AS population \
+                FROM geo_dmas d \
+                LEFT JOIN geo_counties c ON c.dma_code = d.code \
+                GROUP BY d.code, d.name\
+             ) area ON area.code = pb.area_code",
+        ),
+    };
+    let query = format!(
+        "WITH per_brand AS (\
+            SELECT {area_column} AS area_code, b.slug, b.relationship, COUNT(*) AS n \
+            FROM store_locations sl \
+            JOIN brands b ON b.id = sl.brand_id \
+            WHERE sl.is_active = TRUE \
+              AND {area_column} IS NOT NULL \
+              AND b.is_active = TRUE \
+              AND b.deleted_at IS NULL \
+              AND (cardinality($1::TEXT[]) = 0 OR b.slug = ANY($1)) \
+              AND ($2::TEXT IS NULL OR b.relationship = $2) \
+              AND ($3::TEXT IS NULL OR sl.state = $3) \
+              AND ($5::TEXT IS NULL OR sl.country = $5) \
+            GROUP BY 1, 2, 3\
+         ) \
+         SELECT \
+            pb.area_code, \
+            area.name AS area_name, \
+            area.state, \
+            area.population, \
+            SUM(pb.n)::BIGINT AS location_count, \
+            COALESCE(SUM(pb.n) FILTER (WHERE pb.relationship = 'portfolio'), 0)::BIGINT \
+                AS portfolio_count, \
+            COALESCE(SUM(pb.n) FILTER (WHERE pb.relationship = 'competitor'), 0)::BIGINT \
+                AS competitor_count, \
+            COUNT(*) FILTER (WHERE pb.relationship = 'portfolio') AS portfolio_brand_count, \
+            COUNT(*) FILTER (WHERE pb.relationship = 'competitor') AS competitor_brand_count, \
+            (SUM(pb.n) * 100000.0 / NULLIF(area.population, 0))::float8 AS stores_per_100k, \
+            jsonb_object_agg(pb.slug, pb.n) AS brand_counts \
+         FROM per_brand pb \
+         {area_join} \
+         GROUP BY pb.area_code, area.name, area.state, area.population \
+         ORDER BY location_count DESC, pb.area_code ASC \
+         LIMIT $4"
+    );
+    sqlx::query_as::<_, LocationGeoRollupRow>(&query)
+        .bind(filters.brand_slugs)
+        .bind(filters.relationship)
+        .bind(filters.state)
+        .bind(limit)
+        .bind(filters.country)
+        .fetch_all(pool)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn haversine_matches_known_distance() {
+        // Columbia, SC to Charleston, SC is roughly 100 miles as the crow flies.
+        let miles = haversine_miles(34.0007, -81.0348, 32.7765, -79.9311);
+        assert!((miles - 104.0).abs() < 3.0, "got {miles}");
+    }
+
+    #[test]
+    fn haversine_is_zero_for_same_point() {
+        assert!(haversine_miles(34.0, -81.0, 34.0, -81.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn bounding_box_contains_radius_edge_points() {
+        let (lat, lng, r) = (34.0, -81.0, 25.0);
+        let bbox = bounding_box(lat, lng, r);
+        // Points due north and due east at exactly `r` miles must fall inside.
+        let north = lat + r / 69.0;
+        assert!(north <= bbox.max_lat + 1e-9);
+        let east_deg = r / (69.0 * lat.to_radians().cos());
+        assert!(lng + east_deg <= bbox.max_lng + 1e-9);
+        assert!(haversine_miles(lat, lng, lat, lng + east_deg) <= r + 0.5);
+    }
+
+    #[test]
+    fn density_grouping_parses_known_values() {
+        assert_eq!(DensityGrouping::parse("zip"), Some(DensityGrouping::Zip));
+        assert_eq!(DensityGrouping::parse("city"), Some(DensityGrouping::City));
+        assert_eq!(
+            DensityGrouping::parse("county"),
+            Some(DensityGrouping::County)
+        );
+        assert_eq!(DensityGrouping::parse("state"), None);
+        assert_eq!(GeoRollupLevel::parse("dma"), Some(GeoRollupLevel::Dma));
+    }
+}
@@ -0,0 +1,88 @@
+//! Weekly active store counts reconstructed from location history, the input
+//! series for growth forecasting.
+
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+/// Active locations for one brand and state at the end of one ISO week.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LocationWeeklyCountRow {
+    /// Monday of the week.
+    pub week_start: NaiveDate,
+    pub brand_name: String,
+    pub brand_slug: String,
+    pub brand_relationship: String,
+    pub state: String,
+    pub active_count: i64,
+}
+
+/// Active location counts per brand, state and week over the last `weeks`
+/// ISO weeks (the current week included).
+///
+/// Each brand/state series starts at the week its first store was seen;
+/// earlier weeks are omitted rather than reported as zero, so a brand picked
+/// up mid-window does not read as sudden growth. Later weeks with no active
+/// stores are zero-filled.
+///
+/// A store counts as active in a week when it was first seen before the
+/// week ended and is still active or was last seen after the week ended.
+/// Rows are ordered by brand, state, then `week_start ASC`, so each
+/// brand/state series is contiguous.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_location_weekly_counts(
+    pool: &PgPool,
+    brand_slug: Option<&str>,
+    state: Option<&str>,
+    country: Option<&str>,
+    weeks: i32,
+) -> Result<Vec<LocationWeeklyCountRow>, sqlx::Error> {
+    sqlx::query_as::<_, LocationWeeklyCountRow>(
+        "WITH weeks AS (\
+            SELECT generate_series(\
+                date_trunc('week', NOW()) - make_interval(weeks => $4 - 1), \
+                date_trunc('week', NOW()), \
+                INTERVAL '1 week'\
+            ) AS week_start\
+         ), \
+         stores AS (\
+            SELECT sl.brand_id, sl.state, sl.first_seen_at, sl.last_seen_at, sl.is_active \
+            FROM store_locations sl \
+            JOIN brands b ON b.id = sl.brand_id \
+            WHERE sl.state IS NOT NULL AND sl.state != '' \
+              AND b.is_active = TRUE \
+              AND b.deleted_at IS NULL \
+              AND ($1::TEXT IS NULL OR b.slug = $1) \
+              AND ($2::TEXT IS NULL OR sl.state = $2) \
+              AND ($3::TEXT IS NULL OR sl.country = $3)\
+         ), \
+         pairs AS (\
+            SELECT brand_id, state, date_trunc('week', MIN(first_seen_at)) AS first_week \
+            FROM stores GROUP BY brand_id, state\
+         ) \
+         SELECT \
+            w.week_start::date AS week_start, \
+            b.name AS brand_name, \
+            b.slug AS brand_slug, \
+            b.relationship AS brand_relationship, \
+            p.state, \
+            COUNT(s.brand_id) AS active_count \
+         FROM pairs p \
+         JOIN weeks w ON w.week_start >= p.first_week \
+         JOIN brands b ON b.id = p.brand_id \
+         LEFT JOIN stores s \
+            ON s.brand_id = p.brand_id AND s.state = p.state \
+           AND s.first_seen_at < w.week_start + INTERVAL '1 week' \
+           AND (s.is_active OR s.last_seen_at >= w.week_start + INTERVAL '1 week') \
+         GROUP BY w.week_start, b.name, b.slug, b.relationship, p.state \
+         ORDER BY b.slug, p.state, w.week_start",
+    )
+    .bind(brand_slug)
+    .bind(state)
+    .bind(country)
+    .bind(weeks)
+    .fetch_all(pool)
+    .await
+}
@@ -0,0 +1,197 @@
+//! Closure hysteresis for store locations.
+//!
+//! Locators drop stores from individual responses all the time (radius
+//! searches especially), so a location missing from one scrape is not
+//! treated as closed. Each miss bumps `store_locations.consecutive_misses`;
+//! the location is deactivated once the count reaches the threshold for its
+//! `locator_source`, or once it has gone unseen for too many days.
+
+use std::collections::{BTreeMap, HashSet};
+
+use sqlx::PgPool;
+
+use super::events::{LocationEventType, NewLocationEvent};
+
+/// When a missing location is marked inactive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocationMissPolicy {
+    /// Consecutive misses before deactivation, for sources without an override.
+    pub default_threshold: u32,
+    /// Per-`locator_source` thresholds (lower-case source names).
+    pub source_thresholds: BTreeMap<String, u32>,
+    /// A location unseen for longer than this is deactivated on its next
+    /// miss; `0` disables the age rule.
+    pub max_missing_days: u32,
+}
+
+impl Default for LocationMissPolicy {
+    fn default() -> Self {
+        Self {
+            default_threshold: 2,
+            source_thresholds: BTreeMap::new(),
+            max_missing_days: 14,
+        }
+    }
+}
+
+impl LocationMissPolicy {
+    #[must_use]
+    pub fn from_app_config(config: &scbdb_core::AppConfig) -> Self {
+        Self {
+            default_threshold: config.location_miss_threshold,
+            source_thresholds: config.location_miss_thresholds.clone(),
+            max_missing_days: config.location_miss_max_days,
+        }
+    }
+
+    /// Threshold for locations collected from `locator_source`.
+    #[must_use]
+    pub fn threshold_for(&self, locator_source: Option<&str>) -> u32 {
+        locator_source
+            .and_then(|s| self.source_thresholds.get(&s.to_ascii_lowercase()))
+            .copied()
+            .unwrap_or(self.default_threshold)
+    }
+}
+
+/// Result of [`record_location_misses`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocationMissOutcome {
+    /// Locations missed this scrape but kept active (below threshold).
+    pub pending: u64,
+    /// `location_key`s deactivated by this scrape.
+    pub deactivated_keys: Vec<String>,
+}
+
+impl LocationMissOutcome {
+    #[must_use]
+    pub fn deactivated(&self) -> u64 {
+        self.deactivated_keys.len() as u64
+    }
+
+    /// Drop `removed` events for locations that stayed active, so the change
+    /// feed only reports closures that were actually applied.
+    pub fn retain_confirmed_removals(&self, events: &mut Vec<NewLocationEvent>) {
+        let deactivated: HashSet<&str> = self.deactivated_keys.iter().map(String::as_str).collect();
+        events.retain(|e| {
+            e.event_type != LocationEventType::Removed
+                || deactivated.contains(e.location_key.as_str())
+        });
+    }
+}
+
+/// Ids of the rows that `moved`/`renamed` events replaced; these are the same
+/// store under a new key, so they are deactivated without waiting.
+fn superseded_location_ids(events: &[NewLocationEvent]) -> Vec<i64> {
+    events
+        .iter()
+        .filter(|e| {
+            matches!(
+                e.event_type,
+                LocationEventType::Moved | LocationEventType::Renamed
+            )
+        })
+        .filter_map(|e| e.details.get("previous_location_id")?.as_i64())
+        .collect()
+}
+
+/// Record a miss for every active location of `brand_id` not in
+/// `active_keys`, deactivating those that reached their threshold.
+///
+/// Replaces [`crate::deactivate_missing_locations`] in the collection
+/// pipelines. `events` is the diff for the same scrape (may be empty); rows
+/// superseded by a `moved`/`renamed` event are deactivated immediately.
+/// Call after the upsert, which resets the counter for every location seen.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the update fails.
+pub async fn record_location_misses(
+    pool: &PgPool,
+    brand_id: i64,
+    active_keys: &[String],
+    events: &[NewLocationEvent],
+    policy: &LocationMissPolicy,
+) -> Result<LocationMissOutcome, sqlx::Error> {
+    let superseded = superseded_location_ids(events);
+    let (sources, thresholds): (Vec<String>, Vec<i32>) = policy
+        .source_thresholds
+        .iter()
+        .map(|(source, n)| (source.clone(), i32::try_from(*n).unwrap_or(i32::MAX)))
+        .unzip();
+
+    let rows: Vec<(String, bool)> = sqlx::query_as(
+        "UPDATE store_locations sl \
+         SET consecutive_misses = sl.consecutive_misses + 1, \
+             is_active = NOT ( \
+                 sl.id = ANY($3) \
+                 OR sl.consecutive_misses + 1 >= COALESCE( \
+                     (SELECT t.threshold \
+                      FROM UNNEST($4::text[], $5::int4[]) AS t(source, threshold) \
+                      WHERE t.source = LOWER(sl.locator_source)), \
+                     $6) \
+                 OR ($7 > 0 AND sl.last_seen_at < NOW() - make_interval(days => $7))), \
+             updated_at = NOW() \
+         WHERE sl.brand_id = $1 \
+           AND sl.is_active = TRUE \
+           AND sl.location_key != ALL($2::text[]) \
+         RETURNING sl.location_key, sl.is_active",
+    )
+    .bind(brand_id)
+    .bind(active_keys)
+    .bind(&superseded)
+    .bind(&sources)
+    .bind(&thresholds)
+    .bind(i32::try_from(policy.default_threshold).unwrap_or(i32::MAX))
+    .bind(i32::try_from(policy.max_missing_days).unwrap_or(i32::MAX))
+    .fetch_all(pool)
+    .await?;
+
+    let mut outcome = LocationMissOutcome::default();
+    for (location_key, still_active) in rows {
+        if still_active {
+            outcome.pending += 1;
+        } else {
+            outcome.deactivated_keys.push(location_key);
+        }
+    }
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn threshold_uses_source_override_case_insensitively() {
+        let policy = LocationMissPolicy {
+            source_thresholds: BTreeMap::from([("destini".to_string(), 4)]),
+            ..LocationMissPolicy::default()
+        };
+        assert_eq!(policy.threshold_for(Some("Destini")), 4);
+        assert_eq!(policy.threshold_for(Some("stockist")), 2);
+        assert_eq!(policy.threshold_for(None), 2);
+    }
+
+    #[test]
+    fn retain_confirmed_removals_keeps_other_events() {
+        let event = |key: &str, event_type| NewLocationEvent {
+            location_key: key.to_string(),
+            event_type,
+            state: None,
+            details: serde_json::json!({}),
+        };
+        let outcome = LocationMissOutcome {
+            pending: 1,
+            deactivated_keys: vec!["gone".to_string()],
+        };
+        let mut events = vec![
+            event("gone", LocationEventType::Removed),
+            event("flaky", LocationEventType::Removed),
+            event("fresh", LocationEventType::Added),
+        ];
+        outcome.retain_confirmed_removals(&mut events);
+        let keys: Vec<&str> = events.iter().map(|e| e.location_key.as_str()).collect();
+        assert_eq!(keys, ["gone", "fresh"]);
+    }
+}
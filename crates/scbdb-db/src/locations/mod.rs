@@ -1,14 +1,65 @@
 //! Database operations for the `store_locations` table.
 
+mod distribution;
+mod events;
+mod geo;
+mod history;
+mod misses;
+mod quarantine;
 mod read;
+mod rekey;
+mod tiles;
 mod types;
+mod whitespace;
 mod write;
 
+pub use distribution::{
+    list_product_match_candidates, list_sku_distribution, record_product_sweep,
+    ProductMatchCandidateRow, ProductSweepInput, ProductSweepOutcome, SkuDistributionFilters,
+    SkuDistributionRow,
+};
+pub use events::{
+    diff_location_snapshots, insert_store_location_events, list_location_net_change_weekly,
+    list_location_snapshots_for_brand, list_store_location_events, LocationEventFilters,
+    LocationEventType, LocationNetChangeRow, LocationSnapshotRow, NewLocationEvent,
+    StoreLocationEventRow,
+};
+pub use geo::{
+    bounding_box, haversine_miles, list_location_density, list_location_geo_rollup,
+    list_locations_within_radius, list_nearest_competitor_stores, resolve_zip_centroid,
+    BoundingBox, DensityGrouping, GeoRollupLevel, LocationDensityFilters, LocationDensityRow,
+    LocationGeoRollupRow, NearbyLocationRow, NearestCompetitorRow, NearestCompetitorSearch,
+    RadiusSearch, EARTH_RADIUS_MILES,
+};
+pub use history::{list_location_weekly_counts, LocationWeeklyCountRow};
+pub use misses::{record_location_misses, LocationMissOutcome, LocationMissPolicy};
+pub use quarantine::{
+    approve_location_quarantine, list_location_quarantines, quarantine_location_scrape_on_drop,
+    reject_location_quarantine, LocationDropAssessment, LocationDropGuard, LocationQuarantineRow,
+    QuarantineApplySummary, QuarantinedScrape,
+};
 pub use read::{
     get_active_location_keys_for_brand, list_active_location_pins, list_active_locations_by_brand,
-    list_locations_by_state, list_locations_dashboard_summary, list_new_locations_since,
+    list_locations_by_state, list_locations_dashboard_summary, list_locations_for_classification,
+    list_locations_for_details, list_new_locations_since,
+};
+pub use rekey::{
+    list_locations_for_rekey, rekey_store_locations, LocationKeyInput, LocationRekey,
+    LocationRekeySummary,
+};
+pub use tiles::{
+    get_location_data_version, list_location_tile_features, LocationDataVersion,
+    LocationTileFeatureRow, LocationTileFilters, TileCoord, MAX_TILE_ZOOM, TILE_SIZE_PX,
 };
 pub use types::{
-    LocationPinRow, LocationsByStateRow, LocationsDashboardRow, NewStoreLocation, StoreLocationRow,
+    LocationClassificationInput, LocationClassificationUpdate, LocationDetailsInput,
+    LocationDetailsUpdate, LocationPinRow, LocationsByStateRow, LocationsDashboardRow,
+    NewCarriedProduct, NewStoreLocation, StoreLocationRow,
+};
+pub use whitespace::{
+    list_location_whitespace, WhitespaceFilters, WhitespaceGapRow, WhitespaceGrouping,
+};
+pub use write::{
+    deactivate_missing_locations, update_location_classifications, update_location_details,
+    upsert_store_locations,
 };
-pub use write::{deactivate_missing_locations, upsert_store_locations};
@@ -0,0 +1,345 @@
+//! Mass-deactivation guardrail for location collection.
+//!
+//! A provider that silently returns a partial result (40 of 900 stores) would
+//! otherwise deactivate most of a brand's footprint. Before applying a scrape,
+//! collectors call [`quarantine_location_scrape_on_drop`]; when the new count
+//! falls too far below the brand's baseline the scrape is stored in
+//! `location_quarantines` instead, to be approved (applied) or rejected.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::events::{
+    diff_location_snapshots, insert_store_location_events, list_location_snapshots_for_brand,
+};
+use super::types::NewStoreLocation;
+use super::write::{deactivate_missing_locations, upsert_store_locations_tx};
+use crate::DbError;
+
+/// Number of recent successful location runs whose median feeds the baseline.
+const HISTORY_RUNS: i64 = 5;
+
+/// Thresholds for quarantining a location scrape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocationDropGuard {
+    /// Largest drop from the baseline, in percent, applied without review.
+    pub max_drop_pct: u32,
+    /// Baselines below this are too small for a percentage to mean much.
+    pub min_baseline: i64,
+}
+
+impl Default for LocationDropGuard {
+    fn default() -> Self {
+        Self {
+            max_drop_pct: 50,
+            min_baseline: 20,
+        }
+    }
+}
+
+impl LocationDropGuard {
+    #[must_use]
+    pub fn from_app_config(config: &scbdb_core::AppConfig) -> Self {
+        Self {
+            max_drop_pct: config.location_max_drop_pct,
+            min_baseline: i64::from(config.location_drop_min_baseline),
+        }
+    }
+
+    /// Percentage drop from `baseline` to `new_count` when it exceeds the
+    /// threshold; `None` when the scrape may be applied.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // location counts are far below 2^52
+    pub fn exceeded(&self, baseline: i64, new_count: i64) -> Option<f64> {
+        if baseline <= 0 || baseline < self.min_baseline || new_count >= baseline {
+            return None;
+        }
+        let drop_pct = (baseline - new_count) as f64 * 100.0 / baseline as f64;
+        (drop_pct > f64::from(self.max_drop_pct)).then_some(drop_pct)
+    }
+}
+
+/// Counts behind a quarantine decision.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocationDropAssessment {
+    /// Active locations stored for the brand when the scrape ran.
+    pub active_count: i64,
+    /// `max(active_count, median of recent successful runs)`.
+    pub baseline_count: i64,
+    pub new_count: i64,
+    pub drop_pct: f64,
+}
+
+/// A scrape held back by [`quarantine_location_scrape_on_drop`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuarantinedScrape {
+    pub public_id: Uuid,
+    pub assessment: LocationDropAssessment,
+}
+
+/// Quarantine `locations` instead of applying them when they would shrink the
+/// brand's footprint by more than the guard allows.
+///
+/// The baseline is the larger of the brand's current active count and the
+/// median record count of its last successful `locations` collection runs,
+/// so a footprint already eroded by an earlier partial scrape still trips the
+/// guard. Any older pending quarantine for the brand is superseded.
+///
+/// Returns `None` when the scrape is safe to apply.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if a query fails, in which case the caller
+/// should not apply the scrape either.
+pub async fn quarantine_location_scrape_on_drop(
+    pool: &PgPool,
+    brand_id: i64,
+    collection_run_id: Option<i64>,
+    locations: &[NewStoreLocation],
+    guard: &LocationDropGuard,
+) -> Result<Option<QuarantinedScrape>, DbError> {
+    let (active_count, history_median): (i64, Option<i64>) = sqlx::query_as(
+        "SELECT \
+             (SELECT COUNT(*) FROM store_locations \
+              WHERE brand_id = $1 AND is_active = TRUE), \
+             (SELECT percentile_cont(0.5) WITHIN GROUP (ORDER BY h.records_processed)::BIGINT \
+              FROM (\
+                  SELECT crb.records_processed \
+                  FROM collection_run_brands crb \
+                  JOIN collection_runs cr ON cr.id = crb.collection_run_id \
+                  WHERE crb.brand_id = $1 \
+                    AND crb.status = 'succeeded' \
+                    AND cr.run_type = 'locations' \
+                  ORDER BY crb.created_at DESC \
+                  LIMIT $2\
+              ) h)",
+    )
+    .bind(brand_id)
+    .bind(HISTORY_RUNS)
+    .fetch_one(pool)
+    .await?;
+
+    let baseline_count = active_count.max(history_median.unwrap_or(0));
+    let new_count = i64::try_from(locations.len()).unwrap_or(i64::MAX);
+    let Some(drop_pct) = guard.exceeded(baseline_count, new_count) else {
+        return Ok(None);
+    };
+    let assessment = LocationDropAssessment {
+        active_count,
+        baseline_count,
+        new_count,
+        drop_pct,
+    };
+
+    let payload = serde_json::to_value(locations).map_err(|e| sqlx::Error::Encode(Box::new(e)))?;
+    let locator_source = locations.iter().find_map(|l| l.locator_source.clone());
+
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "UPDATE location_quarantines \
+         SET status = 'superseded', reviewed_at = NOW() \
+         WHERE brand_id = $1 AND status = 'pending'",
+    )
+    .bind(brand_id)
+    .execute(&mut *tx)
+    .await?;
+    let public_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO location_quarantines \
+             (brand_id, collection_run_id, active_count, baseline_count, new_count, \
+              drop_pct, locator_source, locations) \
+         VALUES ($1, $2, $3, $4, $5, ROUND($6::NUMERIC, 2), $7, $8) \
+         RETURNING public_id",
+    )
+    .bind(brand_id)
+    .bind(collection_run_id)
+    .bind(i32::try_from(active_count).unwrap_or(i32::MAX))
+    .bind(i32::try_from(baseline_count).unwrap_or(i32::MAX))
+    .bind(i32::try_from(new_count).unwrap_or(i32::MAX))
+    .bind(drop_pct)
+    .bind(locator_source)
+    .bind(payload)
+    .fetch_one(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    Ok(Some(QuarantinedScrape {
+        public_id,
+        assessment,
+    }))
+}
+
+/// A quarantined scrape, without its location payload.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LocationQuarantineRow {
+    pub public_id: Uuid,
+    pub brand_slug: String,
+    pub brand_name: String,
+    /// `pending`, `approved`, `rejected` or `superseded`.
+    pub status: String,
+    pub active_count: i32,
+    pub baseline_count: i32,
+    pub new_count: i32,
+    pub drop_pct: Decimal,
+    pub locator_source: Option<String>,
+    pub review_note: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// List quarantined scrapes, newest first, optionally by status.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_location_quarantines(
+    pool: &PgPool,
+    status: Option<&str>,
+    limit: i64,
+) -> Result<Vec<LocationQuarantineRow>, sqlx::Error> {
+    sqlx::query_as::<_, LocationQuarantineRow>(
+        "SELECT q.public_id, b.slug AS brand_slug, b.name AS brand_name, q.status, \
+                q.active_count, q.baseline_count, q.new_count, q.drop_pct, \
+                q.locator_source, q.review_note, q.reviewed_at, q.created_at \
+         FROM location_quarantines q \
+         JOIN brands b ON b.id = q.brand_id \
+         WHERE ($1::TEXT IS NULL OR q.status = $1) \
+         ORDER BY q.created_at DESC, q.id DESC \
+         LIMIT $2",
+    )
+    .bind(status)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// What approving a quarantine changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QuarantineApplySummary {
+    pub brand_id: i64,
+    pub new: u64,
+    pub updated: u64,
+    pub deactivated: u64,
+    pub events: u64,
+}
+
+/// Lock a pending quarantine and fetch its brand, run and payload, or
+/// explain why it cannot be reviewed.
+async fn lock_pending_quarantine(
+    conn: &mut PgConnection,
+    public_id: Uuid,
+) -> Result<(i64, Option<i64>, serde_json::Value), DbError> {
+    let row: Option<(String, i64, Option<i64>, serde_json::Value)> = sqlx::query_as(
+        "SELECT status, brand_id, collection_run_id, locations \
+         FROM location_quarantines WHERE public_id = $1 \
+         FOR UPDATE",
+    )
+    .bind(public_id)
+    .fetch_optional(conn)
+    .await?;
+    match row {
+        None => Err(DbError::NotFound),
+        Some((status, ..)) if status != "pending" => {
+            Err(DbError::QuarantineNotPending { public_id, status })
+        }
+        Some((_, brand_id, run_id, locations)) => Ok((brand_id, run_id, locations)),
+    }
+}
+
+/// Mark a locked pending quarantine reviewed.
+async fn mark_quarantine_reviewed(
+    conn: &mut PgConnection,
+    public_id: Uuid,
+    status: &str,
+    note: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE location_quarantines \
+         SET status = $2, reviewed_at = NOW(), review_note = $3 \
+         WHERE public_id = $1",
+    )
+    .bind(public_id)
+    .bind(status)
+    .bind(note)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+/// Apply a quarantined scrape as if it had passed the guard: upsert its
+/// locations, deactivate the rest, re-assign county/DMA, record change
+/// events and mark the quarantine `approved`, all in one transaction.
+///
+/// The quarantine row is locked first, so concurrent reviews of the same
+/// scrape apply it at most once; a failure leaves it pending for another
+/// attempt.
+///
+/// # Errors
+///
+/// Returns [`DbError::NotFound`] for an unknown id,
+/// [`DbError::QuarantineNotPending`] if it was already reviewed or
+/// superseded, or [`DbError::Sqlx`] if a write fails.
+pub async fn approve_location_quarantine(
+    pool: &PgPool,
+    public_id: Uuid,
+    note: Option<&str>,
+) -> Result<QuarantineApplySummary, DbError> {
+    let mut tx = pool.begin().await?;
+    let (brand_id, run_id, payload) = lock_pending_quarantine(&mut tx, public_id).await?;
+    let locations: Vec<NewStoreLocation> =
+        serde_json::from_value(payload).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+    let active_keys: Vec<String> = locations.iter().map(|l| l.location_key.clone()).collect();
+
+    let prev = list_location_snapshots_for_brand(&mut *tx, brand_id).await?;
+    let (new, updated) = upsert_store_locations_tx(&mut tx, brand_id, &locations).await?;
+    let deactivated = deactivate_missing_locations(&mut *tx, brand_id, &active_keys).await?;
+    crate::geo_reference::assign_location_geographies_tx(&mut tx, Some(brand_id)).await?;
+    let events = diff_location_snapshots(&prev, &locations);
+    let events = insert_store_location_events(&mut *tx, brand_id, run_id, &events).await?;
+
+    mark_quarantine_reviewed(&mut tx, public_id, "approved", note).await?;
+    tx.commit().await?;
+
+    Ok(QuarantineApplySummary {
+        brand_id,
+        new,
+        updated,
+        deactivated,
+        events,
+    })
+}
+
+/// Discard a quarantined scrape, leaving the stored footprint unchanged.
+///
+/// # Errors
+///
+/// Returns [`DbError::NotFound`] for an unknown id,
+/// [`DbError::QuarantineNotPending`] if it was already reviewed or
+/// superseded, or [`DbError::Sqlx`] if the update fails.
+pub async fn reject_location_quarantine(
+    pool: &PgPool,
+    public_id: Uuid,
+    note: Option<&str>,
+) -> Result<(), DbError> {
+    let mut tx = pool.begin().await?;
+    lock_pending_quarantine(&mut tx, public_id).await?;
+    mark_quarantine_reviewed(&mut tx, public_id, "rejected", note).await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guard_flags_large_drops_from_meaningful_baselines() {
+        let guard = LocationDropGuard::default();
+        let drop = guard.exceeded(900, 40).expect("95% drop should trip");
+        assert!((drop - 95.555).abs() < 0.01);
+        assert_eq!(guard.exceeded(900, 450), None, "exactly 50% is allowed");
+        assert_eq!(guard.exceeded(900, 1_200), None);
+        assert_eq!(guard.exceeded(10, 0), None, "below min_baseline");
+    }
+}
@@ -5,7 +5,10 @@ use std::collections::HashSet;
 use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 
-use super::types::{LocationPinRow, LocationsByStateRow, LocationsDashboardRow, StoreLocationRow};
+use super::types::{
+    LocationClassificationInput, LocationDetailsInput, LocationPinRow, LocationsByStateRow,
+    LocationsDashboardRow, StoreLocationRow,
+};
 
 /// Shared column list for `StoreLocationRow` queries to avoid duplication.
 const STORE_LOCATION_COLUMNS: &str = "\
@@ -13,6 +16,8 @@ const STORE_LOCATION_COLUMNS: &str = "\
     sl.name, sl.address_line1, sl.city, sl.state, sl.zip, \
     sl.country, sl.latitude, sl.longitude, sl.phone, \
     sl.external_id, sl.locator_source, \
+    sl.retail_chain, sl.retail_channel, \
+    sl.hours, sl.website, sl.email, sl.store_type, \
     sl.first_seen_at, sl.last_seen_at, sl.is_active, \
     sl.created_at, sl.updated_at";
 
@@ -64,13 +69,16 @@ pub async fn list_new_locations_since(
 
 /// Return per-brand location stats for all brands with at least one active location.
 ///
-/// Ordered by `active_count DESC`.
+/// `channel_mix` maps each retail channel to the brand's active location count;
+/// unclassified stores are counted under `unknown`. Pass `country` to count
+/// only stores in that country. Ordered by `active_count DESC`.
 ///
 /// # Errors
 ///
 /// Returns [`sqlx::Error`] if the query fails.
 pub async fn list_locations_dashboard_summary(
     pool: &PgPool,
+    country: Option<&str>,
 ) -> Result<Vec<LocationsDashboardRow>, sqlx::Error> {
     sqlx::query_as::<_, LocationsDashboardRow>(
         "SELECT \
@@ -92,48 +100,83 @@ pub async fn list_locations_dashboard_summary(
                 WHERE sl2.brand_id = b.id \
                   AND sl2.is_active = TRUE \
                   AND sl2.locator_source IS NOT NULL \
+                  AND ($1::TEXT IS NULL OR sl2.country = $1) \
                 GROUP BY sl2.locator_source \
                 ORDER BY COUNT(*) DESC \
                 LIMIT 1\
             ) AS locator_source, \
-            MAX(sl.last_seen_at) FILTER (WHERE sl.is_active = TRUE) AS last_seen_at \
+            MAX(sl.last_seen_at) FILTER (WHERE sl.is_active = TRUE) AS last_seen_at, \
+            (\
+                SELECT COALESCE(jsonb_object_agg(mix.channel, mix.n), '{}'::jsonb) \
+                FROM (\
+                    SELECT COALESCE(sl3.retail_channel, 'unknown') AS channel, COUNT(*) AS n \
+                    FROM store_locations sl3 \
+                    WHERE sl3.brand_id = b.id AND sl3.is_active = TRUE \
+                      AND ($1::TEXT IS NULL OR sl3.country = $1) \
+                    GROUP BY 1\
+                ) mix\
+            ) AS channel_mix \
          FROM brands b \
          JOIN store_locations sl ON sl.brand_id = b.id \
          WHERE b.is_active = TRUE AND b.deleted_at IS NULL \
+           AND ($1::TEXT IS NULL OR sl.country = $1) \
          GROUP BY b.id, b.name, b.slug \
          HAVING COUNT(*) FILTER (WHERE sl.is_active = TRUE) > 0 \
          ORDER BY active_count DESC",
     )
+    .bind(country)
     .fetch_all(pool)
     .await
 }
 
-/// Return state-level location counts across all active locations.
+/// Return state/province-level location counts across all active locations.
 ///
-/// Used to color the US state coverage tile map on the dashboard.
-/// Ordered by `location_count DESC`.
+/// Used to color the state coverage tile map on the dashboard. Rows are keyed
+/// by country and state, so Canadian provinces appear alongside US states;
+/// pass `country` to restrict to one country. Each row carries the state's
+/// channel mix (active locations per `retail_channel`). Ordered by
+/// `location_count DESC`.
 ///
 /// # Errors
 ///
 /// Returns [`sqlx::Error`] if the query fails.
 pub async fn list_locations_by_state(
     pool: &PgPool,
+    country: Option<&str>,
 ) -> Result<Vec<LocationsByStateRow>, sqlx::Error> {
     sqlx::query_as::<_, LocationsByStateRow>(
-        "SELECT \
-            sl.state, \
-            COUNT(DISTINCT sl.brand_id) AS brand_count, \
-            COUNT(*) AS location_count \
-         FROM store_locations sl \
-         JOIN brands b ON b.id = sl.brand_id \
-         WHERE sl.is_active = TRUE \
-           AND sl.state IS NOT NULL \
-           AND sl.state != '' \
-           AND b.is_active = TRUE \
-           AND b.deleted_at IS NULL \
-         GROUP BY sl.state \
+        "WITH active AS (\
+            SELECT sl.country, sl.state, sl.brand_id, \
+                   COALESCE(sl.retail_channel, 'unknown') AS channel \
+            FROM store_locations sl \
+            JOIN brands b ON b.id = sl.brand_id \
+            WHERE sl.is_active = TRUE \
+              AND sl.state IS NOT NULL \
+              AND sl.state != '' \
+              AND b.is_active = TRUE \
+              AND b.deleted_at IS NULL \
+              AND ($1::TEXT IS NULL OR sl.country = $1)\
+         ), \
+         mix AS (\
+            SELECT country, state, jsonb_object_agg(channel, n) AS channel_mix \
+            FROM (\
+                SELECT country, state, channel, COUNT(*) AS n \
+                FROM active GROUP BY country, state, channel\
+            ) c \
+            GROUP BY country, state\
+         ) \
+         SELECT \
+            a.country, \
+            a.state, \
+            COUNT(DISTINCT a.brand_id) AS brand_count, \
+            COUNT(*) AS location_count, \
+            mix.channel_mix \
+         FROM active a \
+         JOIN mix ON mix.country = a.country AND mix.state = a.state \
+         GROUP BY a.country, a.state, mix.channel_mix \
          ORDER BY location_count DESC",
     )
+    .bind(country)
     .fetch_all(pool)
     .await
 }
@@ -142,7 +185,7 @@ pub async fn list_locations_by_state(
 ///
 /// Supports cursor-based pagination keyed on `sl.id ASC`. Pass `cursor` as the
 /// `id` of the last pin from the previous page to fetch the next page. Pass
-/// `brand_slug` to filter to a single brand.
+/// `brand_slug` to filter to a single brand and `country` to a single country.
 ///
 /// The caller should request `limit + 1` rows to detect whether a next page
 /// exists.
@@ -157,6 +200,7 @@ pub async fn list_active_location_pins(
     limit: i64,
     cursor: Option<i64>,
     brand_slug: Option<&str>,
+    country: Option<&str>,
 ) -> Result<Vec<LocationPinRow>, sqlx::Error> {
     sqlx::query_as::<_, LocationPinRow>(
         "SELECT \
@@ -164,7 +208,7 @@ pub async fn list_active_location_pins(
             sl.latitude::float8 AS latitude, \
             sl.longitude::float8 AS longitude, \
             sl.name AS store_name, \
-            sl.address_line1, sl.city, sl.state, sl.zip, sl.locator_source, \
+            sl.address_line1, sl.city, sl.state, sl.zip, sl.country, sl.locator_source, \
             b.name AS brand_name, \
             b.slug AS brand_slug, \
             b.relationship AS brand_relationship, \
@@ -178,12 +222,14 @@ pub async fn list_active_location_pins(
            AND b.deleted_at IS NULL \
            AND ($1::BIGINT IS NULL OR sl.id > $1) \
            AND ($2::TEXT IS NULL OR b.slug = $2) \
+           AND ($4::TEXT IS NULL OR sl.country = $4) \
          ORDER BY sl.id ASC \
          LIMIT $3",
     )
     .bind(cursor)
     .bind(brand_slug)
     .bind(limit)
+    .bind(country)
     .fetch_all(pool)
     .await
 }
@@ -204,6 +250,8 @@ pub async fn list_active_locations_by_brand(
                 name, address_line1, city, state, zip, \
                 country, latitude, longitude, phone, \
                 external_id, locator_source, \
+                retail_chain, retail_channel, \
+                hours, website, email, store_type, \
                 first_seen_at, last_seen_at, is_active, \
                 created_at, updated_at \
          FROM store_locations \
@@ -236,3 +284,47 @@ pub async fn get_active_location_keys_for_brand(
     .await?;
     Ok(rows.into_iter().map(|(k,)| k).collect())
 }
+
+/// Return the fields needed to (re)classify stored locations by retailer.
+///
+/// Pass `brand_id` to restrict to one brand. Inactive locations are included
+/// so that a reactivated store already carries a current classification.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_locations_for_classification(
+    pool: &PgPool,
+    brand_id: Option<i64>,
+) -> Result<Vec<LocationClassificationInput>, sqlx::Error> {
+    sqlx::query_as::<_, LocationClassificationInput>(
+        "SELECT id, name, raw_data \
+         FROM store_locations \
+         WHERE ($1::BIGINT IS NULL OR brand_id = $1) \
+         ORDER BY id",
+    )
+    .bind(brand_id)
+    .fetch_all(pool)
+    .await
+}
+
+/// Fetch the provider payload of every stored location (optionally for one
+/// brand) so detail fields can be re-mapped after the mappers change.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_locations_for_details(
+    pool: &PgPool,
+    brand_id: Option<i64>,
+) -> Result<Vec<LocationDetailsInput>, sqlx::Error> {
+    sqlx::query_as::<_, LocationDetailsInput>(
+        "SELECT id, locator_source, raw_data \
+         FROM store_locations \
+         WHERE ($1::BIGINT IS NULL OR brand_id = $1) \
+         ORDER BY id",
+    )
+    .bind(brand_id)
+    .fetch_all(pool)
+    .await
+}
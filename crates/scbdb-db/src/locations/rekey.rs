@@ -0,0 +1,190 @@
+//! Re-keying of stored locations after a change to location key inputs.
+//!
+//! `location_key` is computed by the scraper from the normalised address, so
+//! when normalisation changes (country detection, state names, ZIP+4) rows
+//! stored earlier no longer match the keys new scrapes produce. The CLI
+//! recomputes every row's key from its stored fields and applies the result
+//! with [`rekey_store_locations`], merging rows that now share a key.
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+/// Stored fields a location key is computed from.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LocationKeyInput {
+    pub id: i64,
+    pub brand_id: i64,
+    pub location_key: String,
+    pub name: String,
+    pub address_line1: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub zip: Option<String>,
+    pub country: String,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+/// Recomputed key and normalised address fields for a stored location.
+#[derive(Debug, Clone)]
+pub struct LocationRekey {
+    pub id: i64,
+    pub location_key: String,
+    pub state: Option<String>,
+    pub zip: Option<String>,
+    pub country: String,
+}
+
+/// What [`rekey_store_locations`] changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LocationRekeySummary {
+    /// Rows whose key or normalised fields changed.
+    pub rekeyed: u64,
+    /// Duplicate rows folded into another row with the same new key.
+    pub merged: u64,
+}
+
+/// Fetch the key inputs of every stored location, optionally for one brand.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_locations_for_rekey(
+    pool: &PgPool,
+    brand_id: Option<i64>,
+) -> Result<Vec<LocationKeyInput>, sqlx::Error> {
+    sqlx::query_as::<_, LocationKeyInput>(
+        "SELECT id, brand_id, location_key, name, address_line1, city, state, zip, \
+                country, last_seen_at \
+         FROM store_locations \
+         WHERE ($1::BIGINT IS NULL OR brand_id = $1) \
+         ORDER BY id",
+    )
+    .bind(brand_id)
+    .fetch_all(pool)
+    .await
+}
+
+/// Apply recomputed keys in one transaction.
+///
+/// `rekeys` must cover every location of each brand it touches. Rows that end
+/// up sharing a `(brand_id, location_key)` are merged into the most recently
+/// seen one: it keeps the earliest `first_seen_at`, change events and carried
+/// products of the others are moved to it, and the others are deleted.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if a statement fails; nothing is written then.
+pub async fn rekey_store_locations(
+    pool: &PgPool,
+    rekeys: &[LocationRekey],
+) -> Result<LocationRekeySummary, sqlx::Error> {
+    if rekeys.is_empty() {
+        return Ok(LocationRekeySummary::default());
+    }
+
+    let ids: Vec<i64> = rekeys.iter().map(|r| r.id).collect();
+    let keys: Vec<&str> = rekeys.iter().map(|r| r.location_key.as_str()).collect();
+    let states: Vec<Option<&str>> = rekeys.iter().map(|r| r.state.as_deref()).collect();
+    let zips: Vec<Option<&str>> = rekeys.iter().map(|r| r.zip.as_deref()).collect();
+    let countries: Vec<&str> = rekeys.iter().map(|r| r.country.as_str()).collect();
+
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        "CREATE TEMP TABLE location_rekey ON COMMIT DROP AS \
+         SELECT u.id, u.location_key, u.state, u.zip, u.country \
+         FROM UNNEST($1::bigint[], $2::text[], $3::text[], $4::text[], $5::text[]) \
+              AS u(id, location_key, state, zip, country)",
+    )
+    .bind(&ids)
+    .bind(&keys)
+    .bind(&states)
+    .bind(&zips)
+    .bind(&countries)
+    .execute(&mut *tx)
+    .await?;
+
+    // Each duplicate and the row it folds into.
+    sqlx::query(
+        "CREATE TEMP TABLE location_rekey_merge ON COMMIT DROP AS \
+         SELECT id, keeper_id FROM ( \
+             SELECT sl.id, \
+                    FIRST_VALUE(sl.id) OVER ( \
+                        PARTITION BY sl.brand_id, COALESCE(r.location_key, sl.location_key) \
+                        ORDER BY sl.last_seen_at DESC, sl.id DESC) AS keeper_id \
+             FROM store_locations sl \
+             LEFT JOIN location_rekey r ON r.id = sl.id \
+             WHERE sl.brand_id IN ( \
+                 SELECT brand_id FROM store_locations \
+                 WHERE id IN (SELECT id FROM location_rekey)) \
+         ) g \
+         WHERE id <> keeper_id",
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "UPDATE store_locations k \
+         SET first_seen_at = m.first_seen_at \
+         FROM ( \
+             SELECT mm.keeper_id, MIN(sl.first_seen_at) AS first_seen_at \
+             FROM location_rekey_merge mm \
+             JOIN store_locations sl ON sl.id = mm.id \
+             GROUP BY mm.keeper_id \
+         ) m \
+         WHERE k.id = m.keeper_id AND m.first_seen_at < k.first_seen_at",
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "UPDATE store_location_events e \
+         SET store_location_id = m.keeper_id \
+         FROM location_rekey_merge m \
+         WHERE e.store_location_id = m.id",
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO store_location_products \
+             (store_location_id, product_ref, product_name, upc, product_id, source, \
+              first_seen_at, last_seen_at) \
+         SELECT m.keeper_id, p.product_ref, p.product_name, p.upc, p.product_id, p.source, \
+                p.first_seen_at, p.last_seen_at \
+         FROM store_location_products p \
+         JOIN location_rekey_merge m ON m.id = p.store_location_id \
+         ON CONFLICT (store_location_id, product_ref) DO NOTHING",
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let merged = sqlx::query(
+        "DELETE FROM store_locations \
+         WHERE id IN (SELECT id FROM location_rekey_merge)",
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    let rekeyed = sqlx::query(
+        "UPDATE store_locations sl \
+         SET location_key = r.location_key, \
+             state = r.state, \
+             zip = r.zip, \
+             country = r.country, \
+             updated_at = NOW() \
+         FROM location_rekey r \
+         WHERE sl.id = r.id \
+           AND (sl.location_key <> r.location_key \
+                OR sl.state IS DISTINCT FROM r.state \
+                OR sl.zip IS DISTINCT FROM r.zip \
+                OR sl.country <> r.country)",
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    tx.commit().await?;
+    Ok(LocationRekeySummary { rekeyed, merged })
+}
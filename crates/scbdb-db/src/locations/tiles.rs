@@ -0,0 +1,259 @@
+//! Map tile queries: active store locations inside one web-mercator XYZ
+//! tile, clustered on a pixel grid below the clustering zoom cut-off.
+//!
+//! Cluster cells are aligned to the world pixel grid and divide the 256-px
+//! tile evenly, so a cluster never straddles two tiles and neighbouring tiles
+//! agree on where each cluster sits.
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use super::geo::BoundingBox;
+
+/// Deepest zoom level a tile can be requested at.
+pub const MAX_TILE_ZOOM: u8 = 22;
+
+/// Logical tile size in pixels; cluster cell sizes are relative to it.
+pub const TILE_SIZE_PX: u32 = 256;
+
+/// Web-mercator latitude limit (the square world map).
+const MAX_MERCATOR_LAT: f64 = 85.051_128_78;
+
+/// An XYZ (slippy map) tile address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TileCoord {
+    pub z: u8,
+    pub x: u32,
+    pub y: u32,
+}
+
+impl TileCoord {
+    /// Validate a tile address: `z <= MAX_TILE_ZOOM` and `x, y < 2^z`.
+    #[must_use]
+    pub fn new(z: u8, x: u32, y: u32) -> Option<Self> {
+        if z > MAX_TILE_ZOOM {
+            return None;
+        }
+        let tiles = 1_u64 << z;
+        (u64::from(x) < tiles && u64::from(y) < tiles).then_some(Self { z, x, y })
+    }
+
+    /// Width of the world map at this zoom, in pixels.
+    #[must_use]
+    pub fn world_px(self) -> f64 {
+        f64::from(TILE_SIZE_PX) * f64::from(1_u32 << self.z.min(31))
+    }
+
+    /// Lat/lng rectangle covered by the tile.
+    #[must_use]
+    pub fn bounds(self) -> BoundingBox {
+        let n = f64::from(1_u32 << self.z.min(31));
+        let lng = |x: f64| x / n * 360.0 - 180.0;
+        let lat = |y: f64| {
+            (std::f64::consts::PI * (1.0 - 2.0 * y / n))
+                .sinh()
+                .atan()
+                .to_degrees()
+        };
+        BoundingBox {
+            min_lat: lat(f64::from(self.y) + 1.0),
+            max_lat: lat(f64::from(self.y)),
+            min_lng: lng(f64::from(self.x)),
+            max_lng: lng(f64::from(self.x) + 1.0),
+        }
+    }
+
+    /// Position of `(lat, lng)` within the tile on a `0..extent` grid
+    /// (origin top-left, y growing south).
+    #[must_use]
+    pub fn project(self, lat: f64, lng: f64, extent: u32) -> (i64, i64) {
+        let (wx, wy) = world_position(lat, lng);
+        let n = f64::from(1_u32 << self.z.min(31));
+        let extent = f64::from(extent);
+        // Truncation is intended: positions are snapped to the extent grid.
+        #[allow(clippy::cast_possible_truncation)]
+        let to_tile =
+            |world: f64, tile: u32| ((world * n - f64::from(tile)) * extent).round() as i64;
+        (to_tile(wx, self.x), to_tile(wy, self.y))
+    }
+}
+
+/// Web-mercator position as a fraction of the world map (`0.0..=1.0`).
+fn world_position(lat: f64, lng: f64) -> (f64, f64) {
+    let lat = lat.clamp(-MAX_MERCATOR_LAT, MAX_MERCATOR_LAT).to_radians();
+    let x = (lng + 180.0) / 360.0;
+    let y = (1.0 - (lat.tan() + 1.0 / lat.cos()).ln() / std::f64::consts::PI) / 2.0;
+    (x, y)
+}
+
+/// Filters for [`list_location_tile_features`].
+#[derive(Debug, Clone, Default)]
+pub struct LocationTileFilters<'a> {
+    /// Brand set to include; empty means every active brand.
+    pub brand_slugs: &'a [String],
+    /// `portfolio` or `competitor`.
+    pub relationship: Option<&'a str>,
+    /// ISO 3166-1 alpha-2 country code.
+    pub country: Option<&'a str>,
+}
+
+/// One tile feature: a cluster of stores, or a single store.
+///
+/// The store and brand columns are set only when `point_count` is 1.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LocationTileFeatureRow {
+    /// Cluster centroid, or the store's own position.
+    pub latitude: f64,
+    pub longitude: f64,
+    pub point_count: i64,
+    pub portfolio_count: i64,
+    pub competitor_count: i64,
+    pub brand_slugs: Vec<String>,
+    pub location_id: Option<i64>,
+    pub store_name: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub brand_name: Option<String>,
+    pub brand_slug: Option<String>,
+    pub brand_relationship: Option<String>,
+}
+
+/// Active, geocoded store locations inside `tile`.
+///
+/// With `cluster_px` set, stores are grouped into square cells of that many
+/// pixels (it should divide [`TILE_SIZE_PX`]); each cell yields one feature
+/// at its stores' centroid. Without it every store is its own feature.
+/// Largest clusters first.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_location_tile_features(
+    pool: &PgPool,
+    tile: TileCoord,
+    cluster_px: Option<u32>,
+    filters: &LocationTileFilters<'_>,
+) -> Result<Vec<LocationTileFeatureRow>, sqlx::Error> {
+    let bounds = tile.bounds();
+    let cells = match cluster_px {
+        Some(_) => {
+            "FLOOR((sl.longitude::float8 + 180.0) / 360.0 * $8 / $9)::BIGINT AS cx, \
+             FLOOR((1.0 - LN(TAN(RADIANS(sl.latitude::float8)) \
+                 + 1.0 / COS(RADIANS(sl.latitude::float8))) / PI()) / 2.0 * $8 / $9)::BIGINT AS cy"
+        }
+        None => "sl.id AS cx, 0::BIGINT AS cy",
+    };
+    let query = format!(
+        "WITH pts AS (\
+            SELECT sl.id, sl.name, sl.city, sl.state, \
+                   sl.latitude::float8 AS lat, sl.longitude::float8 AS lng, \
+                   b.name AS brand_name, b.slug AS brand_slug, b.relationship, \
+                   {cells} \
+            FROM store_locations sl \
+            JOIN brands b ON b.id = sl.brand_id \
+            WHERE sl.is_active = TRUE \
+              AND sl.latitude > $1::NUMERIC AND sl.latitude <= $2::NUMERIC \
+              AND sl.longitude >= $3::NUMERIC AND sl.longitude < $4::NUMERIC \
+              AND b.is_active = TRUE \
+              AND b.deleted_at IS NULL \
+              AND (cardinality($5::TEXT[]) = 0 OR b.slug = ANY($5)) \
+              AND ($6::TEXT IS NULL OR b.relationship = $6) \
+              AND ($7::TEXT IS NULL OR sl.country = $7)\
+         ) \
+         SELECT \
+            AVG(lat) AS latitude, \
+            AVG(lng) AS longitude, \
+            COUNT(*) AS point_count, \
+            COUNT(*) FILTER (WHERE relationship = 'portfolio') AS portfolio_count, \
+            COUNT(*) FILTER (WHERE relationship = 'competitor') AS competitor_count, \
+            ARRAY_AGG(DISTINCT brand_slug ORDER BY brand_slug) AS brand_slugs, \
+            CASE WHEN COUNT(*) = 1 THEN MIN(id) END AS location_id, \
+            CASE WHEN COUNT(*) = 1 THEN MIN(name) END AS store_name, \
+            CASE WHEN COUNT(*) = 1 THEN MIN(city) END AS city, \
+            CASE WHEN COUNT(*) = 1 THEN MIN(state) END AS state, \
+            CASE WHEN COUNT(*) = 1 THEN MIN(brand_name) END AS brand_name, \
+            CASE WHEN COUNT(*) = 1 THEN MIN(brand_slug) END AS brand_slug, \
+            CASE WHEN COUNT(*) = 1 THEN MIN(relationship) END AS brand_relationship \
+         FROM pts \
+         GROUP BY cx, cy \
+         ORDER BY point_count DESC, MIN(id) ASC"
+    );
+    sqlx::query_as::<_, LocationTileFeatureRow>(&query)
+        .bind(bounds.min_lat)
+        .bind(bounds.max_lat)
+        .bind(bounds.min_lng)
+        .bind(bounds.max_lng)
+        .bind(filters.brand_slugs)
+        .bind(filters.relationship)
+        .bind(filters.country)
+        .bind(tile.world_px())
+        .bind(f64::from(cluster_px.unwrap_or(TILE_SIZE_PX)))
+        .fetch_all(pool)
+        .await
+}
+
+/// Identity of the stored location footprint, for caching views derived
+/// from it (map tiles).
+///
+/// Changes when a `locations` collection run starts or finishes, and when a
+/// quarantined scrape is approved.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, sqlx::FromRow)]
+pub struct LocationDataVersion {
+    pub run_id: Option<i64>,
+    pub run_status: Option<String>,
+    pub last_approved_at: Option<DateTime<Utc>>,
+}
+
+/// Fetch the current [`LocationDataVersion`].
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn get_location_data_version(pool: &PgPool) -> Result<LocationDataVersion, sqlx::Error> {
+    sqlx::query_as::<_, LocationDataVersion>(
+        "SELECT \
+            r.id AS run_id, \
+            r.status AS run_status, \
+            (SELECT MAX(reviewed_at) FROM location_quarantines WHERE status = 'approved') \
+                AS last_approved_at \
+         FROM (SELECT 1) one \
+         LEFT JOIN LATERAL (\
+            SELECT id, status FROM collection_runs \
+            WHERE run_type = 'locations' \
+            ORDER BY id DESC \
+            LIMIT 1\
+         ) r ON TRUE",
+    )
+    .fetch_one(pool)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tile_coord_rejects_out_of_range_addresses() {
+        assert!(TileCoord::new(0, 0, 0).is_some());
+        assert!(TileCoord::new(2, 3, 3).is_some());
+        assert!(TileCoord::new(2, 4, 0).is_none());
+        assert!(TileCoord::new(MAX_TILE_ZOOM + 1, 0, 0).is_none());
+    }
+
+    #[test]
+    fn tile_bounds_and_projection_agree() {
+        // Columbia, SC sits in z=10 tile 281/409.
+        let tile = TileCoord::new(10, 281, 409).expect("valid tile");
+        let b = tile.bounds();
+        assert!(b.min_lat < 34.0007 && 34.0007 < b.max_lat, "{b:?}");
+        assert!(b.min_lng < -81.0348 && -81.0348 < b.max_lng, "{b:?}");
+
+        let (x, y) = tile.project(34.0007, -81.0348, 4096);
+        assert!(
+            (0..4096).contains(&x) && (0..4096).contains(&y),
+            "({x}, {y})"
+        );
+        assert_eq!(tile.project(b.max_lat, b.min_lng, 4096), (0, 0));
+        assert_eq!(tile.project(b.min_lat, b.max_lng, 4096), (4096, 4096));
+    }
+}
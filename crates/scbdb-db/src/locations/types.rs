@@ -5,7 +5,9 @@ use rust_decimal::Decimal;
 use uuid::Uuid;
 
 /// Input record for inserting/upserting a store location.
-#[derive(Debug, Clone)]
+///
+/// Serializable so a held-back scrape can be stored in `location_quarantines`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct NewStoreLocation {
     pub location_key: String,
     pub name: String,
@@ -20,6 +22,33 @@ pub struct NewStoreLocation {
     pub external_id: Option<String>,
     pub locator_source: Option<String>,
     pub raw_data: serde_json::Value,
+    /// Canonical retail chain (e.g. `"Total Wine & More"`), when recognised.
+    pub retail_chain: Option<String>,
+    /// Retail channel (`liquor` / `grocery` / `convenience` / `smoke_vape` /
+    /// `on_premise` / `dispensary`), when it can be inferred.
+    pub retail_channel: Option<String>,
+    /// Opening hours as display text, mapped from `raw_data`.
+    #[serde(default)]
+    pub hours: Option<String>,
+    #[serde(default)]
+    pub website: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    /// Provider store type or category.
+    #[serde(default)]
+    pub store_type: Option<String>,
+    /// Products the locator lists at this store; replaces the stored set.
+    #[serde(default)]
+    pub carried_products: Vec<NewCarriedProduct>,
+}
+
+/// A product a locator lists as carried by a store.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NewCarriedProduct {
+    /// UPC, provider product id, or lower-cased name.
+    pub product_ref: String,
+    pub product_name: Option<String>,
+    pub upc: Option<String>,
 }
 
 /// A row from the `store_locations` table.
@@ -43,6 +72,12 @@ pub struct StoreLocationRow {
     pub phone: Option<String>,
     pub external_id: Option<String>,
     pub locator_source: Option<String>,
+    pub retail_chain: Option<String>,
+    pub retail_channel: Option<String>,
+    pub hours: Option<String>,
+    pub website: Option<String>,
+    pub email: Option<String>,
+    pub store_type: Option<String>,
     pub first_seen_at: DateTime<Utc>,
     pub last_seen_at: DateTime<Utc>,
     pub is_active: bool,
@@ -65,14 +100,54 @@ pub struct LocationsDashboardRow {
     pub locator_source: Option<String>,
     /// Timestamp of the most recent active location update.
     pub last_seen_at: Option<DateTime<Utc>>,
+    /// Active location counts keyed by retail channel (`unknown` for unclassified).
+    pub channel_mix: serde_json::Value,
 }
 
 /// Per-state aggregate for the coverage tile map.
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct LocationsByStateRow {
+    pub country: String,
     pub state: String,
     pub brand_count: i64,
     pub location_count: i64,
+    /// Active location counts keyed by retail channel (`unknown` for unclassified).
+    pub channel_mix: serde_json::Value,
+}
+
+/// Minimal store location fields needed to (re)classify a retailer.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LocationClassificationInput {
+    pub id: i64,
+    pub name: String,
+    pub raw_data: Option<serde_json::Value>,
+}
+
+/// Minimal store location fields needed to re-map `raw_data` details.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LocationDetailsInput {
+    pub id: i64,
+    pub locator_source: Option<String>,
+    pub raw_data: Option<serde_json::Value>,
+}
+
+/// Mapped details to write back to a stored location.
+#[derive(Debug, Clone)]
+pub struct LocationDetailsUpdate {
+    pub id: i64,
+    pub hours: Option<String>,
+    pub website: Option<String>,
+    pub email: Option<String>,
+    pub store_type: Option<String>,
+    pub carried_products: Vec<NewCarriedProduct>,
+}
+
+/// Retailer classification to write back to a stored location.
+#[derive(Debug, Clone)]
+pub struct LocationClassificationUpdate {
+    pub id: i64,
+    pub retail_chain: Option<String>,
+    pub retail_channel: Option<String>,
 }
 
 /// A pin row for the map — active locations with coordinates joined with brand info.
@@ -89,6 +164,7 @@ pub struct LocationPinRow {
     pub city: Option<String>,
     pub state: Option<String>,
     pub zip: Option<String>,
+    pub country: String,
     pub locator_source: Option<String>,
     pub brand_name: String,
     pub brand_slug: String,
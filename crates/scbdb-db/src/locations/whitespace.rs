@@ -0,0 +1,198 @@
+//! Whitespace (territory gap) analysis: areas and retailers where competitor
+//! brands sell and portfolio brands do not.
+
+use sqlx::PgPool;
+
+/// Dimension a whitespace gap is reported on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WhitespaceGrouping {
+    /// Five-digit ZIP code; Canadian stores group by forward sortation area.
+    Zip,
+    /// County FIPS code (requires `locations load-geo`).
+    County,
+    /// Canonical retail chain (`store_locations.retail_chain`).
+    Retailer,
+}
+
+impl WhitespaceGrouping {
+    /// Parse the API/CLI value (`zip`, `county` or `retailer`).
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "zip" => Some(Self::Zip),
+            "county" => Some(Self::County),
+            "retailer" => Some(Self::Retailer),
+            _ => None,
+        }
+    }
+
+    fn area_key_sql(self) -> &'static str {
+        match self {
+            Self::Zip => {
+                "CASE WHEN sl.country = 'CA' THEN LEFT(sl.zip, 3) ELSE LEFT(sl.zip, 5) END"
+            }
+            Self::County => "sl.county_fips",
+            Self::Retailer => "sl.retail_chain",
+        }
+    }
+
+    fn area_filter_sql(self) -> &'static str {
+        match self {
+            Self::Zip => {
+                "(sl.zip ~ '^[0-9]{5}' OR (sl.country = 'CA' AND sl.zip ~ '^[A-Z][0-9][A-Z]'))"
+            }
+            Self::County => "sl.county_fips IS NOT NULL",
+            Self::Retailer => "sl.retail_chain IS NOT NULL",
+        }
+    }
+
+    fn area_name_sql(self) -> &'static str {
+        match self {
+            Self::County => "(SELECT gc.name FROM geo_counties gc WHERE gc.fips = g.area_key)",
+            Self::Zip | Self::Retailer => "NULL::TEXT",
+        }
+    }
+}
+
+/// Filters for [`list_location_whitespace`].
+#[derive(Debug, Clone, Default)]
+pub struct WhitespaceFilters<'a> {
+    /// Portfolio brands to test for absence; empty means every portfolio brand.
+    pub portfolio_slugs: &'a [String],
+    /// Competitor brands to count; empty means every competitor brand.
+    pub competitor_slugs: &'a [String],
+    /// Only consider stores in this retail channel.
+    pub channel: Option<&'a str>,
+    pub state: Option<&'a str>,
+    /// ISO 3166-1 alpha-2 country code.
+    pub country: Option<&'a str>,
+}
+
+/// One territory gap.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct WhitespaceGapRow {
+    /// ZIP, county FIPS or retail chain name, depending on the grouping.
+    pub area_key: String,
+    /// County name when grouped by county.
+    pub area_name: Option<String>,
+    /// States the area's stores are in (one for ZIP/county, possibly many for a chain).
+    pub states: Vec<String>,
+    pub competitor_brand_count: i64,
+    pub competitor_store_count: i64,
+    pub competitor_brands: Vec<String>,
+    /// Retail channels of the competitor stores in the area.
+    pub competitor_channels: Vec<String>,
+    pub portfolio_brands_present: Vec<String>,
+    pub portfolio_brands_absent: Vec<String>,
+}
+
+/// Areas (or retailers) where competitor brands have active stores and at
+/// least one portfolio brand has none.
+///
+/// Ranked by number of competitor brands present, then competitor store
+/// count; narrow to a retail channel with [`WhitespaceFilters::channel`].
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn list_location_whitespace(
+    pool: &PgPool,
+    grouping: WhitespaceGrouping,
+    filters: &WhitespaceFilters<'_>,
+    limit: i64,
+) -> Result<Vec<WhitespaceGapRow>, sqlx::Error> {
+    let area_key = grouping.area_key_sql();
+    let area_filter = grouping.area_filter_sql();
+    let area_name = grouping.area_name_sql();
+    let query = format!(
+        "WITH portfolio AS (\
+            SELECT slug FROM brands \
+            WHERE relationship = 'portfolio' \
+              AND is_active = TRUE \
+              AND deleted_at IS NULL \
+              AND (cardinality($1::TEXT[]) = 0 OR slug = ANY($1))\
+         ), \
+         stores AS (\
+            SELECT {area_key} AS area_key, sl.state, sl.retail_channel, \
+                   b.slug, b.relationship \
+            FROM store_locations sl \
+            JOIN brands b ON b.id = sl.brand_id \
+            WHERE sl.is_active = TRUE \
+              AND {area_filter} \
+              AND b.is_active = TRUE \
+              AND b.deleted_at IS NULL \
+              AND ((b.relationship = 'competitor' \
+                    AND (cardinality($2::TEXT[]) = 0 OR b.slug = ANY($2))) \
+                   OR b.slug IN (SELECT slug FROM portfolio)) \
+              AND ($3::TEXT IS NULL OR sl.retail_channel = $3) \
+              AND ($4::TEXT IS NULL OR sl.state = $4) \
+              AND ($6::TEXT IS NULL OR sl.country = $6)\
+         ), \
+         g AS (\
+            SELECT \
+                area_key, \
+                COALESCE(ARRAY_AGG(DISTINCT state) FILTER (WHERE state IS NOT NULL), '{{}}') \
+                    AS states, \
+                COUNT(DISTINCT slug) FILTER (WHERE relationship = 'competitor') \
+                    AS competitor_brand_count, \
+                COUNT(*) FILTER (WHERE relationship = 'competitor') AS competitor_store_count, \
+                COALESCE(ARRAY_AGG(DISTINCT slug) FILTER (WHERE relationship = 'competitor'), '{{}}') \
+                    AS competitor_brands, \
+                COALESCE(ARRAY_AGG(DISTINCT retail_channel) \
+                    FILTER (WHERE relationship = 'competitor' AND retail_channel IS NOT NULL), '{{}}') \
+                    AS competitor_channels, \
+                COALESCE(ARRAY_AGG(DISTINCT slug) FILTER (WHERE relationship = 'portfolio'), '{{}}') \
+                    AS portfolio_brands_present \
+            FROM stores \
+            GROUP BY area_key\
+         ) \
+         SELECT * FROM (\
+            SELECT \
+                g.area_key, \
+                {area_name} AS area_name, \
+                g.states, \
+                g.competitor_brand_count, \
+                g.competitor_store_count, \
+                g.competitor_brands, \
+                g.competitor_channels, \
+                g.portfolio_brands_present, \
+                ARRAY(\
+                    SELECT p.slug FROM portfolio p \
+                    WHERE p.slug <> ALL(g.portfolio_brands_present) \
+                    ORDER BY p.slug\
+                ) AS portfolio_brands_absent \
+            FROM g \
+            WHERE g.competitor_brand_count > 0\
+         ) gaps \
+         WHERE cardinality(portfolio_brands_absent) > 0 \
+         ORDER BY competitor_brand_count DESC, competitor_store_count DESC, area_key ASC \
+         LIMIT $5"
+    );
+    sqlx::query_as::<_, WhitespaceGapRow>(&query)
+        .bind(filters.portfolio_slugs)
+        .bind(filters.competitor_slugs)
+        .bind(filters.channel)
+        .bind(filters.state)
+        .bind(limit)
+        .bind(filters.country)
+        .fetch_all(pool)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn whitespace_grouping_parses_known_values() {
+        assert_eq!(
+            WhitespaceGrouping::parse("retailer"),
+            Some(WhitespaceGrouping::Retailer)
+        );
+        assert_eq!(
+            WhitespaceGrouping::parse("county"),
+            Some(WhitespaceGrouping::County)
+        );
+        assert_eq!(WhitespaceGrouping::parse("city"), None);
+    }
+}
@@ -1,12 +1,17 @@
 //! Write operations for the `store_locations` table.
 
-use sqlx::PgPool;
+use sqlx::{Connection, PgConnection, PgExecutor, PgPool};
 
-use super::types::NewStoreLocation;
+use std::collections::HashSet;
+
+use super::types::{
+    LocationClassificationUpdate, LocationDetailsUpdate, NewCarriedProduct, NewStoreLocation,
+};
 
 const UPSERT_STORE_LOCATIONS_SQL: &str = "INSERT INTO store_locations \
      (brand_id, location_key, name, address_line1, city, state, zip, country, \
-      latitude, longitude, phone, external_id, locator_source, raw_data) \
+      latitude, longitude, phone, external_id, locator_source, raw_data, \
+      retail_chain, retail_channel, address_key, hours, website, email, store_type) \
  SELECT \
      $1, \
      u.location_key, \
@@ -21,7 +26,14 @@ const UPSERT_STORE_LOCATIONS_SQL: &str = "INSERT INTO store_locations \
      u.phone, \
      u.external_id, \
      u.locator_source, \
-     u.raw_data \
+     u.raw_data, \
+     u.retail_chain, \
+     u.retail_channel, \
+     u.address_key, \
+     u.hours, \
+     u.website, \
+     u.email, \
+     u.store_type \
  FROM UNNEST(\
       $2::text[], \
       $3::text[], \
@@ -35,7 +47,14 @@ const UPSERT_STORE_LOCATIONS_SQL: &str = "INSERT INTO store_locations \
       $11::text[], \
       $12::text[], \
       $13::text[], \
-      $14::jsonb[]) \
+      $14::jsonb[], \
+      $15::text[], \
+      $16::text[], \
+      $17::text[], \
+      $18::text[], \
+      $19::text[], \
+      $20::text[], \
+      $21::text[]) \
  AS u(\
       location_key, \
       name, \
@@ -49,10 +68,18 @@ const UPSERT_STORE_LOCATIONS_SQL: &str = "INSERT INTO store_locations \
       phone, \
       external_id, \
       locator_source, \
-      raw_data) \
+      raw_data, \
+      retail_chain, \
+      retail_channel, \
+      address_key, \
+      hours, \
+      website, \
+      email, \
+      store_type) \
  ON CONFLICT (brand_id, location_key) DO UPDATE SET \
      last_seen_at    = NOW(), \
      is_active       = TRUE, \
+     consecutive_misses = 0, \
      updated_at      = NOW(), \
      name            = EXCLUDED.name, \
      address_line1   = EXCLUDED.address_line1, \
@@ -65,8 +92,15 @@ const UPSERT_STORE_LOCATIONS_SQL: &str = "INSERT INTO store_locations \
      phone           = EXCLUDED.phone, \
      external_id     = EXCLUDED.external_id, \
      locator_source  = EXCLUDED.locator_source, \
-     raw_data        = EXCLUDED.raw_data \
- RETURNING (xmax = 0) AS is_new";
+     raw_data        = EXCLUDED.raw_data, \
+     retail_chain    = EXCLUDED.retail_chain, \
+     retail_channel  = EXCLUDED.retail_channel, \
+     address_key     = EXCLUDED.address_key, \
+     hours           = EXCLUDED.hours, \
+     website         = EXCLUDED.website, \
+     email           = EXCLUDED.email, \
+     store_type      = EXCLUDED.store_type \
+ RETURNING id, (xmax = 0) AS is_new";
 
 struct StoreLocationBatch {
     location_keys: Vec<String>,
@@ -82,6 +116,13 @@ struct StoreLocationBatch {
     external_ids: Vec<Option<String>>,
     locator_sources: Vec<Option<String>>,
     raw_datas: Vec<serde_json::Value>,
+    retail_chains: Vec<Option<String>>,
+    retail_channels: Vec<Option<String>>,
+    address_keys: Vec<Option<String>>,
+    hours: Vec<Option<String>>,
+    websites: Vec<Option<String>>,
+    emails: Vec<Option<String>>,
+    store_types: Vec<Option<String>>,
 }
 
 impl StoreLocationBatch {
@@ -100,6 +141,13 @@ impl StoreLocationBatch {
             external_ids: Vec::with_capacity(locations.len()),
             locator_sources: Vec::with_capacity(locations.len()),
             raw_datas: Vec::with_capacity(locations.len()),
+            retail_chains: Vec::with_capacity(locations.len()),
+            retail_channels: Vec::with_capacity(locations.len()),
+            address_keys: Vec::with_capacity(locations.len()),
+            hours: Vec::with_capacity(locations.len()),
+            websites: Vec::with_capacity(locations.len()),
+            emails: Vec::with_capacity(locations.len()),
+            store_types: Vec::with_capacity(locations.len()),
         };
 
         for loc in locations {
@@ -118,6 +166,18 @@ impl StoreLocationBatch {
             batch.external_ids.push(loc.external_id.clone());
             batch.locator_sources.push(loc.locator_source.clone());
             batch.raw_datas.push(loc.raw_data.clone());
+            batch.retail_chains.push(loc.retail_chain.clone());
+            batch.retail_channels.push(loc.retail_channel.clone());
+            batch
+                .address_keys
+                .push(scbdb_core::address::address_match_key(
+                    loc.address_line1.as_deref(),
+                    loc.zip.as_deref(),
+                ));
+            batch.hours.push(loc.hours.clone());
+            batch.websites.push(loc.website.clone());
+            batch.emails.push(loc.email.clone());
+            batch.store_types.push(loc.store_type.clone());
         }
 
         batch
@@ -125,11 +185,11 @@ impl StoreLocationBatch {
 }
 
 async fn run_store_location_upsert(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     brand_id: i64,
     batch: &StoreLocationBatch,
-) -> Result<Vec<bool>, sqlx::Error> {
-    sqlx::query_scalar::<_, bool>(UPSERT_STORE_LOCATIONS_SQL)
+) -> Result<Vec<(i64, bool)>, sqlx::Error> {
+    sqlx::query_as::<_, (i64, bool)>(UPSERT_STORE_LOCATIONS_SQL)
         .bind(brand_id)
         .bind(&batch.location_keys)
         .bind(&batch.names)
@@ -144,7 +204,14 @@ async fn run_store_location_upsert(
         .bind(&batch.external_ids)
         .bind(&batch.locator_sources)
         .bind(&batch.raw_datas)
-        .fetch_all(pool)
+        .bind(&batch.retail_chains)
+        .bind(&batch.retail_channels)
+        .bind(&batch.address_keys)
+        .bind(&batch.hours)
+        .bind(&batch.websites)
+        .bind(&batch.emails)
+        .bind(&batch.store_types)
+        .fetch_all(conn)
         .await
 }
 
@@ -162,6 +229,12 @@ async fn run_store_location_upsert(
 /// performs the type coercion consistently (matching the pattern used in
 /// `upsert_variant` for dosage/size columns).
 ///
+/// `address_key` is derived from the street address and ZIP so locator rows
+/// can be matched to imported accounts.
+///
+/// Each location's `carried_products` replace its rows in
+/// `store_location_products`.
+///
 /// # Errors
 ///
 /// Returns [`sqlx::Error`] if the query fails.
@@ -169,15 +242,33 @@ pub async fn upsert_store_locations(
     pool: &PgPool,
     brand_id: i64,
     locations: &[NewStoreLocation],
+) -> Result<(u64, u64), sqlx::Error> {
+    upsert_store_locations_tx(&mut *pool.acquire().await?, brand_id, locations).await
+}
+
+/// [`upsert_store_locations`] on a caller's connection, typically inside its
+/// transaction.
+pub(crate) async fn upsert_store_locations_tx(
+    conn: &mut PgConnection,
+    brand_id: i64,
+    locations: &[NewStoreLocation],
 ) -> Result<(u64, u64), sqlx::Error> {
     if locations.is_empty() {
         return Ok((0, 0));
     }
 
     let batch = StoreLocationBatch::from_locations(locations);
-    let rows = run_store_location_upsert(pool, brand_id, &batch).await?;
+    let rows = run_store_location_upsert(conn, brand_id, &batch).await?;
 
-    let new_count = rows.iter().filter(|&&is_new| is_new).count() as u64;
+    // RETURNING follows the UNNEST input order, one row per location.
+    let products: Vec<(i64, &[NewCarriedProduct])> = rows
+        .iter()
+        .zip(locations)
+        .map(|((id, _), loc)| (*id, loc.carried_products.as_slice()))
+        .collect();
+    replace_location_products(conn, &products).await?;
+
+    let new_count = rows.iter().filter(|(_, is_new)| *is_new).count() as u64;
     let updated_count = rows.len() as u64 - new_count;
 
     Ok((new_count, updated_count))
@@ -197,8 +288,8 @@ pub async fn upsert_store_locations(
 /// # Errors
 ///
 /// Returns [`sqlx::Error`] if the query fails.
-pub async fn deactivate_missing_locations(
-    pool: &PgPool,
+pub async fn deactivate_missing_locations<'e>(
+    executor: impl PgExecutor<'e>,
     brand_id: i64,
     active_keys: &[String],
 ) -> Result<u64, sqlx::Error> {
@@ -211,9 +302,177 @@ pub async fn deactivate_missing_locations(
     )
     .bind(brand_id)
     .bind(active_keys)
+    .execute(executor)
+    .await?
+    .rows_affected();
+
+    Ok(rows_affected)
+}
+
+/// Write retailer chain/channel classifications back to stored locations.
+///
+/// Used by the classification backfill after the pattern catalog changes.
+/// Applies the whole batch in one `UPDATE … FROM UNNEST(…)` and returns the
+/// number of rows whose classification actually changed.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if the query fails.
+pub async fn update_location_classifications(
+    pool: &PgPool,
+    updates: &[LocationClassificationUpdate],
+) -> Result<u64, sqlx::Error> {
+    if updates.is_empty() {
+        return Ok(0);
+    }
+
+    let ids: Vec<i64> = updates.iter().map(|u| u.id).collect();
+    let chains: Vec<Option<String>> = updates.iter().map(|u| u.retail_chain.clone()).collect();
+    let channels: Vec<Option<String>> = updates.iter().map(|u| u.retail_channel.clone()).collect();
+
+    let rows_affected = sqlx::query(
+        "UPDATE store_locations sl \
+         SET retail_chain = u.retail_chain, \
+             retail_channel = u.retail_channel, \
+             updated_at = NOW() \
+         FROM UNNEST($1::bigint[], $2::text[], $3::text[]) \
+              AS u(id, retail_chain, retail_channel) \
+         WHERE sl.id = u.id \
+           AND (sl.retail_chain IS DISTINCT FROM u.retail_chain \
+                OR sl.retail_channel IS DISTINCT FROM u.retail_channel)",
+    )
+    .bind(&ids)
+    .bind(&chains)
+    .bind(&channels)
     .execute(pool)
     .await?
     .rows_affected();
 
     Ok(rows_affected)
 }
+
+/// Replace the carried-product rows of each listed location.
+///
+/// Products no longer listed for a location are deleted; the rest are
+/// upserted with a fresh `last_seen_at`. Rows recorded by a product sweep
+/// are left alone. Runs in one transaction (a savepoint when `conn` is
+/// already in one).
+async fn replace_location_products(
+    conn: &mut PgConnection,
+    products: &[(i64, &[NewCarriedProduct])],
+) -> Result<(), sqlx::Error> {
+    if products.is_empty() {
+        return Ok(());
+    }
+
+    let location_ids: Vec<i64> = products.iter().map(|(id, _)| *id).collect();
+    let mut seen: HashSet<(i64, &str)> = HashSet::new();
+    let mut ids: Vec<i64> = Vec::new();
+    let mut refs: Vec<&str> = Vec::new();
+    let mut names: Vec<Option<&str>> = Vec::new();
+    let mut upcs: Vec<Option<&str>> = Vec::new();
+    for (id, items) in products {
+        for item in *items {
+            if !seen.insert((*id, item.product_ref.as_str())) {
+                continue;
+            }
+            ids.push(*id);
+            refs.push(&item.product_ref);
+            names.push(item.product_name.as_deref());
+            upcs.push(item.upc.as_deref());
+        }
+    }
+
+    let mut tx = conn.begin().await?;
+    sqlx::query(
+        "DELETE FROM store_location_products p \
+         WHERE p.store_location_id = ANY($1::bigint[]) \
+           AND p.source = 'locator' \
+           AND NOT EXISTS ( \
+               SELECT 1 FROM UNNEST($2::bigint[], $3::text[]) AS u(id, product_ref) \
+               WHERE u.id = p.store_location_id AND u.product_ref = p.product_ref)",
+    )
+    .bind(&location_ids)
+    .bind(&ids)
+    .bind(&refs)
+    .execute(&mut *tx)
+    .await?;
+
+    if !ids.is_empty() {
+        sqlx::query(
+            "INSERT INTO store_location_products \
+                 (store_location_id, product_ref, product_name, upc) \
+             SELECT u.id, u.product_ref, u.product_name, u.upc \
+             FROM UNNEST($1::bigint[], $2::text[], $3::text[], $4::text[]) \
+                  AS u(id, product_ref, product_name, upc) \
+             ON CONFLICT (store_location_id, product_ref) DO UPDATE SET \
+                 product_name = EXCLUDED.product_name, \
+                 upc          = EXCLUDED.upc, \
+                 last_seen_at = NOW()",
+        )
+        .bind(&ids)
+        .bind(&refs)
+        .bind(&names)
+        .bind(&upcs)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await
+}
+
+/// Write re-mapped `raw_data` details back to stored locations.
+///
+/// Used by the details backfill after the field mappers change. Updates the
+/// detail columns in one `UPDATE … FROM UNNEST(…)`, replaces each location's
+/// carried products, and returns the number of rows whose columns changed.
+///
+/// # Errors
+///
+/// Returns [`sqlx::Error`] if a query fails.
+pub async fn update_location_details(
+    pool: &PgPool,
+    updates: &[LocationDetailsUpdate],
+) -> Result<u64, sqlx::Error> {
+    if updates.is_empty() {
+        return Ok(0);
+    }
+
+    let ids: Vec<i64> = updates.iter().map(|u| u.id).collect();
+    let hours: Vec<Option<&str>> = updates.iter().map(|u| u.hours.as_deref()).collect();
+    let websites: Vec<Option<&str>> = updates.iter().map(|u| u.website.as_deref()).collect();
+    let emails: Vec<Option<&str>> = updates.iter().map(|u| u.email.as_deref()).collect();
+    let store_types: Vec<Option<&str>> = updates.iter().map(|u| u.store_type.as_deref()).collect();
+
+    let rows_affected = sqlx::query(
+        "UPDATE store_locations sl \
+         SET hours = u.hours, \
+             website = u.website, \
+             email = u.email, \
+             store_type = u.store_type, \
+             updated_at = NOW() \
+         FROM UNNEST($1::bigint[], $2::text[], $3::text[], $4::text[], $5::text[]) \
+              AS u(id, hours, website, email, store_type) \
+         WHERE sl.id = u.id \
+           AND (sl.hours IS DISTINCT FROM u.hours \
+                OR sl.website IS DISTINCT FROM u.website \
+                OR sl.email IS DISTINCT FROM u.email \
+                OR sl.store_type IS DISTINCT FROM u.store_type)",
+    )
+    .bind(&ids)
+    .bind(&hours)
+    .bind(&websites)
+    .bind(&emails)
+    .bind(&store_types)
+    .execute(pool)
+    .await?
+    .rows_affected();
+
+    let products: Vec<(i64, &[NewCarriedProduct])> = updates
+        .iter()
+        .map(|u| (u.id, u.carried_products.as_slice()))
+        .collect();
+    replace_location_products(&mut *pool.acquire().await?, &products).await?;
+
+    Ok(rows_affected)
+}
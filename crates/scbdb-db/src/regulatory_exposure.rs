@@ -0,0 +1,279 @@
+//! Regulatory exposure of brands' store footprints to pending bills, and its
+//! daily snapshots in `regulatory_exposure_snapshots`.
+//!
+//! The scoring itself lives in [`scbdb_core::regulatory_exposure`].
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use scbdb_core::{
+    bill_pressure, state_exposure, topic_weight, BillTopicsFile, FEDERAL_JURISDICTION,
+    PENDING_ACTIVITY_DAYS,
+};
+use sqlx::PgPool;
+
+use crate::DbError;
+
+/// A brand's exposure in one state on one day.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ExposureStateRow {
+    pub brand_id: i64,
+    pub brand_slug: String,
+    pub brand_name: String,
+    pub state: String,
+    pub snapshot_date: NaiveDate,
+    /// Active stores of the brand in the state.
+    pub store_count: i32,
+    /// Share of the brand's active stores in the state (0–1).
+    pub footprint_share: f64,
+    /// Pending bills in the state, federal bills included.
+    pub pending_bills: i32,
+    /// 0–100 pressure of the state's pending bills.
+    pub state_exposure: f64,
+    /// `footprint_share` × `state_exposure`; a brand's score is the sum.
+    pub contribution: f64,
+}
+
+/// A brand's exposure score on one day.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct BrandExposurePoint {
+    pub brand_slug: String,
+    pub brand_name: String,
+    pub snapshot_date: NaiveDate,
+    /// 0–100 exposure across the brand's footprint.
+    pub score: f64,
+    pub store_count: i64,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct FootprintRow {
+    brand_id: i64,
+    brand_slug: String,
+    brand_name: String,
+    state: String,
+    store_count: i64,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct PendingBillRow {
+    jurisdiction: String,
+    status: String,
+    relevance_score: Option<f64>,
+    topics: Vec<String>,
+}
+
+/// Computes each brand's exposure per state with active stores on `as_of`.
+///
+/// Bills count as pending while introduced, engrossed or enrolled, until
+/// [`PENDING_ACTIVITY_DAYS`] pass without recorded action. Rows are ordered
+/// by brand, largest contribution first.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if a query fails.
+pub async fn compute_regulatory_exposure(
+    pool: &PgPool,
+    taxonomy: &BillTopicsFile,
+    as_of: NaiveDate,
+    brand_slug: Option<&str>,
+) -> Result<Vec<ExposureStateRow>, DbError> {
+    let footprint = sqlx::query_as::<_, FootprintRow>(
+        "SELECT b.id AS brand_id, b.slug AS brand_slug, b.name AS brand_name, \
+                sl.state, COUNT(*) AS store_count \
+         FROM store_locations sl \
+         JOIN brands b ON b.id = sl.brand_id \
+         WHERE sl.is_active = TRUE AND sl.state IS NOT NULL AND sl.state <> '' \
+           AND b.is_active = TRUE AND b.deleted_at IS NULL \
+           AND ($1::TEXT IS NULL OR b.slug = $1) \
+         GROUP BY b.id, b.slug, b.name, sl.state \
+         ORDER BY b.slug, sl.state",
+    )
+    .bind(brand_slug)
+    .fetch_all(pool)
+    .await?;
+
+    let bills = sqlx::query_as::<_, PendingBillRow>(
+        "SELECT b.jurisdiction, b.status, b.relevance_score, \
+                COALESCE(ARRAY_AGG(bt.topic) FILTER (WHERE bt.topic IS NOT NULL), '{}') \
+                    AS topics \
+         FROM bills b \
+         LEFT JOIN bill_topics bt ON bt.bill_id = b.id \
+         WHERE b.deleted_at IS NULL \
+           AND b.status IN ('introduced', 'engrossed', 'enrolled') \
+           AND COALESCE(b.last_action_date, b.status_date, b.introduced_date, \
+                        b.created_at::DATE) > $1::DATE - $2::INTEGER \
+         GROUP BY b.id",
+    )
+    .bind(as_of)
+    .bind(i32::try_from(PENDING_ACTIVITY_DAYS).unwrap_or(i32::MAX))
+    .fetch_all(pool)
+    .await?;
+
+    // Summed pressure and bill count per jurisdiction.
+    let mut pressure: HashMap<&str, (f64, i32)> = HashMap::new();
+    for bill in &bills {
+        let weight = topic_weight(taxonomy, &bill.topics);
+        if let Some(p) = bill_pressure(&bill.status, bill.relevance_score, weight) {
+            let entry = pressure.entry(bill.jurisdiction.as_str()).or_default();
+            entry.0 += p;
+            entry.1 += 1;
+        }
+    }
+    let federal = pressure
+        .get(FEDERAL_JURISDICTION)
+        .copied()
+        .unwrap_or_default();
+
+    let mut brand_totals: HashMap<i64, i64> = HashMap::new();
+    for row in &footprint {
+        *brand_totals.entry(row.brand_id).or_default() += row.store_count;
+    }
+
+    let mut rows: Vec<ExposureStateRow> = footprint
+        .into_iter()
+        .map(|row| {
+            let (state_pressure, state_bills) = pressure
+                .get(row.state.as_str())
+                .copied()
+                .unwrap_or_default();
+            let exposure = state_exposure(state_pressure + federal.0);
+            #[allow(clippy::cast_precision_loss)] // store counts are far below 2^52
+            let share = row.store_count as f64 / brand_totals[&row.brand_id] as f64;
+            ExposureStateRow {
+                brand_id: row.brand_id,
+                brand_slug: row.brand_slug,
+                brand_name: row.brand_name,
+                state: row.state,
+                snapshot_date: as_of,
+                store_count: i32::try_from(row.store_count).unwrap_or(i32::MAX),
+                footprint_share: share,
+                pending_bills: state_bills + federal.1,
+                state_exposure: exposure,
+                contribution: share * exposure,
+            }
+        })
+        .collect();
+    rows.sort_by(|a, b| {
+        a.brand_slug
+            .cmp(&b.brand_slug)
+            .then_with(|| b.contribution.total_cmp(&a.contribution))
+    });
+
+    Ok(rows)
+}
+
+/// Stores `rows` as their brands' snapshot for `snapshot_date`, replacing an
+/// earlier snapshot of the same brands and day. Runs in one transaction.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if a statement fails; nothing is written then.
+pub async fn record_regulatory_exposure(
+    pool: &PgPool,
+    snapshot_date: NaiveDate,
+    rows: &[ExposureStateRow],
+) -> Result<u64, DbError> {
+    let brand_ids: Vec<i64> = rows.iter().map(|r| r.brand_id).collect();
+    let states: Vec<&str> = rows.iter().map(|r| r.state.as_str()).collect();
+    let store_counts: Vec<i32> = rows.iter().map(|r| r.store_count).collect();
+    let shares: Vec<f64> = rows.iter().map(|r| r.footprint_share).collect();
+    let pending: Vec<i32> = rows.iter().map(|r| r.pending_bills).collect();
+    let exposures: Vec<f64> = rows.iter().map(|r| r.state_exposure).collect();
+    let contributions: Vec<f64> = rows.iter().map(|r| r.contribution).collect();
+
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "DELETE FROM regulatory_exposure_snapshots \
+         WHERE snapshot_date = $1 AND brand_id = ANY($2)",
+    )
+    .bind(snapshot_date)
+    .bind(&brand_ids)
+    .execute(&mut *tx)
+    .await?;
+
+    let inserted = sqlx::query(
+        "INSERT INTO regulatory_exposure_snapshots \
+             (brand_id, state, snapshot_date, store_count, footprint_share, pending_bills, \
+              state_exposure, contribution) \
+         SELECT u.brand_id, u.state, $1, u.store_count, u.footprint_share, u.pending_bills, \
+                u.state_exposure, u.contribution \
+         FROM UNNEST($2::BIGINT[], $3::TEXT[], $4::INTEGER[], $5::FLOAT8[], $6::INTEGER[], \
+                     $7::FLOAT8[], $8::FLOAT8[]) \
+              AS u(brand_id, state, store_count, footprint_share, pending_bills, \
+                   state_exposure, contribution)",
+    )
+    .bind(snapshot_date)
+    .bind(&brand_ids)
+    .bind(&states)
+    .bind(&store_counts)
+    .bind(&shares)
+    .bind(&pending)
+    .bind(&exposures)
+    .bind(&contributions)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    tx.commit().await?;
+    Ok(inserted)
+}
+
+/// Each brand's most recent snapshot, per state, largest contribution first.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_latest_regulatory_exposure(
+    pool: &PgPool,
+    brand_slug: Option<&str>,
+) -> Result<Vec<ExposureStateRow>, DbError> {
+    let rows = sqlx::query_as::<_, ExposureStateRow>(
+        "WITH latest AS (\
+            SELECT brand_id, MAX(snapshot_date) AS snapshot_date \
+            FROM regulatory_exposure_snapshots GROUP BY brand_id\
+         ) \
+         SELECT s.brand_id, b.slug AS brand_slug, b.name AS brand_name, s.state, \
+                s.snapshot_date, s.store_count, s.footprint_share, s.pending_bills, \
+                s.state_exposure, s.contribution \
+         FROM regulatory_exposure_snapshots s \
+         JOIN latest l ON l.brand_id = s.brand_id AND l.snapshot_date = s.snapshot_date \
+         JOIN brands b ON b.id = s.brand_id \
+         WHERE b.is_active = TRUE AND b.deleted_at IS NULL \
+           AND ($1::TEXT IS NULL OR b.slug = $1) \
+         ORDER BY b.slug, s.contribution DESC, s.state",
+    )
+    .bind(brand_slug)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Daily exposure scores per brand since `since`, oldest first.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_regulatory_exposure_series(
+    pool: &PgPool,
+    brand_slug: Option<&str>,
+    since: NaiveDate,
+) -> Result<Vec<BrandExposurePoint>, DbError> {
+    let rows = sqlx::query_as::<_, BrandExposurePoint>(
+        "SELECT b.slug AS brand_slug, b.name AS brand_name, s.snapshot_date, \
+                SUM(s.contribution) AS score, SUM(s.store_count)::BIGINT AS store_count \
+         FROM regulatory_exposure_snapshots s \
+         JOIN brands b ON b.id = s.brand_id \
+         WHERE b.is_active = TRUE AND b.deleted_at IS NULL \
+           AND s.snapshot_date >= $2 \
+           AND ($1::TEXT IS NULL OR b.slug = $1) \
+         GROUP BY b.slug, b.name, s.snapshot_date \
+         ORDER BY b.slug, s.snapshot_date",
+    )
+    .bind(brand_slug)
+    .bind(since)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
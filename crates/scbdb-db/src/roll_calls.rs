@@ -0,0 +1,360 @@
+//! Database operations for the `roll_calls` and `roll_call_votes` tables.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::DbError;
+
+/// A row from the `roll_calls` table.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct RollCallRow {
+    pub id: i64,
+    pub bill_id: i64,
+    pub legiscan_roll_call_id: i64,
+    pub vote_date: Option<NaiveDate>,
+    pub chamber: Option<String>,
+    pub description: String,
+    pub yea: i32,
+    pub nay: i32,
+    pub nv: i32,
+    pub absent: i32,
+    pub total: i32,
+    pub passed: bool,
+    pub votes_fetched_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A roll call summary to insert or refresh.
+#[derive(Debug, Clone, Copy)]
+pub struct NewRollCall<'a> {
+    pub legiscan_roll_call_id: i64,
+    pub vote_date: Option<NaiveDate>,
+    pub chamber: Option<&'a str>,
+    pub description: &'a str,
+    pub yea: i32,
+    pub nay: i32,
+    pub nv: i32,
+    pub absent: i32,
+    pub total: i32,
+    pub passed: bool,
+}
+
+/// A roll call whose individual votes have not been fetched yet.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PendingRollCallRow {
+    pub id: i64,
+    pub legiscan_roll_call_id: i64,
+    pub jurisdiction: String,
+    pub bill_number: String,
+}
+
+/// A voter seen on a roll call who has no `legislators` row yet.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UnknownVoterRow {
+    pub legiscan_people_id: i64,
+    pub jurisdiction: String,
+}
+
+/// One legislator's vote on a roll call, with the legislator's details when
+/// known.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct RollCallVoteRow {
+    pub roll_call_id: i64,
+    pub legiscan_people_id: i64,
+    pub legislator_public_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub party: Option<String>,
+    pub vote: String,
+}
+
+/// Inserts a roll call summary or refreshes its counts; returns the internal
+/// id. Fetched votes are kept.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the upsert fails.
+pub async fn upsert_roll_call(
+    pool: &PgPool,
+    bill_id: i64,
+    roll_call: &NewRollCall<'_>,
+) -> Result<i64, DbError> {
+    let id = sqlx::query_scalar::<_, i64>(
+        "INSERT INTO roll_calls \
+             (bill_id, legiscan_roll_call_id, vote_date, chamber, description, \
+              yea, nay, nv, absent, total, passed) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) \
+         ON CONFLICT (legiscan_roll_call_id) DO UPDATE SET \
+             vote_date   = EXCLUDED.vote_date, \
+             chamber     = EXCLUDED.chamber, \
+             description = EXCLUDED.description, \
+             yea         = EXCLUDED.yea, \
+             nay         = EXCLUDED.nay, \
+             nv          = EXCLUDED.nv, \
+             absent      = EXCLUDED.absent, \
+             total       = EXCLUDED.total, \
+             passed      = EXCLUDED.passed \
+         RETURNING id",
+    )
+    .bind(bill_id)
+    .bind(roll_call.legiscan_roll_call_id)
+    .bind(roll_call.vote_date)
+    .bind(roll_call.chamber)
+    .bind(roll_call.description)
+    .bind(roll_call.yea)
+    .bind(roll_call.nay)
+    .bind(roll_call.nv)
+    .bind(roll_call.absent)
+    .bind(roll_call.total)
+    .bind(roll_call.passed)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(id)
+}
+
+/// Maps `LegiScan` roll call ids to internal ids for the stored roll calls
+/// among `legiscan_roll_call_ids`.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn get_roll_call_ids(
+    pool: &PgPool,
+    legiscan_roll_call_ids: &[i64],
+) -> Result<HashMap<i64, i64>, DbError> {
+    let rows = sqlx::query_as::<_, (i64, i64)>(
+        "SELECT legiscan_roll_call_id, id \
+         FROM roll_calls \
+         WHERE legiscan_roll_call_id = ANY($1::bigint[])",
+    )
+    .bind(legiscan_roll_call_ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().collect())
+}
+
+/// Returns roll calls of non-deleted bills whose votes have not been
+/// fetched, oldest first, optionally narrowed to a jurisdiction. Roll calls
+/// whose fetch already failed `max_attempts` times are left out.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_roll_calls_pending_votes(
+    pool: &PgPool,
+    jurisdiction: Option<&str>,
+    max_attempts: i32,
+    limit: i64,
+) -> Result<Vec<PendingRollCallRow>, DbError> {
+    let rows = sqlx::query_as::<_, PendingRollCallRow>(
+        "SELECT rc.id, rc.legiscan_roll_call_id, b.jurisdiction, b.bill_number \
+         FROM roll_calls rc \
+         JOIN bills b ON b.id = rc.bill_id \
+         WHERE rc.votes_fetched_at IS NULL \
+           AND rc.votes_fetch_attempts < $2 \
+           AND b.deleted_at IS NULL \
+           AND ($1::TEXT IS NULL OR b.jurisdiction = $1) \
+         ORDER BY rc.id \
+         LIMIT $3",
+    )
+    .bind(jurisdiction)
+    .bind(max_attempts)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Replaces the individual votes of a roll call and marks them fetched.
+///
+/// `votes` holds `(legiscan_people_id, vote)` pairs.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if any statement fails; nothing is written in
+/// that case.
+pub async fn record_roll_call_votes(
+    pool: &PgPool,
+    roll_call_id: i64,
+    votes: &[(i64, &str)],
+) -> Result<(), DbError> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM roll_call_votes WHERE roll_call_id = $1")
+        .bind(roll_call_id)
+        .execute(&mut *tx)
+        .await?;
+
+    let (people_ids, values): (Vec<i64>, Vec<&str>) = votes.iter().copied().unzip();
+    sqlx::query(
+        "INSERT INTO roll_call_votes (roll_call_id, legiscan_people_id, vote) \
+         SELECT $1, v.people_id, v.vote \
+         FROM UNNEST($2::BIGINT[], $3::TEXT[]) AS v(people_id, vote) \
+         ON CONFLICT (roll_call_id, legiscan_people_id) DO NOTHING",
+    )
+    .bind(roll_call_id)
+    .bind(&people_ids)
+    .bind(&values)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query("UPDATE roll_calls SET votes_fetched_at = NOW() WHERE id = $1")
+        .bind(roll_call_id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Records a failed `getRollCall` for a roll call; after enough failures
+/// [`list_roll_calls_pending_votes`] stops returning it.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the update fails.
+pub async fn record_roll_call_fetch_failure(
+    pool: &PgPool,
+    roll_call_id: i64,
+    error: &str,
+) -> Result<(), DbError> {
+    sqlx::query(
+        "UPDATE roll_calls \
+         SET votes_fetch_attempts = votes_fetch_attempts + 1, \
+             votes_fetch_error = $2, \
+             votes_fetch_attempted_at = NOW() \
+         WHERE id = $1",
+    )
+    .bind(roll_call_id)
+    .bind(error)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Returns voters on stored roll calls who have no `legislators` row, with
+/// the jurisdiction of a bill they voted on. Voters whose `getPerson`
+/// already failed `max_attempts` times are left out.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_unknown_voters(
+    pool: &PgPool,
+    jurisdiction: Option<&str>,
+    max_attempts: i32,
+    limit: i64,
+) -> Result<Vec<UnknownVoterRow>, DbError> {
+    let rows = sqlx::query_as::<_, UnknownVoterRow>(
+        "SELECT v.legiscan_people_id, MIN(b.jurisdiction) AS jurisdiction \
+         FROM roll_call_votes v \
+         JOIN roll_calls rc ON rc.id = v.roll_call_id \
+         JOIN bills b ON b.id = rc.bill_id \
+         WHERE NOT EXISTS ( \
+                 SELECT 1 FROM legislators l WHERE l.legiscan_people_id = v.legiscan_people_id) \
+           AND NOT EXISTS ( \
+                 SELECT 1 FROM voter_fetch_failures f \
+                 WHERE f.legiscan_people_id = v.legiscan_people_id AND f.attempts >= $2) \
+           AND ($1::TEXT IS NULL OR b.jurisdiction = $1) \
+         GROUP BY v.legiscan_people_id \
+         ORDER BY v.legiscan_people_id \
+         LIMIT $3",
+    )
+    .bind(jurisdiction)
+    .bind(max_attempts)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Records a failed `getPerson` for a voter; after enough failures
+/// [`list_unknown_voters`] stops returning them.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the upsert fails.
+pub async fn record_voter_fetch_failure(
+    pool: &PgPool,
+    legiscan_people_id: i64,
+    error: &str,
+) -> Result<(), DbError> {
+    sqlx::query(
+        "INSERT INTO voter_fetch_failures (legiscan_people_id, last_error) \
+         VALUES ($1, $2) \
+         ON CONFLICT (legiscan_people_id) DO UPDATE SET \
+             attempts          = voter_fetch_failures.attempts + 1, \
+             last_error        = EXCLUDED.last_error, \
+             last_attempted_at = NOW()",
+    )
+    .bind(legiscan_people_id)
+    .bind(error)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Returns the roll calls of a non-deleted bill by its public UUID, newest
+/// first.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_bill_roll_calls_by_public_id(
+    pool: &PgPool,
+    bill_public_id: Uuid,
+) -> Result<Vec<RollCallRow>, DbError> {
+    let rows = sqlx::query_as::<_, RollCallRow>(
+        "SELECT rc.id, rc.bill_id, rc.legiscan_roll_call_id, rc.vote_date, rc.chamber, \
+                rc.description, rc.yea, rc.nay, rc.nv, rc.absent, rc.total, rc.passed, \
+                rc.votes_fetched_at, rc.created_at \
+         FROM roll_calls rc \
+         JOIN bills b ON b.id = rc.bill_id \
+         WHERE b.public_id = $1 AND b.deleted_at IS NULL \
+         ORDER BY rc.vote_date DESC NULLS LAST, rc.legiscan_roll_call_id DESC",
+    )
+    .bind(bill_public_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Returns the individual votes of each roll call in `roll_call_ids`,
+/// ordered by voter name.
+///
+/// Roll calls without fetched votes are omitted from the map.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_roll_call_votes_batch(
+    pool: &PgPool,
+    roll_call_ids: &[i64],
+) -> Result<HashMap<i64, Vec<RollCallVoteRow>>, DbError> {
+    let rows = sqlx::query_as::<_, RollCallVoteRow>(
+        "SELECT v.roll_call_id, v.legiscan_people_id, l.public_id AS legislator_public_id, \
+                l.name, l.party, v.vote \
+         FROM roll_call_votes v \
+         LEFT JOIN legislators l ON l.legiscan_people_id = v.legiscan_people_id \
+         WHERE v.roll_call_id = ANY($1::bigint[]) \
+         ORDER BY v.roll_call_id, l.name NULLS LAST, v.legiscan_people_id",
+    )
+    .bind(roll_call_ids)
+    .fetch_all(pool)
+    .await?;
+
+    let mut map: HashMap<i64, Vec<RollCallVoteRow>> = HashMap::new();
+    for row in rows {
+        map.entry(row.roll_call_id).or_default().push(row);
+    }
+    Ok(map)
+}
@@ -0,0 +1,224 @@
+//! Database operations for the `state_rules` table.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::DbError;
+
+/// A rule version with its enacting bill, when linked.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct StateRuleRow {
+    pub id: i64,
+    pub public_id: Uuid,
+    pub jurisdiction: String,
+    pub rule_kind: String,
+    pub value: Option<f64>,
+    pub channels: Vec<String>,
+    pub unit: Option<String>,
+    pub effective_from: NaiveDate,
+    /// First day the rule no longer applies; `None` while open ended.
+    pub effective_to: Option<NaiveDate>,
+    pub bill_public_id: Option<Uuid>,
+    pub bill_number: Option<String>,
+    pub source_text: Option<String>,
+    pub source_url: Option<String>,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A rule version to add to the registry.
+#[derive(Debug, Clone, Copy)]
+pub struct NewStateRule<'a> {
+    pub jurisdiction: &'a str,
+    pub rule_kind: &'a str,
+    pub value: Option<f64>,
+    pub channels: &'a [String],
+    pub unit: Option<&'a str>,
+    pub effective_from: NaiveDate,
+    /// `None` runs until the next version, if any, or open ended.
+    pub effective_to: Option<NaiveDate>,
+    pub bill_id: Option<i64>,
+    pub source_text: Option<&'a str>,
+    pub source_url: Option<&'a str>,
+    pub notes: Option<&'a str>,
+}
+
+/// Filters for [`list_state_rules`]; `None` fields are ignored.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StateRuleFilters<'a> {
+    pub jurisdiction: Option<&'a str>,
+    pub rule_kind: Option<&'a str>,
+    /// Only versions in effect on this date.
+    pub as_of: Option<NaiveDate>,
+}
+
+const STATE_RULE_COLUMNS: &str = "r.id, r.public_id, r.jurisdiction, r.rule_kind, r.value, \
+     r.channels, r.unit, r.effective_from, r.effective_to, b.public_id AS bill_public_id, \
+     b.bill_number, r.source_text, r.source_url, r.notes, r.created_at, r.updated_at";
+
+/// Adds a rule version.
+///
+/// The version in effect on `effective_from` of the same jurisdiction and
+/// kind is closed on that date. Without an explicit `effective_to`, the new
+/// version runs until the next later version, if one exists.
+///
+/// # Errors
+///
+/// Returns [`DbError::StateRuleOverlap`] when an explicit `effective_to` is
+/// after the start of the next later version, or [`DbError::Sqlx`] if a
+/// statement fails, including a unique violation when a version of the same
+/// jurisdiction and kind already starts on `effective_from`. Nothing is
+/// written in either case.
+pub async fn insert_state_rule(
+    pool: &PgPool,
+    rule: &NewStateRule<'_>,
+) -> Result<StateRuleRow, DbError> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        "SELECT 1 FROM state_rules \
+         WHERE jurisdiction = $1 AND rule_kind = $2 \
+         FOR UPDATE",
+    )
+    .bind(rule.jurisdiction)
+    .bind(rule.rule_kind)
+    .execute(&mut *tx)
+    .await?;
+
+    if let Some(effective_to) = rule.effective_to {
+        let next_effective_from = sqlx::query_scalar::<_, Option<NaiveDate>>(
+            "SELECT MIN(effective_from) FROM state_rules \
+             WHERE jurisdiction = $1 AND rule_kind = $2 AND effective_from > $3",
+        )
+        .bind(rule.jurisdiction)
+        .bind(rule.rule_kind)
+        .bind(rule.effective_from)
+        .fetch_one(&mut *tx)
+        .await?;
+        if let Some(next_effective_from) = next_effective_from {
+            if effective_to > next_effective_from {
+                return Err(DbError::StateRuleOverlap {
+                    effective_to,
+                    next_effective_from,
+                });
+            }
+        }
+    }
+
+    sqlx::query(
+        "UPDATE state_rules SET effective_to = $3, updated_at = NOW() \
+         WHERE jurisdiction = $1 AND rule_kind = $2 \
+           AND effective_from < $3 \
+           AND (effective_to IS NULL OR effective_to > $3)",
+    )
+    .bind(rule.jurisdiction)
+    .bind(rule.rule_kind)
+    .bind(rule.effective_from)
+    .execute(&mut *tx)
+    .await?;
+
+    let id = sqlx::query_scalar::<_, i64>(
+        "INSERT INTO state_rules \
+             (jurisdiction, rule_kind, value, channels, unit, effective_from, effective_to, \
+              bill_id, source_text, source_url, notes) \
+         VALUES ($1, $2, $3, $4, $5, $6, \
+                 COALESCE($7, (SELECT MIN(effective_from) FROM state_rules \
+                               WHERE jurisdiction = $1 AND rule_kind = $2 \
+                                 AND effective_from > $6)), \
+                 $8, $9, $10, $11) \
+         RETURNING id",
+    )
+    .bind(rule.jurisdiction)
+    .bind(rule.rule_kind)
+    .bind(rule.value)
+    .bind(rule.channels)
+    .bind(rule.unit)
+    .bind(rule.effective_from)
+    .bind(rule.effective_to)
+    .bind(rule.bill_id)
+    .bind(rule.source_text)
+    .bind(rule.source_url)
+    .bind(rule.notes)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let row = sqlx::query_as::<_, StateRuleRow>(&format!(
+        "SELECT {STATE_RULE_COLUMNS} \
+         FROM state_rules r \
+         LEFT JOIN bills b ON b.id = r.bill_id \
+         WHERE r.id = $1"
+    ))
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(row)
+}
+
+/// Returns rule versions matching `filters`, by jurisdiction and kind, newest
+/// version first.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_state_rules(
+    pool: &PgPool,
+    filters: &StateRuleFilters<'_>,
+) -> Result<Vec<StateRuleRow>, DbError> {
+    let rows = sqlx::query_as::<_, StateRuleRow>(&format!(
+        "SELECT {STATE_RULE_COLUMNS} \
+         FROM state_rules r \
+         LEFT JOIN bills b ON b.id = r.bill_id \
+         WHERE ($1::TEXT IS NULL OR r.jurisdiction = $1) \
+           AND ($2::TEXT IS NULL OR r.rule_kind = $2) \
+           AND ($3::DATE IS NULL OR (r.effective_from <= $3 \
+                AND (r.effective_to IS NULL OR r.effective_to > $3))) \
+         ORDER BY r.jurisdiction, r.rule_kind, r.effective_from DESC"
+    ))
+    .bind(filters.jurisdiction)
+    .bind(filters.rule_kind)
+    .bind(filters.as_of)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Deletes a rule version, e.g. one entered in error. The version it closed,
+/// if any, is extended over its range again.
+///
+/// # Errors
+///
+/// Returns [`DbError::NotFound`] for an unknown id, or [`DbError::Sqlx`] if
+/// a statement fails; nothing is written in that case.
+pub async fn delete_state_rule(pool: &PgPool, public_id: Uuid) -> Result<(), DbError> {
+    let mut tx = pool.begin().await?;
+
+    let deleted = sqlx::query_as::<_, (String, String, NaiveDate, Option<NaiveDate>)>(
+        "DELETE FROM state_rules WHERE public_id = $1 \
+         RETURNING jurisdiction, rule_kind, effective_from, effective_to",
+    )
+    .bind(public_id)
+    .fetch_optional(&mut *tx)
+    .await?;
+    let Some((jurisdiction, rule_kind, effective_from, effective_to)) = deleted else {
+        return Err(DbError::NotFound);
+    };
+
+    sqlx::query(
+        "UPDATE state_rules SET effective_to = $4, updated_at = NOW() \
+         WHERE jurisdiction = $1 AND rule_kind = $2 AND effective_to = $3",
+    )
+    .bind(&jurisdiction)
+    .bind(&rule_kind)
+    .bind(effective_from)
+    .bind(effective_to)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
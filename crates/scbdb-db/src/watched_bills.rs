@@ -0,0 +1,114 @@
+//! Database operations for the `watched_bills` watchlist.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::DbError;
+
+/// A watched bill with the bill's current state.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct WatchedBillRow {
+    pub bill_id: i64,
+    pub public_id: Uuid,
+    pub jurisdiction: String,
+    pub bill_number: String,
+    pub title: String,
+    pub status: String,
+    pub status_date: Option<NaiveDate>,
+    pub last_action_date: Option<NaiveDate>,
+    pub relevance_score: Option<f64>,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+const WATCHED_BILL_SELECT: &str =
+    "SELECT w.bill_id, b.public_id, b.jurisdiction, b.bill_number, b.title, b.status, \
+            b.status_date, b.last_action_date, b.relevance_score, w.note, w.created_at \
+     FROM watched_bills w \
+     JOIN bills b ON b.id = w.bill_id";
+
+/// Adds a bill to the watchlist, or replaces the note of a watched bill when
+/// `note` is given.
+///
+/// A newly watched bill is reported by the next change notification run as a
+/// new bill with its events, unless it was already tracked for its relevance.
+///
+/// # Errors
+///
+/// Returns [`DbError::NotFound`] if the bill does not exist, or
+/// [`DbError::Sqlx`] if a statement fails.
+pub async fn watch_bill(
+    pool: &PgPool,
+    bill_id: i64,
+    note: Option<&str>,
+) -> Result<WatchedBillRow, DbError> {
+    let mut tx = pool.begin().await?;
+
+    let exists: Option<i64> =
+        sqlx::query_scalar("SELECT id FROM bills WHERE id = $1 AND deleted_at IS NULL")
+            .bind(bill_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+    if exists.is_none() {
+        return Err(DbError::NotFound);
+    }
+
+    sqlx::query(
+        "INSERT INTO watched_bills (bill_id, note) VALUES ($1, $2) \
+         ON CONFLICT (bill_id) DO UPDATE \
+             SET note = COALESCE(EXCLUDED.note, watched_bills.note)",
+    )
+    .bind(bill_id)
+    .bind(note)
+    .execute(&mut *tx)
+    .await?;
+
+    let row =
+        sqlx::query_as::<_, WatchedBillRow>(&format!("{WATCHED_BILL_SELECT} WHERE w.bill_id = $1"))
+            .bind(bill_id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+    tx.commit().await?;
+    Ok(row)
+}
+
+/// Removes a bill from the watchlist.
+///
+/// # Errors
+///
+/// Returns [`DbError::NotFound`] if the bill is not watched, or
+/// [`DbError::Sqlx`] if the statement fails.
+pub async fn unwatch_bill(pool: &PgPool, bill_id: i64) -> Result<(), DbError> {
+    let deleted = sqlx::query("DELETE FROM watched_bills WHERE bill_id = $1")
+        .bind(bill_id)
+        .execute(pool)
+        .await?
+        .rows_affected();
+    if deleted == 0 {
+        return Err(DbError::NotFound);
+    }
+    Ok(())
+}
+
+/// Watched bills, optionally in one jurisdiction, by jurisdiction and number.
+///
+/// # Errors
+///
+/// Returns [`DbError::Sqlx`] if the query fails.
+pub async fn list_watched_bills(
+    pool: &PgPool,
+    jurisdiction: Option<&str>,
+) -> Result<Vec<WatchedBillRow>, DbError> {
+    let rows = sqlx::query_as::<_, WatchedBillRow>(&format!(
+        "{WATCHED_BILL_SELECT} \
+         WHERE b.deleted_at IS NULL AND ($1::TEXT IS NULL OR b.jurisdiction = $1) \
+         ORDER BY b.jurisdiction, b.bill_number"
+    ))
+    .bind(jurisdiction)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
@@ -18,17 +18,30 @@ fn pool_config_from_app_config_uses_core_values() {
         bind_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 3000),
         log_level: "info".to_string(),
         brands_path: PathBuf::from("./config/brands.yaml"),
+        bill_topics_path: PathBuf::from("./config/bill_topics.yaml"),
         legiscan_api_key: None,
         db_max_connections: 42,
         db_min_connections: 7,
         db_acquire_timeout_secs: 9,
         scraper_request_timeout_secs: 30,
         legiscan_request_timeout_secs: 30,
+        legiscan_monthly_budget: 30_000,
         scraper_user_agent: "ua".to_string(),
         scraper_max_concurrent_brands: 1,
         scraper_inter_request_delay_ms: 250,
         scraper_max_retries: 3,
         scraper_retry_backoff_base_secs: 5,
+        location_max_drop_pct: 50,
+        location_drop_min_baseline: 20,
+        location_miss_threshold: 2,
+        location_miss_thresholds: std::collections::BTreeMap::new(),
+        location_miss_max_days: 14,
+        notify_webhook_url: None,
+        notify_slack_webhook_url: None,
+        notify_smtp_url: None,
+        notify_email_from: None,
+        notify_email_to: Vec::new(),
+        notify_min_relevance: scbdb_core::DEFAULT_NOTIFY_MIN_RELEVANCE,
     };
 
     let pool_config = PoolConfig::from_app_config(&app_config);
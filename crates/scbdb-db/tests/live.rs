@@ -7,24 +7,55 @@
 
 use chrono::NaiveDate;
 use rust_decimal::Decimal;
-use scbdb_core::{NormalizedProduct, NormalizedVariant};
+use scbdb_core::{NormalizedProduct, NormalizedVariant, Severity};
 use scbdb_db::{
-    complete_collection_run, create_collection_run, deactivate_missing_locations,
-    fail_collection_run, get_active_location_keys_for_brand, get_bill_by_jurisdiction_number,
-    get_brand_by_slug, get_brand_completeness, get_collection_run, get_last_price_snapshot,
+    advance_bill_alert_cursors, approve_location_quarantine, assign_location_geographies,
+    check_compliance, complete_collection_run, compute_regulatory_exposure, create_collection_run,
+    deactivate_missing_locations, delete_state_rule, diff_location_snapshots,
+    dismiss_brand_candidate, fail_collection_run, get_active_location_keys_for_brand,
+    get_bill_by_jurisdiction_number, get_bill_text_content, get_brand_by_slug, get_brand_candidate,
+    get_brand_completeness, get_collection_run, get_dataset_hashes, get_last_price_snapshot,
+    get_legiscan_usage, get_location_data_version, get_roll_call_ids, import_accounts,
     insert_brand_competitor_relationship, insert_brand_distributor, insert_brand_funding_event,
     insert_brand_lab_test, insert_brand_legal_proceeding, insert_brand_media_appearance,
     insert_brand_newsletter, insert_brand_sponsorship, insert_price_snapshot_if_changed,
+    insert_state_rule, insert_store_location_events, list_account_competitor_presence,
     list_active_brands, list_active_location_pins, list_active_locations_by_brand,
-    list_bill_events, list_bills, list_brand_competitor_relationships, list_brand_distributors,
-    list_brand_funding_events, list_brand_lab_tests, list_brand_legal_proceedings,
-    list_brand_media_appearances, list_brand_newsletters, list_brand_sponsorships,
-    list_brands_without_profiles, list_collection_run_brands, list_locations_by_state,
-    list_locations_dashboard_summary, start_collection_run, update_brand_logo, upsert_bill,
-    upsert_bill_event, upsert_brand_profile, upsert_collection_run_brand, upsert_product,
-    upsert_store_locations, upsert_variant, NewBrandCompetitorRelationship, NewBrandDistributor,
-    NewBrandFundingEvent, NewBrandLabTest, NewBrandLegalProceeding, NewBrandMediaAppearance,
-    NewBrandNewsletter, NewBrandSponsorship, NewStoreLocation,
+    list_bill_changes, list_bill_events, list_bill_texts_by_public_id,
+    list_bill_texts_pending_extraction, list_bill_topics_batch, list_bills, list_bills_filtered,
+    list_bills_for_scoring, list_brand_candidates, list_brand_competitor_relationships,
+    list_brand_distributors, list_brand_funding_events, list_brand_lab_tests,
+    list_brand_legal_proceedings, list_brand_media_appearances, list_brand_newsletters,
+    list_brand_sponsorships, list_brands_without_profiles, list_collection_run_brands,
+    list_competitor_stores_without_account, list_latest_regulatory_exposure, list_legiscan_usage,
+    list_legislator_votes, list_location_density, list_location_geo_rollup,
+    list_location_net_change_weekly, list_location_quarantines, list_location_snapshots_for_brand,
+    list_location_tile_features, list_location_weekly_counts, list_location_whitespace,
+    list_locations_by_state, list_locations_dashboard_summary, list_locations_for_classification,
+    list_locations_for_details, list_locations_for_rekey, list_locations_within_radius,
+    list_nearest_competitor_stores, list_product_match_candidates, list_regulatory_exposure_series,
+    list_roll_calls_pending_votes, list_sku_distribution, list_state_rules,
+    list_store_location_events, list_unknown_voters, list_upcoming_hearings, list_watched_bills,
+    load_geo_reference, promote_brand_candidate, quarantine_location_scrape_on_drop,
+    record_bill_text_extraction, record_brand_candidate_evidence, record_dataset_import,
+    record_location_misses, record_product_sweep, record_regulatory_exposure,
+    record_roll_call_fetch_failure, record_roll_call_votes, record_voter_fetch_failure,
+    reject_location_quarantine, rekey_store_locations, replace_bill_hearings, replace_bill_topics,
+    reserve_legiscan_request, resolve_zip_centroid, start_bill_alert_channel, start_collection_run,
+    tally_legislator_votes, unwatch_bill, update_brand_logo, update_location_classifications,
+    update_location_details, upsert_bill, upsert_bill_event, upsert_bill_text,
+    upsert_brand_profile, upsert_collection_run_brand, upsert_legislator, upsert_product,
+    upsert_roll_call, upsert_store_locations, upsert_variant, watch_bill, AccountPresenceFilters,
+    BillFilters, ComplianceFilters, DbError, DensityGrouping, GeoReferenceLoadSummary,
+    GeoRollupLevel, HearingFilters, LocationClassificationUpdate, LocationDensityFilters,
+    LocationDetailsUpdate, LocationDropGuard, LocationEventFilters, LocationEventType,
+    LocationMissPolicy, LocationRekey, LocationTileFilters, NearestCompetitorSearch, NewAccount,
+    NewBillHearing, NewBrand, NewBrandCandidateEvidence, NewBrandCompetitorRelationship,
+    NewBrandDistributor, NewBrandFundingEvent, NewBrandLabTest, NewBrandLegalProceeding,
+    NewBrandMediaAppearance, NewBrandNewsletter, NewBrandSponsorship, NewCarriedProduct,
+    NewDatasetImport, NewGeoCounty, NewLegislator, NewLocationEvent, NewRollCall, NewStateRule,
+    NewStoreLocation, NewZipCounty, ProductSweepInput, ProspectFilters, RadiusSearch,
+    SkuDistributionFilters, StateRuleFilters, TileCoord, WhitespaceFilters, WhitespaceGrouping,
 };
 
 // ---------------------------------------------------------------------------
@@ -1275,1424 +1306,4016 @@ async fn get_bill_by_jurisdiction_number_not_found(pool: sqlx::PgPool) {
     assert!(result.is_none(), "expected None for nonexistent bill");
 }
 
-// ---------------------------------------------------------------------------
-// Section 7: Collection Run Brands
-// ---------------------------------------------------------------------------
-
 #[sqlx::test(migrations = "../../migrations")]
-async fn upsert_collection_run_brand_overwrites_on_conflict(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "crb-upsert", true).await;
-    let run = create_collection_run(&pool, "products", "cli")
-        .await
-        .expect("create_collection_run failed");
-
-    // First call: simulate a failure recording
-    upsert_collection_run_brand(&pool, run.id, brand_id, "failed", None, Some("first error"))
-        .await
-        .expect("first upsert_collection_run_brand failed");
-
-    // Second call: simulate a re-run that succeeded
-    upsert_collection_run_brand(&pool, run.id, brand_id, "succeeded", Some(5), None)
-        .await
-        .expect("second upsert_collection_run_brand failed");
-
-    // Verify exactly one row exists for this (run_id, brand_id) pair
-    let count: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM collection_run_brands \
-         WHERE collection_run_id = $1 AND brand_id = $2",
+async fn bill_text_extraction_is_recorded_and_not_retried(pool: sqlx::PgPool) {
+    let bill_id = upsert_bill(
+        &pool,
+        "SC",
+        "H-7100",
+        "Hemp Beverage Limits",
+        None,
+        "introduced",
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     )
-    .bind(run.id)
-    .bind(brand_id)
-    .fetch_one(&pool)
     .await
     .unwrap();
-
-    assert_eq!(
-        count, 1,
-        "upsert should produce exactly one row, not a duplicate"
-    );
-
-    // Verify the row reflects the second (overwriting) call
-    let (status, records_processed, error_message): (String, i32, Option<String>) = sqlx::query_as(
-        "SELECT status, records_processed, error_message \
-             FROM collection_run_brands \
-             WHERE collection_run_id = $1 AND brand_id = $2",
+    upsert_bill_text(
+        &pool,
+        bill_id,
+        501,
+        NaiveDate::from_ymd_opt(2025, 1, 10),
+        "Introduced",
+        "text/html",
+        None,
     )
-    .bind(run.id)
-    .bind(brand_id)
-    .fetch_one(&pool)
     .await
-    .expect("fetch upserted row failed");
+    .unwrap();
+    upsert_bill_text(
+        &pool,
+        bill_id,
+        502,
+        NaiveDate::from_ymd_opt(2025, 2, 10),
+        "Amended",
+        "application/msword",
+        None,
+    )
+    .await
+    .unwrap();
 
-    assert_eq!(
-        status, "succeeded",
-        "status should be overwritten to 'succeeded'"
-    );
-    assert_eq!(
-        records_processed, 5,
-        "records_processed should be overwritten to 5"
-    );
+    let pending = list_bill_texts_pending_extraction(&pool, Some("SC"), false, 10)
+        .await
+        .unwrap();
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending[0].legiscan_text_id, 501);
+    assert_eq!(pending[0].bill_number, "H-7100");
     assert!(
-        error_message.is_none(),
-        "error_message should be overwritten to NULL"
+        list_bill_texts_pending_extraction(&pool, Some("GA"), false, 10)
+            .await
+            .unwrap()
+            .is_empty()
     );
-}
 
-#[sqlx::test(migrations = "../../migrations")]
-async fn list_collection_run_brands_returns_inserted_entries(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "crb-list", true).await;
-    let run = create_collection_run(&pool, "products", "cli")
+    record_bill_text_extraction(&pool, pending[0].id, Some("SECTION 1. Text."), None)
         .await
-        .expect("create_collection_run failed");
-
-    // Insert a brand-level result via the upsert helper
-    upsert_collection_run_brand(&pool, run.id, brand_id, "succeeded", Some(3), None)
+        .unwrap();
+    record_bill_text_extraction(&pool, pending[1].id, None, Some("unsupported"))
         .await
-        .expect("upsert_collection_run_brand failed");
+        .unwrap();
 
-    let entries = list_collection_run_brands(&pool, run.id)
+    assert!(list_bill_texts_pending_extraction(&pool, None, false, 10)
         .await
-        .expect("list_collection_run_brands failed");
-
-    assert_eq!(entries.len(), 1, "should return exactly one entry");
-    assert_eq!(entries[0].collection_run_id, run.id);
-    assert_eq!(entries[0].brand_id, brand_id);
-    assert_eq!(entries[0].status, "succeeded");
-    assert_eq!(entries[0].records_processed, 3);
-    assert!(entries[0].error_message.is_none());
-}
+        .unwrap()
+        .is_empty());
+    let retry = list_bill_texts_pending_extraction(&pool, None, true, 10)
+        .await
+        .unwrap();
+    assert_eq!(retry.len(), 1);
+    assert_eq!(retry[0].legiscan_text_id, 502);
 
-// ---------------------------------------------------------------------------
-// Section 8: Store Locations — Dashboard Aggregates
-// ---------------------------------------------------------------------------
+    let bill = get_bill_by_jurisdiction_number(&pool, "SC", "H-7100")
+        .await
+        .unwrap()
+        .unwrap();
+    let texts = list_bill_texts_by_public_id(&pool, bill.public_id)
+        .await
+        .unwrap();
+    assert_eq!(texts[0].legiscan_text_id, 502);
+    assert!(!texts[0].has_content);
+    assert_eq!(texts[0].extraction_error.as_deref(), Some("unsupported"));
+    assert!(texts[1].has_content);
 
-/// Build a minimal `NewStoreLocation` for tests.
-fn make_test_location(
-    key_suffix: &str,
-    name: &str,
-    state: Option<&str>,
-    locator_source: Option<&str>,
-) -> NewStoreLocation {
-    NewStoreLocation {
-        location_key: format!("test-loc-{key_suffix}"),
-        name: name.to_string(),
-        address_line1: None,
-        city: None,
-        state: state.map(str::to_string),
-        zip: None,
-        country: Some("US".to_string()),
-        latitude: None,
-        longitude: None,
-        phone: None,
-        external_id: None,
-        locator_source: locator_source.map(str::to_string),
-        raw_data: serde_json::json!({}),
-    }
+    let content = get_bill_text_content(&pool, bill.public_id, 501)
+        .await
+        .unwrap()
+        .expect("text version");
+    assert_eq!(content.content.as_deref(), Some("SECTION 1. Text."));
+    assert!(get_bill_text_content(&pool, bill.public_id, 999)
+        .await
+        .unwrap()
+        .is_none());
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn locations_dashboard_summary_returns_per_brand_counts(pool: sqlx::PgPool) {
-    let brand_a = insert_test_brand(&pool, "loc-brand-a", true).await;
-    let brand_b = insert_test_brand(&pool, "loc-brand-b", true).await;
-
-    // Brand-a: 3 locations across CA (×2) and TX.
-    let locs_a = vec![
-        make_test_location("a-ca-1", "Store CA 1", Some("CA"), Some("locally")),
-        make_test_location("a-ca-2", "Store CA 2", Some("CA"), Some("locally")),
-        make_test_location("a-tx-1", "Store TX 1", Some("TX"), Some("locally")),
-    ];
-    upsert_store_locations(&pool, brand_a, &locs_a)
+async fn bill_topics_replace_tags_and_filter_bills(pool: sqlx::PgPool) {
+    let mut ids = Vec::new();
+    for number in ["H-7200", "H-7201"] {
+        let id = upsert_bill(
+            &pool,
+            "SC",
+            number,
+            "Hemp Beverages",
+            Some("Serving caps"),
+            "introduced",
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
         .await
-        .expect("upsert brand-a locations failed");
+        .unwrap();
+        ids.push(id);
+    }
+    upsert_bill_text(&pool, ids[0], 601, None, "Introduced", "text/html", None)
+        .await
+        .unwrap();
+    let pending = list_bill_texts_pending_extraction(&pool, None, false, 10)
+        .await
+        .unwrap();
+    record_bill_text_extraction(&pool, pending[0].id, Some("SECTION 1. 5 mg."), None)
+        .await
+        .unwrap();
 
-    // Brand-b: 1 location in NY.
-    let locs_b = vec![make_test_location(
-        "b-ny-1",
-        "Store NY 1",
-        Some("NY"),
-        Some("storemapper"),
-    )];
-    upsert_store_locations(&pool, brand_b, &locs_b)
+    let scoring = list_bills_for_scoring(&pool, Some("SC"), None)
         .await
-        .expect("upsert brand-b locations failed");
+        .unwrap();
+    assert_eq!(scoring.len(), 2);
+    assert_eq!(scoring[0].text.as_deref(), Some("SECTION 1. 5 mg."));
+    assert!(scoring[1].text.is_none());
+    let only_second = list_bills_for_scoring(&pool, None, Some(&ids[1..]))
+        .await
+        .unwrap();
+    assert_eq!(only_second.len(), 1);
 
-    let rows = list_locations_dashboard_summary(&pool)
+    replace_bill_topics(&pool, ids[0], 20.0, &[("excise_tax", 4.0)])
         .await
-        .expect("list_locations_dashboard_summary failed");
+        .unwrap();
+    replace_bill_topics(
+        &pool,
+        ids[0],
+        81.5,
+        &[("thc_serving_caps", 9.0), ("age_limits", 3.5)],
+    )
+    .await
+    .unwrap();
+    replace_bill_topics(&pool, ids[1], 12.0, &[]).await.unwrap();
 
-    assert_eq!(rows.len(), 2, "expected 2 brand rows");
+    let topics = list_bill_topics_batch(&pool, &ids).await.unwrap();
+    let first: Vec<&str> = topics[&ids[0]].iter().map(|t| t.topic.as_str()).collect();
+    assert_eq!(first, vec!["thc_serving_caps", "age_limits"]);
+    assert!(!topics.contains_key(&ids[1]));
 
-    // Results are ordered by active_count DESC, so brand-a (3) comes first.
-    let row_a = &rows[0];
-    assert_eq!(row_a.brand_slug, "loc-brand-a");
-    assert_eq!(row_a.active_count, 3, "brand-a active_count");
-    assert_eq!(row_a.states_covered, 2, "brand-a covers CA + TX = 2 states");
-    assert_eq!(
-        row_a.locator_source.as_deref(),
-        Some("locally"),
-        "brand-a locator_source"
-    );
+    let by_topic = list_bills_filtered(
+        &pool,
+        &BillFilters {
+            topic: Some("thc_serving_caps"),
+            ..BillFilters::default()
+        },
+        100,
+    )
+    .await
+    .unwrap();
+    assert_eq!(by_topic.len(), 1);
+    assert_eq!(by_topic[0].relevance_score, Some(81.5));
 
-    let row_b = &rows[1];
-    assert_eq!(row_b.brand_slug, "loc-brand-b");
-    assert_eq!(row_b.active_count, 1, "brand-b active_count");
-    assert_eq!(row_b.states_covered, 1, "brand-b covers NY = 1 state");
-    assert_eq!(
-        row_b.locator_source.as_deref(),
-        Some("storemapper"),
-        "brand-b locator_source"
-    );
+    let relevant = list_bills_filtered(
+        &pool,
+        &BillFilters {
+            jurisdiction: Some("SC"),
+            min_relevance: Some(50.0),
+            ..BillFilters::default()
+        },
+        100,
+    )
+    .await
+    .unwrap();
+    assert_eq!(relevant.len(), 1);
+    assert_eq!(relevant[0].bill_number, "H-7200");
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn locations_dashboard_summary_excludes_brands_with_no_active_locations(pool: sqlx::PgPool) {
-    // Brand with no locations at all.
-    insert_test_brand(&pool, "loc-empty", true).await;
-
-    // Brand with one location that gets deactivated.
-    let brand_deactivated = insert_test_brand(&pool, "loc-deactivated", true).await;
-    let locs = vec![make_test_location(
-        "d-ca-1",
-        "Deactivated Store",
-        Some("CA"),
+async fn roll_call_votes_are_queued_until_fetched(pool: sqlx::PgPool) {
+    let bill_id = upsert_bill(
+        &pool,
+        "SC",
+        "H-7300",
+        "Hemp Beverage Serving Limits",
         None,
-    )];
-    upsert_store_locations(&pool, brand_deactivated, &locs)
+        "passed",
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    let summary = NewRollCall {
+        legiscan_roll_call_id: 900,
+        vote_date: NaiveDate::from_ymd_opt(2025, 3, 4),
+        chamber: Some("H"),
+        description: "Third Reading",
+        yea: 1,
+        nay: 1,
+        nv: 0,
+        absent: 0,
+        total: 2,
+        passed: false,
+    };
+    let roll_call_id = upsert_roll_call(&pool, bill_id, &summary).await.unwrap();
+
+    let pending = list_roll_calls_pending_votes(&pool, Some("SC"), 3, 10)
         .await
-        .expect("upsert failed");
+        .unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].legiscan_roll_call_id, 900);
 
-    // Deactivate all locations for the brand by passing an empty active_keys slice.
-    deactivate_missing_locations(&pool, brand_deactivated, &[])
+    record_roll_call_votes(&pool, roll_call_id, &[(10, "yea"), (11, "nay")])
         .await
-        .expect("deactivate failed");
+        .unwrap();
+    upsert_legislator(
+        &pool,
+        &NewLegislator {
+            legiscan_people_id: 10,
+            jurisdiction: "SC",
+            name: "Jane Doe",
+            first_name: None,
+            last_name: None,
+            party: Some("R"),
+            role: None,
+            district: None,
+        },
+    )
+    .await
+    .unwrap();
 
-    let rows = list_locations_dashboard_summary(&pool)
+    // Re-ingesting the bill refreshes the summary but keeps the fetched votes.
+    let again = upsert_roll_call(&pool, bill_id, &summary).await.unwrap();
+    assert_eq!(again, roll_call_id);
+    assert!(list_roll_calls_pending_votes(&pool, None, 3, 10)
         .await
-        .expect("list_locations_dashboard_summary failed");
+        .unwrap()
+        .is_empty());
+
+    let unknown = list_unknown_voters(&pool, None, 3, 10).await.unwrap();
+    assert_eq!(unknown.len(), 1);
+    assert_eq!(unknown[0].legiscan_people_id, 11);
+    assert_eq!(unknown[0].jurisdiction, "SC");
 
+    // A voter whose lookup keeps failing is retried up to the attempt limit.
+    record_voter_fetch_failure(&pool, 11, "person not found")
+        .await
+        .unwrap();
     assert_eq!(
-        rows.len(),
-        0,
-        "HAVING clause should exclude brands with 0 active locations"
+        list_unknown_voters(&pool, None, 3, 10).await.unwrap().len(),
+        1
     );
-}
+    record_voter_fetch_failure(&pool, 11, "person not found")
+        .await
+        .unwrap();
+    assert!(list_unknown_voters(&pool, None, 2, 10)
+        .await
+        .unwrap()
+        .is_empty());
 
-#[sqlx::test(migrations = "../../migrations")]
-async fn locations_by_state_groups_correctly(pool: sqlx::PgPool) {
-    let brand_a = insert_test_brand(&pool, "state-brand-a", true).await;
-    let brand_b = insert_test_brand(&pool, "state-brand-b", true).await;
-
-    // brand-a: CA, CA, TX
-    let locs_a = vec![
-        make_test_location("s-a-ca-1", "Store A-CA-1", Some("CA"), None),
-        make_test_location("s-a-ca-2", "Store A-CA-2", Some("CA"), None),
-        make_test_location("s-a-tx-1", "Store A-TX-1", Some("TX"), None),
-    ];
-    upsert_store_locations(&pool, brand_a, &locs_a)
-        .await
-        .expect("upsert brand-a failed");
-
-    // brand-b: CA, NY
-    let locs_b = vec![
-        make_test_location("s-b-ca-1", "Store B-CA-1", Some("CA"), None),
-        make_test_location("s-b-ny-1", "Store B-NY-1", Some("NY"), None),
-    ];
-    upsert_store_locations(&pool, brand_b, &locs_b)
-        .await
-        .expect("upsert brand-b failed");
-
-    let rows = list_locations_by_state(&pool)
-        .await
-        .expect("list_locations_by_state failed");
-
-    // Ordered by location_count DESC: CA(3), TX(1), NY(1) — TX/NY order may vary.
-    let ca_row = rows
-        .iter()
-        .find(|r| r.state == "CA")
-        .expect("CA row missing");
-    assert_eq!(ca_row.location_count, 3, "CA location_count");
-    assert_eq!(ca_row.brand_count, 2, "CA brand_count");
-
-    let tx_row = rows
-        .iter()
-        .find(|r| r.state == "TX")
-        .expect("TX row missing");
-    assert_eq!(tx_row.location_count, 1, "TX location_count");
-    assert_eq!(tx_row.brand_count, 1, "TX brand_count");
-
-    let ny_row = rows
-        .iter()
-        .find(|r| r.state == "NY")
-        .expect("NY row missing");
-    assert_eq!(ny_row.location_count, 1, "NY location_count");
-    assert_eq!(ny_row.brand_count, 1, "NY brand_count");
-
-    // CA must come first (highest count).
+    let tally = tally_legislator_votes(&pool, 10, None, None).await.unwrap();
+    assert_eq!(tally.yea, 1);
+    assert_eq!(tally.nay, 0);
     assert_eq!(
-        rows[0].state, "CA",
-        "CA should be first (location_count DESC)"
+        list_legislator_votes(&pool, 10, None, None, 10)
+            .await
+            .unwrap()
+            .len(),
+        1
     );
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn locations_by_state_excludes_null_and_empty_states(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "null-state-brand", true).await;
-
-    // One location with state=None, one with state=Some("").
-    let locs = vec![
-        make_test_location("ns-null", "Null State Store", None, None),
-        make_test_location("ns-empty", "Empty State Store", Some(""), None),
-    ];
-    upsert_store_locations(&pool, brand_id, &locs)
-        .await
-        .expect("upsert failed");
-
-    let rows = list_locations_by_state(&pool)
-        .await
-        .expect("list_locations_by_state failed");
+async fn failing_roll_call_fetches_stop_after_max_attempts(pool: sqlx::PgPool) {
+    let bill_id = upsert_bill(
+        &pool,
+        "SC",
+        "H-7310",
+        "Hemp Beverage Labeling",
+        None,
+        "passed",
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    let roll_call_id = upsert_roll_call(
+        &pool,
+        bill_id,
+        &NewRollCall {
+            legiscan_roll_call_id: 901,
+            vote_date: None,
+            chamber: Some("S"),
+            description: "Second Reading",
+            yea: 0,
+            nay: 0,
+            nv: 0,
+            absent: 0,
+            total: 0,
+            passed: true,
+        },
+    )
+    .await
+    .unwrap();
 
+    for _ in 0..2 {
+        record_roll_call_fetch_failure(&pool, roll_call_id, "roll call not found")
+            .await
+            .unwrap();
+    }
     assert_eq!(
-        rows.len(),
-        0,
-        "rows with NULL or empty state should be excluded by WHERE clause"
+        list_roll_calls_pending_votes(&pool, None, 3, 10)
+            .await
+            .unwrap()
+            .len(),
+        1
     );
+    record_roll_call_fetch_failure(&pool, roll_call_id, "roll call not found")
+        .await
+        .unwrap();
+    assert!(list_roll_calls_pending_votes(&pool, None, 3, 10)
+        .await
+        .unwrap()
+        .is_empty());
 }
 
-// ---------------------------------------------------------------------------
-// Section 9: Brand Profiles
-// ---------------------------------------------------------------------------
-
 #[sqlx::test(migrations = "../../migrations")]
-async fn brand_profile_round_trip(pool: sqlx::PgPool) {
-    let brand_id: i64 = sqlx::query_scalar(
-        "INSERT INTO brands (name, slug, relationship, tier, is_active) \
-         VALUES ('TestCo', 'testco', 'competitor', 1, true) RETURNING id",
+async fn bill_hearings_are_replaced_on_refetch(pool: sqlx::PgPool) {
+    let bill_id = upsert_bill(
+        &pool,
+        "SC",
+        "H-7400",
+        "Hemp Beverage Licensing",
+        None,
+        "introduced",
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    let hearing = |day: u32, event_type: &'static str| NewBillHearing {
+        hearing_date: NaiveDate::from_ymd_opt(2025, 3, day).unwrap(),
+        hearing_time: None,
+        event_type,
+        committee: Some("Judiciary"),
+        chamber: Some("H"),
+        location: None,
+        description: None,
+    };
+    replace_bill_hearings(&pool, bill_id, &[hearing(10, "Hearing")])
+        .await
+        .unwrap();
+    // The hearing was rescheduled and an executive session added.
+    replace_bill_hearings(
+        &pool,
+        bill_id,
+        &[hearing(12, "Hearing"), hearing(14, "Executive Session")],
     )
-    .fetch_one(&pool)
     .await
     .unwrap();
 
-    upsert_brand_profile(
+    let window = list_upcoming_hearings(
         &pool,
-        brand_id,
-        Some("Test tagline"),
-        Some("Test desc"),
-        Some(2021_i16),
-        Some("Portland"),
-        Some("OR"),
-        Some("BigCorp"),
+        NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+        NaiveDate::from_ymd_opt(2025, 3, 13).unwrap(),
+        &HearingFilters {
+            jurisdiction: Some("SC"),
+            ..HearingFilters::default()
+        },
+        50,
     )
     .await
     .unwrap();
+    assert_eq!(window.len(), 1);
+    assert_eq!(
+        window[0].hearing_date,
+        NaiveDate::from_ymd_opt(2025, 3, 12).unwrap()
+    );
+    assert_eq!(window[0].bill_number, "H-7400");
+}
 
-    let profile = scbdb_db::get_brand_profile(&pool, brand_id)
+#[sqlx::test(migrations = "../../migrations")]
+async fn dataset_imports_are_keyed_by_session_hash(pool: sqlx::PgPool) {
+    assert!(get_dataset_hashes(&pool, &[2100, 2101])
         .await
         .unwrap()
-        .expect("profile exists");
+        .is_empty());
+
+    let mut import = NewDatasetImport {
+        session_id: 2100,
+        jurisdiction: "SC",
+        session_name: "2025-2026 Regular Session",
+        dataset_hash: "hash-1",
+        dataset_date: NaiveDate::from_ymd_opt(2025, 3, 1),
+        bills_imported: 3,
+    };
+    record_dataset_import(&pool, &import).await.unwrap();
+    import.dataset_hash = "hash-2";
+    record_dataset_import(&pool, &import).await.unwrap();
 
-    assert_eq!(profile.tagline.as_deref(), Some("Test tagline"));
-    assert_eq!(profile.founded_year, Some(2021_i16));
-    assert_eq!(profile.hq_state.as_deref(), Some("OR"));
+    let hashes = get_dataset_hashes(&pool, &[2100, 2101]).await.unwrap();
+    assert_eq!(hashes.len(), 1);
+    assert_eq!(hashes[&2100], "hash-2");
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn list_brands_without_profiles_returns_unprofiled(pool: sqlx::PgPool) {
-    sqlx::query(
-        "INSERT INTO brands (name, slug, relationship, tier, is_active) \
-         VALUES ('NoBrand', 'nobrand', 'competitor', 2, true)",
+async fn roll_call_ids_map_legiscan_ids_to_stored_rows(pool: sqlx::PgPool) {
+    let bill_id = upsert_bill(
+        &pool,
+        "SC",
+        "H-7500",
+        "Hemp Beverages",
+        None,
+        "introduced",
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    let roll_call_id = upsert_roll_call(
+        &pool,
+        bill_id,
+        &NewRollCall {
+            legiscan_roll_call_id: 950,
+            vote_date: None,
+            chamber: None,
+            description: "Second Reading",
+            yea: 0,
+            nay: 0,
+            nv: 0,
+            absent: 0,
+            total: 0,
+            passed: true,
+        },
     )
-    .execute(&pool)
     .await
     .unwrap();
 
-    let ids = list_brands_without_profiles(&pool).await.unwrap();
-    assert!(!ids.is_empty(), "should find brand without profile");
+    let ids = get_roll_call_ids(&pool, &[950, 951]).await.unwrap();
+    assert_eq!(ids.len(), 1);
+    assert_eq!(ids[&950], roll_call_id);
 }
 
-// ---------------------------------------------------------------------------
-// Section 10: Brand Signals
-// ---------------------------------------------------------------------------
-
 #[sqlx::test(migrations = "../../migrations")]
-async fn brand_signal_upsert_and_list(pool: sqlx::PgPool) {
-    let brand_id: i64 = sqlx::query_scalar(
-        "INSERT INTO brands (name, slug, relationship, tier, is_active) \
-         VALUES ('SigBrand', 'sigbrand', 'competitor', 1, true) RETURNING id",
-    )
-    .fetch_one(&pool)
-    .await
-    .unwrap();
+async fn legiscan_ledger_refuses_requests_past_the_monthly_budget(pool: sqlx::PgPool) {
+    let march = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+    let april = NaiveDate::from_ymd_opt(2026, 4, 1).unwrap();
 
-    let new = scbdb_db::NewBrandSignal {
-        brand_id,
-        signal_type: "article",
-        source_platform: Some("web"),
-        source_url: Some("https://example.com/article"),
-        external_id: Some("art-001"),
-        title: Some("Big News"),
-        summary: None,
-        content: None,
-        image_url: None,
-        qdrant_point_id: None,
-        published_at: None,
-    };
-    scbdb_db::upsert_brand_signal(&pool, &new).await.unwrap();
+    assert_eq!(
+        reserve_legiscan_request(&pool, "key-a", march, 2)
+            .await
+            .unwrap(),
+        Some(1)
+    );
+    assert_eq!(
+        reserve_legiscan_request(&pool, "key-a", march, 2)
+            .await
+            .unwrap(),
+        Some(2)
+    );
+    assert_eq!(
+        reserve_legiscan_request(&pool, "key-a", march, 2)
+            .await
+            .unwrap(),
+        None
+    );
+    // Other keys and months have their own count; a zero budget refuses all.
+    assert_eq!(
+        reserve_legiscan_request(&pool, "key-b", march, 2)
+            .await
+            .unwrap(),
+        Some(1)
+    );
+    assert_eq!(
+        reserve_legiscan_request(&pool, "key-a", april, 2)
+            .await
+            .unwrap(),
+        Some(1)
+    );
+    assert_eq!(
+        reserve_legiscan_request(&pool, "key-c", march, 0)
+            .await
+            .unwrap(),
+        None
+    );
 
-    let signals = scbdb_db::list_brand_signals(&pool, brand_id, None, 10, None)
+    let usage = get_legiscan_usage(&pool, "key-a", march)
         .await
-        .unwrap();
-    assert_eq!(signals.len(), 1);
-    assert_eq!(signals[0].title.as_deref(), Some("Big News"));
+        .unwrap()
+        .expect("usage row");
+    assert_eq!(usage.requests, 2);
+    assert_eq!(usage.monthly_budget, 2);
+    let listed = list_legiscan_usage(&pool, march).await.unwrap();
+    assert_eq!(listed.len(), 2);
+    assert_eq!(listed[0].key_fingerprint, "key-a");
 }
 
-// ---------------------------------------------------------------------------
-// Section 11: Brand Funding Events
-// ---------------------------------------------------------------------------
-
 #[sqlx::test(migrations = "../../migrations")]
-async fn brand_funding_event_round_trip(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "funding-brand", true).await;
-
-    let investors = vec!["Sequoia".to_string(), "a16z".to_string()];
-    let event = NewBrandFundingEvent {
-        brand_id,
-        event_type: "series_a",
-        amount_usd: Some(500_000_000), // $5,000,000 in cents
-        announced_at: NaiveDate::from_ymd_opt(2025, 6, 15),
-        investors: Some(&investors),
-        acquirer: None,
-        source_url: Some("https://example.com/funding"),
-        notes: Some("Series A round"),
+async fn state_rule_versions_close_and_reopen_around_each_other(pool: sqlx::PgPool) {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let rule = |value: f64, effective_from: NaiveDate| NewStateRule {
+        jurisdiction: "SC",
+        rule_kind: "max_thc_mg_per_serving",
+        value: Some(value),
+        channels: &[],
+        unit: Some("mg"),
+        effective_from,
+        effective_to: None,
+        bill_id: None,
+        source_text: None,
+        source_url: None,
+        notes: None,
+    };
+    let in_effect = |as_of: NaiveDate| {
+        let pool = pool.clone();
+        async move {
+            list_state_rules(
+                &pool,
+                &StateRuleFilters {
+                    jurisdiction: Some("SC"),
+                    as_of: Some(as_of),
+                    ..StateRuleFilters::default()
+                },
+            )
+            .await
+            .unwrap()
+        }
     };
 
-    let id = insert_brand_funding_event(&pool, &event)
+    insert_state_rule(&pool, &rule(10.0, date(2020, 1, 1)))
         .await
-        .expect("insert_brand_funding_event failed");
-    assert!(id > 0, "returned id should be positive");
+        .unwrap();
+    let current = insert_state_rule(&pool, &rule(5.0, date(2025, 7, 1)))
+        .await
+        .unwrap();
+    assert_eq!(current.effective_to, None);
+    assert_eq!(in_effect(date(2024, 1, 1)).await[0].value, Some(10.0));
+    assert_eq!(in_effect(date(2025, 7, 1)).await[0].value, Some(5.0));
 
-    let rows = list_brand_funding_events(&pool, brand_id)
+    // A backdated version slots in between and runs until the next one.
+    let middle = insert_state_rule(&pool, &rule(8.0, date(2022, 1, 1)))
         .await
-        .expect("list_brand_funding_events failed");
+        .unwrap();
+    assert_eq!(middle.effective_to, Some(date(2025, 7, 1)));
+    assert_eq!(in_effect(date(2021, 6, 1)).await[0].value, Some(10.0));
+    assert_eq!(in_effect(date(2023, 6, 1)).await[0].value, Some(8.0));
+    assert!(insert_state_rule(&pool, &rule(7.0, date(2022, 1, 1)))
+        .await
+        .is_err());
 
-    assert_eq!(rows.len(), 1, "should return exactly one funding event");
-    let row = &rows[0];
-    assert_eq!(row.id, id);
-    assert_eq!(row.brand_id, brand_id);
-    assert_eq!(row.event_type, "series_a");
-    assert_eq!(row.amount_usd, Some(500_000_000));
-    assert_eq!(row.announced_at, NaiveDate::from_ymd_opt(2025, 6, 15));
-    assert_eq!(
-        row.investors.as_deref(),
-        Some(&["Sequoia".to_string(), "a16z".to_string()][..])
-    );
-    assert!(row.acquirer.is_none());
-    assert_eq!(
-        row.source_url.as_deref(),
-        Some("https://example.com/funding")
-    );
-    assert_eq!(row.notes.as_deref(), Some("Series A round"));
-}
+    // An explicit end may meet the next version but not run past it.
+    let overlapping = NewStateRule {
+        effective_to: Some(date(2025, 8, 1)),
+        ..rule(7.0, date(2024, 1, 1))
+    };
+    assert!(matches!(
+        insert_state_rule(&pool, &overlapping).await,
+        Err(scbdb_db::DbError::StateRuleOverlap { next_effective_from, .. })
+            if next_effective_from == date(2025, 7, 1)
+    ));
+    assert_eq!(in_effect(date(2024, 6, 1)).await[0].value, Some(8.0));
 
-// ---------------------------------------------------------------------------
-// Section 12: Brand Lab Tests
-// ---------------------------------------------------------------------------
+    // Deleting it hands its range back to the version it closed.
+    delete_state_rule(&pool, middle.public_id).await.unwrap();
+    assert_eq!(in_effect(date(2023, 6, 1)).await[0].value, Some(10.0));
+    assert!(matches!(
+        delete_state_rule(&pool, middle.public_id).await,
+        Err(scbdb_db::DbError::NotFound)
+    ));
+
+    let history = list_state_rules(&pool, &StateRuleFilters::default())
+        .await
+        .unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].effective_from, date(2025, 7, 1));
+}
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn brand_lab_test_round_trip(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "lab-brand", true).await;
+async fn compliance_flags_caps_and_channels_by_store_presence(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "comply-brand", true).await;
+    let mut product = make_normalized_product("comply-prod-1");
+    product.name = "Lemon Seltzer".to_string();
+    let product_id = upsert_product(&pool, brand_id, &product)
+        .await
+        .expect("upsert product failed");
+    let mut single = make_normalized_variant("comply-var-1");
+    single.title = "12oz / 10mg THC".to_string();
+    single.dosage_mg = Some(10.0);
+    let mut pack = make_normalized_variant("comply-var-2");
+    pack.title = "12oz / 5mg THC / 4-Pack".to_string();
+    let mut unknown = make_normalized_variant("comply-var-3");
+    unknown.title = "Variety".to_string();
+    unknown.dosage_mg = None;
+    for variant in [&single, &pack, &unknown] {
+        upsert_variant(&pool, product_id, variant)
+            .await
+            .expect("upsert variant failed");
+    }
 
-    let raw = serde_json::json!({"source": "third_party_lab"});
-    let test_input = NewBrandLabTest {
+    let mut liquor_1 = make_test_location("comply-1", "Liquor One", Some("SC"), None);
+    liquor_1.retail_channel = Some("liquor".to_string());
+    let mut liquor_2 = make_test_location("comply-2", "Liquor Two", Some("SC"), None);
+    liquor_2.retail_channel = Some("liquor".to_string());
+    let mut vape = make_test_location("comply-3", "Vape Shop", Some("SC"), None);
+    vape.retail_channel = Some("smoke_vape".to_string());
+    upsert_store_locations(&pool, brand_id, &[liquor_1, liquor_2, vape])
+        .await
+        .expect("upsert locations failed");
+    let keys = vec!["test-loc-comply-1".to_string()];
+    record_product_sweep(
+        &pool,
         brand_id,
-        product_id: None,
-        variant_id: None,
-        lab_name: Some("SC Labs"),
-        test_date: NaiveDate::from_ymd_opt(2025, 4, 10),
-        report_url: Some("https://example.com/coa.pdf"),
-        thc_mg_actual: Some(Decimal::new(5_250, 3)),
-        cbd_mg_actual: Some(Decimal::new(125, 3)),
-        total_cannabinoids_mg: Some(Decimal::new(5_375, 3)),
-        passed: Some(true),
-        raw_data: Some(&raw),
-    };
-
-    let id = insert_brand_lab_test(&pool, &test_input)
+        &ProductSweepInput {
+            product_id,
+            product_ref: "comply-upc",
+            product_name: None,
+            upc: None,
+            location_keys: &keys,
+        },
+    )
+    .await
+    .expect("record sweep failed");
+
+    let from = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+    let channels = ["liquor".to_string(), "grocery".to_string()];
+    for (jurisdiction, rule_kind, value, channels) in [
+        ("SC", "max_thc_mg_per_serving", Some(5.0), &[][..]),
+        ("SC", "max_thc_mg_per_container", Some(10.0), &[][..]),
+        ("SC", "retail_channels", None, &channels[..]),
+        ("NC", "max_thc_mg_per_serving", Some(10.0), &[][..]),
+        ("GA", "max_thc_mg_per_serving", Some(2.0), &[][..]),
+    ] {
+        insert_state_rule(
+            &pool,
+            &NewStateRule {
+                jurisdiction,
+                rule_kind,
+                value,
+                channels,
+                unit: value.map(|_| "mg"),
+                effective_from: from,
+                effective_to: None,
+                bill_id: None,
+                source_text: None,
+                source_url: None,
+                notes: None,
+            },
+        )
         .await
-        .expect("insert_brand_lab_test failed");
-    assert!(id > 0);
+        .expect("insert rule failed");
+    }
 
-    let rows = list_brand_lab_tests(&pool, brand_id)
+    let as_of = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+    let report = check_compliance(&pool, &ComplianceFilters::default(), as_of)
         .await
-        .expect("list_brand_lab_tests failed");
+        .expect("compliance check failed");
+    assert_eq!(report.variants_checked, 2);
+    assert_eq!(report.variants_without_dosage, 1);
+    assert_eq!(report.states_with_rules, 3);
 
-    assert_eq!(rows.len(), 1, "should return exactly one lab test");
-    let row = &rows[0];
-    assert_eq!(row.id, id);
-    assert_eq!(row.brand_id, brand_id);
-    assert_eq!(row.lab_name.as_deref(), Some("SC Labs"));
-    assert_eq!(row.test_date, NaiveDate::from_ymd_opt(2025, 4, 10));
+    let summary: Vec<(Severity, &str, &str, Option<f64>)> = report
+        .violations
+        .iter()
+        .map(|v| {
+            (
+                v.severity,
+                v.jurisdiction.as_str(),
+                v.rule_kind.as_str(),
+                v.actual_mg,
+            )
+        })
+        .collect();
     assert_eq!(
-        row.report_url.as_deref(),
-        Some("https://example.com/coa.pdf")
+        summary,
+        [
+            (Severity::Critical, "SC", "retail_channels", None),
+            (
+                Severity::Critical,
+                "SC",
+                "max_thc_mg_per_container",
+                Some(20.0)
+            ),
+            (
+                Severity::Critical,
+                "SC",
+                "max_thc_mg_per_serving",
+                Some(10.0)
+            ),
+            (Severity::Info, "GA", "max_thc_mg_per_serving", Some(10.0)),
+            (Severity::Info, "GA", "max_thc_mg_per_serving", Some(5.0)),
+        ]
     );
-    assert_eq!(row.thc_mg_actual, Some(Decimal::new(5_250, 3)));
-    assert_eq!(row.cbd_mg_actual, Some(Decimal::new(125, 3)));
-    assert_eq!(row.total_cannabinoids_mg, Some(Decimal::new(5_375, 3)));
-    assert_eq!(row.passed, Some(true));
-    assert!(row.raw_data.is_some());
-}
+    let serving = &report.violations[2];
+    assert_eq!(serving.product_store_count, 1);
+    assert_eq!(serving.brand_store_count, 3);
+    assert!(
+        serving
+            .message
+            .contains("the locator shows it in 1 SC stores"),
+        "{}",
+        serving.message
+    );
+    let channel = &report.violations[0];
+    assert_eq!(channel.retail_channel.as_deref(), Some("smoke_vape"));
+    assert_eq!(channel.brand_store_count, 1);
 
-// ---------------------------------------------------------------------------
-// Section 13: Brand Legal Proceedings
-// ---------------------------------------------------------------------------
+    let ga_only = check_compliance(
+        &pool,
+        &ComplianceFilters {
+            brand_slug: Some("comply-brand"),
+            jurisdiction: Some("GA"),
+        },
+        as_of,
+    )
+    .await
+    .expect("filtered compliance check failed");
+    assert_eq!(ga_only.violations.len(), 2);
+    assert_eq!(ga_only.states_with_rules, 1);
+}
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn brand_legal_proceeding_round_trip(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "legal-brand", true).await;
-
-    let proceeding = NewBrandLegalProceeding {
-        brand_id,
-        proceeding_type: "lawsuit",
-        jurisdiction: Some("SC"),
-        case_number: Some("2025-CV-001"),
-        title: "State v. HempCo",
-        summary: Some("Challenge to hemp beverage ban"),
-        status: "active",
-        filed_at: NaiveDate::from_ymd_opt(2025, 3, 1),
-        resolved_at: None,
-        source_url: Some("https://example.com/case"),
+async fn regulatory_exposure_weights_pending_bills_by_footprint(pool: sqlx::PgPool) {
+    let taxonomy_path =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../config/bill_topics.yaml");
+    let taxonomy = scbdb_core::load_bill_topics(&taxonomy_path).expect("taxonomy");
+    let brand_id = insert_test_brand(&pool, "exposed-brand", true).await;
+    let locations = vec![
+        make_test_location("exp-1", "SC One", Some("SC"), None),
+        make_test_location("exp-2", "SC Two", Some("SC"), None),
+        make_test_location("exp-3", "SC Three", Some("SC"), None),
+        make_test_location("exp-4", "NC One", Some("NC"), None),
+    ];
+    upsert_store_locations(&pool, brand_id, &locations)
+        .await
+        .expect("upsert locations failed");
+
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let bill = |jurisdiction: &'static str, number: &'static str, status: &'static str, last| {
+        let pool = pool.clone();
+        async move {
+            upsert_bill(
+                &pool,
+                jurisdiction,
+                number,
+                "Hemp beverage act",
+                None,
+                status,
+                None,
+                None,
+                Some(last),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .expect("upsert bill failed")
+        }
     };
+    let caps = bill("SC", "H1", "enrolled", date(2026, 2, 1)).await;
+    replace_bill_topics(&pool, caps, 100.0, &[("thc_serving_caps", 9.0)])
+        .await
+        .expect("topics");
+    let federal = bill("US", "HR1", "introduced", date(2026, 1, 15)).await;
+    replace_bill_topics(&pool, federal, 100.0, &[])
+        .await
+        .expect("topics");
+    bill("SC", "H2", "passed", date(2026, 2, 1)).await;
+    bill("SC", "H3", "introduced", date(2024, 1, 1)).await;
 
-    let id = insert_brand_legal_proceeding(&pool, &proceeding)
+    let rows = compute_regulatory_exposure(&pool, &taxonomy, date(2026, 3, 1), None)
         .await
-        .expect("insert_brand_legal_proceeding failed");
-    assert!(id > 0);
+        .expect("compute failed");
+    let summary: Vec<(&str, i32, i32)> = rows
+        .iter()
+        .map(|r| (r.state.as_str(), r.store_count, r.pending_bills))
+        .collect();
+    assert_eq!(summary, [("SC", 3, 2), ("NC", 1, 1)]);
+    let expected_sc = 100.0 * (1.0 - (-1.05_f64).exp());
+    let expected_nc = 100.0 * (1.0 - (-0.15_f64).exp());
+    assert!((rows[0].state_exposure - expected_sc).abs() < 1e-6);
+    assert!((rows[1].state_exposure - expected_nc).abs() < 1e-6);
+    assert!((rows[0].footprint_share - 0.75).abs() < 1e-9);
+
+    record_regulatory_exposure(&pool, date(2026, 3, 1), &rows)
+        .await
+        .expect("record failed");
+    // Re-recording the same day replaces the snapshot.
+    record_regulatory_exposure(&pool, date(2026, 3, 1), &rows)
+        .await
+        .expect("re-record failed");
 
-    let rows = list_brand_legal_proceedings(&pool, brand_id)
+    upsert_bill(
+        &pool,
+        "SC",
+        "H1",
+        "Hemp beverage act",
+        None,
+        "passed",
+        None,
+        None,
+        Some(date(2026, 3, 5)),
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .expect("pass bill");
+    let later = compute_regulatory_exposure(&pool, &taxonomy, date(2026, 3, 8), None)
         .await
-        .expect("list_brand_legal_proceedings failed");
+        .expect("compute failed");
+    record_regulatory_exposure(&pool, date(2026, 3, 8), &later)
+        .await
+        .expect("record failed");
 
-    assert_eq!(rows.len(), 1, "should return exactly one legal proceeding");
-    let row = &rows[0];
-    assert_eq!(row.id, id);
-    assert_eq!(row.brand_id, brand_id);
-    assert_eq!(row.proceeding_type, "lawsuit");
-    assert_eq!(row.jurisdiction.as_deref(), Some("SC"));
-    assert_eq!(row.case_number.as_deref(), Some("2025-CV-001"));
-    assert_eq!(row.title, "State v. HempCo");
-    assert_eq!(
-        row.summary.as_deref(),
-        Some("Challenge to hemp beverage ban")
+    let latest = list_latest_regulatory_exposure(&pool, Some("exposed-brand"))
+        .await
+        .expect("latest failed");
+    assert_eq!(latest.len(), 2);
+    assert!(latest.iter().all(|r| r.snapshot_date == date(2026, 3, 8)));
+
+    let series = list_regulatory_exposure_series(&pool, None, date(2026, 1, 1))
+        .await
+        .expect("series failed");
+    let scores: Vec<(NaiveDate, i64)> = series
+        .iter()
+        .map(|p| (p.snapshot_date, p.store_count))
+        .collect();
+    assert_eq!(scores, [(date(2026, 3, 1), 4), (date(2026, 3, 8), 4)]);
+    let before = 0.75 * expected_sc + 0.25 * expected_nc;
+    assert!(
+        (series[0].score - before).abs() < 1e-6,
+        "{}",
+        series[0].score
+    );
+    assert!(
+        (series[1].score - expected_nc).abs() < 1e-6,
+        "{}",
+        series[1].score
     );
-    assert_eq!(row.status, "active");
-    assert_eq!(row.filed_at, NaiveDate::from_ymd_opt(2025, 3, 1));
-    assert!(row.resolved_at.is_none());
-    assert_eq!(row.source_url.as_deref(), Some("https://example.com/case"));
 }
 
-// ---------------------------------------------------------------------------
-// Section 14: Brand Sponsorships
-// ---------------------------------------------------------------------------
-
 #[sqlx::test(migrations = "../../migrations")]
-async fn brand_sponsorship_round_trip(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "sponsor-brand", true).await;
-
-    let sponsorship = NewBrandSponsorship {
-        brand_id,
-        entity_name: "Coastal Music Festival",
-        entity_type: "event",
-        deal_type: "title_sponsor",
-        announced_at: NaiveDate::from_ymd_opt(2025, 5, 1),
-        ends_at: NaiveDate::from_ymd_opt(2025, 9, 30),
-        source_url: Some("https://example.com/sponsor"),
-        notes: Some("Title sponsorship for summer series"),
-        is_active: true,
+async fn bill_changes_report_watched_and_relevant_bills_once(pool: sqlx::PgPool) {
+    let bill = |number: &'static str, status: &'static str| {
+        let pool = pool.clone();
+        async move {
+            upsert_bill(
+                &pool,
+                "SC",
+                number,
+                "Hemp beverage act",
+                None,
+                status,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .expect("upsert bill failed")
+        }
     };
-
-    let id = insert_brand_sponsorship(&pool, &sponsorship)
+    let watched = bill("H1", "introduced").await;
+    let relevant = bill("H2", "introduced").await;
+    replace_bill_topics(&pool, relevant, 90.0, &[])
         .await
-        .expect("insert_brand_sponsorship failed");
-    assert!(id > 0);
+        .expect("topics");
+    let ignored = bill("H3", "introduced").await;
+    replace_bill_topics(&pool, ignored, 10.0, &[])
+        .await
+        .expect("topics");
+    let day = NaiveDate::from_ymd_opt(2026, 3, 1);
+    upsert_bill_event(&pool, watched, day, None, None, "Introduced", None)
+        .await
+        .expect("event");
 
-    let rows = list_brand_sponsorships(&pool, brand_id)
+    let row = watch_bill(&pool, watched, Some("serving cap bill"))
         .await
-        .expect("list_brand_sponsorships failed");
+        .expect("watch failed");
+    assert_eq!(row.note.as_deref(), Some("serving cap bill"));
+    // Re-watching without a note keeps it.
+    let row = watch_bill(&pool, watched, None).await.expect("rewatch");
+    assert_eq!(row.note.as_deref(), Some("serving cap bill"));
+    assert!(matches!(
+        watch_bill(&pool, i64::MAX, None).await,
+        Err(DbError::NotFound)
+    ));
 
-    assert_eq!(rows.len(), 1, "should return exactly one sponsorship");
-    let row = &rows[0];
-    assert_eq!(row.id, id);
-    assert_eq!(row.brand_id, brand_id);
-    assert_eq!(row.entity_name, "Coastal Music Festival");
-    assert_eq!(row.entity_type, "event");
-    assert_eq!(row.deal_type, "title_sponsor");
-    assert_eq!(row.announced_at, NaiveDate::from_ymd_opt(2025, 5, 1));
-    assert_eq!(row.ends_at, NaiveDate::from_ymd_opt(2025, 9, 30));
+    // A channel's first run starts every tracked bill at its current state.
     assert_eq!(
-        row.source_url.as_deref(),
-        Some("https://example.com/sponsor")
+        start_bill_alert_channel(&pool, "slack", 70.0)
+            .await
+            .unwrap(),
+        Some(2)
     );
     assert_eq!(
-        row.notes.as_deref(),
-        Some("Title sponsorship for summer series")
+        start_bill_alert_channel(&pool, "slack", 70.0)
+            .await
+            .unwrap(),
+        None
+    );
+    assert!(list_bill_changes(&pool, "slack", 70.0)
+        .await
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        start_bill_alert_channel(&pool, "email", 70.0)
+            .await
+            .unwrap(),
+        Some(2)
     );
-    assert!(row.is_active);
-}
-
-// ---------------------------------------------------------------------------
-// Section 15: Brand Distributors
-// ---------------------------------------------------------------------------
-
-#[sqlx::test(migrations = "../../migrations")]
-async fn brand_distributor_round_trip(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "dist-brand", true).await;
 
-    let states = vec!["CA".to_string(), "OR".to_string(), "WA".to_string()];
-    let distributor = NewBrandDistributor {
-        brand_id,
-        distributor_name: "Pacific Distribution Co",
-        distributor_slug: "pacific-distribution-co",
-        states: Some(&states),
-        territory_type: "regional",
-        channel_type: "retail",
-        started_at: NaiveDate::from_ymd_opt(2025, 1, 1),
-        ended_at: None,
-        is_active: true,
-        notes: Some("West coast coverage"),
-    };
+    bill("H1", "engrossed").await;
+    upsert_bill_event(&pool, watched, day, None, Some("H"), "Passed House", None)
+        .await
+        .expect("event");
+    upsert_bill_event(&pool, relevant, day, None, None, "Referred", None)
+        .await
+        .expect("event");
+    bill("H3", "engrossed").await;
 
-    let id = insert_brand_distributor(&pool, &distributor)
+    let changes = list_bill_changes(&pool, "slack", 70.0)
         .await
-        .expect("insert_brand_distributor failed");
-    assert!(id > 0, "returned id should be positive");
+        .expect("changes");
+    let summary: Vec<(&str, Option<&str>, &str, bool, usize)> = changes
+        .iter()
+        .map(|c| {
+            (
+                c.bill_number.as_str(),
+                c.previous_status.as_deref(),
+                c.status.as_str(),
+                c.watched,
+                c.events.len(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("H1", Some("introduced"), "engrossed", true, 1),
+            ("H2", Some("introduced"), "introduced", false, 1),
+        ]
+    );
+    assert_eq!(changes[0].events[0].description, "Passed House");
 
-    let rows = list_brand_distributors(&pool, brand_id)
+    assert_eq!(
+        advance_bill_alert_cursors(&pool, "slack", &changes)
+            .await
+            .unwrap(),
+        2
+    );
+    assert!(list_bill_changes(&pool, "slack", 70.0)
         .await
-        .expect("list_brand_distributors failed");
+        .unwrap()
+        .is_empty());
+    // Channels keep their own cursors; email has not delivered yet.
+    assert_eq!(
+        list_bill_changes(&pool, "email", 70.0).await.unwrap().len(),
+        2
+    );
 
-    assert_eq!(rows.len(), 1, "should return exactly one distributor");
-    let row = &rows[0];
-    assert_eq!(row.id, id);
-    assert_eq!(row.brand_id, brand_id);
-    assert_eq!(row.distributor_name, "Pacific Distribution Co");
-    assert_eq!(row.distributor_slug, "pacific-distribution-co");
+    // A bill tracked after the channel started is reported as new, with all
+    // its events.
+    replace_bill_topics(&pool, ignored, 95.0, &[])
+        .await
+        .expect("topics");
+    upsert_bill_event(&pool, ignored, day, None, None, "Introduced", None)
+        .await
+        .expect("event");
+    let new = list_bill_changes(&pool, "slack", 70.0).await.unwrap();
+    assert_eq!(new.len(), 1);
+    assert_eq!(new[0].bill_number, "H3");
+    assert_eq!(new[0].previous_status, None);
+    assert_eq!(new[0].events.len(), 1);
     assert_eq!(
-        row.states.as_deref(),
-        Some(&["CA".to_string(), "OR".to_string(), "WA".to_string()][..])
+        advance_bill_alert_cursors(&pool, "slack", &new)
+            .await
+            .unwrap(),
+        1
     );
-    assert_eq!(row.territory_type, "regional");
-    assert_eq!(row.channel_type, "retail");
-    assert_eq!(row.started_at, NaiveDate::from_ymd_opt(2025, 1, 1));
-    assert!(row.ended_at.is_none());
-    assert!(row.is_active);
-    assert_eq!(row.notes.as_deref(), Some("West coast coverage"));
+    assert!(list_bill_changes(&pool, "slack", 70.0)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let listed = list_watched_bills(&pool, Some("SC")).await.expect("list");
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].status, "engrossed");
+    unwatch_bill(&pool, watched).await.expect("unwatch");
+    assert!(matches!(
+        unwatch_bill(&pool, watched).await,
+        Err(DbError::NotFound)
+    ));
+    assert!(list_watched_bills(&pool, None).await.unwrap().is_empty());
 }
 
 // ---------------------------------------------------------------------------
-// Section 16: Brand Competitor Relationships
+// Section 7: Collection Run Brands
 // ---------------------------------------------------------------------------
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn brand_competitor_relationship_round_trip(pool: sqlx::PgPool) {
-    let brand_a = insert_test_brand(&pool, "comp-brand-a", true).await;
-    let brand_b = insert_test_brand(&pool, "comp-brand-b", true).await;
-
-    let states = vec!["SC".to_string(), "NC".to_string()];
-    let rel = NewBrandCompetitorRelationship {
-        brand_id: brand_a,
-        competitor_brand_id: brand_b,
-        relationship_type: "direct_competitor",
-        distributor_name: Some("Southern Dist"),
-        states: Some(&states),
-        notes: Some("Both sell in Carolinas"),
-        is_active: true,
-    };
-
-    let id = insert_brand_competitor_relationship(&pool, &rel)
+async fn upsert_collection_run_brand_overwrites_on_conflict(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "crb-upsert", true).await;
+    let run = create_collection_run(&pool, "products", "cli")
         .await
-        .expect("insert_brand_competitor_relationship failed");
-    assert!(id > 0, "returned id should be positive");
+        .expect("create_collection_run failed");
 
-    // Query from brand_a side
-    let rows_a = list_brand_competitor_relationships(&pool, brand_a)
+    // First call: simulate a failure recording
+    upsert_collection_run_brand(&pool, run.id, brand_id, "failed", None, Some("first error"))
         .await
-        .expect("list from brand_a failed");
-    assert_eq!(rows_a.len(), 1, "brand_a should see 1 relationship");
+        .expect("first upsert_collection_run_brand failed");
 
-    // Query from brand_b side — same row should appear
-    let rows_b = list_brand_competitor_relationships(&pool, brand_b)
+    // Second call: simulate a re-run that succeeded
+    upsert_collection_run_brand(&pool, run.id, brand_id, "succeeded", Some(5), None)
         .await
-        .expect("list from brand_b failed");
-    assert_eq!(rows_b.len(), 1, "brand_b should see 1 relationship");
-    assert_eq!(rows_a[0].id, rows_b[0].id, "same row from both sides");
+        .expect("second upsert_collection_run_brand failed");
+
+    // Verify exactly one row exists for this (run_id, brand_id) pair
+    let count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM collection_run_brands \
+         WHERE collection_run_id = $1 AND brand_id = $2",
+    )
+    .bind(run.id)
+    .bind(brand_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
 
-    let row = &rows_a[0];
-    assert!(
-        row.brand_id < row.competitor_brand_id,
-        "stored row must have brand_id < competitor_brand_id (canonical order)"
-    );
-    assert_eq!(row.relationship_type, "direct_competitor");
-    assert_eq!(row.distributor_name.as_deref(), Some("Southern Dist"));
     assert_eq!(
-        row.states.as_deref(),
-        Some(&["SC".to_string(), "NC".to_string()][..])
+        count, 1,
+        "upsert should produce exactly one row, not a duplicate"
     );
-    assert_eq!(row.notes.as_deref(), Some("Both sell in Carolinas"));
-    assert!(row.is_active);
-}
 
-#[sqlx::test(migrations = "../../migrations")]
-async fn brand_competitor_relationship_canonical_ordering(pool: sqlx::PgPool) {
-    // Insert two brands; brand_a will have a lower id than brand_b.
-    let brand_a = insert_test_brand(&pool, "canon-lo", true).await;
-    let brand_b = insert_test_brand(&pool, "canon-hi", true).await;
+    // Verify the row reflects the second (overwriting) call
+    let (status, records_processed, error_message): (String, i32, Option<String>) = sqlx::query_as(
+        "SELECT status, records_processed, error_message \
+             FROM collection_run_brands \
+             WHERE collection_run_id = $1 AND brand_id = $2",
+    )
+    .bind(run.id)
+    .bind(brand_id)
+    .fetch_one(&pool)
+    .await
+    .expect("fetch upserted row failed");
+
+    assert_eq!(
+        status, "succeeded",
+        "status should be overwritten to 'succeeded'"
+    );
+    assert_eq!(
+        records_processed, 5,
+        "records_processed should be overwritten to 5"
+    );
     assert!(
-        brand_a < brand_b,
-        "test setup: brand_a should have lower id"
+        error_message.is_none(),
+        "error_message should be overwritten to NULL"
     );
+}
 
-    // Pass ids in REVERSE order (higher first) to verify canonicalization.
-    let rel = NewBrandCompetitorRelationship {
-        brand_id: brand_b,            // higher id
-        competitor_brand_id: brand_a, // lower id
-        relationship_type: "indirect_competitor",
-        distributor_name: None,
-        states: None,
-        notes: None,
-        is_active: true,
-    };
+#[sqlx::test(migrations = "../../migrations")]
+async fn list_collection_run_brands_returns_inserted_entries(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "crb-list", true).await;
+    let run = create_collection_run(&pool, "products", "cli")
+        .await
+        .expect("create_collection_run failed");
 
-    let id = insert_brand_competitor_relationship(&pool, &rel)
+    // Insert a brand-level result via the upsert helper
+    upsert_collection_run_brand(&pool, run.id, brand_id, "succeeded", Some(3), None)
         .await
-        .expect("insert with reversed ids failed");
+        .expect("upsert_collection_run_brand failed");
 
-    // Verify the stored row has canonical ordering: brand_id < competitor_brand_id.
-    let (stored_brand_id, stored_competitor_id): (i64, i64) = sqlx::query_as(
-        "SELECT brand_id, competitor_brand_id \
-         FROM brand_competitor_relationships WHERE id = $1",
-    )
-    .bind(id)
-    .fetch_one(&pool)
-    .await
-    .expect("fetch stored row failed");
+    let entries = list_collection_run_brands(&pool, run.id)
+        .await
+        .expect("list_collection_run_brands failed");
 
-    assert_eq!(stored_brand_id, brand_a, "brand_id should be the lower id");
-    assert_eq!(
-        stored_competitor_id, brand_b,
-        "competitor_brand_id should be the higher id"
-    );
+    assert_eq!(entries.len(), 1, "should return exactly one entry");
+    assert_eq!(entries[0].collection_run_id, run.id);
+    assert_eq!(entries[0].brand_id, brand_id);
+    assert_eq!(entries[0].status, "succeeded");
+    assert_eq!(entries[0].records_processed, 3);
+    assert!(entries[0].error_message.is_none());
 }
 
 // ---------------------------------------------------------------------------
-// Section 17: Brand Newsletters
+// Section 8: Store Locations — Dashboard Aggregates
 // ---------------------------------------------------------------------------
 
+/// Build a minimal `NewStoreLocation` for tests.
+fn make_test_location(
+    key_suffix: &str,
+    name: &str,
+    state: Option<&str>,
+    locator_source: Option<&str>,
+) -> NewStoreLocation {
+    NewStoreLocation {
+        location_key: format!("test-loc-{key_suffix}"),
+        name: name.to_string(),
+        address_line1: None,
+        city: None,
+        state: state.map(str::to_string),
+        zip: None,
+        country: Some("US".to_string()),
+        latitude: None,
+        longitude: None,
+        phone: None,
+        external_id: None,
+        locator_source: locator_source.map(str::to_string),
+        raw_data: serde_json::json!({}),
+        retail_chain: None,
+        retail_channel: None,
+        hours: None,
+        website: None,
+        email: None,
+        store_type: None,
+        carried_products: Vec::new(),
+    }
+}
+
 #[sqlx::test(migrations = "../../migrations")]
-async fn brand_newsletter_round_trip(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "news-brand", true).await;
+async fn locations_dashboard_summary_returns_per_brand_counts(pool: sqlx::PgPool) {
+    let brand_a = insert_test_brand(&pool, "loc-brand-a", true).await;
+    let brand_b = insert_test_brand(&pool, "loc-brand-b", true).await;
 
-    let newsletter = NewBrandNewsletter {
-        brand_id,
-        list_name: "Monthly Buzz",
-        subscribe_url: Some("https://example.com/subscribe"),
-        unsubscribe_url: Some("https://example.com/unsubscribe"),
-        inbox_address: Some("buzz@inbox.example.com"),
-        subscribed_at: None,
-        last_received_at: None,
-        is_active: true,
-        notes: Some("Main marketing newsletter"),
-    };
+    // Brand-a: 3 locations across CA (×2) and TX.
+    let locs_a = vec![
+        make_test_location("a-ca-1", "Store CA 1", Some("CA"), Some("locally")),
+        make_test_location("a-ca-2", "Store CA 2", Some("CA"), Some("locally")),
+        make_test_location("a-tx-1", "Store TX 1", Some("TX"), Some("locally")),
+    ];
+    upsert_store_locations(&pool, brand_a, &locs_a)
+        .await
+        .expect("upsert brand-a locations failed");
 
-    let id = insert_brand_newsletter(&pool, &newsletter)
+    // Brand-b: 1 location in NY.
+    let locs_b = vec![make_test_location(
+        "b-ny-1",
+        "Store NY 1",
+        Some("NY"),
+        Some("storemapper"),
+    )];
+    upsert_store_locations(&pool, brand_b, &locs_b)
         .await
-        .expect("insert_brand_newsletter failed");
-    assert!(id > 0, "returned id should be positive");
+        .expect("upsert brand-b locations failed");
 
-    let rows = list_brand_newsletters(&pool, brand_id)
+    let rows = list_locations_dashboard_summary(&pool, None)
         .await
-        .expect("list_brand_newsletters failed");
+        .expect("list_locations_dashboard_summary failed");
 
-    assert_eq!(rows.len(), 1, "should return exactly one newsletter");
-    let row = &rows[0];
-    assert_eq!(row.id, id);
-    assert_eq!(row.brand_id, brand_id);
-    assert_eq!(row.list_name, "Monthly Buzz");
+    assert_eq!(rows.len(), 2, "expected 2 brand rows");
+
+    // Results are ordered by active_count DESC, so brand-a (3) comes first.
+    let row_a = &rows[0];
+    assert_eq!(row_a.brand_slug, "loc-brand-a");
+    assert_eq!(row_a.active_count, 3, "brand-a active_count");
+    assert_eq!(row_a.states_covered, 2, "brand-a covers CA + TX = 2 states");
     assert_eq!(
-        row.subscribe_url.as_deref(),
-        Some("https://example.com/subscribe")
+        row_a.locator_source.as_deref(),
+        Some("locally"),
+        "brand-a locator_source"
     );
+
+    let row_b = &rows[1];
+    assert_eq!(row_b.brand_slug, "loc-brand-b");
+    assert_eq!(row_b.active_count, 1, "brand-b active_count");
+    assert_eq!(row_b.states_covered, 1, "brand-b covers NY = 1 state");
     assert_eq!(
-        row.unsubscribe_url.as_deref(),
-        Some("https://example.com/unsubscribe")
+        row_b.locator_source.as_deref(),
+        Some("storemapper"),
+        "brand-b locator_source"
     );
-    assert_eq!(row.inbox_address.as_deref(), Some("buzz@inbox.example.com"));
-    assert!(row.subscribed_at.is_none());
-    assert!(row.last_received_at.is_none());
-    assert!(row.is_active);
-    assert_eq!(row.notes.as_deref(), Some("Main marketing newsletter"));
 }
 
-// ---------------------------------------------------------------------------
-// Section 18: Brand Media Appearances
-// ---------------------------------------------------------------------------
-
 #[sqlx::test(migrations = "../../migrations")]
-async fn brand_media_appearance_round_trip(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "media-brand", true).await;
+async fn locations_dashboard_summary_excludes_brands_with_no_active_locations(pool: sqlx::PgPool) {
+    // Brand with no locations at all.
+    insert_test_brand(&pool, "loc-empty", true).await;
 
-    let appearance = NewBrandMediaAppearance {
-        brand_id,
-        brand_signal_id: None,
-        appearance_type: "podcast",
-        outlet_name: "Hemp Industry Daily",
-        title: Some("The Future of THC Beverages"),
-        host_or_author: Some("Jane Doe"),
-        aired_at: NaiveDate::from_ymd_opt(2025, 7, 20),
-        duration_seconds: Some(3600),
-        source_url: Some("https://example.com/podcast/ep42"),
-        notes: Some("CEO interview"),
-    };
+    // Brand with one location that gets deactivated.
+    let brand_deactivated = insert_test_brand(&pool, "loc-deactivated", true).await;
+    let locs = vec![make_test_location(
+        "d-ca-1",
+        "Deactivated Store",
+        Some("CA"),
+        None,
+    )];
+    upsert_store_locations(&pool, brand_deactivated, &locs)
+        .await
+        .expect("upsert failed");
 
-    let id = insert_brand_media_appearance(&pool, &appearance)
+    // Deactivate all locations for the brand by passing an empty active_keys slice.
+    deactivate_missing_locations(&pool, brand_deactivated, &[])
         .await
-        .expect("insert_brand_media_appearance failed");
-    assert!(id > 0, "returned id should be positive");
+        .expect("deactivate failed");
 
-    let rows = list_brand_media_appearances(&pool, brand_id)
+    let rows = list_locations_dashboard_summary(&pool, None)
         .await
-        .expect("list_brand_media_appearances failed");
+        .expect("list_locations_dashboard_summary failed");
 
-    assert_eq!(rows.len(), 1, "should return exactly one media appearance");
-    let row = &rows[0];
-    assert_eq!(row.id, id);
-    assert_eq!(row.brand_id, brand_id);
-    assert!(row.brand_signal_id.is_none());
-    assert_eq!(row.appearance_type, "podcast");
-    assert_eq!(row.outlet_name, "Hemp Industry Daily");
-    assert_eq!(row.title.as_deref(), Some("The Future of THC Beverages"));
-    assert_eq!(row.host_or_author.as_deref(), Some("Jane Doe"));
-    assert_eq!(row.aired_at, NaiveDate::from_ymd_opt(2025, 7, 20));
-    assert_eq!(row.duration_seconds, Some(3600));
     assert_eq!(
-        row.source_url.as_deref(),
-        Some("https://example.com/podcast/ep42")
+        rows.len(),
+        0,
+        "HAVING clause should exclude brands with 0 active locations"
     );
-    assert_eq!(row.notes.as_deref(), Some("CEO interview"));
 }
 
-// ---------------------------------------------------------------------------
-// Section 19: Brand Completeness Score
-// ---------------------------------------------------------------------------
-
 #[sqlx::test(migrations = "../../migrations")]
-async fn brand_completeness_score_nonexistent_brand(pool: sqlx::PgPool) {
-    let result = get_brand_completeness(&pool, 999_999)
+async fn locations_by_state_groups_correctly(pool: sqlx::PgPool) {
+    let brand_a = insert_test_brand(&pool, "state-brand-a", true).await;
+    let brand_b = insert_test_brand(&pool, "state-brand-b", true).await;
+
+    // brand-a: CA, CA, TX
+    let locs_a = vec![
+        make_test_location("s-a-ca-1", "Store A-CA-1", Some("CA"), None),
+        make_test_location("s-a-ca-2", "Store A-CA-2", Some("CA"), None),
+        make_test_location("s-a-tx-1", "Store A-TX-1", Some("TX"), None),
+    ];
+    upsert_store_locations(&pool, brand_a, &locs_a)
         .await
-        .expect("query should not fail");
+        .expect("upsert brand-a failed");
 
-    assert!(
-        result.is_none(),
-        "expected None for nonexistent brand, got Some"
+    // brand-b: CA, NY
+    let locs_b = vec![
+        make_test_location("s-b-ca-1", "Store B-CA-1", Some("CA"), None),
+        make_test_location("s-b-ny-1", "Store B-NY-1", Some("NY"), None),
+    ];
+    upsert_store_locations(&pool, brand_b, &locs_b)
+        .await
+        .expect("upsert brand-b failed");
+
+    let rows = list_locations_by_state(&pool, None)
+        .await
+        .expect("list_locations_by_state failed");
+
+    // Ordered by location_count DESC: CA(3), TX(1), NY(1) — TX/NY order may vary.
+    let ca_row = rows
+        .iter()
+        .find(|r| r.state == "CA")
+        .expect("CA row missing");
+    assert_eq!(ca_row.location_count, 3, "CA location_count");
+    assert_eq!(ca_row.brand_count, 2, "CA brand_count");
+
+    let tx_row = rows
+        .iter()
+        .find(|r| r.state == "TX")
+        .expect("TX row missing");
+    assert_eq!(tx_row.location_count, 1, "TX location_count");
+    assert_eq!(tx_row.brand_count, 1, "TX brand_count");
+
+    let ny_row = rows
+        .iter()
+        .find(|r| r.state == "NY")
+        .expect("NY row missing");
+    assert_eq!(ny_row.location_count, 1, "NY location_count");
+    assert_eq!(ny_row.brand_count, 1, "NY brand_count");
+
+    // CA must come first (highest count).
+    assert_eq!(
+        rows[0].state, "CA",
+        "CA should be first (location_count DESC)"
     );
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn brand_completeness_score_empty_brand(pool: sqlx::PgPool) {
-    // Brand with no profile, no signals, no anything.
-    let brand_id = insert_test_brand(&pool, "completeness-empty", true).await;
+async fn locations_by_state_excludes_null_and_empty_states(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "null-state-brand", true).await;
 
-    let result = get_brand_completeness(&pool, brand_id)
+    // One location with state=None, one with state=Some("").
+    let locs = vec![
+        make_test_location("ns-null", "Null State Store", None, None),
+        make_test_location("ns-empty", "Empty State Store", Some(""), None),
+    ];
+    upsert_store_locations(&pool, brand_id, &locs)
         .await
-        .expect("query should not fail")
-        .expect("brand exists, expected Some");
+        .expect("upsert failed");
 
-    assert_eq!(result.brand_id, brand_id);
-    assert_eq!(result.score, 0, "empty brand should have score 0");
-    assert!(!result.has_profile);
-    assert!(!result.has_description);
-    assert!(!result.has_tagline);
-    assert!(!result.has_founded_year);
-    assert!(!result.has_location);
-    assert!(!result.has_social_handles);
-    assert!(!result.has_domains);
-    assert!(!result.has_signals);
-    assert!(!result.has_funding);
-    assert!(!result.has_lab_tests);
-    assert!(!result.has_legal);
-    assert!(!result.has_sponsorships);
-    assert!(!result.has_distributors);
-    assert!(!result.has_media);
+    let rows = list_locations_by_state(&pool, None)
+        .await
+        .expect("list_locations_by_state failed");
+
+    assert_eq!(
+        rows.len(),
+        0,
+        "rows with NULL or empty state should be excluded by WHERE clause"
+    );
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn brand_completeness_score_with_profile(pool: sqlx::PgPool) {
-    use scbdb_db::brand_completeness::{
-        W_DESCRIPTION, W_FOUNDED_YEAR, W_LOCATION, W_PROFILE, W_TAGLINE,
-    };
-
-    let brand_id = insert_test_brand(&pool, "completeness-profile", true).await;
-
-    // Upsert a profile with all core fields filled.
-    upsert_brand_profile(
-        &pool,
-        brand_id,
-        Some("Refreshingly different"),
-        Some("Premium hemp-derived THC seltzers"),
-        Some(2020_i16),
-        Some("Charleston"),
-        Some("SC"),
-        Some("ParentCo"),
-    )
-    .await
-    .expect("upsert_brand_profile failed");
-
-    let result = get_brand_completeness(&pool, brand_id)
+async fn locations_channel_mix_counts_active_locations_by_channel(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "channel-mix-brand", true).await;
+
+    let mut liquor_1 = make_test_location("cm-1", "Total Wine 1", Some("SC"), None);
+    liquor_1.retail_chain = Some("Total Wine & More".to_string());
+    liquor_1.retail_channel = Some("liquor".to_string());
+    let mut liquor_2 = make_test_location("cm-2", "Total Wine 2", Some("SC"), None);
+    liquor_2.retail_channel = Some("liquor".to_string());
+    let mut grocery = make_test_location("cm-3", "Publix", Some("GA"), None);
+    grocery.retail_channel = Some("grocery".to_string());
+    let unknown = make_test_location("cm-4", "Gift Shop", Some("SC"), None);
+
+    upsert_store_locations(&pool, brand_id, &[liquor_1, liquor_2, grocery, unknown])
         .await
-        .expect("query should not fail")
-        .expect("brand exists, expected Some");
+        .expect("upsert failed");
 
-    let expected_score = W_PROFILE + W_DESCRIPTION + W_TAGLINE + W_FOUNDED_YEAR + W_LOCATION;
+    let summary = list_locations_dashboard_summary(&pool, None)
+        .await
+        .expect("summary failed");
+    assert_eq!(
+        summary[0].channel_mix,
+        serde_json::json!({ "liquor": 2, "grocery": 1, "unknown": 1 })
+    );
 
-    assert_eq!(result.brand_id, brand_id);
+    let by_state = list_locations_by_state(&pool, None)
+        .await
+        .expect("by-state failed");
+    let sc = by_state.iter().find(|r| r.state == "SC").expect("SC row");
+    assert_eq!(sc.location_count, 3);
     assert_eq!(
-        result.score, expected_score,
-        "score should be {expected_score} (profile + desc + tagline + year + location), got {}",
-        result.score
+        sc.channel_mix,
+        serde_json::json!({ "liquor": 2, "unknown": 1 })
     );
-    assert!(result.has_profile);
-    assert!(result.has_description);
-    assert!(result.has_tagline);
-    assert!(result.has_founded_year);
-    assert!(result.has_location);
-    // These should still be false.
-    assert!(!result.has_social_handles);
-    assert!(!result.has_domains);
-    assert!(!result.has_signals);
-    assert!(!result.has_funding);
-    assert!(!result.has_lab_tests);
-    assert!(!result.has_legal);
-    assert!(!result.has_sponsorships);
-    assert!(!result.has_distributors);
-    assert!(!result.has_media);
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn brand_completeness_score_partial_profile(pool: sqlx::PgPool) {
-    use scbdb_db::brand_completeness::{W_DESCRIPTION, W_PROFILE};
-
-    let brand_id = insert_test_brand(&pool, "completeness-partial", true).await;
+async fn location_reads_filter_by_country(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "country-brand", true).await;
+
+    let mut austin = make_test_location("cf-us", "Austin Store", Some("TX"), None);
+    austin.latitude = Some(30.2672);
+    austin.longitude = Some(-97.7431);
+    let mut toronto = make_test_location("cf-ca", "Toronto Store", Some("ON"), None);
+    toronto.country = Some("CA".to_string());
+    toronto.latitude = Some(43.6532);
+    toronto.longitude = Some(-79.3832);
+    upsert_store_locations(&pool, brand_id, &[austin, toronto])
+        .await
+        .expect("upsert failed");
 
-    // Profile with only description filled (no tagline, no year, no location).
-    upsert_brand_profile(
-        &pool,
-        brand_id,
-        None,                                   // tagline
-        Some("A description but nothing else"), // description
-        None,                                   // founded_year
-        None,                                   // hq_city
-        None,                                   // hq_state
-        None,                                   // parent_company
-    )
-    .await
-    .expect("upsert_brand_profile failed");
+    let by_state = list_locations_by_state(&pool, None)
+        .await
+        .expect("by-state failed");
+    let on = by_state.iter().find(|r| r.state == "ON").expect("ON row");
+    assert_eq!(on.country, "CA");
 
-    let result = get_brand_completeness(&pool, brand_id)
+    let canada_only = list_locations_by_state(&pool, Some("CA"))
         .await
-        .expect("query should not fail")
-        .expect("brand exists, expected Some");
+        .expect("by-state failed");
+    assert_eq!(canada_only.len(), 1);
+    assert_eq!(canada_only[0].state, "ON");
 
-    let expected_score = W_PROFILE + W_DESCRIPTION;
+    let summary = list_locations_dashboard_summary(&pool, Some("US"))
+        .await
+        .expect("summary failed");
+    assert_eq!(summary[0].active_count, 1);
 
-    assert_eq!(
-        result.score, expected_score,
-        "score should be {expected_score} (profile + description only), got {}",
-        result.score
-    );
-    assert!(result.has_profile);
-    assert!(result.has_description);
-    assert!(!result.has_tagline);
-    assert!(!result.has_founded_year);
-    assert!(!result.has_location);
+    let pins = list_active_location_pins(&pool, 10, None, None, Some("CA"))
+        .await
+        .expect("pins failed");
+    assert_eq!(pins.len(), 1);
+    assert_eq!(pins[0].store_name, "Toronto Store");
+    assert_eq!(pins[0].country, "CA");
 }
 
-// ---------------------------------------------------------------------------
-// Section: Location Pins
-// ---------------------------------------------------------------------------
-
 #[sqlx::test(migrations = "../../migrations")]
-async fn list_active_location_pins_empty_when_no_locations(pool: sqlx::PgPool) {
-    let pins = list_active_location_pins(&pool, 1000, None, None)
+async fn location_weekly_counts_rebuild_active_history(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "weekly-brand", true).await;
+    upsert_store_locations(
+        &pool,
+        brand_id,
+        &[
+            make_test_location("wk-new", "Opened Recently", Some("SC"), None),
+            make_test_location("wk-old", "Closed Store", Some("SC"), None),
+        ],
+    )
+    .await
+    .expect("upsert failed");
+    sqlx::query(
+        "UPDATE store_locations SET \
+            first_seen_at = date_trunc('week', NOW()) - INTERVAL '2 weeks' \
+         WHERE location_key = 'test-loc-wk-new'",
+    )
+    .execute(&pool)
+    .await
+    .expect("backdate new store");
+    sqlx::query(
+        "UPDATE store_locations SET \
+            first_seen_at = date_trunc('week', NOW()) - INTERVAL '6 weeks', \
+            last_seen_at = date_trunc('week', NOW()) - INTERVAL '4 weeks' + INTERVAL '1 day', \
+            is_active = FALSE \
+         WHERE location_key = 'test-loc-wk-old'",
+    )
+    .execute(&pool)
+    .await
+    .expect("backdate closed store");
+
+    let rows = list_location_weekly_counts(&pool, Some("weekly-brand"), None, None, 8)
         .await
-        .expect("query failed");
-    assert!(pins.is_empty());
+        .expect("weekly counts failed");
+    let counts: Vec<i64> = rows.iter().map(|r| r.active_count).collect();
+    // Oldest first, starting at the closed store's first week: weeks -6 and
+    // -5, a gap, then the new store from week -2. Week -7 is omitted.
+    assert_eq!(counts, vec![1, 1, 0, 0, 1, 1, 1]);
+    assert!(rows.windows(2).all(|w| w[0].week_start < w[1].week_start));
+    assert_eq!(rows[0].state, "SC");
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn list_active_location_pins_returns_rows_with_coords(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "pin-brand-coords", true).await;
+async fn location_weekly_counts_start_at_first_seen_week(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "weekly-late-brand", true).await;
     upsert_store_locations(
         &pool,
         brand_id,
-        &[NewStoreLocation {
-            location_key: "pin-loc-1".to_string(),
-            name: "Pin Store".to_string(),
-            address_line1: Some("123 Main St".to_string()),
-            city: Some("Austin".to_string()),
-            state: Some("TX".to_string()),
-            zip: Some("78701".to_string()),
-            country: Some("US".to_string()),
-            latitude: Some(30.2672),
-            longitude: Some(-97.7431),
-            phone: None,
-            external_id: None,
-            locator_source: Some("locally".to_string()),
-            raw_data: serde_json::json!({}),
+        &[
+            make_test_location("wl-sc", "Late SC Store", Some("SC"), None),
+            make_test_location("wl-nc", "Late NC Store", Some("NC"), None),
+        ],
+    )
+    .await
+    .expect("upsert failed");
+    sqlx::query(
+        "UPDATE store_locations SET first_seen_at = CASE location_key \
+            WHEN 'test-loc-wl-sc' THEN date_trunc('week', NOW()) - INTERVAL '3 weeks' \
+            ELSE date_trunc('week', NOW()) - INTERVAL '1 week' + INTERVAL '2 days' END \
+         WHERE brand_id = $1",
+    )
+    .bind(brand_id)
+    .execute(&pool)
+    .await
+    .expect("backdate stores");
+
+    let rows = list_location_weekly_counts(&pool, Some("weekly-late-brand"), None, None, 12)
+        .await
+        .expect("weekly counts failed");
+    let series = |state: &str| -> Vec<i64> {
+        rows.iter()
+            .filter(|r| r.state == state)
+            .map(|r| r.active_count)
+            .collect()
+    };
+    // Brand first seen mid-window: no zero weeks before each state's first
+    // store.
+    assert_eq!(series("SC"), vec![1, 1, 1, 1]);
+    assert_eq!(series("NC"), vec![1, 1]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn update_location_classifications_only_touches_changed_rows(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "classify-brand", true).await;
+    let mut already = make_test_location("cl-1", "Circle K 12", Some("SC"), None);
+    already.retail_chain = Some("Circle K".to_string());
+    already.retail_channel = Some("convenience".to_string());
+    let pending = make_test_location("cl-2", "Publix 9", Some("SC"), None);
+    upsert_store_locations(&pool, brand_id, &[already, pending])
+        .await
+        .expect("upsert failed");
+
+    let inputs = list_locations_for_classification(&pool, Some(brand_id))
+        .await
+        .expect("list inputs failed");
+    assert_eq!(inputs.len(), 2);
+
+    let updates: Vec<LocationClassificationUpdate> = inputs
+        .iter()
+        .map(|row| {
+            let (chain, channel) = if row.name.starts_with("Circle K") {
+                ("Circle K", "convenience")
+            } else {
+                ("Publix", "grocery")
+            };
+            LocationClassificationUpdate {
+                id: row.id,
+                retail_chain: Some(chain.to_string()),
+                retail_channel: Some(channel.to_string()),
+            }
+        })
+        .collect();
+
+    let changed = update_location_classifications(&pool, &updates)
+        .await
+        .expect("update failed");
+    assert_eq!(changed, 1, "only the unclassified row should change");
+
+    let rows = list_active_locations_by_brand(&pool, brand_id)
+        .await
+        .expect("list failed");
+    let publix = rows.iter().find(|r| r.name == "Publix 9").expect("publix");
+    assert_eq!(publix.retail_chain.as_deref(), Some("Publix"));
+    assert_eq!(publix.retail_channel.as_deref(), Some("grocery"));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn location_events_recorded_from_snapshot_diff(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "events-brand", true).await;
+    let first = vec![
+        make_test_location("ev-1", "Kept Store", Some("SC"), None),
+        make_test_location("ev-2", "Dropped Store", Some("SC"), None),
+    ];
+    upsert_store_locations(&pool, brand_id, &first)
+        .await
+        .expect("initial upsert failed");
+
+    let prev = list_location_snapshots_for_brand(&pool, brand_id)
+        .await
+        .expect("snapshot failed");
+    assert_eq!(prev.len(), 2);
+
+    let second = vec![
+        make_test_location("ev-1", "Kept Store", Some("SC"), None),
+        make_test_location("ev-3", "Opened Store", Some("NC"), None),
+    ];
+    upsert_store_locations(&pool, brand_id, &second)
+        .await
+        .expect("second upsert failed");
+    let active_keys: Vec<String> = second.iter().map(|l| l.location_key.clone()).collect();
+    deactivate_missing_locations(&pool, brand_id, &active_keys)
+        .await
+        .expect("deactivate failed");
+
+    let events = diff_location_snapshots(&prev, &second);
+    let written = insert_store_location_events(&pool, brand_id, None, &events)
+        .await
+        .expect("insert events failed");
+    assert_eq!(written, 2);
+
+    let feed = list_store_location_events(&pool, &LocationEventFilters::default(), 10, None)
+        .await
+        .expect("list events failed");
+    assert_eq!(feed.len(), 2);
+    let removed = feed
+        .iter()
+        .find(|e| e.event_type == "removed")
+        .expect("removed event");
+    assert_eq!(removed.store_name, "Dropped Store");
+    assert_eq!(removed.state.as_deref(), Some("SC"));
+
+    let added_only = list_store_location_events(
+        &pool,
+        &LocationEventFilters {
+            event_type: Some("added"),
+            ..LocationEventFilters::default()
+        },
+        10,
+        None,
+    )
+    .await
+    .expect("filtered list failed");
+    assert_eq!(added_only.len(), 1);
+    assert_eq!(added_only[0].store_name, "Opened Store");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn location_details_and_carried_products_are_replaced(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "details-brand", true).await;
+    let carried = |refs: &[&str]| -> Vec<NewCarriedProduct> {
+        refs.iter()
+            .map(|r| NewCarriedProduct {
+                product_ref: (*r).to_string(),
+                product_name: Some(format!("Product {r}")),
+                upc: None,
+            })
+            .collect()
+    };
+    let mut loc = make_test_location("det-1", "Detail Store", Some("SC"), Some("destini"));
+    loc.hours = Some("mon 9am-9pm".to_string());
+    loc.website = Some("https://store.example".to_string());
+    loc.carried_products = carried(&["p1", "p2"]);
+    upsert_store_locations(&pool, brand_id, &[loc.clone()])
+        .await
+        .expect("upsert failed");
+
+    let products = |pool: sqlx::PgPool| async move {
+        sqlx::query_scalar::<_, String>(
+            "SELECT product_ref FROM store_location_products ORDER BY product_ref",
+        )
+        .fetch_all(&pool)
+        .await
+        .expect("products query failed")
+    };
+    assert_eq!(products(pool.clone()).await, ["p1", "p2"]);
+
+    // A later scrape that drops p1 and adds p3 replaces the set.
+    loc.carried_products = carried(&["p2", "p3"]);
+    upsert_store_locations(&pool, brand_id, &[loc])
+        .await
+        .expect("second upsert failed");
+    assert_eq!(products(pool.clone()).await, ["p2", "p3"]);
+
+    let inputs = list_locations_for_details(&pool, Some(brand_id))
+        .await
+        .expect("list for details failed");
+    assert_eq!(inputs.len(), 1);
+    assert_eq!(inputs[0].locator_source.as_deref(), Some("destini"));
+
+    let changed = update_location_details(
+        &pool,
+        &[LocationDetailsUpdate {
+            id: inputs[0].id,
+            hours: Some("mon 9am-9pm".to_string()),
+            website: None,
+            email: Some("store@example.com".to_string()),
+            store_type: Some("Liquor Store".to_string()),
+            carried_products: Vec::new(),
         }],
     )
     .await
-    .expect("upsert");
-    let pins = list_active_location_pins(&pool, 1000, None, None)
+    .expect("update details failed");
+    assert_eq!(changed, 1);
+    assert!(products(pool.clone()).await.is_empty());
+
+    let row = list_active_locations_by_brand(&pool, brand_id)
         .await
-        .expect("query failed");
-    assert_eq!(pins.len(), 1);
-    assert!((pins[0].latitude - 30.2672).abs() < 0.001);
-    assert_eq!(pins[0].store_name, "Pin Store");
+        .expect("list active failed")
+        .remove(0);
+    assert_eq!(row.website, None);
+    assert_eq!(row.email.as_deref(), Some("store@example.com"));
+    assert_eq!(row.store_type.as_deref(), Some("Liquor Store"));
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn list_active_location_pins_excludes_null_coords(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "pin-brand-null-coords", true).await;
+async fn product_sweep_links_stores_and_rolls_up_distribution(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "sku-brand", true).await;
+    let mut product = make_normalized_product("sku-prod-1");
+    product.name = "Lemon Seltzer".to_string();
+    let product_id = upsert_product(&pool, brand_id, &product)
+        .await
+        .expect("upsert product failed");
+    let mut variant = make_normalized_variant("sku-var-1");
+    variant.sku = Some("0850000000012".to_string());
+    upsert_variant(&pool, product_id, &variant)
+        .await
+        .expect("upsert variant failed");
+
+    let candidates = list_product_match_candidates(&pool, brand_id)
+        .await
+        .expect("match candidates failed");
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].skus, ["0850000000012"]);
+
+    // One store also lists the product in its own payload.
+    let mut sc = make_test_location("sku-1", "SC Store", Some("SC"), Some("destini"));
+    sc.carried_products = vec![NewCarriedProduct {
+        product_ref: "payload-only".to_string(),
+        product_name: Some("Berry Seltzer".to_string()),
+        upc: None,
+    }];
+    let locations = vec![
+        sc,
+        make_test_location("sku-2", "NC Store", Some("NC"), Some("destini")),
+        make_test_location("sku-3", "Other NC Store", Some("NC"), Some("destini")),
+    ];
+    upsert_store_locations(&pool, brand_id, &locations)
+        .await
+        .expect("upsert locations failed");
+
+    let keys: Vec<String> = ["test-loc-sku-1", "test-loc-sku-2", "test-loc-unknown"]
+        .iter()
+        .map(|k| (*k).to_string())
+        .collect();
+    let mut sweep = ProductSweepInput {
+        product_id,
+        product_ref: "0850000000012",
+        product_name: Some("Lemon Seltzer 12oz"),
+        upc: Some("0850000000012"),
+        location_keys: &keys,
+    };
+    let outcome = record_product_sweep(&pool, brand_id, &sweep)
+        .await
+        .expect("record sweep failed");
+    assert_eq!(outcome.linked, 2);
+    assert_eq!(outcome.unknown_stores, 1);
+    assert_eq!(outcome.removed, 0);
+
+    // A re-scrape of the payload does not prune sweep rows.
+    upsert_store_locations(&pool, brand_id, &locations[..1])
+        .await
+        .expect("re-upsert failed");
+
+    let per_sku = list_sku_distribution(
+        &pool,
+        &SkuDistributionFilters {
+            brand_slug: Some("sku-brand"),
+            ..SkuDistributionFilters::default()
+        },
+        false,
+        10,
+    )
+    .await
+    .expect("sku distribution failed");
+    assert_eq!(per_sku.len(), 2);
+    assert_eq!(per_sku[0].product_id, Some(product_id));
+    assert_eq!(per_sku[0].product_name, "Lemon Seltzer");
+    assert_eq!(per_sku[0].store_count, 2);
+    assert_eq!(per_sku[0].state_count, 2);
+    assert_eq!(per_sku[1].sku_key, "payload-only");
+
+    let per_state = list_sku_distribution(
+        &pool,
+        &SkuDistributionFilters {
+            product_id: Some(product_id),
+            ..SkuDistributionFilters::default()
+        },
+        true,
+        10,
+    )
+    .await
+    .expect("state distribution failed");
+    let states: Vec<_> = per_state
+        .iter()
+        .filter_map(|r| r.state.as_deref())
+        .collect();
+    assert_eq!(states.len(), 2);
+    assert!(states.contains(&"NC") && states.contains(&"SC"));
+
+    // A later sweep that no longer returns the NC store removes its link.
+    sweep.location_keys = &keys[..1];
+    let outcome = record_product_sweep(&pool, brand_id, &sweep)
+        .await
+        .expect("second sweep failed");
+    assert_eq!(outcome.removed, 1);
+
+    // A row the locator recorded first is taken over by a sweep that
+    // confirms it, and survives a later payload that no longer lists it.
+    let mut other = locations[2].clone();
+    other.carried_products = vec![NewCarriedProduct {
+        product_ref: "0850000000012".to_string(),
+        product_name: None,
+        upc: None,
+    }];
+    upsert_store_locations(&pool, brand_id, std::slice::from_ref(&other))
+        .await
+        .expect("payload upsert failed");
+    let swept_keys = vec!["test-loc-sku-1".to_string(), "test-loc-sku-3".to_string()];
+    sweep.location_keys = &swept_keys;
+    record_product_sweep(&pool, brand_id, &sweep)
+        .await
+        .expect("third sweep failed");
+    other.carried_products.clear();
+    upsert_store_locations(&pool, brand_id, std::slice::from_ref(&other))
+        .await
+        .expect("payload re-upsert failed");
+    let source: String = sqlx::query_scalar(
+        "SELECT p.source FROM store_location_products p \
+         JOIN store_locations sl ON sl.id = p.store_location_id \
+         WHERE sl.location_key = 'test-loc-sku-3' AND p.product_ref = '0850000000012'",
+    )
+    .fetch_one(&pool)
+    .await
+    .expect("swept row should survive the payload re-scrape");
+    assert_eq!(source, "product_sweep");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn rekey_merges_locations_that_share_a_new_key(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "rekey-brand", true).await;
+    let mut legacy = make_test_location("legacy", "Corner Store", Some("South Carolina"), None);
+    legacy.carried_products = vec![NewCarriedProduct {
+        product_ref: "old-sku".to_string(),
+        product_name: None,
+        upc: None,
+    }];
+    let locations = vec![
+        legacy,
+        make_test_location("fresh", "Corner Store", Some("SC"), None),
+        make_test_location("other", "Other Store", Some("North Carolina"), None),
+    ];
+    upsert_store_locations(&pool, brand_id, &locations)
+        .await
+        .expect("upsert failed");
+    sqlx::query(
+        "UPDATE store_locations \
+         SET first_seen_at = NOW() - INTERVAL '90 days', last_seen_at = NOW() - INTERVAL '7 days' \
+         WHERE location_key IN ('test-loc-legacy', 'test-loc-other')",
+    )
+    .execute(&pool)
+    .await
+    .expect("age rows");
+    sqlx::query(
+        "INSERT INTO store_location_events (brand_id, store_location_id, event_type, state) \
+         SELECT brand_id, id, 'added', 'SC' FROM store_locations \
+         WHERE location_key = 'test-loc-legacy'",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert event");
+
+    let rows = list_locations_for_rekey(&pool, Some(brand_id))
+        .await
+        .expect("list failed");
+    assert_eq!(rows.len(), 3);
+    let rekeys: Vec<LocationRekey> = rows
+        .iter()
+        .map(|row| LocationRekey {
+            id: row.id,
+            location_key: if row.name == "Corner Store" {
+                "corner".to_string()
+            } else {
+                "other".to_string()
+            },
+            state: Some(
+                if row.name == "Corner Store" {
+                    "SC"
+                } else {
+                    "NC"
+                }
+                .to_string(),
+            ),
+            zip: row.zip.clone(),
+            country: "US".to_string(),
+        })
+        .collect();
+    let summary = rekey_store_locations(&pool, &rekeys)
+        .await
+        .expect("rekey failed");
+    assert_eq!(summary.merged, 1);
+    assert_eq!(summary.rekeyed, 2);
+
+    let kept: (String, String, bool) = sqlx::query_as(
+        "SELECT location_key, state, first_seen_at < NOW() - INTERVAL '60 days' \
+         FROM store_locations WHERE brand_id = $1 AND name = 'Corner Store'",
+    )
+    .bind(brand_id)
+    .fetch_one(&pool)
+    .await
+    .expect("one corner store remains");
+    assert_eq!(kept, ("corner".to_string(), "SC".to_string(), true));
+
+    let (events, products): (i64, i64) = sqlx::query_as(
+        "SELECT \
+             (SELECT COUNT(*) FROM store_location_events e \
+              JOIN store_locations sl ON sl.id = e.store_location_id \
+              WHERE sl.location_key = 'corner'), \
+             (SELECT COUNT(*) FROM store_location_products p \
+              JOIN store_locations sl ON sl.id = p.store_location_id \
+              WHERE sl.location_key = 'corner' AND p.product_ref = 'old-sku')",
+    )
+    .fetch_one(&pool)
+    .await
+    .expect("count failed");
+    assert_eq!((events, products), (1, 1), "history moves to the kept row");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn location_misses_deactivate_only_after_source_threshold(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "miss-brand", true).await;
+    let first = vec![
+        make_test_location("miss-1", "Steady Store", Some("SC"), Some("stockist")),
+        make_test_location("miss-2", "Flaky Store", Some("SC"), Some("stockist")),
+        make_test_location("miss-3", "Radius Store", Some("SC"), Some("destini")),
+    ];
+    upsert_store_locations(&pool, brand_id, &first)
+        .await
+        .expect("initial upsert failed");
+
+    let policy = LocationMissPolicy {
+        source_thresholds: std::collections::BTreeMap::from([("destini".to_string(), 3)]),
+        ..LocationMissPolicy::default()
+    };
+    let steady = vec![first[0].clone()];
+    let active_keys = vec![steady[0].location_key.clone()];
+    let scrape = |pool: sqlx::PgPool, locations: Vec<NewStoreLocation>| {
+        let active_keys = active_keys.clone();
+        let policy = policy.clone();
+        async move {
+            upsert_store_locations(&pool, brand_id, &locations)
+                .await
+                .expect("upsert failed");
+            record_location_misses(&pool, brand_id, &active_keys, &[], &policy)
+                .await
+                .expect("record misses failed")
+        }
+    };
+
+    // First miss: nothing closes yet.
+    let outcome = scrape(pool.clone(), steady.clone()).await;
+    assert_eq!(outcome.pending, 2);
+    assert!(outcome.deactivated_keys.is_empty());
+
+    // Second miss closes the stockist store; the destini store needs three.
+    let outcome = scrape(pool.clone(), steady.clone()).await;
+    assert_eq!(
+        outcome.deactivated_keys,
+        vec!["test-loc-miss-2".to_string()]
+    );
+    assert_eq!(outcome.pending, 1);
+
+    // Seen again: the counter resets, so the next miss starts over.
+    upsert_store_locations(&pool, brand_id, &[first[2].clone()])
+        .await
+        .expect("re-seen upsert failed");
+    let outcome = scrape(pool.clone(), steady.clone()).await;
+    assert!(outcome.deactivated_keys.is_empty());
+    assert_eq!(outcome.pending, 1);
+
+    let active = get_active_location_keys_for_brand(&pool, brand_id)
+        .await
+        .expect("active keys failed");
+    assert_eq!(active.len(), 2, "steady and radius stores remain active");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn location_misses_close_long_unseen_and_superseded_rows(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "miss-age-brand", true).await;
+    let mut old_name = make_test_location("age-2", "Old Name", Some("SC"), None);
+    old_name.external_id = Some("ext-age".to_string());
+    let first = vec![
+        make_test_location("age-1", "Stale Store", Some("SC"), None),
+        old_name,
+    ];
+    upsert_store_locations(&pool, brand_id, &first)
+        .await
+        .expect("initial upsert failed");
+    sqlx::query(
+        "UPDATE store_locations SET last_seen_at = NOW() - INTERVAL '30 days' \
+         WHERE location_key = 'test-loc-age-1'",
+    )
+    .execute(&pool)
+    .await
+    .expect("backdate failed");
+
+    let prev = list_location_snapshots_for_brand(&pool, brand_id)
+        .await
+        .expect("snapshot failed");
+    let mut renamed = make_test_location("age-3", "New Name", Some("SC"), None);
+    renamed.external_id = Some("ext-age".to_string());
+
+    let second = vec![renamed];
+    upsert_store_locations(&pool, brand_id, &second)
+        .await
+        .expect("second upsert failed");
+    let mut events = diff_location_snapshots(&prev, &second);
+    let active_keys = vec![second[0].location_key.clone()];
+    let outcome = record_location_misses(
+        &pool,
+        brand_id,
+        &active_keys,
+        &events,
+        &LocationMissPolicy::default(),
+    )
+    .await
+    .expect("record misses failed");
+
+    let mut closed = outcome.deactivated_keys.clone();
+    closed.sort();
+    assert_eq!(closed, vec!["test-loc-age-1", "test-loc-age-2"]);
+    outcome.retain_confirmed_removals(&mut events);
+    let types: Vec<LocationEventType> = events.iter().map(|e| e.event_type).collect();
+    assert!(types.contains(&LocationEventType::Removed));
+    assert!(
+        types.contains(&LocationEventType::Renamed) || types.contains(&LocationEventType::Moved)
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn location_net_change_rolls_up_by_week_and_flags_new_territory(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "net-change-brand", true).await;
     upsert_store_locations(
         &pool,
         brand_id,
-        &[NewStoreLocation {
-            location_key: "pin-loc-null".to_string(),
-            name: "No Coords Store".to_string(),
-            address_line1: None,
-            city: None,
-            state: None,
-            zip: None,
-            country: Some("US".to_string()),
-            latitude: None,
-            longitude: None,
-            phone: None,
-            external_id: None,
-            locator_source: None,
-            raw_data: serde_json::json!({}),
-        }],
+        &[
+            make_test_location("nc-1", "SC One", Some("SC"), None),
+            make_test_location("nc-2", "SC Two", Some("SC"), None),
+            make_test_location("nc-3", "GA One", Some("GA"), None),
+        ],
+    )
+    .await
+    .expect("upsert failed");
+
+    // GA has been covered for months; SC is new this week.
+    sqlx::query(
+        "UPDATE store_locations SET first_seen_at = NOW() - INTERVAL '90 days' \
+         WHERE brand_id = $1 AND state = 'GA'",
+    )
+    .bind(brand_id)
+    .execute(&pool)
+    .await
+    .expect("backdate failed");
+
+    let events = vec![
+        NewLocationEvent {
+            location_key: "test-loc-nc-1".to_string(),
+            event_type: LocationEventType::Added,
+            state: Some("SC".to_string()),
+            details: serde_json::json!({}),
+        },
+        NewLocationEvent {
+            location_key: "test-loc-nc-2".to_string(),
+            event_type: LocationEventType::Added,
+            state: Some("SC".to_string()),
+            details: serde_json::json!({}),
+        },
+        NewLocationEvent {
+            location_key: "test-loc-nc-3".to_string(),
+            event_type: LocationEventType::Removed,
+            state: Some("GA".to_string()),
+            details: serde_json::json!({}),
+        },
+    ];
+    insert_store_location_events(&pool, brand_id, None, &events)
+        .await
+        .expect("insert events failed");
+
+    let rows = list_location_net_change_weekly(&pool, Some("net-change-brand"), None, None, 4)
+        .await
+        .expect("net change query failed");
+    assert_eq!(rows.len(), 2);
+
+    let sc = rows.iter().find(|r| r.state == "SC").expect("SC row");
+    assert_eq!(sc.added, 2);
+    assert_eq!(sc.net_change, 2);
+    assert!(sc.new_territory);
+
+    let ga = rows.iter().find(|r| r.state == "GA").expect("GA row");
+    assert_eq!(ga.removed, 1);
+    assert_eq!(ga.net_change, -1);
+    assert!(!ga.new_territory);
+}
+
+// ---------------------------------------------------------------------------
+// Section 9: Brand Profiles
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_profile_round_trip(pool: sqlx::PgPool) {
+    let brand_id: i64 = sqlx::query_scalar(
+        "INSERT INTO brands (name, slug, relationship, tier, is_active) \
+         VALUES ('TestCo', 'testco', 'competitor', 1, true) RETURNING id",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    upsert_brand_profile(
+        &pool,
+        brand_id,
+        Some("Test tagline"),
+        Some("Test desc"),
+        Some(2021_i16),
+        Some("Portland"),
+        Some("OR"),
+        Some("BigCorp"),
+    )
+    .await
+    .unwrap();
+
+    let profile = scbdb_db::get_brand_profile(&pool, brand_id)
+        .await
+        .unwrap()
+        .expect("profile exists");
+
+    assert_eq!(profile.tagline.as_deref(), Some("Test tagline"));
+    assert_eq!(profile.founded_year, Some(2021_i16));
+    assert_eq!(profile.hq_state.as_deref(), Some("OR"));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn list_brands_without_profiles_returns_unprofiled(pool: sqlx::PgPool) {
+    sqlx::query(
+        "INSERT INTO brands (name, slug, relationship, tier, is_active) \
+         VALUES ('NoBrand', 'nobrand', 'competitor', 2, true)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let ids = list_brands_without_profiles(&pool).await.unwrap();
+    assert!(!ids.is_empty(), "should find brand without profile");
+}
+
+// ---------------------------------------------------------------------------
+// Section 10: Brand Signals
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_signal_upsert_and_list(pool: sqlx::PgPool) {
+    let brand_id: i64 = sqlx::query_scalar(
+        "INSERT INTO brands (name, slug, relationship, tier, is_active) \
+         VALUES ('SigBrand', 'sigbrand', 'competitor', 1, true) RETURNING id",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    let new = scbdb_db::NewBrandSignal {
+        brand_id,
+        signal_type: "article",
+        source_platform: Some("web"),
+        source_url: Some("https://example.com/article"),
+        external_id: Some("art-001"),
+        title: Some("Big News"),
+        summary: None,
+        content: None,
+        image_url: None,
+        qdrant_point_id: None,
+        published_at: None,
+    };
+    scbdb_db::upsert_brand_signal(&pool, &new).await.unwrap();
+
+    let signals = scbdb_db::list_brand_signals(&pool, brand_id, None, 10, None)
+        .await
+        .unwrap();
+    assert_eq!(signals.len(), 1);
+    assert_eq!(signals[0].title.as_deref(), Some("Big News"));
+}
+
+// ---------------------------------------------------------------------------
+// Section 11: Brand Funding Events
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_funding_event_round_trip(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "funding-brand", true).await;
+
+    let investors = vec!["Sequoia".to_string(), "a16z".to_string()];
+    let event = NewBrandFundingEvent {
+        brand_id,
+        event_type: "series_a",
+        amount_usd: Some(500_000_000), // $5,000,000 in cents
+        announced_at: NaiveDate::from_ymd_opt(2025, 6, 15),
+        investors: Some(&investors),
+        acquirer: None,
+        source_url: Some("https://example.com/funding"),
+        notes: Some("Series A round"),
+    };
+
+    let id = insert_brand_funding_event(&pool, &event)
+        .await
+        .expect("insert_brand_funding_event failed");
+    assert!(id > 0, "returned id should be positive");
+
+    let rows = list_brand_funding_events(&pool, brand_id)
+        .await
+        .expect("list_brand_funding_events failed");
+
+    assert_eq!(rows.len(), 1, "should return exactly one funding event");
+    let row = &rows[0];
+    assert_eq!(row.id, id);
+    assert_eq!(row.brand_id, brand_id);
+    assert_eq!(row.event_type, "series_a");
+    assert_eq!(row.amount_usd, Some(500_000_000));
+    assert_eq!(row.announced_at, NaiveDate::from_ymd_opt(2025, 6, 15));
+    assert_eq!(
+        row.investors.as_deref(),
+        Some(&["Sequoia".to_string(), "a16z".to_string()][..])
+    );
+    assert!(row.acquirer.is_none());
+    assert_eq!(
+        row.source_url.as_deref(),
+        Some("https://example.com/funding")
+    );
+    assert_eq!(row.notes.as_deref(), Some("Series A round"));
+}
+
+// ---------------------------------------------------------------------------
+// Section 12: Brand Lab Tests
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_lab_test_round_trip(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "lab-brand", true).await;
+
+    let raw = serde_json::json!({"source": "third_party_lab"});
+    let test_input = NewBrandLabTest {
+        brand_id,
+        product_id: None,
+        variant_id: None,
+        lab_name: Some("SC Labs"),
+        test_date: NaiveDate::from_ymd_opt(2025, 4, 10),
+        report_url: Some("https://example.com/coa.pdf"),
+        thc_mg_actual: Some(Decimal::new(5_250, 3)),
+        cbd_mg_actual: Some(Decimal::new(125, 3)),
+        total_cannabinoids_mg: Some(Decimal::new(5_375, 3)),
+        passed: Some(true),
+        raw_data: Some(&raw),
+    };
+
+    let id = insert_brand_lab_test(&pool, &test_input)
+        .await
+        .expect("insert_brand_lab_test failed");
+    assert!(id > 0);
+
+    let rows = list_brand_lab_tests(&pool, brand_id)
+        .await
+        .expect("list_brand_lab_tests failed");
+
+    assert_eq!(rows.len(), 1, "should return exactly one lab test");
+    let row = &rows[0];
+    assert_eq!(row.id, id);
+    assert_eq!(row.brand_id, brand_id);
+    assert_eq!(row.lab_name.as_deref(), Some("SC Labs"));
+    assert_eq!(row.test_date, NaiveDate::from_ymd_opt(2025, 4, 10));
+    assert_eq!(
+        row.report_url.as_deref(),
+        Some("https://example.com/coa.pdf")
+    );
+    assert_eq!(row.thc_mg_actual, Some(Decimal::new(5_250, 3)));
+    assert_eq!(row.cbd_mg_actual, Some(Decimal::new(125, 3)));
+    assert_eq!(row.total_cannabinoids_mg, Some(Decimal::new(5_375, 3)));
+    assert_eq!(row.passed, Some(true));
+    assert!(row.raw_data.is_some());
+}
+
+// ---------------------------------------------------------------------------
+// Section 13: Brand Legal Proceedings
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_legal_proceeding_round_trip(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "legal-brand", true).await;
+
+    let proceeding = NewBrandLegalProceeding {
+        brand_id,
+        proceeding_type: "lawsuit",
+        jurisdiction: Some("SC"),
+        case_number: Some("2025-CV-001"),
+        title: "State v. HempCo",
+        summary: Some("Challenge to hemp beverage ban"),
+        status: "active",
+        filed_at: NaiveDate::from_ymd_opt(2025, 3, 1),
+        resolved_at: None,
+        source_url: Some("https://example.com/case"),
+    };
+
+    let id = insert_brand_legal_proceeding(&pool, &proceeding)
+        .await
+        .expect("insert_brand_legal_proceeding failed");
+    assert!(id > 0);
+
+    let rows = list_brand_legal_proceedings(&pool, brand_id)
+        .await
+        .expect("list_brand_legal_proceedings failed");
+
+    assert_eq!(rows.len(), 1, "should return exactly one legal proceeding");
+    let row = &rows[0];
+    assert_eq!(row.id, id);
+    assert_eq!(row.brand_id, brand_id);
+    assert_eq!(row.proceeding_type, "lawsuit");
+    assert_eq!(row.jurisdiction.as_deref(), Some("SC"));
+    assert_eq!(row.case_number.as_deref(), Some("2025-CV-001"));
+    assert_eq!(row.title, "State v. HempCo");
+    assert_eq!(
+        row.summary.as_deref(),
+        Some("Challenge to hemp beverage ban")
+    );
+    assert_eq!(row.status, "active");
+    assert_eq!(row.filed_at, NaiveDate::from_ymd_opt(2025, 3, 1));
+    assert!(row.resolved_at.is_none());
+    assert_eq!(row.source_url.as_deref(), Some("https://example.com/case"));
+}
+
+// ---------------------------------------------------------------------------
+// Section 14: Brand Sponsorships
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_sponsorship_round_trip(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "sponsor-brand", true).await;
+
+    let sponsorship = NewBrandSponsorship {
+        brand_id,
+        entity_name: "Coastal Music Festival",
+        entity_type: "event",
+        deal_type: "title_sponsor",
+        announced_at: NaiveDate::from_ymd_opt(2025, 5, 1),
+        ends_at: NaiveDate::from_ymd_opt(2025, 9, 30),
+        source_url: Some("https://example.com/sponsor"),
+        notes: Some("Title sponsorship for summer series"),
+        is_active: true,
+    };
+
+    let id = insert_brand_sponsorship(&pool, &sponsorship)
+        .await
+        .expect("insert_brand_sponsorship failed");
+    assert!(id > 0);
+
+    let rows = list_brand_sponsorships(&pool, brand_id)
+        .await
+        .expect("list_brand_sponsorships failed");
+
+    assert_eq!(rows.len(), 1, "should return exactly one sponsorship");
+    let row = &rows[0];
+    assert_eq!(row.id, id);
+    assert_eq!(row.brand_id, brand_id);
+    assert_eq!(row.entity_name, "Coastal Music Festival");
+    assert_eq!(row.entity_type, "event");
+    assert_eq!(row.deal_type, "title_sponsor");
+    assert_eq!(row.announced_at, NaiveDate::from_ymd_opt(2025, 5, 1));
+    assert_eq!(row.ends_at, NaiveDate::from_ymd_opt(2025, 9, 30));
+    assert_eq!(
+        row.source_url.as_deref(),
+        Some("https://example.com/sponsor")
+    );
+    assert_eq!(
+        row.notes.as_deref(),
+        Some("Title sponsorship for summer series")
+    );
+    assert!(row.is_active);
+}
+
+// ---------------------------------------------------------------------------
+// Section 15: Brand Distributors
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_distributor_round_trip(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "dist-brand", true).await;
+
+    let states = vec!["CA".to_string(), "OR".to_string(), "WA".to_string()];
+    let distributor = NewBrandDistributor {
+        brand_id,
+        distributor_name: "Pacific Distribution Co",
+        distributor_slug: "pacific-distribution-co",
+        states: Some(&states),
+        territory_type: "regional",
+        channel_type: "retail",
+        started_at: NaiveDate::from_ymd_opt(2025, 1, 1),
+        ended_at: None,
+        is_active: true,
+        notes: Some("West coast coverage"),
+    };
+
+    let id = insert_brand_distributor(&pool, &distributor)
+        .await
+        .expect("insert_brand_distributor failed");
+    assert!(id > 0, "returned id should be positive");
+
+    let rows = list_brand_distributors(&pool, brand_id)
+        .await
+        .expect("list_brand_distributors failed");
+
+    assert_eq!(rows.len(), 1, "should return exactly one distributor");
+    let row = &rows[0];
+    assert_eq!(row.id, id);
+    assert_eq!(row.brand_id, brand_id);
+    assert_eq!(row.distributor_name, "Pacific Distribution Co");
+    assert_eq!(row.distributor_slug, "pacific-distribution-co");
+    assert_eq!(
+        row.states.as_deref(),
+        Some(&["CA".to_string(), "OR".to_string(), "WA".to_string()][..])
+    );
+    assert_eq!(row.territory_type, "regional");
+    assert_eq!(row.channel_type, "retail");
+    assert_eq!(row.started_at, NaiveDate::from_ymd_opt(2025, 1, 1));
+    assert!(row.ended_at.is_none());
+    assert!(row.is_active);
+    assert_eq!(row.notes.as_deref(), Some("West coast coverage"));
+}
+
+// ---------------------------------------------------------------------------
+// Section 16: Brand Competitor Relationships
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_competitor_relationship_round_trip(pool: sqlx::PgPool) {
+    let brand_a = insert_test_brand(&pool, "comp-brand-a", true).await;
+    let brand_b = insert_test_brand(&pool, "comp-brand-b", true).await;
+
+    let states = vec!["SC".to_string(), "NC".to_string()];
+    let rel = NewBrandCompetitorRelationship {
+        brand_id: brand_a,
+        competitor_brand_id: brand_b,
+        relationship_type: "direct_competitor",
+        distributor_name: Some("Southern Dist"),
+        states: Some(&states),
+        notes: Some("Both sell in Carolinas"),
+        is_active: true,
+    };
+
+    let id = insert_brand_competitor_relationship(&pool, &rel)
+        .await
+        .expect("insert_brand_competitor_relationship failed");
+    assert!(id > 0, "returned id should be positive");
+
+    // Query from brand_a side
+    let rows_a = list_brand_competitor_relationships(&pool, brand_a)
+        .await
+        .expect("list from brand_a failed");
+    assert_eq!(rows_a.len(), 1, "brand_a should see 1 relationship");
+
+    // Query from brand_b side — same row should appear
+    let rows_b = list_brand_competitor_relationships(&pool, brand_b)
+        .await
+        .expect("list from brand_b failed");
+    assert_eq!(rows_b.len(), 1, "brand_b should see 1 relationship");
+    assert_eq!(rows_a[0].id, rows_b[0].id, "same row from both sides");
+
+    let row = &rows_a[0];
+    assert!(
+        row.brand_id < row.competitor_brand_id,
+        "stored row must have brand_id < competitor_brand_id (canonical order)"
+    );
+    assert_eq!(row.relationship_type, "direct_competitor");
+    assert_eq!(row.distributor_name.as_deref(), Some("Southern Dist"));
+    assert_eq!(
+        row.states.as_deref(),
+        Some(&["SC".to_string(), "NC".to_string()][..])
+    );
+    assert_eq!(row.notes.as_deref(), Some("Both sell in Carolinas"));
+    assert!(row.is_active);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_competitor_relationship_canonical_ordering(pool: sqlx::PgPool) {
+    // Insert two brands; brand_a will have a lower id than brand_b.
+    let brand_a = insert_test_brand(&pool, "canon-lo", true).await;
+    let brand_b = insert_test_brand(&pool, "canon-hi", true).await;
+    assert!(
+        brand_a < brand_b,
+        "test setup: brand_a should have lower id"
+    );
+
+    // Pass ids in REVERSE order (higher first) to verify canonicalization.
+    let rel = NewBrandCompetitorRelationship {
+        brand_id: brand_b,            // higher id
+        competitor_brand_id: brand_a, // lower id
+        relationship_type: "indirect_competitor",
+        distributor_name: None,
+        states: None,
+        notes: None,
+        is_active: true,
+    };
+
+    let id = insert_brand_competitor_relationship(&pool, &rel)
+        .await
+        .expect("insert with reversed ids failed");
+
+    // Verify the stored row has canonical ordering: brand_id < competitor_brand_id.
+    let (stored_brand_id, stored_competitor_id): (i64, i64) = sqlx::query_as(
+        "SELECT brand_id, competitor_brand_id \
+         FROM brand_competitor_relationships WHERE id = $1",
+    )
+    .bind(id)
+    .fetch_one(&pool)
+    .await
+    .expect("fetch stored row failed");
+
+    assert_eq!(stored_brand_id, brand_a, "brand_id should be the lower id");
+    assert_eq!(
+        stored_competitor_id, brand_b,
+        "competitor_brand_id should be the higher id"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Section 17: Brand Newsletters
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_newsletter_round_trip(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "news-brand", true).await;
+
+    let newsletter = NewBrandNewsletter {
+        brand_id,
+        list_name: "Monthly Buzz",
+        subscribe_url: Some("https://example.com/subscribe"),
+        unsubscribe_url: Some("https://example.com/unsubscribe"),
+        inbox_address: Some("buzz@inbox.example.com"),
+        subscribed_at: None,
+        last_received_at: None,
+        is_active: true,
+        notes: Some("Main marketing newsletter"),
+    };
+
+    let id = insert_brand_newsletter(&pool, &newsletter)
+        .await
+        .expect("insert_brand_newsletter failed");
+    assert!(id > 0, "returned id should be positive");
+
+    let rows = list_brand_newsletters(&pool, brand_id)
+        .await
+        .expect("list_brand_newsletters failed");
+
+    assert_eq!(rows.len(), 1, "should return exactly one newsletter");
+    let row = &rows[0];
+    assert_eq!(row.id, id);
+    assert_eq!(row.brand_id, brand_id);
+    assert_eq!(row.list_name, "Monthly Buzz");
+    assert_eq!(
+        row.subscribe_url.as_deref(),
+        Some("https://example.com/subscribe")
+    );
+    assert_eq!(
+        row.unsubscribe_url.as_deref(),
+        Some("https://example.com/unsubscribe")
+    );
+    assert_eq!(row.inbox_address.as_deref(), Some("buzz@inbox.example.com"));
+    assert!(row.subscribed_at.is_none());
+    assert!(row.last_received_at.is_none());
+    assert!(row.is_active);
+    assert_eq!(row.notes.as_deref(), Some("Main marketing newsletter"));
+}
+
+// ---------------------------------------------------------------------------
+// Section 18: Brand Media Appearances
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_media_appearance_round_trip(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "media-brand", true).await;
+
+    let appearance = NewBrandMediaAppearance {
+        brand_id,
+        brand_signal_id: None,
+        appearance_type: "podcast",
+        outlet_name: "Hemp Industry Daily",
+        title: Some("The Future of THC Beverages"),
+        host_or_author: Some("Jane Doe"),
+        aired_at: NaiveDate::from_ymd_opt(2025, 7, 20),
+        duration_seconds: Some(3600),
+        source_url: Some("https://example.com/podcast/ep42"),
+        notes: Some("CEO interview"),
+    };
+
+    let id = insert_brand_media_appearance(&pool, &appearance)
+        .await
+        .expect("insert_brand_media_appearance failed");
+    assert!(id > 0, "returned id should be positive");
+
+    let rows = list_brand_media_appearances(&pool, brand_id)
+        .await
+        .expect("list_brand_media_appearances failed");
+
+    assert_eq!(rows.len(), 1, "should return exactly one media appearance");
+    let row = &rows[0];
+    assert_eq!(row.id, id);
+    assert_eq!(row.brand_id, brand_id);
+    assert!(row.brand_signal_id.is_none());
+    assert_eq!(row.appearance_type, "podcast");
+    assert_eq!(row.outlet_name, "Hemp Industry Daily");
+    assert_eq!(row.title.as_deref(), Some("The Future of THC Beverages"));
+    assert_eq!(row.host_or_author.as_deref(), Some("Jane Doe"));
+    assert_eq!(row.aired_at, NaiveDate::from_ymd_opt(2025, 7, 20));
+    assert_eq!(row.duration_seconds, Some(3600));
+    assert_eq!(
+        row.source_url.as_deref(),
+        Some("https://example.com/podcast/ep42")
+    );
+    assert_eq!(row.notes.as_deref(), Some("CEO interview"));
+}
+
+// ---------------------------------------------------------------------------
+// Section 19: Brand Completeness Score
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_completeness_score_nonexistent_brand(pool: sqlx::PgPool) {
+    let result = get_brand_completeness(&pool, 999_999)
+        .await
+        .expect("query should not fail");
+
+    assert!(
+        result.is_none(),
+        "expected None for nonexistent brand, got Some"
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_completeness_score_empty_brand(pool: sqlx::PgPool) {
+    // Brand with no profile, no signals, no anything.
+    let brand_id = insert_test_brand(&pool, "completeness-empty", true).await;
+
+    let result = get_brand_completeness(&pool, brand_id)
+        .await
+        .expect("query should not fail")
+        .expect("brand exists, expected Some");
+
+    assert_eq!(result.brand_id, brand_id);
+    assert_eq!(result.score, 0, "empty brand should have score 0");
+    assert!(!result.has_profile);
+    assert!(!result.has_description);
+    assert!(!result.has_tagline);
+    assert!(!result.has_founded_year);
+    assert!(!result.has_location);
+    assert!(!result.has_social_handles);
+    assert!(!result.has_domains);
+    assert!(!result.has_signals);
+    assert!(!result.has_funding);
+    assert!(!result.has_lab_tests);
+    assert!(!result.has_legal);
+    assert!(!result.has_sponsorships);
+    assert!(!result.has_distributors);
+    assert!(!result.has_media);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_completeness_score_with_profile(pool: sqlx::PgPool) {
+    use scbdb_db::brand_completeness::{
+        W_DESCRIPTION, W_FOUNDED_YEAR, W_LOCATION, W_PROFILE, W_TAGLINE,
+    };
+
+    let brand_id = insert_test_brand(&pool, "completeness-profile", true).await;
+
+    // Upsert a profile with all core fields filled.
+    upsert_brand_profile(
+        &pool,
+        brand_id,
+        Some("Refreshingly different"),
+        Some("Premium hemp-derived THC seltzers"),
+        Some(2020_i16),
+        Some("Charleston"),
+        Some("SC"),
+        Some("ParentCo"),
+    )
+    .await
+    .expect("upsert_brand_profile failed");
+
+    let result = get_brand_completeness(&pool, brand_id)
+        .await
+        .expect("query should not fail")
+        .expect("brand exists, expected Some");
+
+    let expected_score = W_PROFILE + W_DESCRIPTION + W_TAGLINE + W_FOUNDED_YEAR + W_LOCATION;
+
+    assert_eq!(result.brand_id, brand_id);
+    assert_eq!(
+        result.score, expected_score,
+        "score should be {expected_score} (profile + desc + tagline + year + location), got {}",
+        result.score
+    );
+    assert!(result.has_profile);
+    assert!(result.has_description);
+    assert!(result.has_tagline);
+    assert!(result.has_founded_year);
+    assert!(result.has_location);
+    // These should still be false.
+    assert!(!result.has_social_handles);
+    assert!(!result.has_domains);
+    assert!(!result.has_signals);
+    assert!(!result.has_funding);
+    assert!(!result.has_lab_tests);
+    assert!(!result.has_legal);
+    assert!(!result.has_sponsorships);
+    assert!(!result.has_distributors);
+    assert!(!result.has_media);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_completeness_score_partial_profile(pool: sqlx::PgPool) {
+    use scbdb_db::brand_completeness::{W_DESCRIPTION, W_PROFILE};
+
+    let brand_id = insert_test_brand(&pool, "completeness-partial", true).await;
+
+    // Profile with only description filled (no tagline, no year, no location).
+    upsert_brand_profile(
+        &pool,
+        brand_id,
+        None,                                   // tagline
+        Some("A description but nothing else"), // description
+        None,                                   // founded_year
+        None,                                   // hq_city
+        None,                                   // hq_state
+        None,                                   // parent_company
+    )
+    .await
+    .expect("upsert_brand_profile failed");
+
+    let result = get_brand_completeness(&pool, brand_id)
+        .await
+        .expect("query should not fail")
+        .expect("brand exists, expected Some");
+
+    let expected_score = W_PROFILE + W_DESCRIPTION;
+
+    assert_eq!(
+        result.score, expected_score,
+        "score should be {expected_score} (profile + description only), got {}",
+        result.score
+    );
+    assert!(result.has_profile);
+    assert!(result.has_description);
+    assert!(!result.has_tagline);
+    assert!(!result.has_founded_year);
+    assert!(!result.has_location);
+}
+
+// ---------------------------------------------------------------------------
+// Section: Location Pins
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn list_active_location_pins_empty_when_no_locations(pool: sqlx::PgPool) {
+    let pins = list_active_location_pins(&pool, 1000, None, None, None)
+        .await
+        .expect("query failed");
+    assert!(pins.is_empty());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn list_active_location_pins_returns_rows_with_coords(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "pin-brand-coords", true).await;
+    upsert_store_locations(
+        &pool,
+        brand_id,
+        &[NewStoreLocation {
+            location_key: "pin-loc-1".to_string(),
+            name: "Pin Store".to_string(),
+            address_line1: Some("123 Main St".to_string()),
+            city: Some("Austin".to_string()),
+            state: Some("TX".to_string()),
+            zip: Some("78701".to_string()),
+            country: Some("US".to_string()),
+            latitude: Some(30.2672),
+            longitude: Some(-97.7431),
+            phone: None,
+            external_id: None,
+            locator_source: Some("locally".to_string()),
+            raw_data: serde_json::json!({}),
+            retail_chain: None,
+            retail_channel: None,
+            hours: None,
+            website: None,
+            email: None,
+            store_type: None,
+            carried_products: Vec::new(),
+        }],
+    )
+    .await
+    .expect("upsert");
+    let pins = list_active_location_pins(&pool, 1000, None, None, None)
+        .await
+        .expect("query failed");
+    assert_eq!(pins.len(), 1);
+    assert!((pins[0].latitude - 30.2672).abs() < 0.001);
+    assert_eq!(pins[0].store_name, "Pin Store");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn list_active_location_pins_excludes_null_coords(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "pin-brand-null-coords", true).await;
+    upsert_store_locations(
+        &pool,
+        brand_id,
+        &[NewStoreLocation {
+            location_key: "pin-loc-null".to_string(),
+            name: "No Coords Store".to_string(),
+            address_line1: None,
+            city: None,
+            state: None,
+            zip: None,
+            country: Some("US".to_string()),
+            latitude: None,
+            longitude: None,
+            phone: None,
+            external_id: None,
+            locator_source: None,
+            raw_data: serde_json::json!({}),
+            retail_chain: None,
+            retail_channel: None,
+            hours: None,
+            website: None,
+            email: None,
+            store_type: None,
+            carried_products: Vec::new(),
+        }],
+    )
+    .await
+    .expect("upsert");
+    let pins = list_active_location_pins(&pool, 1000, None, None, None)
+        .await
+        .expect("query failed");
+    assert!(pins.is_empty(), "null coords should be excluded");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn list_active_location_pins_excludes_inactive_locations(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "pin-brand-inactive", true).await;
+    upsert_store_locations(
+        &pool,
+        brand_id,
+        &[NewStoreLocation {
+            location_key: "pin-loc-inactive".to_string(),
+            name: "Inactive Store".to_string(),
+            address_line1: None,
+            city: None,
+            state: None,
+            zip: None,
+            country: Some("US".to_string()),
+            latitude: Some(30.0),
+            longitude: Some(-97.0),
+            phone: None,
+            external_id: None,
+            locator_source: None,
+            raw_data: serde_json::json!({}),
+            retail_chain: None,
+            retail_channel: None,
+            hours: None,
+            website: None,
+            email: None,
+            store_type: None,
+            carried_products: Vec::new(),
+        }],
+    )
+    .await
+    .expect("upsert");
+    // Set is_active = false
+    sqlx::query(
+        "UPDATE store_locations SET is_active = false WHERE location_key = 'pin-loc-inactive'",
+    )
+    .execute(&pool)
+    .await
+    .expect("deactivate");
+    let pins = list_active_location_pins(&pool, 1000, None, None, None)
+        .await
+        .expect("query failed");
+    assert!(pins.is_empty(), "inactive locations should be excluded");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn list_active_location_pins_includes_brand_fields(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "pin-brand-fields", true).await;
+    upsert_store_locations(
+        &pool,
+        brand_id,
+        &[NewStoreLocation {
+            location_key: "pin-loc-fields".to_string(),
+            name: "Fields Store".to_string(),
+            address_line1: None,
+            city: Some("Denver".to_string()),
+            state: Some("CO".to_string()),
+            zip: None,
+            country: Some("US".to_string()),
+            latitude: Some(39.7392),
+            longitude: Some(-104.9903),
+            phone: None,
+            external_id: None,
+            locator_source: Some("storemapper".to_string()),
+            raw_data: serde_json::json!({}),
+            retail_chain: None,
+            retail_channel: None,
+            hours: None,
+            website: None,
+            email: None,
+            store_type: None,
+            carried_products: Vec::new(),
+        }],
+    )
+    .await
+    .expect("upsert");
+    let pins = list_active_location_pins(&pool, 1000, None, None, None)
+        .await
+        .expect("query failed");
+    assert_eq!(pins.len(), 1);
+    assert_eq!(pins[0].brand_slug, "pin-brand-fields");
+    assert_eq!(pins[0].brand_relationship, "portfolio");
+    assert_eq!(pins[0].brand_tier, 1);
+}
+
+// ---------------------------------------------------------------------------
+// Section: get_active_location_keys_for_brand
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn get_active_location_keys_returns_empty_for_unknown_brand(pool: sqlx::PgPool) {
+    let keys = get_active_location_keys_for_brand(&pool, 999_999)
+        .await
+        .expect("query should succeed even with no rows");
+    assert!(keys.is_empty());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn get_active_location_keys_returns_only_active_keys(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "active-keys-brand", true).await;
+
+    let locs = vec![
+        make_test_location("ak-1", "Active Store A", Some("SC"), None),
+        make_test_location("ak-2", "Active Store B", Some("NC"), None),
+    ];
+    upsert_store_locations(&pool, brand_id, &locs)
+        .await
+        .expect("upsert failed");
+
+    // Deactivate one location to verify the filter.
+    sqlx::query(
+        "UPDATE store_locations SET is_active = false WHERE location_key = 'test-loc-ak-2'",
+    )
+    .execute(&pool)
+    .await
+    .expect("deactivate failed");
+
+    let keys = get_active_location_keys_for_brand(&pool, brand_id)
+        .await
+        .expect("query failed");
+
+    assert_eq!(keys.len(), 1, "should return only the one active key");
+    assert!(keys.contains("test-loc-ak-1"), "active key must be present");
+    assert!(
+        !keys.contains("test-loc-ak-2"),
+        "deactivated key must not be present"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Section 13: Store Locations — Batch Upsert
+// ---------------------------------------------------------------------------
+
+/// Build a `NewStoreLocation` with full field coverage for upsert tests.
+fn make_full_location(
+    key: &str,
+    name: &str,
+    address: &str,
+    city: &str,
+    state: &str,
+    zip: &str,
+    lat: f64,
+    lng: f64,
+) -> NewStoreLocation {
+    NewStoreLocation {
+        location_key: key.to_string(),
+        name: name.to_string(),
+        address_line1: Some(address.to_string()),
+        city: Some(city.to_string()),
+        state: Some(state.to_string()),
+        zip: Some(zip.to_string()),
+        country: Some("US".to_string()),
+        latitude: Some(lat),
+        longitude: Some(lng),
+        phone: Some("555-0100".to_string()),
+        external_id: Some(format!("ext-{key}")),
+        locator_source: Some("locally".to_string()),
+        raw_data: serde_json::json!({"source": "test"}),
+        retail_chain: None,
+        retail_channel: None,
+        hours: None,
+        website: None,
+        email: None,
+        store_type: None,
+        carried_products: Vec::new(),
+    }
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_upsert_inserts_new_locations(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "upsert-insert", true).await;
+
+    let locs = vec![
+        make_full_location(
+            "ins-1",
+            "Store Alpha",
+            "100 Main St",
+            "Austin",
+            "TX",
+            "78701",
+            30.27,
+            -97.74,
+        ),
+        make_full_location(
+            "ins-2",
+            "Store Beta",
+            "200 Oak Ave",
+            "Dallas",
+            "TX",
+            "75201",
+            32.78,
+            -96.80,
+        ),
+        make_full_location(
+            "ins-3",
+            "Store Gamma",
+            "300 Elm Rd",
+            "Houston",
+            "TX",
+            "77001",
+            29.76,
+            -95.37,
+        ),
+    ];
+
+    let (new_count, updated_count) = upsert_store_locations(&pool, brand_id, &locs)
+        .await
+        .expect("upsert failed");
+
+    assert_eq!(new_count, 3, "all 3 locations should be new inserts");
+    assert_eq!(updated_count, 0, "no existing rows to update");
+
+    let rows = list_active_locations_by_brand(&pool, brand_id)
+        .await
+        .expect("list failed");
+
+    assert_eq!(rows.len(), 3, "should have 3 active locations");
+
+    let alpha = rows
+        .iter()
+        .find(|r| r.location_key == "ins-1")
+        .expect("ins-1 missing");
+    assert_eq!(alpha.name, "Store Alpha");
+    assert_eq!(alpha.address_line1.as_deref(), Some("100 Main St"));
+    assert_eq!(alpha.city.as_deref(), Some("Austin"));
+    assert_eq!(alpha.state.as_deref(), Some("TX"));
+    assert_eq!(alpha.zip.as_deref(), Some("78701"));
+    assert_eq!(alpha.country, "US");
+    assert!(alpha.is_active);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_upsert_updates_existing_locations(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "upsert-update", true).await;
+
+    // Insert initial locations.
+    let initial = vec![
+        make_full_location(
+            "upd-1",
+            "Store Original",
+            "100 Main St",
+            "Austin",
+            "TX",
+            "78701",
+            30.27,
+            -97.74,
+        ),
+        make_full_location(
+            "upd-2",
+            "Store Unchanged",
+            "200 Oak Ave",
+            "Dallas",
+            "TX",
+            "75201",
+            32.78,
+            -96.80,
+        ),
+    ];
+    upsert_store_locations(&pool, brand_id, &initial)
+        .await
+        .expect("initial upsert failed");
+
+    // Upsert with changed data for upd-1 (new address, new city), upd-2 unchanged.
+    let updated = vec![
+        make_full_location(
+            "upd-1",
+            "Store Renamed",
+            "999 New Blvd",
+            "San Antonio",
+            "TX",
+            "78201",
+            29.42,
+            -98.49,
+        ),
+        make_full_location(
+            "upd-2",
+            "Store Unchanged",
+            "200 Oak Ave",
+            "Dallas",
+            "TX",
+            "75201",
+            32.78,
+            -96.80,
+        ),
+    ];
+    let (new_count, updated_count) = upsert_store_locations(&pool, brand_id, &updated)
+        .await
+        .expect("update upsert failed");
+
+    assert_eq!(new_count, 0, "no new rows — both keys already exist");
+    assert_eq!(updated_count, 2, "both rows hit the ON CONFLICT path");
+
+    let rows = list_active_locations_by_brand(&pool, brand_id)
+        .await
+        .expect("list failed");
+
+    let renamed = rows
+        .iter()
+        .find(|r| r.location_key == "upd-1")
+        .expect("upd-1 missing");
+    assert_eq!(renamed.name, "Store Renamed", "name should be updated");
+    assert_eq!(
+        renamed.address_line1.as_deref(),
+        Some("999 New Blvd"),
+        "address should be updated"
+    );
+    assert_eq!(
+        renamed.city.as_deref(),
+        Some("San Antonio"),
+        "city should be updated"
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_upsert_handles_conflict(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "upsert-conflict", true).await;
+
+    let loc = make_full_location(
+        "conflict-1",
+        "Original Name",
+        "100 Main St",
+        "Austin",
+        "TX",
+        "78701",
+        30.27,
+        -97.74,
+    );
+    let (new1, upd1) = upsert_store_locations(&pool, brand_id, &[loc])
+        .await
+        .expect("first upsert failed");
+    assert_eq!(new1, 1);
+    assert_eq!(upd1, 0);
+
+    // Upsert same key with different data — should update, not duplicate.
+    let loc2 = make_full_location(
+        "conflict-1",
+        "Updated Name",
+        "200 New Ave",
+        "Dallas",
+        "TX",
+        "75201",
+        32.78,
+        -96.80,
+    );
+    let (new2, upd2) = upsert_store_locations(&pool, brand_id, &[loc2])
+        .await
+        .expect("second upsert failed");
+    assert_eq!(new2, 0, "same key should not produce a new row");
+    assert_eq!(upd2, 1, "should update the existing row");
+
+    let rows = list_active_locations_by_brand(&pool, brand_id)
+        .await
+        .expect("list failed");
+
+    assert_eq!(
+        rows.len(),
+        1,
+        "should have exactly 1 location — no duplicates"
+    );
+    assert_eq!(
+        rows[0].name, "Updated Name",
+        "data should reflect the latest upsert"
+    );
+    assert_eq!(rows[0].city.as_deref(), Some("Dallas"));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_upsert_empty_vec(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "upsert-empty", true).await;
+
+    let (new_count, updated_count) = upsert_store_locations(&pool, brand_id, &[])
+        .await
+        .expect("upsert with empty vec should not error");
+
+    assert_eq!(new_count, 0);
+    assert_eq!(updated_count, 0);
+
+    let rows = list_active_locations_by_brand(&pool, brand_id)
+        .await
+        .expect("list failed");
+    assert_eq!(rows.len(), 0, "no locations should exist");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_upsert_large_batch(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "upsert-large", true).await;
+
+    let locations: Vec<NewStoreLocation> = (0..150)
+        .map(|i| NewStoreLocation {
+            location_key: format!("large-{i:04}"),
+            name: format!("Store #{i}"),
+            address_line1: Some(format!("{i} Main St")),
+            city: Some("Testville".to_string()),
+            state: Some("TX".to_string()),
+            zip: Some(format!("7{i:04}")),
+            country: Some("US".to_string()),
+            latitude: Some(30.0 + f64::from(i) * 0.01),
+            longitude: Some(-97.0 - f64::from(i) * 0.01),
+            phone: None,
+            external_id: None,
+            locator_source: Some("locally".to_string()),
+            raw_data: serde_json::json!({"index": i}),
+            retail_chain: None,
+            retail_channel: None,
+            hours: None,
+            website: None,
+            email: None,
+            store_type: None,
+            carried_products: Vec::new(),
+        })
+        .collect();
+
+    let (new_count, updated_count) = upsert_store_locations(&pool, brand_id, &locations)
+        .await
+        .expect("large batch upsert failed");
+
+    assert_eq!(new_count, 150, "all 150 locations should be new");
+    assert_eq!(updated_count, 0);
+
+    let rows = list_active_locations_by_brand(&pool, brand_id)
+        .await
+        .expect("list failed");
+    assert_eq!(
+        rows.len(),
+        150,
+        "all 150 locations should be persisted and active"
+    );
+
+    // Verify a spot-check row.
+    let row_42 = rows
+        .iter()
+        .find(|r| r.location_key == "large-0042")
+        .expect("large-0042 missing");
+    assert_eq!(row_42.name, "Store #42");
+    assert_eq!(row_42.address_line1.as_deref(), Some("42 Main St"));
+}
+
+// ---------------------------------------------------------------------------
+// Section: Location Geospatial Queries
+// ---------------------------------------------------------------------------
+
+/// Seed one portfolio and one competitor brand with stores around Columbia, SC.
+///
+/// Returns `(portfolio_id, competitor_id)`.
+async fn seed_geo_fixture(pool: &sqlx::PgPool) -> (i64, i64) {
+    let portfolio = insert_test_brand(pool, "geo-portfolio", true).await;
+    let competitor = insert_test_brand(pool, "geo-competitor", true).await;
+    sqlx::query("UPDATE brands SET relationship = 'competitor' WHERE id = $1")
+        .bind(competitor)
+        .execute(pool)
+        .await
+        .expect("set competitor relationship");
+
+    upsert_store_locations(
+        pool,
+        portfolio,
+        &[
+            make_full_location(
+                "geo-p1",
+                "Portfolio Downtown",
+                "1 Main St",
+                "Columbia",
+                "SC",
+                "29201",
+                34.0007,
+                -81.0348,
+            ),
+            make_full_location(
+                "geo-p2",
+                "Portfolio Coast",
+                "2 King St",
+                "Charleston",
+                "SC",
+                "29401",
+                32.7765,
+                -79.9311,
+            ),
+        ],
     )
     .await
-    .expect("upsert");
-    let pins = list_active_location_pins(&pool, 1000, None, None)
-        .await
-        .expect("query failed");
-    assert!(pins.is_empty(), "null coords should be excluded");
+    .expect("portfolio upsert failed");
+    upsert_store_locations(
+        pool,
+        competitor,
+        &[
+            // ~1.4 miles from Portfolio Downtown.
+            make_full_location(
+                "geo-c1",
+                "Rival Vista",
+                "900 Gervais St",
+                "Columbia",
+                "SC",
+                "29201",
+                34.0100,
+                -81.0150,
+            ),
+            // ~10 miles from Portfolio Downtown.
+            make_full_location(
+                "geo-c2",
+                "Rival Irmo",
+                "5 Lake Murray Blvd",
+                "Irmo",
+                "SC",
+                "29063",
+                34.0860,
+                -81.1832,
+            ),
+        ],
+    )
+    .await
+    .expect("competitor upsert failed");
+
+    (portfolio, competitor)
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn list_active_location_pins_excludes_inactive_locations(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "pin-brand-inactive", true).await;
-    upsert_store_locations(
+async fn locations_within_radius_orders_by_distance(pool: sqlx::PgPool) {
+    seed_geo_fixture(&pool).await;
+
+    let rows = list_locations_within_radius(
         &pool,
-        brand_id,
-        &[NewStoreLocation {
-            location_key: "pin-loc-inactive".to_string(),
-            name: "Inactive Store".to_string(),
-            address_line1: None,
-            city: None,
-            state: None,
-            zip: None,
-            country: Some("US".to_string()),
-            latitude: Some(30.0),
-            longitude: Some(-97.0),
-            phone: None,
-            external_id: None,
-            locator_source: None,
-            raw_data: serde_json::json!({}),
-        }],
+        &RadiusSearch {
+            latitude: 34.0007,
+            longitude: -81.0348,
+            radius_miles: 15.0,
+            brand_slug: None,
+            relationship: None,
+            country: None,
+        },
+        50,
     )
     .await
-    .expect("upsert");
-    // Set is_active = false
-    sqlx::query(
-        "UPDATE store_locations SET is_active = false WHERE location_key = 'pin-loc-inactive'",
+    .expect("radius query failed");
+
+    let names: Vec<&str> = rows.iter().map(|r| r.store_name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec!["Portfolio Downtown", "Rival Vista", "Rival Irmo"]
+    );
+    assert!(rows[0].distance_miles < 0.01);
+    assert!(rows[1].distance_miles > 1.0 && rows[1].distance_miles < 2.0);
+
+    let competitors_only = list_locations_within_radius(
+        &pool,
+        &RadiusSearch {
+            latitude: 34.0007,
+            longitude: -81.0348,
+            radius_miles: 5.0,
+            brand_slug: None,
+            relationship: Some("competitor"),
+            country: None,
+        },
+        50,
     )
-    .execute(&pool)
     .await
-    .expect("deactivate");
-    let pins = list_active_location_pins(&pool, 1000, None, None)
-        .await
-        .expect("query failed");
-    assert!(pins.is_empty(), "inactive locations should be excluded");
+    .expect("filtered radius query failed");
+    assert_eq!(competitors_only.len(), 1);
+    assert_eq!(competitors_only[0].store_name, "Rival Vista");
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn list_active_location_pins_includes_brand_fields(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "pin-brand-fields", true).await;
-    upsert_store_locations(
+async fn location_tiles_cluster_by_zoom_and_track_data_version(pool: sqlx::PgPool) {
+    seed_geo_fixture(&pool).await;
+    // z=6 tile 17/25 covers all of South Carolina.
+    let tile = TileCoord::new(6, 17, 25).expect("valid tile");
+    let no_filters = LocationTileFilters::default();
+
+    let clusters = list_location_tile_features(&pool, tile, Some(64), &no_filters)
+        .await
+        .expect("clustered tile query failed");
+    assert_eq!(clusters.len(), 2, "Columbia cluster plus Charleston");
+    assert_eq!(clusters[0].point_count, 3);
+    assert_eq!(clusters[0].portfolio_count, 1);
+    assert_eq!(clusters[0].competitor_count, 2);
+    assert_eq!(
+        clusters[0].brand_slugs,
+        vec!["geo-competitor", "geo-portfolio"]
+    );
+    assert!(clusters[0].store_name.is_none());
+    assert_eq!(clusters[1].point_count, 1);
+    assert_eq!(clusters[1].store_name.as_deref(), Some("Portfolio Coast"));
+
+    let points = list_location_tile_features(&pool, tile, None, &no_filters)
+        .await
+        .expect("unclustered tile query failed");
+    assert_eq!(points.len(), 4);
+
+    let competitors = list_location_tile_features(
         &pool,
-        brand_id,
-        &[NewStoreLocation {
-            location_key: "pin-loc-fields".to_string(),
-            name: "Fields Store".to_string(),
-            address_line1: None,
-            city: Some("Denver".to_string()),
-            state: Some("CO".to_string()),
-            zip: None,
-            country: Some("US".to_string()),
-            latitude: Some(39.7392),
-            longitude: Some(-104.9903),
-            phone: None,
-            external_id: None,
-            locator_source: Some("storemapper".to_string()),
-            raw_data: serde_json::json!({}),
-        }],
+        tile,
+        None,
+        &LocationTileFilters {
+            relationship: Some("competitor"),
+            ..LocationTileFilters::default()
+        },
     )
     .await
-    .expect("upsert");
-    let pins = list_active_location_pins(&pool, 1000, None, None)
-        .await
-        .expect("query failed");
-    assert_eq!(pins.len(), 1);
-    assert_eq!(pins[0].brand_slug, "pin-brand-fields");
-    assert_eq!(pins[0].brand_relationship, "portfolio");
-    assert_eq!(pins[0].brand_tier, 1);
-}
+    .expect("filtered tile query failed");
+    assert_eq!(competitors.len(), 2);
 
-// ---------------------------------------------------------------------------
-// Section: get_active_location_keys_for_brand
-// ---------------------------------------------------------------------------
+    let neighbour = TileCoord::new(6, 16, 25).expect("valid tile");
+    let empty = list_location_tile_features(&pool, neighbour, Some(64), &no_filters)
+        .await
+        .expect("empty tile query failed");
+    assert!(empty.is_empty());
 
-#[sqlx::test(migrations = "../../migrations")]
-async fn get_active_location_keys_returns_empty_for_unknown_brand(pool: sqlx::PgPool) {
-    let keys = get_active_location_keys_for_brand(&pool, 999_999)
+    let before = get_location_data_version(&pool).await.expect("version");
+    assert_eq!(before.run_id, None);
+    let run = create_collection_run(&pool, "locations", "scheduler")
         .await
-        .expect("query should succeed even with no rows");
-    assert!(keys.is_empty());
+        .expect("create run");
+    let after = get_location_data_version(&pool).await.expect("version");
+    assert_eq!(after.run_id, Some(run.id));
+    assert_ne!(before, after);
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn get_active_location_keys_returns_only_active_keys(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "active-keys-brand", true).await;
+async fn resolve_zip_centroid_averages_store_coordinates(pool: sqlx::PgPool) {
+    seed_geo_fixture(&pool).await;
 
-    let locs = vec![
-        make_test_location("ak-1", "Active Store A", Some("SC"), None),
-        make_test_location("ak-2", "Active Store B", Some("NC"), None),
-    ];
-    upsert_store_locations(&pool, brand_id, &locs)
+    let (lat, lng) = resolve_zip_centroid(&pool, "29201")
         .await
-        .expect("upsert failed");
+        .expect("centroid query failed")
+        .expect("centroid missing");
+    assert!((lat - 34.00535).abs() < 1e-4);
+    assert!((lng - -81.0249).abs() < 1e-4);
 
-    // Deactivate one location to verify the filter.
-    sqlx::query(
-        "UPDATE store_locations SET is_active = false WHERE location_key = 'test-loc-ak-2'",
+    let missing = resolve_zip_centroid(&pool, "99999")
+        .await
+        .expect("centroid query failed");
+    assert!(missing.is_none());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn nearest_competitor_stores_pairs_each_portfolio_store(pool: sqlx::PgPool) {
+    seed_geo_fixture(&pool).await;
+
+    let rows = list_nearest_competitor_stores(
+        &pool,
+        &NearestCompetitorSearch {
+            portfolio_brand_slug: Some("geo-portfolio"),
+            competitor_brand_slug: None,
+            max_miles: 25.0,
+            per_store: 2,
+            country: None,
+        },
+        10,
+        None,
     )
-    .execute(&pool)
     .await
-    .expect("deactivate failed");
+    .expect("nearest competitor query failed");
 
-    let keys = get_active_location_keys_for_brand(&pool, brand_id)
-        .await
-        .expect("query failed");
+    let downtown: Vec<_> = rows
+        .iter()
+        .filter(|r| r.portfolio_store_name == "Portfolio Downtown")
+        .collect();
+    assert_eq!(downtown.len(), 2);
+    assert_eq!(
+        downtown[0].competitor_store_name.as_deref(),
+        Some("Rival Vista")
+    );
+    assert_eq!(
+        downtown[1].competitor_store_name.as_deref(),
+        Some("Rival Irmo")
+    );
 
-    assert_eq!(keys.len(), 1, "should return only the one active key");
-    assert!(keys.contains("test-loc-ak-1"), "active key must be present");
-    assert!(
-        !keys.contains("test-loc-ak-2"),
-        "deactivated key must not be present"
+    // Charleston has no competitor within 25 miles but still appears.
+    let coast: Vec<_> = rows
+        .iter()
+        .filter(|r| r.portfolio_store_name == "Portfolio Coast")
+        .collect();
+    assert_eq!(coast.len(), 1);
+    assert!(coast[0].competitor_location_id.is_none());
+    assert!(coast[0].distance_miles.is_none());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn location_density_groups_by_zip_for_brand_set(pool: sqlx::PgPool) {
+    seed_geo_fixture(&pool).await;
+
+    let rows = list_location_density(
+        &pool,
+        DensityGrouping::Zip,
+        &LocationDensityFilters::default(),
+        50,
+    )
+    .await
+    .expect("density query failed");
+    assert_eq!(rows[0].area_key, "29201");
+    assert_eq!(rows[0].location_count, 2);
+    assert_eq!(rows[0].portfolio_count, 1);
+    assert_eq!(rows[0].competitor_count, 1);
+    assert_eq!(
+        rows[0].brand_slugs,
+        vec!["geo-competitor".to_string(), "geo-portfolio".to_string()]
     );
+
+    let brand_set = vec!["geo-competitor".to_string()];
+    let rows = list_location_density(
+        &pool,
+        DensityGrouping::City,
+        &LocationDensityFilters {
+            brand_slugs: &brand_set,
+            ..LocationDensityFilters::default()
+        },
+        50,
+    )
+    .await
+    .expect("city density query failed");
+    let areas: Vec<&str> = rows.iter().map(|r| r.area_key.as_str()).collect();
+    assert_eq!(areas, vec!["Columbia", "Irmo"]);
 }
 
 // ---------------------------------------------------------------------------
-// Section 13: Store Locations — Batch Upsert
+// Section: County / DMA Reference Data
 // ---------------------------------------------------------------------------
 
-/// Build a `NewStoreLocation` with full field coverage for upsert tests.
-fn make_full_location(
-    key: &str,
-    name: &str,
-    address: &str,
-    city: &str,
-    state: &str,
-    zip: &str,
-    lat: f64,
-    lng: f64,
-) -> NewStoreLocation {
-    NewStoreLocation {
-        location_key: key.to_string(),
+fn test_county(fips: &str, name: &str, population: i32, dma: (&str, &str)) -> NewGeoCounty {
+    NewGeoCounty {
+        fips: fips.to_string(),
         name: name.to_string(),
-        address_line1: Some(address.to_string()),
-        city: Some(city.to_string()),
-        state: Some(state.to_string()),
-        zip: Some(zip.to_string()),
-        country: Some("US".to_string()),
-        latitude: Some(lat),
-        longitude: Some(lng),
-        phone: Some("555-0100".to_string()),
-        external_id: Some(format!("ext-{key}")),
-        locator_source: Some("locally".to_string()),
-        raw_data: serde_json::json!({"source": "test"}),
+        state: "SC".to_string(),
+        population: Some(population),
+        dma_code: Some(dma.0.to_string()),
+        dma_name: Some(dma.1.to_string()),
+    }
+}
+
+fn test_zip(zip: &str, county_fips: &str, res_ratio: f64) -> NewZipCounty {
+    NewZipCounty {
+        zip: zip.to_string(),
+        county_fips: county_fips.to_string(),
+        res_ratio,
     }
 }
 
+/// Load three SC counties in two DMAs; 29063 straddles Lexington and Richland.
+async fn load_sc_geo_reference(pool: &sqlx::PgPool) -> GeoReferenceLoadSummary {
+    let columbia = ("546", "Columbia SC");
+    let charleston = ("519", "Charleston SC");
+    load_geo_reference(
+        pool,
+        &[
+            test_county("45079", "Richland County", 400_000, columbia),
+            test_county("45063", "Lexington County", 300_000, columbia),
+            test_county("45019", "Charleston County", 400_000, charleston),
+        ],
+        &[
+            test_zip("29201", "45079", 1.0),
+            test_zip("29063", "45079", 0.4),
+            test_zip("29063", "45063", 0.6),
+            test_zip("29401", "45019", 1.0),
+        ],
+    )
+    .await
+    .expect("load geo reference failed")
+}
+
 #[sqlx::test(migrations = "../../migrations")]
-async fn test_upsert_inserts_new_locations(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "upsert-insert", true).await;
+async fn geo_reference_load_and_assign_sets_county_and_dma(pool: sqlx::PgPool) {
+    seed_geo_fixture(&pool).await;
+    let summary = load_sc_geo_reference(&pool).await;
+    assert_eq!(summary.dmas, 2);
+    assert_eq!(summary.counties, 3);
+    assert_eq!(summary.zip_counties, 4);
+
+    let assigned = assign_location_geographies(&pool, None)
+        .await
+        .expect("assign failed");
+    assert_eq!(assigned, 4);
 
-    let locs = vec![
-        make_full_location(
-            "ins-1",
-            "Store Alpha",
-            "100 Main St",
-            "Austin",
-            "TX",
-            "78701",
-            30.27,
-            -97.74,
-        ),
-        make_full_location(
-            "ins-2",
-            "Store Beta",
-            "200 Oak Ave",
-            "Dallas",
-            "TX",
-            "75201",
-            32.78,
-            -96.80,
-        ),
-        make_full_location(
-            "ins-3",
-            "Store Gamma",
-            "300 Elm Rd",
-            "Houston",
-            "TX",
-            "77001",
-            29.76,
-            -95.37,
-        ),
-    ];
+    let irmo: (Option<String>, Option<String>) = sqlx::query_as(
+        "SELECT county_fips, dma_code FROM store_locations WHERE location_key = 'geo-c2'",
+    )
+    .fetch_one(&pool)
+    .await
+    .expect("fetch irmo");
+    assert_eq!(irmo.0.as_deref(), Some("45063"), "highest res_ratio wins");
+    assert_eq!(irmo.1.as_deref(), Some("546"));
 
-    let (new_count, updated_count) = upsert_store_locations(&pool, brand_id, &locs)
+    let again = assign_location_geographies(&pool, None)
         .await
-        .expect("upsert failed");
+        .expect("re-assign failed");
+    assert_eq!(again, 0, "assignment is idempotent");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn geo_rollup_reports_population_density_and_competitor_coverage(pool: sqlx::PgPool) {
+    seed_geo_fixture(&pool).await;
+    load_sc_geo_reference(&pool).await;
+    assign_location_geographies(&pool, None)
+        .await
+        .expect("assign failed");
+
+    let counties = list_location_geo_rollup(
+        &pool,
+        GeoRollupLevel::County,
+        &LocationDensityFilters::default(),
+        50,
+    )
+    .await
+    .expect("county rollup failed");
+    let richland = counties
+        .iter()
+        .find(|r| r.area_code == "45079")
+        .expect("Richland row");
+    assert_eq!(richland.area_name.as_deref(), Some("Richland County"));
+    assert_eq!(richland.location_count, 2);
+    assert_eq!(richland.portfolio_brand_count, 1);
+    assert_eq!(richland.competitor_brand_count, 1);
+    assert!((richland.stores_per_100k.expect("per 100k") - 0.5).abs() < 1e-9);
+    assert_eq!(richland.brand_counts["geo-competitor"], 1);
+
+    let dmas = list_location_geo_rollup(
+        &pool,
+        GeoRollupLevel::Dma,
+        &LocationDensityFilters::default(),
+        50,
+    )
+    .await
+    .expect("dma rollup failed");
+    assert_eq!(dmas[0].area_code, "546");
+    assert_eq!(dmas[0].population, Some(700_000));
+    assert_eq!(dmas[0].location_count, 3);
+    assert_eq!(dmas[0].competitor_count, 2);
+    assert!(dmas[0].state.is_none());
+
+    let density = list_location_density(
+        &pool,
+        DensityGrouping::County,
+        &LocationDensityFilters::default(),
+        50,
+    )
+    .await
+    .expect("county density failed");
+    assert_eq!(density[0].area_key, "45079");
+}
+
+// ---------------------------------------------------------------------------
+// Section: Whitespace Analysis
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn whitespace_lists_competitor_only_zips_and_retailers(pool: sqlx::PgPool) {
+    seed_geo_fixture(&pool).await;
+    sqlx::query(
+        "UPDATE store_locations SET retail_chain = 'Publix', retail_channel = 'grocery' \
+         WHERE location_key IN ('geo-c1', 'geo-c2')",
+    )
+    .execute(&pool)
+    .await
+    .expect("classify competitor stores");
+
+    let zips = list_location_whitespace(
+        &pool,
+        WhitespaceGrouping::Zip,
+        &WhitespaceFilters::default(),
+        50,
+    )
+    .await
+    .expect("zip whitespace failed");
+    // 29201 has both brands; 29401 has only the portfolio brand.
+    assert_eq!(zips.len(), 1);
+    assert_eq!(zips[0].area_key, "29063");
+    assert_eq!(
+        zips[0].competitor_brands,
+        vec!["geo-competitor".to_string()]
+    );
+    assert_eq!(zips[0].competitor_channels, vec!["grocery".to_string()]);
+    assert_eq!(
+        zips[0].portfolio_brands_absent,
+        vec!["geo-portfolio".to_string()]
+    );
 
-    assert_eq!(new_count, 3, "all 3 locations should be new inserts");
-    assert_eq!(updated_count, 0, "no existing rows to update");
+    let retailers = list_location_whitespace(
+        &pool,
+        WhitespaceGrouping::Retailer,
+        &WhitespaceFilters {
+            channel: Some("grocery"),
+            ..WhitespaceFilters::default()
+        },
+        50,
+    )
+    .await
+    .expect("retailer whitespace failed");
+    assert_eq!(retailers.len(), 1);
+    assert_eq!(retailers[0].area_key, "Publix");
+    assert_eq!(retailers[0].competitor_store_count, 2);
 
-    let rows = list_active_locations_by_brand(&pool, brand_id)
-        .await
-        .expect("list failed");
+    let liquor_only = list_location_whitespace(
+        &pool,
+        WhitespaceGrouping::Retailer,
+        &WhitespaceFilters {
+            channel: Some("liquor"),
+            ..WhitespaceFilters::default()
+        },
+        50,
+    )
+    .await
+    .expect("channel-filtered whitespace failed");
+    assert!(liquor_only.is_empty());
+}
 
-    assert_eq!(rows.len(), 3, "should have 3 active locations");
+// ---------------------------------------------------------------------------
+// Section: Accounts
+// ---------------------------------------------------------------------------
 
-    let alpha = rows
-        .iter()
-        .find(|r| r.location_key == "ins-1")
-        .expect("ins-1 missing");
-    assert_eq!(alpha.name, "Store Alpha");
-    assert_eq!(alpha.address_line1.as_deref(), Some("100 Main St"));
-    assert_eq!(alpha.city.as_deref(), Some("Austin"));
-    assert_eq!(alpha.state.as_deref(), Some("TX"));
-    assert_eq!(alpha.zip.as_deref(), Some("78701"));
-    assert_eq!(alpha.country, "US");
-    assert!(alpha.is_active);
+fn make_account(number: &str, name: &str, address: Option<&str>, zip: Option<&str>) -> NewAccount {
+    NewAccount {
+        account_number: number.to_string(),
+        name: name.to_string(),
+        address_line1: address.map(str::to_string),
+        city: Some("Columbia".to_string()),
+        state: Some("SC".to_string()),
+        zip: zip.map(str::to_string),
+    }
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn test_upsert_updates_existing_locations(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "upsert-update", true).await;
-
-    // Insert initial locations.
-    let initial = vec![
-        make_full_location(
-            "upd-1",
-            "Store Original",
-            "100 Main St",
-            "Austin",
-            "TX",
-            "78701",
-            30.27,
-            -97.74,
-        ),
-        make_full_location(
-            "upd-2",
-            "Store Unchanged",
-            "200 Oak Ave",
-            "Dallas",
-            "TX",
-            "75201",
-            32.78,
-            -96.80,
-        ),
-    ];
-    upsert_store_locations(&pool, brand_id, &initial)
+async fn accounts_import_matches_locator_rows_by_normalized_address(pool: sqlx::PgPool) {
+    seed_geo_fixture(&pool).await;
+    // Simulate a row collected before address keys existed.
+    sqlx::query("UPDATE store_locations SET address_key = NULL WHERE location_key = 'geo-c1'")
+        .execute(&pool)
         .await
-        .expect("initial upsert failed");
+        .expect("clear address key");
 
-    // Upsert with changed data for upd-1 (new address, new city), upd-2 unchanged.
-    let updated = vec![
-        make_full_location(
-            "upd-1",
-            "Store Renamed",
-            "999 New Blvd",
-            "San Antonio",
-            "TX",
-            "78201",
-            29.42,
-            -98.49,
-        ),
-        make_full_location(
-            "upd-2",
-            "Store Unchanged",
-            "200 Oak Ave",
-            "Dallas",
-            "TX",
-            "75201",
-            32.78,
-            -96.80,
-        ),
-    ];
-    let (new_count, updated_count) = upsert_store_locations(&pool, brand_id, &updated)
+    let summary = import_accounts(
+        &pool,
+        &[
+            make_account(
+                "A-1",
+                "Vista Liquors",
+                Some("900 Gervais Street, Suite 2"),
+                Some("29201-1111"),
+            ),
+            make_account("A-2", "Walk-in Customer", None, None),
+        ],
+        false,
+    )
+    .await
+    .expect("import failed");
+    assert_eq!(summary.inserted, 2);
+    assert_eq!(summary.unmatchable, 1);
+    assert_eq!(summary.matched, 1);
+
+    let presence = list_account_competitor_presence(
+        &pool,
+        &AccountPresenceFilters {
+            with_competitors_only: true,
+            ..AccountPresenceFilters::default()
+        },
+        50,
+    )
+    .await
+    .expect("presence query failed");
+    assert_eq!(presence.len(), 1);
+    assert_eq!(presence[0].account_number, "A-1");
+    assert_eq!(presence[0].matched_store_count, 1);
+    assert_eq!(
+        presence[0].competitor_brands,
+        vec!["geo-competitor".to_string()]
+    );
+    assert!(presence[0].portfolio_brands.is_empty());
+
+    let prospects = list_competitor_stores_without_account(&pool, &ProspectFilters::default(), 50)
         .await
-        .expect("update upsert failed");
+        .expect("prospects query failed");
+    assert_eq!(prospects.len(), 1);
+    assert_eq!(prospects[0].name, "Rival Irmo");
 
-    assert_eq!(new_count, 0, "no new rows — both keys already exist");
-    assert_eq!(updated_count, 2, "both rows hit the ON CONFLICT path");
+    let summary = import_accounts(
+        &pool,
+        &[make_account(
+            "A-1",
+            "Vista Liquors",
+            Some("900 Gervais St"),
+            Some("29201"),
+        )],
+        true,
+    )
+    .await
+    .expect("re-import failed");
+    assert_eq!(summary.updated, 1);
+    assert_eq!(summary.deactivated, 1);
 
-    let rows = list_active_locations_by_brand(&pool, brand_id)
+    // An empty export must not deactivate the whole account list.
+    assert!(matches!(
+        import_accounts(&pool, &[], true).await,
+        Err(DbError::EmptyAccountImport)
+    ));
+
+    let all = list_account_competitor_presence(&pool, &AccountPresenceFilters::default(), 50)
         .await
-        .expect("list failed");
+        .expect("presence query failed");
+    assert_eq!(all.len(), 1, "deactivated accounts are excluded");
+}
 
-    let renamed = rows
-        .iter()
-        .find(|r| r.location_key == "upd-1")
-        .expect("upd-1 missing");
-    assert_eq!(renamed.name, "Store Renamed", "name should be updated");
-    assert_eq!(
-        renamed.address_line1.as_deref(),
-        Some("999 New Blvd"),
-        "address should be updated"
-    );
-    assert_eq!(
-        renamed.city.as_deref(),
-        Some("San Antonio"),
-        "city should be updated"
-    );
+// ---------------------------------------------------------------------------
+// Section: Location Quarantine
+// ---------------------------------------------------------------------------
+
+fn numbered_locations(count: usize) -> Vec<NewStoreLocation> {
+    (0..count)
+        .map(|i| {
+            make_full_location(
+                &format!("q-{i}"),
+                &format!("Store {i}"),
+                &format!("{i} Main St"),
+                "Columbia",
+                "SC",
+                "29201",
+                34.0,
+                -81.0,
+            )
+        })
+        .collect()
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn test_upsert_handles_conflict(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "upsert-conflict", true).await;
-
-    let loc = make_full_location(
-        "conflict-1",
-        "Original Name",
-        "100 Main St",
-        "Austin",
-        "TX",
-        "78701",
-        30.27,
-        -97.74,
-    );
-    let (new1, upd1) = upsert_store_locations(&pool, brand_id, &[loc])
+async fn location_drop_guard_quarantines_and_applies_on_approval(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "quarantine-brand", true).await;
+    upsert_store_locations(&pool, brand_id, &numbered_locations(30))
         .await
-        .expect("first upsert failed");
-    assert_eq!(new1, 1);
-    assert_eq!(upd1, 0);
+        .expect("seed upsert failed");
+    let guard = LocationDropGuard::default();
 
-    // Upsert same key with different data — should update, not duplicate.
-    let loc2 = make_full_location(
-        "conflict-1",
-        "Updated Name",
-        "200 New Ave",
-        "Dallas",
-        "TX",
-        "75201",
-        32.78,
-        -96.80,
-    );
-    let (new2, upd2) = upsert_store_locations(&pool, brand_id, &[loc2])
+    let safe =
+        quarantine_location_scrape_on_drop(&pool, brand_id, None, &numbered_locations(20), &guard)
+            .await
+            .expect("drop check failed");
+    assert!(safe.is_none(), "a one-third drop is within the threshold");
+
+    let partial = numbered_locations(3);
+    let first = quarantine_location_scrape_on_drop(&pool, brand_id, None, &partial, &guard)
         .await
-        .expect("second upsert failed");
-    assert_eq!(new2, 0, "same key should not produce a new row");
-    assert_eq!(upd2, 1, "should update the existing row");
+        .expect("drop check failed")
+        .expect("90% drop should be quarantined");
+    assert_eq!(first.assessment.baseline_count, 30);
+    let second = quarantine_location_scrape_on_drop(&pool, brand_id, None, &partial, &guard)
+        .await
+        .expect("drop check failed")
+        .expect("still quarantined");
 
-    let rows = list_active_locations_by_brand(&pool, brand_id)
+    let pending = list_location_quarantines(&pool, Some("pending"), 10)
         .await
         .expect("list failed");
-
     assert_eq!(
-        rows.len(),
+        pending.len(),
         1,
-        "should have exactly 1 location — no duplicates"
+        "the newer scrape supersedes the older one"
     );
-    assert_eq!(
-        rows[0].name, "Updated Name",
-        "data should reflect the latest upsert"
+    assert_eq!(pending[0].public_id, second.public_id);
+    assert_eq!(pending[0].new_count, 3);
+
+    let err = approve_location_quarantine(&pool, first.public_id, None)
+        .await
+        .expect_err("superseded quarantine cannot be approved");
+    assert!(matches!(err, DbError::QuarantineNotPending { .. }));
+
+    let (a, b) = tokio::join!(
+        approve_location_quarantine(&pool, second.public_id, Some("store closures")),
+        approve_location_quarantine(&pool, second.public_id, None),
     );
-    assert_eq!(rows[0].city.as_deref(), Some("Dallas"));
+    let (summary, err) = match (a, b) {
+        (Ok(summary), Err(err)) | (Err(err), Ok(summary)) => (summary, err),
+        other => panic!("exactly one concurrent approval should apply: {other:?}"),
+    };
+    assert!(matches!(err, DbError::QuarantineNotPending { .. }));
+    assert_eq!(summary.updated, 3);
+    assert_eq!(summary.deactivated, 27);
+    assert_eq!(summary.events, 27);
+
+    let active: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM store_locations WHERE brand_id = $1 AND is_active = TRUE",
+    )
+    .bind(brand_id)
+    .fetch_one(&pool)
+    .await
+    .expect("count failed");
+    assert_eq!(active, 3);
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn test_upsert_empty_vec(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "upsert-empty", true).await;
-
-    let (new_count, updated_count) = upsert_store_locations(&pool, brand_id, &[])
+async fn rejected_location_quarantine_leaves_footprint_untouched(pool: sqlx::PgPool) {
+    let brand_id = insert_test_brand(&pool, "quarantine-reject", true).await;
+    upsert_store_locations(&pool, brand_id, &numbered_locations(25))
         .await
-        .expect("upsert with empty vec should not error");
+        .expect("seed upsert failed");
 
-    assert_eq!(new_count, 0);
-    assert_eq!(updated_count, 0);
+    let held = quarantine_location_scrape_on_drop(
+        &pool,
+        brand_id,
+        None,
+        &[],
+        &LocationDropGuard::default(),
+    )
+    .await
+    .expect("drop check failed")
+    .expect("empty scrape should be quarantined");
+    reject_location_quarantine(&pool, held.public_id, Some("provider outage"))
+        .await
+        .expect("reject failed");
 
-    let rows = list_active_locations_by_brand(&pool, brand_id)
+    let rows = list_location_quarantines(&pool, None, 10)
         .await
         .expect("list failed");
-    assert_eq!(rows.len(), 0, "no locations should exist");
-}
-
-#[sqlx::test(migrations = "../../migrations")]
-async fn test_upsert_large_batch(pool: sqlx::PgPool) {
-    let brand_id = insert_test_brand(&pool, "upsert-large", true).await;
+    assert_eq!(rows[0].status, "rejected");
+    assert_eq!(rows[0].review_note.as_deref(), Some("provider outage"));
 
-    let locations: Vec<NewStoreLocation> = (0..150)
-        .map(|i| NewStoreLocation {
-            location_key: format!("large-{i:04}"),
-            name: format!("Store #{i}"),
-            address_line1: Some(format!("{i} Main St")),
-            city: Some("Testville".to_string()),
-            state: Some("TX".to_string()),
-            zip: Some(format!("7{i:04}")),
-            country: Some("US".to_string()),
-            latitude: Some(30.0 + f64::from(i) * 0.01),
-            longitude: Some(-97.0 - f64::from(i) * 0.01),
-            phone: None,
-            external_id: None,
-            locator_source: Some("locally".to_string()),
-            raw_data: serde_json::json!({"index": i}),
-        })
-        .collect();
+    let active: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM store_locations WHERE brand_id = $1 AND is_active = TRUE",
+    )
+    .bind(brand_id)
+    .fetch_one(&pool)
+    .await
+    .expect("count failed");
+    assert_eq!(active, 25);
 
-    let (new_count, updated_count) = upsert_store_locations(&pool, brand_id, &locations)
+    let err = reject_location_quarantine(&pool, uuid::Uuid::new_v4(), None)
         .await
-        .expect("large batch upsert failed");
+        .expect_err("unknown id");
+    assert!(matches!(err, DbError::NotFound));
+}
 
-    assert_eq!(new_count, 150, "all 150 locations should be new");
-    assert_eq!(updated_count, 0);
+#[sqlx::test(migrations = "../../migrations")]
+async fn brand_candidates_collect_evidence_and_skip_known_brands(pool: sqlx::PgPool) {
+    insert_test_brand(&pool, "cann", true).await;
+    let seen = chrono::Utc::now();
+    let sighting = |name: &'static str, source: &'static str, source_ref: &'static str| {
+        NewBrandCandidateEvidence {
+            name,
+            source,
+            source_ref,
+            context: Some("hemp sodas from Wynk"),
+            observed_at: seen,
+        }
+    };
+    let summary = record_brand_candidate_evidence(
+        &pool,
+        &[
+            sighting("Cann", "sentiment", "https://news.example/a"),
+            sighting("Wynk", "sentiment", "https://news.example/a"),
+            sighting("Wynk", "sentiment", "https://news.example/a"),
+            sighting("WYNK", "locator", "test-loc-1"),
+            sighting("Keef", "retailer_page", "https://shop.example/keef"),
+        ],
+    )
+    .await
+    .expect("record failed");
+    assert_eq!(summary.known_brands_skipped, 1);
+    assert_eq!(summary.candidates_created, 2);
+    assert_eq!(
+        summary.evidence_added, 3,
+        "repeat sightings are recorded once"
+    );
 
-    let rows = list_active_locations_by_brand(&pool, brand_id)
+    let pending = list_brand_candidates(&pool, Some("pending"), 2, 10)
         .await
         .expect("list failed");
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].name, "Wynk");
+    assert_eq!(pending[0].evidence_count, 2);
+    assert_eq!(pending[0].sources, vec!["locator", "sentiment"]);
+    assert_eq!(pending[0].sample_contexts.len(), 2);
+
+    let keef = list_brand_candidates(&pool, None, 1, 10)
+        .await
+        .expect("list failed")
+        .into_iter()
+        .find(|c| c.slug == "keef")
+        .expect("keef candidate");
+    dismiss_brand_candidate(&pool, keef.public_id, Some("distributor, not a brand"))
+        .await
+        .expect("dismiss failed");
+    let err = dismiss_brand_candidate(&pool, keef.public_id, None)
+        .await
+        .expect_err("already dismissed");
+    assert!(matches!(err, DbError::BrandCandidateNotPending { .. }));
+
+    let brand = |slug| NewBrand {
+        name: "Wynk",
+        slug,
+        relationship: "competitor",
+        tier: 2,
+        domain: None,
+        shop_url: None,
+        store_locator_url: None,
+        twitter_handle: None,
+        notes: None,
+    };
+    promote_brand_candidate(&pool, pending[0].public_id, &brand("cann"), None)
+        .await
+        .expect_err("slug already taken");
+    let still_pending = get_brand_candidate(&pool, pending[0].public_id)
+        .await
+        .expect("get failed")
+        .expect("candidate exists");
     assert_eq!(
-        rows.len(),
-        150,
-        "all 150 locations should be persisted and active"
+        still_pending.status, "pending",
+        "a failed insert rolls back"
     );
 
-    // Verify a spot-check row.
-    let row_42 = rows
-        .iter()
-        .find(|r| r.location_key == "large-0042")
-        .expect("large-0042 missing");
-    assert_eq!(row_42.name, "Store #42");
-    assert_eq!(row_42.address_line1.as_deref(), Some("42 Main St"));
+    let created = promote_brand_candidate(&pool, pending[0].public_id, &brand("wynk"), None)
+        .await
+        .expect("promote failed");
+    let promoted = get_brand_candidate(&pool, pending[0].public_id)
+        .await
+        .expect("get failed")
+        .expect("candidate exists");
+    assert_eq!(promoted.status, "promoted");
+    assert_eq!(promoted.brand_slug.as_deref(), Some(created.slug.as_str()));
+    let err = promote_brand_candidate(&pool, pending[0].public_id, &brand("wynk-2"), None)
+        .await
+        .expect_err("already promoted");
+    assert!(matches!(err, DbError::BrandCandidateNotPending { .. }));
+    assert!(list_brand_candidates(&pool, Some("pending"), 1, 10)
+        .await
+        .expect("list failed")
+        .is_empty());
 }
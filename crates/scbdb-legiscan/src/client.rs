@@ -9,13 +9,18 @@
 //! Session and master-list endpoints live in [`super::session`].
 
 use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
 use reqwest::{Client, Url};
 
 use crate::error::LegiscanError;
+use crate::ledger::RequestLedger;
 use crate::retry::retry_with_backoff;
-use crate::types::{ApiResponse, BillDetail, BillResponse, BillSearchItem, SearchResponse};
+use crate::types::{
+    ApiResponse, BillDetail, BillResponse, BillSearchItem, BillTextDocument, BillTextResponse,
+    SearchResponse,
+};
 
 const DEFAULT_BASE_URL: &str = "https://api.legiscan.com/";
 
@@ -24,6 +29,7 @@ const DEFAULT_BASE_URL: &str = "https://api.legiscan.com/";
 /// Tracks a per-session request counter and enforces `max_requests` to protect
 /// the monthly API quota. Once the ceiling is reached every method returns
 /// [`LegiscanError::BudgetExceeded`] immediately — no network traffic.
+/// An optional [`RequestLedger`] adds a budget shared across processes.
 pub struct LegiscanClient {
     client: Client,
     api_key: String,
@@ -32,6 +38,8 @@ pub struct LegiscanClient {
     request_count: AtomicU32,
     /// Hard ceiling on HTTP requests for this session.
     max_requests: u32,
+    /// Shared budget consulted before each request, if any.
+    ledger: Option<Arc<dyn RequestLedger>>,
 }
 
 impl LegiscanClient {
@@ -76,9 +84,17 @@ impl LegiscanClient {
             base_url,
             request_count: AtomicU32::new(0),
             max_requests,
+            ledger: None,
         })
     }
 
+    /// Counts every request against `ledger` as well as `max_requests`.
+    #[must_use]
+    pub fn with_ledger(mut self, ledger: Arc<dyn RequestLedger>) -> Self {
+        self.ledger = Some(ledger);
+        self
+    }
+
     /// Returns the number of HTTP requests issued by this client so far.
     #[must_use]
     pub fn requests_used(&self) -> u32 {
@@ -106,6 +122,24 @@ impl LegiscanClient {
         Ok(envelope.data.bill)
     }
 
+    /// Fetches one bill text version, including its base64 document, by
+    /// `LegiScan` `doc_id`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::get_bill`].
+    pub async fn get_bill_text(&self, doc_id: i64) -> Result<BillTextDocument, LegiscanError> {
+        let url = self.build_url("getBillText", &[("id", &doc_id.to_string())]);
+        let body = self.request_json(&url).await?;
+        Self::check_api_error(&body)?;
+        let envelope: ApiResponse<BillTextResponse> =
+            serde_json::from_value(body).map_err(|e| LegiscanError::Deserialize {
+                context: format!("getBillText(id={doc_id})"),
+                source: e,
+            })?;
+        Ok(envelope.data.text)
+    }
+
     /// Searches for bills by keyword and optional state, fetching up to
     /// `max_pages` pages of results (50 bills per page).
     ///
@@ -196,6 +230,8 @@ impl LegiscanClient {
     /// If the ceiling is already reached, returns [`LegiscanError::BudgetExceeded`]
     /// without making any network request. Each logical call counts as one
     /// budget unit regardless of how many retry attempts succeed internally.
+    /// The ledger, when set, is consulted after the session budget; a refusal
+    /// leaves the session counter unchanged.
     pub(crate) async fn request_json(&self, url: &Url) -> Result<serde_json::Value, LegiscanError> {
         let used = self.request_count.fetch_add(1, Ordering::Relaxed);
         if used >= self.max_requests {
@@ -205,6 +241,12 @@ impl LegiscanClient {
                 limit: self.max_requests,
             });
         }
+        if let Some(ledger) = &self.ledger {
+            if let Err(e) = ledger.reserve().await {
+                self.request_count.fetch_sub(1, Ordering::Relaxed);
+                return Err(e);
+            }
+        }
         tracing::debug!(
             requests_used = used + 1,
             max = self.max_requests,
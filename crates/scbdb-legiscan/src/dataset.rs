@@ -0,0 +1,217 @@
+//! Bulk dataset endpoints for the `LegiScan` API client and reading of the
+//! downloaded archives.
+//!
+//! A dataset is a weekly ZIP of one session: `bill/*.json` files shaped like
+//! `getBill` responses, `vote/*.json` like `getRollCall` and `people/*.json`
+//! like `getPerson`. One `getDataset` request replaces a `getBill` per bill
+//! (and a `getRollCall` per roll call), which is what makes backfills cheap.
+
+use std::io::{Cursor, Read};
+
+use base64::Engine as _;
+use serde::de::DeserializeOwned;
+
+use crate::client::LegiscanClient;
+use crate::error::LegiscanError;
+use crate::types::{
+    ApiResponse, BillDetail, BillResponse, DatasetArchive, DatasetInfo, DatasetListResponse,
+    DatasetResponse, Person, PersonResponse, RollCall, RollCallResponse,
+};
+
+/// The JSON files of a dataset archive, parsed.
+#[derive(Debug, Default)]
+pub struct DatasetContents {
+    pub bills: Vec<BillDetail>,
+    pub roll_calls: Vec<RollCall>,
+    pub people: Vec<Person>,
+}
+
+impl LegiscanClient {
+    /// Lists the available datasets, optionally for a single state.
+    ///
+    /// # Errors
+    ///
+    /// - [`LegiscanError::BudgetExceeded`] if the session budget is reached.
+    /// - [`LegiscanError::QuotaExceeded`] if `LegiScan`'s quota is exhausted.
+    /// - [`LegiscanError::ApiError`] on API-level failure.
+    /// - [`LegiscanError::Http`] on network failure.
+    /// - [`LegiscanError::Deserialize`] if the response shape is unexpected.
+    pub async fn get_dataset_list(
+        &self,
+        state: Option<&str>,
+    ) -> Result<Vec<DatasetInfo>, LegiscanError> {
+        let state_upper = state.map(str::to_uppercase);
+        let params: Vec<(&str, &str)> = state_upper
+            .as_deref()
+            .map(|s| vec![("state", s)])
+            .unwrap_or_default();
+        let url = self.build_url("getDatasetList", &params);
+        let body = self.request_json(&url).await?;
+        Self::check_api_error(&body)?;
+        let envelope: ApiResponse<DatasetListResponse> =
+            serde_json::from_value(body).map_err(|e| LegiscanError::Deserialize {
+                context: format!("getDatasetList(state={state:?})"),
+                source: e,
+            })?;
+        Ok(envelope.data.datasetlist)
+    }
+
+    /// Downloads the dataset of a session. `access_key` comes from
+    /// [`Self::get_dataset_list`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::get_dataset_list`].
+    pub async fn get_dataset(
+        &self,
+        session_id: i64,
+        access_key: &str,
+    ) -> Result<DatasetArchive, LegiscanError> {
+        let url = self.build_url(
+            "getDataset",
+            &[("id", &session_id.to_string()), ("access_key", access_key)],
+        );
+        let body = self.request_json(&url).await?;
+        Self::check_api_error(&body)?;
+        let envelope: ApiResponse<DatasetResponse> =
+            serde_json::from_value(body).map_err(|e| LegiscanError::Deserialize {
+                context: format!("getDataset(id={session_id})"),
+                source: e,
+            })?;
+        Ok(envelope.data.dataset)
+    }
+}
+
+/// Unpack a downloaded dataset and parse its bill, roll call and people
+/// files. Other files (`text/`, `README`) are ignored.
+///
+/// # Errors
+///
+/// Returns [`LegiscanError::Dataset`] if the archive is not valid base64 or
+/// ZIP, or a JSON file cannot be parsed.
+pub fn read_dataset(archive: &DatasetArchive) -> Result<DatasetContents, LegiscanError> {
+    let compact: String = archive
+        .zip
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace())
+        .collect();
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(compact)
+        .map_err(|e| LegiscanError::Dataset(format!("invalid base64 archive: {e}")))?;
+    let mut zip = zip::ZipArchive::new(Cursor::new(bytes))
+        .map_err(|e| LegiscanError::Dataset(format!("invalid ZIP archive: {e}")))?;
+
+    let mut contents = DatasetContents::default();
+    for i in 0..zip.len() {
+        let mut file = zip
+            .by_index(i)
+            .map_err(|e| LegiscanError::Dataset(format!("unreadable ZIP entry {i}: {e}")))?;
+        let name = file.name().to_string();
+        let is_json = std::path::Path::new(&name)
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        if !file.is_file() || !is_json {
+            continue;
+        }
+        let Some(kind) = name.rsplit('/').nth(1) else {
+            continue;
+        };
+        if !matches!(kind, "bill" | "vote" | "people") {
+            continue;
+        }
+        let mut json = Vec::new();
+        file.read_to_end(&mut json)
+            .map_err(|e| LegiscanError::Dataset(format!("{name}: {e}")))?;
+        match kind {
+            "bill" => contents
+                .bills
+                .push(parse_entry::<BillResponse>(&name, &json)?.bill),
+            "vote" => contents
+                .roll_calls
+                .push(parse_entry::<RollCallResponse>(&name, &json)?.roll_call),
+            _ => contents
+                .people
+                .push(parse_entry::<PersonResponse>(&name, &json)?.person),
+        }
+    }
+    Ok(contents)
+}
+
+fn parse_entry<T: DeserializeOwned>(name: &str, json: &[u8]) -> Result<T, LegiscanError> {
+    serde_json::from_slice(json).map_err(|e| LegiscanError::Dataset(format!("{name}: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn archive(files: &[(&str, serde_json::Value)]) -> DatasetArchive {
+        let mut buf = Vec::new();
+        {
+            let mut writer = zip::ZipWriter::new(Cursor::new(&mut buf));
+            for (name, value) in files {
+                writer
+                    .start_file(*name, zip::write::SimpleFileOptions::default())
+                    .unwrap();
+                writer.write_all(value.to_string().as_bytes()).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+        DatasetArchive {
+            session_id: 2100,
+            dataset_hash: "abc".to_string(),
+            dataset_date: None,
+            mime: "application/zip".to_string(),
+            zip: base64::engine::general_purpose::STANDARD.encode(buf),
+        }
+    }
+
+    #[test]
+    fn read_dataset_parses_bills_votes_and_people() {
+        let archive = archive(&[
+            (
+                "SC/2025-2026_Regular/bill/H3001.json",
+                serde_json::json!({"bill": {
+                    "bill_id": 1, "bill_number": "H3001", "title": "Hemp beverages",
+                    "status": 1, "state": "SC", "change_hash": "h1"
+                }}),
+            ),
+            (
+                "SC/2025-2026_Regular/vote/500.json",
+                serde_json::json!({"roll_call": {"roll_call_id": 500, "bill_id": 1, "votes": []}}),
+            ),
+            (
+                "SC/2025-2026_Regular/people/10.json",
+                serde_json::json!({"person": {"people_id": 10, "name": "Jane Doe"}}),
+            ),
+            (
+                "SC/2025-2026_Regular/text/99.json",
+                serde_json::json!({"text": {}}),
+            ),
+        ]);
+
+        let contents = read_dataset(&archive).unwrap();
+        assert_eq!(contents.bills.len(), 1);
+        assert_eq!(contents.bills[0].change_hash.as_deref(), Some("h1"));
+        assert_eq!(contents.roll_calls.len(), 1);
+        assert_eq!(contents.people[0].name, "Jane Doe");
+    }
+
+    #[test]
+    fn read_dataset_rejects_malformed_archives() {
+        let mut bad = archive(&[]);
+        bad.zip = "not base64!".to_string();
+        assert!(matches!(read_dataset(&bad), Err(LegiscanError::Dataset(_))));
+
+        let broken = archive(&[(
+            "SC/2025/bill/H1.json",
+            serde_json::json!({"bill": {"bill_id": 1}}),
+        )]);
+        assert!(matches!(
+            read_dataset(&broken),
+            Err(LegiscanError::Dataset(msg)) if msg.contains("H1.json")
+        ));
+    }
+}
@@ -27,10 +27,23 @@ pub enum LegiscanError {
     #[error("LegiScan quota exceeded: {0}")]
     QuotaExceeded(String),
 
-    /// The per-session request budget configured via `--max-requests` was reached.
+    /// The per-session request budget configured via `--max-requests`, or the
+    /// monthly budget of a [`RequestLedger`](crate::RequestLedger), was reached.
     ///
     /// No more HTTP requests will be issued for this run. Raise `--max-requests`
     /// or reduce `--max-pages` / the number of keywords if more coverage is needed.
     #[error("request budget exceeded: used {used} of {limit} allowed requests")]
     BudgetExceeded { used: u32, limit: u32 },
+
+    /// A bill text document could not be decoded or its text extracted.
+    #[error("bill text document error: {0}")]
+    Document(String),
+
+    /// The shared request ledger could not be read or updated.
+    #[error("request ledger error: {0}")]
+    Ledger(String),
+
+    /// A bulk dataset archive could not be decoded or read.
+    #[error("dataset archive error: {0}")]
+    Dataset(String),
 }
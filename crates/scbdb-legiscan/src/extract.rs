@@ -0,0 +1,203 @@
+//! Plain-text extraction from `getBillText` documents.
+//!
+//! `LegiScan` returns each bill version as a base64 document in the state's
+//! own format. HTML and plain text are converted directly; PDFs go through
+//! the minimal content-stream reader in [`crate::pdf`]. Word documents and
+//! scanned PDFs are reported as [`LegiscanError::Document`] so callers can
+//! record the failure and move on.
+
+use base64::Engine as _;
+
+use crate::error::LegiscanError;
+use crate::types::BillTextDocument;
+
+/// Decode the base64 `doc` field of a `getBillText` response.
+///
+/// # Errors
+///
+/// Returns [`LegiscanError::Document`] if the payload is not valid base64.
+pub fn decode_document(doc: &str) -> Result<Vec<u8>, LegiscanError> {
+    let compact: String = doc.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+    base64::engine::general_purpose::STANDARD
+        .decode(compact)
+        .map_err(|e| LegiscanError::Document(format!("invalid base64 document: {e}")))
+}
+
+/// Extract normalized plain text from a decoded document of the given MIME
+/// type.
+///
+/// # Errors
+///
+/// Returns [`LegiscanError::Document`] for unsupported formats and for
+/// documents that yield no text.
+pub fn extract_text(mime: &str, bytes: &[u8]) -> Result<String, LegiscanError> {
+    let mime = mime
+        .split(';')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_ascii_lowercase();
+    let raw = match mime.as_str() {
+        "text/html" | "application/xhtml+xml" => html_to_text(&String::from_utf8_lossy(bytes)),
+        "text/plain" => String::from_utf8_lossy(bytes).into_owned(),
+        "application/pdf" => crate::pdf::pdf_text(bytes)?,
+        other => {
+            return Err(LegiscanError::Document(format!(
+                "unsupported document type '{other}'"
+            )))
+        }
+    };
+    let text = normalize_whitespace(&raw);
+    if text.is_empty() {
+        return Err(LegiscanError::Document(format!(
+            "no extractable text in {mime} document"
+        )));
+    }
+    Ok(text)
+}
+
+/// Decode a `getBillText` document and extract its text in one step.
+///
+/// # Errors
+///
+/// See [`decode_document`] and [`extract_text`].
+pub fn extract_document_text(document: &BillTextDocument) -> Result<String, LegiscanError> {
+    extract_text(&document.mime, &decode_document(&document.doc)?)
+}
+
+/// Tags whose start or end begins a new line.
+const BLOCK_TAGS: &[&str] = &[
+    "address",
+    "blockquote",
+    "br",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "li",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "tr",
+    "ul",
+];
+
+/// Tags whose content is never text.
+const SKIPPED_TAGS: &[&str] = &["head", "script", "style", "title"];
+
+fn html_to_text(html: &str) -> String {
+    let mut out = String::with_capacity(html.len() / 2);
+    let mut rest = html;
+    while let Some(lt) = rest.find('<') {
+        out.push_str(&decode_entities(&rest[..lt]));
+        rest = &rest[lt..];
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map_or("", |end| &after[end + 3..]);
+            continue;
+        }
+        let Some(gt) = rest.find('>') else {
+            rest = "";
+            break;
+        };
+        let tag = &rest[1..gt];
+        rest = &rest[gt + 1..];
+        let closing = tag.starts_with('/');
+        let name: String = tag
+            .trim_start_matches('/')
+            .chars()
+            .take_while(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_ascii_lowercase();
+        if !closing && SKIPPED_TAGS.contains(&name.as_str()) {
+            let end = format!("</{name}");
+            let lower = rest.to_ascii_lowercase();
+            rest = lower.find(&end).map_or("", |i| &rest[i..]);
+            continue;
+        }
+        if BLOCK_TAGS.contains(&name.as_str()) {
+            out.push('\n');
+        } else if matches!(name.as_str(), "td" | "th") {
+            out.push(' ');
+        }
+    }
+    out.push_str(&decode_entities(rest));
+    out
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest
+            .find(';')
+            .filter(|&semi| semi <= 10)
+            .and_then(|semi| entity_char(&rest[1..semi]).map(|c| (c, semi)));
+        if let Some((c, semi)) = decoded {
+            out.push(c);
+            rest = &rest[semi + 1..];
+        } else {
+            out.push('&');
+            rest = &rest[1..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn entity_char(entity: &str) -> Option<char> {
+    if let Some(num) = entity.strip_prefix('#') {
+        let code = match num.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => num.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+    Some(match entity {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" | "ldquo" | "rdquo" => '"',
+        "apos" | "rsquo" | "lsquo" => '\'',
+        "nbsp" => ' ',
+        "sect" => '§',
+        "para" => '¶',
+        "mdash" => '—',
+        "ndash" => '–',
+        "hellip" => '…',
+        _ => return None,
+    })
+}
+
+/// Collapse runs of spaces, trim lines and keep at most one blank line.
+pub(crate) fn normalize_whitespace(text: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.lines() {
+        let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+        if line.is_empty() && lines.last().is_none_or(String::is_empty) {
+            continue;
+        }
+        lines.push(line);
+    }
+    while lines.last().is_some_and(String::is_empty) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+#[path = "extract_test.rs"]
+mod tests;
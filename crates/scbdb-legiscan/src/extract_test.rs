@@ -0,0 +1,97 @@
+use std::io::Write as _;
+
+use super::*;
+
+fn pdf_with_stream(dict: &str, data: &[u8]) -> Vec<u8> {
+    let mut pdf = b"%PDF-1.4\n1 0 obj\n<< /Type /Catalog >>\nendobj\n4 0 obj\n".to_vec();
+    pdf.extend_from_slice(format!("<< {dict} /Length {} >>\nstream\n", data.len()).as_bytes());
+    pdf.extend_from_slice(data);
+    pdf.extend_from_slice(b"\nendstream\nendobj\ntrailer\n<< /Root 1 0 R >>\n%%EOF\n");
+    pdf
+}
+
+#[test]
+fn html_documents_keep_block_structure_and_decode_entities() {
+    let html = "<html><head><title>H.B. 100</title><style>p { x: 1 }</style></head>\
+        <body><p>SECTION&nbsp;1.&#160;A hemp beverage may contain</p>\
+        <p>no more than <u>10</u> <strike>5</strike> milligrams &amp; must be labeled.</p>\
+        <!-- page break --><div>SECTION 2. Effective date.</div></body></html>";
+    let text = extract_text("text/html; charset=utf-8", html.as_bytes()).expect("html text");
+    assert_eq!(
+        text,
+        "SECTION 1. A hemp beverage may contain\n\n\
+         no more than 10 5 milligrams & must be labeled.\n\n\
+         SECTION 2. Effective date."
+    );
+}
+
+#[test]
+fn pdf_text_operators_become_lines() {
+    let content = b"BT /F1 12 Tf 72 712 Td (SECTION 1. THC limit) Tj 0 -14 Td \
+        [(is )-250(5 mg per \\(one\\) serving.)] TJ T* <5345435449> Tj ( 2.) Tj ET";
+    let pdf = pdf_with_stream("", content);
+    let text = extract_text("application/pdf", &pdf).expect("pdf text");
+    assert_eq!(
+        text,
+        "SECTION 1. THC limit\nis 5 mg per (one) serving.\nSECTI 2."
+    );
+}
+
+#[test]
+fn pdf_flate_streams_are_inflated_and_fonts_skipped() {
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(b"BT 1 0 0 1 72 700 Tm (Enrolled text) Tj ET")
+        .expect("compress");
+    let compressed = encoder.finish().expect("finish");
+    let pdf = pdf_with_stream("/Filter /FlateDecode", &compressed);
+    assert_eq!(
+        extract_text("application/pdf", &pdf).expect("pdf text"),
+        "Enrolled text"
+    );
+
+    let font_only = pdf_with_stream("/Length1 10", b"BT (glyph data) Tj ET");
+    assert!(matches!(
+        extract_text("application/pdf", &font_only),
+        Err(LegiscanError::Document(_))
+    ));
+}
+
+#[test]
+fn pdf_streams_inflating_past_the_budget_are_errors() {
+    let content = b"BT (Enrolled text) Tj ET";
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(content).expect("compress");
+    let pdf = pdf_with_stream("/Filter /FlateDecode", &encoder.finish().expect("finish"));
+
+    let budget = content.len() as u64;
+    assert!(crate::pdf::pdf_text_with_limit(&pdf, budget).is_ok());
+    assert!(matches!(
+        crate::pdf::pdf_text_with_limit(&pdf, budget - 1),
+        Err(LegiscanError::Document(_))
+    ));
+}
+
+#[test]
+fn unsupported_and_undecodable_documents_are_errors() {
+    assert!(matches!(
+        extract_text("application/msword", b"\xd0\xcf\x11\xe0"),
+        Err(LegiscanError::Document(_))
+    ));
+    assert!(matches!(
+        decode_document("not base64!"),
+        Err(LegiscanError::Document(_))
+    ));
+    let doc = BillTextDocument {
+        doc_id: 1,
+        bill_id: 2,
+        date: None,
+        text_type: "Introduced".to_string(),
+        mime: "text/plain".to_string(),
+        doc: "U0VDVElPTiAx\nLiBUZXh0Lg==".to_string(),
+    };
+    assert_eq!(
+        extract_document_text(&doc).expect("plain text"),
+        "SECTION 1. Text."
+    );
+}
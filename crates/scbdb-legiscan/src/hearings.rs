@@ -0,0 +1,143 @@
+//! Normalization of `LegiScan` calendar entries into hearings suitable for
+//! database persistence.
+
+use chrono::{NaiveDate, NaiveTime};
+
+use crate::normalize::parse_date;
+use crate::types::BillDetail;
+
+/// A normalized hearing or calendar event ready for database persistence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedHearing {
+    pub hearing_date: NaiveDate,
+    pub hearing_time: Option<NaiveTime>,
+    /// `LegiScan` calendar type, e.g. `"Hearing"`, `"Executive Session"`.
+    pub event_type: String,
+    pub committee: Option<String>,
+    pub chamber: Option<String>,
+    pub location: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Converts the calendar entries of a [`BillDetail`] into
+/// [`NormalizedHearing`]s.
+///
+/// Calendar entries carry no committee of their own; hearings, executive and
+/// markup sessions (`type_id` 1–3) dated `today` or later are attributed to
+/// the committee currently holding the bill. Past entries keep no committee,
+/// since the bill may have moved on since. Entries with an unparseable date
+/// and exact duplicates are dropped.
+#[must_use]
+pub fn normalize_bill_hearings(detail: &BillDetail, today: NaiveDate) -> Vec<NormalizedHearing> {
+    let mut hearings: Vec<NormalizedHearing> = Vec::new();
+    for entry in &detail.calendar {
+        let Some(hearing_date) = parse_date(&entry.date) else {
+            continue;
+        };
+        let committee = detail
+            .committee
+            .as_ref()
+            .filter(|_| (1..=3).contains(&entry.type_id) && hearing_date >= today);
+        let hearing = NormalizedHearing {
+            hearing_date,
+            hearing_time: entry
+                .time
+                .as_deref()
+                .and_then(|t| NaiveTime::parse_from_str(t.trim(), "%H:%M").ok()),
+            event_type: non_empty(Some(&entry.event_type)).unwrap_or_else(|| "Calendar".into()),
+            committee: committee.map(|c| c.name.clone()),
+            chamber: committee.and_then(|c| c.chamber.clone()),
+            location: non_empty(entry.location.as_deref()),
+            description: non_empty(entry.description.as_deref()),
+        };
+        if !hearings.contains(&hearing) {
+            hearings.push(hearing);
+        }
+    }
+    hearings
+}
+
+fn non_empty(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, d).unwrap()
+    }
+
+    #[test]
+    fn hearings_take_committee_and_parse_times() {
+        let detail: BillDetail = serde_json::from_value(serde_json::json!({
+            "bill_id": 1,
+            "bill_number": "HB1",
+            "title": "Hemp",
+            "status": 1,
+            "state": "SC",
+            "committee": {"committee_id": 7, "chamber": "H", "name": "Judiciary"},
+            "calendar": [
+                {"type_id": 1, "type": "Hearing", "date": "2025-03-10", "time": "09:30",
+                 "location": "Room 101", "description": "House Judiciary Hearing"},
+                {"type_id": 1, "type": "Hearing", "date": "2025-03-10", "time": "09:30",
+                 "location": "Room 101", "description": "House Judiciary Hearing"},
+                {"type_id": 4, "type": "Floor", "date": "2025-03-20", "time": "",
+                 "location": "", "description": "Third Reading"},
+                {"type_id": 1, "type": "Hearing", "date": "TBD"}
+            ]
+        }))
+        .unwrap();
+
+        let hearings = normalize_bill_hearings(&detail, day(1));
+        assert_eq!(hearings.len(), 2);
+        assert_eq!(hearings[0].committee.as_deref(), Some("Judiciary"));
+        assert_eq!(hearings[0].hearing_time, NaiveTime::from_hms_opt(9, 30, 0));
+        assert_eq!(hearings[1].committee, None);
+        assert_eq!(hearings[1].hearing_time, None);
+        assert_eq!(hearings[1].location, None);
+    }
+
+    #[test]
+    fn empty_committee_array_deserializes_as_none() {
+        let detail: BillDetail = serde_json::from_value(serde_json::json!({
+            "bill_id": 1,
+            "bill_number": "HB1",
+            "title": "Hemp",
+            "status": 1,
+            "state": "SC",
+            "committee": [],
+            "calendar": [{"type_id": 1, "type": "Hearing", "date": "2025-03-10"}]
+        }))
+        .unwrap();
+
+        assert!(detail.committee.is_none());
+        assert_eq!(normalize_bill_hearings(&detail, day(1))[0].committee, None);
+    }
+
+    #[test]
+    fn past_hearings_get_no_committee() {
+        let detail: BillDetail = serde_json::from_value(serde_json::json!({
+            "bill_id": 1,
+            "bill_number": "HB1",
+            "title": "Hemp",
+            "status": 1,
+            "state": "SC",
+            "committee": {"committee_id": 9, "chamber": "S", "name": "Finance"},
+            "calendar": [
+                {"type_id": 1, "type": "Hearing", "date": "2025-03-10"},
+                {"type_id": 1, "type": "Hearing", "date": "2025-03-20"}
+            ]
+        }))
+        .unwrap();
+
+        let hearings = normalize_bill_hearings(&detail, day(15));
+        assert_eq!(hearings[0].committee, None);
+        assert_eq!(hearings[0].chamber, None);
+        assert_eq!(hearings[1].committee.as_deref(), Some("Finance"));
+    }
+}
@@ -0,0 +1,26 @@
+//! Shared request accounting for the `LegiScan` API client.
+//!
+//! [`LegiscanClient`](crate::LegiscanClient)'s `max_requests` only limits one
+//! process. A [`RequestLedger`] lets every process sharing an API key count
+//! against the same monthly budget; the client consults it before each
+//! request.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::error::LegiscanError;
+
+/// Future returned by [`RequestLedger::reserve`].
+pub type LedgerFuture<'a> = Pin<Box<dyn Future<Output = Result<(), LegiscanError>> + Send + 'a>>;
+
+/// A budget shared by every client using the same API key.
+pub trait RequestLedger: Send + Sync {
+    /// Counts one request against the budget.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LegiscanError::BudgetExceeded`] when the budget is spent, or
+    /// [`LegiscanError::Ledger`] when the ledger cannot be updated. The
+    /// request is not sent in either case.
+    fn reserve(&self) -> LedgerFuture<'_>;
+}
@@ -1,18 +1,36 @@
 //! `LegiScan` API client for SCBDB regulatory tracking.
 //!
 //! Provides a typed client for the [`LegiScan` API](https://legiscan.com/legiscan),
-//! along with domain-level normalization for persisting bill and event data.
+//! along with domain-level normalization for persisting bill, event, hearing,
+//! sponsor and roll call data and plain-text extraction from bill text
+//! documents.
 
 pub mod client;
+pub mod dataset;
 pub mod error;
+pub mod extract;
+pub mod hearings;
+pub mod ledger;
 pub mod normalize;
+pub(crate) mod pdf;
+pub(crate) mod people;
 pub(crate) mod retry;
 pub(crate) mod session;
 pub mod types;
+pub mod votes;
 
 pub use client::LegiscanClient;
+pub use dataset::{read_dataset, DatasetContents};
 pub use error::LegiscanError;
+pub use extract::{decode_document, extract_document_text, extract_text};
+pub use hearings::{normalize_bill_hearings, NormalizedHearing};
+pub use ledger::{LedgerFuture, RequestLedger};
 pub use normalize::{
     normalize_bill, normalize_bill_events, normalize_bill_texts, NormalizedBill,
     NormalizedBillEvent, NormalizedBillText,
 };
+pub use votes::{
+    normalize_bill_roll_calls, normalize_bill_sponsors, normalize_person,
+    normalize_roll_call_votes, NormalizedLegislator, NormalizedRollCall, NormalizedSponsor,
+    NormalizedVote,
+};
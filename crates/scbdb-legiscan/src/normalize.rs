@@ -191,6 +191,11 @@ mod tests {
             ],
             progress: vec![],
             texts: vec![],
+            sponsors: vec![],
+            votes: vec![],
+            committee: None,
+            calendar: vec![],
+            change_hash: None,
         };
 
         let normalized = normalize_bill(&detail);
@@ -226,6 +231,11 @@ mod tests {
             }],
             progress: vec![],
             texts: vec![],
+            sponsors: vec![],
+            votes: vec![],
+            committee: None,
+            calendar: vec![],
+            change_hash: None,
         };
 
         let events = normalize_bill_events(&detail);
@@ -264,6 +274,11 @@ mod tests {
                     url: None,
                 },
             ],
+            sponsors: vec![],
+            votes: vec![],
+            committee: None,
+            calendar: vec![],
+            change_hash: None,
         };
 
         let texts = normalize_bill_texts(&detail);
@@ -0,0 +1,376 @@
+//! Minimal PDF text extraction for bill documents.
+//!
+//! Legislature PDFs are overwhelmingly generated text with simple fonts, so
+//! a content-stream reader covers them without a full PDF library: every
+//! stream is inflated (when `FlateDecode`), and the strings shown by the
+//! `Tj`, `TJ`, `'` and `"` operators are collected, with line breaks on text
+//! moves. Fonts with custom CID encodings and scanned pages yield no usable
+//! text and are reported as errors.
+
+use std::io::Read as _;
+
+use flate2::read::ZlibDecoder;
+
+use crate::error::LegiscanError;
+
+/// `TJ` adjustments more negative than this (thousandths of an em) read as
+/// a word gap.
+const TJ_SPACE_THRESHOLD: f64 = -200.0;
+
+/// Most bytes all of a document's streams may inflate to; a small stream
+/// crafted to inflate far beyond it would otherwise exhaust memory.
+const MAX_INFLATED_BYTES: u64 = 64 * 1024 * 1024;
+
+pub(crate) fn pdf_text(bytes: &[u8]) -> Result<String, LegiscanError> {
+    pdf_text_with_limit(bytes, MAX_INFLATED_BYTES)
+}
+
+/// [`pdf_text`] with an explicit inflate budget.
+pub(crate) fn pdf_text_with_limit(
+    bytes: &[u8],
+    max_inflated: u64,
+) -> Result<String, LegiscanError> {
+    if !bytes.starts_with(b"%PDF") {
+        return Err(LegiscanError::Document(
+            "document is not a PDF (missing %PDF header)".to_string(),
+        ));
+    }
+    let mut text = String::new();
+    let mut budget = max_inflated;
+    for (dict, data) in streams(bytes) {
+        if !is_content_stream(dict) {
+            continue;
+        }
+        let decoded;
+        let content = if contains(dict, b"/FlateDecode") {
+            decoded = inflate(data, budget)?;
+            budget -= decoded.len() as u64;
+            decoded.as_slice()
+        } else if contains(dict, b"/Filter") {
+            continue;
+        } else {
+            data
+        };
+        let page = content_text(content);
+        if !page.trim().is_empty() {
+            text.push_str(&page);
+            text.push('\n');
+        }
+    }
+    if text.trim().is_empty() {
+        return Err(LegiscanError::Document(
+            "no extractable text in PDF (scanned pages or unsupported font encoding)".to_string(),
+        ));
+    }
+    Ok(text)
+}
+
+/// `(dictionary, raw data)` for every `stream … endstream` in the file.
+fn streams(bytes: &[u8]) -> Vec<(&[u8], &[u8])> {
+    let mut out = Vec::new();
+    let mut pos = 0;
+    while let Some(offset) = find(&bytes[pos..], b"stream") {
+        let start = pos + offset;
+        pos = start + b"stream".len();
+        if start >= 3 && &bytes[start - 3..start] == b"end" {
+            continue;
+        }
+        let data_start = match bytes.get(pos..pos + 2) {
+            Some(b"\r\n") => pos + 2,
+            Some([b'\n' | b'\r', _]) => pos + 1,
+            _ => continue,
+        };
+        let Some(len) = find(&bytes[data_start..], b"endstream") else {
+            break;
+        };
+        let dict_start = rfind(&bytes[..start], b"obj").map_or(0, |i| i + 3);
+        out.push((
+            &bytes[dict_start..start],
+            &bytes[data_start..data_start + len],
+        ));
+        pos = data_start + len + b"endstream".len();
+    }
+    out
+}
+
+/// Page and form content; skips images, fonts, xref and object streams.
+fn is_content_stream(dict: &[u8]) -> bool {
+    let skipped: [&[u8]; 7] = [
+        b"/Image",
+        b"/XRef",
+        b"/ObjStm",
+        b"/Length1",
+        b"/Length2",
+        b"/FontFile",
+        b"/Metadata",
+    ];
+    !skipped.iter().any(|needle| contains(dict, needle))
+}
+
+/// Inflate a `FlateDecode` stream of at most `limit` bytes.
+fn inflate(data: &[u8], limit: u64) -> Result<Vec<u8>, LegiscanError> {
+    let mut out = Vec::new();
+    // A truncated stream still yields whatever inflated before the error.
+    let _ = ZlibDecoder::new(data)
+        .take(limit.saturating_add(1))
+        .read_to_end(&mut out);
+    if out.len() as u64 > limit {
+        return Err(LegiscanError::Document(
+            "PDF streams inflate past the size limit".to_string(),
+        ));
+    }
+    Ok(out)
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).rposition(|w| w == needle)
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    find(haystack, needle).is_some()
+}
+
+/// Operand stack entries the text operators care about.
+enum Operand {
+    Number(f64),
+    Str(Vec<u8>),
+    Array(Vec<Operand>),
+    Other,
+}
+
+/// Text shown by one content stream.
+fn content_text(content: &[u8]) -> String {
+    let mut out = String::new();
+    let mut operands: Vec<Operand> = Vec::new();
+    let mut last_y: Option<f64> = None;
+    let mut pos = 0;
+    while pos < content.len() {
+        let (token, next) = next_token(content, pos);
+        pos = next;
+        let Some(token) = token else { continue };
+        let Token::Operator(op) = token else {
+            operands.push(token.into_operand());
+            continue;
+        };
+        match op.as_str() {
+            "Tj" => {
+                if let Some(Operand::Str(s)) = operands.last() {
+                    push_latin1(&mut out, s);
+                }
+            }
+            "'" | "\"" => {
+                out.push('\n');
+                if let Some(Operand::Str(s)) = operands.last() {
+                    push_latin1(&mut out, s);
+                }
+            }
+            "TJ" => {
+                if let Some(Operand::Array(items)) = operands.last() {
+                    for item in items {
+                        match item {
+                            Operand::Str(s) => push_latin1(&mut out, s),
+                            Operand::Number(n) if *n < TJ_SPACE_THRESHOLD => out.push(' '),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            "T*" => out.push('\n'),
+            "Td" | "TD" => {
+                let ty = number_at(&operands, 0);
+                out.push(if ty.abs() > 0.1 { '\n' } else { ' ' });
+            }
+            "Tm" => {
+                let y = number_at(&operands, 0);
+                if last_y.is_some_and(|prev| (prev - y).abs() > 0.1) {
+                    out.push('\n');
+                } else {
+                    out.push(' ');
+                }
+                last_y = Some(y);
+            }
+            "ET" => out.push(' '),
+            _ => {}
+        }
+        operands.clear();
+    }
+    out
+}
+
+/// The number `from_end` places from the top of the operand stack (0 = last).
+fn number_at(operands: &[Operand], from_end: usize) -> f64 {
+    operands
+        .len()
+        .checked_sub(from_end + 1)
+        .and_then(|i| match operands[i] {
+            Operand::Number(n) => Some(n),
+            _ => None,
+        })
+        .unwrap_or(0.0)
+}
+
+/// Single-byte strings read as Latin-1, close to the `WinAnsi`/`PDFDoc`
+/// encodings simple fonts use.
+fn push_latin1(out: &mut String, bytes: &[u8]) {
+    out.extend(bytes.iter().map(|&b| char::from(b)));
+}
+
+enum Token {
+    Number(f64),
+    Str(Vec<u8>),
+    Array(Vec<Operand>),
+    Operator(String),
+    Other,
+}
+
+impl Token {
+    fn into_operand(self) -> Operand {
+        match self {
+            Token::Number(n) => Operand::Number(n),
+            Token::Str(s) => Operand::Str(s),
+            Token::Array(items) => Operand::Array(items),
+            Token::Operator(_) | Token::Other => Operand::Other,
+        }
+    }
+}
+
+/// Read the token starting at or after `pos`; returns it and the position
+/// after it. `None` for whitespace, comments and dictionary delimiters.
+fn next_token(content: &[u8], pos: usize) -> (Option<Token>, usize) {
+    let c = content[pos];
+    match c {
+        b'(' => {
+            let (s, end) = literal_string(content, pos + 1);
+            (Some(Token::Str(s)), end)
+        }
+        b'<' if content.get(pos + 1) == Some(&b'<') => (None, pos + 2),
+        b'>' if content.get(pos + 1) == Some(&b'>') => (None, pos + 2),
+        b'<' => {
+            let end = content[pos..]
+                .iter()
+                .position(|&b| b == b'>')
+                .map_or(content.len(), |i| pos + i);
+            (
+                Some(Token::Str(hex_string(&content[pos + 1..end]))),
+                end + 1,
+            )
+        }
+        b'[' => {
+            let mut items = Vec::new();
+            let mut p = pos + 1;
+            while p < content.len() && content[p] != b']' {
+                let (token, next) = next_token(content, p);
+                p = next;
+                if let Some(token) = token {
+                    items.push(token.into_operand());
+                }
+            }
+            (Some(Token::Array(items)), p + 1)
+        }
+        b'%' => {
+            let end = content[pos..]
+                .iter()
+                .position(|&b| b == b'\n' || b == b'\r')
+                .map_or(content.len(), |i| pos + i);
+            (None, end)
+        }
+        b'/' => {
+            let end = scan_regular(content, pos + 1);
+            (Some(Token::Other), end)
+        }
+        _ if c.is_ascii_whitespace() || matches!(c, b']' | b')' | b'>' | b'{' | b'}') => {
+            (None, pos + 1)
+        }
+        _ => {
+            let end = scan_regular(content, pos + 1);
+            let word = String::from_utf8_lossy(&content[pos..end]).into_owned();
+            let token = match word.parse::<f64>() {
+                Ok(n) => Token::Number(n),
+                Err(_) => Token::Operator(word),
+            };
+            (Some(token), end)
+        }
+    }
+}
+
+/// End of a run of regular (non-whitespace, non-delimiter) bytes.
+fn scan_regular(content: &[u8], mut pos: usize) -> usize {
+    while pos < content.len()
+        && !content[pos].is_ascii_whitespace()
+        && !b"()<>[]{}/%".contains(&content[pos])
+    {
+        pos += 1;
+    }
+    pos
+}
+
+/// Body of a `( … )` string starting after the open paren, with escapes
+/// resolved and balanced inner parens kept.
+fn literal_string(content: &[u8], mut pos: usize) -> (Vec<u8>, usize) {
+    let mut out = Vec::new();
+    let mut depth = 1;
+    while pos < content.len() {
+        let c = content[pos];
+        pos += 1;
+        match c {
+            b'\\' => {
+                let Some(&e) = content.get(pos) else { break };
+                pos += 1;
+                match e {
+                    b'n' => out.push(b'\n'),
+                    b'r' => out.push(b'\r'),
+                    b't' => out.push(b'\t'),
+                    b'b' | b'f' => {}
+                    b'0'..=b'7' => {
+                        let mut value = u32::from(e - b'0');
+                        for _ in 0..2 {
+                            match content.get(pos) {
+                                Some(&d @ b'0'..=b'7') => {
+                                    value = value * 8 + u32::from(d - b'0');
+                                    pos += 1;
+                                }
+                                _ => break,
+                            }
+                        }
+                        out.push(u8::try_from(value & 0xFF).unwrap_or(b'?'));
+                    }
+                    b'\r' | b'\n' => {
+                        if e == b'\r' && content.get(pos) == Some(&b'\n') {
+                            pos += 1;
+                        }
+                    }
+                    other => out.push(other),
+                }
+            }
+            b'(' => {
+                depth += 1;
+                out.push(c);
+            }
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+                out.push(c);
+            }
+            _ => out.push(c),
+        }
+    }
+    (out, pos)
+}
+
+fn hex_string(hex: &[u8]) -> Vec<u8> {
+    let digits: Vec<u8> = hex
+        .iter()
+        .filter_map(|&b| char::from(b).to_digit(16))
+        .map(|d| u8::try_from(d).unwrap_or(0))
+        .collect();
+    digits
+        .chunks(2)
+        .map(|pair| pair[0] * 16 + pair.get(1).copied().unwrap_or(0))
+        .collect()
+}
@@ -0,0 +1,45 @@
+//! Roll call and legislator endpoints for the `LegiScan` API client.
+
+use crate::client::LegiscanClient;
+use crate::error::LegiscanError;
+use crate::types::{ApiResponse, Person, PersonResponse, RollCall, RollCallResponse};
+
+impl LegiscanClient {
+    /// Fetches a roll call with every legislator's vote.
+    ///
+    /// # Errors
+    ///
+    /// - [`LegiscanError::BudgetExceeded`] if the session budget is reached.
+    /// - [`LegiscanError::QuotaExceeded`] if `LegiScan`'s quota is exhausted.
+    /// - [`LegiscanError::ApiError`] on API-level failure.
+    /// - [`LegiscanError::Http`] on network failure.
+    /// - [`LegiscanError::Deserialize`] if the response shape is unexpected.
+    pub async fn get_roll_call(&self, roll_call_id: i64) -> Result<RollCall, LegiscanError> {
+        let url = self.build_url("getRollCall", &[("id", &roll_call_id.to_string())]);
+        let body = self.request_json(&url).await?;
+        Self::check_api_error(&body)?;
+        let envelope: ApiResponse<RollCallResponse> =
+            serde_json::from_value(body).map_err(|e| LegiscanError::Deserialize {
+                context: format!("getRollCall(id={roll_call_id})"),
+                source: e,
+            })?;
+        Ok(envelope.data.roll_call)
+    }
+
+    /// Fetches a legislator by `LegiScan` `people_id`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::get_roll_call`].
+    pub async fn get_person(&self, people_id: i64) -> Result<Person, LegiscanError> {
+        let url = self.build_url("getPerson", &[("id", &people_id.to_string())]);
+        let body = self.request_json(&url).await?;
+        Self::check_api_error(&body)?;
+        let envelope: ApiResponse<PersonResponse> =
+            serde_json::from_value(body).map_err(|e| LegiscanError::Deserialize {
+                context: format!("getPerson(id={people_id})"),
+                source: e,
+            })?;
+        Ok(envelope.data.person)
+    }
+}
@@ -21,6 +21,9 @@ use crate::error::LegiscanError;
 /// - [`LegiscanError::QuotaExceeded`] — API monthly/daily quota; stop immediately.
 /// - [`LegiscanError::ApiError`] — application-level error; retrying won't fix it.
 /// - [`LegiscanError::Deserialize`] — malformed response; retrying won't fix it.
+/// - [`LegiscanError::Document`] — undecodable document; retrying won't fix it.
+/// - [`LegiscanError::Dataset`] — unreadable dataset archive; retrying won't fix it.
+/// - [`LegiscanError::Ledger`] — request ledger unavailable; checked before any retry.
 pub(crate) fn is_retriable(err: &LegiscanError) -> bool {
     match err {
         LegiscanError::Http(e) => {
@@ -29,7 +32,10 @@ pub(crate) fn is_retriable(err: &LegiscanError) -> bool {
         LegiscanError::BudgetExceeded { .. }
         | LegiscanError::QuotaExceeded(_)
         | LegiscanError::ApiError(_)
-        | LegiscanError::Deserialize { .. } => false,
+        | LegiscanError::Deserialize { .. }
+        | LegiscanError::Document(_)
+        | LegiscanError::Dataset(_)
+        | LegiscanError::Ledger(_) => false,
     }
 }
 
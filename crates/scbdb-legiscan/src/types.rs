@@ -37,6 +37,27 @@ mod de_opt_int {
     }
 }
 
+/// Deserializes the `committee` field of `getBill`, which the `LegiScan` API
+/// returns as an object when the bill sits in a committee and as an empty
+/// array (`[]`) otherwise.
+mod de_committee {
+    use serde::{Deserialize, Deserializer};
+
+    use super::BillCommittee;
+
+    pub(super) fn deserialize<'de, D>(d: D) -> Result<Option<BillCommittee>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<serde_json::Value>::deserialize(d)? {
+            Some(value) if value.is_object() => serde_json::from_value(value)
+                .map(Some)
+                .map_err(serde::de::Error::custom),
+            _ => Ok(None),
+        }
+    }
+}
+
 /// Top-level envelope for all `LegiScan` API responses.
 ///
 /// The `status` field is `"OK"` on success or `"ERROR"` on failure.
@@ -83,6 +104,98 @@ pub struct BillDetail {
     /// Versioned text links (Introduced, Engrossed, etc.) returned by `getBill`.
     #[serde(default)]
     pub texts: Vec<BillTextEntry>,
+    #[serde(default)]
+    pub sponsors: Vec<BillSponsor>,
+    /// Roll call summaries; individual votes come from `getRollCall`.
+    #[serde(default)]
+    pub votes: Vec<BillVoteSummary>,
+    /// Committee currently holding the bill, if any.
+    #[serde(default, deserialize_with = "de_committee::deserialize")]
+    pub committee: Option<BillCommittee>,
+    /// Scheduled hearings and floor calendar entries.
+    #[serde(default)]
+    pub calendar: Vec<BillCalendarEntry>,
+    /// Same hash as the master list entry; changes whenever the bill does.
+    #[serde(default)]
+    pub change_hash: Option<String>,
+}
+
+/// The committee a bill is referred to, inside `getBill`.
+#[derive(Debug, Deserialize)]
+pub struct BillCommittee {
+    pub committee_id: i64,
+    #[serde(default)]
+    pub chamber: Option<String>,
+    pub name: String,
+}
+
+/// A calendar entry inside `getBill`: a committee hearing, executive
+/// session or floor calendar placement.
+#[derive(Debug, Deserialize)]
+pub struct BillCalendarEntry {
+    /// 1 = hearing, 2 = executive session, 3 = markup session.
+    #[serde(default)]
+    pub type_id: i32,
+    #[serde(default, rename = "type")]
+    pub event_type: String,
+    pub date: String,
+    /// `"HH:MM"`, or empty when no time is set.
+    #[serde(default)]
+    pub time: Option<String>,
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// A sponsor listed inside `getBill`.
+///
+/// `sponsor_type_id`: 1 = primary, 2 = co-sponsor, 3 = joint. Committee
+/// sponsors carry `people_id = 0`.
+#[derive(Debug, Deserialize)]
+pub struct BillSponsor {
+    pub people_id: i64,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub last_name: Option<String>,
+    #[serde(default)]
+    pub party: Option<String>,
+    #[serde(default)]
+    pub role: Option<String>,
+    #[serde(default)]
+    pub district: Option<String>,
+    #[serde(default)]
+    pub sponsor_type_id: i32,
+    #[serde(default)]
+    pub sponsor_order: i32,
+}
+
+/// A roll call summary listed inside `getBill`.
+#[derive(Debug, Deserialize)]
+pub struct BillVoteSummary {
+    pub roll_call_id: i64,
+    #[serde(default)]
+    pub date: Option<String>,
+    #[serde(default)]
+    pub desc: String,
+    #[serde(default)]
+    pub yea: i32,
+    #[serde(default)]
+    pub nay: i32,
+    #[serde(default)]
+    pub nv: i32,
+    #[serde(default)]
+    pub absent: i32,
+    #[serde(default)]
+    pub total: i32,
+    /// `1` when the motion passed.
+    #[serde(default)]
+    pub passed: i32,
+    #[serde(default)]
+    pub chamber: Option<String>,
 }
 
 /// A versioned text entry for a bill, returned inside `getBill`.
@@ -127,6 +240,153 @@ pub struct BillProgress {
     pub event: i32,
 }
 
+// ---------------------------------------------------------------------------
+// getBillText
+// ---------------------------------------------------------------------------
+
+/// Wrapper for the `getBillText` response: `{ "text": { ... } }`.
+#[derive(Debug, Deserialize)]
+pub struct BillTextResponse {
+    pub text: BillTextDocument,
+}
+
+/// One bill text version with its document, returned by `getBillText`.
+///
+/// `doc` is the base64-encoded document (HTML, PDF, …); see
+/// [`crate::extract_text`] to turn it into plain text.
+#[derive(Debug, Deserialize)]
+pub struct BillTextDocument {
+    pub doc_id: i64,
+    pub bill_id: i64,
+    #[serde(default)]
+    pub date: Option<String>,
+    #[serde(rename = "type")]
+    pub text_type: String,
+    pub mime: String,
+    pub doc: String,
+}
+
+// ---------------------------------------------------------------------------
+// getRollCall / getPerson
+// ---------------------------------------------------------------------------
+
+/// Wrapper for the `getRollCall` response: `{ "roll_call": { ... } }`.
+#[derive(Debug, Deserialize)]
+pub struct RollCallResponse {
+    pub roll_call: RollCall,
+}
+
+/// A roll call with every legislator's vote.
+#[derive(Debug, Deserialize)]
+pub struct RollCall {
+    pub roll_call_id: i64,
+    pub bill_id: i64,
+    #[serde(default)]
+    pub date: Option<String>,
+    #[serde(default)]
+    pub desc: String,
+    #[serde(default)]
+    pub yea: i32,
+    #[serde(default)]
+    pub nay: i32,
+    #[serde(default)]
+    pub nv: i32,
+    #[serde(default)]
+    pub absent: i32,
+    #[serde(default)]
+    pub total: i32,
+    #[serde(default)]
+    pub passed: i32,
+    #[serde(default)]
+    pub chamber: Option<String>,
+    #[serde(default)]
+    pub votes: Vec<RollCallVote>,
+}
+
+/// One legislator's vote: `vote_id` 1 = yea, 2 = nay, 3 = not voting,
+/// 4 = absent.
+#[derive(Debug, Deserialize)]
+pub struct RollCallVote {
+    pub people_id: i64,
+    pub vote_id: i32,
+    #[serde(default)]
+    pub vote_text: String,
+}
+
+/// Wrapper for the `getPerson` response: `{ "person": { ... } }`.
+#[derive(Debug, Deserialize)]
+pub struct PersonResponse {
+    pub person: Person,
+}
+
+/// A legislator returned by `getPerson`.
+#[derive(Debug, Deserialize)]
+pub struct Person {
+    pub people_id: i64,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub last_name: Option<String>,
+    #[serde(default)]
+    pub party: Option<String>,
+    #[serde(default)]
+    pub role: Option<String>,
+    #[serde(default)]
+    pub district: Option<String>,
+}
+
+// ---------------------------------------------------------------------------
+// getDatasetList / getDataset
+// ---------------------------------------------------------------------------
+
+/// Wrapper for the `getDatasetList` response: `{ "datasetlist": [ ... ] }`.
+#[derive(Debug, Deserialize)]
+pub struct DatasetListResponse {
+    pub datasetlist: Vec<DatasetInfo>,
+}
+
+/// A weekly bulk dataset of one session.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DatasetInfo {
+    pub state_id: i64,
+    pub session_id: i64,
+    #[serde(default)]
+    pub year_start: i32,
+    #[serde(default)]
+    pub year_end: i32,
+    #[serde(default)]
+    pub session_name: String,
+    /// Changes whenever the dataset is rebuilt with new data.
+    pub dataset_hash: String,
+    #[serde(default)]
+    pub dataset_date: Option<String>,
+    #[serde(default)]
+    pub dataset_size: i64,
+    /// Required by `getDataset`.
+    pub access_key: String,
+}
+
+/// Wrapper for the `getDataset` response: `{ "dataset": { ... } }`.
+#[derive(Debug, Deserialize)]
+pub struct DatasetResponse {
+    pub dataset: DatasetArchive,
+}
+
+/// A downloaded dataset: a base64-encoded ZIP of per-bill, per-roll-call and
+/// per-person JSON files.
+#[derive(Debug, Deserialize)]
+pub struct DatasetArchive {
+    pub session_id: i64,
+    pub dataset_hash: String,
+    #[serde(default)]
+    pub dataset_date: Option<String>,
+    #[serde(default)]
+    pub mime: String,
+    pub zip: String,
+}
+
 // ---------------------------------------------------------------------------
 // search
 // ---------------------------------------------------------------------------
@@ -0,0 +1,221 @@
+//! Normalization of `LegiScan` sponsor, roll call and legislator data into
+//! domain types suitable for database persistence.
+
+use chrono::NaiveDate;
+
+use crate::normalize::parse_date;
+use crate::types::{BillDetail, Person, RollCall};
+
+/// A normalized legislator ready for database persistence.
+#[derive(Debug, Clone)]
+pub struct NormalizedLegislator {
+    pub legiscan_people_id: i64,
+    pub name: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub party: Option<String>,
+    pub role: Option<String>,
+    pub district: Option<String>,
+}
+
+/// A normalized bill sponsor ready for database persistence.
+#[derive(Debug, Clone)]
+pub struct NormalizedSponsor {
+    pub legislator: NormalizedLegislator,
+    /// `"primary"`, `"cosponsor"` or `"joint"`.
+    pub sponsor_type: &'static str,
+    pub sponsor_order: i32,
+}
+
+/// A normalized roll call summary ready for database persistence.
+#[derive(Debug, Clone)]
+pub struct NormalizedRollCall {
+    pub legiscan_roll_call_id: i64,
+    pub vote_date: Option<NaiveDate>,
+    pub chamber: Option<String>,
+    pub description: String,
+    pub yea: i32,
+    pub nay: i32,
+    pub nv: i32,
+    pub absent: i32,
+    pub total: i32,
+    pub passed: bool,
+}
+
+/// One legislator's normalized vote on a roll call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedVote {
+    pub legiscan_people_id: i64,
+    /// `"yea"`, `"nay"`, `"nv"` or `"absent"`.
+    pub vote: &'static str,
+}
+
+/// Maps a `LegiScan` `sponsor_type_id` to the stored sponsor type.
+#[must_use]
+pub fn map_sponsor_type(sponsor_type_id: i32) -> &'static str {
+    match sponsor_type_id {
+        1 => "primary",
+        3 => "joint",
+        _ => "cosponsor",
+    }
+}
+
+/// Maps a `LegiScan` `vote_id` to the stored vote. Unknown codes count as
+/// not voting.
+#[must_use]
+pub fn map_vote(vote_id: i32) -> &'static str {
+    match vote_id {
+        1 => "yea",
+        2 => "nay",
+        4 => "absent",
+        _ => "nv",
+    }
+}
+
+/// Converts a [`Person`] from `getPerson` into a [`NormalizedLegislator`].
+#[must_use]
+pub fn normalize_person(person: &Person) -> NormalizedLegislator {
+    NormalizedLegislator {
+        legiscan_people_id: person.people_id,
+        name: person.name.clone(),
+        first_name: person.first_name.clone(),
+        last_name: person.last_name.clone(),
+        party: person.party.clone(),
+        role: person.role.clone(),
+        district: person.district.clone(),
+    }
+}
+
+/// Converts the sponsors of a [`BillDetail`] into [`NormalizedSponsor`]s.
+///
+/// Committee sponsors (`people_id = 0`) are skipped; they are not legislators.
+#[must_use]
+pub fn normalize_bill_sponsors(detail: &BillDetail) -> Vec<NormalizedSponsor> {
+    detail
+        .sponsors
+        .iter()
+        .filter(|s| s.people_id > 0)
+        .map(|s| NormalizedSponsor {
+            legislator: NormalizedLegislator {
+                legiscan_people_id: s.people_id,
+                name: s.name.clone(),
+                first_name: s.first_name.clone(),
+                last_name: s.last_name.clone(),
+                party: s.party.clone(),
+                role: s.role.clone(),
+                district: s.district.clone(),
+            },
+            sponsor_type: map_sponsor_type(s.sponsor_type_id),
+            sponsor_order: s.sponsor_order,
+        })
+        .collect()
+}
+
+/// Converts the roll call summaries of a [`BillDetail`] into
+/// [`NormalizedRollCall`]s.
+#[must_use]
+pub fn normalize_bill_roll_calls(detail: &BillDetail) -> Vec<NormalizedRollCall> {
+    detail
+        .votes
+        .iter()
+        .map(|v| NormalizedRollCall {
+            legiscan_roll_call_id: v.roll_call_id,
+            vote_date: v.date.as_deref().and_then(parse_date),
+            chamber: v.chamber.clone(),
+            description: v.desc.clone(),
+            yea: v.yea,
+            nay: v.nay,
+            nv: v.nv,
+            absent: v.absent,
+            total: v.total,
+            passed: v.passed == 1,
+        })
+        .collect()
+}
+
+/// Converts the individual votes of a [`RollCall`] into [`NormalizedVote`]s.
+#[must_use]
+pub fn normalize_roll_call_votes(roll_call: &RollCall) -> Vec<NormalizedVote> {
+    roll_call
+        .votes
+        .iter()
+        .filter(|v| v.people_id > 0)
+        .map(|v| NormalizedVote {
+            legiscan_people_id: v.people_id,
+            vote: map_vote(v.vote_id),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail_from_json(value: serde_json::Value) -> BillDetail {
+        serde_json::from_value(value).expect("valid bill detail")
+    }
+
+    #[test]
+    fn sponsors_skip_committees_and_map_types() {
+        let detail = detail_from_json(serde_json::json!({
+            "bill_id": 1,
+            "bill_number": "HB1",
+            "title": "Hemp",
+            "status": 1,
+            "state": "SC",
+            "sponsors": [
+                {"people_id": 10, "name": "Jane Doe", "party": "R", "sponsor_type_id": 1, "sponsor_order": 1},
+                {"people_id": 11, "name": "John Roe", "party": "D", "sponsor_type_id": 2, "sponsor_order": 2},
+                {"people_id": 0, "name": "Judiciary Committee", "sponsor_type_id": 1, "sponsor_order": 3}
+            ]
+        }));
+
+        let sponsors = normalize_bill_sponsors(&detail);
+        assert_eq!(sponsors.len(), 2);
+        assert_eq!(sponsors[0].sponsor_type, "primary");
+        assert_eq!(sponsors[0].legislator.party.as_deref(), Some("R"));
+        assert_eq!(sponsors[1].sponsor_type, "cosponsor");
+    }
+
+    #[test]
+    fn roll_call_summaries_parse_dates_and_passage() {
+        let detail = detail_from_json(serde_json::json!({
+            "bill_id": 1,
+            "bill_number": "HB1",
+            "title": "Hemp",
+            "status": 1,
+            "state": "SC",
+            "votes": [
+                {"roll_call_id": 500, "date": "2025-03-04", "desc": "Third Reading",
+                 "yea": 80, "nay": 20, "nv": 1, "absent": 3, "total": 104, "passed": 1, "chamber": "H"}
+            ]
+        }));
+
+        let calls = normalize_bill_roll_calls(&detail);
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].passed);
+        assert_eq!(calls[0].vote_date, NaiveDate::from_ymd_opt(2025, 3, 4));
+        assert_eq!(calls[0].yea, 80);
+    }
+
+    #[test]
+    fn roll_call_votes_map_vote_codes() {
+        let roll_call: RollCall = serde_json::from_value(serde_json::json!({
+            "roll_call_id": 500,
+            "bill_id": 1,
+            "votes": [
+                {"people_id": 10, "vote_id": 1, "vote_text": "Yea"},
+                {"people_id": 11, "vote_id": 2, "vote_text": "Nay"},
+                {"people_id": 12, "vote_id": 3, "vote_text": "NV"},
+                {"people_id": 13, "vote_id": 4, "vote_text": "Absent"}
+            ]
+        }))
+        .unwrap();
+
+        let votes: Vec<&str> = normalize_roll_call_votes(&roll_call)
+            .iter()
+            .map(|v| v.vote)
+            .collect();
+        assert_eq!(votes, ["yea", "nay", "nv", "absent"]);
+    }
+}
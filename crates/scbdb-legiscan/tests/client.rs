@@ -49,6 +49,11 @@ async fn get_bill_returns_parsed_bill() {
             ],
             "progress": [
                 { "date": "2025-01-10", "event": 1 }
+            ],
+            "committee": { "committee_id": 7, "chamber": "H", "name": "Judiciary" },
+            "calendar": [
+                { "type_id": 1, "type": "Hearing", "date": "2025-01-20", "time": "10:00",
+                  "location": "Room 101", "description": "House Judiciary Hearing" }
             ]
         }
     });
@@ -72,6 +77,49 @@ async fn get_bill_returns_parsed_bill() {
     assert_eq!(bill.status, 1);
     assert_eq!(bill.history.len(), 1);
     assert_eq!(bill.history[0].action, "Introduced");
+    assert_eq!(bill.committee.map(|c| c.name).as_deref(), Some("Judiciary"));
+    assert_eq!(bill.calendar.len(), 1);
+}
+
+#[tokio::test]
+async fn get_bill_text_returns_decodable_document() {
+    let server = MockServer::start().await;
+
+    // "<p>SECTION 1. Limit is 10 mg.</p>" base64-encoded.
+    let body = serde_json::json!({
+        "status": "OK",
+        "text": {
+            "doc_id": 2_929_001,
+            "bill_id": 12345,
+            "date": "2025-03-02",
+            "type": "Amended",
+            "type_id": 3,
+            "mime": "text/html",
+            "mime_id": 1,
+            "doc": "PHA+U0VDVElPTiAxLiBMaW1pdCBpcyAxMCBtZy48L3A+"
+        }
+    });
+
+    Mock::given(method("GET"))
+        .and(query_param("op", "getBillText"))
+        .and(query_param("id", "2929001"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&body))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = test_client(&server.uri());
+    let doc = client
+        .get_bill_text(2_929_001)
+        .await
+        .expect("should parse bill text");
+
+    assert_eq!(doc.doc_id, 2_929_001);
+    assert_eq!(doc.text_type, "Amended");
+    assert_eq!(
+        scbdb_legiscan::extract_document_text(&doc).expect("extract"),
+        "SECTION 1. Limit is 10 mg."
+    );
 }
 
 #[tokio::test]
@@ -220,6 +268,192 @@ async fn get_master_list_parses_bill_entries() {
     assert!(ids.contains(&101));
 }
 
+#[tokio::test]
+async fn get_roll_call_and_person_parse_votes_and_legislator() {
+    let server = MockServer::start().await;
+
+    let roll_call = serde_json::json!({
+        "status": "OK",
+        "roll_call": {
+            "roll_call_id": 500,
+            "bill_id": 12345,
+            "date": "2025-03-04",
+            "desc": "Third Reading",
+            "yea": 1,
+            "nay": 1,
+            "nv": 0,
+            "absent": 0,
+            "total": 2,
+            "passed": 0,
+            "chamber": "H",
+            "votes": [
+                { "people_id": 10, "vote_id": 1, "vote_text": "Yea" },
+                { "people_id": 11, "vote_id": 2, "vote_text": "Nay" }
+            ]
+        }
+    });
+    let person = serde_json::json!({
+        "status": "OK",
+        "person": {
+            "people_id": 11,
+            "name": "John Roe",
+            "first_name": "John",
+            "last_name": "Roe",
+            "party": "D",
+            "role": "Rep",
+            "district": "HD-012"
+        }
+    });
+
+    Mock::given(method("GET"))
+        .and(query_param("op", "getRollCall"))
+        .and(query_param("id", "500"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&roll_call))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(query_param("op", "getPerson"))
+        .and(query_param("id", "11"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&person))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = test_client(&server.uri());
+    let roll_call = client
+        .get_roll_call(500)
+        .await
+        .expect("should parse roll call");
+    assert_eq!(roll_call.bill_id, 12345);
+    assert_eq!(roll_call.votes.len(), 2);
+    assert_eq!(roll_call.votes[1].vote_id, 2);
+
+    let person = client.get_person(11).await.expect("should parse person");
+    assert_eq!(person.name, "John Roe");
+    assert_eq!(person.district.as_deref(), Some("HD-012"));
+}
+
+#[tokio::test]
+async fn get_dataset_list_and_dataset_pass_access_key() {
+    let server = MockServer::start().await;
+
+    let list = serde_json::json!({
+        "status": "OK",
+        "datasetlist": [{
+            "state_id": 40,
+            "session_id": 2100,
+            "special": 0,
+            "year_start": 2025,
+            "year_end": 2026,
+            "session_name": "126th General Assembly",
+            "session_title": "126th General Assembly",
+            "dataset_hash": "d41d8cd9",
+            "dataset_date": "2025-03-02",
+            "dataset_size": 1024,
+            "access_key": "secret-key"
+        }]
+    });
+    let dataset = serde_json::json!({
+        "status": "OK",
+        "dataset": {
+            "state_id": 40,
+            "session_id": 2100,
+            "dataset_hash": "d41d8cd9",
+            "dataset_date": "2025-03-02",
+            "dataset_size": 1024,
+            "mime": "application/zip",
+            "zip": "UEsFBgAAAAAAAAAAAAAAAAAAAAAAAA=="
+        }
+    });
+
+    Mock::given(method("GET"))
+        .and(query_param("op", "getDatasetList"))
+        .and(query_param("state", "SC"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&list))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(query_param("op", "getDataset"))
+        .and(query_param("id", "2100"))
+        .and(query_param("access_key", "secret-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&dataset))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = test_client(&server.uri());
+    let datasets = client
+        .get_dataset_list(Some("sc"))
+        .await
+        .expect("should parse dataset list");
+    assert_eq!(datasets.len(), 1);
+    assert_eq!(datasets[0].dataset_hash, "d41d8cd9");
+
+    let archive = client
+        .get_dataset(datasets[0].session_id, &datasets[0].access_key)
+        .await
+        .expect("should parse dataset");
+    let contents = scbdb_legiscan::read_dataset(&archive).expect("empty zip is readable");
+    assert!(contents.bills.is_empty());
+    assert_eq!(client.requests_used(), 2);
+}
+
+/// Ledger allowing `limit` requests in total.
+struct CountingLedger {
+    used: std::sync::atomic::AtomicU32,
+    limit: u32,
+}
+
+impl scbdb_legiscan::RequestLedger for CountingLedger {
+    fn reserve(&self) -> scbdb_legiscan::LedgerFuture<'_> {
+        Box::pin(async move {
+            let used = self.used.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            if used >= self.limit {
+                return Err(scbdb_legiscan::LegiscanError::BudgetExceeded {
+                    used,
+                    limit: self.limit,
+                });
+            }
+            Ok(())
+        })
+    }
+}
+
+#[tokio::test]
+async fn ledger_refusal_sends_no_request() {
+    let server = MockServer::start().await;
+
+    let body = serde_json::json!({
+        "status": "OK",
+        "bill": {"bill_id": 1, "bill_number": "H1", "title": "Hemp", "status": 1, "state": "SC"}
+    });
+    Mock::given(method("GET"))
+        .and(query_param("op", "getBill"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&body))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let ledger = std::sync::Arc::new(CountingLedger {
+        used: std::sync::atomic::AtomicU32::new(0),
+        limit: 1,
+    });
+    let client = test_client(&server.uri()).with_ledger(ledger);
+
+    client
+        .get_bill(1)
+        .await
+        .expect("first request is within budget");
+    let refused = client.get_bill(1).await;
+    assert!(matches!(
+        refused,
+        Err(scbdb_legiscan::LegiscanError::BudgetExceeded { used: 1, limit: 1 })
+    ));
+    assert_eq!(client.requests_used(), 1);
+}
+
 #[tokio::test]
 async fn api_error_response_returns_err() {
     let server = MockServer::start().await;
@@ -0,0 +1,151 @@
+//! Brand discovery: harvest the names of brands we do not track yet.
+//!
+//! Three kinds of source feed the candidate queue:
+//!
+//! - news and sentiment text, where a name sits next to a category term
+//!   ("Cann THC seltzer", "hemp sodas from Wynk", "brands like Cann and Wynk")
+//! - multi-brand locator payloads (`BeverageFinder`, `AskHoodie`), whose stores
+//!   list the brands they carry
+//! - retailer product pages (JSON-LD `brand`, `product:brand` meta, Shopify
+//!   `vendor`)
+//!
+//! Everything here is pure parsing; deduplication against `brands` and the
+//! evidence bookkeeping live in the database layer.
+
+mod payload;
+mod text;
+
+pub use payload::{brand_names_in_locator_payload, brand_names_in_retailer_page};
+pub use text::{brand_mentions_in_text, BrandMention};
+
+/// Words that are never a brand name on their own.
+const GENERIC_WORDS: &[&str] = &[
+    "a",
+    "all",
+    "an",
+    "and",
+    "assorted",
+    "beverage",
+    "beverages",
+    "best",
+    "brand",
+    "brands",
+    "cannabis",
+    "cbd",
+    "delta",
+    "delta-8",
+    "delta-9",
+    "drink",
+    "drinks",
+    "hemp",
+    "infused",
+    "multiple",
+    "n/a",
+    "new",
+    "non-alcoholic",
+    "other",
+    "our",
+    "seltzer",
+    "seltzers",
+    "soda",
+    "sodas",
+    "sparkling",
+    "thc",
+    "the",
+    "these",
+    "this",
+    "tonic",
+    "top",
+    "unknown",
+    "various",
+    "water",
+];
+
+/// Longest candidate name kept, in characters.
+const MAX_NAME_CHARS: usize = 40;
+
+/// Most words in a candidate name.
+const MAX_NAME_WORDS: usize = 4;
+
+fn is_generic(word: &str) -> bool {
+    GENERIC_WORDS.contains(&word.to_lowercase().as_str())
+}
+
+/// Tidy a harvested name, or `None` when it cannot plausibly be a brand.
+///
+/// Strips trademark symbols, quotes, surrounding punctuation and a trailing
+/// possessive, drops leading generic words ("The", "New") and collapses
+/// whitespace. Names that are empty, all generic, longer than 40 characters
+/// or four words, or have no letters are rejected.
+#[must_use]
+pub fn clean_brand_name(raw: &str) -> Option<String> {
+    let stripped: String = raw
+        .chars()
+        .filter(|c| !matches!(c, '™' | '®' | '©'))
+        .map(|c| {
+            if matches!(c, '“' | '”' | '"') {
+                ' '
+            } else {
+                c
+            }
+        })
+        .collect();
+    let mut words: Vec<&str> = stripped
+        .split_whitespace()
+        .map(|w| {
+            w.trim_matches(|c: char| {
+                matches!(
+                    c,
+                    '\'' | '‘' | '’' | ',' | '.' | ':' | ';' | '!' | '?' | '(' | ')' | '-'
+                )
+            })
+        })
+        .filter(|w| !w.is_empty())
+        .collect();
+    while words.first().is_some_and(|w| is_generic(w)) {
+        words.remove(0);
+    }
+    if let Some(last) = words.last_mut() {
+        *last = last
+            .strip_suffix("'s")
+            .or_else(|| last.strip_suffix("’s"))
+            .unwrap_or(last);
+    }
+    if words.is_empty()
+        || words.len() > MAX_NAME_WORDS
+        || words.iter().all(|w| is_generic(w))
+        || !words.iter().any(|w| w.chars().any(char::is_alphabetic))
+    {
+        return None;
+    }
+    let name = words.join(" ");
+    (name.chars().count() <= MAX_NAME_CHARS).then_some(name)
+}
+
+/// Drop case-insensitive duplicates, keeping the first spelling.
+fn dedup_names(names: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    names
+        .into_iter()
+        .filter(|name| seen.insert(name.to_lowercase()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clean_brand_name_strips_noise_and_rejects_generic_names() {
+        assert_eq!(clean_brand_name("  Cann™ "), Some("Cann".to_string()));
+        assert_eq!(
+            clean_brand_name("The “Happy Apple” Co."),
+            Some("Happy Apple Co".to_string())
+        );
+        assert_eq!(clean_brand_name("Wynk's"), Some("Wynk".to_string()));
+        assert_eq!(clean_brand_name("THC Seltzer"), None);
+        assert_eq!(clean_brand_name("The"), None);
+        assert_eq!(clean_brand_name("12"), None);
+        assert_eq!(clean_brand_name("One Two Three Four Five"), None);
+    }
+}
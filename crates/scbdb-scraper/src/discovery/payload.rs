@@ -0,0 +1,169 @@
+//! Brand names listed in multi-brand locator payloads and retailer pages.
+
+use regex::Regex;
+use serde_json::Value;
+
+use super::{clean_brand_name, dedup_names};
+
+/// Payload keys that hold a brand (or list of brands) carried by a store.
+const BRAND_KEYS: &[&str] = &[
+    "brand",
+    "brands",
+    "brand_name",
+    "brandName",
+    "BRAND_NAME",
+    "manufacturer",
+    "vendor",
+];
+
+/// How deep into nested objects/arrays the payload walk goes.
+const MAX_PAYLOAD_DEPTH: usize = 4;
+
+/// Brand names a multi-brand locator lists for one store.
+///
+/// Walks the stored `raw_data` looking for brand-ish keys; values may be a
+/// string (comma-separated lists are split), an array, or an object with a
+/// `name`.
+#[must_use]
+pub fn brand_names_in_locator_payload(payload: &Value) -> Vec<String> {
+    let mut raw = Vec::new();
+    walk_payload(payload, 0, &mut raw);
+    dedup_names(raw.iter().filter_map(|name| clean_brand_name(name)))
+}
+
+fn walk_payload(value: &Value, depth: usize, out: &mut Vec<String>) {
+    if depth > MAX_PAYLOAD_DEPTH {
+        return;
+    }
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                if BRAND_KEYS.contains(&key.as_str()) {
+                    brand_values(child, out);
+                } else {
+                    walk_payload(child, depth + 1, out);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                walk_payload(item, depth + 1, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn brand_values(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::String(s) => out.extend(s.split([',', '|', ';']).map(str::to_string)),
+        Value::Array(items) => items.iter().for_each(|item| brand_values(item, out)),
+        Value::Object(map) => {
+            if let Some(Value::String(name)) = map.get("name") {
+                out.push(name.clone());
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Brand names a retailer product page declares.
+///
+/// Reads JSON-LD `brand` (a string or `{"name": ...}`), the
+/// `product:brand` meta tag, Shopify `"vendor"` fields and `data-brand`
+/// attributes.
+///
+/// # Panics
+///
+/// Panics only if one of the built-in patterns fails to compile.
+#[must_use]
+pub fn brand_names_in_retailer_page(html: &str) -> Vec<String> {
+    let mut raw = Vec::new();
+
+    let ld_re = Regex::new(r"(?is)<script[^>]*application/ld\+json[^>]*>(.*?)</script>")
+        .expect("valid regex");
+    for cap in ld_re.captures_iter(html) {
+        if let Ok(json) = serde_json::from_str::<Value>(cap[1].trim()) {
+            json_ld_brands(&json, 0, &mut raw);
+        }
+    }
+
+    let patterns = [
+        r#"(?i)<meta[^>]+property\s*=\s*["'](?:product|og):brand["'][^>]*content\s*=\s*["']([^"']+)["']"#,
+        r#"(?i)<meta[^>]+content\s*=\s*["']([^"']+)["'][^>]*property\s*=\s*["'](?:product|og):brand["']"#,
+        r#""vendor"\s*:\s*"([^"]+)""#,
+        r#"(?i)data-brand\s*=\s*["']([^"']+)["']"#,
+    ];
+    for pattern in patterns {
+        let re = Regex::new(pattern).expect("valid regex");
+        raw.extend(re.captures_iter(html).map(|cap| decode_entities(&cap[1])));
+    }
+
+    dedup_names(raw.iter().filter_map(|name| clean_brand_name(name)))
+}
+
+fn json_ld_brands(value: &Value, depth: usize, out: &mut Vec<String>) {
+    if depth > MAX_PAYLOAD_DEPTH {
+        return;
+    }
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                if key == "brand" {
+                    brand_values(child, out);
+                } else {
+                    json_ld_brands(child, depth + 1, out);
+                }
+            }
+        }
+        Value::Array(items) => items
+            .iter()
+            .for_each(|item| json_ld_brands(item, depth + 1, out)),
+        _ => {}
+    }
+}
+
+fn decode_entities(raw: &str) -> String {
+    raw.replace("&amp;", "&")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locator_payload_brands_from_strings_arrays_and_objects() {
+        let payload = serde_json::json!({
+            "name": "Total Wine",
+            "brands": "Cann, Wynk | Keef",
+            "products": [
+                { "brand": { "name": "Happy Apple" } },
+                { "vendor": ["Cann", "Unknown"] }
+            ]
+        });
+        assert_eq!(
+            brand_names_in_locator_payload(&payload),
+            vec!["Cann", "Wynk", "Keef", "Happy Apple"]
+        );
+    }
+
+    #[test]
+    fn retailer_page_brands_from_json_ld_meta_and_shopify() {
+        let html = r#"
+            <meta property="product:brand" content="Wynk &amp; Co">
+            <script type="application/ld+json">
+                {"@type": "Product", "name": "Lemon 5mg", "brand": {"@type": "Brand", "name": "Cann"}}
+            </script>
+            <script>var meta = {"product": {"vendor":"Keef Brands"}};</script>
+            <div class="tile" data-brand="cann"></div>
+        "#;
+        assert_eq!(
+            brand_names_in_retailer_page(html),
+            vec!["Cann", "Wynk & Co", "Keef Brands"]
+        );
+    }
+}
@@ -0,0 +1,218 @@
+//! Brand names co-mentioned with a category term in free text.
+
+use super::{clean_brand_name, dedup_names};
+
+/// First word of a category term: "THC seltzer", "hemp soda".
+const CATEGORY_QUALIFIERS: &[&str] = &[
+    "thc",
+    "hemp",
+    "hemp-derived",
+    "cannabis",
+    "cbd",
+    "delta-9",
+    "d9",
+    "infused",
+    "thc-infused",
+];
+
+/// Second word of a category term.
+const CATEGORY_NOUNS: &[&str] = &[
+    "seltzer",
+    "seltzers",
+    "soda",
+    "sodas",
+    "beverage",
+    "beverages",
+    "drink",
+    "drinks",
+    "tonic",
+    "tonics",
+    "lemonade",
+    "lemonades",
+];
+
+/// Words that introduce a list of brands: "brands like Cann and Wynk".
+const LIST_LEADS: &[&str] = &["like", "including", "as"];
+
+/// Most capitalized words taken as one name.
+const MAX_RUN: usize = 3;
+
+/// Longest context snippet kept, in characters.
+const MAX_CONTEXT_CHARS: usize = 200;
+
+/// A candidate brand name and the sentence it was found in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrandMention {
+    pub name: String,
+    pub context: String,
+}
+
+/// Find brand names next to hemp/THC beverage category terms.
+///
+/// Recognises a capitalized name right before a category term ("Cann THC
+/// seltzer"), right after one with `from`/`by` ("hemp sodas from Wynk"), and
+/// lists after `like`/`including`/`such as` in a sentence that mentions the
+/// category. Names come back cleaned and deduplicated, first mention first.
+#[must_use]
+pub fn brand_mentions_in_text(text: &str) -> Vec<BrandMention> {
+    let mut mentions = Vec::new();
+    for sentence in text.split(['.', '!', '?', '\n']) {
+        let words: Vec<&str> = sentence.split_whitespace().collect();
+        let categories: Vec<usize> = (0..words.len().saturating_sub(1))
+            .filter(|&i| {
+                CATEGORY_QUALIFIERS.contains(&norm(words[i]).as_str())
+                    && CATEGORY_NOUNS.contains(&norm(words[i + 1]).as_str())
+            })
+            .collect();
+        if categories.is_empty() {
+            continue;
+        }
+
+        let mut names = Vec::new();
+        for &i in &categories {
+            names.extend(name_before(&words, i));
+            if words
+                .get(i + 2)
+                .is_some_and(|w| matches!(norm(w).as_str(), "from" | "by"))
+            {
+                names.extend(name_from(&words, i + 3).map(|(name, _)| name));
+            }
+        }
+        for (i, word) in words.iter().enumerate() {
+            let lead = norm(word);
+            if LIST_LEADS.contains(&lead.as_str())
+                && (lead != "as" || i > 0 && norm(words[i - 1]) == "such")
+            {
+                names.extend(name_list(&words, i + 1));
+            }
+        }
+
+        let context: String = sentence.trim().chars().take(MAX_CONTEXT_CHARS).collect();
+        mentions.extend(
+            names
+                .iter()
+                .filter_map(|name| clean_brand_name(name))
+                .map(|name| BrandMention {
+                    name,
+                    context: context.clone(),
+                }),
+        );
+    }
+
+    let kept = dedup_names(mentions.iter().map(|m| m.name.clone()));
+    kept.into_iter()
+        .filter_map(|name| mentions.iter().find(|m| m.name == name).cloned())
+        .collect()
+}
+
+fn norm(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric() && c != '-')
+        .to_lowercase()
+}
+
+fn is_capitalized(word: &str) -> bool {
+    word.trim_start_matches(['"', '“', '‘', '\''])
+        .chars()
+        .next()
+        .is_some_and(char::is_uppercase)
+}
+
+/// Ends with punctuation that separates phrases (a trailing possessive does not).
+fn ends_phrase(word: &str) -> bool {
+    word.ends_with([',', ';', ':', ')'])
+}
+
+/// Capitalized run ending just before `end`.
+fn name_before(words: &[&str], end: usize) -> Option<String> {
+    let mut start = end;
+    while start > 0
+        && end - start < MAX_RUN
+        && is_capitalized(words[start - 1])
+        && !ends_phrase(words[start - 1])
+    {
+        start -= 1;
+    }
+    (start < end).then(|| words[start..end].join(" "))
+}
+
+/// Capitalized run starting at `start`; also reports whether it ended on
+/// phrase punctuation.
+fn name_from(words: &[&str], start: usize) -> Option<(String, bool)> {
+    let mut end = start;
+    let mut closed = false;
+    while end < words.len() && end - start < MAX_RUN && is_capitalized(words[end]) {
+        end += 1;
+        if ends_phrase(words[end - 1]) {
+            closed = true;
+            break;
+        }
+    }
+    (end > start).then(|| (words[start..end].join(" "), closed))
+}
+
+/// Comma/`and`/`or`-separated names starting at `start`.
+fn name_list(words: &[&str], mut start: usize) -> Vec<String> {
+    let mut names = Vec::new();
+    while let Some((name, closed)) = name_from(words, start) {
+        start += name.split_whitespace().count();
+        names.push(name);
+        if !closed {
+            match words.get(start).map(|w| norm(w)) {
+                Some(sep) if sep == "and" || sep == "or" => start += 1,
+                _ => break,
+            }
+        } else if words
+            .get(start)
+            .is_some_and(|w| matches!(norm(w).as_str(), "and" | "or"))
+        {
+            start += 1;
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(text: &str) -> Vec<String> {
+        brand_mentions_in_text(text)
+            .into_iter()
+            .map(|m| m.name)
+            .collect()
+    }
+
+    #[test]
+    fn finds_names_around_category_terms() {
+        assert_eq!(
+            names("Columbia shoppers can now buy Happy Apple THC seltzers at Total Wine."),
+            vec!["Happy Apple"]
+        );
+        assert_eq!(
+            names("The new hemp sodas from Wynk, launched in March, sold out."),
+            vec!["Wynk"]
+        );
+        assert_eq!(
+            names("Cann's THC-infused drinks are everywhere"),
+            vec!["Cann"]
+        );
+    }
+
+    #[test]
+    fn finds_lists_in_category_sentences_only() {
+        assert_eq!(
+            names("THC beverage brands like Cann, Wynk and Keef Brands are growing."),
+            vec!["Cann", "Wynk", "Keef Brands"]
+        );
+        assert!(names("Artists like Taylor Swift and Drake toured.").is_empty());
+    }
+
+    #[test]
+    fn keeps_sentence_as_context_and_skips_sentence_starts() {
+        let mentions =
+            brand_mentions_in_text("Hemp drinks are hot. New Cann THC sodas hit shelves!");
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(mentions[0].name, "Cann");
+        assert_eq!(mentions[0].context, "New Cann THC sodas hit shelves");
+    }
+}
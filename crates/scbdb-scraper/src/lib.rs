@@ -1,4 +1,5 @@
 pub mod client;
+pub mod discovery;
 pub mod error;
 pub mod locator;
 pub mod logo;
@@ -12,8 +13,10 @@ pub mod types;
 pub use client::ShopifyClient;
 pub use error::ScraperError;
 pub use locator::{
-    fetch_store_locations, make_location_key, validate_store_locations_trust, LocatorError,
-    RawStoreLocation,
+    classify_retailer, extract_location_details, fetch_product_store_locations,
+    fetch_store_locations, make_location_key, match_locator_product, normalize_store_location,
+    validate_store_locations_trust, CarriedProduct, LocationDetails, LocatorError, LocatorProduct,
+    ProductMatchTarget, ProductStoreList, RawStoreLocation, RetailChannel, RetailerClassification,
 };
 pub use logo::fetch_brand_logo_url;
 pub use normalize::normalize_product;
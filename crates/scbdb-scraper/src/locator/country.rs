@@ -0,0 +1,329 @@
+//! Country detection and region / postal code normalization.
+//!
+//! Locator providers report countries as ISO codes, ISO-3 codes or names —
+//! or not at all — and most brand-level collections assume the US. This
+//! module settles every scraped record on an ISO 3166-1 alpha-2 country,
+//! a region code (US state or Canadian province) and a canonical postal code.
+//!
+//! Country resolution order:
+//! 1. The provider's own country field (names and ISO-3 codes accepted).
+//! 2. The region: a Canadian province code or name means `CA`, and a `PR`
+//!    "state" means Puerto Rico even when the provider says `US`.
+//! 3. The postal code: `A1A 1A1` means `CA`; ZIPs `006`–`009` mean `PR`.
+//! 4. Otherwise `US`.
+
+use super::types::RawStoreLocation;
+
+/// US states, DC and the inhabited territories: code and full name.
+const US_REGIONS: &[(&str, &str)] = &[
+    ("AL", "alabama"),
+    ("AK", "alaska"),
+    ("AZ", "arizona"),
+    ("AR", "arkansas"),
+    ("CA", "california"),
+    ("CO", "colorado"),
+    ("CT", "connecticut"),
+    ("DE", "delaware"),
+    ("DC", "district of columbia"),
+    ("FL", "florida"),
+    ("GA", "georgia"),
+    ("HI", "hawaii"),
+    ("ID", "idaho"),
+    ("IL", "illinois"),
+    ("IN", "indiana"),
+    ("IA", "iowa"),
+    ("KS", "kansas"),
+    ("KY", "kentucky"),
+    ("LA", "louisiana"),
+    ("ME", "maine"),
+    ("MD", "maryland"),
+    ("MA", "massachusetts"),
+    ("MI", "michigan"),
+    ("MN", "minnesota"),
+    ("MS", "mississippi"),
+    ("MO", "missouri"),
+    ("MT", "montana"),
+    ("NE", "nebraska"),
+    ("NV", "nevada"),
+    ("NH", "new hampshire"),
+    ("NJ", "new jersey"),
+    ("NM", "new mexico"),
+    ("NY", "new york"),
+    ("NC", "north carolina"),
+    ("ND", "north dakota"),
+    ("OH", "ohio"),
+    ("OK", "oklahoma"),
+    ("OR", "oregon"),
+    ("PA", "pennsylvania"),
+    ("RI", "rhode island"),
+    ("SC", "south carolina"),
+    ("SD", "south dakota"),
+    ("TN", "tennessee"),
+    ("TX", "texas"),
+    ("UT", "utah"),
+    ("VT", "vermont"),
+    ("VA", "virginia"),
+    ("WA", "washington"),
+    ("WV", "west virginia"),
+    ("WI", "wisconsin"),
+    ("WY", "wyoming"),
+    ("PR", "puerto rico"),
+    ("VI", "virgin islands"),
+    ("GU", "guam"),
+];
+
+/// Canadian provinces and territories: code and full name.
+const CA_REGIONS: &[(&str, &str)] = &[
+    ("AB", "alberta"),
+    ("BC", "british columbia"),
+    ("MB", "manitoba"),
+    ("NB", "new brunswick"),
+    ("NL", "newfoundland and labrador"),
+    ("NS", "nova scotia"),
+    ("NT", "northwest territories"),
+    ("NU", "nunavut"),
+    ("ON", "ontario"),
+    ("PE", "prince edward island"),
+    ("QC", "quebec"),
+    ("SK", "saskatchewan"),
+    ("YT", "yukon"),
+];
+
+/// Country names and ISO-3 codes seen in provider payloads.
+const COUNTRY_ALIASES: &[(&str, &str)] = &[
+    ("usa", "US"),
+    ("u.s.", "US"),
+    ("u.s.a.", "US"),
+    ("united states", "US"),
+    ("united states of america", "US"),
+    ("can", "CA"),
+    ("canada", "CA"),
+    ("pri", "PR"),
+    ("puerto rico", "PR"),
+    ("mex", "MX"),
+    ("mexico", "MX"),
+    ("méxico", "MX"),
+    ("gbr", "GB"),
+    ("uk", "GB"),
+    ("united kingdom", "GB"),
+];
+
+/// Normalize a provider country value to an ISO 3166-1 alpha-2 code.
+///
+/// Unrecognized values that are already two letters are upper-cased and
+/// kept; anything else yields `None`.
+#[must_use]
+pub fn normalize_country(raw: &str) -> Option<String> {
+    let lowered = raw.trim().to_lowercase();
+    if let Some((_, code)) = COUNTRY_ALIASES.iter().find(|(name, _)| *name == lowered) {
+        return Some((*code).to_string());
+    }
+    (lowered.len() == 2 && lowered.chars().all(|c| c.is_ascii_alphabetic()))
+        .then(|| lowered.to_ascii_uppercase())
+}
+
+/// Normalize a state or province to its postal code within `country`.
+///
+/// US and Canadian full names map to their two-letter codes (`"Ontario"` →
+/// `"ON"`, `"Québec"` → `"QC"`); other values are trimmed and upper-cased
+/// when they already look like a code, or returned trimmed otherwise.
+#[must_use]
+pub fn normalize_region(country: &str, raw: &str) -> Option<String> {
+    let trimmed = raw.trim().trim_end_matches('.');
+    if trimmed.is_empty() {
+        return None;
+    }
+    let lowered = fold_accents(&trimmed.to_lowercase());
+    let table = match country {
+        "CA" => CA_REGIONS,
+        "US" | "PR" => US_REGIONS,
+        _ => &[],
+    };
+    if let Some((code, _)) = table
+        .iter()
+        .find(|(code, name)| code.eq_ignore_ascii_case(&lowered) || *name == lowered)
+    {
+        return Some((*code).to_string());
+    }
+    if trimmed.len() <= 3 && trimmed.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Some(trimmed.to_ascii_uppercase());
+    }
+    Some(trimmed.to_string())
+}
+
+/// Normalize a postal code for `country`.
+///
+/// US and Puerto Rico ZIPs become `12345` or `12345-6789`, restoring leading
+/// zeros that spreadsheets drop (`"2134"` → `"02134"`). Canadian codes
+/// become `A1A 1A1`. Anything else is trimmed and upper-cased.
+#[must_use]
+pub fn normalize_postal_code(country: &str, raw: &str) -> Option<String> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    let compact: String = trimmed
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    match country {
+        "US" | "PR" if compact.bytes().all(|b| b.is_ascii_digit()) => match compact.len() {
+            3..=5 => Some(format!("{compact:0>5}")),
+            9 => Some(format!("{}-{}", &compact[..5], &compact[5..])),
+            _ => Some(trimmed.to_string()),
+        },
+        "CA" if is_canadian_postal(&compact) => {
+            Some(format!("{} {}", &compact[..3], &compact[3..]))
+        }
+        _ => Some(trimmed.to_ascii_uppercase()),
+    }
+}
+
+/// Resolve a record's country (see the module docs for the order).
+#[must_use]
+pub fn detect_country(loc: &RawStoreLocation) -> String {
+    let explicit = loc.country.as_deref().and_then(normalize_country);
+    let region = loc
+        .state
+        .as_deref()
+        .map(|s| fold_accents(&s.trim().trim_end_matches('.').to_lowercase()))
+        .unwrap_or_default();
+    let region_code = |table: &[(&'static str, &str)]| -> Option<&'static str> {
+        table
+            .iter()
+            .find(|(code, name)| code.eq_ignore_ascii_case(&region) || *name == region)
+            .map(|(code, _)| *code)
+    };
+
+    match explicit.as_deref() {
+        Some("US") | None if region_code(US_REGIONS) == Some("PR") => return "PR".to_string(),
+        Some(code) => return code.to_string(),
+        None => {}
+    }
+    if region_code(CA_REGIONS).is_some() {
+        return "CA".to_string();
+    }
+
+    let compact: String = loc
+        .zip
+        .as_deref()
+        .unwrap_or_default()
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    if is_canadian_postal(&compact) {
+        return "CA".to_string();
+    }
+    if compact.len() >= 5
+        && compact.bytes().all(|b| b.is_ascii_digit())
+        && ["006", "007", "009"].contains(&&compact[..3])
+    {
+        return "PR".to_string();
+    }
+    "US".to_string()
+}
+
+/// Settle a scraped record's country, region and postal code in place.
+///
+/// Applied to every record the locator pipeline returns, before location
+/// keys are computed, so the same store always keys the same way.
+pub fn normalize_store_location(loc: &mut RawStoreLocation) {
+    let country = detect_country(loc);
+    loc.state = loc
+        .state
+        .as_deref()
+        .and_then(|s| normalize_region(&country, s));
+    loc.zip = loc
+        .zip
+        .as_deref()
+        .and_then(|z| normalize_postal_code(&country, z));
+    loc.country = Some(country);
+}
+
+fn is_canadian_postal(compact: &str) -> bool {
+    let b = compact.as_bytes();
+    b.len() == 6
+        && b[0].is_ascii_alphabetic()
+        && b[1].is_ascii_digit()
+        && b[2].is_ascii_alphabetic()
+        && b[3].is_ascii_digit()
+        && b[4].is_ascii_alphabetic()
+        && b[5].is_ascii_digit()
+}
+
+/// Strip the French accents that appear in Canadian province names.
+fn fold_accents(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            'é' | 'è' | 'ê' => 'e',
+            'à' | 'â' => 'a',
+            'î' => 'i',
+            'ô' => 'o',
+            'ç' => 'c',
+            other => other,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loc(state: Option<&str>, zip: Option<&str>, country: Option<&str>) -> RawStoreLocation {
+        RawStoreLocation {
+            external_id: None,
+            name: "Store".to_string(),
+            address_line1: None,
+            city: None,
+            state: state.map(str::to_string),
+            zip: zip.map(str::to_string),
+            country: country.map(str::to_string),
+            latitude: None,
+            longitude: None,
+            phone: None,
+            locator_source: "stockist".to_string(),
+            raw_data: serde_json::Value::Null,
+        }
+    }
+
+    #[test]
+    fn detects_country_from_field_region_and_postal_code() {
+        assert_eq!(
+            detect_country(&loc(Some("SC"), None, Some("United States"))),
+            "US"
+        );
+        assert_eq!(detect_country(&loc(Some("ON"), None, None)), "CA");
+        assert_eq!(detect_country(&loc(Some("Québec"), None, None)), "CA");
+        assert_eq!(detect_country(&loc(None, Some("k1a0b1"), None)), "CA");
+        assert_eq!(detect_country(&loc(Some("PR"), None, Some("US"))), "PR");
+        assert_eq!(detect_country(&loc(None, Some("00901"), None)), "PR");
+        assert_eq!(detect_country(&loc(Some("TX"), Some("78701"), None)), "US");
+        assert_eq!(
+            detect_country(&loc(Some("Jalisco"), None, Some("MEX"))),
+            "MX"
+        );
+    }
+
+    #[test]
+    fn normalizes_regions_and_postal_codes() {
+        let mut ca = loc(Some("British Columbia"), Some("v6b1a1"), Some("Canada"));
+        normalize_store_location(&mut ca);
+        assert_eq!(ca.country.as_deref(), Some("CA"));
+        assert_eq!(ca.state.as_deref(), Some("BC"));
+        assert_eq!(ca.zip.as_deref(), Some("V6B 1A1"));
+
+        let mut us = loc(Some("Massachusetts"), Some("2134"), None);
+        normalize_store_location(&mut us);
+        assert_eq!(us.country.as_deref(), Some("US"));
+        assert_eq!(us.state.as_deref(), Some("MA"));
+        assert_eq!(us.zip.as_deref(), Some("02134"));
+
+        assert_eq!(
+            normalize_postal_code("US", "297151234").as_deref(),
+            Some("29715-1234")
+        );
+        assert_eq!(normalize_region("US", " sc ").as_deref(), Some("SC"));
+    }
+}
@@ -0,0 +1,316 @@
+//! Structured store details recovered from provider `raw_data`.
+//!
+//! Every locator format keeps the provider's JSON (or HTML) for each store.
+//! Each format module exposes a field mapper that pulls hours, website,
+//! email, store type and — where the provider lists them — the products a
+//! store carries out of that payload. [`extract_location_details`] dispatches
+//! on `locator_source`, so the same mapping serves fresh scrapes and the
+//! backfill of rows stored before the mappers existed.
+
+use serde::{Deserialize, Serialize};
+
+use super::formats;
+
+/// Structured fields extracted from a store's provider payload.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocationDetails {
+    /// Opening hours as display text (`"mon 9am-9pm; tue 9am-9pm"`).
+    pub hours: Option<String>,
+    pub website: Option<String>,
+    pub email: Option<String>,
+    /// Provider store type or category (`"Liquor Store"`, `"Dispensary"`).
+    pub store_type: Option<String>,
+    /// Products the provider says this store carries.
+    pub products: Vec<CarriedProduct>,
+}
+
+/// A product listed as available at a store.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CarriedProduct {
+    /// Stable reference within the store: UPC, provider product id, or the
+    /// lower-cased name, in that order of preference.
+    pub product_ref: String,
+    pub name: Option<String>,
+    pub upc: Option<String>,
+}
+
+/// Map a stored provider payload onto [`LocationDetails`].
+///
+/// Unknown sources fall back to the generic mapper used for embedded JSON.
+#[must_use]
+pub fn extract_location_details(
+    locator_source: Option<&str>,
+    raw_data: &serde_json::Value,
+) -> LocationDetails {
+    match locator_source.unwrap_or_default() {
+        "locally" => formats::locally_details(raw_data),
+        "storemapper" => formats::storemapper_details(raw_data),
+        "stockist" => formats::stockist_details(raw_data),
+        "storepoint" => formats::storepoint_details(raw_data),
+        "roseperl" => formats::roseperl_details(raw_data),
+        "vtinfo" => formats::vtinfo_details(raw_data),
+        "askhoodie" => formats::askhoodie_details(raw_data),
+        "beveragefinder" => formats::beveragefinder_details(raw_data),
+        "agile_store_locator" => formats::agile_store_locator_details(raw_data),
+        "storerocket" => formats::storerocket_details(raw_data),
+        "destini" => formats::destini_details(raw_data),
+        "jsonld" => formats::jsonld_details(raw_data),
+        "kml" | "geojson" | "csv" => formats::store_list_details(raw_data),
+        _ => formats::json_embed_details(raw_data),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Shared helpers for the per-format mappers
+// ---------------------------------------------------------------------------
+
+const WEEKDAYS: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+/// First non-empty value among `keys`, rendered as text.
+///
+/// Arrays of strings (or of `{name}` objects) are joined with `", "`.
+pub(in crate::locator) fn text_at(raw: &serde_json::Value, keys: &[&str]) -> Option<String> {
+    keys.iter().find_map(|key| value_text(raw.get(*key)?))
+}
+
+fn value_text(value: &serde_json::Value) -> Option<String> {
+    let text = match value {
+        serde_json::Value::String(s) => s.trim().to_string(),
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .filter_map(value_text)
+            .collect::<Vec<_>>()
+            .join(", "),
+        serde_json::Value::Object(_) => value
+            .get("name")
+            .or_else(|| value.get("title"))
+            .and_then(value_text)?,
+        _ => return None,
+    };
+    (!text.is_empty()).then_some(text)
+}
+
+/// First value among `keys` that looks like a web address, with a scheme.
+pub(in crate::locator) fn website_at(raw: &serde_json::Value, keys: &[&str]) -> Option<String> {
+    keys.iter().find_map(|key| {
+        let url = raw.get(*key)?.as_str()?.trim();
+        if url.contains(' ') || !url.contains('.') || url.contains('@') {
+            return None;
+        }
+        if url.starts_with("http://") || url.starts_with("https://") {
+            Some(url.to_string())
+        } else {
+            Some(format!("https://{}", url.trim_start_matches("//")))
+        }
+    })
+}
+
+/// First value among `keys` that looks like an email address.
+pub(in crate::locator) fn email_at(raw: &serde_json::Value, keys: &[&str]) -> Option<String> {
+    keys.iter().find_map(|key| {
+        let email = raw.get(*key)?.as_str()?.trim();
+        let email = email.strip_prefix("mailto:").unwrap_or(email);
+        let (local, domain) = email.split_once('@')?;
+        (!local.is_empty() && domain.contains('.') && !email.contains(' '))
+            .then(|| email.to_lowercase())
+    })
+}
+
+/// Opening hours from the first of `keys` present.
+///
+/// Accepts plain strings, arrays of strings, and weekday-keyed objects
+/// (`{"monday": "9-5"}`, `{"mon": {...}}`), which are rendered Monday-first.
+/// A JSON-encoded object inside a string is decoded first.
+pub(in crate::locator) fn hours_at(raw: &serde_json::Value, keys: &[&str]) -> Option<String> {
+    keys.iter().find_map(|key| hours_text(raw.get(*key)?))
+}
+
+fn hours_text(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(s) => {
+            let s = s.trim();
+            if s.starts_with('{') {
+                if let Ok(decoded) = serde_json::from_str::<serde_json::Value>(s) {
+                    return hours_text(&decoded);
+                }
+            }
+            (!s.is_empty()).then(|| s.to_string())
+        }
+        serde_json::Value::Array(items) => {
+            let parts: Vec<String> = items.iter().filter_map(hours_text).collect();
+            (!parts.is_empty()).then(|| parts.join("; "))
+        }
+        serde_json::Value::Object(map) => {
+            let mut days: Vec<(usize, String)> = map
+                .iter()
+                .filter_map(|(day, hours)| {
+                    let idx = weekday_index(day)?;
+                    let hours = value_text(hours).or_else(|| open_close_text(hours))?;
+                    Some((idx, format!("{} {hours}", &WEEKDAYS[idx][..3])))
+                })
+                .collect();
+            days.sort_by_key(|(idx, _)| *idx);
+            let parts: Vec<String> = days.into_iter().map(|(_, text)| text).collect();
+            (!parts.is_empty()).then(|| parts.join("; "))
+        }
+        _ => None,
+    }
+}
+
+/// Hours written as one field per weekday directly on the store object
+/// (`"monday": "9am-5pm"`, or `"hours_monday"` when `prefix` is given).
+pub(in crate::locator) fn weekday_fields(raw: &serde_json::Value, prefix: &str) -> Option<String> {
+    let parts: Vec<String> = WEEKDAYS
+        .iter()
+        .filter_map(|day| {
+            let hours = value_text(raw.get(format!("{prefix}{day}"))?)?;
+            Some(format!("{} {hours}", &day[..3]))
+        })
+        .collect();
+    (!parts.is_empty()).then(|| parts.join("; "))
+}
+
+fn weekday_index(day: &str) -> Option<usize> {
+    let day = day.trim().to_ascii_lowercase();
+    if day.len() < 2 {
+        return None;
+    }
+    WEEKDAYS.iter().position(|full| full.starts_with(&day))
+}
+
+fn open_close_text(value: &serde_json::Value) -> Option<String> {
+    let open = value
+        .get("open")
+        .or_else(|| value.get("opens"))
+        .and_then(value_text)?;
+    let close = value
+        .get("close")
+        .or_else(|| value.get("closes"))
+        .and_then(value_text)?;
+    Some(format!("{open}-{close}"))
+}
+
+/// Products listed under the first of `keys` that holds an array.
+///
+/// Items may be bare ids/names or objects with an id (`pID`, `productId`,
+/// `id`, `sku`), a name (`name`, `productName`, `title`) and a `upc`.
+pub(in crate::locator) fn products_at(
+    raw: &serde_json::Value,
+    keys: &[&str],
+) -> Vec<CarriedProduct> {
+    let Some(items) = keys.iter().find_map(|key| raw.get(*key)?.as_array()) else {
+        return Vec::new();
+    };
+    let mut seen = std::collections::HashSet::new();
+    items
+        .iter()
+        .filter_map(carried_product)
+        .filter(|p| seen.insert(p.product_ref.clone()))
+        .collect()
+}
+
+fn carried_product(item: &serde_json::Value) -> Option<CarriedProduct> {
+    if let Some(text) = value_text(item).filter(|_| !item.is_object()) {
+        return Some(named_product(&text));
+    }
+    let upc = text_at(item, &["upc", "UPC", "gtin"]);
+    let id = text_at(item, &["pID", "productId", "product_id", "id", "sku"]);
+    let name = text_at(item, &["name", "productName", "product_name", "title"]);
+    let product_ref = upc
+        .clone()
+        .or(id)
+        .or_else(|| name.as_deref().map(str::to_lowercase))?;
+    Some(CarriedProduct {
+        product_ref,
+        name,
+        upc,
+    })
+}
+
+/// A product known only by its display name.
+pub(in crate::locator) fn named_product(name: &str) -> CarriedProduct {
+    CarriedProduct {
+        product_ref: name.trim().to_lowercase(),
+        name: Some(name.trim().to_string()),
+        upc: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weekday_objects_render_monday_first() {
+        let raw = serde_json::json!({
+            "hours": {"sun": "closed", "mon": "9am-9pm", "tue": {"open": "10:00", "close": "18:00"}}
+        });
+        assert_eq!(
+            hours_at(&raw, &["hours"]).as_deref(),
+            Some("mon 9am-9pm; tue 10:00-18:00; sun closed")
+        );
+    }
+
+    #[test]
+    fn json_encoded_hours_are_decoded() {
+        let raw = serde_json::json!({"open_hours": r#"{"monday":"9:00 - 17:00"}"#});
+        assert_eq!(
+            hours_at(&raw, &["open_hours"]).as_deref(),
+            Some("mon 9:00 - 17:00")
+        );
+    }
+
+    #[test]
+    fn website_and_email_are_validated() {
+        let raw = serde_json::json!({
+            "url": "www.example.com",
+            "bad": "call us",
+            "email": "mailto:Store@Example.com",
+        });
+        assert_eq!(
+            website_at(&raw, &["bad", "url"]).as_deref(),
+            Some("https://www.example.com")
+        );
+        assert_eq!(
+            email_at(&raw, &["email"]).as_deref(),
+            Some("store@example.com")
+        );
+        assert_eq!(website_at(&raw, &["email"]), None);
+    }
+
+    #[test]
+    fn products_prefer_upc_then_id_then_name() {
+        let raw = serde_json::json!({"products": [
+            {"pID": "123", "name": "Lemon 12oz", "upc": "0850000000012"},
+            {"productId": 456, "productName": "Lime 12oz"},
+            {"name": "Berry 12oz"},
+            "Mango 12oz",
+            {"pID": "123"},
+        ]});
+        let refs: Vec<String> = products_at(&raw, &["products"])
+            .into_iter()
+            .map(|p| p.product_ref)
+            .collect();
+        assert_eq!(
+            refs,
+            ["0850000000012", "456", "berry 12oz", "mango 12oz", "123"]
+        );
+    }
+
+    #[test]
+    fn unknown_source_uses_generic_mapper() {
+        let raw = serde_json::json!({"website": "https://shop.example", "category": "Grocery"});
+        let details = extract_location_details(Some("somewhere_new"), &raw);
+        assert_eq!(details.website.as_deref(), Some("https://shop.example"));
+        assert_eq!(details.store_type.as_deref(), Some("Grocery"));
+    }
+}
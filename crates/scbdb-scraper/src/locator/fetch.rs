@@ -6,6 +6,8 @@ const BROWSER_FALLBACK_UA: &str =
     "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0.0.0 Safari/537.36";
 const HTML_FETCH_ATTEMPTS: usize = 3;
 const HTML_FETCH_BACKOFF_MS: [u64; 3] = [0, 300, 900];
+/// Largest resource (store list, KMZ archive) [`fetch_bytes`] reads.
+pub(crate) const MAX_DOWNLOAD_BYTES: usize = 32 * 1024 * 1024;
 
 /// Fetch the HTML body of a URL, trying the supplied user-agent first and
 /// then the browser fallback UA.  Returns the first successful body.
@@ -154,6 +156,57 @@ pub(crate) async fn fetch_text(
     Ok(response.text().await?)
 }
 
+/// Fetch a binary resource body (e.g. a KMZ archive) of at most
+/// [`MAX_DOWNLOAD_BYTES`].
+pub(crate) async fn fetch_bytes(
+    client: &reqwest::Client,
+    url: &str,
+    user_agent: &str,
+) -> Result<Vec<u8>, LocatorError> {
+    fetch_bytes_capped(client, url, user_agent, MAX_DOWNLOAD_BYTES).await
+}
+
+/// [`fetch_bytes`] with an explicit size limit. A declared `Content-Length`
+/// over the limit is refused before reading; otherwise the body is read in
+/// chunks and abandoned once it passes the limit.
+async fn fetch_bytes_capped(
+    client: &reqwest::Client,
+    url: &str,
+    user_agent: &str,
+    limit: usize,
+) -> Result<Vec<u8>, LocatorError> {
+    let mut response = client
+        .get(url)
+        .header(reqwest::header::USER_AGENT, user_agent)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Err(LocatorError::HttpStatus {
+            status: response.status().as_u16(),
+            url: url.to_owned(),
+        });
+    }
+    let too_large = || LocatorError::ResponseTooLarge {
+        url: url.to_owned(),
+        limit,
+    };
+    if response
+        .content_length()
+        .is_some_and(|len| len > limit as u64)
+    {
+        return Err(too_large());
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if body.len() + chunk.len() > limit {
+            return Err(too_large());
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
 /// Perform a simple GET and parse the body as JSON.
 pub(crate) async fn fetch_json(
     client: &reqwest::Client,
@@ -174,3 +227,35 @@ pub(crate) async fn fetch_json(
     let value = response.json::<serde_json::Value>().await?;
     Ok(value)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn fetch_bytes_refuses_bodies_over_the_limit() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/stores.kmz"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![b'x'; 64]))
+            .mount(&server)
+            .await;
+        let client = reqwest::Client::new();
+        let url = format!("{}/stores.kmz", server.uri());
+
+        let body = fetch_bytes_capped(&client, &url, "test-agent", 64)
+            .await
+            .expect("body at the limit");
+        assert_eq!(body.len(), 64);
+
+        let err = fetch_bytes_capped(&client, &url, "test-agent", 63)
+            .await
+            .expect_err("body over the limit");
+        assert!(
+            matches!(err, LocatorError::ResponseTooLarge { limit: 63, .. }),
+            "{err}"
+        );
+    }
+}
@@ -2,6 +2,7 @@
 
 use regex::Regex;
 
+use crate::locator::details::{self, LocationDetails};
 use crate::locator::types::{LocatorError, RawStoreLocation};
 
 const AGILE_STORE_LOCATOR_ATTEMPTS: usize = 3;
@@ -286,6 +287,18 @@ fn value_as_f64(value: &serde_json::Value) -> Option<f64> {
         .or_else(|| value.as_str().and_then(|v| v.parse::<f64>().ok()))
 }
 
+/// Map a Agile Store Locator store row to [`LocationDetails`].
+pub(in crate::locator) fn agile_store_locator_details(raw: &serde_json::Value) -> LocationDetails {
+    LocationDetails {
+        // `open_hours` is a JSON-encoded weekday object in most installs.
+        hours: details::hours_at(raw, &["open_hours", "hours"]),
+        website: details::website_at(raw, &["website", "url"]),
+        email: details::email_at(raw, &["email"]),
+        store_type: details::text_at(raw, &["categories", "category"]),
+        products: Vec::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{extract_agile_store_locator_config, parse_agile_store_locator_stores};
@@ -2,6 +2,7 @@
 
 use regex::Regex;
 
+use crate::locator::details::{self, LocationDetails};
 use crate::locator::types::{LocatorError, RawStoreLocation};
 
 const ASKHOODIE_SEARCH_URL: &str = "https://www.askhoodie.com/api/search";
@@ -280,6 +281,17 @@ fn value_as_f64(value: &serde_json::Value) -> Option<f64> {
         .or_else(|| value.as_str().and_then(|s| s.parse::<f64>().ok()))
 }
 
+/// Map an `AskHoodie` search hit to [`LocationDetails`].
+pub(in crate::locator) fn askhoodie_details(raw: &serde_json::Value) -> LocationDetails {
+    LocationDetails {
+        hours: details::hours_at(raw, &["HOURS", "hours"]),
+        website: details::website_at(raw, &["MASTER_D_WEBSITE", "WEBSITE", "website"]),
+        email: details::email_at(raw, &["MASTER_D_EMAIL", "EMAIL", "email"]),
+        store_type: details::text_at(raw, &["LICENSE_TYPE", "STORE_TYPE", "type"]),
+        products: details::products_at(raw, &["PRODUCTS", "products", "SKUS"]),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{extract_askhoodie_embed_id, extract_hits_array, next_page_state, value_as_f64};
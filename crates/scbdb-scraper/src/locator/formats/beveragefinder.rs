@@ -2,6 +2,7 @@
 
 use regex::Regex;
 
+use crate::locator::details::{self, LocationDetails};
 use crate::locator::types::{LocatorError, RawStoreLocation};
 
 /// Extract `BeverageFinder` key from embed script HTML.
@@ -209,6 +210,17 @@ fn value_as_f64(value: &serde_json::Value) -> Option<f64> {
         .or_else(|| value.as_str().and_then(|s| s.parse::<f64>().ok()))
 }
 
+/// Map a `BeverageFinder` store object to [`LocationDetails`].
+pub(in crate::locator) fn beveragefinder_details(raw: &serde_json::Value) -> LocationDetails {
+    LocationDetails {
+        hours: details::hours_at(raw, &["hours"]),
+        website: details::website_at(raw, &["website", "url"]),
+        email: details::email_at(raw, &["email"]),
+        store_type: details::text_at(raw, &["type", "storeType", "category"]),
+        products: details::products_at(raw, &["products", "items", "brands"]),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{extract_beveragefinder_key, extract_data_locations_json, extract_search_html};
@@ -1,11 +1,14 @@
 //! `Destini` / `lets.shop` locator extraction.
 
 mod parse;
+mod products;
 mod response;
 
 use regex::Regex;
 
 pub(in crate::locator) use parse::fetch_destini_stores;
+pub(in crate::locator) use products::fetch_destini_product_stores;
+pub(in crate::locator) use response::destini_details;
 
 pub(in crate::locator) const DEFAULT_DISTANCE_MILES: u64 = 100;
 pub(in crate::locator) const DEFAULT_MAX_STORES: u64 = 100;
@@ -205,7 +208,9 @@ mod tests {
 
     use super::{
         extract_destini_locator_config, extract_script_urls_for_destini_probe,
-        response::{parse_knox_locations, parse_product_ids_from_categories},
+        response::{
+            parse_knox_locations, parse_product_ids_from_categories, parse_products_from_categories,
+        },
     };
 
     #[test]
@@ -311,6 +316,27 @@ mod tests {
         assert_eq!(ids, vec!["prod-1", "prod-2", "prod-3"]);
     }
 
+    #[test]
+    fn parses_catalog_names_and_upcs_from_categories() {
+        let response = json!({
+            "categories": [
+                {
+                    "subCategories": [
+                        {"products": [{"pID": "p-2", "pName": "Lime 12oz", "upc": "0850000000029"}]},
+                        {"products": [{"pID": "p-1", "name": "Lemon 12oz"}, {"pID": "p-2"}]}
+                    ]
+                }
+            ]
+        });
+
+        let products = parse_products_from_categories(&response);
+        assert_eq!(products.len(), 2);
+        assert_eq!(products[0].provider_product_id, "p-1");
+        assert_eq!(products[0].name.as_deref(), Some("Lemon 12oz"));
+        assert_eq!(products[1].name.as_deref(), Some("Lime 12oz"));
+        assert_eq!(products[1].upc.as_deref(), Some("0850000000029"));
+    }
+
     #[test]
     fn resolves_relative_nuxt_script_urls_for_probe() {
         let html = r#"
@@ -86,17 +86,23 @@ async fn fetch_knox_for_point(
     Ok(parse_knox_locations(&response))
 }
 
-/// Fetch store locations from `Destini` using the provider's own bootstrap and
-/// API contract.
+/// Knox API settings resolved from a locator's `lets.shop` bootstrap JSON.
+pub(super) struct KnoxContext {
+    pub knox_base: String,
+    pub client_id: String,
+    pub distance: u64,
+    pub max_stores: u64,
+    pub text_style_bm: String,
+}
+
+/// Fetch the locator bootstrap and resolve the Knox API settings.
 ///
-/// Iterates a CONUS geographic grid (~168 points at 200-mile spacing) and
-/// deduplicates results by coordinate fingerprint so overlapping radius
-/// windows do not produce duplicate entries.
-pub(in crate::locator) async fn fetch_destini_stores(
+/// Returns `Ok(None)` when no client id is configured or advertised.
+pub(super) async fn load_knox_context(
     client: &reqwest::Client,
     config: &DestiniLocatorConfig,
     user_agent: &str,
-) -> Result<Vec<RawStoreLocation>, LocatorError> {
+) -> Result<Option<KnoxContext>, LocatorError> {
     let bootstrap_url = format!(
         "https://lets.shop/locators/{}/{}/{}.json",
         config.alpha_code, config.locator_id, config.locator_id
@@ -111,7 +117,7 @@ pub(in crate::locator) async fn fetch_destini_stores(
         .or_else(|| value_as_string(context.get("clientId")?))
         .unwrap_or_default();
     if client_id.is_empty() {
-        return Ok(vec![]);
+        return Ok(None);
     }
 
     let knox_base = context
@@ -122,39 +128,74 @@ pub(in crate::locator) async fn fetch_destini_stores(
 
     let settings = context.get("settings").unwrap_or(&serde_json::Value::Null);
 
-    let distance = settings
-        .get("radius")
-        .and_then(serde_json::Value::as_u64)
-        .unwrap_or(DEFAULT_DISTANCE_MILES);
-    let max_stores = settings
-        .get("maxStores")
-        .and_then(serde_json::Value::as_u64)
-        .unwrap_or(DEFAULT_MAX_STORES);
-    let text_style_bm = settings
-        .get("textStyleBm")
-        .and_then(serde_json::Value::as_str)
-        .unwrap_or(DEFAULT_TEXT_STYLE_BM);
+    Ok(Some(KnoxContext {
+        knox_base: knox_base.to_string(),
+        client_id,
+        distance: settings
+            .get("radius")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(DEFAULT_DISTANCE_MILES),
+        max_stores: settings
+            .get("maxStores")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(DEFAULT_MAX_STORES),
+        text_style_bm: settings
+            .get("textStyleBm")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or(DEFAULT_TEXT_STYLE_BM)
+            .to_string(),
+    }))
+}
+
+/// Fetch store locations from `Destini` using the provider's own bootstrap and
+/// API contract.
+///
+/// Iterates a CONUS geographic grid (~168 points at 200-mile spacing) and
+/// deduplicates results by coordinate fingerprint so overlapping radius
+/// windows do not produce duplicate entries.
+pub(in crate::locator) async fn fetch_destini_stores(
+    client: &reqwest::Client,
+    config: &DestiniLocatorConfig,
+    user_agent: &str,
+) -> Result<Vec<RawStoreLocation>, LocatorError> {
+    let Some(knox) = load_knox_context(client, config, user_agent).await? else {
+        return Ok(vec![]);
+    };
 
-    let product_ids = fetch_product_ids(client, user_agent, knox_base, &client_id).await?;
+    let product_ids = fetch_product_ids(client, user_agent, &knox).await?;
     if product_ids.is_empty() {
         return Ok(vec![]);
     }
 
-    let grid = generate_grid(&GridConfig::conus_coarse()); // 168 points
+    Ok(sweep_knox_grid(client, user_agent, &knox, &product_ids).await)
+}
+
+/// Query Knox for `product_ids` at every CONUS grid point, plus the Canadian
+/// and Puerto Rico regions of [`GridConfig::beyond_conus`], and deduplicate.
+///
+/// Failed grid points are logged and skipped.
+pub(super) async fn sweep_knox_grid(
+    client: &reqwest::Client,
+    user_agent: &str,
+    knox: &KnoxContext,
+    product_ids: &[String],
+) -> Vec<RawStoreLocation> {
+    let mut grid = generate_grid(&GridConfig::conus_coarse()); // 168 points
+    grid.extend(GridConfig::beyond_conus().iter().flat_map(generate_grid));
     let mut all_locs: Vec<RawStoreLocation> = Vec::new();
 
     for point in &grid {
         let locs = match fetch_knox_for_point(
             client,
             user_agent,
-            knox_base,
-            &client_id,
+            &knox.knox_base,
+            &knox.client_id,
             point.lat,
             point.lng,
-            distance,
-            max_stores,
-            text_style_bm,
-            &product_ids,
+            knox.distance,
+            knox.max_stores,
+            &knox.text_style_bm,
+            product_ids,
         )
         .await
         {
@@ -170,50 +211,57 @@ pub(in crate::locator) async fn fetch_destini_stores(
             }
         };
 
-        if locs.len() as u64 >= max_stores {
+        if locs.len() as u64 >= knox.max_stores {
             tracing::warn!(
                 lat = point.lat,
                 lng = point.lng,
-                max_stores,
+                max_stores = knox.max_stores,
                 "Knox returned max_stores; locations in this region may be truncated"
             );
         }
 
         all_locs.extend(locs);
 
-        // Courtesy delay — 168 calls at 500ms ≈ 84 s total per brand
+        // Courtesy delay — ~200 calls at 500ms ≈ 100 s total per brand
         tokio::time::sleep(Duration::from_millis(500)).await;
     }
 
-    Ok(dedup_by_coordinates(all_locs))
+    dedup_by_coordinates(all_locs)
 }
 
 async fn fetch_product_ids(
     client: &reqwest::Client,
     user_agent: &str,
-    knox_base: &str,
-    client_id: &str,
+    knox: &KnoxContext,
 ) -> Result<Vec<String>, LocatorError> {
-    let url = join_url(knox_base, "productCategories");
+    let response = fetch_product_categories(client, user_agent, knox).await?;
+    Ok(parse_product_ids_from_categories(&response))
+}
+
+/// Fetch the brand's `productCategories` catalog from Knox.
+pub(super) async fn fetch_product_categories(
+    client: &reqwest::Client,
+    user_agent: &str,
+    knox: &KnoxContext,
+) -> Result<serde_json::Value, LocatorError> {
+    let url = join_url(&knox.knox_base, "productCategories");
     let payload = serde_json::json!({
         "params": {
             "categoryIds": "",
             "subCategoryIds": "",
-            "clientId": client_id,
+            "clientId": knox.client_id,
             "level": 2,
         }
     });
 
-    let response = client
+    Ok(client
         .post(url)
         .header(reqwest::header::USER_AGENT, user_agent)
         .json(&payload)
         .send()
         .await?
         .json::<serde_json::Value>()
-        .await?;
-
-    Ok(parse_product_ids_from_categories(&response))
+        .await?)
 }
 
 fn join_url(base: &str, path: &str) -> String {
@@ -0,0 +1,57 @@
+//! `Destini` product-level sweep: one Knox grid walk per matched product.
+
+use crate::locator::sku::{match_locator_product, ProductMatchTarget};
+use crate::locator::types::{LocatorError, ProductStoreList};
+
+use super::parse::{fetch_product_categories, load_knox_context, sweep_knox_grid};
+use super::response::parse_products_from_categories;
+use super::DestiniLocatorConfig;
+
+/// Fetch the stores carrying each catalog product that matches one of
+/// `targets`, querying Knox with that single product id.
+///
+/// Unmatched catalog entries are skipped without any Knox search.
+pub(in crate::locator) async fn fetch_destini_product_stores(
+    client: &reqwest::Client,
+    config: &DestiniLocatorConfig,
+    user_agent: &str,
+    targets: &[ProductMatchTarget],
+) -> Result<Vec<ProductStoreList>, LocatorError> {
+    let Some(knox) = load_knox_context(client, config, user_agent).await? else {
+        return Ok(vec![]);
+    };
+
+    let categories = fetch_product_categories(client, user_agent, &knox).await?;
+    let mut lists = Vec::new();
+    for product in parse_products_from_categories(&categories) {
+        let Some(product_id) = match_locator_product(&product, targets) else {
+            tracing::debug!(
+                provider_product_id = product.provider_product_id,
+                name = product.name.as_deref().unwrap_or(""),
+                "Destini product does not match a stored product; skipping"
+            );
+            continue;
+        };
+
+        let stores = sweep_knox_grid(
+            client,
+            user_agent,
+            &knox,
+            std::slice::from_ref(&product.provider_product_id),
+        )
+        .await;
+        tracing::debug!(
+            provider_product_id = product.provider_product_id,
+            product_id,
+            count = stores.len(),
+            "Destini product sweep complete"
+        );
+        lists.push(ProductStoreList {
+            product,
+            product_id,
+            stores,
+        });
+    }
+
+    Ok(lists)
+}
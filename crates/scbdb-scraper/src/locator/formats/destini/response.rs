@@ -2,10 +2,21 @@
 //!
 //! Converts raw Knox/productCategories JSON into domain types.
 
-use crate::locator::types::RawStoreLocation;
+use crate::locator::details::{self, LocationDetails};
+use crate::locator::types::{LocatorProduct, RawStoreLocation};
 
 pub(super) fn parse_product_ids_from_categories(response: &serde_json::Value) -> Vec<String> {
-    let mut seen: std::collections::BTreeSet<String> = std::collections::BTreeSet::new();
+    parse_products_from_categories(response)
+        .into_iter()
+        .map(|product| product.provider_product_id)
+        .collect()
+}
+
+/// Catalog entries (`pID`, name, UPC) from a `productCategories` response,
+/// deduplicated and ordered by product id.
+pub(super) fn parse_products_from_categories(response: &serde_json::Value) -> Vec<LocatorProduct> {
+    let mut seen: std::collections::BTreeMap<String, LocatorProduct> =
+        std::collections::BTreeMap::new();
 
     let categories = response
         .get("categories")
@@ -28,20 +39,26 @@ pub(super) fn parse_product_ids_from_categories(response: &serde_json::Value) ->
                 .unwrap_or_default();
 
             for product in products {
-                if let Some(product_id) = product
+                let Some(product_id) = product
                     .get("pID")
                     .or_else(|| product.get("productId"))
                     .and_then(value_as_string)
                     .map(|v| v.trim().to_string())
                     .filter(|v| !v.is_empty())
-                {
-                    seen.insert(product_id);
-                }
+                else {
+                    continue;
+                };
+                seen.entry(product_id.clone())
+                    .or_insert_with(|| LocatorProduct {
+                        provider_product_id: product_id,
+                        name: details::text_at(&product, &["pName", "name", "productName"]),
+                        upc: details::text_at(&product, &["upc", "UPC", "gtin"]),
+                    });
             }
         }
     }
 
-    seen.into_iter().collect()
+    seen.into_values().collect()
 }
 
 pub(super) fn parse_knox_locations(response: &serde_json::Value) -> Vec<RawStoreLocation> {
@@ -109,6 +126,20 @@ fn map_knox_store(store: &serde_json::Value) -> Option<RawStoreLocation> {
     })
 }
 
+/// Map a Knox store record to [`LocationDetails`].
+///
+/// Knox lists the product ids matched at each store (the ids requested from
+/// `productCategories`), which is what makes SKU-level distribution possible.
+pub(in crate::locator) fn destini_details(raw: &serde_json::Value) -> LocationDetails {
+    LocationDetails {
+        hours: details::hours_at(raw, &["hours", "storeHours"]),
+        website: details::website_at(raw, &["website", "url", "storeUrl"]),
+        email: details::email_at(raw, &["email"]),
+        store_type: details::text_at(raw, &["storeType", "retailerType", "type"]),
+        products: details::products_at(raw, &["products", "productIds", "items"]),
+    }
+}
+
 pub(super) fn value_as_string(value: &serde_json::Value) -> Option<String> {
     value.as_str().map(str::to_string).or_else(|| {
         if value.is_number() {
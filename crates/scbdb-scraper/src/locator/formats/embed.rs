@@ -2,6 +2,7 @@
 
 use regex::Regex;
 
+use crate::locator::details::{self, LocationDetails};
 use crate::locator::types::RawStoreLocation;
 
 /// Scan `<script>` tag contents for JSON arrays whose objects look like
@@ -194,3 +195,15 @@ fn embed_object_to_location(obj: &serde_json::Value) -> Option<RawStoreLocation>
         raw_data: obj.clone(),
     })
 }
+
+/// Map a store object found in embedded page JSON to [`LocationDetails`].
+pub(in crate::locator) fn json_embed_details(raw: &serde_json::Value) -> LocationDetails {
+    LocationDetails {
+        hours: details::hours_at(raw, &["hours", "opening_hours", "openingHours", "Hours"])
+            .or_else(|| details::weekday_fields(raw, "")),
+        website: details::website_at(raw, &["website", "url", "Website", "web"]),
+        email: details::email_at(raw, &["email", "Email"]),
+        store_type: details::text_at(raw, &["store_type", "type", "category", "Category"]),
+        products: details::products_at(raw, &["products", "Products"]),
+    }
+}
@@ -2,6 +2,7 @@
 
 use regex::Regex;
 
+use crate::locator::details::{self, LocationDetails};
 use crate::locator::types::RawStoreLocation;
 
 /// Extract store locations from `<script type="application/ld+json">` blocks.
@@ -142,3 +143,65 @@ fn jsonld_item_to_location(item: &serde_json::Value) -> Option<RawStoreLocation>
         raw_data: item.clone(),
     })
 }
+
+/// Map a schema.org `LocalBusiness` item to [`LocationDetails`].
+///
+/// Hours come from `openingHours` or, failing that, from
+/// `openingHoursSpecification` entries (`dayOfWeek` + `opens`/`closes`).
+/// The store type is the most specific `@type` (generic `LocalBusiness` /
+/// `Store` only when nothing else is given).
+pub(in crate::locator) fn jsonld_details(raw: &serde_json::Value) -> LocationDetails {
+    LocationDetails {
+        hours: details::hours_at(raw, &["openingHours"])
+            .or_else(|| opening_hours_specification(raw)),
+        website: details::website_at(raw, &["url", "sameAs"]),
+        email: details::email_at(raw, &["email"]),
+        store_type: jsonld_store_type(raw),
+        products: Vec::new(),
+    }
+}
+
+fn opening_hours_specification(raw: &serde_json::Value) -> Option<String> {
+    let specs = raw.get("openingHoursSpecification")?;
+    let specs = specs
+        .as_array()
+        .cloned()
+        .unwrap_or_else(|| vec![specs.clone()]);
+    let parts: Vec<String> = specs
+        .iter()
+        .filter_map(|spec| {
+            let days = match spec.get("dayOfWeek")? {
+                serde_json::Value::Array(days) => days
+                    .iter()
+                    .filter_map(serde_json::Value::as_str)
+                    .map(short_day)
+                    .collect::<Vec<_>>()
+                    .join(","),
+                day => short_day(day.as_str()?),
+            };
+            let opens = spec.get("opens")?.as_str()?;
+            let closes = spec.get("closes")?.as_str()?;
+            Some(format!("{days} {opens}-{closes}"))
+        })
+        .collect();
+    (!parts.is_empty()).then(|| parts.join("; "))
+}
+
+/// `"https://schema.org/Monday"` → `"mon"`.
+fn short_day(day: &str) -> String {
+    let day = day.rsplit('/').next().unwrap_or(day);
+    day.chars().take(3).collect::<String>().to_lowercase()
+}
+
+fn jsonld_store_type(raw: &serde_json::Value) -> Option<String> {
+    const GENERIC: [&str; 3] = ["LocalBusiness", "Store", "Place"];
+    let types: Vec<&str> = match raw.get("@type")? {
+        serde_json::Value::Array(items) => items.iter().filter_map(|v| v.as_str()).collect(),
+        value => vec![value.as_str()?],
+    };
+    types
+        .iter()
+        .find(|t| !GENERIC.contains(t))
+        .or_else(|| types.first())
+        .map(|t| (*t).to_string())
+}
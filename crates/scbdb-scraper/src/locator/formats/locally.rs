@@ -2,6 +2,7 @@
 
 use regex::Regex;
 
+use crate::locator::details::{self, LocationDetails};
 use crate::locator::types::{LocatorError, RawStoreLocation};
 
 /// Extract the Locally.com company ID from HTML.
@@ -106,3 +107,14 @@ pub(in crate::locator) async fn fetch_locally_stores(
 
     Ok(locations)
 }
+
+/// Map a Locally.com store object to [`LocationDetails`].
+pub(in crate::locator) fn locally_details(raw: &serde_json::Value) -> LocationDetails {
+    LocationDetails {
+        hours: details::hours_at(raw, &["hours", "display_hours", "store_hours"]),
+        website: details::website_at(raw, &["web_address", "website", "url"]),
+        email: details::email_at(raw, &["email"]),
+        store_type: details::text_at(raw, &["store_type", "type", "category"]),
+        products: Vec::new(),
+    }
+}
@@ -2,6 +2,7 @@
 
 use regex::Regex;
 
+use crate::locator::details::{self, LocationDetails};
 use crate::locator::types::{LocatorError, RawStoreLocation};
 
 /// Extract a Roseperl "where to buy" JS URL from HTML.
@@ -161,6 +162,17 @@ fn extract_assignment_payload<'a>(js: &'a str, variable: &str) -> Option<&'a str
     None
 }
 
+/// Map a Roseperl where-to-buy location to [`LocationDetails`].
+pub(in crate::locator) fn roseperl_details(raw: &serde_json::Value) -> LocationDetails {
+    LocationDetails {
+        hours: details::hours_at(raw, &["hours", "store_hours"]),
+        website: details::website_at(raw, &["website", "url"]),
+        email: details::email_at(raw, &["email"]),
+        store_type: details::text_at(raw, &["type", "category", "store_type"]),
+        products: details::products_at(raw, &["products", "items"]),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{extract_assignment_payload, extract_roseperl_wtb_url};
@@ -2,6 +2,7 @@
 
 use regex::Regex;
 
+use crate::locator::details::{self, LocationDetails};
 use crate::locator::types::{LocatorError, RawStoreLocation};
 
 /// Extract the Stockist widget tag from HTML.
@@ -152,6 +153,18 @@ fn json_number_or_string(value: &serde_json::Value, key: &str) -> Option<f64> {
     })
 }
 
+/// Map a Stockist location object to [`LocationDetails`].
+pub(in crate::locator) fn stockist_details(raw: &serde_json::Value) -> LocationDetails {
+    LocationDetails {
+        hours: details::hours_at(raw, &["hours", "opening_hours"]),
+        website: details::website_at(raw, &["website", "url"]),
+        email: details::email_at(raw, &["email"]),
+        // Stockist exposes merchant-defined filters (`[{"name": "Retail"}]`).
+        store_type: details::text_at(raw, &["filters", "category"]),
+        products: Vec::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::extract_stockist_widget_tag;
@@ -0,0 +1,61 @@
+//! CSV store list parsing.
+//!
+//! The header row names the columns; each data row becomes a record keyed by
+//! header, which [`super::record_to_location`] maps by column-name alias.
+
+use serde_json::{Map, Value};
+
+use super::record_to_location;
+use crate::locator::types::RawStoreLocation;
+
+/// Parse a CSV store list into store locations.
+///
+/// Rows with a different number of fields than the header are read as far
+/// as they go; rows that do not map to a store are skipped.
+pub(super) fn parse_csv(body: &str) -> Vec<RawStoreLocation> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(body.trim_start_matches('\u{feff}').as_bytes());
+    let Ok(headers) = reader.headers().cloned() else {
+        tracing::debug!("store list CSV has no readable header row");
+        return vec![];
+    };
+
+    reader
+        .records()
+        .filter_map(Result::ok)
+        .filter_map(|row| {
+            let record: Map<String, Value> = headers
+                .iter()
+                .zip(row.iter())
+                .filter(|(header, value)| !header.is_empty() && !value.is_empty())
+                .map(|(header, value)| (header.to_string(), Value::String(value.to_string())))
+                .collect();
+            record_to_location(record, "csv")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_csv;
+
+    #[test]
+    fn maps_csv_columns_to_locations() {
+        let body = "\u{feff}Retailer Name,Street Address,City,State/Province,Postal Code,Latitude,Longitude\n\
+                    Publix #1021,100 Main St,Greenville,SC,29601,34.8526,-82.3940\n\
+                    \"Bob's Bottles, LLC\",7 Oak Ave,Columbia,SC,29201,,\n\
+                    ,,,,,,\n";
+        let locations = parse_csv(body);
+        assert_eq!(locations.len(), 2);
+        assert_eq!(locations[0].name, "Publix #1021");
+        assert_eq!(locations[0].address_line1.as_deref(), Some("100 Main St"));
+        assert_eq!(locations[0].state.as_deref(), Some("SC"));
+        assert_eq!(locations[0].zip.as_deref(), Some("29601"));
+        assert_eq!(locations[0].latitude, Some(34.8526));
+        assert_eq!(locations[1].name, "Bob's Bottles, LLC");
+        assert_eq!(locations[1].latitude, None);
+        assert_eq!(locations[1].locator_source, "csv");
+    }
+}
@@ -0,0 +1,81 @@
+//! `GeoJSON` store list parsing.
+//!
+//! Reads `Point` features of a `FeatureCollection` (or a bare feature
+//! array); each feature's `properties` are the record, with the point's
+//! coordinates added as `latitude`/`longitude`.
+
+use serde_json::Value;
+
+use super::record_to_location;
+use crate::locator::types::RawStoreLocation;
+
+/// Parse a `GeoJSON` document into store locations.
+///
+/// Non-point features and features without usable properties are skipped.
+pub(super) fn parse_geojson(body: &str) -> Vec<RawStoreLocation> {
+    let Ok(doc) = serde_json::from_str::<Value>(body.trim_start_matches('\u{feff}')) else {
+        tracing::debug!("store list is not valid GeoJSON");
+        return vec![];
+    };
+    let features = match &doc {
+        Value::Array(features) => features.as_slice(),
+        Value::Object(obj) => obj
+            .get("features")
+            .and_then(Value::as_array)
+            .map_or(&[][..], Vec::as_slice),
+        _ => &[],
+    };
+
+    features
+        .iter()
+        .filter_map(|feature| {
+            let mut record = feature.get("properties")?.as_object()?.clone();
+            let geometry = feature.get("geometry");
+            if geometry.and_then(|g| g.get("type")).and_then(Value::as_str) == Some("Point") {
+                let coords = geometry?.get("coordinates")?.as_array()?;
+                if let (Some(lng), Some(lat)) = (coords.first(), coords.get(1)) {
+                    record.insert("longitude".to_string(), lng.clone());
+                    record.insert("latitude".to_string(), lat.clone());
+                }
+            }
+            if let Some(id) = feature.get("id").filter(|id| !id.is_null()) {
+                record.entry("id").or_insert_with(|| id.clone());
+            }
+            record_to_location(record, "geojson")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_geojson;
+
+    #[test]
+    fn parses_point_features() {
+        let body = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "id": 17,
+                    "geometry": {"type": "Point", "coordinates": [-97.7431, 30.2672]},
+                    "properties": {"Store": "Austin Beer Works", "City": "Austin", "State": "TX"}
+                },
+                {
+                    "type": "Feature",
+                    "geometry": {"type": "Polygon", "coordinates": [[[0, 0], [1, 1], [1, 0], [0, 0]]]},
+                    "properties": {"name": "Delivery zone"}
+                }
+            ]
+        }"#;
+        let locations = parse_geojson(body);
+        assert_eq!(locations.len(), 1);
+        let loc = &locations[0];
+        assert_eq!(loc.name, "Austin Beer Works");
+        assert_eq!(loc.external_id.as_deref(), Some("17"));
+        assert_eq!(loc.city.as_deref(), Some("Austin"));
+        assert_eq!(loc.latitude, Some(30.2672));
+        assert_eq!(loc.longitude, Some(-97.7431));
+        assert_eq!(loc.locator_source, "geojson");
+    }
+}
@@ -0,0 +1,220 @@
+//! KML / KMZ placemark parsing.
+//!
+//! Each `<Placemark>` becomes one record: `name`, `description`, `address`
+//! and `phoneNumber`, every `ExtendedData` `<Data>`/`<SimpleData>` field
+//! (My Maps stores imported sheet columns there), and the `<Point>`
+//! coordinates as `latitude`/`longitude`.
+
+use std::io::Read as _;
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use serde_json::{Map, Value};
+
+use super::record_to_location;
+use crate::locator::types::RawStoreLocation;
+
+/// Parse a KML document, or a KMZ archive containing one.
+pub(super) fn parse_kml_bytes(body: &[u8]) -> Vec<RawStoreLocation> {
+    if body.starts_with(b"PK") {
+        let Some(kml) = unzip_kml(body, MAX_KML_BYTES) else {
+            tracing::debug!("KMZ archive has no readable .kml entry");
+            return vec![];
+        };
+        return parse_kml(&kml);
+    }
+    parse_kml(&String::from_utf8_lossy(body))
+}
+
+/// Largest `.kml` entry read out of a KMZ archive, so a small archive
+/// cannot inflate without bound.
+const MAX_KML_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Return the first `.kml` entry of a KMZ archive (conventionally `doc.kml`),
+/// or `None` if it inflates past `max_bytes`.
+fn unzip_kml(body: &[u8], max_bytes: u64) -> Option<String> {
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(body)).ok()?;
+    let index = (0..archive.len()).find(|&i| {
+        archive
+            .by_index(i)
+            .is_ok_and(|entry| entry.name().to_ascii_lowercase().ends_with(".kml"))
+    })?;
+    let mut kml = String::new();
+    archive
+        .by_index(index)
+        .ok()?
+        .take(max_bytes + 1)
+        .read_to_string(&mut kml)
+        .ok()?;
+    if kml.len() as u64 > max_bytes {
+        tracing::warn!(max_bytes, "KMZ entry exceeds the size limit; skipped");
+        return None;
+    }
+    Some(kml)
+}
+
+/// Parse the placemarks of a KML document.
+///
+/// Malformed XML ends the parse; placemarks read before the error are kept.
+pub(super) fn parse_kml(xml: &str) -> Vec<RawStoreLocation> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut locations = Vec::new();
+    let mut placemark: Option<Map<String, Value>> = None;
+    // Local name of the innermost open element, and the `name` attribute of
+    // the open `<Data>`/`<SimpleData>` field.
+    let mut element = String::new();
+    let mut data_name: Option<String> = None;
+    let mut in_point = false;
+
+    loop {
+        let text = match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                element = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+                match element.as_str() {
+                    "Placemark" => placemark = Some(Map::new()),
+                    "Point" => in_point = true,
+                    "Data" | "SimpleData" => {
+                        data_name = e
+                            .try_get_attribute("name")
+                            .ok()
+                            .flatten()
+                            .and_then(|a| a.decode_and_unescape_value(reader.decoder()).ok())
+                            .map(std::borrow::Cow::into_owned);
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+            Ok(Event::End(e)) => {
+                match e.local_name().as_ref() {
+                    b"Placemark" => {
+                        if let Some(record) = placemark.take() {
+                            locations.extend(record_to_location(record, "kml"));
+                        }
+                    }
+                    b"Point" => in_point = false,
+                    _ => {}
+                }
+                element.clear();
+                continue;
+            }
+            Ok(Event::Text(e)) => e
+                .unescape()
+                .map(std::borrow::Cow::into_owned)
+                .unwrap_or_default(),
+            Ok(Event::CData(e)) => String::from_utf8_lossy(&e).into_owned(),
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                tracing::debug!(error = %e, "KML parse error; keeping placemarks read so far");
+                break;
+            }
+            Ok(_) => continue,
+        };
+
+        let Some(record) = placemark.as_mut() else {
+            continue;
+        };
+        let text = text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        match element.as_str() {
+            "name" | "description" | "address" | "phoneNumber" => {
+                record
+                    .entry(element.clone())
+                    .or_insert_with(|| Value::String(text.to_string()));
+            }
+            "value" | "SimpleData" => {
+                if let Some(name) = data_name.clone() {
+                    record
+                        .entry(name)
+                        .or_insert_with(|| Value::String(text.to_string()));
+                }
+            }
+            // `lng,lat[,alt]`. Lines and polygons are map decoration, not stores.
+            "coordinates" if in_point => {
+                let mut parts = text.split(',').map(str::trim);
+                if let (Some(lng), Some(lat)) = (parts.next(), parts.next()) {
+                    record.insert("longitude".to_string(), Value::String(lng.to_string()));
+                    record.insert("latitude".to_string(), Value::String(lat.to_string()));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    locations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<kml xmlns="http://www.opengis.net/kml/2.2">
+  <Document>
+    <name>Where to buy</name>
+    <Folder>
+      <name>Retailers</name>
+      <Placemark>
+        <name>Total Wine &amp; More</name>
+        <description><![CDATA[Open daily<br>Ask for the seltzers]]></description>
+        <ExtendedData>
+          <Data name="Address"><value>9 Market St, Charleston, SC 29401</value></Data>
+          <Data name="Phone"><value>843-555-0123</value></Data>
+        </ExtendedData>
+        <Point><coordinates>-79.9311,32.7809,0</coordinates></Point>
+      </Placemark>
+      <Placemark>
+        <name>Route outline</name>
+      </Placemark>
+    </Folder>
+  </Document>
+</kml>"#;
+
+    #[test]
+    fn parses_placemarks_with_extended_data() {
+        let locations = parse_kml(KML);
+        assert_eq!(locations.len(), 1, "placemark without location is dropped");
+        let loc = &locations[0];
+        assert_eq!(loc.name, "Total Wine & More");
+        assert_eq!(loc.address_line1.as_deref(), Some("9 Market St"));
+        assert_eq!(loc.city.as_deref(), Some("Charleston"));
+        assert_eq!(loc.state.as_deref(), Some("SC"));
+        assert_eq!(loc.zip.as_deref(), Some("29401"));
+        assert_eq!(loc.phone.as_deref(), Some("843-555-0123"));
+        assert_eq!(loc.latitude, Some(32.7809));
+        assert_eq!(loc.longitude, Some(-79.9311));
+        assert_eq!(loc.locator_source, "kml");
+    }
+
+    fn kmz(kml: &str) -> Vec<u8> {
+        let mut buf = std::io::Cursor::new(Vec::new());
+        {
+            let mut writer = zip::ZipWriter::new(&mut buf);
+            writer
+                .start_file("doc.kml", zip::write::SimpleFileOptions::default())
+                .expect("start entry");
+            std::io::Write::write_all(&mut writer, kml.as_bytes()).expect("write entry");
+            writer.finish().expect("finish archive");
+        }
+        buf.into_inner()
+    }
+
+    #[test]
+    fn reads_kml_out_of_kmz_archive() {
+        let locations = parse_kml_bytes(&kmz(KML));
+        assert_eq!(locations.len(), 1);
+        assert_eq!(locations[0].name, "Total Wine & More");
+    }
+
+    #[test]
+    fn skips_kmz_entries_inflating_past_the_limit() {
+        let archive = kmz(KML);
+        let len = KML.len() as u64;
+        assert_eq!(unzip_kml(&archive, len).as_deref(), Some(KML));
+        assert_eq!(unzip_kml(&archive, len - 1), None);
+    }
+}
@@ -0,0 +1,393 @@
+//! Strategy 12: static store lists — Google My Maps embeds and linked
+//! KML/KMZ, `GeoJSON` and CSV files.
+//!
+//! Brands without a locator widget often embed a My Maps map or link a
+//! downloadable store list instead. Every record is flattened to a JSON
+//! object of its fields (KML `ExtendedData`, `GeoJSON` `properties`, CSV
+//! columns) and mapped onto [`RawStoreLocation`] by column-name aliases, so a
+//! sheet headed `Store Name, Street, Postal Code` maps the same way as one
+//! headed `name, address, zip`.
+
+mod csv_list;
+mod geojson;
+mod kml;
+
+use regex::Regex;
+use serde_json::{Map, Value};
+
+use crate::locator::details::{self, LocationDetails};
+use crate::locator::fetch::fetch_bytes;
+use crate::locator::types::{LocatorError, RawStoreLocation};
+
+/// File format of a linked store list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(in crate::locator) enum StoreListKind {
+    /// KML, or KMZ (zipped KML).
+    Kml,
+    GeoJson,
+    Csv,
+}
+
+/// A store list file linked from the locator page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(in crate::locator) struct StoreListLink {
+    pub url: String,
+    pub kind: StoreListKind,
+}
+
+const NAME_KEYS: &[&str] = &[
+    "name",
+    "storename",
+    "store",
+    "locationname",
+    "location",
+    "retailer",
+    "retailername",
+    "accountname",
+    "account",
+    "businessname",
+    "dba",
+    "title",
+];
+const ADDRESS_KEYS: &[&str] = &[
+    "address1",
+    "addressline1",
+    "streetaddress",
+    "street",
+    "address",
+    "addr",
+    "fulladdress",
+];
+const CITY_KEYS: &[&str] = &["city", "town", "locality"];
+const STATE_KEYS: &[&str] = &[
+    "state",
+    "statecode",
+    "st",
+    "province",
+    "stateprovince",
+    "region",
+];
+const ZIP_KEYS: &[&str] = &["zip", "zipcode", "postalcode", "postcode", "postal"];
+const COUNTRY_KEYS: &[&str] = &["country", "countrycode"];
+const LAT_KEYS: &[&str] = &["latitude", "lat", "y"];
+const LNG_KEYS: &[&str] = &["longitude", "lng", "lon", "long", "x"];
+const PHONE_KEYS: &[&str] = &["phone", "phonenumber", "telephone", "tel"];
+const ID_KEYS: &[&str] = &["id", "storeid", "locationid", "storenumber", "externalid"];
+
+/// Extract the map id from a Google My Maps embed or link (`?mid=…`).
+pub(in crate::locator) fn extract_mymaps_mid(html: &str) -> Option<String> {
+    let re = Regex::new(r#"google\.com/maps/d/[^\s"'>]*?[?&](?:amp;)?mid=([A-Za-z0-9_-]+)"#)
+        .expect("valid regex");
+    Some(re.captures(html)?.get(1)?.as_str().to_string())
+}
+
+/// Find links to `.kml`, `.kmz`, `.geojson` and `.csv` files, resolved
+/// against `locator_url`.
+pub(in crate::locator) fn extract_store_list_links(
+    html: &str,
+    locator_url: &str,
+) -> Vec<StoreListLink> {
+    let re = Regex::new(r#"(?i)(?:href|src|data-[a-z-]+)=["']([^"'\s]+?\.(kml|kmz|geojson|csv))(?:[?#][^"']*)?["']"#)
+        .expect("valid regex");
+    let base = reqwest::Url::parse(locator_url).ok();
+
+    let mut links: Vec<StoreListLink> = Vec::new();
+    for cap in re.captures_iter(html) {
+        let (Some(href), Some(ext)) = (cap.get(1), cap.get(2)) else {
+            continue;
+        };
+        let href = href.as_str().replace("&amp;", "&");
+        let Some(url) = reqwest::Url::parse(&href)
+            .ok()
+            .or_else(|| base.as_ref()?.join(&href).ok())
+        else {
+            continue;
+        };
+        let kind = match ext.as_str().to_ascii_lowercase().as_str() {
+            "kml" | "kmz" => StoreListKind::Kml,
+            "geojson" => StoreListKind::GeoJson,
+            _ => StoreListKind::Csv,
+        };
+        let link = StoreListLink {
+            url: url.to_string(),
+            kind,
+        };
+        if !links.contains(&link) {
+            links.push(link);
+        }
+    }
+    links
+}
+
+/// Fetch a My Maps map's KML export and parse its placemarks.
+pub(in crate::locator) async fn fetch_mymaps_stores(
+    client: &reqwest::Client,
+    mid: &str,
+    user_agent: &str,
+) -> Result<Vec<RawStoreLocation>, LocatorError> {
+    // `forcekml=1` returns plain KML instead of a KMZ archive; the parser
+    // accepts either.
+    let url = format!("https://www.google.com/maps/d/kml?mid={mid}&forcekml=1");
+    let body = fetch_bytes(client, &url, user_agent).await?;
+    Ok(kml::parse_kml_bytes(&body))
+}
+
+/// Fetch a linked store list file and parse it according to its kind.
+///
+/// Every kind is read through the size-capped [`fetch_bytes`]; text formats
+/// are decoded leniently, as `reqwest` would.
+pub(in crate::locator) async fn fetch_store_list_stores(
+    client: &reqwest::Client,
+    link: &StoreListLink,
+    user_agent: &str,
+) -> Result<Vec<RawStoreLocation>, LocatorError> {
+    let body = fetch_bytes(client, &link.url, user_agent).await?;
+    Ok(match link.kind {
+        StoreListKind::Kml => kml::parse_kml_bytes(&body),
+        StoreListKind::GeoJson => geojson::parse_geojson(&String::from_utf8_lossy(&body)),
+        StoreListKind::Csv => csv_list::parse_csv(&String::from_utf8_lossy(&body)),
+    })
+}
+
+/// Map one flattened store list record onto a [`RawStoreLocation`].
+///
+/// Requires a name plus an address, city or coordinates. When there is no
+/// city column, a comma-separated full address (`"1 Main St, Austin, TX
+/// 78701"`) is split into street, city, state and ZIP.
+fn record_to_location(record: Map<String, Value>, source: &str) -> Option<RawStoreLocation> {
+    let fields: Vec<(String, String)> = record
+        .iter()
+        .filter_map(|(key, value)| {
+            let text = match value {
+                Value::String(s) => s.trim().to_string(),
+                Value::Number(n) => n.to_string(),
+                _ => return None,
+            };
+            (!text.is_empty()).then(|| (normalize_key(key), text))
+        })
+        .collect();
+    let field = |keys: &[&str]| -> Option<String> {
+        keys.iter().find_map(|key| {
+            fields
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.clone())
+        })
+    };
+    let coordinate = |keys: &[&str], limit: f64| -> Option<f64> {
+        field(keys)?
+            .parse::<f64>()
+            .ok()
+            .filter(|v| v.is_finite() && v.abs() <= limit)
+    };
+
+    let name = field(NAME_KEYS)?;
+    let mut address_line1 = field(ADDRESS_KEYS);
+    let mut city = field(CITY_KEYS);
+    let mut state = field(STATE_KEYS);
+    let mut zip = field(ZIP_KEYS);
+    if city.is_none() {
+        if let Some((street, parsed_city, parsed_state, parsed_zip)) =
+            address_line1.as_deref().and_then(split_full_address)
+        {
+            address_line1 = Some(street);
+            city = Some(parsed_city);
+            state = state.or(parsed_state);
+            zip = zip.or(parsed_zip);
+        }
+    }
+    let (latitude, longitude) = match (coordinate(LAT_KEYS, 90.0), coordinate(LNG_KEYS, 180.0)) {
+        // (0, 0) is an unset pin, not a store in the Gulf of Guinea.
+        (Some(lat), Some(lng)) if lat != 0.0 || lng != 0.0 => (Some(lat), Some(lng)),
+        _ => (None, None),
+    };
+
+    if address_line1.is_none() && city.is_none() && latitude.is_none() {
+        return None;
+    }
+
+    Some(RawStoreLocation {
+        external_id: field(ID_KEYS),
+        name,
+        address_line1,
+        city,
+        state,
+        zip,
+        country: field(COUNTRY_KEYS),
+        latitude,
+        longitude,
+        phone: field(PHONE_KEYS),
+        locator_source: source.to_string(),
+        raw_data: Value::Object(record),
+    })
+}
+
+/// Lower-case a column name and drop everything but letters and digits, so
+/// `"Store Name"`, `"store_name"` and `"StoreName"` compare equal.
+fn normalize_key(key: &str) -> String {
+    key.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Split `"street, city, ST 12345[, country]"` into its parts.
+fn split_full_address(address: &str) -> Option<(String, String, Option<String>, Option<String>)> {
+    let mut parts: Vec<&str> = address
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .collect();
+    if parts.last().is_some_and(|p| {
+        let p = p.to_ascii_lowercase();
+        p == "usa" || p == "us" || p == "united states"
+    }) {
+        parts.pop();
+    }
+    if parts.len() < 3 {
+        return None;
+    }
+
+    let tail: Vec<&str> = parts[parts.len() - 1].split_whitespace().collect();
+    let (state, zip) = match tail.as_slice() {
+        [state, zip] if zip.chars().any(|c| c.is_ascii_digit()) => {
+            (Some((*state).to_string()), Some((*zip).to_string()))
+        }
+        [state] => (Some((*state).to_string()), None),
+        _ => return None,
+    };
+    let city = parts[parts.len() - 2].to_string();
+    let street = parts[..parts.len() - 2].join(", ");
+    Some((street, city, state, zip))
+}
+
+/// Map a store list record to [`LocationDetails`].
+///
+/// Column names vary per file, so the record is looked up by normalized key.
+pub(in crate::locator) fn store_list_details(raw: &Value) -> LocationDetails {
+    let normalized: Map<String, Value> = raw
+        .as_object()
+        .map(|obj| {
+            obj.iter()
+                .map(|(key, value)| (normalize_key(key), value.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+    let raw = Value::Object(normalized);
+
+    LocationDetails {
+        hours: details::hours_at(&raw, &["hours", "storehours", "openinghours"]),
+        website: details::website_at(&raw, &["website", "url", "web", "site"]),
+        email: details::email_at(&raw, &["email", "emailaddress"]),
+        store_type: details::text_at(&raw, &["storetype", "type", "category", "channel"]),
+        products: details::products_at(&raw, &["products"]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_mymaps_mid_in_embed_iframe() {
+        let html = r#"<iframe src="https://www.google.com/maps/d/u/0/embed?mid=1AbC_dEf-123&ehbc=2E312F" width="640"></iframe>"#;
+        assert_eq!(extract_mymaps_mid(html).as_deref(), Some("1AbC_dEf-123"));
+        assert_eq!(extract_mymaps_mid(r#"<a href="/maps">Map</a>"#), None);
+    }
+
+    #[test]
+    fn resolves_linked_store_list_files() {
+        let html = r#"
+            <a href="/files/stores.CSV?v=3">Download CSV</a>
+            <a href="https://cdn.example.com/retailers.geojson">GeoJSON</a>
+            <a href="stores.kmz">Google Earth</a>
+            <a href="/files/stores.CSV?v=3">Again</a>
+        "#;
+        let links = extract_store_list_links(html, "https://brand.example.com/pages/find-us");
+        assert_eq!(
+            links,
+            vec![
+                StoreListLink {
+                    url: "https://brand.example.com/files/stores.CSV".to_string(),
+                    kind: StoreListKind::Csv,
+                },
+                StoreListLink {
+                    url: "https://cdn.example.com/retailers.geojson".to_string(),
+                    kind: StoreListKind::GeoJson,
+                },
+                StoreListLink {
+                    url: "https://brand.example.com/pages/stores.kmz".to_string(),
+                    kind: StoreListKind::Kml,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn maps_columns_by_alias_and_splits_full_address() {
+        let record = serde_json::json!({
+            "Store Name": "Bottle Shop",
+            "Full Address": "12 King St, Suite 4, Charleston, SC 29401, USA",
+            "Phone Number": "843-555-0100",
+            "Store Type": "Liquor",
+        });
+        let Value::Object(record) = record else {
+            unreachable!()
+        };
+        let loc = record_to_location(record, "csv").expect("mapped");
+        assert_eq!(loc.name, "Bottle Shop");
+        assert_eq!(loc.address_line1.as_deref(), Some("12 King St, Suite 4"));
+        assert_eq!(loc.city.as_deref(), Some("Charleston"));
+        assert_eq!(loc.state.as_deref(), Some("SC"));
+        assert_eq!(loc.zip.as_deref(), Some("29401"));
+        assert_eq!(loc.phone.as_deref(), Some("843-555-0100"));
+        assert_eq!(
+            store_list_details(&loc.raw_data).store_type.as_deref(),
+            Some("Liquor")
+        );
+    }
+
+    #[test]
+    fn rejects_records_without_location() {
+        let Value::Object(record) =
+            serde_json::json!({"name": "Newsletter", "lat": "0", "lng": "0"})
+        else {
+            unreachable!()
+        };
+        assert!(record_to_location(record, "csv").is_none());
+    }
+
+    #[tokio::test]
+    async fn oversized_geojson_and_csv_lists_are_refused() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![
+                b' ';
+                crate::locator::fetch::MAX_DOWNLOAD_BYTES
+                    + 1
+            ]))
+            .mount(&server)
+            .await;
+        let client = reqwest::Client::new();
+
+        for (file, kind) in [
+            ("stores.geojson", StoreListKind::GeoJson),
+            ("stores.csv", StoreListKind::Csv),
+        ] {
+            let link = StoreListLink {
+                url: format!("{}/{file}", server.uri()),
+                kind,
+            };
+            let err = fetch_store_list_stores(&client, &link, "test-agent")
+                .await
+                .expect_err("oversized store list");
+            assert!(
+                matches!(err, LocatorError::ResponseTooLarge { .. }),
+                "{file}: {err}"
+            );
+        }
+    }
+}
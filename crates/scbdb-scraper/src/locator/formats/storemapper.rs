@@ -4,6 +4,7 @@ use std::sync::LazyLock;
 
 use regex::Regex;
 
+use crate::locator::details::{self, LocationDetails};
 use crate::locator::types::{LocatorError, RawStoreLocation};
 
 static TOKEN_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
@@ -154,6 +155,17 @@ fn extract_jsonp_payload(body: &str) -> Option<&str> {
     Some(body[open + 1..close].trim())
 }
 
+/// Map a Storemapper store object to [`LocationDetails`].
+pub(in crate::locator) fn storemapper_details(raw: &serde_json::Value) -> LocationDetails {
+    LocationDetails {
+        hours: details::hours_at(raw, &["hours", "opening_hours"]),
+        website: details::website_at(raw, &["url", "website"]),
+        email: details::email_at(raw, &["email"]),
+        store_type: details::text_at(raw, &["category", "categories", "tags"]),
+        products: Vec::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{extract_jsonp_payload, extract_storemapper_user_id};
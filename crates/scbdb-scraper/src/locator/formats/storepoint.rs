@@ -2,6 +2,7 @@
 
 use regex::Regex;
 
+use crate::locator::details::{self, LocationDetails};
 use crate::locator::types::{LocatorError, RawStoreLocation};
 
 /// Extract the Storepoint widget ID from HTML.
@@ -249,6 +250,18 @@ fn parse_storepoint_address_tail_with_country(
     )
 }
 
+/// Map a Storepoint location object to [`LocationDetails`].
+pub(in crate::locator) fn storepoint_details(raw: &serde_json::Value) -> LocationDetails {
+    LocationDetails {
+        // Storepoint stores one field per weekday (`"monday": "9am - 5pm"`).
+        hours: details::weekday_fields(raw, "").or_else(|| details::hours_at(raw, &["hours"])),
+        website: details::website_at(raw, &["website", "url"]),
+        email: details::email_at(raw, &["email"]),
+        store_type: details::text_at(raw, &["tags", "category"]),
+        products: Vec::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{extract_storepoint_widget_id, parse_storepoint_address_tail};
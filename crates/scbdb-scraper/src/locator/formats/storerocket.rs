@@ -2,6 +2,7 @@
 
 use regex::Regex;
 
+use crate::locator::details::{self, LocationDetails};
 use crate::locator::types::{LocatorError, RawStoreLocation};
 
 const MAX_SCRIPT_PROBES: usize = 4;
@@ -202,6 +203,17 @@ fn extract_script_urls_for_account_probe(html: &str) -> Vec<String> {
     urls.into_iter().collect()
 }
 
+/// Map a `StoreRocket` location object to [`LocationDetails`].
+pub(in crate::locator) fn storerocket_details(raw: &serde_json::Value) -> LocationDetails {
+    LocationDetails {
+        hours: details::hours_at(raw, &["hours"]),
+        website: details::website_at(raw, &["url", "website"]),
+        email: details::email_at(raw, &["email"]),
+        store_type: details::text_at(raw, &["location_type_name", "location_type", "filters"]),
+        products: Vec::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -2,16 +2,19 @@
 
 mod vtinfo_http;
 mod vtinfo_parse;
+mod vtinfo_products;
 
 use crate::locator::types::{LocatorError, RawStoreLocation};
 use vtinfo_http::{
     build_vtinfo_form, fetch_vtinfo_iframe, fetch_vtinfo_search, vtinfo_brand_pacing_delay,
     BROWSER_FALLBACK_UA,
 };
+pub(in crate::locator) use vtinfo_parse::vtinfo_details;
 use vtinfo_parse::{
     extract_hidden_input_value, extract_js_string_assignment, parse_vtinfo_search_results,
     vtinfo_dedup_key,
 };
+pub(in crate::locator) use vtinfo_products::fetch_vtinfo_product_stores;
 
 /// Parameters needed to query `VTInfo` finder search.
 #[derive(Debug, Clone)]
@@ -55,7 +58,7 @@ pub(in crate::locator) fn extract_vtinfo_embed(html: &str) -> Option<VtinfoEmbed
 }
 
 /// `VTInfo` search points: [`crate::locator::STRATEGIC_US_POINTS`] paired with
-/// representative zip codes.
+/// representative zip codes, followed by Toronto, Vancouver and San Juan.
 ///
 /// Zip codes are form-submission hints only; lat/lng drives the actual
 /// geographic search.
@@ -75,20 +78,46 @@ fn vtinfo_search_points() -> Vec<(f64, f64, &'static str)> {
         "80202", // Denver
         "85001", // Phoenix
     ];
+    // Searched last so brands with 100+ US results never spend requests here.
+    const NON_US: &[(f64, f64, &str)] = &[
+        (43.653_2, -79.383_2, "M5H 2N2"),  // Toronto
+        (49.282_7, -123.120_7, "V6B 1A1"), // Vancouver
+        (18.466_3, -66.105_7, "00901"),    // San Juan
+    ];
     crate::locator::STRATEGIC_US_POINTS
         .iter()
         .zip(ZIPS.iter())
         .map(|(pt, zip)| (pt.lat, pt.lng, *zip))
+        .chain(NON_US.iter().copied())
         .collect()
 }
 
-pub(in crate::locator) async fn fetch_vtinfo_stores(
+/// Search parameters parsed from the finder iframe, shared by every search
+/// request for one embed.
+pub(super) struct VtinfoSession {
+    iframe_url: String,
+    /// The iframe HTML, kept for the product picker options.
+    pub iframe_html: String,
+    user_agents: Vec<String>,
+    cust_id: String,
+    pagesize: String,
+    implementation_id: String,
+    uuid: String,
+    csrf_token: String,
+    on_prem: String,
+    off_prem: String,
+}
+
+/// Fetch the finder iframe and parse its search parameters.
+///
+/// Returns `None` when the iframe cannot be fetched.
+pub(super) async fn open_vtinfo_session(
     client: &reqwest::Client,
     embed: &VtinfoEmbed,
     locator_url: &str,
     timeout_secs: u64,
     user_agent: &str,
-) -> Result<Vec<RawStoreLocation>, LocatorError> {
+) -> Option<VtinfoSession> {
     let iframe_url = match &embed.uuid {
         Some(uuid) => format!(
             "https://finder.vtinfo.com/finder/web/v2/iframe?custID={}&UUID={uuid}",
@@ -106,17 +135,14 @@ pub(in crate::locator) async fn fetch_vtinfo_stores(
         vec![user_agent.to_string(), BROWSER_FALLBACK_UA.to_string()]
     };
 
-    let Some(iframe_html) =
-        fetch_vtinfo_iframe(client, &user_agents, &iframe_url, locator_url, timeout_secs).await
-    else {
-        return Ok(vec![]);
-    };
+    let iframe_html =
+        fetch_vtinfo_iframe(client, &user_agents, &iframe_url, locator_url, timeout_secs).await?;
 
     let pagesize =
         extract_hidden_input_value(&iframe_html, "pagesize").unwrap_or_else(|| "50".to_string());
     let implementation_id =
         extract_hidden_input_value(&iframe_html, "implementationID").unwrap_or_default();
-    let resolved_uuid = extract_hidden_input_value(&iframe_html, "UUID")
+    let uuid = extract_hidden_input_value(&iframe_html, "UUID")
         .or_else(|| embed.uuid.clone())
         .unwrap_or_default();
     let csrf_token = extract_js_string_assignment(&iframe_html, "CSRFToken").unwrap_or_default();
@@ -127,36 +153,63 @@ pub(in crate::locator) async fn fetch_vtinfo_stores(
 
     tracing::debug!(
         cust_id = embed.cust_id,
-        uuid = resolved_uuid,
+        uuid,
         pagesize,
         implementation_id,
         "vtinfo iframe parsed"
     );
 
-    let search_points = vtinfo_search_points();
+    Some(VtinfoSession {
+        iframe_url,
+        iframe_html,
+        user_agents,
+        cust_id: embed.cust_id.clone(),
+        pagesize,
+        implementation_id,
+        uuid,
+        csrf_token,
+        on_prem,
+        off_prem,
+    })
+}
 
+pub(in crate::locator) async fn fetch_vtinfo_stores(
+    client: &reqwest::Client,
+    embed: &VtinfoEmbed,
+    locator_url: &str,
+    timeout_secs: u64,
+    user_agent: &str,
+) -> Result<Vec<RawStoreLocation>, LocatorError> {
+    let Some(session) =
+        open_vtinfo_session(client, embed, locator_url, timeout_secs, user_agent).await
+    else {
+        return Ok(vec![]);
+    };
+
+    Ok(sweep_vtinfo_search_points(client, &session, None, timeout_secs).await)
+}
+
+/// Run the search at every [`vtinfo_search_points`] entry, optionally
+/// filtered to one product (`(form field, option value)`).
+///
+/// Stops early once 100 deduplicated locations have been collected.
+pub(super) async fn sweep_vtinfo_search_points(
+    client: &reqwest::Client,
+    session: &VtinfoSession,
+    product_filter: Option<(&str, &str)>,
+    timeout_secs: u64,
+) -> Vec<RawStoreLocation> {
     let mut dedup: std::collections::HashMap<String, RawStoreLocation> =
         std::collections::HashMap::new();
 
-    for (request_index, (lat, lng, zip)) in search_points.into_iter().enumerate() {
-        let pacing_delay = vtinfo_brand_pacing_delay(&embed.cust_id, request_index);
+    for (request_index, (lat, lng, zip)) in vtinfo_search_points().into_iter().enumerate() {
+        let pacing_delay = vtinfo_brand_pacing_delay(&session.cust_id, request_index);
         tokio::time::sleep(pacing_delay).await;
 
         run_vtinfo_search_point(
             client,
-            &user_agents,
-            &iframe_url,
-            &embed.cust_id,
-            &pagesize,
-            if resolved_uuid.is_empty() {
-                None
-            } else {
-                Some(resolved_uuid.as_str())
-            },
-            &implementation_id,
-            &csrf_token,
-            &on_prem,
-            &off_prem,
+            session,
+            product_filter,
             zip,
             lat,
             lng,
@@ -170,42 +223,45 @@ pub(in crate::locator) async fn fetch_vtinfo_stores(
         }
     }
 
-    Ok(dedup.into_values().collect())
+    dedup.into_values().collect()
 }
 
 #[allow(clippy::too_many_arguments)]
 async fn run_vtinfo_search_point(
     client: &reqwest::Client,
-    user_agents: &[String],
-    iframe_url: &str,
-    cust_id: &str,
-    pagesize: &str,
-    uuid: Option<&str>,
-    implementation_id: &str,
-    csrf_token: &str,
-    on_prem: &str,
-    off_prem: &str,
+    session: &VtinfoSession,
+    product_filter: Option<(&str, &str)>,
     zip: &str,
     lat: f64,
     lng: f64,
     timeout_secs: u64,
     dedup: &mut std::collections::HashMap<String, RawStoreLocation>,
 ) {
-    let form = build_vtinfo_form(
+    let cust_id = session.cust_id.as_str();
+    let mut form = build_vtinfo_form(
         cust_id,
-        pagesize,
-        uuid,
-        implementation_id,
-        csrf_token,
-        on_prem,
-        off_prem,
+        &session.pagesize,
+        Some(session.uuid.as_str()),
+        &session.implementation_id,
+        &session.csrf_token,
+        &session.on_prem,
+        &session.off_prem,
         zip,
         lat,
         lng,
     );
+    if let Some((field, value)) = product_filter {
+        form.push((field, value.to_string()));
+    }
 
-    let Some(html) =
-        fetch_vtinfo_search(client, user_agents, iframe_url, &form, timeout_secs).await
+    let Some(html) = fetch_vtinfo_search(
+        client,
+        &session.user_agents,
+        &session.iframe_url,
+        &form,
+        timeout_secs,
+    )
+    .await
     else {
         tracing::debug!(cust_id, zip, "vtinfo search request failed");
         return;
@@ -231,7 +287,7 @@ mod tests {
     use super::vtinfo_http::{
         build_vtinfo_form, retry_after_delay, vtinfo_brand_pacing_delay, vtinfo_retry_backoff_delay,
     };
-    use super::vtinfo_parse::parse_vtinfo_search_results;
+    use super::vtinfo_parse::{parse_vtinfo_search_results, vtinfo_details};
     use super::{extract_vtinfo_embed, vtinfo_search_points};
 
     #[test]
@@ -279,6 +335,26 @@ mod tests {
         assert_eq!(rows[0].longitude, Some(-93.26));
     }
 
+    #[test]
+    fn maps_products_carried_from_vtinfo_article() {
+        let html = r#"
+<article class="card finder_location" data-latitude="44.98" data-longitude="-93.26">
+  <h2 class="finder_dba_text">FOWLING WAREHOUSE</h2>
+  <span class="finder_premise">On Premise</span>
+  <ul><li class="finder_product">Lemon Seltzer 12oz</li><li class="finder_product">Lime Seltzer 12oz</li></ul>
+</article>
+"#;
+        let row = parse_vtinfo_search_results(html).remove(0);
+        let details = vtinfo_details(&row.raw_data);
+        assert_eq!(details.store_type.as_deref(), Some("On Premise"));
+        let names: Vec<_> = details
+            .products
+            .iter()
+            .filter_map(|p| p.name.as_deref())
+            .collect();
+        assert_eq!(names, ["Lemon Seltzer 12oz", "Lime Seltzer 12oz"]);
+    }
+
     #[test]
     fn retry_backoff_is_bounded_and_exponential() {
         assert_eq!(vtinfo_retry_backoff_delay(0).as_millis(), 500);
@@ -308,8 +384,11 @@ mod tests {
     }
 
     #[test]
-    fn vtinfo_search_points_matches_strategic_us_count() {
+    fn vtinfo_search_points_cover_us_then_non_us() {
         let pts = vtinfo_search_points();
-        assert_eq!(pts.len(), crate::locator::STRATEGIC_US_POINTS.len());
+        let us = crate::locator::STRATEGIC_US_POINTS.len();
+        assert_eq!(pts.len(), us + 3);
+        assert_eq!(pts[1].2, "28201", "Charlotte stays at index 1");
+        assert_eq!(pts[us].2, "M5H 2N2");
     }
 }
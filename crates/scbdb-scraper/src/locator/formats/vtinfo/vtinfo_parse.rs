@@ -2,6 +2,7 @@
 
 use regex::Regex;
 
+use crate::locator::details::{self, LocationDetails};
 use crate::locator::types::RawStoreLocation;
 
 pub(super) fn parse_vtinfo_search_results(html: &str) -> Vec<RawStoreLocation> {
@@ -66,7 +67,8 @@ fn parse_location_article(article: &str) -> Option<RawStoreLocation> {
         city,
         state,
         zip: None,
-        country: Some("US".to_string()),
+        // Detected from the state by `normalize_store_location`.
+        country: None,
         latitude: lat,
         longitude: lng,
         phone,
@@ -75,6 +77,52 @@ fn parse_location_article(article: &str) -> Option<RawStoreLocation> {
     })
 }
 
+/// Map a stored `VTInfo` result article (`{"html": "<article …>"}`) to
+/// [`LocationDetails`].
+///
+/// Product searches render the matching items as `finder_product*` elements;
+/// brand-level searches have none.
+pub(in crate::locator) fn vtinfo_details(raw: &serde_json::Value) -> LocationDetails {
+    let Some(article) = raw.get("html").and_then(serde_json::Value::as_str) else {
+        return LocationDetails::default();
+    };
+
+    let website = Regex::new(
+        r#"<a[^>]*class=\"[^\"]*finder_(?:website|url)[^\"]*\"[^>]*href=\"(https?://[^\"]+)\""#,
+    )
+    .expect("valid regex")
+    .captures(article)
+    .and_then(|cap| cap.get(1).map(|m| decode_html(m.as_str())));
+    let store_type = Regex::new(
+        r#"(?s)<[^>]*class=\"[^\"]*finder_(?:premise|store_type|type)[^\"]*\"[^>]*>(.*?)</"#,
+    )
+    .expect("valid regex")
+    .captures(article)
+    .and_then(|cap| cap.get(1).map(|m| strip_html(m.as_str())))
+    .filter(|s| !s.is_empty());
+
+    let product_re =
+        Regex::new(r#"(?s)<(?:li|span|div)[^>]*class=\"[^\"]*finder_product[^\"]*\"[^>]*>(.*?)</(?:li|span|div)>"#)
+            .expect("valid regex");
+    let mut seen = std::collections::HashSet::new();
+    let products = product_re
+        .captures_iter(article)
+        .filter_map(|cap| {
+            let name = strip_html(cap.get(1)?.as_str());
+            (!name.is_empty()).then(|| details::named_product(&name))
+        })
+        .filter(|p| seen.insert(p.product_ref.clone()))
+        .collect();
+
+    LocationDetails {
+        hours: None,
+        website,
+        email: None,
+        store_type,
+        products,
+    }
+}
+
 pub(super) fn extract_hidden_input_value(html: &str, name: &str) -> Option<String> {
     let pattern = format!(
         r#"<input[^>]*name=\"{}\"[^>]*value=\"([^\"]*)\""#,
@@ -0,0 +1,126 @@
+//! `VTInfo` product-level sweep.
+//!
+//! Finder iframes configured for product search render a product picker
+//! (`<select name="prodID">` or similar); submitting the search with one of
+//! its option values restricts the results to stores that carry that item.
+
+use regex::Regex;
+
+use crate::locator::sku::{match_locator_product, ProductMatchTarget};
+use crate::locator::types::{LocatorError, LocatorProduct, ProductStoreList};
+
+use super::{open_vtinfo_session, sweep_vtinfo_search_points, VtinfoEmbed};
+
+/// The product picker's form field name and its options.
+///
+/// Returns `None` when the iframe has no product picker (brand-level finder).
+pub(super) fn extract_vtinfo_product_options(html: &str) -> Option<(String, Vec<LocatorProduct>)> {
+    let select_re =
+        Regex::new(r#"(?is)<select[^>]*name=["']([^"']*prod[^"']*)["'][^>]*>(.*?)</select>"#)
+            .expect("valid regex");
+    let option_re = Regex::new(r#"(?is)<option[^>]*value=["']([^"']*)["'][^>]*>(.*?)</option>"#)
+        .expect("valid regex");
+
+    let caps = select_re.captures(html)?;
+    let field = caps.get(1)?.as_str().to_string();
+    let products: Vec<LocatorProduct> = option_re
+        .captures_iter(caps.get(2)?.as_str())
+        .filter_map(|option| {
+            let value = option.get(1)?.as_str().trim();
+            let name = option.get(2)?.as_str().trim();
+            if value.is_empty() || value == "0" {
+                return None;
+            }
+            Some(LocatorProduct {
+                provider_product_id: value.to_string(),
+                name: (!name.is_empty()).then(|| name.replace("&amp;", "&")),
+                upc: value
+                    .bytes()
+                    .all(|b| b.is_ascii_digit())
+                    .then(|| value.to_string())
+                    .filter(|v| v.len() >= 12),
+            })
+        })
+        .collect();
+
+    (!products.is_empty()).then_some((field, products))
+}
+
+/// Fetch the stores carrying each picker product that matches one of
+/// `targets`, one search sweep per product.
+pub(in crate::locator) async fn fetch_vtinfo_product_stores(
+    client: &reqwest::Client,
+    embed: &VtinfoEmbed,
+    locator_url: &str,
+    timeout_secs: u64,
+    user_agent: &str,
+    targets: &[ProductMatchTarget],
+) -> Result<Vec<ProductStoreList>, LocatorError> {
+    let Some(session) =
+        open_vtinfo_session(client, embed, locator_url, timeout_secs, user_agent).await
+    else {
+        return Ok(vec![]);
+    };
+    let Some((field, catalog)) = extract_vtinfo_product_options(&session.iframe_html) else {
+        tracing::debug!(
+            cust_id = embed.cust_id,
+            "vtinfo finder has no product picker"
+        );
+        return Ok(vec![]);
+    };
+
+    let mut lists = Vec::new();
+    for product in catalog {
+        let Some(product_id) = match_locator_product(&product, targets) else {
+            tracing::debug!(
+                provider_product_id = product.provider_product_id,
+                "vtinfo product does not match a stored product; skipping"
+            );
+            continue;
+        };
+
+        let stores = sweep_vtinfo_search_points(
+            client,
+            &session,
+            Some((field.as_str(), product.provider_product_id.as_str())),
+            timeout_secs,
+        )
+        .await;
+        lists.push(ProductStoreList {
+            product,
+            product_id,
+            stores,
+        });
+    }
+
+    Ok(lists)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::extract_vtinfo_product_options;
+
+    #[test]
+    fn parses_product_picker_options() {
+        let html = r#"
+<select name="prodID" class="form-control">
+  <option value="">All Products</option>
+  <option value="850000000012">Lemon Seltzer 12oz</option>
+  <option value="A-77" selected>Lime &amp; Salt Seltzer</option>
+</select>
+"#;
+        let (field, products) = extract_vtinfo_product_options(html).expect("picker");
+        assert_eq!(field, "prodID");
+        assert_eq!(products.len(), 2);
+        assert_eq!(products[0].upc.as_deref(), Some("850000000012"));
+        assert_eq!(products[1].provider_product_id, "A-77");
+        assert_eq!(products[1].name.as_deref(), Some("Lime & Salt Seltzer"));
+        assert_eq!(products[1].upc, None);
+    }
+
+    #[test]
+    fn brand_level_finder_has_no_picker() {
+        let html = r#"<select name="radius"><option value="25">25 mi</option></select>"#;
+        assert!(extract_vtinfo_product_options(html).is_none());
+    }
+}
@@ -39,9 +39,10 @@ impl GridConfig {
     /// CONUS at 200-mile step → 168 points. Pair with 100-mile search radius.
     ///
     /// **Coverage note:** The 0.5-step overshoot adds one latitude band at
-    /// ~50.5°N (southern Canada). The Knox API filters non-US stores, so no
-    /// bad data enters the DB, but those ~10 extra grid points generate real
-    /// HTTP calls.
+    /// ~50.5°N, so with the 100-mile radius the southern Canadian corridor
+    /// (Vancouver, Calgary, Winnipeg, Toronto, Montréal) is already covered.
+    /// [`GridConfig::beyond_conus`] fills in the rest of populated Canada and
+    /// Puerto Rico.
     ///
     /// **Gap note:** A 200-mile grid with a 100-mile radius circle leaves
     /// 41-mile dead zones at cell corners (diagonal = 141 mi > 100-mi radius).
@@ -56,6 +57,39 @@ impl GridConfig {
             step_miles: 200.0,
         }
     }
+
+    /// Non-CONUS regions where brands list stores, at the same 200-mile step
+    /// as [`GridConfig::conus_coarse`] (Puerto Rico uses 60 miles so the
+    /// island gets more than one point). Together → ~30 extra points.
+    ///
+    /// - Northern prairies and interior BC (Edmonton, Saskatoon, Prince George)
+    /// - Atlantic Canada (Halifax, Moncton, Charlottetown, St. John's)
+    /// - Puerto Rico
+    pub fn beyond_conus() -> Vec<Self> {
+        vec![
+            Self {
+                min_lat: 51.5,
+                max_lat: 54.0,
+                min_lng: -124.0,
+                max_lng: -96.0,
+                step_miles: 200.0,
+            },
+            Self {
+                min_lat: 44.0,
+                max_lat: 48.0,
+                min_lng: -66.9,
+                max_lng: -52.7,
+                step_miles: 200.0,
+            },
+            Self {
+                min_lat: 18.2,
+                max_lat: 18.2,
+                min_lng: -67.2,
+                max_lng: -65.6,
+                step_miles: 60.0,
+            },
+        ]
+    }
 }
 
 /// Strategic US city centers covering all major population regions including the Southeast.
@@ -147,6 +181,25 @@ mod tests {
         assert!(pts.len() >= 50 && pts.len() <= 400, "got {}", pts.len());
     }
 
+    #[test]
+    fn grid_beyond_conus_reaches_canada_and_puerto_rico() {
+        let pts: Vec<GridPoint> = GridConfig::beyond_conus()
+            .iter()
+            .flat_map(generate_grid)
+            .collect();
+        assert!(pts.len() <= 60, "got {}", pts.len());
+        let near = |lat: f64, lng: f64, miles: f64| {
+            pts.iter().any(|p| {
+                let dlat = (p.lat - lat) * MILES_PER_LAT_DEGREE;
+                let dlng = (p.lng - lng) * MILES_PER_LAT_DEGREE * (lat * PI / 180.0).cos();
+                dlat.hypot(dlng) <= miles
+            })
+        };
+        assert!(near(53.546_1, -113.493_8, 100.0), "Edmonton uncovered");
+        assert!(near(44.648_8, -63.575_2, 100.0), "Halifax uncovered");
+        assert!(near(18.466_3, -66.105_7, 50.0), "San Juan uncovered");
+    }
+
     #[test]
     fn strategic_us_points_includes_southeast() {
         // Charlotte must be present for SC brand coverage
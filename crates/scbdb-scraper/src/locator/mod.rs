@@ -2,52 +2,76 @@
 //!
 //! Tries extraction strategies in priority order (Locally.com, Storemapper,
 //! Stockist, Storepoint, Roseperl, `VTInfo`, `AskHoodie`, `BeverageFinder`,
-//! Agile Store Locator, `StoreRocket`, `Destini`, JSON-LD, embedded JSON) and
-//! returns the
-//! first successful result.
+//! Agile Store Locator, `StoreRocket`, `Destini`, My Maps / KML / `GeoJSON` /
+//! CSV store lists, JSON-LD, embedded JSON) and returns the first successful
+//! result.
 
+pub mod country;
+mod details;
 pub(crate) mod fetch;
 mod formats;
 mod grid;
+pub mod retailer;
+mod sku;
 pub mod trust;
 pub mod types;
 
 pub(crate) use grid::{generate_grid, GridConfig, STRATEGIC_US_POINTS};
 
+pub use country::normalize_store_location;
+pub use details::{extract_location_details, CarriedProduct, LocationDetails};
+pub use retailer::{classify_retailer, RetailChannel, RetailerClassification};
+pub use sku::{fetch_product_store_locations, match_locator_product, ProductMatchTarget};
 pub use trust::{make_location_key, validate_store_locations_trust};
-pub use types::{LocatorError, RawStoreLocation};
+pub use types::{LocatorError, LocatorProduct, ProductStoreList, RawStoreLocation};
 
 use fetch::fetch_html;
 use formats::{
     discover_destini_locator_config, discover_storerocket_account,
     extract_agile_store_locator_config, extract_askhoodie_embed_id, extract_beveragefinder_key,
     extract_json_embed_locations, extract_jsonld_locations, extract_locally_company_id,
-    extract_roseperl_wtb_url, extract_stockist_widget_tag, extract_storemapper_token,
-    extract_storemapper_user_id, extract_storepoint_widget_id, extract_vtinfo_embed,
-    fetch_agile_store_locator_stores, fetch_askhoodie_stores, fetch_beveragefinder_stores,
-    fetch_destini_stores, fetch_locally_stores, fetch_roseperl_stores, fetch_stockist_stores,
-    fetch_storemapper_stores, fetch_storemapper_stores_by_user_id, fetch_storepoint_stores,
-    fetch_storerocket_stores, fetch_vtinfo_stores,
+    extract_mymaps_mid, extract_roseperl_wtb_url, extract_stockist_widget_tag,
+    extract_store_list_links, extract_storemapper_token, extract_storemapper_user_id,
+    extract_storepoint_widget_id, extract_vtinfo_embed, fetch_agile_store_locator_stores,
+    fetch_askhoodie_stores, fetch_beveragefinder_stores, fetch_destini_stores,
+    fetch_locally_stores, fetch_mymaps_stores, fetch_roseperl_stores, fetch_stockist_stores,
+    fetch_store_list_stores, fetch_storemapper_stores, fetch_storemapper_stores_by_user_id,
+    fetch_storepoint_stores, fetch_storerocket_stores, fetch_vtinfo_stores,
 };
 
 /// Fetch store locations from a brand's store locator page.
 ///
 /// Tries extraction strategies in order (Locally.com, Storemapper, Stockist,
 /// Storepoint, Roseperl, `VTInfo`, `AskHoodie`, `BeverageFinder`, Agile
-/// Store Locator, `StoreRocket`, `Destini`, JSON-LD, embedded JSON) and
-/// returns the
-/// first successful result. Returns `Ok(vec![])` when the page is reachable
+/// Store Locator, `StoreRocket`, `Destini`, My Maps / KML / `GeoJSON` / CSV
+/// store lists, JSON-LD, embedded JSON) and returns the first successful
+/// result. Returns `Ok(vec![])` when the page is reachable
 /// but no locations can be parsed.
 ///
+/// Every record's country, state/province and postal code are normalized
+/// with [`normalize_store_location`].
+///
 /// # Errors
 ///
 /// Returns [`LocatorError::Http`] if the locator page cannot be fetched.
-#[allow(clippy::too_many_lines)]
 pub async fn fetch_store_locations(
     client: &reqwest::Client,
     locator_url: &str,
     timeout_secs: u64,
     user_agent: &str,
+) -> Result<Vec<RawStoreLocation>, LocatorError> {
+    let mut locations =
+        fetch_with_first_matching_strategy(client, locator_url, timeout_secs, user_agent).await?;
+    locations.iter_mut().for_each(normalize_store_location);
+    Ok(locations)
+}
+
+#[allow(clippy::too_many_lines)]
+async fn fetch_with_first_matching_strategy(
+    client: &reqwest::Client,
+    locator_url: &str,
+    timeout_secs: u64,
+    user_agent: &str,
 ) -> Result<Vec<RawStoreLocation>, LocatorError> {
     let html = match fetch_html(client, locator_url, timeout_secs, user_agent).await {
         Ok(body) => body,
@@ -224,7 +248,29 @@ pub async fn fetch_store_locations(
         }
     }
 
-    // Strategy 12: schema.org JSON-LD
+    // Strategy 12: Google My Maps embed, then linked KML/KMZ, GeoJSON or CSV files
+    if let Some(mid) = extract_mymaps_mid(&html) {
+        tracing::debug!(locator_url, mid, "detected Google My Maps embed");
+        match fetch_mymaps_stores(client, &mid, user_agent).await {
+            Ok(stores) if !stores.is_empty() => return Ok(stores),
+            Ok(_) => {}
+            Err(e) => {
+                tracing::debug!(locator_url, error = %e, "My Maps KML fetch failed; trying next strategy");
+            }
+        }
+    }
+    for link in extract_store_list_links(&html, locator_url) {
+        tracing::debug!(locator_url, url = link.url, kind = ?link.kind, "detected linked store list");
+        match fetch_store_list_stores(client, &link, user_agent).await {
+            Ok(stores) if !stores.is_empty() => return Ok(stores),
+            Ok(_) => {}
+            Err(e) => {
+                tracing::debug!(locator_url, url = link.url, error = %e, "store list fetch failed; trying next strategy");
+            }
+        }
+    }
+
+    // Strategy 13: schema.org JSON-LD
     let jsonld_stores = extract_jsonld_locations(&html);
     if !jsonld_stores.is_empty() {
         tracing::debug!(
@@ -235,7 +281,7 @@ pub async fn fetch_store_locations(
         return Ok(jsonld_stores);
     }
 
-    // Strategy 13: Embedded JSON arrays in script tags
+    // Strategy 14: Embedded JSON arrays in script tags
     let embed_stores = extract_json_embed_locations(&html);
     if !embed_stores.is_empty() {
         tracing::debug!(
@@ -246,7 +292,7 @@ pub async fn fetch_store_locations(
         return Ok(embed_stores);
     }
 
-    // Strategy 14: give up gracefully
+    // Strategy 15: give up gracefully
     tracing::warn!(locator_url, "no parseable locator found");
     Ok(vec![])
 }
@@ -365,6 +411,22 @@ mod tests {
         other_state.state = Some("CA".to_string());
         let key_ca = make_location_key(1, &other_state);
         assert_ne!(key_brand_1, key_ca, "different state => different key");
+
+        let mut explicit_us = base.clone();
+        explicit_us.country = Some("US".to_string());
+        assert_eq!(
+            key_brand_1,
+            make_location_key(1, &explicit_us),
+            "US keys are unchanged by the country field"
+        );
+
+        let mut canadian = base.clone();
+        canadian.country = Some("CA".to_string());
+        assert_ne!(
+            key_brand_1,
+            make_location_key(1, &canadian),
+            "non-US country => different key"
+        );
     }
 
     #[test]
@@ -488,6 +550,29 @@ mod tests {
         assert_eq!(loc.locator_source, "jsonld");
     }
 
+    #[test]
+    fn maps_jsonld_hours_website_and_type() {
+        let item = serde_json::json!({
+            "@type": ["LocalBusiness", "LiquorStore"],
+            "name": "Main St Spirits",
+            "url": "https://mainstspirits.example",
+            "openingHoursSpecification": [
+                {"dayOfWeek": ["Monday", "Tuesday"], "opens": "09:00", "closes": "21:00"},
+                {"dayOfWeek": "https://schema.org/Sunday", "opens": "12:00", "closes": "18:00"}
+            ]
+        });
+        let details = extract_location_details(Some("jsonld"), &item);
+        assert_eq!(details.store_type.as_deref(), Some("LiquorStore"));
+        assert_eq!(
+            details.website.as_deref(),
+            Some("https://mainstspirits.example")
+        );
+        assert_eq!(
+            details.hours.as_deref(),
+            Some("mon,tue 09:00-21:00; sun 12:00-18:00")
+        );
+    }
+
     #[test]
     fn skips_jsonld_non_location_types() {
         let html = r#"
@@ -1104,6 +1189,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn trust_guard_checks_shape_of_static_store_lists() {
+        let store = |source: &str, city: Option<&str>| RawStoreLocation {
+            external_id: None,
+            name: "Corner Market".to_string(),
+            address_line1: None,
+            city: city.map(str::to_string),
+            state: city.map(|_| "SC".to_string()),
+            zip: None,
+            country: None,
+            latitude: None,
+            longitude: None,
+            phone: None,
+            locator_source: source.to_string(),
+            raw_data: serde_json::Value::Null,
+        };
+
+        for source in ["kml", "geojson", "csv"] {
+            assert!(
+                validate_store_locations_trust(&[store(source, Some("Greenville"))]).is_ok(),
+                "a single well-shaped {source} record should be trusted"
+            );
+            let mostly_blank = vec![
+                store(source, Some("Greenville")),
+                store(source, None),
+                store(source, None),
+            ];
+            let err = validate_store_locations_trust(&mostly_blank).unwrap_err();
+            assert!(err.starts_with(source), "unexpected reason: {err}");
+        }
+    }
+
     #[test]
     fn trust_guard_accepts_all_named_providers() {
         let providers = [
@@ -0,0 +1,579 @@
+//! Retailer chain and channel classification for store locator records.
+//!
+//! Locator providers return free-text store names ("TOTAL WINE & MORE #1204",
+//! "Circle K 2716"). This module maps those names — plus any category hints
+//! the provider left in `raw_data` — onto a canonical chain name and a retail
+//! channel so footprints can be compared across brands.
+//!
+//! Resolution order:
+//! 1. Known chain patterns ([`CHAIN_CATALOG`]) matched against the store name.
+//!    A chain match also fixes the channel.
+//! 2. Provider category hints in `raw_data` (`category`, `store_type`,
+//!    JSON-LD `@type`, tags, …) matched against [`CHANNEL_KEYWORDS`].
+//! 3. Channel keywords matched against the store name itself.
+
+use serde::{Deserialize, Serialize};
+
+/// Retail channel a store sells through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetailChannel {
+    /// Liquor, wine and spirits, package stores.
+    Liquor,
+    /// Supermarkets, grocers, big-box and club stores.
+    Grocery,
+    /// Convenience stores and fuel stations.
+    Convenience,
+    /// Smoke, vape, tobacco and head shops (including CBD shops).
+    SmokeVape,
+    /// Bars, restaurants, breweries and other on-premise accounts.
+    OnPremise,
+    /// Licensed cannabis dispensaries.
+    Dispensary,
+}
+
+impl RetailChannel {
+    /// Every channel, in display order.
+    pub const ALL: [RetailChannel; 6] = [
+        RetailChannel::Liquor,
+        RetailChannel::Grocery,
+        RetailChannel::Convenience,
+        RetailChannel::SmokeVape,
+        RetailChannel::OnPremise,
+        RetailChannel::Dispensary,
+    ];
+
+    /// Stable storage value used in `store_locations.retail_channel`.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RetailChannel::Liquor => "liquor",
+            RetailChannel::Grocery => "grocery",
+            RetailChannel::Convenience => "convenience",
+            RetailChannel::SmokeVape => "smoke_vape",
+            RetailChannel::OnPremise => "on_premise",
+            RetailChannel::Dispensary => "dispensary",
+        }
+    }
+
+    /// Parse a storage value back into a channel.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == value)
+    }
+}
+
+impl std::fmt::Display for RetailChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A known retail chain and the name fragments that identify it.
+#[derive(Debug, Clone, Copy)]
+pub struct ChainPattern {
+    /// Canonical chain name stored in `store_locations.retail_chain`.
+    pub chain: &'static str,
+    /// Lower-case fragments; any one matching the normalized store name is a hit.
+    pub patterns: &'static [&'static str],
+    pub channel: RetailChannel,
+}
+
+/// Maintained catalog of retail chains seen in hemp beverage locators.
+///
+/// Patterns are matched as whole words against the lower-cased store name with
+/// punctuation collapsed to single spaces, so `"ABC Fine Wine & Spirits #12"`
+/// becomes `"abc fine wine spirits 12"`. The first matching entry wins, so keep
+/// more specific chains ahead of generic ones that share words.
+pub const CHAIN_CATALOG: &[ChainPattern] = &[
+    // Liquor
+    ChainPattern {
+        chain: "Total Wine & More",
+        patterns: &["total wine"],
+        channel: RetailChannel::Liquor,
+    },
+    ChainPattern {
+        chain: "ABC Fine Wine & Spirits",
+        patterns: &["abc fine wine", "abc liquor"],
+        channel: RetailChannel::Liquor,
+    },
+    ChainPattern {
+        chain: "BevMo!",
+        patterns: &["bevmo"],
+        channel: RetailChannel::Liquor,
+    },
+    ChainPattern {
+        chain: "Spec's",
+        patterns: &[
+            "spec s wines",
+            "specs wines",
+            "spec s liquor",
+            "specs liquor",
+        ],
+        channel: RetailChannel::Liquor,
+    },
+    ChainPattern {
+        chain: "Binny's Beverage Depot",
+        patterns: &["binny s", "binnys"],
+        channel: RetailChannel::Liquor,
+    },
+    ChainPattern {
+        chain: "Liquor Barn",
+        patterns: &["liquor barn"],
+        channel: RetailChannel::Liquor,
+    },
+    ChainPattern {
+        chain: "Twin Liquors",
+        patterns: &["twin liquors"],
+        channel: RetailChannel::Liquor,
+    },
+    ChainPattern {
+        chain: "Total Beverage",
+        patterns: &["total beverage"],
+        channel: RetailChannel::Liquor,
+    },
+    ChainPattern {
+        chain: "Bottles & Cans",
+        patterns: &["bottles cans"],
+        channel: RetailChannel::Liquor,
+    },
+    ChainPattern {
+        chain: "Green's Beverages",
+        patterns: &["green s beverages", "greens beverages"],
+        channel: RetailChannel::Liquor,
+    },
+    // Grocery / mass
+    ChainPattern {
+        chain: "Publix",
+        patterns: &["publix"],
+        channel: RetailChannel::Grocery,
+    },
+    ChainPattern {
+        chain: "Kroger",
+        patterns: &["kroger"],
+        channel: RetailChannel::Grocery,
+    },
+    ChainPattern {
+        chain: "Harris Teeter",
+        patterns: &["harris teeter"],
+        channel: RetailChannel::Grocery,
+    },
+    ChainPattern {
+        chain: "Whole Foods Market",
+        patterns: &["whole foods"],
+        channel: RetailChannel::Grocery,
+    },
+    ChainPattern {
+        chain: "Food Lion",
+        patterns: &["food lion"],
+        channel: RetailChannel::Grocery,
+    },
+    ChainPattern {
+        chain: "Ingles",
+        patterns: &["ingles market", "ingles"],
+        channel: RetailChannel::Grocery,
+    },
+    ChainPattern {
+        chain: "Piggly Wiggly",
+        patterns: &["piggly wiggly"],
+        channel: RetailChannel::Grocery,
+    },
+    ChainPattern {
+        chain: "Lowes Foods",
+        patterns: &["lowes foods"],
+        channel: RetailChannel::Grocery,
+    },
+    ChainPattern {
+        chain: "H-E-B",
+        patterns: &["h e b", "heb"],
+        channel: RetailChannel::Grocery,
+    },
+    ChainPattern {
+        chain: "Hy-Vee",
+        patterns: &["hy vee", "hyvee"],
+        channel: RetailChannel::Grocery,
+    },
+    ChainPattern {
+        chain: "Meijer",
+        patterns: &["meijer"],
+        channel: RetailChannel::Grocery,
+    },
+    ChainPattern {
+        chain: "Target",
+        patterns: &["target"],
+        channel: RetailChannel::Grocery,
+    },
+    // Convenience
+    ChainPattern {
+        chain: "Circle K",
+        patterns: &["circle k"],
+        channel: RetailChannel::Convenience,
+    },
+    ChainPattern {
+        chain: "7-Eleven",
+        patterns: &["7 eleven", "7eleven", "seven eleven"],
+        channel: RetailChannel::Convenience,
+    },
+    ChainPattern {
+        chain: "Wawa",
+        patterns: &["wawa"],
+        channel: RetailChannel::Convenience,
+    },
+    ChainPattern {
+        chain: "Sheetz",
+        patterns: &["sheetz"],
+        channel: RetailChannel::Convenience,
+    },
+    ChainPattern {
+        chain: "QuikTrip",
+        patterns: &["quiktrip", "quik trip"],
+        channel: RetailChannel::Convenience,
+    },
+    ChainPattern {
+        chain: "RaceTrac",
+        patterns: &["racetrac"],
+        channel: RetailChannel::Convenience,
+    },
+    ChainPattern {
+        chain: "Casey's",
+        patterns: &["casey s general", "caseys general"],
+        channel: RetailChannel::Convenience,
+    },
+    ChainPattern {
+        chain: "Speedway",
+        patterns: &["speedway"],
+        channel: RetailChannel::Convenience,
+    },
+    ChainPattern {
+        chain: "Kangaroo Express",
+        patterns: &["kangaroo express"],
+        channel: RetailChannel::Convenience,
+    },
+    ChainPattern {
+        chain: "Buc-ee's",
+        patterns: &["buc ee s", "bucees"],
+        channel: RetailChannel::Convenience,
+    },
+    ChainPattern {
+        chain: "Spinx",
+        patterns: &["spinx"],
+        channel: RetailChannel::Convenience,
+    },
+    ChainPattern {
+        chain: "Murphy USA",
+        patterns: &["murphy usa", "murphy express"],
+        channel: RetailChannel::Convenience,
+    },
+];
+
+/// Channel keywords matched against provider hints and store names.
+///
+/// Evaluated in order; the first channel with a matching keyword wins. On-premise
+/// and dispensary keywords come first because those names often also contain
+/// grocery/liquor words ("Wine Bar", "Cannabis Market").
+pub const CHANNEL_KEYWORDS: &[(RetailChannel, &[&str])] = &[
+    (
+        RetailChannel::Dispensary,
+        &["dispensary", "dispensaries", "cannabis", "marijuana"],
+    ),
+    (
+        RetailChannel::OnPremise,
+        &[
+            "bar",
+            "barorpub",
+            "pub",
+            "tavern",
+            "saloon",
+            "grill",
+            "restaurant",
+            "brewery",
+            "brewing",
+            "taproom",
+            "tap room",
+            "bistro",
+            "cafe",
+            "kitchen",
+            "lounge",
+            "on premise",
+            "onpremise",
+            "winery",
+        ],
+    ),
+    (
+        RetailChannel::SmokeVape,
+        &[
+            "smoke",
+            "vape",
+            "vapor",
+            "tobacco",
+            "cbd",
+            "head shop",
+            "hemp shop",
+            "kratom",
+        ],
+    ),
+    (
+        RetailChannel::Liquor,
+        &[
+            "liquor",
+            "liquorstore",
+            "wine spirits",
+            "wines spirits",
+            "spirits",
+            "package store",
+            "bottle shop",
+            "beverage depot",
+            "abc store",
+        ],
+    ),
+    (
+        RetailChannel::Convenience,
+        &[
+            "convenience",
+            "conveniencestore",
+            "c store",
+            "food mart",
+            "foodmart",
+            "quick stop",
+            "gas station",
+            "gasstation",
+            "fuel",
+            "shell",
+            "exxon",
+            "chevron",
+            "texaco",
+            "sunoco",
+        ],
+    ),
+    (
+        RetailChannel::Grocery,
+        &[
+            "grocery",
+            "grocerystore",
+            "supermarket",
+            "market",
+            "foods",
+            "co op",
+            "food coop",
+        ],
+    ),
+];
+
+/// Keys under which locator providers expose store categories in `raw_data`.
+const HINT_KEYS: &[&str] = &[
+    "@type",
+    "category",
+    "categories",
+    "store_type",
+    "storeType",
+    "type",
+    "location_type",
+    "locationType",
+    "retailer_type",
+    "retailerType",
+    "channel",
+    "tags",
+    "filters",
+];
+
+/// Classification result for a single store.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RetailerClassification {
+    /// Canonical chain name, when the store belongs to a known chain.
+    pub chain: Option<&'static str>,
+    /// Retail channel, when it can be inferred.
+    pub channel: Option<RetailChannel>,
+}
+
+/// Classify a store by name and provider `raw_data` hints.
+///
+/// Returns an empty classification (both fields `None`) when nothing matches;
+/// callers store that as `NULL` so the store is reported as `unknown`.
+#[must_use]
+pub fn classify_retailer(name: &str, raw_data: &serde_json::Value) -> RetailerClassification {
+    let normalized_name = normalize_text(name);
+
+    if let Some(pattern) = match_chain(&normalized_name) {
+        return RetailerClassification {
+            chain: Some(pattern.chain),
+            channel: Some(pattern.channel),
+        };
+    }
+
+    let channel = provider_hints(raw_data)
+        .iter()
+        .find_map(|hint| match_channel(&normalize_text(hint)))
+        .or_else(|| match_channel(&normalized_name));
+
+    RetailerClassification {
+        chain: None,
+        channel,
+    }
+}
+
+fn match_chain(normalized_name: &str) -> Option<&'static ChainPattern> {
+    let padded = format!(" {normalized_name} ");
+    CHAIN_CATALOG.iter().find(|entry| {
+        entry
+            .patterns
+            .iter()
+            .any(|p| padded.contains(&format!(" {p} ")))
+    })
+}
+
+fn match_channel(normalized: &str) -> Option<RetailChannel> {
+    if normalized.is_empty() {
+        return None;
+    }
+    let padded = format!(" {normalized} ");
+    CHANNEL_KEYWORDS.iter().find_map(|(channel, keywords)| {
+        keywords
+            .iter()
+            .any(|kw| padded.contains(&format!(" {kw} ")))
+            .then_some(*channel)
+    })
+}
+
+/// Collect string category hints from well-known `raw_data` keys.
+///
+/// Values may be strings, arrays of strings, or arrays of objects carrying a
+/// `name`/`title`/`label` field (Storepoint/StoreRocket style filters).
+fn provider_hints(raw_data: &serde_json::Value) -> Vec<String> {
+    let Some(obj) = raw_data.as_object() else {
+        return Vec::new();
+    };
+
+    let mut hints = Vec::new();
+    for key in HINT_KEYS {
+        if let Some(value) = obj.get(*key) {
+            collect_hint_strings(value, &mut hints);
+        }
+    }
+    hints
+}
+
+fn collect_hint_strings(value: &serde_json::Value, out: &mut Vec<String>) {
+    match value {
+        serde_json::Value::String(s) => {
+            // Comma/pipe separated tag lists are common ("Liquor,Beer & Wine").
+            out.extend(
+                s.split([',', '|', ';'])
+                    .map(str::trim)
+                    .filter(|part| !part.is_empty())
+                    .map(str::to_string),
+            );
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_hint_strings(item, out);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for key in ["name", "title", "label", "value"] {
+                if let Some(inner) = map.get(key) {
+                    collect_hint_strings(inner, out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Lower-case and collapse every non-alphanumeric run into a single space.
+fn normalize_text(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut last_space = true;
+    for ch in input.chars() {
+        if ch.is_alphanumeric() {
+            out.extend(ch.to_lowercase());
+            last_space = false;
+        } else if !last_space {
+            out.push(' ');
+            last_space = true;
+        }
+    }
+    out.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn chain_match_sets_chain_and_channel() {
+        let class = classify_retailer("TOTAL WINE & MORE #1204", &json!({}));
+        assert_eq!(class.chain, Some("Total Wine & More"));
+        assert_eq!(class.channel, Some(RetailChannel::Liquor));
+
+        let class = classify_retailer("ABC Fine Wine & Spirits #88", &json!({}));
+        assert_eq!(class.chain, Some("ABC Fine Wine & Spirits"));
+
+        let class = classify_retailer("Circle K 2716", &json!({}));
+        assert_eq!(class.chain, Some("Circle K"));
+        assert_eq!(class.channel, Some(RetailChannel::Convenience));
+
+        let class = classify_retailer("Publix Super Market at Town Center", &json!({}));
+        assert_eq!(class.chain, Some("Publix"));
+        assert_eq!(class.channel, Some(RetailChannel::Grocery));
+    }
+
+    #[test]
+    fn provider_hint_wins_over_name_keywords() {
+        let raw = json!({ "category": "Bar & Restaurant" });
+        let class = classify_retailer("Main Street Market", &raw);
+        assert_eq!(class.chain, None);
+        assert_eq!(class.channel, Some(RetailChannel::OnPremise));
+    }
+
+    #[test]
+    fn jsonld_type_hint_is_recognized() {
+        let raw = json!({ "@type": "LiquorStore", "name": "Joe's" });
+        let class = classify_retailer("Joe's", &raw);
+        assert_eq!(class.channel, Some(RetailChannel::Liquor));
+    }
+
+    #[test]
+    fn array_of_filter_objects_is_read() {
+        let raw = json!({ "filters": [{ "name": "Smoke Shop" }] });
+        let class = classify_retailer("Cloud 9", &raw);
+        assert_eq!(class.channel, Some(RetailChannel::SmokeVape));
+    }
+
+    #[test]
+    fn name_keywords_used_as_fallback() {
+        assert_eq!(
+            classify_retailer("Palmetto Vape & Smoke", &json!({})).channel,
+            Some(RetailChannel::SmokeVape)
+        );
+        assert_eq!(
+            classify_retailer("Hops & Barley Brewing Co", &json!({})).channel,
+            Some(RetailChannel::OnPremise)
+        );
+        assert_eq!(
+            classify_retailer("Greenville Wine & Spirits", &json!({})).channel,
+            Some(RetailChannel::Liquor)
+        );
+    }
+
+    #[test]
+    fn keywords_match_whole_words_only() {
+        // "barn" must not match the on-premise keyword "bar".
+        let class = classify_retailer("Red Barn Gifts", &json!({}));
+        assert_eq!(class.channel, None);
+    }
+
+    #[test]
+    fn unknown_store_yields_empty_classification() {
+        let class = classify_retailer("Sunshine Gifts", &json!({ "id": 7 }));
+        assert_eq!(class, RetailerClassification::default());
+    }
+
+    #[test]
+    fn channel_round_trips_through_storage_value() {
+        for channel in RetailChannel::ALL {
+            assert_eq!(RetailChannel::parse(channel.as_str()), Some(channel));
+        }
+        assert_eq!(RetailChannel::parse("casino"), None);
+    }
+}
@@ -0,0 +1,232 @@
+//! SKU-level store availability from product-level locators.
+//!
+//! Destini and `VTInfo` can filter a store search by product. Brand-level
+//! collection asks for every product at once; the sweep here asks for one
+//! product at a time — only the provider products that match one of ours —
+//! so each store can be tied to the SKUs it carries.
+
+use std::collections::BTreeSet;
+
+use super::country::normalize_store_location;
+use super::fetch::fetch_html;
+use super::formats::{
+    discover_destini_locator_config, extract_vtinfo_embed, fetch_destini_product_stores,
+    fetch_vtinfo_product_stores,
+};
+use super::types::{LocatorError, LocatorProduct, ProductStoreList};
+
+/// One of our products, as candidates for matching a provider catalog entry.
+#[derive(Debug, Clone)]
+pub struct ProductMatchTarget {
+    /// `products.id`.
+    pub product_id: i64,
+    pub name: String,
+    /// Variant SKUs; compared against the provider product id and UPC.
+    pub skus: Vec<String>,
+}
+
+/// Words too common in product names to tell two products apart.
+const IGNORED_TOKENS: [&str; 6] = ["the", "and", "with", "by", "of", "a"];
+
+/// Match a provider catalog entry to one of our products.
+///
+/// A SKU equal to the provider's product id or UPC wins outright. Otherwise
+/// the product whose name tokens are all contained in the provider's name
+/// (or vice versa) matches, preferring the most specific name; a tie between
+/// equally specific names is ambiguous and matches nothing.
+#[must_use]
+pub fn match_locator_product(
+    product: &LocatorProduct,
+    targets: &[ProductMatchTarget],
+) -> Option<i64> {
+    let codes: Vec<String> = [Some(&product.provider_product_id), product.upc.as_ref()]
+        .into_iter()
+        .flatten()
+        .map(|code| normalize_code(code))
+        .filter(|code| !code.is_empty())
+        .collect();
+    if let Some(target) = targets.iter().find(|t| {
+        t.skus
+            .iter()
+            .any(|sku| codes.contains(&normalize_code(sku)))
+    }) {
+        return Some(target.product_id);
+    }
+
+    let provider_tokens = name_tokens(product.name.as_deref()?);
+    if provider_tokens.is_empty() {
+        return None;
+    }
+
+    let mut best: Option<(usize, i64)> = None;
+    let mut tied = false;
+    for target in targets {
+        let tokens = name_tokens(&target.name);
+        if tokens.is_empty()
+            || !(tokens.is_subset(&provider_tokens) || provider_tokens.is_subset(&tokens))
+        {
+            continue;
+        }
+        match best {
+            Some((len, _)) if tokens.len() < len => {}
+            Some((len, _)) if tokens.len() == len => tied = true,
+            _ => {
+                best = Some((tokens.len(), target.product_id));
+                tied = false;
+            }
+        }
+    }
+
+    if tied {
+        return None;
+    }
+    best.map(|(_, id)| id)
+}
+
+fn name_tokens(name: &str) -> BTreeSet<String> {
+    name.to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|token| !token.is_empty() && !IGNORED_TOKENS.contains(token))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Codes compare case-insensitively; all-digit codes (UPCs) ignore leading zeros.
+fn normalize_code(code: &str) -> String {
+    let code = code.trim();
+    if !code.is_empty() && code.bytes().all(|b| b.is_ascii_digit()) {
+        code.trim_start_matches('0').to_string()
+    } else {
+        code.to_lowercase()
+    }
+}
+
+/// Sweep a product-level locator one matched product at a time.
+///
+/// Detects a `VTInfo` or Destini locator on the page, lists the provider's
+/// product catalog, and runs the provider's store search for each catalog
+/// entry that [`match_locator_product`] ties to one of `targets`. Returns
+/// `Ok(vec![])` when the page has no product-level locator or nothing matches.
+///
+/// Stores are normalized like [`super::fetch_store_locations`] results so
+/// their location keys match brand-level collection.
+///
+/// A Destini sweep walks the full CONUS, Canada and Puerto Rico grid per
+/// product (~100 s each), so callers should expect long runs for brands with
+/// large catalogs.
+///
+/// # Errors
+///
+/// Returns [`LocatorError`] if the locator page or provider catalog cannot
+/// be fetched.
+pub async fn fetch_product_store_locations(
+    client: &reqwest::Client,
+    locator_url: &str,
+    timeout_secs: u64,
+    user_agent: &str,
+    targets: &[ProductMatchTarget],
+) -> Result<Vec<ProductStoreList>, LocatorError> {
+    let mut lists =
+        fetch_from_product_level_locator(client, locator_url, timeout_secs, user_agent, targets)
+            .await?;
+    for list in &mut lists {
+        list.stores.iter_mut().for_each(normalize_store_location);
+    }
+    Ok(lists)
+}
+
+async fn fetch_from_product_level_locator(
+    client: &reqwest::Client,
+    locator_url: &str,
+    timeout_secs: u64,
+    user_agent: &str,
+    targets: &[ProductMatchTarget],
+) -> Result<Vec<ProductStoreList>, LocatorError> {
+    if targets.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let html = fetch_html(client, locator_url, timeout_secs, user_agent).await?;
+
+    if let Some(embed) = extract_vtinfo_embed(&html) {
+        tracing::debug!(locator_url, cust_id = embed.cust_id, "VTInfo product sweep");
+        return fetch_vtinfo_product_stores(
+            client,
+            &embed,
+            locator_url,
+            timeout_secs,
+            user_agent,
+            targets,
+        )
+        .await;
+    }
+
+    if let Some(config) =
+        discover_destini_locator_config(client, &html, locator_url, user_agent).await
+    {
+        tracing::debug!(
+            locator_url,
+            locator_id = config.locator_id,
+            "Destini product sweep"
+        );
+        return fetch_destini_product_stores(client, &config, user_agent, targets).await;
+    }
+
+    tracing::debug!(locator_url, "no product-level locator found");
+    Ok(vec![])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(product_id: i64, name: &str, skus: &[&str]) -> ProductMatchTarget {
+        ProductMatchTarget {
+            product_id,
+            name: name.to_string(),
+            skus: skus.iter().map(|s| (*s).to_string()).collect(),
+        }
+    }
+
+    fn provider(id: &str, name: &str, upc: Option<&str>) -> LocatorProduct {
+        LocatorProduct {
+            provider_product_id: id.to_string(),
+            name: Some(name.to_string()),
+            upc: upc.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn sku_matching_upc_wins_over_names() {
+        let targets = [
+            target(1, "Blood Orange Cardamom", &["0850000000012"]),
+            target(2, "CANN Blood Orange", &[]),
+        ];
+        let product = provider("p-9", "CANN Blood Orange", Some("850000000012"));
+        assert_eq!(match_locator_product(&product, &targets), Some(1));
+    }
+
+    #[test]
+    fn most_specific_name_wins() {
+        let targets = [
+            target(1, "Lemon Lavender", &[]),
+            target(2, "Lemon Lavender 4-Pack", &[]),
+            target(3, "Grapefruit Rosemary", &[]),
+        ];
+        let product = provider("p-1", "CANN Lemon Lavender (4-Pack)", None);
+        assert_eq!(match_locator_product(&product, &targets), Some(2));
+    }
+
+    #[test]
+    fn ambiguous_or_unrelated_names_do_not_match() {
+        let targets = [target(1, "Lemon 5mg", &[]), target(2, "Lemon 10mg", &[])];
+        assert_eq!(
+            match_locator_product(&provider("p-1", "Lemon", None), &targets),
+            None
+        );
+        assert_eq!(
+            match_locator_product(&provider("p-2", "Mango Chili", None), &targets),
+            None
+        );
+    }
+}
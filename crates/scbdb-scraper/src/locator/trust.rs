@@ -9,7 +9,10 @@ use super::types::RawStoreLocation;
 /// `agile_store_locator`, `jsonld`.
 ///
 /// `json_embed` is a fallback parser and is accepted only when quality is
-/// high enough to reduce false positives.
+/// high enough to reduce false positives. Static store lists (`kml`,
+/// `geojson`, `csv`) are explicit store files, but their columns are mapped
+/// by name guessing, so they get the same shape-quality check without the
+/// minimum record count.
 ///
 /// # Errors
 ///
@@ -37,30 +40,39 @@ pub fn validate_store_locations_trust(locations: &[RawStoreLocation]) -> Result<
         | "agile_store_locator"
         | "jsonld"
         | "destini" => Ok(()),
-        "json_embed" => {
-            let quality_count = locations
-                .iter()
-                .filter(|loc| location_record_has_minimum_shape(loc))
-                .count();
-            // Both counts are bounded by the slice length which is at most
-            // usize::MAX; for any realistic data set they fit well within
-            // f64's 52-bit mantissa without precision loss.
-            #[allow(clippy::cast_precision_loss)]
-            let quality_ratio = quality_count as f64 / locations.len() as f64;
-
-            if locations.len() >= 5 && quality_ratio >= 0.80 {
-                Ok(())
-            } else {
-                Err(format!(
-                    "json_embed scrape below trust threshold (count={}, quality_ratio={quality_ratio:.2})",
-                    locations.len()
-                ))
-            }
-        }
+        "json_embed" => check_shape_quality(source, locations, 5),
+        "kml" | "geojson" | "csv" => check_shape_quality(source, locations, 1),
         other => Err(format!("unknown locator source '{other}'")),
     }
 }
 
+/// Accept a fallback-parsed result only when at least `min_count` records
+/// came back and at least 80% of them look like stores.
+fn check_shape_quality(
+    source: &str,
+    locations: &[RawStoreLocation],
+    min_count: usize,
+) -> Result<(), String> {
+    let quality_count = locations
+        .iter()
+        .filter(|loc| location_record_has_minimum_shape(loc))
+        .count();
+    // Both counts are bounded by the slice length which is at most
+    // usize::MAX; for any realistic data set they fit well within
+    // f64's 52-bit mantissa without precision loss.
+    #[allow(clippy::cast_precision_loss)]
+    let quality_ratio = quality_count as f64 / locations.len() as f64;
+
+    if locations.len() >= min_count && quality_ratio >= 0.80 {
+        Ok(())
+    } else {
+        Err(format!(
+            "{source} scrape below trust threshold (count={}, quality_ratio={quality_ratio:.2})",
+            locations.len()
+        ))
+    }
+}
+
 fn location_record_has_minimum_shape(location: &RawStoreLocation) -> bool {
     let has_name = !location.name.trim().is_empty();
     let has_address = location
@@ -88,10 +100,13 @@ fn location_record_has_minimum_shape(location: &RawStoreLocation) -> bool {
 /// `address_line1` is included to disambiguate co-located stores with the same
 /// name in the same zip code (e.g. two "Whole Foods" at different street
 /// addresses in the same postal zone).
+///
+/// Non-US records append `country`, so a Canadian "ON" store never collides
+/// with a US one; US keys (country `US` or unset) omit it and stay stable.
 #[must_use]
 pub fn make_location_key(brand_id: i64, loc: &RawStoreLocation) -> String {
     use sha2::{Digest, Sha256};
-    let input = format!(
+    let mut input = format!(
         "{}\x00{}\x00{}\x00{}\x00{}\x00{}",
         brand_id,
         loc.name.to_lowercase().trim(),
@@ -104,5 +119,13 @@ pub fn make_location_key(brand_id: i64, loc: &RawStoreLocation) -> String {
         loc.state.as_deref().unwrap_or("").trim().to_uppercase(),
         loc.zip.as_deref().unwrap_or("").trim(),
     );
+    match loc.country.as_deref().map(str::trim) {
+        None | Some("") => {}
+        Some(country) if country.eq_ignore_ascii_case("US") => {}
+        Some(country) => {
+            input.push('\x00');
+            input.push_str(&country.to_uppercase());
+        }
+    }
     format!("{:x}", Sha256::digest(input.as_bytes()))
 }
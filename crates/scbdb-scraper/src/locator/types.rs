@@ -19,10 +19,11 @@ pub struct RawStoreLocation {
     ///
     /// One of: `"locally"`, `"storemapper"`, `"stockist"`, `"storepoint"`,
     /// `"roseperl"`, `"vtinfo"`, `"askhoodie"`, `"beveragefinder"`,
-    /// `"storerocket"`, `"agile_store_locator"`, `"destini"`, `"jsonld"`,
-    /// `"json_embed"`.
+    /// `"storerocket"`, `"agile_store_locator"`, `"destini"`, `"kml"`,
+    /// `"geojson"`, `"csv"`, `"jsonld"`, `"json_embed"`.
     pub locator_source: String,
-    /// The raw provider JSON object for this store, preserved for debugging.
+    /// The raw provider JSON object for this store; mapped into structured
+    /// fields by [`crate::extract_location_details`].
     pub raw_data: serde_json::Value,
 }
 
@@ -37,4 +38,24 @@ pub enum LocatorError {
     AllAttemptsFailed { url: String },
     #[error("non-success HTTP status {status} fetching {url}")]
     HttpStatus { status: u16, url: String },
+    #[error("response from {url} exceeds {limit} bytes")]
+    ResponseTooLarge { url: String, limit: usize },
+}
+
+/// A product listed in a product-level locator's catalog (Destini, `VTInfo`).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LocatorProduct {
+    /// The provider's id for the product (Destini `pID`, `VTInfo` option value).
+    pub provider_product_id: String,
+    pub name: Option<String>,
+    pub upc: Option<String>,
+}
+
+/// Stores a product-level locator returned for one product.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ProductStoreList {
+    pub product: LocatorProduct,
+    /// Our `products.id` the provider product was matched to.
+    pub product_id: i64,
+    pub stores: Vec<RawStoreLocation>,
 }
@@ -0,0 +1,256 @@
+//! Retailer account endpoints.
+//!
+//! - `POST /api/v1/accounts/import`              — upsert accounts from an ERP export
+//! - `GET  /api/v1/accounts/competitor-presence` — competitor brands listed at our accounts
+//! - `GET  /api/v1/accounts/prospects`           — competitor storefronts that are not accounts
+
+use axum::{
+    extract::{Query, State},
+    Extension, Json,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::middleware::RequestId;
+
+use super::{
+    map_db_error, normalize_limit, parse_slug_list, ApiError, ApiResponse, AppState, ResponseMeta,
+};
+
+/// Upper bound on accounts per import request; larger exports go through
+/// `scbdb-cli accounts import`.
+const MAX_IMPORT_ACCOUNTS: usize = 10_000;
+
+// ---------------------------------------------------------------------------
+// Request bodies
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize)]
+pub(super) struct ImportAccountsRequest {
+    pub accounts: Vec<ImportAccount>,
+    /// Mark active accounts absent from `accounts` as inactive.
+    #[serde(default)]
+    pub deactivate_missing: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct ImportAccount {
+    pub account_number: String,
+    pub name: String,
+    pub address_line1: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub zip: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct CompetitorPresenceQuery {
+    pub state: Option<String>,
+    /// Comma-separated competitor slugs; omit for every competitor.
+    pub competitors: Option<String>,
+    /// Only accounts where at least one competitor is listed.
+    pub with_competitors: Option<bool>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct ProspectsQuery {
+    pub state: Option<String>,
+    pub channel: Option<String>,
+    /// Comma-separated competitor slugs; omit for every competitor.
+    pub competitors: Option<String>,
+    pub limit: Option<i64>,
+}
+
+// ---------------------------------------------------------------------------
+// Response bodies
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Serialize)]
+pub(super) struct ImportAccountsResponse {
+    pub received: usize,
+    pub inserted: u64,
+    pub updated: u64,
+    pub deactivated: u64,
+    pub unmatchable: u64,
+    pub matched: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct AccountPresenceItem {
+    pub account_number: String,
+    pub name: String,
+    pub address_line1: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub zip: Option<String>,
+    pub matched_store_count: i64,
+    pub competitor_brand_count: i64,
+    pub competitor_brands: Vec<String>,
+    pub portfolio_brands: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct ProspectStoreItem {
+    pub name: String,
+    pub address_line1: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub zip: Option<String>,
+    pub retail_chain: Option<String>,
+    pub retail_channel: Option<String>,
+    pub competitor_brand_count: i64,
+    pub competitor_brands: Vec<String>,
+    pub portfolio_brands: Vec<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Handlers
+// ---------------------------------------------------------------------------
+
+/// POST /api/v1/accounts/import — upsert accounts by account number.
+pub(super) async fn import_accounts(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Json(body): Json<ImportAccountsRequest>,
+) -> Result<Json<ApiResponse<ImportAccountsResponse>>, ApiError> {
+    let rid = &req_id.0;
+
+    if body.accounts.is_empty() || body.accounts.len() > MAX_IMPORT_ACCOUNTS {
+        return Err(ApiError::new(
+            rid,
+            "validation_error",
+            format!("accounts must contain 1–{MAX_IMPORT_ACCOUNTS} entries"),
+        ));
+    }
+    let mut accounts = Vec::with_capacity(body.accounts.len());
+    for (idx, account) in body.accounts.into_iter().enumerate() {
+        let account_number = account.account_number.trim().to_owned();
+        let name = account.name.trim().to_owned();
+        if account_number.is_empty() || name.is_empty() {
+            return Err(ApiError::new(
+                rid,
+                "validation_error",
+                format!("accounts[{idx}]: account_number and name are required"),
+            ));
+        }
+        accounts.push(scbdb_db::NewAccount {
+            account_number,
+            name,
+            address_line1: account.address_line1,
+            city: account.city,
+            state: account.state.map(|s| s.trim().to_uppercase()),
+            zip: account.zip,
+        });
+    }
+
+    let summary = scbdb_db::import_accounts(&state.pool, &accounts, body.deactivate_missing)
+        .await
+        .map_err(|e| map_db_error(rid.clone(), &e))?;
+
+    Ok(Json(ApiResponse {
+        data: ImportAccountsResponse {
+            received: accounts.len(),
+            inserted: summary.inserted,
+            updated: summary.updated,
+            deactivated: summary.deactivated,
+            unmatchable: summary.unmatchable,
+            matched: summary.matched,
+        },
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+pub(super) async fn list_competitor_presence(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<CompetitorPresenceQuery>,
+) -> Result<Json<ApiResponse<Vec<AccountPresenceItem>>>, ApiError> {
+    let limit = normalize_limit(query.limit);
+    let competitors = parse_slug_list(query.competitors.as_deref());
+    let state_code = query.state.as_deref().map(str::to_uppercase);
+
+    let rows = scbdb_db::list_account_competitor_presence(
+        &state.pool,
+        &scbdb_db::AccountPresenceFilters {
+            state: state_code.as_deref(),
+            competitor_slugs: &competitors,
+            with_competitors_only: query.with_competitors.unwrap_or(false),
+        },
+        limit,
+    )
+    .await
+    .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?;
+
+    let data = rows
+        .into_iter()
+        .map(|row| AccountPresenceItem {
+            account_number: row.account_number,
+            name: row.name,
+            address_line1: row.address_line1,
+            city: row.city,
+            state: row.state,
+            zip: row.zip,
+            matched_store_count: row.matched_store_count,
+            competitor_brand_count: row.competitor_brand_count,
+            competitor_brands: row.competitor_brands,
+            portfolio_brands: row.portfolio_brands,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+pub(super) async fn list_prospects(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<ProspectsQuery>,
+) -> Result<Json<ApiResponse<Vec<ProspectStoreItem>>>, ApiError> {
+    if let Some(channel) = query.channel.as_deref() {
+        if scbdb_scraper::RetailChannel::parse(channel).is_none() {
+            return Err(ApiError::new(
+                &req_id.0,
+                "validation_error",
+                format!("unknown retail channel '{channel}'"),
+            ));
+        }
+    }
+    let limit = normalize_limit(query.limit);
+    let competitors = parse_slug_list(query.competitors.as_deref());
+    let state_code = query.state.as_deref().map(str::to_uppercase);
+
+    let rows = scbdb_db::list_competitor_stores_without_account(
+        &state.pool,
+        &scbdb_db::ProspectFilters {
+            state: state_code.as_deref(),
+            channel: query.channel.as_deref(),
+            competitor_slugs: &competitors,
+        },
+        limit,
+    )
+    .await
+    .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?;
+
+    let data = rows
+        .into_iter()
+        .map(|row| ProspectStoreItem {
+            name: row.name,
+            address_line1: row.address_line1,
+            city: row.city,
+            state: row.state,
+            zip: row.zip,
+            retail_chain: row.retail_chain,
+            retail_channel: row.retail_channel,
+            competitor_brand_count: row.competitor_brand_count,
+            competitor_brands: row.competitor_brands,
+            portfolio_brands: row.portfolio_brands,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
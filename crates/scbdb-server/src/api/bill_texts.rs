@@ -0,0 +1,178 @@
+//! Extracted bill text versions and section-level diffs between them.
+
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::middleware::RequestId;
+
+use super::{map_db_error, ApiError, ApiResponse, AppState, ResponseMeta};
+
+#[derive(Debug, Serialize)]
+pub(super) struct BillTextContentItem {
+    text_id: i64,
+    text_date: Option<NaiveDate>,
+    text_type: String,
+    mime: String,
+    url: Option<String>,
+    content: Option<String>,
+    extraction_error: Option<String>,
+    extracted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct BillDiffQuery {
+    pub from: Option<i64>,
+    pub to: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(clippy::struct_field_names)] // Mirrors the `text_*` fields of `BillTextItem`
+pub(super) struct BillTextVersionRef {
+    text_id: i64,
+    text_date: Option<NaiveDate>,
+    text_type: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct BillDiffResponse {
+    from: BillTextVersionRef,
+    to: BillTextVersionRef,
+    diff: scbdb_core::BillTextDiff,
+}
+
+pub(super) async fn get_bill_text(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Path((bill_id, text_id)): Path<(Uuid, i64)>,
+) -> Result<Json<ApiResponse<BillTextContentItem>>, ApiError> {
+    let row = scbdb_db::get_bill_text_content(&state.pool, bill_id, text_id)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    let Some(row) = row else {
+        return Err(ApiError::new(req_id.0, "not_found", "bill text not found"));
+    };
+
+    let data = BillTextContentItem {
+        text_id: row.legiscan_text_id,
+        text_date: row.text_date,
+        text_type: row.text_type,
+        mime: row.mime,
+        url: row.legiscan_url,
+        content: row.content,
+        extraction_error: row.extraction_error,
+        extracted_at: row.extracted_at,
+    };
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+/// Diff two text versions of a bill. `to` defaults to the newest version
+/// with extracted text and `from` to the extracted version before `to`.
+pub(super) async fn diff_bill_texts(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Path(bill_id): Path<Uuid>,
+    Query(query): Query<BillDiffQuery>,
+) -> Result<Json<ApiResponse<BillDiffResponse>>, ApiError> {
+    let bill = scbdb_db::get_bill_by_public_id(&state.pool, bill_id)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    if bill.is_none() {
+        return Err(ApiError::new(req_id.0, "not_found", "bill not found"));
+    }
+
+    // Newest first, so "the version before" is the next extracted entry.
+    let versions = scbdb_db::list_bill_texts_by_public_id(&state.pool, bill_id)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    let to_id = match query.to {
+        Some(id) => id,
+        None => versions
+            .iter()
+            .find(|v| v.has_content)
+            .map(|v| v.legiscan_text_id)
+            .ok_or_else(|| {
+                ApiError::new(
+                    req_id.0.clone(),
+                    "validation_error",
+                    "bill has no extracted text versions",
+                )
+            })?,
+    };
+    let from_id = match query.from {
+        Some(id) => id,
+        None => versions
+            .iter()
+            .skip_while(|v| v.legiscan_text_id != to_id)
+            .skip(1)
+            .find(|v| v.has_content)
+            .map(|v| v.legiscan_text_id)
+            .ok_or_else(|| {
+                ApiError::new(
+                    req_id.0.clone(),
+                    "validation_error",
+                    "no earlier extracted version to compare against; pass `from`",
+                )
+            })?,
+    };
+
+    let (from, from_text) = load_version(&state, &req_id, bill_id, from_id).await?;
+    let (to, to_text) = load_version(&state, &req_id, bill_id, to_id).await?;
+
+    let data = BillDiffResponse {
+        diff: scbdb_core::diff_bill_texts(&from_text, &to_text),
+        from,
+        to,
+    };
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+async fn load_version(
+    state: &AppState,
+    req_id: &RequestId,
+    bill_id: Uuid,
+    text_id: i64,
+) -> Result<(BillTextVersionRef, String), ApiError> {
+    let row = scbdb_db::get_bill_text_content(&state.pool, bill_id, text_id)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    let Some(row) = row else {
+        return Err(ApiError::new(
+            req_id.0.clone(),
+            "not_found",
+            format!("bill text {text_id} not found"),
+        ));
+    };
+    let Some(content) = row.content else {
+        return Err(ApiError::new(
+            req_id.0.clone(),
+            "validation_error",
+            format!("bill text {text_id} has no extracted content"),
+        ));
+    };
+
+    Ok((
+        BillTextVersionRef {
+            text_id: row.legiscan_text_id,
+            text_date: row.text_date,
+            text_type: row.text_type,
+        },
+        content,
+    ))
+}
@@ -12,6 +12,10 @@ use super::{map_db_error, normalize_limit, ApiError, ApiResponse, AppState, Resp
 #[derive(Debug, Deserialize)]
 pub(super) struct BillsQuery {
     pub jurisdiction: Option<String>,
+    /// Bill topic taxonomy slug, e.g. `thc_serving_caps`.
+    pub topic: Option<String>,
+    /// Minimum relevance score, 0–100.
+    pub min_relevance: Option<f64>,
     pub limit: Option<i64>,
 }
 
@@ -28,6 +32,8 @@ pub(super) struct BillItem {
     last_action_date: Option<chrono::NaiveDate>,
     source_url: Option<String>,
     event_count: usize,
+    relevance_score: Option<f64>,
+    topics: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -44,18 +50,33 @@ pub(super) async fn list_bills(
     Extension(req_id): Extension<RequestId>,
     Query(query): Query<BillsQuery>,
 ) -> Result<Json<ApiResponse<Vec<BillItem>>>, ApiError> {
-    let bills = scbdb_db::list_bills(
-        &state.pool,
-        query.jurisdiction.as_deref(),
-        normalize_limit(query.limit),
-    )
-    .await
-    .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+    if query
+        .min_relevance
+        .is_some_and(|min| !(0.0..=100.0).contains(&min))
+    {
+        return Err(ApiError::new(
+            req_id.0,
+            "validation_error",
+            "min_relevance must be between 0 and 100",
+        ));
+    }
+
+    let filters = scbdb_db::BillFilters {
+        jurisdiction: query.jurisdiction.as_deref(),
+        topic: query.topic.as_deref(),
+        min_relevance: query.min_relevance,
+    };
+    let bills = scbdb_db::list_bills_filtered(&state.pool, &filters, normalize_limit(query.limit))
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
 
     let bill_ids: Vec<i64> = bills.iter().map(|bill| bill.id).collect();
     let events_by_bill = scbdb_db::list_bill_events_batch(&state.pool, &bill_ids)
         .await
         .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+    let mut topics_by_bill = scbdb_db::list_bill_topics_batch(&state.pool, &bill_ids)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
 
     let data = bills
         .into_iter()
@@ -71,6 +92,8 @@ pub(super) async fn list_bills(
             last_action_date: bill.last_action_date,
             source_url: bill.source_url,
             event_count: events_by_bill.get(&bill.id).map_or(0, Vec::len),
+            relevance_score: bill.relevance_score,
+            topics: topic_slugs(topics_by_bill.remove(&bill.id)),
         })
         .collect();
 
@@ -96,6 +119,9 @@ pub(super) async fn get_bill(
     let events = scbdb_db::list_bill_events(&state.pool, bill.id)
         .await
         .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+    let mut topics = scbdb_db::list_bill_topics_batch(&state.pool, &[bill.id])
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
 
     let data = BillItem {
         bill_id: bill.public_id,
@@ -109,6 +135,8 @@ pub(super) async fn get_bill(
         last_action_date: bill.last_action_date,
         source_url: bill.source_url,
         event_count: events.len(),
+        relevance_score: bill.relevance_score,
+        topics: topic_slugs(topics.remove(&bill.id)),
     };
 
     Ok(Json(ApiResponse {
@@ -117,12 +145,23 @@ pub(super) async fn get_bill(
     }))
 }
 
+/// Topic slugs, highest score first (the DB order).
+fn topic_slugs(rows: Option<Vec<scbdb_db::BillTopicRow>>) -> Vec<String> {
+    rows.unwrap_or_default()
+        .into_iter()
+        .map(|row| row.topic)
+        .collect()
+}
+
 #[derive(Debug, Serialize)]
 pub(super) struct BillTextItem {
+    text_id: i64,
     text_date: Option<chrono::NaiveDate>,
     text_type: String,
     mime: String,
     url: Option<String>,
+    has_content: bool,
+    extraction_error: Option<String>,
 }
 
 pub(super) async fn list_bill_texts(
@@ -145,10 +184,13 @@ pub(super) async fn list_bill_texts(
     let data = rows
         .into_iter()
         .map(|t| BillTextItem {
+            text_id: t.legiscan_text_id,
             text_date: t.text_date,
             text_type: t.text_type,
             mime: t.mime,
             url: t.legiscan_url,
+            has_content: t.has_content,
+            extraction_error: t.extraction_error,
         })
         .collect();
 
@@ -211,6 +253,8 @@ mod tests {
             last_action_date: None,
             source_url: Some("https://example.com/bill".to_string()),
             event_count: 2,
+            relevance_score: Some(72.5),
+            topics: vec!["excise_tax".to_string()],
         };
 
         let json = serde_json::to_string(&item).expect("serialize bill item");
@@ -0,0 +1,205 @@
+//! Review queue for auto-discovered brands.
+//!
+//! - `GET  /api/v1/brand-candidates`               — candidates, best-evidenced first
+//! - `POST /api/v1/brand-candidates/{id}/promote`  — create the brand and close the candidate
+//! - `POST /api/v1/brand-candidates/{id}/dismiss`  — drop a candidate from the queue
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::middleware::RequestId;
+
+use super::brands::{
+    map_unique_violation, validate_new_brand, CreateBrandRequest, CreateBrandResponse,
+};
+use super::{map_db_error, normalize_limit, ApiError, ApiResponse, AppState, ResponseMeta};
+
+const CANDIDATE_STATUSES: &[&str] = &["pending", "promoted", "dismissed"];
+
+#[derive(Debug, Deserialize)]
+pub(super) struct BrandCandidateListQuery {
+    /// Defaults to `pending`; `all` lists every status.
+    pub status: Option<String>,
+    /// Minimum evidence rows (default 1).
+    pub min_evidence: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+/// Brand fields for promotion; the name defaults to the candidate's.
+#[derive(Debug, Deserialize)]
+pub(super) struct PromoteBrandCandidateRequest {
+    pub name: Option<String>,
+    pub relationship: String,
+    pub tier: i16,
+    pub domain: Option<String>,
+    pub shop_url: Option<String>,
+    pub store_locator_url: Option<String>,
+    pub twitter_handle: Option<String>,
+    pub notes: Option<String>,
+    pub review_note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct DismissBrandCandidateRequest {
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct BrandCandidateItem {
+    pub id: Uuid,
+    pub name: String,
+    pub slug: String,
+    pub status: String,
+    pub brand_slug: Option<String>,
+    pub evidence_count: i64,
+    pub sources: Vec<String>,
+    pub sample_contexts: Vec<String>,
+    pub review_note: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub first_seen_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+fn map_review_error(request_id: &str, error: &scbdb_db::DbError) -> ApiError {
+    match error {
+        scbdb_db::DbError::NotFound => {
+            ApiError::new(request_id, "not_found", "brand candidate not found")
+        }
+        scbdb_db::DbError::BrandCandidateNotPending { .. } => {
+            ApiError::new(request_id, "conflict", error.to_string())
+        }
+        _ => map_db_error(request_id.to_owned(), error),
+    }
+}
+
+pub(super) async fn list_brand_candidates(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<BrandCandidateListQuery>,
+) -> Result<Json<ApiResponse<Vec<BrandCandidateItem>>>, ApiError> {
+    let status = match query.status.as_deref().unwrap_or("pending") {
+        "all" => None,
+        s if CANDIDATE_STATUSES.contains(&s) => Some(s),
+        other => {
+            return Err(ApiError::new(
+                &req_id.0,
+                "validation_error",
+                format!(
+                    "status must be one of all, {}, got '{other}'",
+                    CANDIDATE_STATUSES.join(", ")
+                ),
+            ))
+        }
+    };
+    let min_evidence = query.min_evidence.unwrap_or(1).max(1);
+    let limit = normalize_limit(query.limit);
+
+    let rows = scbdb_db::list_brand_candidates(&state.pool, status, min_evidence, limit)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?;
+
+    let data = rows
+        .into_iter()
+        .map(|row| BrandCandidateItem {
+            id: row.public_id,
+            name: row.name,
+            slug: row.slug,
+            status: row.status,
+            brand_slug: row.brand_slug,
+            evidence_count: row.evidence_count,
+            sources: row.sources,
+            sample_contexts: row.sample_contexts,
+            review_note: row.review_note,
+            reviewed_at: row.reviewed_at,
+            first_seen_at: row.first_seen_at,
+            last_seen_at: row.last_seen_at,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+/// POST /api/v1/brand-candidates/:id/promote — create the brand through the
+/// same validation as `POST /api/v1/brands` and mark the candidate promoted,
+/// in one transaction.
+pub(super) async fn promote_brand_candidate(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Path(id): Path<Uuid>,
+    Json(body): Json<PromoteBrandCandidateRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<CreateBrandResponse>>), ApiError> {
+    let rid = &req_id.0;
+    let candidate = scbdb_db::get_brand_candidate(&state.pool, id)
+        .await
+        .map_err(|e| map_db_error(rid.clone(), &scbdb_db::DbError::from(e)))?
+        .ok_or_else(|| ApiError::new(rid, "not_found", "brand candidate not found"))?;
+
+    let request = CreateBrandRequest {
+        name: body.name.unwrap_or(candidate.name),
+        relationship: body.relationship,
+        tier: body.tier,
+        domain: body.domain,
+        shop_url: body.shop_url,
+        store_locator_url: body.store_locator_url,
+        twitter_handle: body.twitter_handle,
+        notes: body.notes,
+    };
+    let name = validate_new_brand(rid, &request)?;
+    let slug = scbdb_core::brands::slug_from_name(&name);
+    let brand = scbdb_db::NewBrand {
+        name: &name,
+        slug: &slug,
+        relationship: &request.relationship,
+        tier: request.tier,
+        domain: request.domain.as_deref(),
+        shop_url: request.shop_url.as_deref(),
+        store_locator_url: request.store_locator_url.as_deref(),
+        twitter_handle: request.twitter_handle.as_deref(),
+        notes: request.notes.as_deref(),
+    };
+    let row =
+        scbdb_db::promote_brand_candidate(&state.pool, id, &brand, body.review_note.as_deref())
+            .await
+            .map_err(|e| match e {
+                scbdb_db::DbError::NotFound
+                | scbdb_db::DbError::BrandCandidateNotPending { .. } => map_review_error(rid, &e),
+                other => map_unique_violation(rid, &other),
+            })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: CreateBrandResponse {
+                id: row.id,
+                slug: row.slug,
+            },
+            meta: ResponseMeta::new(req_id.0),
+        }),
+    ))
+}
+
+/// POST /api/v1/brand-candidates/:id/dismiss — remove a candidate from review.
+pub(super) async fn dismiss_brand_candidate(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Path(id): Path<Uuid>,
+    Json(body): Json<DismissBrandCandidateRequest>,
+) -> Result<Json<ApiResponse<serde_json::Value>>, ApiError> {
+    scbdb_db::dismiss_brand_candidate(&state.pool, id, body.note.as_deref())
+        .await
+        .map_err(|e| map_review_error(&req_id.0, &e))?;
+
+    Ok(Json(ApiResponse {
+        data: serde_json::json!({ "dismissed": true }),
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
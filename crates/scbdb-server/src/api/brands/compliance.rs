@@ -0,0 +1,145 @@
+//! GET /api/v1/brands/:slug/compliance — the brand's products and stores
+//! checked against the state rules in effect.
+
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use chrono::{NaiveDate, Utc};
+use scbdb_core::Severity;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::middleware::RequestId;
+
+use super::super::{map_db_error, ApiError, ApiResponse, AppState, ResponseMeta};
+use super::resolve_brand;
+
+#[derive(Debug, Deserialize)]
+pub(in crate::api) struct ComplianceQuery {
+    /// Rules in effect on this date; defaults to today.
+    pub as_of: Option<NaiveDate>,
+    pub jurisdiction: Option<String>,
+    /// Only violations at least this severe.
+    pub severity: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct ComplianceViolationItem {
+    severity: Severity,
+    jurisdiction: String,
+    rule_kind: &'static str,
+    rule_id: Uuid,
+    bill_number: Option<String>,
+    product_id: Option<Uuid>,
+    product_name: Option<String>,
+    variant_id: Option<Uuid>,
+    variant_title: Option<String>,
+    size_value: Option<f64>,
+    size_unit: Option<String>,
+    pack_count: Option<u32>,
+    limit_mg: Option<f64>,
+    actual_mg: Option<f64>,
+    retail_channel: Option<String>,
+    product_store_count: i64,
+    brand_store_count: i64,
+    message: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub(in crate::api) struct SeverityCounts {
+    critical: usize,
+    warning: usize,
+    info: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct BrandComplianceReport {
+    brand_slug: String,
+    as_of: NaiveDate,
+    variants_checked: usize,
+    variants_without_dosage: usize,
+    states_with_rules: usize,
+    counts: SeverityCounts,
+    violations: Vec<ComplianceViolationItem>,
+}
+
+pub(in crate::api) async fn get_brand_compliance(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Path(slug): Path<String>,
+    Query(query): Query<ComplianceQuery>,
+) -> Result<Json<ApiResponse<BrandComplianceReport>>, ApiError> {
+    let rid = &req_id.0;
+    let min_severity = match query.severity.as_deref() {
+        Some(value) => Severity::parse(value).ok_or_else(|| {
+            ApiError::new(
+                rid,
+                "validation_error",
+                format!("severity must be critical, warning or info, got '{value}'"),
+            )
+        })?,
+        None => Severity::Info,
+    };
+    let brand = resolve_brand(&state.pool, &slug, rid).await?;
+    let as_of = query.as_of.unwrap_or_else(|| Utc::now().date_naive());
+    let jurisdiction = query.jurisdiction.map(|j| j.to_uppercase());
+
+    let report = scbdb_db::check_compliance(
+        &state.pool,
+        &scbdb_db::ComplianceFilters {
+            brand_slug: Some(&brand.slug),
+            jurisdiction: jurisdiction.as_deref(),
+        },
+        as_of,
+    )
+    .await
+    .map_err(|e| map_db_error(rid.clone(), &e))?;
+
+    let mut counts = SeverityCounts::default();
+    for violation in &report.violations {
+        match violation.severity {
+            Severity::Critical => counts.critical += 1,
+            Severity::Warning => counts.warning += 1,
+            Severity::Info => counts.info += 1,
+        }
+    }
+    let violations = report
+        .violations
+        .into_iter()
+        .filter(|v| v.severity <= min_severity)
+        .map(|v| ComplianceViolationItem {
+            severity: v.severity,
+            jurisdiction: v.jurisdiction,
+            rule_kind: v.rule_kind.as_str(),
+            rule_id: v.rule_public_id,
+            bill_number: v.bill_number,
+            product_id: v.product_public_id,
+            product_name: v.product_name,
+            variant_id: v.variant_public_id,
+            variant_title: v.variant_title,
+            size_value: v.size_value,
+            size_unit: v.size_unit,
+            pack_count: v.pack_count,
+            limit_mg: v.limit_mg,
+            actual_mg: v.actual_mg,
+            retail_channel: v.retail_channel,
+            product_store_count: v.product_store_count,
+            brand_store_count: v.brand_store_count,
+            message: v.message,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse {
+        data: BrandComplianceReport {
+            brand_slug: brand.slug,
+            as_of,
+            variants_checked: report.variants_checked,
+            variants_without_dosage: report.variants_without_dosage,
+            states_with_rules: report.states_with_rules,
+            counts,
+            violations,
+        },
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
@@ -10,7 +10,9 @@
 //! - `GET /api/v1/brands/:slug/distributors` — distributors
 //! - `GET /api/v1/brands/:slug/competitors`  — competitor relationships
 //! - `GET /api/v1/brands/:slug/media`    — media appearances
+//! - `GET /api/v1/brands/:slug/compliance` — products and stores checked against state rules
 
+mod compliance;
 mod detail;
 mod intel;
 mod list;
@@ -18,6 +20,7 @@ mod signals;
 mod write;
 mod write_enrichment;
 
+pub(super) use compliance::get_brand_compliance;
 pub(super) use detail::get_brand;
 pub(super) use intel::{
     list_competitors, list_distributors, list_funding, list_lab_tests, list_legal, list_media,
@@ -25,7 +28,10 @@ pub(super) use intel::{
 };
 pub(super) use list::list_brands;
 pub(super) use signals::list_brand_signals;
-pub(super) use write::{create_brand, deactivate_brand, update_brand};
+pub(super) use write::{
+    create_brand, deactivate_brand, map_unique_violation, update_brand, validate_new_brand,
+    CreateBrandRequest, CreateBrandResponse,
+};
 pub(super) use write_enrichment::{
     upsert_brand_domains, upsert_brand_profile, upsert_brand_social,
 };
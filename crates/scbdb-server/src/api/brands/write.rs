@@ -88,7 +88,7 @@ fn validate_url_if_present(req_id: &str, field: &str, value: &str) -> Result<(),
     .map(|_| ())
 }
 
-fn map_unique_violation(req_id: &str, e: &scbdb_db::DbError) -> ApiError {
+pub(in crate::api) fn map_unique_violation(req_id: &str, e: &scbdb_db::DbError) -> ApiError {
     if let scbdb_db::DbError::Sqlx(sqlx::Error::Database(db_err)) = e {
         if db_err.code().as_deref() == Some("23505") {
             return ApiError::new(req_id, "conflict", "a brand with that slug already exists");
@@ -101,14 +101,14 @@ fn map_unique_violation(req_id: &str, e: &scbdb_db::DbError) -> ApiError {
 // Handlers
 // ---------------------------------------------------------------------------
 
-/// POST /api/v1/brands — create a new brand.
-pub(in crate::api) async fn create_brand(
-    State(state): State<AppState>,
-    Extension(req_id): Extension<RequestId>,
-    Json(body): Json<CreateBrandRequest>,
-) -> Result<(StatusCode, Json<ApiResponse<CreateBrandResponse>>), ApiError> {
-    let rid = &req_id.0;
-
+/// Validate a create request and return the trimmed brand name.
+///
+/// Shared by `POST /api/v1/brands` and brand-candidate promotion so both
+/// apply the same rules.
+pub(in crate::api) fn validate_new_brand(
+    rid: &str,
+    body: &CreateBrandRequest,
+) -> Result<String, ApiError> {
     let name = body.name.trim().to_owned();
     if name.is_empty() || name.len() > 200 {
         return Err(ApiError::new(
@@ -125,11 +125,20 @@ pub(in crate::api) async fn create_brand(
     if let Some(ref u) = body.store_locator_url {
         validate_url_if_present(rid, "store_locator_url", u)?;
     }
+    Ok(name)
+}
 
+/// Validate a create request and insert the brand.
+async fn insert_brand(
+    pool: &sqlx::PgPool,
+    rid: &str,
+    body: &CreateBrandRequest,
+) -> Result<CreateBrandResponse, ApiError> {
+    let name = validate_new_brand(rid, body)?;
     let slug = scbdb_core::brands::slug_from_name(&name);
 
     let row = scbdb_db::create_brand(
-        &state.pool,
+        pool,
         &name,
         &slug,
         &body.relationship,
@@ -143,13 +152,24 @@ pub(in crate::api) async fn create_brand(
     .await
     .map_err(|e| map_unique_violation(rid, &e))?;
 
+    Ok(CreateBrandResponse {
+        id: row.id,
+        slug: row.slug,
+    })
+}
+
+/// POST /api/v1/brands — create a new brand.
+pub(in crate::api) async fn create_brand(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Json(body): Json<CreateBrandRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<CreateBrandResponse>>), ApiError> {
+    let data = insert_brand(&state.pool, &req_id.0, &body).await?;
+
     Ok((
         StatusCode::CREATED,
         Json(ApiResponse {
-            data: CreateBrandResponse {
-                id: row.id,
-                slug: row.slug,
-            },
+            data,
             meta: ResponseMeta::new(req_id.0),
         }),
     ))
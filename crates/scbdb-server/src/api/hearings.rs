@@ -0,0 +1,157 @@
+//! Committee hearings and legislative calendar entries.
+
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use chrono::{Days, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::middleware::RequestId;
+
+use super::{map_db_error, normalize_limit, ApiError, ApiResponse, AppState, ResponseMeta};
+
+/// Default and maximum look-ahead of the upcoming hearings endpoint.
+const DEFAULT_DAYS: u32 = 14;
+const MAX_DAYS: u32 = 90;
+
+#[derive(Debug, Deserialize)]
+pub(super) struct UpcomingHearingsQuery {
+    /// Days ahead to include, counting today; 1–90, default 14.
+    pub days: Option<u32>,
+    pub jurisdiction: Option<String>,
+    /// Bill topic taxonomy slug, e.g. `thc_serving_caps`.
+    pub topic: Option<String>,
+    /// Minimum bill relevance score, 0–100.
+    pub min_relevance: Option<f64>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct HearingItem {
+    hearing_date: NaiveDate,
+    hearing_time: Option<NaiveTime>,
+    event_type: String,
+    committee: Option<String>,
+    chamber: Option<String>,
+    location: Option<String>,
+    description: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct UpcomingHearingItem {
+    #[serde(flatten)]
+    hearing: HearingItem,
+    bill_id: Uuid,
+    jurisdiction: String,
+    bill_number: String,
+    title: String,
+    status: String,
+    relevance_score: Option<f64>,
+}
+
+pub(super) async fn list_upcoming_hearings(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<UpcomingHearingsQuery>,
+) -> Result<Json<ApiResponse<Vec<UpcomingHearingItem>>>, ApiError> {
+    let days = query.days.unwrap_or(DEFAULT_DAYS);
+    if !(1..=MAX_DAYS).contains(&days) {
+        return Err(ApiError::new(
+            req_id.0,
+            "validation_error",
+            format!("days must be between 1 and {MAX_DAYS}"),
+        ));
+    }
+    if query
+        .min_relevance
+        .is_some_and(|min| !(0.0..=100.0).contains(&min))
+    {
+        return Err(ApiError::new(
+            req_id.0,
+            "validation_error",
+            "min_relevance must be between 0 and 100",
+        ));
+    }
+
+    let today = Utc::now().date_naive();
+    let until = today
+        .checked_add_days(Days::new(u64::from(days - 1)))
+        .unwrap_or(today);
+    let filters = scbdb_db::HearingFilters {
+        jurisdiction: query.jurisdiction.as_deref(),
+        topic: query.topic.as_deref(),
+        min_relevance: query.min_relevance,
+    };
+    let rows = scbdb_db::list_upcoming_hearings(
+        &state.pool,
+        today,
+        until,
+        &filters,
+        normalize_limit(query.limit),
+    )
+    .await
+    .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    let data = rows
+        .into_iter()
+        .map(|row| UpcomingHearingItem {
+            hearing: HearingItem {
+                hearing_date: row.hearing_date,
+                hearing_time: row.hearing_time,
+                event_type: row.event_type,
+                committee: row.committee,
+                chamber: row.chamber,
+                location: row.location,
+                description: row.description,
+            },
+            bill_id: row.bill_public_id,
+            jurisdiction: row.jurisdiction,
+            bill_number: row.bill_number,
+            title: row.title,
+            status: row.status,
+            relevance_score: row.relevance_score,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+pub(super) async fn list_bill_hearings(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Path(bill_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<HearingItem>>>, ApiError> {
+    let bill = scbdb_db::get_bill_by_public_id(&state.pool, bill_id)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+    if bill.is_none() {
+        return Err(ApiError::new(req_id.0, "not_found", "bill not found"));
+    }
+
+    let rows = scbdb_db::list_bill_hearings_by_public_id(&state.pool, bill_id)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    let data = rows
+        .into_iter()
+        .map(|row| HearingItem {
+            hearing_date: row.hearing_date,
+            hearing_time: row.hearing_time,
+            event_type: row.event_type,
+            committee: row.committee,
+            chamber: row.chamber,
+            location: row.location,
+            description: row.description,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
@@ -0,0 +1,48 @@
+//! `LegiScan` request usage for the current month, per API key.
+
+use axum::{extract::State, Extension, Json};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use serde::Serialize;
+
+use crate::middleware::RequestId;
+
+use super::{map_db_error, ApiError, ApiResponse, AppState, ResponseMeta};
+
+#[derive(Debug, Serialize)]
+pub(super) struct LegiscanQuotaItem {
+    /// Prefix of the API key's SHA-256 hash; the key itself is never stored.
+    key_fingerprint: String,
+    month: NaiveDate,
+    requests_used: i32,
+    monthly_budget: i32,
+    remaining: i32,
+    updated_at: DateTime<Utc>,
+}
+
+pub(super) async fn get_legiscan_quota(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+) -> Result<Json<ApiResponse<Vec<LegiscanQuotaItem>>>, ApiError> {
+    let today = Utc::now().date_naive();
+    let month = today.with_day(1).unwrap_or(today);
+    let rows = scbdb_db::list_legiscan_usage(&state.pool, month)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    let data = rows
+        .into_iter()
+        .map(|row| LegiscanQuotaItem {
+            remaining: (row.monthly_budget - row.requests).max(0),
+            key_fingerprint: row.key_fingerprint,
+            month: row.month,
+            requests_used: row.requests,
+            monthly_budget: row.monthly_budget,
+            updated_at: row.updated_at,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
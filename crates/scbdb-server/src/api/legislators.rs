@@ -0,0 +1,345 @@
+//! Bill sponsors, roll call votes and legislators' records on tracked bills.
+
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::middleware::RequestId;
+
+use super::{map_db_error, normalize_limit, ApiError, ApiResponse, AppState, ResponseMeta};
+
+#[derive(Debug, Deserialize)]
+pub(super) struct LegislatorsQuery {
+    pub jurisdiction: Option<String>,
+    /// Case-insensitive name substring.
+    pub q: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct LegislatorRecordQuery {
+    /// Bill topic taxonomy slug, e.g. `thc_serving_caps`.
+    pub topic: Option<String>,
+    /// Minimum bill relevance score, 0–100.
+    pub min_relevance: Option<f64>,
+    /// Most sponsorships and votes to list; the tally counts every vote.
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct BillSponsorItem {
+    legislator_id: Option<Uuid>,
+    people_id: i64,
+    name: Option<String>,
+    party: Option<String>,
+    role: Option<String>,
+    district: Option<String>,
+    sponsor_type: String,
+    sponsor_order: i32,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct VoteItem {
+    legislator_id: Option<Uuid>,
+    people_id: i64,
+    name: Option<String>,
+    party: Option<String>,
+    vote: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct RollCallItem {
+    roll_call_id: i64,
+    vote_date: Option<NaiveDate>,
+    chamber: Option<String>,
+    description: String,
+    yea: i32,
+    nay: i32,
+    nv: i32,
+    absent: i32,
+    total: i32,
+    passed: bool,
+    /// Empty until the individual votes have been fetched.
+    votes: Vec<VoteItem>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct LegislatorItem {
+    legislator_id: Uuid,
+    people_id: i64,
+    jurisdiction: String,
+    name: String,
+    party: Option<String>,
+    role: Option<String>,
+    district: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct SponsoredBillItem {
+    bill_id: Uuid,
+    jurisdiction: String,
+    bill_number: String,
+    title: String,
+    status: String,
+    relevance_score: Option<f64>,
+    sponsor_type: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct RecordVoteItem {
+    bill_id: Uuid,
+    jurisdiction: String,
+    bill_number: String,
+    title: String,
+    relevance_score: Option<f64>,
+    roll_call_id: i64,
+    vote_date: Option<NaiveDate>,
+    chamber: Option<String>,
+    description: String,
+    passed: bool,
+    vote: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct VoteTally {
+    yea: i64,
+    nay: i64,
+    nv: i64,
+    absent: i64,
+}
+
+impl From<scbdb_db::LegislatorVoteTally> for VoteTally {
+    fn from(tally: scbdb_db::LegislatorVoteTally) -> Self {
+        Self {
+            yea: tally.yea,
+            nay: tally.nay,
+            nv: tally.nv,
+            absent: tally.absent,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct LegislatorRecordResponse {
+    legislator: LegislatorItem,
+    tally: VoteTally,
+    sponsored: Vec<SponsoredBillItem>,
+    votes: Vec<RecordVoteItem>,
+}
+
+pub(super) async fn list_bill_sponsors(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Path(bill_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<BillSponsorItem>>>, ApiError> {
+    ensure_bill_exists(&state, &req_id, bill_id).await?;
+
+    let rows = scbdb_db::list_bill_sponsors_by_public_id(&state.pool, bill_id)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    let data = rows
+        .into_iter()
+        .map(|row| BillSponsorItem {
+            legislator_id: row.legislator_public_id,
+            people_id: row.legiscan_people_id,
+            name: row.name,
+            party: row.party,
+            role: row.role,
+            district: row.district,
+            sponsor_type: row.sponsor_type,
+            sponsor_order: row.sponsor_order,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+pub(super) async fn list_bill_votes(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Path(bill_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<RollCallItem>>>, ApiError> {
+    ensure_bill_exists(&state, &req_id, bill_id).await?;
+
+    let roll_calls = scbdb_db::list_bill_roll_calls_by_public_id(&state.pool, bill_id)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+    let ids: Vec<i64> = roll_calls.iter().map(|rc| rc.id).collect();
+    let mut votes_by_roll_call = scbdb_db::list_roll_call_votes_batch(&state.pool, &ids)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    let data = roll_calls
+        .into_iter()
+        .map(|rc| RollCallItem {
+            votes: votes_by_roll_call
+                .remove(&rc.id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|v| VoteItem {
+                    legislator_id: v.legislator_public_id,
+                    people_id: v.legiscan_people_id,
+                    name: v.name,
+                    party: v.party,
+                    vote: v.vote,
+                })
+                .collect(),
+            roll_call_id: rc.legiscan_roll_call_id,
+            vote_date: rc.vote_date,
+            chamber: rc.chamber,
+            description: rc.description,
+            yea: rc.yea,
+            nay: rc.nay,
+            nv: rc.nv,
+            absent: rc.absent,
+            total: rc.total,
+            passed: rc.passed,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+pub(super) async fn list_legislators(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<LegislatorsQuery>,
+) -> Result<Json<ApiResponse<Vec<LegislatorItem>>>, ApiError> {
+    let rows = scbdb_db::list_legislators(
+        &state.pool,
+        query.jurisdiction.as_deref(),
+        query.q.as_deref().map(str::trim).filter(|q| !q.is_empty()),
+        normalize_limit(query.limit),
+    )
+    .await
+    .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    let data = rows.into_iter().map(legislator_item).collect();
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+/// A legislator's sponsorships and votes on tracked bills, optionally
+/// narrowed to a topic and a minimum bill relevance.
+pub(super) async fn get_legislator_record(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Path(legislator_id): Path<Uuid>,
+    Query(query): Query<LegislatorRecordQuery>,
+) -> Result<Json<ApiResponse<LegislatorRecordResponse>>, ApiError> {
+    if query
+        .min_relevance
+        .is_some_and(|min| !(0.0..=100.0).contains(&min))
+    {
+        return Err(ApiError::new(
+            req_id.0,
+            "validation_error",
+            "min_relevance must be between 0 and 100",
+        ));
+    }
+
+    let legislator = scbdb_db::get_legislator_by_public_id(&state.pool, legislator_id)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+    let Some(legislator) = legislator else {
+        return Err(ApiError::new(req_id.0, "not_found", "legislator not found"));
+    };
+
+    let people_id = legislator.legiscan_people_id;
+    let topic = query.topic.as_deref();
+    let min_relevance = query.min_relevance;
+    let limit = normalize_limit(query.limit);
+    let sponsored =
+        scbdb_db::list_legislator_sponsorships(&state.pool, people_id, topic, min_relevance, limit)
+            .await
+            .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+    let votes =
+        scbdb_db::list_legislator_votes(&state.pool, people_id, topic, min_relevance, limit)
+            .await
+            .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+    let tally = scbdb_db::tally_legislator_votes(&state.pool, people_id, topic, min_relevance)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    let data = LegislatorRecordResponse {
+        legislator: legislator_item(legislator),
+        tally: VoteTally::from(tally),
+        sponsored: sponsored
+            .into_iter()
+            .map(|row| SponsoredBillItem {
+                bill_id: row.bill_public_id,
+                jurisdiction: row.jurisdiction,
+                bill_number: row.bill_number,
+                title: row.title,
+                status: row.status,
+                relevance_score: row.relevance_score,
+                sponsor_type: row.sponsor_type,
+            })
+            .collect(),
+        votes: votes
+            .into_iter()
+            .map(|row| RecordVoteItem {
+                bill_id: row.bill_public_id,
+                jurisdiction: row.jurisdiction,
+                bill_number: row.bill_number,
+                title: row.title,
+                relevance_score: row.relevance_score,
+                roll_call_id: row.legiscan_roll_call_id,
+                vote_date: row.vote_date,
+                chamber: row.chamber,
+                description: row.description,
+                passed: row.passed,
+                vote: row.vote,
+            })
+            .collect(),
+    };
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+fn legislator_item(row: scbdb_db::LegislatorRow) -> LegislatorItem {
+    LegislatorItem {
+        legislator_id: row.public_id,
+        people_id: row.legiscan_people_id,
+        jurisdiction: row.jurisdiction,
+        name: row.name,
+        party: row.party,
+        role: row.role,
+        district: row.district,
+    }
+}
+
+async fn ensure_bill_exists(
+    state: &AppState,
+    req_id: &RequestId,
+    bill_id: Uuid,
+) -> Result<(), ApiError> {
+    let bill = scbdb_db::get_bill_by_public_id(&state.pool, bill_id)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+    if bill.is_none() {
+        return Err(ApiError::new(
+            req_id.0.clone(),
+            "not_found",
+            "bill not found",
+        ));
+    }
+    Ok(())
+}
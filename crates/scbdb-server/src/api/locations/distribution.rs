@@ -0,0 +1,97 @@
+//! SKU-level distribution points.
+//!
+//! - `GET /api/v1/locations/distribution` — active stores carrying each SKU,
+//!   per SKU or (with `group_by=state`) per SKU and state
+
+use axum::{
+    extract::{Query, State},
+    Extension, Json,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::middleware::RequestId;
+
+use super::super::{map_db_error, normalize_limit, ApiError, ApiResponse, AppState, ResponseMeta};
+use super::parse_country;
+
+#[derive(Debug, Deserialize)]
+pub(in crate::api) struct SkuDistributionQuery {
+    pub brand_slug: Option<String>,
+    /// Restrict to one of our products (`products.id`).
+    pub product_id: Option<i64>,
+    pub state: Option<String>,
+    pub country: Option<String>,
+    /// `sku` (default) or `state`.
+    pub group_by: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct SkuDistributionItem {
+    pub brand_slug: String,
+    pub brand_name: String,
+    pub product_id: Option<i64>,
+    pub sku_key: String,
+    pub product_name: String,
+    pub upc: Option<String>,
+    pub state: Option<String>,
+    /// Distribution points: active stores carrying the SKU.
+    pub store_count: i64,
+    pub state_count: i64,
+}
+
+impl From<scbdb_db::SkuDistributionRow> for SkuDistributionItem {
+    fn from(row: scbdb_db::SkuDistributionRow) -> Self {
+        Self {
+            brand_slug: row.brand_slug,
+            brand_name: row.brand_name,
+            product_id: row.product_id,
+            sku_key: row.sku_key,
+            product_name: row.product_name,
+            upc: row.upc,
+            state: row.state,
+            store_count: row.store_count,
+            state_count: row.state_count,
+        }
+    }
+}
+
+pub(in crate::api) async fn list_sku_distribution(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<SkuDistributionQuery>,
+) -> Result<Json<ApiResponse<Vec<SkuDistributionItem>>>, ApiError> {
+    let by_state = match query.group_by.as_deref().unwrap_or("sku") {
+        "sku" => false,
+        "state" => true,
+        other => {
+            return Err(ApiError::new(
+                &req_id.0,
+                "validation_error",
+                format!("group_by must be 'sku' or 'state', got '{other}'"),
+            ));
+        }
+    };
+    let limit = normalize_limit(query.limit);
+    let state_code = query.state.as_deref().map(str::to_uppercase);
+    let country = parse_country(&req_id.0, query.country.as_deref())?;
+
+    let rows = scbdb_db::list_sku_distribution(
+        &state.pool,
+        &scbdb_db::SkuDistributionFilters {
+            brand_slug: query.brand_slug.as_deref(),
+            product_id: query.product_id,
+            state: state_code.as_deref(),
+            country: country.as_deref(),
+        },
+        by_state,
+        limit,
+    )
+    .await
+    .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?;
+
+    Ok(Json(ApiResponse {
+        data: rows.into_iter().map(SkuDistributionItem::from).collect(),
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
@@ -0,0 +1,192 @@
+//! Store location change history: event feed and weekly net-change rollup.
+
+use axum::{
+    extract::{Query, State},
+    Extension, Json,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::middleware::RequestId;
+
+use super::super::{map_db_error, normalize_limit, ApiError, ApiResponse, AppState, ResponseMeta};
+use super::parse_country;
+
+const EVENT_TYPES: &[&str] = &["added", "removed", "reactivated", "moved", "renamed"];
+const DEFAULT_NET_CHANGE_WEEKS: i32 = 12;
+const MAX_NET_CHANGE_WEEKS: i32 = 104;
+
+#[derive(Debug, Deserialize)]
+pub(in crate::api) struct LocationEventsQuery {
+    pub brand_slug: Option<String>,
+    pub state: Option<String>,
+    pub country: Option<String>,
+    pub event_type: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub limit: Option<i64>,
+    pub cursor: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct LocationEventItem {
+    pub id: i64,
+    pub public_id: Uuid,
+    pub brand_name: String,
+    pub brand_slug: String,
+    pub store_location_id: i64,
+    pub store_name: String,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub event_type: String,
+    /// Previous name/address for `moved` and `renamed`; empty otherwise.
+    pub details: serde_json::Value,
+    pub collection_run_id: Option<i64>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct PaginatedLocationEvents {
+    pub items: Vec<LocationEventItem>,
+    pub next_cursor: Option<i64>,
+}
+
+pub(in crate::api) async fn list_location_events(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<LocationEventsQuery>,
+) -> Result<Json<ApiResponse<PaginatedLocationEvents>>, ApiError> {
+    if let Some(event_type) = query.event_type.as_deref() {
+        if !EVENT_TYPES.contains(&event_type) {
+            return Err(ApiError::new(
+                &req_id.0,
+                "validation_error",
+                format!("event_type must be one of: {}", EVENT_TYPES.join(", ")),
+            ));
+        }
+    }
+
+    let limit = normalize_limit(query.limit);
+    let state_code = query.state.as_deref().map(str::to_uppercase);
+    let country = parse_country(&req_id.0, query.country.as_deref())?;
+    let filters = scbdb_db::LocationEventFilters {
+        brand_slug: query.brand_slug.as_deref(),
+        state: state_code.as_deref(),
+        country: country.as_deref(),
+        event_type: query.event_type.as_deref(),
+        since: query.since,
+    };
+
+    let rows = scbdb_db::list_store_location_events(
+        &state.pool,
+        &filters,
+        limit + 1, // fetch one extra to detect next page
+        query.cursor,
+    )
+    .await
+    .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?;
+
+    // `normalize_limit` clamps to 1..=200, so the conversion is always safe.
+    let limit_usize = usize::try_from(limit).unwrap_or(usize::MAX);
+    let has_more = rows.len() > limit_usize;
+    let take = if has_more { limit_usize } else { rows.len() };
+
+    let items: Vec<LocationEventItem> = rows
+        .into_iter()
+        .take(take)
+        .map(|row| LocationEventItem {
+            id: row.id,
+            public_id: row.public_id,
+            brand_name: row.brand_name,
+            brand_slug: row.brand_slug,
+            store_location_id: row.store_location_id,
+            store_name: row.store_name,
+            city: row.city,
+            state: row.state,
+            event_type: row.event_type,
+            details: row.details,
+            collection_run_id: row.collection_run_id,
+            occurred_at: row.occurred_at,
+        })
+        .collect();
+
+    let next_cursor = if has_more {
+        items.last().map(|item| item.id)
+    } else {
+        None
+    };
+
+    Ok(Json(ApiResponse {
+        data: PaginatedLocationEvents { items, next_cursor },
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+pub(in crate::api) struct LocationNetChangeQuery {
+    pub brand_slug: Option<String>,
+    pub state: Option<String>,
+    pub country: Option<String>,
+    pub weeks: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct LocationNetChangeItem {
+    pub week_start: NaiveDate,
+    pub brand_name: String,
+    pub brand_slug: String,
+    pub state: String,
+    pub added: i64,
+    pub reactivated: i64,
+    pub removed: i64,
+    pub moved: i64,
+    pub renamed: i64,
+    pub net_change: i64,
+    /// First week the brand had any location in this state.
+    pub new_territory: bool,
+}
+
+pub(in crate::api) async fn list_location_net_change(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<LocationNetChangeQuery>,
+) -> Result<Json<ApiResponse<Vec<LocationNetChangeItem>>>, ApiError> {
+    let weeks = query
+        .weeks
+        .unwrap_or(DEFAULT_NET_CHANGE_WEEKS)
+        .clamp(1, MAX_NET_CHANGE_WEEKS);
+    let state_code = query.state.as_deref().map(str::to_uppercase);
+    let country = parse_country(&req_id.0, query.country.as_deref())?;
+
+    let rows = scbdb_db::list_location_net_change_weekly(
+        &state.pool,
+        query.brand_slug.as_deref(),
+        state_code.as_deref(),
+        country.as_deref(),
+        weeks,
+    )
+    .await
+    .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?;
+
+    let data = rows
+        .into_iter()
+        .map(|row| LocationNetChangeItem {
+            week_start: row.week_start,
+            brand_name: row.brand_name,
+            brand_slug: row.brand_slug,
+            state: row.state,
+            added: row.added,
+            reactivated: row.reactivated,
+            removed: row.removed,
+            moved: row.moved,
+            renamed: row.renamed,
+            net_change: row.net_change,
+            new_territory: row.new_territory,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
@@ -0,0 +1,253 @@
+//! Location growth forecasts per brand and state.
+//!
+//! Weekly active store counts are rebuilt from location history, a trend
+//! model (`holt` or `linear`) is fitted to each series, and the recent growth
+//! rate is compared with the earlier history so that sudden changes — often a
+//! new distributor deal — are flagged.
+
+use std::collections::BTreeMap;
+
+use axum::{
+    extract::{Query, State},
+    Extension, Json,
+};
+use chrono::{Days, NaiveDate};
+use scbdb_core::{ForecastModel, GrowthChange};
+use serde::{Deserialize, Serialize};
+
+use crate::middleware::RequestId;
+
+use super::super::{map_db_error, normalize_limit, ApiError, ApiResponse, AppState, ResponseMeta};
+use super::parse_country;
+
+const DEFAULT_HISTORY_WEEKS: i32 = 26;
+const MIN_HISTORY_WEEKS: i32 = 8;
+const MAX_HISTORY_WEEKS: i32 = 104;
+const DEFAULT_HORIZON_WEEKS: u32 = 8;
+const MIN_HORIZON_WEEKS: u32 = 4;
+const MAX_HORIZON_WEEKS: u32 = 12;
+/// Weeks treated as "recent" when looking for a change in growth rate.
+const DEFAULT_RECENT_WEEKS: usize = 6;
+
+#[derive(Debug, Deserialize)]
+pub(in crate::api) struct LocationForecastQuery {
+    pub brand_slug: Option<String>,
+    pub state: Option<String>,
+    pub country: Option<String>,
+    /// `state` (default) for one series per brand and state, or `brand`.
+    pub group_by: Option<String>,
+    /// `holt` (default) or `linear`.
+    pub model: Option<String>,
+    /// Weeks of history to fit on (default 26, 8..=104).
+    pub weeks: Option<i32>,
+    /// Weeks to forecast (default 8, 4..=12).
+    pub horizon: Option<u32>,
+    /// Only return series whose growth rate changed significantly.
+    pub flagged: Option<bool>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct ForecastPointItem {
+    pub week_start: NaiveDate,
+    pub value: f64,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct LocationForecastItem {
+    pub brand_name: String,
+    pub brand_slug: String,
+    pub brand_relationship: String,
+    /// `None` when grouped by brand.
+    pub state: Option<String>,
+    pub current_count: i64,
+    pub history_weeks: usize,
+    pub model: ForecastModel,
+    /// Fitted growth in stores per week at the end of the history.
+    pub weekly_trend: f64,
+    pub forecast: Vec<ForecastPointItem>,
+    /// Recent vs earlier growth rate; `None` when the history is too short.
+    pub growth_change: Option<GrowthChange>,
+}
+
+/// One weekly series, oldest week first.
+struct Series {
+    brand_name: String,
+    brand_slug: String,
+    brand_relationship: String,
+    state: Option<String>,
+    weeks: Vec<(NaiveDate, i64)>,
+}
+
+/// Split the ordered rows into per-brand/state (or per-brand) series.
+fn build_series(rows: Vec<scbdb_db::LocationWeeklyCountRow>, by_brand: bool) -> Vec<Series> {
+    let mut series: BTreeMap<(String, Option<String>), Series> = BTreeMap::new();
+    for row in rows {
+        let state = (!by_brand).then_some(row.state);
+        let entry = series
+            .entry((row.brand_slug.clone(), state.clone()))
+            .or_insert_with(|| Series {
+                brand_name: row.brand_name,
+                brand_slug: row.brand_slug,
+                brand_relationship: row.brand_relationship,
+                state,
+                weeks: Vec::new(),
+            });
+        match entry
+            .weeks
+            .iter_mut()
+            .find(|(week, _)| *week == row.week_start)
+        {
+            Some((_, count)) => *count += row.active_count,
+            None => entry.weeks.push((row.week_start, row.active_count)),
+        }
+    }
+    let mut series: Vec<Series> = series.into_values().collect();
+    for s in &mut series {
+        s.weeks.sort_by_key(|(week, _)| *week);
+    }
+    series
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn forecast_series(
+    series: Series,
+    model: ForecastModel,
+    horizon: u32,
+) -> Option<LocationForecastItem> {
+    let values: Vec<f64> = series.weeks.iter().map(|(_, n)| *n as f64).collect();
+    let (last_week, current_count) = *series.weeks.last()?;
+    let fitted = scbdb_core::forecast(&values, model, horizon)?;
+    let forecast = fitted
+        .points
+        .iter()
+        .map(|p| ForecastPointItem {
+            week_start: last_week + Days::new(7 * u64::from(p.step)),
+            value: p.value,
+            lower: p.lower,
+            upper: p.upper,
+        })
+        .collect();
+    Some(LocationForecastItem {
+        brand_name: series.brand_name,
+        brand_slug: series.brand_slug,
+        brand_relationship: series.brand_relationship,
+        state: series.state,
+        current_count,
+        history_weeks: values.len(),
+        model: fitted.model,
+        weekly_trend: fitted.trend,
+        forecast,
+        growth_change: scbdb_core::detect_growth_change(&values, DEFAULT_RECENT_WEEKS),
+    })
+}
+
+fn is_flagged(item: &LocationForecastItem) -> bool {
+    item.growth_change.is_some_and(|c| c.significant)
+}
+
+pub(in crate::api) async fn list_location_forecasts(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<LocationForecastQuery>,
+) -> Result<Json<ApiResponse<Vec<LocationForecastItem>>>, ApiError> {
+    let by_brand = match query.group_by.as_deref().unwrap_or("state") {
+        "state" => false,
+        "brand" => true,
+        other => {
+            return Err(ApiError::new(
+                &req_id.0,
+                "validation_error",
+                format!("group_by must be 'state' or 'brand', got '{other}'"),
+            ))
+        }
+    };
+    let model = match query.model.as_deref() {
+        None => ForecastModel::Holt,
+        Some(raw) => ForecastModel::parse(raw).ok_or_else(|| {
+            ApiError::new(
+                &req_id.0,
+                "validation_error",
+                format!("model must be 'holt' or 'linear', got '{raw}'"),
+            )
+        })?,
+    };
+    let weeks = query
+        .weeks
+        .unwrap_or(DEFAULT_HISTORY_WEEKS)
+        .clamp(MIN_HISTORY_WEEKS, MAX_HISTORY_WEEKS);
+    let horizon = query
+        .horizon
+        .unwrap_or(DEFAULT_HORIZON_WEEKS)
+        .clamp(MIN_HORIZON_WEEKS, MAX_HORIZON_WEEKS);
+    let limit = usize::try_from(normalize_limit(query.limit)).unwrap_or(usize::MAX);
+    let state_code = query.state.as_deref().map(str::to_uppercase);
+    let country = parse_country(&req_id.0, query.country.as_deref())?;
+
+    let rows = scbdb_db::list_location_weekly_counts(
+        &state.pool,
+        query.brand_slug.as_deref(),
+        state_code.as_deref(),
+        country.as_deref(),
+        weeks,
+    )
+    .await
+    .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?;
+
+    let mut items: Vec<LocationForecastItem> = build_series(rows, by_brand)
+        .into_iter()
+        .filter_map(|series| forecast_series(series, model, horizon))
+        .filter(|item| !query.flagged.unwrap_or(false) || is_flagged(item))
+        .collect();
+    // Flagged series first, then the largest rate changes.
+    items.sort_by(|a, b| {
+        let change =
+            |i: &LocationForecastItem| i.growth_change.map_or(0.0, |c| c.rate_change.abs());
+        is_flagged(b)
+            .cmp(&is_flagged(a))
+            .then_with(|| change(b).total_cmp(&change(a)))
+    });
+    items.truncate(limit);
+
+    Ok(Json(ApiResponse {
+        data: items,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(slug: &str, state: &str, week: u32, count: i64) -> scbdb_db::LocationWeeklyCountRow {
+        scbdb_db::LocationWeeklyCountRow {
+            week_start: NaiveDate::from_ymd_opt(2026, 1, 5).expect("date")
+                + Days::new(7 * u64::from(week)),
+            brand_name: slug.to_uppercase(),
+            brand_slug: slug.to_string(),
+            brand_relationship: "competitor".to_string(),
+            state: state.to_string(),
+            active_count: count,
+        }
+    }
+
+    #[test]
+    fn brand_grouping_sums_states_per_week() {
+        let rows = vec![
+            row("cann", "CA", 0, 3),
+            row("cann", "CA", 1, 4),
+            row("cann", "TX", 0, 1),
+            row("cann", "TX", 1, 2),
+        ];
+        let by_state = build_series(rows.clone(), false);
+        assert_eq!(by_state.len(), 2);
+
+        let by_brand = build_series(rows, true);
+        assert_eq!(by_brand.len(), 1);
+        assert_eq!(by_brand[0].state, None);
+        let counts: Vec<i64> = by_brand[0].weeks.iter().map(|(_, n)| *n).collect();
+        assert_eq!(counts, vec![4, 6]);
+    }
+}
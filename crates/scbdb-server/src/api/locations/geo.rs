@@ -0,0 +1,461 @@
+//! Geospatial location queries: radius search, nearest competitors, density
+//! and county/DMA rollups.
+
+use axum::{
+    extract::{Query, State},
+    Extension, Json,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::middleware::RequestId;
+
+use super::super::{map_db_error, normalize_limit, ApiError, ApiResponse, AppState, ResponseMeta};
+use super::{parse_country, parse_slug_list};
+
+const DEFAULT_RADIUS_MILES: f64 = 10.0;
+const MAX_RADIUS_MILES: f64 = 250.0;
+const DEFAULT_COMPETITOR_MILES: f64 = 25.0;
+const DEFAULT_PER_STORE: i64 = 1;
+const MAX_PER_STORE: i64 = 10;
+
+/// Reject anything other than `portfolio` / `competitor`.
+fn validate_relationship(request_id: &str, value: Option<&str>) -> Result<(), ApiError> {
+    match value {
+        None | Some("portfolio" | "competitor") => Ok(()),
+        Some(other) => Err(ApiError::new(
+            request_id,
+            "validation_error",
+            format!("relationship must be 'portfolio' or 'competitor', got '{other}'"),
+        )),
+    }
+}
+
+/// Validate a caller-supplied radius, falling back to `default` when absent.
+fn validate_radius(request_id: &str, value: Option<f64>, default: f64) -> Result<f64, ApiError> {
+    let miles = value.unwrap_or(default);
+    if miles.is_finite() && miles > 0.0 && miles <= MAX_RADIUS_MILES {
+        Ok(miles)
+    } else {
+        Err(ApiError::new(
+            request_id,
+            "validation_error",
+            format!("radius must be greater than 0 and at most {MAX_RADIUS_MILES} miles"),
+        ))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(in crate::api) struct NearbyQuery {
+    pub lat: Option<f64>,
+    pub lng: Option<f64>,
+    /// Alternative to `lat`/`lng`: centre on the stores in this ZIP.
+    pub zip: Option<String>,
+    pub radius_miles: Option<f64>,
+    pub brand_slug: Option<String>,
+    pub relationship: Option<String>,
+    pub country: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct NearbyLocationItem {
+    pub id: i64,
+    pub store_name: String,
+    pub address_line1: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub zip: Option<String>,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub retail_chain: Option<String>,
+    pub retail_channel: Option<String>,
+    pub brand_name: String,
+    pub brand_slug: String,
+    pub brand_relationship: String,
+    pub distance_miles: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct NearbyLocations {
+    pub center_latitude: f64,
+    pub center_longitude: f64,
+    pub radius_miles: f64,
+    pub items: Vec<NearbyLocationItem>,
+}
+
+pub(in crate::api) async fn list_nearby_locations(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<NearbyQuery>,
+) -> Result<Json<ApiResponse<NearbyLocations>>, ApiError> {
+    validate_relationship(&req_id.0, query.relationship.as_deref())?;
+    let radius_miles = validate_radius(&req_id.0, query.radius_miles, DEFAULT_RADIUS_MILES)?;
+    let country = parse_country(&req_id.0, query.country.as_deref())?;
+
+    let (latitude, longitude) = match (query.lat, query.lng, query.zip.as_deref()) {
+        (Some(lat), Some(lng), _) => {
+            if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lng) {
+                return Err(ApiError::new(
+                    &req_id.0,
+                    "validation_error",
+                    "lat must be within [-90, 90] and lng within [-180, 180]",
+                ));
+            }
+            (lat, lng)
+        }
+        (None, None, Some(zip)) => scbdb_db::resolve_zip_centroid(&state.pool, zip)
+            .await
+            .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?
+            .ok_or_else(|| {
+                ApiError::new(
+                    &req_id.0,
+                    "not_found",
+                    format!("no geocoded store locations in ZIP '{zip}'"),
+                )
+            })?,
+        _ => {
+            return Err(ApiError::new(
+                &req_id.0,
+                "validation_error",
+                "provide either both lat and lng, or zip",
+            ));
+        }
+    };
+
+    let limit = normalize_limit(query.limit);
+    let rows = scbdb_db::list_locations_within_radius(
+        &state.pool,
+        &scbdb_db::RadiusSearch {
+            latitude,
+            longitude,
+            radius_miles,
+            brand_slug: query.brand_slug.as_deref(),
+            relationship: query.relationship.as_deref(),
+            country: country.as_deref(),
+        },
+        limit,
+    )
+    .await
+    .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?;
+
+    let items = rows
+        .into_iter()
+        .map(|row| NearbyLocationItem {
+            id: row.id,
+            store_name: row.store_name,
+            address_line1: row.address_line1,
+            city: row.city,
+            state: row.state,
+            zip: row.zip,
+            latitude: row.latitude,
+            longitude: row.longitude,
+            retail_chain: row.retail_chain,
+            retail_channel: row.retail_channel,
+            brand_name: row.brand_name,
+            brand_slug: row.brand_slug,
+            brand_relationship: row.brand_relationship,
+            distance_miles: row.distance_miles,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse {
+        data: NearbyLocations {
+            center_latitude: latitude,
+            center_longitude: longitude,
+            radius_miles,
+            items,
+        },
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+pub(in crate::api) struct NearestCompetitorsQuery {
+    /// Portfolio brand whose stores are the origins.
+    pub brand_slug: Option<String>,
+    pub competitor_slug: Option<String>,
+    pub max_miles: Option<f64>,
+    pub per_store: Option<i64>,
+    /// Restrict portfolio stores to one country.
+    pub country: Option<String>,
+    pub limit: Option<i64>,
+    pub cursor: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct CompetitorStoreItem {
+    pub id: i64,
+    pub store_name: String,
+    pub brand_name: String,
+    pub brand_slug: String,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub distance_miles: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct PortfolioStoreCompetitors {
+    pub id: i64,
+    pub store_name: String,
+    pub brand_slug: String,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    /// Nearest first; empty when no competitor store is within `max_miles`.
+    pub competitors: Vec<CompetitorStoreItem>,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct PaginatedNearestCompetitors {
+    pub items: Vec<PortfolioStoreCompetitors>,
+    pub next_cursor: Option<i64>,
+}
+
+/// Fold flat `(portfolio store, competitor)` rows into one item per portfolio store.
+fn group_nearest_competitors(
+    rows: Vec<scbdb_db::NearestCompetitorRow>,
+) -> Vec<PortfolioStoreCompetitors> {
+    let mut items: Vec<PortfolioStoreCompetitors> = Vec::new();
+    for row in rows {
+        if items
+            .last()
+            .is_none_or(|item| item.id != row.portfolio_location_id)
+        {
+            items.push(PortfolioStoreCompetitors {
+                id: row.portfolio_location_id,
+                store_name: row.portfolio_store_name,
+                brand_slug: row.portfolio_brand_slug,
+                city: row.portfolio_city,
+                state: row.portfolio_state,
+                competitors: Vec::new(),
+            });
+        }
+        if let (Some(id), Some(store_name), Some(brand_name), Some(brand_slug), Some(distance)) = (
+            row.competitor_location_id,
+            row.competitor_store_name,
+            row.competitor_brand_name,
+            row.competitor_brand_slug,
+            row.distance_miles,
+        ) {
+            if let Some(item) = items.last_mut() {
+                item.competitors.push(CompetitorStoreItem {
+                    id,
+                    store_name,
+                    brand_name,
+                    brand_slug,
+                    city: row.competitor_city,
+                    state: row.competitor_state,
+                    distance_miles: distance,
+                });
+            }
+        }
+    }
+    items
+}
+
+pub(in crate::api) async fn list_nearest_competitors(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<NearestCompetitorsQuery>,
+) -> Result<Json<ApiResponse<PaginatedNearestCompetitors>>, ApiError> {
+    let max_miles = validate_radius(&req_id.0, query.max_miles, DEFAULT_COMPETITOR_MILES)?;
+    let per_store = query
+        .per_store
+        .unwrap_or(DEFAULT_PER_STORE)
+        .clamp(1, MAX_PER_STORE);
+    let country = parse_country(&req_id.0, query.country.as_deref())?;
+    let limit = normalize_limit(query.limit);
+
+    let rows = scbdb_db::list_nearest_competitor_stores(
+        &state.pool,
+        &scbdb_db::NearestCompetitorSearch {
+            portfolio_brand_slug: query.brand_slug.as_deref(),
+            competitor_brand_slug: query.competitor_slug.as_deref(),
+            max_miles,
+            per_store,
+            country: country.as_deref(),
+        },
+        limit + 1, // fetch one extra portfolio store to detect next page
+        query.cursor,
+    )
+    .await
+    .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?;
+
+    let mut items = group_nearest_competitors(rows);
+
+    // `normalize_limit` clamps to 1..=200, so the conversion is always safe.
+    let limit_usize = usize::try_from(limit).unwrap_or(usize::MAX);
+    let has_more = items.len() > limit_usize;
+    items.truncate(limit_usize);
+
+    let next_cursor = if has_more {
+        items.last().map(|item| item.id)
+    } else {
+        None
+    };
+
+    Ok(Json(ApiResponse {
+        data: PaginatedNearestCompetitors { items, next_cursor },
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+pub(in crate::api) struct DensityQuery {
+    /// `zip` (default), `city` or `county`.
+    pub group_by: Option<String>,
+    /// Comma-separated brand slugs; omit for every brand.
+    pub brands: Option<String>,
+    pub relationship: Option<String>,
+    pub state: Option<String>,
+    pub country: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct LocationDensityItem {
+    pub area: String,
+    pub state: Option<String>,
+    pub location_count: i64,
+    pub brand_count: i64,
+    pub portfolio_count: i64,
+    pub competitor_count: i64,
+    pub brand_slugs: Vec<String>,
+}
+
+pub(in crate::api) async fn list_location_density(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<DensityQuery>,
+) -> Result<Json<ApiResponse<Vec<LocationDensityItem>>>, ApiError> {
+    let group_by = query.group_by.as_deref().unwrap_or("zip");
+    let grouping = scbdb_db::DensityGrouping::parse(group_by).ok_or_else(|| {
+        ApiError::new(
+            &req_id.0,
+            "validation_error",
+            format!("group_by must be 'zip', 'city' or 'county', got '{group_by}'"),
+        )
+    })?;
+    validate_relationship(&req_id.0, query.relationship.as_deref())?;
+
+    let brand_slugs = parse_slug_list(query.brands.as_deref());
+    let state_code = query.state.as_deref().map(str::to_uppercase);
+    let country = parse_country(&req_id.0, query.country.as_deref())?;
+    let limit = normalize_limit(query.limit);
+
+    let rows = scbdb_db::list_location_density(
+        &state.pool,
+        grouping,
+        &scbdb_db::LocationDensityFilters {
+            brand_slugs: &brand_slugs,
+            relationship: query.relationship.as_deref(),
+            state: state_code.as_deref(),
+            country: country.as_deref(),
+        },
+        limit,
+    )
+    .await
+    .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?;
+
+    let data = rows
+        .into_iter()
+        .map(|row| LocationDensityItem {
+            area: row.area_key,
+            state: row.state,
+            location_count: row.location_count,
+            brand_count: row.brand_count,
+            portfolio_count: row.portfolio_count,
+            competitor_count: row.competitor_count,
+            brand_slugs: row.brand_slugs,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+pub(in crate::api) struct GeoRollupQuery {
+    /// `county` (default) or `dma`.
+    pub level: Option<String>,
+    /// Comma-separated brand slugs; omit for every brand.
+    pub brands: Option<String>,
+    pub relationship: Option<String>,
+    pub state: Option<String>,
+    pub country: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct LocationGeoRollupItem {
+    pub area_code: String,
+    pub area_name: Option<String>,
+    pub state: Option<String>,
+    pub population: Option<i64>,
+    pub location_count: i64,
+    pub portfolio_count: i64,
+    pub competitor_count: i64,
+    pub portfolio_brand_count: i64,
+    pub competitor_brand_count: i64,
+    pub stores_per_100k: Option<f64>,
+    /// Active store count keyed by brand slug.
+    pub brand_counts: serde_json::Value,
+}
+
+pub(in crate::api) async fn list_location_geo_rollup(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<GeoRollupQuery>,
+) -> Result<Json<ApiResponse<Vec<LocationGeoRollupItem>>>, ApiError> {
+    let level_value = query.level.as_deref().unwrap_or("county");
+    let level = scbdb_db::GeoRollupLevel::parse(level_value).ok_or_else(|| {
+        ApiError::new(
+            &req_id.0,
+            "validation_error",
+            format!("level must be 'county' or 'dma', got '{level_value}'"),
+        )
+    })?;
+    validate_relationship(&req_id.0, query.relationship.as_deref())?;
+
+    let brand_slugs = parse_slug_list(query.brands.as_deref());
+    let state_code = query.state.as_deref().map(str::to_uppercase);
+    let country = parse_country(&req_id.0, query.country.as_deref())?;
+    let limit = normalize_limit(query.limit);
+
+    let rows = scbdb_db::list_location_geo_rollup(
+        &state.pool,
+        level,
+        &scbdb_db::LocationDensityFilters {
+            brand_slugs: &brand_slugs,
+            relationship: query.relationship.as_deref(),
+            state: state_code.as_deref(),
+            country: country.as_deref(),
+        },
+        limit,
+    )
+    .await
+    .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?;
+
+    let data = rows
+        .into_iter()
+        .map(|row| LocationGeoRollupItem {
+            area_code: row.area_code,
+            area_name: row.area_name,
+            state: row.state,
+            population: row.population,
+            location_count: row.location_count,
+            portfolio_count: row.portfolio_count,
+            competitor_count: row.competitor_count,
+            portfolio_brand_count: row.portfolio_brand_count,
+            competitor_brand_count: row.competitor_brand_count,
+            stores_per_100k: row.stores_per_100k,
+            brand_counts: row.brand_counts,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
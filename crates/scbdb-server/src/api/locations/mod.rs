@@ -1,3 +1,46 @@
+//! Store locator API handlers.
+//!
+//! - `GET /api/v1/locations/summary`    — per-brand footprint summary
+//! - `GET /api/v1/locations/by-state`   — per-state coverage
+//! - `GET /api/v1/locations/pins`       — cursor-paginated map pins
+//! - `GET /api/v1/locations/events`     — cursor-paginated change feed
+//! - `GET /api/v1/locations/net-change` — weekly net change per brand/state
+//! - `GET /api/v1/locations/forecast`   — store count forecasts and growth-change flags
+//! - `GET /api/v1/locations/nearby`     — stores within a radius of a point/ZIP
+//! - `GET /api/v1/locations/nearest-competitors` — nearest competitor stores
+//! - `GET /api/v1/locations/density`    — store counts per ZIP/city/county
+//! - `GET /api/v1/locations/geo-rollup` — county/DMA footprint with population
+//! - `GET /api/v1/locations/whitespace` — competitor-only areas/retailers (also `.csv`)
+//! - `GET /api/v1/locations/distribution` — distribution points per SKU (and state)
+//! - `GET /api/v1/locations/quarantines` — scrapes held for review (plus approve/reject)
+//! - `GET /api/v1/locations/tiles/{z}/{x}/{y}` — clustered `GeoJSON` or vector map tiles
+//!
+//! Every endpoint except the quarantines (which hold whole scrapes, not
+//! stores) accepts a `country` filter: an ISO 3166-1 alpha-2 code or a
+//! country name (`CA`, `can`, `Canada`).
+
+mod distribution;
+mod events;
+mod forecast;
+mod geo;
+mod mvt;
+mod quarantine;
+mod tiles;
+mod whitespace;
+
+pub(super) use distribution::list_sku_distribution;
+pub(super) use events::{list_location_events, list_location_net_change};
+pub(super) use forecast::list_location_forecasts;
+pub(super) use geo::{
+    list_location_density, list_location_geo_rollup, list_nearby_locations,
+    list_nearest_competitors,
+};
+pub(super) use quarantine::{
+    approve_location_quarantine, list_location_quarantines, reject_location_quarantine,
+};
+pub(super) use tiles::{get_location_tile, TileCache};
+pub(super) use whitespace::{export_location_whitespace_csv, list_location_whitespace};
+
 use axum::{
     extract::{Query, State},
     Extension, Json,
@@ -7,7 +50,31 @@ use serde::{Deserialize, Serialize};
 
 use crate::middleware::RequestId;
 
-use super::{map_db_error, normalize_limit, ApiError, ApiResponse, AppState, ResponseMeta};
+use super::{
+    map_db_error, normalize_limit, parse_slug_list, ApiError, ApiResponse, AppState, ResponseMeta,
+};
+
+/// Resolve a `country` query value to its ISO 3166-1 alpha-2 code.
+fn parse_country(request_id: &str, value: Option<&str>) -> Result<Option<String>, ApiError> {
+    let Some(raw) = value.map(str::trim).filter(|v| !v.is_empty()) else {
+        return Ok(None);
+    };
+    scbdb_scraper::locator::country::normalize_country(raw)
+        .map(Some)
+        .ok_or_else(|| {
+            ApiError::new(
+                request_id,
+                "validation_error",
+                format!("country must be an ISO 3166-1 alpha-2 code or name, got '{raw}'"),
+            )
+        })
+}
+
+/// `country` filter shared by the summary and by-state endpoints.
+#[derive(Debug, Deserialize)]
+pub(super) struct LocationsCountryQuery {
+    pub country: Option<String>,
+}
 
 #[derive(Debug, Serialize)]
 pub(super) struct LocationsDashboardItem {
@@ -18,20 +85,27 @@ pub(super) struct LocationsDashboardItem {
     pub states_covered: i64,
     pub locator_source: Option<String>,
     pub last_seen_at: Option<DateTime<Utc>>,
+    /// Active location counts keyed by retail channel (`unknown` for unclassified).
+    pub channel_mix: serde_json::Value,
 }
 
 #[derive(Debug, Serialize)]
 pub(super) struct LocationsByStateItem {
+    pub country: String,
     pub state: String,
     pub brand_count: i64,
     pub location_count: i64,
+    /// Active location counts keyed by retail channel (`unknown` for unclassified).
+    pub channel_mix: serde_json::Value,
 }
 
 pub(super) async fn list_locations_summary(
     State(state): State<AppState>,
     Extension(req_id): Extension<RequestId>,
+    Query(query): Query<LocationsCountryQuery>,
 ) -> Result<Json<ApiResponse<Vec<LocationsDashboardItem>>>, ApiError> {
-    let rows = scbdb_db::list_locations_dashboard_summary(&state.pool)
+    let country = parse_country(&req_id.0, query.country.as_deref())?;
+    let rows = scbdb_db::list_locations_dashboard_summary(&state.pool, country.as_deref())
         .await
         .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?;
 
@@ -45,6 +119,7 @@ pub(super) async fn list_locations_summary(
             states_covered: row.states_covered,
             locator_source: row.locator_source,
             last_seen_at: row.last_seen_at,
+            channel_mix: row.channel_mix,
         })
         .collect();
 
@@ -64,6 +139,7 @@ pub(super) struct LocationPinItem {
     pub city: Option<String>,
     pub state: Option<String>,
     pub zip: Option<String>,
+    pub country: String,
     pub locator_source: Option<String>,
     pub brand_name: String,
     pub brand_slug: String,
@@ -76,6 +152,7 @@ pub(super) struct LocationPinsQuery {
     pub limit: Option<i64>,
     pub cursor: Option<i64>,
     pub brand_slug: Option<String>,
+    pub country: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -90,12 +167,14 @@ pub(super) async fn list_location_pins(
     Query(query): Query<LocationPinsQuery>,
 ) -> Result<Json<ApiResponse<PaginatedLocationPins>>, ApiError> {
     let limit = normalize_limit(query.limit);
+    let country = parse_country(&req_id.0, query.country.as_deref())?;
 
     let rows = scbdb_db::list_active_location_pins(
         &state.pool,
         limit + 1, // fetch one extra to detect next page
         query.cursor,
         query.brand_slug.as_deref(),
+        country.as_deref(),
     )
     .await
     .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?;
@@ -117,6 +196,7 @@ pub(super) async fn list_location_pins(
             city: row.city,
             state: row.state,
             zip: row.zip,
+            country: row.country,
             locator_source: row.locator_source,
             brand_name: row.brand_name,
             brand_slug: row.brand_slug,
@@ -140,17 +220,21 @@ pub(super) async fn list_location_pins(
 pub(super) async fn list_locations_by_state(
     State(state): State<AppState>,
     Extension(req_id): Extension<RequestId>,
+    Query(query): Query<LocationsCountryQuery>,
 ) -> Result<Json<ApiResponse<Vec<LocationsByStateItem>>>, ApiError> {
-    let rows = scbdb_db::list_locations_by_state(&state.pool)
+    let country = parse_country(&req_id.0, query.country.as_deref())?;
+    let rows = scbdb_db::list_locations_by_state(&state.pool, country.as_deref())
         .await
         .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?;
 
     let data = rows
         .into_iter()
         .map(|row| LocationsByStateItem {
+            country: row.country,
             state: row.state,
             brand_count: row.brand_count,
             location_count: row.location_count,
+            channel_mix: row.channel_mix,
         })
         .collect();
 
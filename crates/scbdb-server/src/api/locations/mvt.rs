@@ -0,0 +1,224 @@
+//! Minimal Mapbox Vector Tile (v2.1) encoder for point layers.
+//!
+//! Only what the location tiles need: one or more layers of point features
+//! with string, integer and boolean properties. The protobuf wire format is
+//! written by hand so the server does not pull in a protobuf toolchain.
+
+use std::collections::HashMap;
+
+/// Grid size of tile-local coordinates.
+pub(super) const MVT_EXTENT: u32 = 4096;
+
+/// A feature property value.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum MvtValue {
+    String(String),
+    Int(i64),
+    Bool(bool),
+}
+
+impl MvtValue {
+    /// Encoded `Value` message; also used as the dedup key.
+    fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        match self {
+            Self::String(s) => write_bytes(&mut buf, 1, s.as_bytes()),
+            // Field 6 is `sint64`: zigzag-encoded.
+            Self::Int(n) => {
+                write_key(&mut buf, 6, WIRE_VARINT);
+                write_varint(&mut buf, zigzag(*n));
+            }
+            Self::Bool(b) => {
+                write_key(&mut buf, 7, WIRE_VARINT);
+                write_varint(&mut buf, u64::from(*b));
+            }
+        }
+        buf
+    }
+}
+
+/// A layer of point features under construction.
+#[derive(Debug)]
+pub(super) struct MvtLayer {
+    name: String,
+    keys: Vec<String>,
+    key_index: HashMap<String, u32>,
+    values: Vec<Vec<u8>>,
+    value_index: HashMap<Vec<u8>, u32>,
+    features: Vec<Vec<u8>>,
+}
+
+impl MvtLayer {
+    pub(super) fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            keys: Vec::new(),
+            key_index: HashMap::new(),
+            values: Vec::new(),
+            value_index: HashMap::new(),
+            features: Vec::new(),
+        }
+    }
+
+    /// Add a point at tile-local `(x, y)` (`0..MVT_EXTENT`, y growing down).
+    pub(super) fn add_point(
+        &mut self,
+        id: Option<u64>,
+        (x, y): (i64, i64),
+        properties: &[(&str, MvtValue)],
+    ) {
+        let mut tags = Vec::with_capacity(properties.len() * 2);
+        for (key, value) in properties {
+            tags.push(self.key_id(key));
+            tags.push(self.value_id(value));
+        }
+
+        let mut feature = Vec::new();
+        if let Some(id) = id {
+            write_key(&mut feature, 1, WIRE_VARINT);
+            write_varint(&mut feature, id);
+        }
+        write_packed(&mut feature, 2, tags.into_iter().map(u64::from));
+        // GeomType::POINT
+        write_key(&mut feature, 3, WIRE_VARINT);
+        write_varint(&mut feature, 1);
+        // MoveTo (command 1) with a count of 1, then the zigzagged offset
+        // from the cursor origin.
+        write_packed(&mut feature, 4, [(1 << 3) | 1, zigzag(x), zigzag(y)]);
+        self.features.push(feature);
+    }
+
+    fn key_id(&mut self, key: &str) -> u32 {
+        if let Some(id) = self.key_index.get(key) {
+            return *id;
+        }
+        let id = u32::try_from(self.keys.len()).unwrap_or(u32::MAX);
+        self.keys.push(key.to_string());
+        self.key_index.insert(key.to_string(), id);
+        id
+    }
+
+    fn value_id(&mut self, value: &MvtValue) -> u32 {
+        let encoded = value.encode();
+        if let Some(id) = self.value_index.get(&encoded) {
+            return *id;
+        }
+        let id = u32::try_from(self.values.len()).unwrap_or(u32::MAX);
+        self.values.push(encoded.clone());
+        self.value_index.insert(encoded, id);
+        id
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_key(&mut buf, 15, WIRE_VARINT);
+        write_varint(&mut buf, 2);
+        write_bytes(&mut buf, 1, self.name.as_bytes());
+        for feature in &self.features {
+            write_bytes(&mut buf, 2, feature);
+        }
+        for key in &self.keys {
+            write_bytes(&mut buf, 3, key.as_bytes());
+        }
+        for value in &self.values {
+            write_bytes(&mut buf, 4, value);
+        }
+        write_key(&mut buf, 5, WIRE_VARINT);
+        write_varint(&mut buf, u64::from(MVT_EXTENT));
+        buf
+    }
+}
+
+/// Encode layers into a complete tile.
+pub(super) fn encode_tile(layers: &[MvtLayer]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    for layer in layers {
+        write_bytes(&mut buf, 3, &layer.encode());
+    }
+    buf
+}
+
+const WIRE_VARINT: u8 = 0;
+const WIRE_LEN: u8 = 2;
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        // Low seven bits plus the continuation flag; truncation is the point.
+        #[allow(clippy::cast_possible_truncation)]
+        buf.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    buf.push(value as u8);
+}
+
+fn write_key(buf: &mut Vec<u8>, field: u32, wire_type: u8) {
+    write_varint(buf, (u64::from(field) << 3) | u64::from(wire_type));
+}
+
+fn write_bytes(buf: &mut Vec<u8>, field: u32, bytes: &[u8]) {
+    write_key(buf, field, WIRE_LEN);
+    write_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+fn write_packed(buf: &mut Vec<u8>, field: u32, values: impl IntoIterator<Item = u64>) {
+    let mut packed = Vec::new();
+    for value in values {
+        write_varint(&mut packed, value);
+    }
+    write_bytes(buf, field, &packed);
+}
+
+#[allow(clippy::cast_sign_loss)]
+fn zigzag(n: i64) -> u64 {
+    ((n << 1) ^ (n >> 63)) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn varint_and_zigzag_match_protobuf() {
+        let mut buf = Vec::new();
+        write_varint(&mut buf, 300);
+        assert_eq!(buf, [0xac, 0x02]);
+        assert_eq!(zigzag(0), 0);
+        assert_eq!(zigzag(-1), 1);
+        assert_eq!(zigzag(1), 2);
+        assert_eq!(zigzag(-2), 3);
+    }
+
+    #[test]
+    fn point_layer_encodes_expected_bytes() {
+        let mut layer = MvtLayer::new("l");
+        layer.add_point(
+            Some(7),
+            (25, 17),
+            &[("k", MvtValue::Int(1)), ("k", MvtValue::Int(1))],
+        );
+
+        let tile = encode_tile(&[layer]);
+        let feature = [
+            0x08, 7, // id
+            0x12, 4, 0, 0, 0, 0, // tags: key 0 / value 0, twice (deduped)
+            0x18, 1, // type POINT
+            0x22, 3, 9, 50, 34, // MoveTo(25, 17)
+        ];
+        let mut expected_layer = vec![
+            0x78,
+            2,
+            0x0a,
+            1,
+            b'l',
+            0x12,
+            u8::try_from(feature.len()).unwrap(),
+        ];
+        expected_layer.extend_from_slice(&feature);
+        expected_layer.extend_from_slice(&[0x1a, 1, b'k', 0x22, 2, 0x30, 2, 0x28, 0x80, 0x20]);
+        let mut expected = vec![0x1a, u8::try_from(expected_layer.len()).unwrap()];
+        expected.extend_from_slice(&expected_layer);
+        assert_eq!(tile, expected);
+    }
+}
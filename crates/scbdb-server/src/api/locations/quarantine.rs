@@ -0,0 +1,160 @@
+//! Review of location scrapes held back by the mass-deactivation guardrail.
+//!
+//! - `GET  /api/v1/locations/quarantines`               — held scrapes, newest first
+//! - `POST /api/v1/locations/quarantines/{id}/approve`  — apply a held scrape
+//! - `POST /api/v1/locations/quarantines/{id}/reject`   — discard a held scrape
+
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::middleware::RequestId;
+
+use super::super::{map_db_error, normalize_limit, ApiError, ApiResponse, AppState, ResponseMeta};
+
+const QUARANTINE_STATUSES: &[&str] = &["pending", "approved", "rejected", "superseded"];
+
+#[derive(Debug, Deserialize)]
+pub(in crate::api) struct QuarantineListQuery {
+    pub status: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(in crate::api) struct QuarantineReviewRequest {
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct LocationQuarantineItem {
+    pub id: Uuid,
+    pub brand_slug: String,
+    pub brand_name: String,
+    pub status: String,
+    pub active_count: i32,
+    pub baseline_count: i32,
+    pub new_count: i32,
+    pub drop_pct: Decimal,
+    pub locator_source: Option<String>,
+    pub review_note: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct QuarantineApprovalResponse {
+    pub new: u64,
+    pub updated: u64,
+    pub deactivated: u64,
+    pub events: u64,
+}
+
+/// The review note of an optional body; approve and reject accept a bare POST.
+fn review_note(body: Option<Json<QuarantineReviewRequest>>) -> Option<String> {
+    body.and_then(|Json(body)| body.note)
+}
+
+fn map_review_error(request_id: &str, error: &scbdb_db::DbError) -> ApiError {
+    match error {
+        scbdb_db::DbError::NotFound => {
+            ApiError::new(request_id, "not_found", "location quarantine not found")
+        }
+        scbdb_db::DbError::QuarantineNotPending { .. } => {
+            ApiError::new(request_id, "conflict", error.to_string())
+        }
+        _ => map_db_error(request_id.to_owned(), error),
+    }
+}
+
+pub(in crate::api) async fn list_location_quarantines(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<QuarantineListQuery>,
+) -> Result<Json<ApiResponse<Vec<LocationQuarantineItem>>>, ApiError> {
+    if let Some(status) = query.status.as_deref() {
+        if !QUARANTINE_STATUSES.contains(&status) {
+            return Err(ApiError::new(
+                &req_id.0,
+                "validation_error",
+                format!(
+                    "status must be one of {}, got '{status}'",
+                    QUARANTINE_STATUSES.join(", ")
+                ),
+            ));
+        }
+    }
+    let limit = normalize_limit(query.limit);
+
+    let rows = scbdb_db::list_location_quarantines(&state.pool, query.status.as_deref(), limit)
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e)))?;
+
+    let data = rows
+        .into_iter()
+        .map(|row| LocationQuarantineItem {
+            id: row.public_id,
+            brand_slug: row.brand_slug,
+            brand_name: row.brand_name,
+            status: row.status,
+            active_count: row.active_count,
+            baseline_count: row.baseline_count,
+            new_count: row.new_count,
+            drop_pct: row.drop_pct,
+            locator_source: row.locator_source,
+            review_note: row.review_note,
+            reviewed_at: row.reviewed_at,
+            created_at: row.created_at,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse {
+        data,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+/// POST /api/v1/locations/quarantines/:id/approve — apply a held scrape.
+pub(in crate::api) async fn approve_location_quarantine(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Path(id): Path<Uuid>,
+    body: Option<Json<QuarantineReviewRequest>>,
+) -> Result<Json<ApiResponse<QuarantineApprovalResponse>>, ApiError> {
+    let note = review_note(body);
+    let summary = scbdb_db::approve_location_quarantine(&state.pool, id, note.as_deref())
+        .await
+        .map_err(|e| map_review_error(&req_id.0, &e))?;
+
+    Ok(Json(ApiResponse {
+        data: QuarantineApprovalResponse {
+            new: summary.new,
+            updated: summary.updated,
+            deactivated: summary.deactivated,
+            events: summary.events,
+        },
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+/// POST /api/v1/locations/quarantines/:id/reject — discard a held scrape.
+pub(in crate::api) async fn reject_location_quarantine(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Path(id): Path<Uuid>,
+    body: Option<Json<QuarantineReviewRequest>>,
+) -> Result<Json<ApiResponse<serde_json::Value>>, ApiError> {
+    let note = review_note(body);
+    scbdb_db::reject_location_quarantine(&state.pool, id, note.as_deref())
+        .await
+        .map_err(|e| map_review_error(&req_id.0, &e))?;
+
+    Ok(Json(ApiResponse {
+        data: serde_json::json!({ "rejected": true }),
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
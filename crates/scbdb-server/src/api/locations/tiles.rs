@@ -0,0 +1,325 @@
+//! Map tiles of store locations.
+//!
+//! - `GET /api/v1/locations/tiles/{z}/{x}/{y}` — clustered `GeoJSON`, or a
+//!   Mapbox Vector Tile when `y` ends in `.mvt`/`.pbf` or `format=mvt`
+//!
+//! Below [`CLUSTER_MAX_ZOOM`] stores are clustered server-side on a fixed
+//! pixel grid; from there on every store is its own point. Rendered tiles are
+//! cached in memory, keyed by tile and filters, and the whole cache is
+//! dropped whenever the location data version (latest `locations` collection
+//! run, latest quarantine approval) changes.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use axum::{
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::header,
+    response::IntoResponse,
+    Extension,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::middleware::RequestId;
+
+use super::super::{map_db_error, ApiError, AppState};
+use super::mvt::{encode_tile, MvtLayer, MvtValue, MVT_EXTENT};
+use super::{parse_country, parse_slug_list};
+
+/// Zoom level from which stores are returned unclustered.
+const CLUSTER_MAX_ZOOM: u8 = 14;
+
+/// Cluster cell size in pixels; divides the 256-px tile evenly.
+const CLUSTER_CELL_PX: u32 = 64;
+
+/// Tiles kept per data version before the cache is flushed.
+const MAX_CACHED_TILES: usize = 4096;
+
+/// Name of the single layer in vector tiles.
+const LAYER_NAME: &str = "locations";
+
+const MVT_CONTENT_TYPE: &str = "application/vnd.mapbox-vector-tile";
+const GEOJSON_CONTENT_TYPE: &str = "application/geo+json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TileFormat {
+    GeoJson,
+    Mvt,
+}
+
+impl TileFormat {
+    fn content_type(self) -> &'static str {
+        match self {
+            Self::GeoJson => GEOJSON_CONTENT_TYPE,
+            Self::Mvt => MVT_CONTENT_TYPE,
+        }
+    }
+}
+
+/// In-memory cache of rendered tiles, shared across requests.
+#[derive(Debug, Clone, Default)]
+pub(in crate::api) struct TileCache {
+    inner: Arc<Mutex<TileCacheInner>>,
+}
+
+#[derive(Debug, Default)]
+struct TileCacheInner {
+    version: Option<scbdb_db::LocationDataVersion>,
+    tiles: HashMap<String, Bytes>,
+}
+
+impl TileCache {
+    /// Look up a tile rendered against `version`, flushing stale entries.
+    fn get(&self, version: &scbdb_db::LocationDataVersion, key: &str) -> Option<Bytes> {
+        let mut inner = self
+            .inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if inner.version.as_ref() != Some(version) {
+            inner.version = Some(version.clone());
+            inner.tiles.clear();
+            return None;
+        }
+        inner.tiles.get(key).cloned()
+    }
+
+    fn insert(&self, version: &scbdb_db::LocationDataVersion, key: String, tile: Bytes) {
+        let mut inner = self
+            .inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        // A newer version may have been seen while this tile was rendering.
+        if inner.version.as_ref() != Some(version) {
+            return;
+        }
+        if inner.tiles.len() >= MAX_CACHED_TILES {
+            inner.tiles.clear();
+        }
+        inner.tiles.insert(key, tile);
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(in crate::api) struct LocationTileQuery {
+    /// `geojson` (default) or `mvt`; a `.mvt`/`.pbf` suffix on `y` also works.
+    pub format: Option<String>,
+    /// Comma-separated brand slugs; omit for every brand.
+    pub brands: Option<String>,
+    /// `portfolio` or `competitor`.
+    pub relationship: Option<String>,
+    pub country: Option<String>,
+}
+
+/// Split `y` into the row number and the format implied by its extension.
+fn parse_tile_row(raw: &str) -> (&str, Option<TileFormat>) {
+    match raw.rsplit_once('.') {
+        Some((y, "mvt" | "pbf")) => (y, Some(TileFormat::Mvt)),
+        Some((y, "geojson" | "json")) => (y, Some(TileFormat::GeoJson)),
+        _ => (raw, None),
+    }
+}
+
+fn parse_tile(
+    request_id: &str,
+    (z, x, y): (&str, &str, &str),
+) -> Result<scbdb_db::TileCoord, ApiError> {
+    let coord = match (z.parse::<u8>(), x.parse::<u32>(), y.parse::<u32>()) {
+        (Ok(z), Ok(x), Ok(y)) => scbdb_db::TileCoord::new(z, x, y),
+        _ => None,
+    };
+    coord.ok_or_else(|| {
+        ApiError::new(
+            request_id,
+            "validation_error",
+            format!(
+                "tile {z}/{x}/{y} is invalid: z must be 0..={} and x, y below 2^z",
+                scbdb_db::MAX_TILE_ZOOM
+            ),
+        )
+    })
+}
+
+pub(in crate::api) async fn get_location_tile(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Extension(cache): Extension<TileCache>,
+    Path((z, x, y)): Path<(String, String, String)>,
+    Query(query): Query<LocationTileQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (y, suffix_format) = parse_tile_row(&y);
+    let tile = parse_tile(&req_id.0, (&z, &x, y))?;
+    let format = match (query.format.as_deref(), suffix_format) {
+        (None, suffix) => suffix.unwrap_or(TileFormat::GeoJson),
+        (Some("mvt" | "pbf"), _) => TileFormat::Mvt,
+        (Some("geojson" | "json"), _) => TileFormat::GeoJson,
+        (Some(other), _) => {
+            return Err(ApiError::new(
+                &req_id.0,
+                "validation_error",
+                format!("format must be 'geojson' or 'mvt', got '{other}'"),
+            ))
+        }
+    };
+    if let Some(rel) = query.relationship.as_deref() {
+        if rel != "portfolio" && rel != "competitor" {
+            return Err(ApiError::new(
+                &req_id.0,
+                "validation_error",
+                format!("relationship must be 'portfolio' or 'competitor', got '{rel}'"),
+            ));
+        }
+    }
+    let mut brands = parse_slug_list(query.brands.as_deref());
+    brands.sort();
+    brands.dedup();
+    let country = parse_country(&req_id.0, query.country.as_deref())?;
+
+    let db_err = |e: sqlx::Error| map_db_error(req_id.0.clone(), &scbdb_db::DbError::from(e));
+    let version = scbdb_db::get_location_data_version(&state.pool)
+        .await
+        .map_err(db_err)?;
+    let key = format!(
+        "{}/{}/{}:{format:?}:{}:{}:{}",
+        tile.z,
+        tile.x,
+        tile.y,
+        brands.join(","),
+        query.relationship.as_deref().unwrap_or(""),
+        country.as_deref().unwrap_or(""),
+    );
+
+    let (body, cache_status) = if let Some(hit) = cache.get(&version, &key) {
+        (hit, "hit")
+    } else {
+        let cluster_px = (tile.z < CLUSTER_MAX_ZOOM).then_some(CLUSTER_CELL_PX);
+        let rows = scbdb_db::list_location_tile_features(
+            &state.pool,
+            tile,
+            cluster_px,
+            &scbdb_db::LocationTileFilters {
+                brand_slugs: &brands,
+                relationship: query.relationship.as_deref(),
+                country: country.as_deref(),
+            },
+        )
+        .await
+        .map_err(db_err)?;
+        let body = Bytes::from(match format {
+            TileFormat::GeoJson => render_geojson(&rows),
+            TileFormat::Mvt => render_mvt(tile, &rows),
+        });
+        cache.insert(&version, key, body.clone());
+        (body, "miss")
+    };
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type()),
+            (
+                header::HeaderName::from_static("x-tile-cache"),
+                cache_status,
+            ),
+        ],
+        body,
+    ))
+}
+
+fn render_geojson(rows: &[scbdb_db::LocationTileFeatureRow]) -> Vec<u8> {
+    let features: Vec<serde_json::Value> = rows
+        .iter()
+        .map(|row| {
+            let properties = if row.point_count > 1 {
+                json!({
+                    "cluster": true,
+                    "point_count": row.point_count,
+                    "portfolio_count": row.portfolio_count,
+                    "competitor_count": row.competitor_count,
+                    "brand_count": row.brand_slugs.len(),
+                    "brands": row.brand_slugs,
+                })
+            } else {
+                json!({
+                    "cluster": false,
+                    "id": row.location_id,
+                    "store_name": row.store_name,
+                    "city": row.city,
+                    "state": row.state,
+                    "brand_name": row.brand_name,
+                    "brand_slug": row.brand_slug,
+                    "brand_relationship": row.brand_relationship,
+                })
+            };
+            json!({
+                "type": "Feature",
+                "geometry": {
+                    "type": "Point",
+                    "coordinates": [row.longitude, row.latitude],
+                },
+                "properties": properties,
+            })
+        })
+        .collect();
+    // Serializing a `Value` cannot fail.
+    serde_json::to_vec(&json!({ "type": "FeatureCollection", "features": features }))
+        .unwrap_or_default()
+}
+
+fn render_mvt(tile: scbdb_db::TileCoord, rows: &[scbdb_db::LocationTileFeatureRow]) -> Vec<u8> {
+    let mut layer = MvtLayer::new(LAYER_NAME);
+    for row in rows {
+        let position = tile.project(row.latitude, row.longitude, MVT_EXTENT);
+        if row.point_count > 1 {
+            layer.add_point(
+                None,
+                position,
+                &[
+                    ("cluster", MvtValue::Bool(true)),
+                    ("point_count", MvtValue::Int(row.point_count)),
+                    ("portfolio_count", MvtValue::Int(row.portfolio_count)),
+                    ("competitor_count", MvtValue::Int(row.competitor_count)),
+                    (
+                        "brand_count",
+                        MvtValue::Int(i64::try_from(row.brand_slugs.len()).unwrap_or(i64::MAX)),
+                    ),
+                    ("brands", MvtValue::String(row.brand_slugs.join(","))),
+                ],
+            );
+            continue;
+        }
+        let mut properties = vec![("cluster", MvtValue::Bool(false))];
+        let text_fields = [
+            ("store_name", &row.store_name),
+            ("city", &row.city),
+            ("state", &row.state),
+            ("brand_name", &row.brand_name),
+            ("brand_slug", &row.brand_slug),
+            ("brand_relationship", &row.brand_relationship),
+        ];
+        for (name, value) in text_fields {
+            if let Some(value) = value {
+                properties.push((name, MvtValue::String(value.clone())));
+            }
+        }
+        let id = row.location_id.and_then(|id| u64::try_from(id).ok());
+        layer.add_point(id, position, &properties);
+    }
+    encode_tile(&[layer])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tile_row_suffix_selects_format() {
+        assert_eq!(parse_tile_row("407.mvt"), ("407", Some(TileFormat::Mvt)));
+        assert_eq!(parse_tile_row("407.pbf"), ("407", Some(TileFormat::Mvt)));
+        assert_eq!(
+            parse_tile_row("407.geojson"),
+            ("407", Some(TileFormat::GeoJson))
+        );
+        assert_eq!(parse_tile_row("407"), ("407", None));
+    }
+}
@@ -0,0 +1,215 @@
+//! Whitespace analysis: where competitors sell and the portfolio doesn't.
+//!
+//! - `GET /api/v1/locations/whitespace`     — ranked gaps as JSON
+//! - `GET /api/v1/locations/whitespace.csv` — the same gaps as a CSV download
+
+use axum::{
+    extract::{Query, State},
+    http::header,
+    response::IntoResponse,
+    Extension, Json,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::middleware::RequestId;
+
+use super::super::{map_db_error, normalize_limit, ApiError, ApiResponse, AppState, ResponseMeta};
+use super::{parse_country, parse_slug_list};
+
+/// Row cap for the CSV export (the JSON endpoint uses the shared 1..=200 limit).
+const MAX_EXPORT_ROWS: i64 = 10_000;
+
+#[derive(Debug, Deserialize)]
+pub(in crate::api) struct WhitespaceQuery {
+    /// `zip` (default), `county` or `retailer`.
+    pub group_by: Option<String>,
+    /// Comma-separated portfolio brand slugs; omit for every portfolio brand.
+    pub portfolio: Option<String>,
+    /// Comma-separated competitor brand slugs; omit for every competitor.
+    pub competitors: Option<String>,
+    /// Retail channel, e.g. `liquor`.
+    pub channel: Option<String>,
+    pub state: Option<String>,
+    pub country: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub(in crate::api) struct WhitespaceGapItem {
+    pub area: String,
+    pub area_name: Option<String>,
+    pub states: Vec<String>,
+    pub competitor_brand_count: i64,
+    pub competitor_store_count: i64,
+    pub competitor_brands: Vec<String>,
+    pub competitor_channels: Vec<String>,
+    pub portfolio_brands_present: Vec<String>,
+    pub portfolio_brands_absent: Vec<String>,
+}
+
+impl From<scbdb_db::WhitespaceGapRow> for WhitespaceGapItem {
+    fn from(row: scbdb_db::WhitespaceGapRow) -> Self {
+        Self {
+            area: row.area_key,
+            area_name: row.area_name,
+            states: row.states,
+            competitor_brand_count: row.competitor_brand_count,
+            competitor_store_count: row.competitor_store_count,
+            competitor_brands: row.competitor_brands,
+            competitor_channels: row.competitor_channels,
+            portfolio_brands_present: row.portfolio_brands_present,
+            portfolio_brands_absent: row.portfolio_brands_absent,
+        }
+    }
+}
+
+/// Validate the query and run the gap analysis with the given row limit.
+async fn fetch_gaps(
+    state: &AppState,
+    request_id: &str,
+    query: &WhitespaceQuery,
+    limit: i64,
+) -> Result<Vec<scbdb_db::WhitespaceGapRow>, ApiError> {
+    let group_by = query.group_by.as_deref().unwrap_or("zip");
+    let grouping = scbdb_db::WhitespaceGrouping::parse(group_by).ok_or_else(|| {
+        ApiError::new(
+            request_id,
+            "validation_error",
+            format!("group_by must be 'zip', 'county' or 'retailer', got '{group_by}'"),
+        )
+    })?;
+    if let Some(channel) = query.channel.as_deref() {
+        if scbdb_scraper::RetailChannel::parse(channel).is_none() {
+            return Err(ApiError::new(
+                request_id,
+                "validation_error",
+                format!("unknown retail channel '{channel}'"),
+            ));
+        }
+    }
+
+    let portfolio = parse_slug_list(query.portfolio.as_deref());
+    let competitors = parse_slug_list(query.competitors.as_deref());
+    let state_code = query.state.as_deref().map(str::to_uppercase);
+    let country = parse_country(request_id, query.country.as_deref())?;
+
+    scbdb_db::list_location_whitespace(
+        &state.pool,
+        grouping,
+        &scbdb_db::WhitespaceFilters {
+            portfolio_slugs: &portfolio,
+            competitor_slugs: &competitors,
+            channel: query.channel.as_deref(),
+            state: state_code.as_deref(),
+            country: country.as_deref(),
+        },
+        limit,
+    )
+    .await
+    .map_err(|e| map_db_error(request_id.to_owned(), &scbdb_db::DbError::from(e)))
+}
+
+pub(in crate::api) async fn list_location_whitespace(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<WhitespaceQuery>,
+) -> Result<Json<ApiResponse<Vec<WhitespaceGapItem>>>, ApiError> {
+    let limit = normalize_limit(query.limit);
+    let rows = fetch_gaps(&state, &req_id.0, &query, limit).await?;
+
+    Ok(Json(ApiResponse {
+        data: rows.into_iter().map(WhitespaceGapItem::from).collect(),
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+/// Render gaps as CSV; list columns are `;`-separated.
+fn whitespace_csv(rows: &[scbdb_db::WhitespaceGapRow]) -> Result<Vec<u8>, csv::Error> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record([
+        "area",
+        "area_name",
+        "states",
+        "competitor_brand_count",
+        "competitor_store_count",
+        "competitor_brands",
+        "competitor_channels",
+        "portfolio_brands_present",
+        "portfolio_brands_absent",
+    ])?;
+    for row in rows {
+        writer.write_record([
+            row.area_key.as_str(),
+            row.area_name.as_deref().unwrap_or(""),
+            &row.states.join(";"),
+            &row.competitor_brand_count.to_string(),
+            &row.competitor_store_count.to_string(),
+            &row.competitor_brands.join(";"),
+            &row.competitor_channels.join(";"),
+            &row.portfolio_brands_present.join(";"),
+            &row.portfolio_brands_absent.join(";"),
+        ])?;
+    }
+    writer
+        .into_inner()
+        .map_err(|e| csv::Error::from(e.into_error()))
+}
+
+pub(in crate::api) async fn export_location_whitespace_csv(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<WhitespaceQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let limit = query
+        .limit
+        .unwrap_or(MAX_EXPORT_ROWS)
+        .clamp(1, MAX_EXPORT_ROWS);
+    let rows = fetch_gaps(&state, &req_id.0, &query, limit).await?;
+
+    let body = whitespace_csv(&rows).map_err(|e| {
+        tracing::error!(request_id = %req_id.0, error = %e, "whitespace CSV encoding failed");
+        ApiError::new(&req_id.0, "internal_error", "failed to encode CSV")
+    })?;
+
+    let group_by = query.group_by.as_deref().unwrap_or("zip");
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"whitespace-{group_by}.csv\""),
+            ),
+        ],
+        body,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn whitespace_csv_joins_list_columns() {
+        let rows = vec![scbdb_db::WhitespaceGapRow {
+            area_key: "Total Wine & More".to_string(),
+            area_name: None,
+            states: vec!["FL".to_string(), "SC".to_string()],
+            competitor_brand_count: 2,
+            competitor_store_count: 7,
+            competitor_brands: vec!["cann".to_string(), "wynk".to_string()],
+            competitor_channels: vec!["liquor".to_string()],
+            portfolio_brands_present: vec![],
+            portfolio_brands_absent: vec!["high-rise".to_string()],
+        }];
+        let csv = String::from_utf8(whitespace_csv(&rows).expect("encode")).expect("utf8");
+        let mut lines = csv.lines();
+        assert!(lines
+            .next()
+            .expect("header")
+            .starts_with("area,area_name,states"));
+        assert_eq!(
+            lines.next().expect("row"),
+            "Total Wine & More,,FL;SC,2,7,cann;wynk,liquor,,high-rise"
+        );
+    }
+}
@@ -1,16 +1,25 @@
+mod accounts;
+mod bill_texts;
 mod bills;
+mod brand_candidates;
 mod brands;
 mod collection_runs;
+mod hearings;
+mod legiscan_quota;
+mod legislators;
 mod locations;
 mod pricing;
 mod products;
+mod regulatory_exposure;
 mod sentiment;
+mod state_rules;
+mod watched_bills;
 
 use axum::{
     extract::State,
     http::{header, HeaderName, Method, StatusCode},
     response::IntoResponse,
-    routing::{get, put},
+    routing::{delete, get, post, put},
     Extension, Json, Router,
 };
 use chrono::{DateTime, Utc};
@@ -102,6 +111,17 @@ pub(super) fn normalize_limit(limit: Option<i64>) -> i64 {
     limit.unwrap_or(50).clamp(1, 200)
 }
 
+/// Split a comma-separated slug list query value (`brands=a,b`) into slugs.
+pub(super) fn parse_slug_list(value: Option<&str>) -> Vec<String> {
+    value
+        .unwrap_or("")
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 pub(super) fn map_db_error(request_id: String, error: &scbdb_db::DbError) -> ApiError {
     tracing::error!(error = %error, "database query failed");
     ApiError::new(request_id, "internal_error", "database query failed")
@@ -124,6 +144,7 @@ fn build_cors() -> CorsLayer {
         ])
 }
 
+#[allow(clippy::too_many_lines)] // Flat route table; one entry per endpoint.
 fn protected_router(auth: AuthState, rate_limit: RateLimitState) -> Router<AppState> {
     Router::new()
         .route("/api/v1/products", get(products::list_products))
@@ -147,10 +168,71 @@ fn protected_router(auth: AuthState, rate_limit: RateLimitState) -> Router<AppSt
             get(bills::list_bill_events),
         )
         .route("/api/v1/bills/{bill_id}/texts", get(bills::list_bill_texts))
+        .route(
+            "/api/v1/bills/{bill_id}/texts/{text_id}",
+            get(bill_texts::get_bill_text),
+        )
+        .route(
+            "/api/v1/bills/{bill_id}/diff",
+            get(bill_texts::diff_bill_texts),
+        )
+        .route(
+            "/api/v1/bills/{bill_id}/hearings",
+            get(hearings::list_bill_hearings),
+        )
+        .route(
+            "/api/v1/hearings/upcoming",
+            get(hearings::list_upcoming_hearings),
+        )
+        .route(
+            "/api/v1/bills/{bill_id}/sponsors",
+            get(legislators::list_bill_sponsors),
+        )
+        .route(
+            "/api/v1/bills/{bill_id}/votes",
+            get(legislators::list_bill_votes),
+        )
+        .route("/api/v1/legislators", get(legislators::list_legislators))
+        .route(
+            "/api/v1/legislators/{legislator_id}/record",
+            get(legislators::get_legislator_record),
+        )
+        .route(
+            "/api/v1/state-rules",
+            get(state_rules::list_state_rules).post(state_rules::create_state_rule),
+        )
+        .route(
+            "/api/v1/state-rules/matrix",
+            get(state_rules::get_state_rules_matrix),
+        )
+        .route(
+            "/api/v1/state-rules/{rule_id}",
+            delete(state_rules::delete_state_rule),
+        )
+        .route(
+            "/api/v1/watched-bills",
+            get(watched_bills::list_watched_bills).post(watched_bills::watch_bill),
+        )
+        .route(
+            "/api/v1/watched-bills/{bill_id}",
+            delete(watched_bills::unwatch_bill),
+        )
+        .route(
+            "/api/v1/regulatory-exposure",
+            get(regulatory_exposure::list_regulatory_exposure),
+        )
+        .route(
+            "/api/v1/regulatory-exposure/series",
+            get(regulatory_exposure::list_regulatory_exposure_series),
+        )
         .route(
             "/api/v1/collection-runs",
             get(collection_runs::list_collection_runs),
         )
+        .route(
+            "/api/v1/legiscan/quota",
+            get(legiscan_quota::get_legiscan_quota),
+        )
         .route(
             "/api/v1/sentiment/summary",
             get(sentiment::list_sentiment_summary),
@@ -168,10 +250,84 @@ fn protected_router(auth: AuthState, rate_limit: RateLimitState) -> Router<AppSt
             get(locations::list_locations_by_state),
         )
         .route("/api/v1/locations/pins", get(locations::list_location_pins))
+        .route(
+            "/api/v1/locations/events",
+            get(locations::list_location_events),
+        )
+        .route(
+            "/api/v1/locations/net-change",
+            get(locations::list_location_net_change),
+        )
+        .route(
+            "/api/v1/locations/forecast",
+            get(locations::list_location_forecasts),
+        )
+        .route(
+            "/api/v1/locations/nearby",
+            get(locations::list_nearby_locations),
+        )
+        .route(
+            "/api/v1/locations/nearest-competitors",
+            get(locations::list_nearest_competitors),
+        )
+        .route(
+            "/api/v1/locations/density",
+            get(locations::list_location_density),
+        )
+        .route(
+            "/api/v1/locations/geo-rollup",
+            get(locations::list_location_geo_rollup),
+        )
+        .route(
+            "/api/v1/locations/whitespace",
+            get(locations::list_location_whitespace),
+        )
+        .route(
+            "/api/v1/locations/whitespace.csv",
+            get(locations::export_location_whitespace_csv),
+        )
+        .route(
+            "/api/v1/locations/distribution",
+            get(locations::list_sku_distribution),
+        )
+        .route(
+            "/api/v1/locations/quarantines",
+            get(locations::list_location_quarantines),
+        )
+        .route(
+            "/api/v1/locations/quarantines/{id}/approve",
+            post(locations::approve_location_quarantine),
+        )
+        .route(
+            "/api/v1/locations/quarantines/{id}/reject",
+            post(locations::reject_location_quarantine),
+        )
+        .route(
+            "/api/v1/locations/tiles/{z}/{x}/{y}",
+            get(locations::get_location_tile),
+        )
+        .route("/api/v1/accounts/import", post(accounts::import_accounts))
+        .route(
+            "/api/v1/accounts/competitor-presence",
+            get(accounts::list_competitor_presence),
+        )
+        .route("/api/v1/accounts/prospects", get(accounts::list_prospects))
         .route(
             "/api/v1/brands",
             get(brands::list_brands).post(brands::create_brand),
         )
+        .route(
+            "/api/v1/brand-candidates",
+            get(brand_candidates::list_brand_candidates),
+        )
+        .route(
+            "/api/v1/brand-candidates/{id}/promote",
+            post(brand_candidates::promote_brand_candidate),
+        )
+        .route(
+            "/api/v1/brand-candidates/{id}/dismiss",
+            post(brand_candidates::dismiss_brand_candidate),
+        )
         .route(
             "/api/v1/brands/{slug}",
             get(brands::get_brand)
@@ -201,6 +357,10 @@ fn protected_router(auth: AuthState, rate_limit: RateLimitState) -> Router<AppSt
             get(brands::list_competitors),
         )
         .route("/api/v1/brands/{slug}/media", get(brands::list_media))
+        .route(
+            "/api/v1/brands/{slug}/compliance",
+            get(brands::get_brand_compliance),
+        )
         .route(
             "/api/v1/brands/{slug}/profile",
             put(brands::upsert_brand_profile),
@@ -235,7 +395,8 @@ pub fn build_app(state: AppState, auth: AuthState, rate_limit: RateLimitState) -
         .layer(
             ServiceBuilder::new()
                 .layer(build_cors())
-                .layer(axum::middleware::from_fn(request_id)),
+                .layer(axum::middleware::from_fn(request_id))
+                .layer(Extension(locations::TileCache::default())),
         )
         .with_state(state)
 }
@@ -452,6 +613,7 @@ mod tests {
             states_covered: 7,
             locator_source: Some("locally".to_string()),
             last_seen_at: Some(Utc::now()),
+            channel_mix: serde_json::json!({ "liquor": 30, "unknown": 12 }),
         };
         let json = serde_json::to_string(&item).expect("serialize LocationsDashboardItem");
         assert!(
@@ -475,6 +637,7 @@ mod tests {
             city: Some("Austin".to_string()),
             state: Some("TX".to_string()),
             zip: Some("78701".to_string()),
+            country: "US".to_string(),
             locator_source: Some("locally".to_string()),
             brand_name: "Test Brand".to_string(),
             brand_slug: "test-brand".to_string(),
@@ -512,6 +675,7 @@ mod tests {
                 city: None,
                 state: None,
                 zip: None,
+                country: "US".to_string(),
                 locator_source: None,
                 brand_name: "Brand".to_string(),
                 brand_slug: "brand".to_string(),
@@ -540,9 +704,11 @@ mod tests {
     #[test]
     fn locations_by_state_item_is_serializable() {
         let item = LocationsByStateItem {
+            country: "US".to_string(),
             state: "TX".to_string(),
             brand_count: 3,
             location_count: 12,
+            channel_mix: serde_json::json!({ "grocery": 12 }),
         };
         let json = serde_json::to_string(&item).expect("serialize LocationsByStateItem");
         let round_tripped: serde_json::Value =
@@ -617,34 +783,27 @@ mod tests {
         );
     }
 
-    // -------------------------------------------------------------------------
-    // Brands — integration tests (with DB)
-    // -------------------------------------------------------------------------
-
-    /// Seed a minimal brand for brand API tests.
-    async fn seed_brand(pool: &sqlx::PgPool, slug: &str) -> i64 {
-        sqlx::query_scalar::<_, i64>(
-            "INSERT INTO brands (name, slug, relationship, tier, shop_url, is_active) \
-             VALUES ($1, $2, 'competitor', 1, $3, true) RETURNING id",
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn locations_by_state_filters_by_country(pool: sqlx::PgPool) {
+        let brand_id = seed_location_brand(&pool, "loc-country-brand").await;
+        sqlx::query(
+            "INSERT INTO store_locations \
+             (brand_id, location_key, name, state, country, locator_source, raw_data) \
+             VALUES ($1, 'loc-country-us', 'Austin Store', 'TX', 'US', 'locally', '{}'::jsonb), \
+                    ($1, 'loc-country-ca', 'Toronto Store', 'ON', 'CA', 'locally', '{}'::jsonb)",
         )
-        .bind(format!("Brand {slug}"))
-        .bind(slug)
-        .bind(format!("https://{slug}.example.com"))
-        .fetch_one(pool)
+        .bind(brand_id)
+        .execute(&pool)
         .await
-        .expect("seed_brand failed")
-    }
-
-    #[sqlx::test(migrations = "../../migrations")]
-    async fn list_brands_returns_ok(pool: sqlx::PgPool) {
-        seed_brand(&pool, "test-brand-list").await;
+        .expect("insert locations");
 
         let auth = crate::middleware::AuthState::from_env(true).expect("auth");
         let app = build_app(AppState { pool }, auth, default_rate_limit_state());
         let response = app
+            .clone()
             .oneshot(
                 Request::builder()
-                    .uri("/api/v1/brands")
+                    .uri("/api/v1/locations/by-state?country=canada")
                     .body(Body::empty())
                     .expect("request"),
             )
@@ -657,38 +816,105 @@ mod tests {
             .expect("body bytes");
         let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
         let data = json["data"].as_array().expect("data array");
-        assert_eq!(data.len(), 1, "expected 1 brand");
-        assert_eq!(data[0]["slug"].as_str(), Some("test-brand-list"));
-        assert_eq!(data[0]["completeness_score"].as_i64(), Some(0));
+        assert_eq!(data.len(), 1, "only the Canadian province");
+        assert_eq!(data[0]["country"].as_str(), Some("CA"));
+        assert_eq!(data[0]["state"].as_str(), Some("ON"));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/locations/pins?country=atlantis")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
     #[sqlx::test(migrations = "../../migrations")]
-    async fn get_brand_returns_404_for_unknown_slug(pool: sqlx::PgPool) {
+    async fn location_events_returns_feed_and_rejects_unknown_type(pool: sqlx::PgPool) {
+        let brand_id = seed_location_brand(&pool, "loc-events-brand").await;
+
+        let location_id = sqlx::query_scalar::<_, i64>(
+            "INSERT INTO store_locations \
+             (brand_id, location_key, name, state, country, locator_source, raw_data) \
+             VALUES ($1, 'loc-evt-key-1', 'Events Store SC', 'SC', 'US', 'locally', '{}'::jsonb) \
+             RETURNING id",
+        )
+        .bind(brand_id)
+        .fetch_one(&pool)
+        .await
+        .expect("insert location");
+        sqlx::query(
+            "INSERT INTO store_location_events (brand_id, store_location_id, event_type, state) \
+             VALUES ($1, $2, 'added', 'SC')",
+        )
+        .bind(brand_id)
+        .bind(location_id)
+        .execute(&pool)
+        .await
+        .expect("insert event");
+
         let auth = crate::middleware::AuthState::from_env(true).expect("auth");
         let app = build_app(AppState { pool }, auth, default_rate_limit_state());
         let response = app
+            .clone()
             .oneshot(
                 Request::builder()
-                    .uri("/api/v1/brands/nonexistent-slug-xyz")
+                    .uri("/api/v1/locations/events?brand_slug=loc-events-brand&state=sc")
                     .body(Body::empty())
                     .expect("request"),
             )
             .await
             .expect("response");
 
-        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        let items = json["data"]["items"].as_array().expect("items array");
+        assert_eq!(items.len(), 1, "expected 1 event");
+        assert_eq!(items[0]["event_type"].as_str(), Some("added"));
+        assert_eq!(items[0]["store_name"].as_str(), Some("Events Store SC"));
+        assert!(json["data"]["next_cursor"].is_null());
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/locations/events?event_type=closed")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
     #[sqlx::test(migrations = "../../migrations")]
-    async fn get_brand_returns_brand_profile(pool: sqlx::PgPool) {
-        seed_brand(&pool, "test-brand-detail").await;
+    async fn location_forecast_returns_series_and_validates_input(pool: sqlx::PgPool) {
+        let brand_id = seed_location_brand(&pool, "loc-forecast-brand").await;
+        // One new Texas store each week for the last ten weeks.
+        sqlx::query(
+            "INSERT INTO store_locations \
+             (brand_id, location_key, name, state, country, first_seen_at, raw_data) \
+             SELECT $1, 'loc-forecast-' || n, 'Store ' || n, 'TX', 'US', \
+                    NOW() - make_interval(weeks => n), '{}'::jsonb \
+             FROM generate_series(0, 9) AS n",
+        )
+        .bind(brand_id)
+        .execute(&pool)
+        .await
+        .expect("insert locations");
 
         let auth = crate::middleware::AuthState::from_env(true).expect("auth");
         let app = build_app(AppState { pool }, auth, default_rate_limit_state());
         let response = app
+            .clone()
             .oneshot(
                 Request::builder()
-                    .uri("/api/v1/brands/test-brand-detail")
+                    .uri("/api/v1/locations/forecast?brand_slug=loc-forecast-brand&model=linear&horizon=4&weeks=12")
                     .body(Body::empty())
                     .expect("request"),
             )
@@ -700,34 +926,117 @@ mod tests {
             .await
             .expect("body bytes");
         let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
-        assert_eq!(json["data"]["slug"].as_str(), Some("test-brand-detail"));
-        assert!(json["data"]["completeness"].is_object());
-        assert!(json["data"]["social_handles"].is_array());
+        let data = json["data"].as_array().expect("data array");
+        assert_eq!(data.len(), 1, "one brand/state series");
+        assert_eq!(data[0]["state"].as_str(), Some("TX"));
+        assert_eq!(data[0]["model"].as_str(), Some("linear"));
+        assert_eq!(data[0]["current_count"].as_i64(), Some(10));
+        assert_eq!(data[0]["forecast"].as_array().map(Vec::len), Some(4));
+        let trend = data[0]["weekly_trend"].as_f64().expect("trend");
+        assert!(trend > 0.5, "growing by ~1 store a week, got {trend}");
+
+        for uri in [
+            "/api/v1/locations/forecast?model=arima",
+            "/api/v1/locations/forecast?group_by=city",
+        ] {
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .uri(uri)
+                        .body(Body::empty())
+                        .expect("request"),
+                )
+                .await
+                .expect("response");
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{uri}");
+        }
     }
 
     #[sqlx::test(migrations = "../../migrations")]
-    async fn locations_by_state_returns_ok(pool: sqlx::PgPool) {
-        let brand_id = seed_location_brand(&pool, "loc-state-brand").await;
+    async fn location_tiles_cluster_and_cache(pool: sqlx::PgPool) {
+        let brand_id = seed_location_brand(&pool, "loc-tiles-brand").await;
+        sqlx::query(
+            "INSERT INTO store_locations \
+             (brand_id, location_key, name, state, country, latitude, longitude, raw_data) \
+             VALUES ($1, 'loc-tiles-1', 'Columbia A', 'SC', 'US', 34.0007, -81.0348, '{}'::jsonb), \
+                    ($1, 'loc-tiles-2', 'Columbia B', 'SC', 'US', 34.0100, -81.0500, '{}'::jsonb)",
+        )
+        .bind(brand_id)
+        .execute(&pool)
+        .await
+        .expect("insert locations");
 
-        for key in &["loc-state-key-1", "loc-state-key-2"] {
-            sqlx::query(
-                "INSERT INTO store_locations \
-                 (brand_id, location_key, name, state, country, raw_data) \
-                 VALUES ($1, $2, 'State Store TX', 'TX', 'US', '{}'::jsonb)",
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let get = |uri: &str| {
+            app.clone().oneshot(
+                Request::builder()
+                    .uri(uri)
+                    .body(Body::empty())
+                    .expect("request"),
             )
-            .bind(brand_id)
-            .bind(key)
-            .execute(&pool)
+        };
+
+        // z=5 clusters both Columbia stores into one feature.
+        let uri = "/api/v1/locations/tiles/5/8/12?brands=loc-tiles-brand";
+        let response = get(uri).await.expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "application/geo+json");
+        assert_eq!(response.headers()["x-tile-cache"], "miss");
+        let body = to_bytes(response.into_body(), usize::MAX)
             .await
-            .expect("insert location");
+            .expect("body bytes");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        let features = json["features"].as_array().expect("features array");
+        assert_eq!(features.len(), 1, "one cluster");
+        assert_eq!(features[0]["properties"]["cluster"], true);
+        assert_eq!(features[0]["properties"]["point_count"], 2);
+
+        let response = get(uri).await.expect("response");
+        assert_eq!(response.headers()["x-tile-cache"], "hit");
+
+        let response = get("/api/v1/locations/tiles/5/8/12.mvt?relationship=competitor")
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()["content-type"],
+            "application/vnd.mapbox-vector-tile"
+        );
+
+        for uri in [
+            "/api/v1/locations/tiles/23/0/0",
+            "/api/v1/locations/tiles/2/4/0",
+            "/api/v1/locations/tiles/5/8/12?relationship=partner",
+        ] {
+            let response = get(uri).await.expect("response");
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{uri}");
         }
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn locations_nearby_resolves_zip_and_validates_input(pool: sqlx::PgPool) {
+        let brand_id = seed_location_brand(&pool, "loc-nearby-brand").await;
+        sqlx::query(
+            "INSERT INTO store_locations \
+             (brand_id, location_key, name, state, zip, country, latitude, longitude, \
+              locator_source, raw_data) \
+             VALUES ($1, 'loc-near-key-1', 'Nearby Store', 'SC', '29201', 'US', \
+                     34.0007, -81.0348, 'locally', '{}'::jsonb)",
+        )
+        .bind(brand_id)
+        .execute(&pool)
+        .await
+        .expect("insert location");
 
         let auth = crate::middleware::AuthState::from_env(true).expect("auth");
         let app = build_app(AppState { pool }, auth, default_rate_limit_state());
         let response = app
+            .clone()
             .oneshot(
                 Request::builder()
-                    .uri("/api/v1/locations/by-state")
+                    .uri("/api/v1/locations/nearby?zip=29201&radius_miles=5")
                     .body(Body::empty())
                     .expect("request"),
             )
@@ -739,15 +1048,1266 @@ mod tests {
             .await
             .expect("body bytes");
         let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
-        let data = json["data"].as_array().expect("data array");
-        let tx_row = data
-            .iter()
-            .find(|r| r["state"].as_str() == Some("TX"))
-            .expect("TX row missing from response");
-        assert_eq!(
-            tx_row["location_count"].as_i64(),
-            Some(2),
-            "TX location_count should be 2"
-        );
+        let items = json["data"]["items"].as_array().expect("items array");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0]["store_name"].as_str(), Some("Nearby Store"));
+
+        for uri in [
+            "/api/v1/locations/nearby?lat=34.0",
+            "/api/v1/locations/nearby?lat=34.0&lng=-81.0&radius_miles=0",
+            "/api/v1/locations/density?group_by=planet",
+            "/api/v1/locations/geo-rollup?level=state",
+        ] {
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .uri(uri)
+                        .body(Body::empty())
+                        .expect("request"),
+                )
+                .await
+                .expect("response");
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{uri}");
+        }
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn location_quarantine_review_maps_errors(pool: sqlx::PgPool) {
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/locations/quarantines?status=maybe")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri(format!(
+                        "/api/v1/locations/quarantines/{}/approve",
+                        uuid::Uuid::new_v4()
+                    ))
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from("{}"))
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        // The review note is optional, so a bare POST reaches the handler.
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri(format!(
+                        "/api/v1/locations/quarantines/{}/reject",
+                        uuid::Uuid::new_v4()
+                    ))
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn accounts_import_validates_and_reports_prospects(pool: sqlx::PgPool) {
+        let competitor_id = seed_location_brand(&pool, "acct-competitor").await;
+        sqlx::query("UPDATE brands SET relationship = 'competitor' WHERE id = $1")
+            .bind(competitor_id)
+            .execute(&pool)
+            .await
+            .expect("set competitor");
+        sqlx::query(
+            "INSERT INTO store_locations \
+             (brand_id, location_key, name, address_line1, state, zip, country, \
+              locator_source, raw_data, address_key) \
+             VALUES ($1, 'acct-c1', 'Rival Shop', '10 Gervais St', 'SC', '29201', 'US', \
+                     'locally', '{}'::jsonb, '10 gervais st|29201')",
+        )
+        .bind(competitor_id)
+        .execute(&pool)
+        .await
+        .expect("insert location");
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let post = |body: &'static str| {
+            Request::builder()
+                .method(Method::POST)
+                .uri("/api/v1/accounts/import")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body))
+                .expect("request")
+        };
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                r#"{"accounts":[{"account_number":" ","name":"Nameless"}]}"#,
+            ))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                r#"{"accounts":[{"account_number":"A-9","name":"Our Shop","address_line1":"1 Main Street","zip":"29201"}]}"#,
+            ))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json");
+        assert_eq!(json["data"]["inserted"], 1);
+        assert_eq!(json["data"]["matched"], 0);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/accounts/prospects?state=sc")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json");
+        assert_eq!(json["data"][0]["name"], "Rival Shop");
+        assert_eq!(json["data"][0]["competitor_brands"][0], "acct-competitor");
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn locations_whitespace_csv_export_returns_attachment(pool: sqlx::PgPool) {
+        let portfolio_id = seed_location_brand(&pool, "ws-portfolio").await;
+        let competitor_id = seed_location_brand(&pool, "ws-competitor").await;
+        sqlx::query("UPDATE brands SET relationship = 'competitor' WHERE id = $1")
+            .bind(competitor_id)
+            .execute(&pool)
+            .await
+            .expect("set competitor");
+        for (brand_id, key, zip) in [
+            (portfolio_id, "ws-p1", "29201"),
+            (competitor_id, "ws-c1", "29201"),
+            (competitor_id, "ws-c2", "29063"),
+        ] {
+            sqlx::query(
+                "INSERT INTO store_locations \
+                 (brand_id, location_key, name, state, zip, country, locator_source, raw_data) \
+                 VALUES ($1, $2, $2, 'SC', $3, 'US', 'locally', '{}'::jsonb)",
+            )
+            .bind(brand_id)
+            .bind(key)
+            .bind(zip)
+            .execute(&pool)
+            .await
+            .expect("insert location");
+        }
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/locations/whitespace.csv?group_by=zip")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()["content-type"],
+            "text/csv; charset=utf-8"
+        );
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let csv = String::from_utf8(body.to_vec()).expect("utf8");
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2, "header + one gap row: {csv}");
+        assert!(lines[1].starts_with("29063,"));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/locations/whitespace?channel=bodega")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn locations_distribution_counts_stores_per_sku(pool: sqlx::PgPool) {
+        let brand_id = seed_location_brand(&pool, "dist-brand").await;
+        let location_id = sqlx::query_scalar::<_, i64>(
+            "INSERT INTO store_locations \
+             (brand_id, location_key, name, state, country, locator_source, raw_data) \
+             VALUES ($1, 'dist-key-1', 'Dist Store', 'SC', 'US', 'destini', '{}'::jsonb) \
+             RETURNING id",
+        )
+        .bind(brand_id)
+        .fetch_one(&pool)
+        .await
+        .expect("insert location");
+        sqlx::query(
+            "INSERT INTO store_location_products (store_location_id, product_ref, product_name) \
+             VALUES ($1, 'p-1', 'Lemon 12oz')",
+        )
+        .bind(location_id)
+        .execute(&pool)
+        .await
+        .expect("insert carried product");
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/locations/distribution?brand_slug=dist-brand&group_by=state")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json");
+        assert_eq!(json["data"][0]["product_name"], "Lemon 12oz");
+        assert_eq!(json["data"][0]["state"], "SC");
+        assert_eq!(json["data"][0]["store_count"], 1);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/locations/distribution?group_by=county")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    // -------------------------------------------------------------------------
+    // Brands — integration tests (with DB)
+    // -------------------------------------------------------------------------
+
+    /// Seed a minimal brand for brand API tests.
+    async fn seed_brand(pool: &sqlx::PgPool, slug: &str) -> i64 {
+        sqlx::query_scalar::<_, i64>(
+            "INSERT INTO brands (name, slug, relationship, tier, shop_url, is_active) \
+             VALUES ($1, $2, 'competitor', 1, $3, true) RETURNING id",
+        )
+        .bind(format!("Brand {slug}"))
+        .bind(slug)
+        .bind(format!("https://{slug}.example.com"))
+        .fetch_one(pool)
+        .await
+        .expect("seed_brand failed")
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn list_brands_returns_ok(pool: sqlx::PgPool) {
+        seed_brand(&pool, "test-brand-list").await;
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/brands")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        let data = json["data"].as_array().expect("data array");
+        assert_eq!(data.len(), 1, "expected 1 brand");
+        assert_eq!(data[0]["slug"].as_str(), Some("test-brand-list"));
+        assert_eq!(data[0]["completeness_score"].as_i64(), Some(0));
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn get_brand_returns_404_for_unknown_slug(pool: sqlx::PgPool) {
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/brands/nonexistent-slug-xyz")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn get_brand_returns_brand_profile(pool: sqlx::PgPool) {
+        seed_brand(&pool, "test-brand-detail").await;
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/brands/test-brand-detail")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        assert_eq!(json["data"]["slug"].as_str(), Some("test-brand-detail"));
+        assert!(json["data"]["completeness"].is_object());
+        assert!(json["data"]["social_handles"].is_array());
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn locations_by_state_returns_ok(pool: sqlx::PgPool) {
+        let brand_id = seed_location_brand(&pool, "loc-state-brand").await;
+
+        for key in &["loc-state-key-1", "loc-state-key-2"] {
+            sqlx::query(
+                "INSERT INTO store_locations \
+                 (brand_id, location_key, name, state, country, raw_data) \
+                 VALUES ($1, $2, 'State Store TX', 'TX', 'US', '{}'::jsonb)",
+            )
+            .bind(brand_id)
+            .bind(key)
+            .execute(&pool)
+            .await
+            .expect("insert location");
+        }
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/locations/by-state")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        let data = json["data"].as_array().expect("data array");
+        let tx_row = data
+            .iter()
+            .find(|r| r["state"].as_str() == Some("TX"))
+            .expect("TX row missing from response");
+        assert_eq!(
+            tx_row["location_count"].as_i64(),
+            Some(2),
+            "TX location_count should be 2"
+        );
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn brand_candidate_promotion_creates_brand(pool: sqlx::PgPool) {
+        scbdb_db::record_brand_candidate_evidence(
+            &pool,
+            &[scbdb_db::NewBrandCandidateEvidence {
+                name: "Wynk",
+                source: "locator",
+                source_ref: "bf-1",
+                context: None,
+                observed_at: Utc::now(),
+            }],
+        )
+        .await
+        .expect("record evidence");
+        let candidate_id: uuid::Uuid =
+            sqlx::query_scalar("SELECT public_id FROM brand_candidates WHERE slug = 'wynk'")
+                .fetch_one(&pool)
+                .await
+                .expect("candidate id");
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let promote = |body: &'static str| {
+            Request::builder()
+                .method(Method::POST)
+                .uri(format!("/api/v1/brand-candidates/{candidate_id}/promote"))
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body))
+                .expect("request")
+        };
+
+        let response = app
+            .clone()
+            .oneshot(promote(r#"{"relationship": "competitor", "tier": 7}"#))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = app
+            .clone()
+            .oneshot(promote(r#"{"relationship": "competitor", "tier": 2}"#))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        assert_eq!(json["data"]["slug"], "wynk");
+
+        let response = app
+            .clone()
+            .oneshot(promote(r#"{"relationship": "competitor", "tier": 2}"#))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/brand-candidates?status=promoted")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        assert_eq!(json["data"][0]["brand_slug"], "wynk");
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn bill_diff_defaults_to_latest_two_extracted_versions(pool: sqlx::PgPool) {
+        let bill_id = scbdb_db::upsert_bill(
+            &pool,
+            "SC",
+            "H-3100",
+            "Hemp Beverages",
+            None,
+            "introduced",
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("bill");
+        let versions = [
+            (
+                11,
+                "2025-01-10",
+                "SECTION 1. No more than 5 milligrams per serving.",
+            ),
+            (
+                12,
+                "2025-02-10",
+                "SECTION 1. No more than 10 milligrams per serving.",
+            ),
+        ];
+        for (text_id, date, content) in versions {
+            scbdb_db::upsert_bill_text(
+                &pool,
+                bill_id,
+                text_id,
+                date.parse().ok(),
+                "Amended",
+                "text/html",
+                None,
+            )
+            .await
+            .expect("text");
+            let row_id: i64 =
+                sqlx::query_scalar("SELECT id FROM bill_texts WHERE legiscan_text_id = $1")
+                    .bind(text_id)
+                    .fetch_one(&pool)
+                    .await
+                    .expect("row id");
+            scbdb_db::record_bill_text_extraction(&pool, row_id, Some(content), None)
+                .await
+                .expect("content");
+        }
+        let public_id: uuid::Uuid = sqlx::query_scalar("SELECT public_id FROM bills WHERE id = $1")
+            .bind(bill_id)
+            .fetch_one(&pool)
+            .await
+            .expect("public id");
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let get_json = |uri: String| {
+            let app = app.clone();
+            async move {
+                let response = app
+                    .oneshot(
+                        Request::builder()
+                            .uri(uri)
+                            .body(Body::empty())
+                            .expect("request"),
+                    )
+                    .await
+                    .expect("response");
+                let status = response.status();
+                let body = to_bytes(response.into_body(), usize::MAX)
+                    .await
+                    .expect("body");
+                let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+                (status, json)
+            }
+        };
+
+        let (status, json) = get_json(format!("/api/v1/bills/{public_id}/diff")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["from"]["text_id"], 11);
+        assert_eq!(json["data"]["to"]["text_id"], 12);
+        assert_eq!(json["data"]["diff"]["modified"], 1);
+        let spans = &json["data"]["diff"]["sections"][0]["spans"];
+        assert_eq!(spans[1]["op"], "delete");
+        assert_eq!(spans[1]["text"], "5");
+        assert_eq!(spans[2]["text"], "10");
+
+        let (status, json) = get_json(format!("/api/v1/bills/{public_id}/texts/12")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(json["data"]["content"]
+            .as_str()
+            .is_some_and(|c| c.contains("10 milligrams")));
+
+        let (status, _) = get_json(format!("/api/v1/bills/{public_id}/diff?from=99&to=12")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn bills_filter_by_topic_and_min_relevance(pool: sqlx::PgPool) {
+        for (number, score, topics) in [
+            ("H-3200", 85.0, vec![("thc_serving_caps", 9.0)]),
+            ("H-3201", 30.0, vec![("excise_tax", 4.0)]),
+        ] {
+            let id = scbdb_db::upsert_bill(
+                &pool,
+                "SC",
+                number,
+                "Hemp",
+                None,
+                "introduced",
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .expect("bill");
+            scbdb_db::replace_bill_topics(&pool, id, score, &topics)
+                .await
+                .expect("topics");
+        }
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let get = |uri: &'static str| {
+            app.clone().oneshot(
+                Request::builder()
+                    .uri(uri)
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+        };
+
+        let response = get("/api/v1/bills?topic=excise_tax")
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        assert_eq!(json["data"].as_array().map(Vec::len), Some(1));
+        assert_eq!(json["data"][0]["bill_number"], "H-3201");
+        assert_eq!(json["data"][0]["topics"][0], "excise_tax");
+
+        let response = get("/api/v1/bills?min_relevance=50")
+            .await
+            .expect("response");
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        assert_eq!(json["data"].as_array().map(Vec::len), Some(1));
+        assert_eq!(json["data"][0]["relevance_score"], 85.0);
+
+        let response = get("/api/v1/bills?min_relevance=150")
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn bill_votes_and_legislator_record(pool: sqlx::PgPool) {
+        let bill_id = scbdb_db::upsert_bill(
+            &pool,
+            "SC",
+            "H-3300",
+            "Hemp Beverage Serving Limits",
+            None,
+            "passed",
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("bill");
+        scbdb_db::replace_bill_topics(&pool, bill_id, 80.0, &[("thc_serving_caps", 9.0)])
+            .await
+            .expect("topics");
+        let legislator = scbdb_db::NewLegislator {
+            legiscan_people_id: 10,
+            jurisdiction: "SC",
+            name: "Jane Doe",
+            first_name: None,
+            last_name: None,
+            party: Some("R"),
+            role: Some("Rep"),
+            district: None,
+        };
+        scbdb_db::upsert_legislator(&pool, &legislator)
+            .await
+            .expect("legislator");
+        scbdb_db::replace_bill_sponsors(
+            &pool,
+            bill_id,
+            &[scbdb_db::NewBillSponsor {
+                legiscan_people_id: 10,
+                sponsor_type: "primary",
+                sponsor_order: 1,
+            }],
+        )
+        .await
+        .expect("sponsors");
+        let roll_call_id = scbdb_db::upsert_roll_call(
+            &pool,
+            bill_id,
+            &scbdb_db::NewRollCall {
+                legiscan_roll_call_id: 500,
+                vote_date: "2025-03-04".parse().ok(),
+                chamber: Some("H"),
+                description: "Third Reading",
+                yea: 1,
+                nay: 1,
+                nv: 0,
+                absent: 0,
+                total: 2,
+                passed: false,
+            },
+        )
+        .await
+        .expect("roll call");
+        scbdb_db::record_roll_call_votes(&pool, roll_call_id, &[(10, "yea"), (11, "nay")])
+            .await
+            .expect("votes");
+        let (bill_public_id, legislator_public_id): (uuid::Uuid, uuid::Uuid) = sqlx::query_as(
+            "SELECT b.public_id, l.public_id FROM bills b, legislators l \
+             WHERE b.id = $1 AND l.legiscan_people_id = 10",
+        )
+        .bind(bill_id)
+        .fetch_one(&pool)
+        .await
+        .expect("public ids");
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let get_json = |uri: String| {
+            let app = app.clone();
+            async move {
+                let response = app
+                    .oneshot(
+                        Request::builder()
+                            .uri(uri)
+                            .body(Body::empty())
+                            .expect("request"),
+                    )
+                    .await
+                    .expect("response");
+                let status = response.status();
+                let body = to_bytes(response.into_body(), usize::MAX)
+                    .await
+                    .expect("body");
+                let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+                (status, json)
+            }
+        };
+
+        let (status, json) = get_json(format!("/api/v1/bills/{bill_public_id}/sponsors")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"][0]["name"], "Jane Doe");
+        assert_eq!(json["data"][0]["sponsor_type"], "primary");
+
+        let (status, json) = get_json(format!("/api/v1/bills/{bill_public_id}/votes")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"][0]["roll_call_id"], 500);
+        assert_eq!(json["data"][0]["votes"].as_array().map(Vec::len), Some(2));
+        assert_eq!(json["data"][0]["votes"][0]["name"], "Jane Doe");
+        assert!(json["data"][0]["votes"][1]["name"].is_null());
+
+        let (status, json) = get_json(format!(
+            "/api/v1/legislators/{legislator_public_id}/record?topic=thc_serving_caps"
+        ))
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["tally"]["yea"], 1);
+        assert_eq!(json["data"]["sponsored"][0]["bill_number"], "H-3300");
+        assert_eq!(json["data"]["votes"][0]["vote"], "yea");
+
+        let (_, json) = get_json(format!(
+            "/api/v1/legislators/{legislator_public_id}/record?topic=excise_tax"
+        ))
+        .await;
+        assert_eq!(json["data"]["votes"].as_array().map(Vec::len), Some(0));
+
+        let (status, json) =
+            get_json("/api/v1/legislators?jurisdiction=SC&q=jane".to_string()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"].as_array().map(Vec::len), Some(1));
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn upcoming_hearings_cover_the_next_days_only(pool: sqlx::PgPool) {
+        let bill_id = scbdb_db::upsert_bill(
+            &pool,
+            "SC",
+            "H-3400",
+            "Hemp Beverage Retail Licensing",
+            None,
+            "introduced",
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("bill");
+        let today = chrono::Utc::now().date_naive();
+        let dates = [
+            today - chrono::Days::new(3),
+            today + chrono::Days::new(2),
+            today + chrono::Days::new(30),
+        ];
+        let hearings: Vec<scbdb_db::NewBillHearing<'_>> = dates
+            .iter()
+            .map(|date| scbdb_db::NewBillHearing {
+                hearing_date: *date,
+                hearing_time: chrono::NaiveTime::from_hms_opt(9, 30, 0),
+                event_type: "Hearing",
+                committee: Some("Judiciary"),
+                chamber: Some("H"),
+                location: Some("Blatt 516"),
+                description: None,
+            })
+            .collect();
+        scbdb_db::replace_bill_hearings(&pool, bill_id, &hearings)
+            .await
+            .expect("hearings");
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let get = |uri: &'static str| {
+            app.clone().oneshot(
+                Request::builder()
+                    .uri(uri)
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+        };
+
+        let response = get("/api/v1/hearings/upcoming?jurisdiction=SC")
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        assert_eq!(json["data"].as_array().map(Vec::len), Some(1));
+        assert_eq!(json["data"][0]["bill_number"], "H-3400");
+        assert_eq!(json["data"][0]["committee"], "Judiciary");
+        assert_eq!(json["data"][0]["hearing_time"], "09:30:00");
+
+        let response = get("/api/v1/hearings/upcoming?days=60")
+            .await
+            .expect("response");
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        assert_eq!(json["data"].as_array().map(Vec::len), Some(2));
+
+        let response = get("/api/v1/hearings/upcoming?days=0")
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn legiscan_quota_reports_this_months_usage(pool: sqlx::PgPool) {
+        let today = chrono::Utc::now().date_naive();
+        let month = chrono::Datelike::with_day(&today, 1).expect("month start");
+        for _ in 0..3 {
+            scbdb_db::reserve_legiscan_request(&pool, "abc123def456", month, 3)
+                .await
+                .expect("reserve");
+        }
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/legiscan/quota")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        assert_eq!(json["data"][0]["key_fingerprint"], "abc123def456");
+        assert_eq!(json["data"][0]["requests_used"], 3);
+        assert_eq!(json["data"][0]["remaining"], 0);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn state_rules_are_curated_and_compared_across_states(pool: sqlx::PgPool) {
+        let bill_id = scbdb_db::upsert_bill(
+            &pool,
+            "SC",
+            "H-3500",
+            "Hemp Beverage Serving Limits",
+            None,
+            "passed",
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("bill");
+        let bill = scbdb_db::list_bills(&pool, Some("SC"), 1)
+            .await
+            .expect("bills")
+            .into_iter()
+            .find(|b| b.id == bill_id)
+            .expect("bill row");
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let post = |body: String| {
+            Request::builder()
+                .method(Method::POST)
+                .uri("/api/v1/state-rules")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body))
+                .expect("request")
+        };
+        let get = |uri: &'static str| {
+            Request::builder()
+                .uri(uri)
+                .body(Body::empty())
+                .expect("request")
+        };
+
+        for body in [
+            r#"{"jurisdiction":"sc","rule_kind":"max_thc_mg_per_serving","value":10,"effective_from":"2020-01-01"}"#.to_string(),
+            format!(
+                r#"{{"jurisdiction":"SC","rule_kind":"max_thc_mg_per_serving","value":5,"effective_from":"2025-01-01","bill_id":"{}","source_text":"not more than 5 milligrams per serving"}}"#,
+                bill.public_id
+            ),
+            r#"{"jurisdiction":"TX","rule_kind":"min_age","value":21,"effective_from":"2025-09-01"}"#.to_string(),
+        ] {
+            let response = app.clone().oneshot(post(body)).await.expect("response");
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+        let response = app
+            .clone()
+            .oneshot(post(
+                r#"{"jurisdiction":"SC","rule_kind":"retail_channels","effective_from":"2025-01-01"}"#
+                    .to_string(),
+            ))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let response = app
+            .clone()
+            .oneshot(post(
+                r#"{"jurisdiction":"SC","rule_kind":"max_thc_mg_per_serving","value":8,"effective_from":"2022-01-01","effective_to":"2026-01-01"}"#
+                    .to_string(),
+            ))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let response = app
+            .clone()
+            .oneshot(get("/api/v1/state-rules/matrix"))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        assert_eq!(json["data"].as_array().map(Vec::len), Some(2));
+        assert_eq!(json["data"][0]["jurisdiction"], "SC");
+        assert_eq!(json["data"][0]["max_thc_mg_per_serving"]["value"], 5.0);
+        assert_eq!(
+            json["data"][0]["max_thc_mg_per_serving"]["bill_number"],
+            "H-3500"
+        );
+        assert!(json["data"][0]["min_age"].is_null());
+        assert_eq!(json["data"][1]["min_age"]["value"], 21.0);
+
+        let response = app
+            .oneshot(get("/api/v1/state-rules?jurisdiction=SC&history=true"))
+            .await
+            .expect("response");
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        assert_eq!(json["data"].as_array().map(Vec::len), Some(2));
+        assert_eq!(json["data"][1]["effective_to"], "2025-01-01");
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn brand_compliance_reports_violations_by_severity(pool: sqlx::PgPool) {
+        let brand_id = seed_brand(&pool, "comply-api").await;
+        let product_id = sqlx::query_scalar::<_, i64>(
+            "INSERT INTO products (brand_id, source_platform, source_product_id, name) \
+             VALUES ($1, 'shopify', 'comply-api-1', 'Cola') RETURNING id",
+        )
+        .bind(brand_id)
+        .fetch_one(&pool)
+        .await
+        .expect("product");
+        sqlx::query(
+            "INSERT INTO product_variants (product_id, source_variant_id, title, dosage_mg) \
+             VALUES ($1, 'v1', '12oz / 10mg', 10)",
+        )
+        .bind(product_id)
+        .execute(&pool)
+        .await
+        .expect("variant");
+        sqlx::query(
+            "INSERT INTO store_locations (brand_id, location_key, name, state, retail_channel) \
+             VALUES ($1, 'comply-api-store', 'Corner Liquor', 'SC', 'liquor')",
+        )
+        .bind(brand_id)
+        .execute(&pool)
+        .await
+        .expect("store");
+        scbdb_db::insert_state_rule(
+            &pool,
+            &scbdb_db::NewStateRule {
+                jurisdiction: "SC",
+                rule_kind: "max_thc_mg_per_serving",
+                value: Some(5.0),
+                channels: &[],
+                unit: Some("mg"),
+                effective_from: chrono::NaiveDate::from_ymd_opt(2025, 1, 1).expect("date"),
+                effective_to: None,
+                bill_id: None,
+                source_text: None,
+                source_url: None,
+                notes: None,
+            },
+        )
+        .await
+        .expect("rule");
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let get = |uri: &'static str| {
+            Request::builder()
+                .uri(uri)
+                .body(Body::empty())
+                .expect("request")
+        };
+
+        let response = app
+            .clone()
+            .oneshot(get("/api/v1/brands/comply-api/compliance?as_of=2026-01-01"))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        assert_eq!(json["data"]["counts"]["warning"], 1);
+        let violation = &json["data"]["violations"][0];
+        assert_eq!(violation["severity"], "warning");
+        assert_eq!(violation["rule_kind"], "max_thc_mg_per_serving");
+        assert_eq!(violation["actual_mg"], 10.0);
+        assert_eq!(violation["brand_store_count"], 1);
+
+        let response = app
+            .clone()
+            .oneshot(get(
+                "/api/v1/brands/comply-api/compliance?as_of=2026-01-01&severity=critical",
+            ))
+            .await
+            .expect("response");
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        assert_eq!(json["data"]["violations"].as_array().map(Vec::len), Some(0));
+        assert_eq!(json["data"]["counts"]["warning"], 1);
+
+        let response = app
+            .clone()
+            .oneshot(get("/api/v1/brands/comply-api/compliance?severity=fatal"))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn regulatory_exposure_ranks_brands_and_serves_series(pool: sqlx::PgPool) {
+        let calm = seed_brand(&pool, "exp-calm").await;
+        let exposed = seed_brand(&pool, "exp-exposed").await;
+        let today = Utc::now().date_naive();
+        let week_ago = today - chrono::Days::new(7);
+        let row = |brand_id, brand_slug: &str, state: &str, date, exposure: f64| {
+            scbdb_db::ExposureStateRow {
+                brand_id,
+                brand_slug: brand_slug.to_string(),
+                brand_name: brand_slug.to_string(),
+                state: state.to_string(),
+                snapshot_date: date,
+                store_count: 2,
+                footprint_share: 0.5,
+                pending_bills: 1,
+                state_exposure: exposure,
+                contribution: 0.5 * exposure,
+            }
+        };
+        for (date, sc) in [(week_ago, 20.0), (today, 60.0)] {
+            let rows = [
+                row(exposed, "exp-exposed", "SC", date, sc),
+                row(exposed, "exp-exposed", "NC", date, 10.0),
+                row(calm, "exp-calm", "GA", date, 4.0),
+                row(calm, "exp-calm", "FL", date, 0.0),
+            ];
+            scbdb_db::record_regulatory_exposure(&pool, date, &rows)
+                .await
+                .expect("record");
+        }
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let get = |uri: &'static str| {
+            Request::builder()
+                .uri(uri)
+                .body(Body::empty())
+                .expect("request")
+        };
+
+        let response = app
+            .clone()
+            .oneshot(get("/api/v1/regulatory-exposure"))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        let data = json["data"].as_array().expect("data array");
+        assert_eq!(data[0]["brand_slug"], "exp-exposed");
+        assert_eq!(data[0]["score"], 35.0);
+        assert_eq!(data[0]["store_count"], 4);
+        assert_eq!(data[0]["states"][0]["state"], "SC");
+        assert_eq!(data[1]["brand_slug"], "exp-calm");
+
+        let response = app
+            .clone()
+            .oneshot(get(
+                "/api/v1/regulatory-exposure/series?brand_slug=exp-exposed&days=30",
+            ))
+            .await
+            .expect("response");
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        let points = json["data"][0]["points"].as_array().expect("points");
+        let scores: Vec<f64> = points
+            .iter()
+            .map(|p| p["score"].as_f64().expect("score"))
+            .collect();
+        assert_eq!(scores, [15.0, 35.0]);
+
+        let response = app
+            .clone()
+            .oneshot(get("/api/v1/regulatory-exposure/series?days=0"))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn watched_bills_are_added_listed_and_removed(pool: sqlx::PgPool) {
+        let bill_id = scbdb_db::upsert_bill(
+            &pool,
+            "SC",
+            "H-3600",
+            "Hemp Beverage Retail Licensing",
+            None,
+            "introduced",
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("bill");
+        let bill = scbdb_db::list_bills(&pool, Some("SC"), 1)
+            .await
+            .expect("bills")
+            .into_iter()
+            .find(|b| b.id == bill_id)
+            .expect("bill row");
+
+        let auth = crate::middleware::AuthState::from_env(true).expect("auth");
+        let app = build_app(AppState { pool }, auth, default_rate_limit_state());
+        let request = |method: Method, uri: String, body: String| {
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body))
+                .expect("request")
+        };
+
+        let watch = format!(
+            r#"{{"bill_id":"{}","note":"licensing overhaul"}}"#,
+            bill.public_id
+        );
+        let response = app
+            .clone()
+            .oneshot(request(Method::POST, "/api/v1/watched-bills".into(), watch))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let unknown = format!(r#"{{"bill_id":"{}"}}"#, uuid::Uuid::new_v4());
+        let response = app
+            .clone()
+            .oneshot(request(
+                Method::POST,
+                "/api/v1/watched-bills".into(),
+                unknown,
+            ))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                Method::GET,
+                "/api/v1/watched-bills?jurisdiction=sc".into(),
+                String::new(),
+            ))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json parse");
+        assert_eq!(json["data"].as_array().map(Vec::len), Some(1));
+        assert_eq!(json["data"][0]["bill_number"], "H-3600");
+        assert_eq!(json["data"][0]["note"], "licensing overhaul");
+
+        let uri = format!("/api/v1/watched-bills/{}", bill.public_id);
+        let response = app
+            .clone()
+            .oneshot(request(Method::DELETE, uri.clone(), String::new()))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = app
+            .oneshot(request(Method::DELETE, uri, String::new()))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 }
@@ -0,0 +1,164 @@
+//! Brand regulatory exposure to pending bills, from the snapshots recorded
+//! by `scbdb-cli regs exposure`.
+//!
+//! - `GET /api/v1/regulatory-exposure`         — latest score per brand, with states
+//! - `GET /api/v1/regulatory-exposure/series`  — daily scores per brand
+
+use axum::{
+    extract::{Query, State},
+    Extension, Json,
+};
+use chrono::{Days, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::middleware::RequestId;
+
+use super::{map_db_error, ApiError, ApiResponse, AppState, ResponseMeta};
+
+/// Default and maximum history of the series endpoint.
+const DEFAULT_DAYS: u32 = 180;
+const MAX_DAYS: u32 = 730;
+
+#[derive(Debug, Deserialize)]
+pub(super) struct ExposureQuery {
+    pub brand_slug: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct ExposureSeriesQuery {
+    pub brand_slug: Option<String>,
+    /// Days of history, counting today; 1–730, default 180.
+    pub days: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct ExposureStateItem {
+    state: String,
+    store_count: i32,
+    footprint_share: f64,
+    pending_bills: i32,
+    state_exposure: f64,
+    contribution: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct BrandExposureItem {
+    brand_slug: String,
+    brand_name: String,
+    snapshot_date: NaiveDate,
+    /// 0–100; the sum of the states' contributions.
+    score: f64,
+    store_count: i32,
+    /// Largest contribution first.
+    states: Vec<ExposureStateItem>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct ExposurePoint {
+    date: NaiveDate,
+    score: f64,
+    store_count: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct BrandExposureSeries {
+    brand_slug: String,
+    brand_name: String,
+    /// Oldest first.
+    points: Vec<ExposurePoint>,
+}
+
+/// GET /api/v1/regulatory-exposure — brands ranked by their latest score.
+pub(super) async fn list_regulatory_exposure(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<ExposureQuery>,
+) -> Result<Json<ApiResponse<Vec<BrandExposureItem>>>, ApiError> {
+    let rows = scbdb_db::list_latest_regulatory_exposure(&state.pool, query.brand_slug.as_deref())
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    // Rows come grouped by brand, largest contribution first.
+    let mut brands: Vec<BrandExposureItem> = Vec::new();
+    for row in rows {
+        if brands.last().is_none_or(|b| b.brand_slug != row.brand_slug) {
+            brands.push(BrandExposureItem {
+                brand_slug: row.brand_slug.clone(),
+                brand_name: row.brand_name.clone(),
+                snapshot_date: row.snapshot_date,
+                score: 0.0,
+                store_count: 0,
+                states: Vec::new(),
+            });
+        }
+        if let Some(brand) = brands.last_mut() {
+            brand.score += row.contribution;
+            brand.store_count += row.store_count;
+            brand.states.push(ExposureStateItem {
+                state: row.state,
+                store_count: row.store_count,
+                footprint_share: row.footprint_share,
+                pending_bills: row.pending_bills,
+                state_exposure: row.state_exposure,
+                contribution: row.contribution,
+            });
+        }
+    }
+    brands.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    Ok(Json(ApiResponse {
+        data: brands,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+/// GET /api/v1/regulatory-exposure/series — one series of daily scores per brand.
+pub(super) async fn list_regulatory_exposure_series(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<ExposureSeriesQuery>,
+) -> Result<Json<ApiResponse<Vec<BrandExposureSeries>>>, ApiError> {
+    let days = query.days.unwrap_or(DEFAULT_DAYS);
+    if !(1..=MAX_DAYS).contains(&days) {
+        return Err(ApiError::new(
+            &req_id.0,
+            "validation_error",
+            format!("days must be between 1 and {MAX_DAYS}"),
+        ));
+    }
+    let today = Utc::now().date_naive();
+    let since = today
+        .checked_sub_days(Days::new(u64::from(days - 1)))
+        .unwrap_or(today);
+
+    let points =
+        scbdb_db::list_regulatory_exposure_series(&state.pool, query.brand_slug.as_deref(), since)
+            .await
+            .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    let mut series: Vec<BrandExposureSeries> = Vec::new();
+    for point in points {
+        if series
+            .last()
+            .is_none_or(|s| s.brand_slug != point.brand_slug)
+        {
+            series.push(BrandExposureSeries {
+                brand_slug: point.brand_slug.clone(),
+                brand_name: point.brand_name.clone(),
+                points: Vec::new(),
+            });
+        }
+        if let Some(brand) = series.last_mut() {
+            brand.points.push(ExposurePoint {
+                date: point.snapshot_date,
+                score: point.score,
+                store_count: point.store_count,
+            });
+        }
+    }
+
+    Ok(Json(ApiResponse {
+        data: series,
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
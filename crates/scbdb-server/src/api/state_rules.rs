@@ -0,0 +1,318 @@
+//! Curated state hemp-beverage rules.
+//!
+//! - `GET    /api/v1/state-rules`            — rules in effect (or every version)
+//! - `POST   /api/v1/state-rules`            — add a rule version
+//! - `DELETE /api/v1/state-rules/{rule_id}`  — remove a version entered in error
+//! - `GET    /api/v1/state-rules/matrix`     — one row per state, one column per rule kind
+
+use std::collections::BTreeMap;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use scbdb_core::RuleKind;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::middleware::RequestId;
+
+use super::{map_db_error, ApiError, ApiResponse, AppState, ResponseMeta};
+
+#[derive(Debug, Deserialize)]
+pub(super) struct StateRulesQuery {
+    pub jurisdiction: Option<String>,
+    pub rule_kind: Option<String>,
+    /// Rules in effect on this date; defaults to today.
+    pub as_of: Option<NaiveDate>,
+    /// Every version instead of those in effect; `as_of` is ignored.
+    #[serde(default)]
+    pub history: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct StateRulesMatrixQuery {
+    pub as_of: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct CreateStateRuleRequest {
+    pub jurisdiction: String,
+    pub rule_kind: String,
+    pub value: Option<f64>,
+    #[serde(default)]
+    pub channels: Vec<String>,
+    pub effective_from: NaiveDate,
+    pub effective_to: Option<NaiveDate>,
+    /// Enacting bill.
+    pub bill_id: Option<Uuid>,
+    /// Excerpt of the statute or bill text the rule comes from.
+    pub source_text: Option<String>,
+    pub source_url: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct StateRuleItem {
+    id: Uuid,
+    jurisdiction: String,
+    rule_kind: String,
+    value: Option<f64>,
+    channels: Vec<String>,
+    unit: Option<String>,
+    effective_from: NaiveDate,
+    effective_to: Option<NaiveDate>,
+    bill_id: Option<Uuid>,
+    bill_number: Option<String>,
+    source_text: Option<String>,
+    source_url: Option<String>,
+    notes: Option<String>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<scbdb_db::StateRuleRow> for StateRuleItem {
+    fn from(row: scbdb_db::StateRuleRow) -> Self {
+        Self {
+            id: row.public_id,
+            jurisdiction: row.jurisdiction,
+            rule_kind: row.rule_kind,
+            value: row.value,
+            channels: row.channels,
+            unit: row.unit,
+            effective_from: row.effective_from,
+            effective_to: row.effective_to,
+            bill_id: row.bill_public_id,
+            bill_number: row.bill_number,
+            source_text: row.source_text,
+            source_url: row.source_url,
+            notes: row.notes,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+/// The rule of one kind in effect for a state, as a matrix cell.
+#[derive(Debug, Serialize)]
+pub(super) struct RuleCell {
+    id: Uuid,
+    value: Option<f64>,
+    channels: Vec<String>,
+    unit: Option<String>,
+    effective_from: NaiveDate,
+    effective_to: Option<NaiveDate>,
+    bill_id: Option<Uuid>,
+    bill_number: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub(super) struct StateRulesMatrixRow {
+    jurisdiction: String,
+    max_thc_mg_per_serving: Option<RuleCell>,
+    max_thc_mg_per_container: Option<RuleCell>,
+    min_age: Option<RuleCell>,
+    retail_channels: Option<RuleCell>,
+    tax_rate: Option<RuleCell>,
+}
+
+fn parse_rule_kind(req_id: &str, value: &str) -> Result<RuleKind, ApiError> {
+    RuleKind::parse(value).ok_or_else(|| {
+        let kinds: Vec<&str> = RuleKind::ALL.iter().map(|k| k.as_str()).collect();
+        ApiError::new(
+            req_id,
+            "validation_error",
+            format!(
+                "rule_kind must be one of {}, got '{value}'",
+                kinds.join(", ")
+            ),
+        )
+    })
+}
+
+pub(super) async fn list_state_rules(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<StateRulesQuery>,
+) -> Result<Json<ApiResponse<Vec<StateRuleItem>>>, ApiError> {
+    if let Some(kind) = query.rule_kind.as_deref() {
+        parse_rule_kind(&req_id.0, kind)?;
+    }
+    let as_of = if query.history {
+        None
+    } else {
+        Some(query.as_of.unwrap_or_else(|| Utc::now().date_naive()))
+    };
+    let jurisdiction = query.jurisdiction.map(|j| j.to_uppercase());
+    let rows = scbdb_db::list_state_rules(
+        &state.pool,
+        &scbdb_db::StateRuleFilters {
+            jurisdiction: jurisdiction.as_deref(),
+            rule_kind: query.rule_kind.as_deref(),
+            as_of,
+        },
+    )
+    .await
+    .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    Ok(Json(ApiResponse {
+        data: rows.into_iter().map(StateRuleItem::from).collect(),
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+/// POST /api/v1/state-rules — add a rule version, closing the one it replaces.
+pub(super) async fn create_state_rule(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Json(body): Json<CreateStateRuleRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<StateRuleItem>>), ApiError> {
+    let rid = &req_id.0;
+    let jurisdiction = scbdb_core::normalize_jurisdiction(&body.jurisdiction)
+        .map_err(|msg| ApiError::new(rid, "validation_error", msg))?;
+    let kind = parse_rule_kind(rid, &body.rule_kind)?;
+    let channels: Vec<String> = body
+        .channels
+        .iter()
+        .map(|c| c.trim().to_lowercase())
+        .collect();
+    kind.validate(body.value, &channels)
+        .map_err(|msg| ApiError::new(rid, "validation_error", msg))?;
+    if body
+        .effective_to
+        .is_some_and(|to| to <= body.effective_from)
+    {
+        return Err(ApiError::new(
+            rid,
+            "validation_error",
+            "effective_to must be after effective_from",
+        ));
+    }
+
+    let bill_id = match body.bill_id {
+        Some(public_id) => Some(
+            scbdb_db::get_bill_by_public_id(&state.pool, public_id)
+                .await
+                .map_err(|e| map_db_error(rid.clone(), &e))?
+                .ok_or_else(|| {
+                    ApiError::new(rid, "validation_error", "bill_id does not match a bill")
+                })?
+                .id,
+        ),
+        None => None,
+    };
+
+    let row = scbdb_db::insert_state_rule(
+        &state.pool,
+        &scbdb_db::NewStateRule {
+            jurisdiction: &jurisdiction,
+            rule_kind: kind.as_str(),
+            value: body.value,
+            channels: &channels,
+            unit: kind.unit(),
+            effective_from: body.effective_from,
+            effective_to: body.effective_to,
+            bill_id,
+            source_text: body.source_text.as_deref(),
+            source_url: body.source_url.as_deref(),
+            notes: body.notes.as_deref(),
+        },
+    )
+    .await
+    .map_err(|e| {
+        if let scbdb_db::DbError::StateRuleOverlap { .. } = &e {
+            return ApiError::new(rid, "conflict", e.to_string());
+        }
+        if let scbdb_db::DbError::Sqlx(sqlx::Error::Database(db_err)) = &e {
+            if db_err.code().as_deref() == Some("23505") {
+                return ApiError::new(
+                    rid,
+                    "conflict",
+                    "a version of this rule already starts on effective_from",
+                );
+            }
+        }
+        map_db_error(rid.clone(), &e)
+    })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: StateRuleItem::from(row),
+            meta: ResponseMeta::new(req_id.0),
+        }),
+    ))
+}
+
+/// DELETE /api/v1/state-rules/:rule_id — remove a version entered in error.
+pub(super) async fn delete_state_rule(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Path(rule_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<serde_json::Value>>, ApiError> {
+    let rid = &req_id.0;
+    scbdb_db::delete_state_rule(&state.pool, rule_id)
+        .await
+        .map_err(|e| match e {
+            scbdb_db::DbError::NotFound => ApiError::new(rid, "not_found", "state rule not found"),
+            other => map_db_error(rid.clone(), &other),
+        })?;
+
+    Ok(Json(ApiResponse {
+        data: serde_json::json!({ "deleted": true }),
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+/// GET /api/v1/state-rules/matrix — the rules in effect, one row per state.
+pub(super) async fn get_state_rules_matrix(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<StateRulesMatrixQuery>,
+) -> Result<Json<ApiResponse<Vec<StateRulesMatrixRow>>>, ApiError> {
+    let as_of = query.as_of.unwrap_or_else(|| Utc::now().date_naive());
+    let rows = scbdb_db::list_state_rules(
+        &state.pool,
+        &scbdb_db::StateRuleFilters {
+            as_of: Some(as_of),
+            ..scbdb_db::StateRuleFilters::default()
+        },
+    )
+    .await
+    .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    let mut matrix: BTreeMap<String, StateRulesMatrixRow> = BTreeMap::new();
+    for row in rows {
+        let Some(kind) = RuleKind::parse(&row.rule_kind) else {
+            continue;
+        };
+        let entry = matrix
+            .entry(row.jurisdiction.clone())
+            .or_insert_with(|| StateRulesMatrixRow {
+                jurisdiction: row.jurisdiction.clone(),
+                ..StateRulesMatrixRow::default()
+            });
+        let cell = Some(RuleCell {
+            id: row.public_id,
+            value: row.value,
+            channels: row.channels,
+            unit: row.unit,
+            effective_from: row.effective_from,
+            effective_to: row.effective_to,
+            bill_id: row.bill_public_id,
+            bill_number: row.bill_number,
+        });
+        match kind {
+            RuleKind::MaxThcMgPerServing => entry.max_thc_mg_per_serving = cell,
+            RuleKind::MaxThcMgPerContainer => entry.max_thc_mg_per_container = cell,
+            RuleKind::MinAge => entry.min_age = cell,
+            RuleKind::RetailChannels => entry.retail_channels = cell,
+            RuleKind::TaxRate => entry.tax_rate = cell,
+        }
+    }
+
+    Ok(Json(ApiResponse {
+        data: matrix.into_values().collect(),
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
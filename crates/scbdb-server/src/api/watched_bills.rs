@@ -0,0 +1,134 @@
+//! Bill watchlist. Watched bills are notified on every status change and new
+//! event after `scbdb-cli regs ingest`, whatever their relevance score.
+//!
+//! - `GET    /api/v1/watched-bills`            — watched bills with their current status
+//! - `POST   /api/v1/watched-bills`            — watch a bill, or replace its note
+//! - `DELETE /api/v1/watched-bills/{bill_id}`  — stop watching a bill
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::middleware::RequestId;
+
+use super::{map_db_error, ApiError, ApiResponse, AppState, ResponseMeta};
+
+#[derive(Debug, Deserialize)]
+pub(super) struct WatchedBillsQuery {
+    pub jurisdiction: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct WatchBillRequest {
+    pub bill_id: Uuid,
+    /// Why the bill is watched; omit to keep the current note.
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct WatchedBillItem {
+    bill_id: Uuid,
+    jurisdiction: String,
+    bill_number: String,
+    title: String,
+    status: String,
+    status_date: Option<NaiveDate>,
+    last_action_date: Option<NaiveDate>,
+    relevance_score: Option<f64>,
+    note: Option<String>,
+    watched_at: DateTime<Utc>,
+}
+
+impl From<scbdb_db::WatchedBillRow> for WatchedBillItem {
+    fn from(row: scbdb_db::WatchedBillRow) -> Self {
+        Self {
+            bill_id: row.public_id,
+            jurisdiction: row.jurisdiction,
+            bill_number: row.bill_number,
+            title: row.title,
+            status: row.status,
+            status_date: row.status_date,
+            last_action_date: row.last_action_date,
+            relevance_score: row.relevance_score,
+            note: row.note,
+            watched_at: row.created_at,
+        }
+    }
+}
+
+pub(super) async fn list_watched_bills(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Query(query): Query<WatchedBillsQuery>,
+) -> Result<Json<ApiResponse<Vec<WatchedBillItem>>>, ApiError> {
+    let jurisdiction = query.jurisdiction.map(|j| j.to_uppercase());
+    let rows = scbdb_db::list_watched_bills(&state.pool, jurisdiction.as_deref())
+        .await
+        .map_err(|e| map_db_error(req_id.0.clone(), &e))?;
+
+    Ok(Json(ApiResponse {
+        data: rows.into_iter().map(WatchedBillItem::from).collect(),
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+/// POST /api/v1/watched-bills — watch a bill; re-watching only updates the note.
+pub(super) async fn watch_bill(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Json(body): Json<WatchBillRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<WatchedBillItem>>), ApiError> {
+    let rid = &req_id.0;
+    let bill = resolve_bill(&state, rid, body.bill_id).await?;
+    let note = body
+        .note
+        .as_deref()
+        .map(str::trim)
+        .filter(|n| !n.is_empty());
+    let row = scbdb_db::watch_bill(&state.pool, bill, note)
+        .await
+        .map_err(|e| map_db_error(rid.clone(), &e))?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse {
+            data: WatchedBillItem::from(row),
+            meta: ResponseMeta::new(req_id.0),
+        }),
+    ))
+}
+
+/// DELETE /api/v1/watched-bills/:bill_id — stop watching a bill.
+pub(super) async fn unwatch_bill(
+    State(state): State<AppState>,
+    Extension(req_id): Extension<RequestId>,
+    Path(bill_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<serde_json::Value>>, ApiError> {
+    let rid = &req_id.0;
+    let bill = resolve_bill(&state, rid, bill_id).await?;
+    scbdb_db::unwatch_bill(&state.pool, bill)
+        .await
+        .map_err(|e| match e {
+            scbdb_db::DbError::NotFound => ApiError::new(rid, "not_found", "bill is not watched"),
+            other => map_db_error(rid.clone(), &other),
+        })?;
+
+    Ok(Json(ApiResponse {
+        data: serde_json::json!({ "deleted": true }),
+        meta: ResponseMeta::new(req_id.0),
+    }))
+}
+
+/// Internal id of the bill with `public_id`, or `not_found`.
+async fn resolve_bill(state: &AppState, rid: &str, public_id: Uuid) -> Result<i64, ApiError> {
+    scbdb_db::get_bill_by_public_id(&state.pool, public_id)
+        .await
+        .map_err(|e| map_db_error(rid.to_string(), &e))?
+        .map(|bill| bill.id)
+        .ok_or_else(|| ApiError::new(rid, "not_found", "bill not found"))
+}
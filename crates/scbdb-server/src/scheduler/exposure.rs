@@ -0,0 +1,76 @@
+//! Scheduled regulatory exposure snapshots.
+//!
+//! Records each brand's exposure for the day, as `scbdb-cli regs exposure`
+//! does, so `/api/v1/regulatory-exposure/series` has a point per day.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use sqlx::PgPool;
+use tokio_cron_scheduler::{Job, JobScheduler, JobSchedulerError};
+
+/// Register a daily regulatory exposure snapshot job.
+///
+/// Runs at 07:00 UTC by default (`0 0 7 * * *`), after the overnight bill and
+/// location collection, and can be overridden with `REGULATORY_EXPOSURE_CRON`.
+pub(super) async fn register_exposure_job(
+    scheduler: &JobScheduler,
+    pool: PgPool,
+    config: Arc<scbdb_core::AppConfig>,
+) -> Result<(), JobSchedulerError> {
+    let cron =
+        std::env::var("REGULATORY_EXPOSURE_CRON").unwrap_or_else(|_| "0 0 7 * * *".to_string());
+    let pool = Arc::new(pool);
+
+    let job = Job::new_async(cron.as_str(), move |_uuid, _lock| {
+        let pool = Arc::clone(&pool);
+        let config = Arc::clone(&config);
+
+        Box::pin(async move {
+            tracing::info!("scheduler: starting daily regulatory exposure snapshot");
+            run_exposure_job(&pool, &config).await;
+            tracing::info!("scheduler: daily regulatory exposure snapshot complete");
+        })
+    })?;
+
+    scheduler.add(job).await?;
+    tracing::info!(cron = %cron, "scheduler: registered regulatory exposure job");
+    Ok(())
+}
+
+/// Compute and record today's exposure snapshot for every brand.
+async fn run_exposure_job(pool: &PgPool, config: &scbdb_core::AppConfig) {
+    let taxonomy = match scbdb_core::load_bill_topics(&config.bill_topics_path) {
+        Ok(taxonomy) => taxonomy,
+        Err(e) => {
+            tracing::error!(
+                path = %config.bill_topics_path.display(),
+                error = %e,
+                "scheduler: failed to load bill topics for exposure snapshot"
+            );
+            return;
+        }
+    };
+
+    let today = Utc::now().date_naive();
+    let rows = match scbdb_db::compute_regulatory_exposure(pool, &taxonomy, today, None).await {
+        Ok(rows) => rows,
+        Err(e) => {
+            tracing::error!(error = %e, "scheduler: failed to compute regulatory exposure");
+            return;
+        }
+    };
+    if rows.is_empty() {
+        tracing::info!("scheduler: no active store locations; skipping exposure snapshot");
+        return;
+    }
+
+    match scbdb_db::record_regulatory_exposure(pool, today, &rows).await {
+        Ok(stored) => tracing::info!(
+            stored,
+            snapshot_date = %today,
+            "scheduler: recorded regulatory exposure snapshot"
+        ),
+        Err(e) => tracing::error!(error = %e, "scheduler: failed to record exposure snapshot"),
+    }
+}
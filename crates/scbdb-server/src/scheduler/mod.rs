@@ -4,6 +4,7 @@
 //! recurring collection jobs.
 
 mod brand_intel;
+mod exposure;
 
 use std::sync::Arc;
 
@@ -27,6 +28,7 @@ pub async fn build_scheduler(
     let scheduler = JobScheduler::new().await?;
 
     register_locations_job(&scheduler, pool.clone(), Arc::clone(&config)).await?;
+    exposure::register_exposure_job(&scheduler, pool.clone(), Arc::clone(&config)).await?;
     brand_intel::register_signal_refresh_job(&scheduler, pool.clone()).await?;
     brand_intel::register_brand_intake_job(&scheduler, pool.clone()).await?;
     brand_intel::register_handle_refresh_job(&scheduler, pool).await?;
@@ -39,7 +41,9 @@ pub async fn build_scheduler(
 ///
 /// Runs every Sunday at 02:00 UTC (`0 0 2 * * SUN`). For each brand with a
 /// `store_locator_url` the job fetches current store locations, upserts
-/// new/changed records, and deactivates any that have gone missing.
+/// new/changed records, and deactivates any missing from several consecutive
+/// scrapes (see `LocationMissPolicy`). Scrapes that would deactivate too much
+/// of a brand's footprint are quarantined for review instead.
 async fn register_locations_job(
     scheduler: &JobScheduler,
     pool: PgPool,
@@ -89,20 +93,49 @@ async fn run_locations_job(pool: &PgPool, config: &scbdb_core::AppConfig) {
         .build()
         .expect("failed to build HTTP client");
 
+    let run_id = begin_locations_run(pool).await;
+
     for brand in &brands {
         let Some(locator_url) = &brand.store_locator_url else {
             // list_brands_with_locator guarantees Some; guard defensively.
             continue;
         };
-        collect_brand_locations(pool, &http_client, config, brand, locator_url).await;
+        collect_brand_locations(pool, &http_client, config, run_id, brand, locator_url).await;
+    }
+
+    if let Some(run_id) = run_id {
+        let processed = i32::try_from(brands.len()).unwrap_or(i32::MAX);
+        if let Err(e) = scbdb_db::complete_collection_run(pool, run_id, processed).await {
+            tracing::warn!(run_id, error = %e, "scheduler: failed to complete locations run");
+        }
+    }
+}
+
+/// Record a running `locations` collection run so that views cached on the
+/// location data version (map tiles) are refreshed after the job. Failing to
+/// record it is logged and does not stop the collection.
+async fn begin_locations_run(pool: &PgPool) -> Option<i64> {
+    let run = match scbdb_db::create_collection_run(pool, "locations", "scheduler").await {
+        Ok(run) => run,
+        Err(e) => {
+            tracing::warn!(error = %e, "scheduler: failed to create locations run");
+            return None;
+        }
+    };
+    if let Err(e) = scbdb_db::start_collection_run(pool, run.id).await {
+        tracing::warn!(run_id = run.id, error = %e, "scheduler: failed to start locations run");
+        return None;
     }
+    Some(run.id)
 }
 
-/// Fetch, upsert, and deactivate locations for a single brand.
+/// Fetch, upsert, and deactivate locations for a single brand. Quarantines
+/// and change events are tagged with `run_id` when the run was recorded.
 async fn collect_brand_locations(
     pool: &PgPool,
     client: &reqwest::Client,
     config: &scbdb_core::AppConfig,
+    run_id: Option<i64>,
     brand: &scbdb_db::BrandRow,
     locator_url: &str,
 ) {
@@ -155,21 +188,7 @@ async fn collect_brand_locations(
 
     let new_locations: Vec<scbdb_db::NewStoreLocation> = raw
         .iter()
-        .map(|loc| scbdb_db::NewStoreLocation {
-            location_key: scbdb_scraper::make_location_key(brand.id, loc),
-            name: loc.name.clone(),
-            address_line1: loc.address_line1.clone(),
-            city: loc.city.clone(),
-            state: loc.state.clone(),
-            zip: loc.zip.clone(),
-            country: loc.country.clone().or_else(|| Some("US".to_string())),
-            latitude: loc.latitude,
-            longitude: loc.longitude,
-            phone: loc.phone.clone(),
-            external_id: loc.external_id.clone(),
-            locator_source: Some(loc.locator_source.clone()),
-            raw_data: loc.raw_data.clone(),
-        })
+        .map(|loc| to_new_store_location(brand.id, loc))
         .collect();
 
     let active_keys: Vec<String> = new_locations
@@ -177,8 +196,144 @@ async fn collect_brand_locations(
         .map(|l| l.location_key.clone())
         .collect();
 
+    if scrape_quarantined(pool, config, run_id, brand, &new_locations).await {
+        return;
+    }
+
+    let prev_snapshot = match scbdb_db::list_location_snapshots_for_brand(pool, brand.id).await {
+        Ok(rows) => Some(rows),
+        Err(e) => {
+            tracing::warn!(
+                brand = %brand.slug,
+                error = %e,
+                "scheduler: could not snapshot locations; change events skipped"
+            );
+            None
+        }
+    };
+
     upsert_and_log(pool, brand, &new_locations).await;
-    deactivate_and_log(pool, brand, &active_keys).await;
+
+    let mut events = prev_snapshot
+        .as_deref()
+        .map(|prev| scbdb_db::diff_location_snapshots(prev, &new_locations))
+        .unwrap_or_default();
+    record_misses_and_log(pool, config, brand, &active_keys, &mut events).await;
+
+    if let Err(e) = scbdb_db::assign_location_geographies(pool, Some(brand.id)).await {
+        tracing::warn!(
+            brand = %brand.slug,
+            error = %e,
+            "scheduler: failed to assign county/DMA to locations"
+        );
+    }
+
+    if prev_snapshot.is_some() {
+        record_events_and_log(pool, run_id, brand, &events).await;
+    }
+}
+
+/// Map a scraped location to its upsert row, with retailer class and
+/// `raw_data` details filled in.
+fn to_new_store_location(
+    brand_id: i64,
+    loc: &scbdb_scraper::RawStoreLocation,
+) -> scbdb_db::NewStoreLocation {
+    let class = scbdb_scraper::classify_retailer(&loc.name, &loc.raw_data);
+    let details = scbdb_scraper::extract_location_details(Some(&loc.locator_source), &loc.raw_data);
+    scbdb_db::NewStoreLocation {
+        location_key: scbdb_scraper::make_location_key(brand_id, loc),
+        name: loc.name.clone(),
+        address_line1: loc.address_line1.clone(),
+        city: loc.city.clone(),
+        state: loc.state.clone(),
+        zip: loc.zip.clone(),
+        country: loc.country.clone(),
+        latitude: loc.latitude,
+        longitude: loc.longitude,
+        phone: loc.phone.clone(),
+        external_id: loc.external_id.clone(),
+        locator_source: Some(loc.locator_source.clone()),
+        raw_data: loc.raw_data.clone(),
+        retail_chain: class.chain.map(str::to_string),
+        retail_channel: class.channel.map(|c| c.as_str().to_string()),
+        carried_products: details
+            .products
+            .into_iter()
+            .map(|p| scbdb_db::NewCarriedProduct {
+                product_ref: p.product_ref,
+                product_name: p.name,
+                upc: p.upc,
+            })
+            .collect(),
+        hours: details.hours,
+        website: details.website,
+        email: details.email,
+        store_type: details.store_type,
+    }
+}
+
+/// Quarantine the scrape for review when it would deactivate too much of the
+/// brand's footprint. Returns `true` when the scrape must not be applied,
+/// including when the check itself fails.
+async fn scrape_quarantined(
+    pool: &PgPool,
+    config: &scbdb_core::AppConfig,
+    run_id: Option<i64>,
+    brand: &scbdb_db::BrandRow,
+    locations: &[scbdb_db::NewStoreLocation],
+) -> bool {
+    let guard = scbdb_db::LocationDropGuard::from_app_config(config);
+    match scbdb_db::quarantine_location_scrape_on_drop(pool, brand.id, run_id, locations, &guard)
+        .await
+    {
+        Ok(None) => false,
+        Ok(Some(held)) => {
+            tracing::warn!(
+                brand = %brand.slug,
+                quarantine = %held.public_id,
+                new_count = held.assessment.new_count,
+                baseline = held.assessment.baseline_count,
+                drop_pct = held.assessment.drop_pct,
+                "scheduler: location scrape quarantined for review"
+            );
+            true
+        }
+        Err(e) => {
+            tracing::error!(
+                brand = %brand.slug,
+                error = %e,
+                "scheduler: location drop check failed; skipping upsert and deactivation"
+            );
+            true
+        }
+    }
+}
+
+/// Persist detected location change events and log the result.
+async fn record_events_and_log(
+    pool: &PgPool,
+    run_id: Option<i64>,
+    brand: &scbdb_db::BrandRow,
+    events: &[scbdb_db::NewLocationEvent],
+) {
+    match scbdb_db::insert_store_location_events(pool, brand.id, run_id, events).await {
+        Ok(n) if n > 0 => {
+            tracing::info!(
+                brand = %brand.slug,
+                events = n,
+                "scheduler: recorded location change events"
+            );
+        }
+        Ok(_) => {}
+        Err(e) => {
+            tracing::warn!(
+                brand = %brand.slug,
+                error = %e,
+                "scheduler: failed to record location change events"
+            );
+        }
+    }
 }
 
 /// Upsert a batch of locations and log the result.
@@ -202,23 +357,35 @@ async fn upsert_and_log(
     }
 }
 
-/// Deactivate missing locations and log the result.
-async fn deactivate_and_log(pool: &PgPool, brand: &scbdb_db::BrandRow, active_keys: &[String]) {
-    match scbdb_db::deactivate_missing_locations(pool, brand.id, active_keys).await {
-        Ok(n) if n > 0 => {
-            tracing::info!(
-                brand = %brand.slug,
-                deactivated = n,
-                "scheduler: deactivated missing locations"
-            );
+/// Record misses for locations absent from the scrape, deactivating those
+/// past their threshold, and drop `removed` events for the ones kept active.
+async fn record_misses_and_log(
+    pool: &PgPool,
+    config: &scbdb_core::AppConfig,
+    brand: &scbdb_db::BrandRow,
+    active_keys: &[String],
+    events: &mut Vec<scbdb_db::NewLocationEvent>,
+) {
+    let policy = scbdb_db::LocationMissPolicy::from_app_config(config);
+    match scbdb_db::record_location_misses(pool, brand.id, active_keys, events, &policy).await {
+        Ok(misses) => {
+            if misses.pending > 0 || misses.deactivated() > 0 {
+                tracing::info!(
+                    brand = %brand.slug,
+                    pending = misses.pending,
+                    deactivated = misses.deactivated(),
+                    "scheduler: recorded missing locations"
+                );
+            }
+            misses.retain_confirmed_removals(events);
         }
-        Ok(_) => {}
         Err(e) => {
             tracing::warn!(
                 brand = %brand.slug,
                 error = %e,
-                "scheduler: failed to deactivate missing locations"
+                "scheduler: failed to record missing locations"
             );
+            events.retain(|e| e.event_type != scbdb_db::LocationEventType::Removed);
         }
     }
 }
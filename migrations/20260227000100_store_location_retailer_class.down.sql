@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_store_locations_retail_channel;
+ALTER TABLE store_locations DROP CONSTRAINT IF EXISTS store_locations_retail_channel_check;
+ALTER TABLE store_locations DROP COLUMN retail_channel;
+ALTER TABLE store_locations DROP COLUMN retail_chain;
@@ -0,0 +1,10 @@
+-- Retailer chain + channel classification for store locations.
+-- Populated in Rust by scbdb_scraper::classify_retailer at upsert time and
+-- backfilled by `scbdb-cli locations classify`.
+ALTER TABLE store_locations ADD COLUMN retail_chain TEXT;
+ALTER TABLE store_locations ADD COLUMN retail_channel TEXT;
+
+ALTER TABLE store_locations ADD CONSTRAINT store_locations_retail_channel_check
+  CHECK (retail_channel IN ('liquor', 'grocery', 'convenience', 'smoke_vape', 'on_premise', 'dispensary'));
+
+CREATE INDEX idx_store_locations_retail_channel ON store_locations (retail_channel) WHERE is_active = TRUE;
@@ -0,0 +1,24 @@
+-- Change history for store locations. One row per detected transition,
+-- written after each locations scrape (CLI and scheduler).
+CREATE TABLE store_location_events (
+  id                  BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+  public_id           UUID NOT NULL DEFAULT gen_random_uuid(),
+  brand_id            BIGINT NOT NULL REFERENCES brands(id),
+  store_location_id   BIGINT NOT NULL REFERENCES store_locations(id),
+  event_type          TEXT NOT NULL
+                        CHECK (event_type IN ('added', 'removed', 'reactivated', 'moved', 'renamed')),
+  -- Denormalised at event time so rollups survive later edits to the location row.
+  state               TEXT,
+  collection_run_id   BIGINT REFERENCES collection_runs(id),
+  -- Previous values for moved/renamed (old name, address, previous_location_id).
+  details             JSONB NOT NULL DEFAULT '{}'::jsonb,
+  occurred_at         TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_store_location_events_brand_occurred
+  ON store_location_events (brand_id, occurred_at DESC);
+CREATE INDEX idx_store_location_events_occurred
+  ON store_location_events (occurred_at DESC);
+CREATE INDEX idx_store_location_events_location
+  ON store_location_events (store_location_id);
@@ -0,0 +1 @@
+DROP INDEX IF EXISTS idx_store_locations_active_lat_lng;
@@ -0,0 +1,4 @@
+-- Bounding-box prefilter for radius, nearest-competitor and density queries.
+CREATE INDEX idx_store_locations_active_lat_lng
+  ON store_locations (latitude, longitude)
+  WHERE is_active = TRUE AND latitude IS NOT NULL AND longitude IS NOT NULL;
@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS idx_store_locations_dma_code;
+DROP INDEX IF EXISTS idx_store_locations_county_fips;
+ALTER TABLE store_locations DROP COLUMN IF EXISTS dma_code;
+ALTER TABLE store_locations DROP COLUMN IF EXISTS county_fips;
+DROP TABLE IF EXISTS geo_zip_counties;
+DROP TABLE IF EXISTS geo_counties;
+DROP TABLE IF EXISTS geo_dmas;
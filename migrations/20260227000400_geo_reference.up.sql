@@ -0,0 +1,35 @@
+-- County / DMA reference data for sub-state location rollups.
+-- Loaded by `scbdb-cli locations load-geo` from Census county population
+-- estimates, a county→DMA mapping and the HUD USPS ZIP→county crosswalk.
+
+CREATE TABLE geo_dmas (
+  code        TEXT PRIMARY KEY,   -- Nielsen DMA code, e.g. '546'
+  name        TEXT NOT NULL,
+  updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE geo_counties (
+  fips        TEXT PRIMARY KEY CHECK (fips ~ '^[0-9]{5}$'),
+  name        TEXT NOT NULL,
+  state       TEXT NOT NULL,      -- 2-letter USPS code
+  population  INTEGER CHECK (population IS NULL OR population >= 0),
+  dma_code    TEXT REFERENCES geo_dmas(code) ON DELETE SET NULL,
+  updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_geo_counties_dma_code ON geo_counties (dma_code);
+
+-- A ZIP can straddle counties; `res_ratio` is the share of residential
+-- addresses in each county. Locations are assigned the highest-ratio county.
+CREATE TABLE geo_zip_counties (
+  zip          TEXT NOT NULL CHECK (zip ~ '^[0-9]{5}$'),
+  county_fips  TEXT NOT NULL REFERENCES geo_counties(fips) ON DELETE CASCADE,
+  res_ratio    NUMERIC(7,6) NOT NULL DEFAULT 1,
+  PRIMARY KEY (zip, county_fips)
+);
+
+ALTER TABLE store_locations ADD COLUMN county_fips TEXT;
+ALTER TABLE store_locations ADD COLUMN dma_code TEXT;
+
+CREATE INDEX idx_store_locations_county_fips ON store_locations (county_fips) WHERE is_active = TRUE;
+CREATE INDEX idx_store_locations_dma_code ON store_locations (dma_code) WHERE is_active = TRUE;
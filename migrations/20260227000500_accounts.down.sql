@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_store_locations_address_key;
+ALTER TABLE store_locations DROP COLUMN IF EXISTS address_key;
+DROP TABLE IF EXISTS accounts;
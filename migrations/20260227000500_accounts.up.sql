@@ -0,0 +1,31 @@
+-- Our own retailer accounts, imported from the distributor ERP export.
+-- Accounts are matched to locator rows on `address_key`: the normalized
+-- street address plus five-digit ZIP (see `scbdb_core::address`).
+
+CREATE TABLE accounts (
+  id              BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+  public_id       UUID NOT NULL DEFAULT gen_random_uuid(),
+  account_number  TEXT NOT NULL,   -- ERP account ID; the import upsert key
+  name            TEXT NOT NULL,
+  address_line1   TEXT,
+  city            TEXT,
+  state           TEXT,
+  zip             TEXT,
+  address_key     TEXT,            -- NULL when street or ZIP is missing
+  is_active       BOOLEAN NOT NULL DEFAULT TRUE,
+  first_imported_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  last_imported_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+  CONSTRAINT uq_accounts_account_number UNIQUE (account_number),
+  CONSTRAINT uq_accounts_public_id UNIQUE (public_id)
+);
+
+CREATE INDEX idx_accounts_address_key ON accounts (address_key) WHERE is_active = TRUE;
+CREATE INDEX idx_accounts_state ON accounts (state) WHERE is_active = TRUE;
+
+-- Computed on upsert; existing rows are backfilled on the next account import.
+ALTER TABLE store_locations ADD COLUMN address_key TEXT;
+
+CREATE INDEX idx_store_locations_address_key ON store_locations (address_key) WHERE is_active = TRUE;
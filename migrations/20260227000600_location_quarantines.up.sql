@@ -0,0 +1,28 @@
+-- Location scrapes held for review because applying them would deactivate an
+-- unusually large share of a brand's footprint (see SCBDB_LOCATION_MAX_DROP_PCT).
+-- `locations` is the full scrape as it would have been upserted; approving a
+-- quarantine applies it, rejecting it leaves the stored footprint untouched.
+
+CREATE TABLE location_quarantines (
+  id                 BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+  public_id          UUID NOT NULL DEFAULT gen_random_uuid() UNIQUE,
+  brand_id           BIGINT NOT NULL REFERENCES brands(id),
+  collection_run_id  BIGINT REFERENCES collection_runs(id) ON DELETE SET NULL,
+  status             TEXT NOT NULL DEFAULT 'pending'
+                     CHECK (status IN ('pending', 'approved', 'rejected', 'superseded')),
+  active_count       INTEGER NOT NULL,   -- active locations when the scrape ran
+  baseline_count     INTEGER NOT NULL,   -- max(active, recent run median)
+  new_count          INTEGER NOT NULL,   -- locations in the held scrape
+  drop_pct           NUMERIC(5,2) NOT NULL,
+  locator_source     TEXT,
+  locations          JSONB NOT NULL,
+  review_note        TEXT,
+  reviewed_at        TIMESTAMPTZ,
+  created_at         TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- At most one scrape per brand awaits review; a newer one supersedes it.
+CREATE UNIQUE INDEX uq_location_quarantines_pending_brand
+  ON location_quarantines (brand_id) WHERE status = 'pending';
+CREATE INDEX idx_location_quarantines_status_created
+  ON location_quarantines (status, created_at DESC);
@@ -0,0 +1,6 @@
+-- Hysteresis for store closures: a location is only deactivated after it has
+-- been missing from several consecutive trusted scrapes (or unseen for too
+-- long). Reset to 0 whenever the location is seen again.
+ALTER TABLE store_locations
+  ADD COLUMN consecutive_misses INTEGER NOT NULL DEFAULT 0
+    CHECK (consecutive_misses >= 0);
@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS store_location_products;
+ALTER TABLE store_locations DROP COLUMN IF EXISTS store_type;
+ALTER TABLE store_locations DROP COLUMN IF EXISTS email;
+ALTER TABLE store_locations DROP COLUMN IF EXISTS website;
+ALTER TABLE store_locations DROP COLUMN IF EXISTS hours;
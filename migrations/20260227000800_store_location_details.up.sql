@@ -0,0 +1,22 @@
+-- Structured fields promoted from locator raw_data by the per-provider mappers
+-- in scbdb-scraper (locator/details.rs). Backfilled with `locations details`.
+ALTER TABLE store_locations ADD COLUMN hours TEXT;
+ALTER TABLE store_locations ADD COLUMN website TEXT;
+ALTER TABLE store_locations ADD COLUMN email TEXT;
+ALTER TABLE store_locations ADD COLUMN store_type TEXT;
+
+-- Products a locator says a store carries (Destini, VTInfo, AskHoodie, ...).
+-- product_ref is the UPC, provider product id, or lower-cased name.
+CREATE TABLE store_location_products (
+  id                  BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+  store_location_id   BIGINT NOT NULL REFERENCES store_locations(id) ON DELETE CASCADE,
+  product_ref         TEXT NOT NULL,
+  product_name        TEXT,
+  upc                 TEXT,
+  first_seen_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  last_seen_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  UNIQUE (store_location_id, product_ref)
+);
+
+CREATE INDEX idx_store_location_products_upc
+  ON store_location_products (upc) WHERE upc IS NOT NULL;
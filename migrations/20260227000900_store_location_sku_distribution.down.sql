@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_store_location_products_product_id;
+ALTER TABLE store_location_products DROP COLUMN IF EXISTS source;
+ALTER TABLE store_location_products DROP COLUMN IF EXISTS product_id;
@@ -0,0 +1,12 @@
+-- SKU-level distribution: product-level locator sweeps (Destini, VTInfo)
+-- record which of our products each store carries. Sweep rows are kept apart
+-- from rows mapped out of the store's own locator payload so neither source
+-- prunes the other's rows.
+ALTER TABLE store_location_products
+  ADD COLUMN product_id BIGINT REFERENCES products(id) ON DELETE SET NULL;
+ALTER TABLE store_location_products
+  ADD COLUMN source TEXT NOT NULL DEFAULT 'locator'
+    CHECK (source IN ('locator', 'product_sweep'));
+
+CREATE INDEX idx_store_location_products_product_id
+  ON store_location_products (product_id) WHERE product_id IS NOT NULL;
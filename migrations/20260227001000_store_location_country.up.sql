@@ -0,0 +1,27 @@
+-- Country-aware locations: the scraper now resolves every store's country
+-- (ISO 3166-1 alpha-2) instead of assuming the US. Settle historical rows on
+-- the same codes so the new country filters see them.
+UPDATE store_locations
+SET country = CASE UPPER(TRIM(country))
+    WHEN 'USA' THEN 'US'
+    WHEN 'UNITED STATES' THEN 'US'
+    WHEN 'CAN' THEN 'CA'
+    WHEN 'CANADA' THEN 'CA'
+    WHEN 'PRI' THEN 'PR'
+    WHEN 'PUERTO RICO' THEN 'PR'
+    ELSE UPPER(TRIM(country))
+  END;
+
+-- Rows defaulted to 'US' before detection existed.
+UPDATE store_locations
+SET country = 'CA'
+WHERE country = 'US'
+  AND UPPER(state) IN (
+    'AB', 'BC', 'MB', 'NB', 'NL', 'NS', 'NT', 'NU', 'ON', 'PE', 'QC', 'SK', 'YT'
+  );
+
+UPDATE store_locations
+SET country = 'PR'
+WHERE country = 'US' AND UPPER(state) = 'PR';
+
+CREATE INDEX idx_store_locations_country ON store_locations (country);
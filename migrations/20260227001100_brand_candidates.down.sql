@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS brand_candidate_evidence;
+DROP TABLE IF EXISTS brand_candidates;
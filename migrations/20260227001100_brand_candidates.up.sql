@@ -0,0 +1,32 @@
+-- Brand auto-discovery: names harvested from news/sentiment text, multi-brand
+-- locators and retailer pages wait here until an operator promotes them into
+-- brands or dismisses them. Each distinct sighting is kept as evidence.
+CREATE TABLE brand_candidates (
+  id           BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+  public_id    UUID NOT NULL DEFAULT gen_random_uuid() UNIQUE,
+  name         TEXT NOT NULL,
+  slug         TEXT NOT NULL UNIQUE,
+  status       TEXT NOT NULL DEFAULT 'pending'
+                 CHECK (status IN ('pending', 'promoted', 'dismissed')),
+  brand_id     BIGINT REFERENCES brands(id),
+  review_note  TEXT,
+  reviewed_at  TIMESTAMPTZ,
+  first_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  last_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at   TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_brand_candidates_status ON brand_candidates (status, last_seen_at DESC);
+
+CREATE TABLE brand_candidate_evidence (
+  id           BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+  candidate_id BIGINT NOT NULL REFERENCES brand_candidates(id) ON DELETE CASCADE,
+  source       TEXT NOT NULL
+                 CHECK (source IN ('sentiment', 'brand_signal', 'locator', 'retailer_page')),
+  -- URL, signal id or location key identifying the sighting.
+  source_ref   TEXT NOT NULL,
+  context      TEXT,
+  observed_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  UNIQUE (candidate_id, source, source_ref)
+);
+CREATE INDEX idx_brand_candidate_evidence_candidate ON brand_candidate_evidence (candidate_id);
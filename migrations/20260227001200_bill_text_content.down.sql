@@ -0,0 +1,6 @@
+DROP INDEX IF EXISTS idx_bill_texts_pending_extraction;
+
+ALTER TABLE bill_texts
+    DROP COLUMN IF EXISTS extracted_at,
+    DROP COLUMN IF EXISTS extraction_error,
+    DROP COLUMN IF EXISTS content;
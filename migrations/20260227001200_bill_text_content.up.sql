@@ -0,0 +1,11 @@
+-- Extracted plain text per bill version. `extracted_at` is set once an
+-- extraction attempt finishes; exactly one of `content` / `extraction_error`
+-- is filled so failed documents are not refetched on every run.
+ALTER TABLE bill_texts
+    ADD COLUMN content          TEXT,
+    ADD COLUMN extraction_error TEXT,
+    ADD COLUMN extracted_at     TIMESTAMPTZ;
+
+CREATE INDEX idx_bill_texts_pending_extraction
+    ON bill_texts (id)
+    WHERE extracted_at IS NULL;
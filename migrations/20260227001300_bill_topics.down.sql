@@ -0,0 +1,7 @@
+DROP TABLE IF EXISTS bill_topics;
+
+DROP INDEX IF EXISTS idx_bills_relevance_score;
+
+ALTER TABLE bills
+    DROP COLUMN IF EXISTS relevance_scored_at,
+    DROP COLUMN IF EXISTS relevance_score;
@@ -0,0 +1,17 @@
+-- Topic tags and relevance scores from the bill topic taxonomy
+-- (config/bill_topics.yaml). Scores are recomputed whenever a bill or one of
+-- its texts changes, so the tags are replaced wholesale per bill.
+ALTER TABLE bills
+    ADD COLUMN relevance_score     DOUBLE PRECISION,
+    ADD COLUMN relevance_scored_at TIMESTAMPTZ;
+
+CREATE INDEX idx_bills_relevance_score ON bills (relevance_score);
+
+CREATE TABLE bill_topics (
+    bill_id BIGINT NOT NULL REFERENCES bills(id) ON DELETE CASCADE,
+    topic   TEXT NOT NULL,
+    score   DOUBLE PRECISION NOT NULL,
+    PRIMARY KEY (bill_id, topic)
+);
+
+CREATE INDEX idx_bill_topics_topic ON bill_topics (topic);
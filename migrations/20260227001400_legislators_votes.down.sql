@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS roll_call_votes;
+DROP TABLE IF EXISTS roll_calls;
+DROP TABLE IF EXISTS bill_sponsors;
+DROP TABLE IF EXISTS legislators;
@@ -0,0 +1,60 @@
+-- Legislators, bill sponsorships and roll call votes from LegiScan
+-- (getBill sponsors/votes, getRollCall, getPerson). Sponsorships and votes
+-- reference legislators by their LegiScan people_id so a vote can be stored
+-- before the voter's getPerson record has been fetched.
+CREATE TABLE legislators (
+    id                 BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    public_id          UUID NOT NULL DEFAULT gen_random_uuid() UNIQUE,
+    legiscan_people_id BIGINT NOT NULL UNIQUE,
+    jurisdiction       TEXT NOT NULL,
+    name               TEXT NOT NULL,
+    first_name         TEXT,
+    last_name          TEXT,
+    party              TEXT,
+    role               TEXT,
+    district           TEXT,
+    created_at         TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at         TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_legislators_jurisdiction ON legislators (jurisdiction, name);
+
+CREATE TABLE bill_sponsors (
+    bill_id            BIGINT NOT NULL REFERENCES bills(id) ON DELETE CASCADE,
+    legiscan_people_id BIGINT NOT NULL,
+    sponsor_type       TEXT NOT NULL CHECK (sponsor_type IN ('primary', 'cosponsor', 'joint')),
+    sponsor_order      INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (bill_id, legiscan_people_id)
+);
+
+CREATE INDEX idx_bill_sponsors_people ON bill_sponsors (legiscan_people_id);
+
+CREATE TABLE roll_calls (
+    id                    BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    bill_id               BIGINT NOT NULL REFERENCES bills(id) ON DELETE CASCADE,
+    legiscan_roll_call_id BIGINT NOT NULL UNIQUE,
+    vote_date             DATE,
+    chamber               TEXT,
+    description           TEXT NOT NULL,
+    yea                   INTEGER NOT NULL DEFAULT 0,
+    nay                   INTEGER NOT NULL DEFAULT 0,
+    nv                    INTEGER NOT NULL DEFAULT 0,
+    absent                INTEGER NOT NULL DEFAULT 0,
+    total                 INTEGER NOT NULL DEFAULT 0,
+    passed                BOOLEAN NOT NULL,
+    -- NULL until the individual votes have been fetched with getRollCall.
+    votes_fetched_at      TIMESTAMPTZ,
+    created_at            TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_roll_calls_bill ON roll_calls (bill_id, vote_date);
+CREATE INDEX idx_roll_calls_pending_votes ON roll_calls (id) WHERE votes_fetched_at IS NULL;
+
+CREATE TABLE roll_call_votes (
+    roll_call_id       BIGINT NOT NULL REFERENCES roll_calls(id) ON DELETE CASCADE,
+    legiscan_people_id BIGINT NOT NULL,
+    vote               TEXT NOT NULL CHECK (vote IN ('yea', 'nay', 'nv', 'absent')),
+    PRIMARY KEY (roll_call_id, legiscan_people_id)
+);
+
+CREATE INDEX idx_roll_call_votes_people ON roll_call_votes (legiscan_people_id);
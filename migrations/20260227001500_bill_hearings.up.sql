@@ -0,0 +1,18 @@
+-- Committee hearings and calendar entries from LegiScan getBill `calendar`.
+-- Entries carry no stable id, so a bill's hearings are replaced wholesale
+-- whenever the bill is re-fetched.
+CREATE TABLE bill_hearings (
+    id           BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    bill_id      BIGINT NOT NULL REFERENCES bills(id) ON DELETE CASCADE,
+    hearing_date DATE NOT NULL,
+    hearing_time TIME,
+    event_type   TEXT NOT NULL,
+    committee    TEXT,
+    chamber      TEXT,
+    location     TEXT,
+    description  TEXT,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_bill_hearings_date ON bill_hearings (hearing_date);
+CREATE INDEX idx_bill_hearings_bill ON bill_hearings (bill_id);
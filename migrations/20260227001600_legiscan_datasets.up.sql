@@ -0,0 +1,12 @@
+-- Bulk LegiScan datasets imported by `regs import-dataset`, one row per
+-- session. A dataset whose `dataset_hash` matches the stored one has not
+-- changed since the last import and is not downloaded again.
+CREATE TABLE legiscan_datasets (
+    session_id     BIGINT PRIMARY KEY,
+    jurisdiction   TEXT NOT NULL,
+    session_name   TEXT NOT NULL,
+    dataset_hash   TEXT NOT NULL,
+    dataset_date   DATE,
+    bills_imported INTEGER NOT NULL DEFAULT 0,
+    imported_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS legiscan_request_ledger;
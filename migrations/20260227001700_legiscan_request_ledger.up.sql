@@ -0,0 +1,11 @@
+-- LegiScan requests counted per API key and calendar month (UTC), shared by
+-- every CLI, cron and server process using the key. The key itself is never
+-- stored; `key_fingerprint` is a prefix of its SHA-256 hash.
+CREATE TABLE legiscan_request_ledger (
+    key_fingerprint TEXT NOT NULL,
+    month           DATE NOT NULL CHECK (EXTRACT(DAY FROM month) = 1),
+    requests        INTEGER NOT NULL DEFAULT 0,
+    monthly_budget  INTEGER NOT NULL,
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (key_fingerprint, month)
+);
@@ -0,0 +1,28 @@
+-- Curated hemp-beverage rules per state, one row per rule version. A rule is
+-- in effect from `effective_from` until the day before `effective_to` (open
+-- ended when NULL); adding a newer version of the same jurisdiction and kind
+-- closes the previous one. `bill_id` and `source_text` record where the rule
+-- comes from.
+CREATE TABLE state_rules (
+    id             BIGSERIAL PRIMARY KEY,
+    public_id      UUID NOT NULL DEFAULT gen_random_uuid() UNIQUE,
+    jurisdiction   TEXT NOT NULL,
+    rule_kind      TEXT NOT NULL CHECK (rule_kind IN (
+                       'max_thc_mg_per_serving', 'max_thc_mg_per_container', 'min_age',
+                       'retail_channels', 'tax_rate')),
+    value          DOUBLE PRECISION,
+    channels       TEXT[] NOT NULL DEFAULT '{}',
+    unit           TEXT,
+    effective_from DATE NOT NULL,
+    effective_to   DATE,
+    bill_id        BIGINT REFERENCES bills(id) ON DELETE SET NULL,
+    source_text    TEXT,
+    source_url     TEXT,
+    notes          TEXT,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (effective_to IS NULL OR effective_to > effective_from),
+    UNIQUE (jurisdiction, rule_kind, effective_from)
+);
+
+CREATE INDEX idx_state_rules_bill ON state_rules (bill_id);
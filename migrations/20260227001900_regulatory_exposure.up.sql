@@ -0,0 +1,19 @@
+-- Daily snapshots of each brand's regulatory exposure, one row per brand and
+-- state with active stores. `state_exposure` (0–100) is the pressure of the
+-- state's pending bills; `contribution` weights it by the state's share of
+-- the brand's stores, so a brand's score on a day is the sum of its rows.
+CREATE TABLE regulatory_exposure_snapshots (
+    brand_id        BIGINT NOT NULL REFERENCES brands(id) ON DELETE CASCADE,
+    state           TEXT NOT NULL,
+    snapshot_date   DATE NOT NULL,
+    store_count     INTEGER NOT NULL,
+    footprint_share DOUBLE PRECISION NOT NULL,
+    pending_bills   INTEGER NOT NULL,
+    state_exposure  DOUBLE PRECISION NOT NULL,
+    contribution    DOUBLE PRECISION NOT NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (brand_id, state, snapshot_date)
+);
+
+CREATE INDEX idx_regulatory_exposure_snapshots_date
+    ON regulatory_exposure_snapshots (snapshot_date);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS bill_alert_cursors;
+DROP TABLE IF EXISTS watched_bills;
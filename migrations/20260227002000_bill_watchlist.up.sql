@@ -0,0 +1,17 @@
+-- Bills a user asked to be notified about, whatever their relevance score.
+CREATE TABLE watched_bills (
+    bill_id    BIGINT PRIMARY KEY REFERENCES bills(id) ON DELETE CASCADE,
+    note       TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- What bill change notifications last reported for each bill that is
+-- watched or scores above the notification threshold: its status then and
+-- the newest `bill_events.id` seen. `regs ingest` reports bills whose status
+-- or events moved past their cursor, then advances it.
+CREATE TABLE bill_alert_cursors (
+    bill_id       BIGINT PRIMARY KEY REFERENCES bills(id) ON DELETE CASCADE,
+    status        TEXT NOT NULL,
+    last_event_id BIGINT NOT NULL DEFAULT 0,
+    updated_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
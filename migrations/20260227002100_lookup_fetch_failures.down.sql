@@ -0,0 +1,6 @@
+DROP TABLE IF EXISTS voter_fetch_failures;
+
+ALTER TABLE roll_calls
+    DROP COLUMN IF EXISTS votes_fetch_attempted_at,
+    DROP COLUMN IF EXISTS votes_fetch_error,
+    DROP COLUMN IF EXISTS votes_fetch_attempts;
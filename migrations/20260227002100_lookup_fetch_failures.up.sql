@@ -0,0 +1,15 @@
+-- Failed getRollCall/getPerson lookups. A roll call or voter whose lookup
+-- keeps failing (deleted upstream, malformed response) is retried on later
+-- runs until it reaches the attempt limit, instead of being requested on
+-- every run forever.
+ALTER TABLE roll_calls
+    ADD COLUMN votes_fetch_attempts     INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN votes_fetch_error        TEXT,
+    ADD COLUMN votes_fetch_attempted_at TIMESTAMPTZ;
+
+CREATE TABLE voter_fetch_failures (
+    legiscan_people_id BIGINT PRIMARY KEY,
+    attempts           INTEGER NOT NULL DEFAULT 1,
+    last_error         TEXT NOT NULL,
+    last_attempted_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,10 @@
+DELETE FROM bill_alert_cursors;
+
+DROP INDEX IF EXISTS idx_bill_alert_cursors_bill;
+
+ALTER TABLE bill_alert_cursors
+    DROP CONSTRAINT bill_alert_cursors_pkey,
+    DROP COLUMN notifier,
+    ADD PRIMARY KEY (bill_id);
+
+DROP TABLE IF EXISTS bill_alert_channels;
@@ -0,0 +1,20 @@
+-- Bill change notifications keep a cursor per channel (webhook, slack,
+-- email, or console when none is configured), so a change one channel
+-- delivered is neither re-sent to it nor lost to another channel that is
+-- failing. A channel's first run baselines every tracked bill without a
+-- notification; bills tracked after that are reported as new.
+CREATE TABLE bill_alert_channels (
+    notifier   TEXT PRIMARY KEY,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Existing cursors were shared by every channel and cannot be attributed to
+-- one; each channel baselines afresh on its next run.
+DELETE FROM bill_alert_cursors;
+
+ALTER TABLE bill_alert_cursors
+    DROP CONSTRAINT bill_alert_cursors_pkey,
+    ADD COLUMN notifier TEXT NOT NULL REFERENCES bill_alert_channels(notifier) ON DELETE CASCADE,
+    ADD PRIMARY KEY (notifier, bill_id);
+
+CREATE INDEX idx_bill_alert_cursors_bill ON bill_alert_cursors (bill_id);
@@ -0,0 +1,99 @@
+#!/usr/bin/env bash
+# Regenerate the bundled county / ZIP reference seed in config/geo/ from
+# public-domain Census Bureau files:
+#
+#   - county population: Vintage 2023 county population estimates
+#   - state abbreviations: 2020 national county FIPS codes
+#   - ZIP→county: 2020 ZCTA-to-county relationship file; each ZIP's ratio is
+#     the share of its land area in the county
+#
+# Usage: scripts/fetch-geo-reference.sh [output-dir]
+set -euo pipefail
+
+readonly SCRIPT_NAME="$(basename "$0")"
+readonly REPO_ROOT="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+readonly OUT_DIR="${1:-$REPO_ROOT/config/geo}"
+
+readonly POPEST_URL="https://www2.census.gov/programs-surveys/popest/datasets/2020-2023/counties/totals/co-est2023-alldata.csv"
+readonly COUNTY_CODES_URL="https://www2.census.gov/geo/docs/reference/codes2020/national_county2020.txt"
+readonly ZCTA_COUNTY_URL="https://www2.census.gov/geo/docs/maps-data/data/rel2020/zcta520/tab20_zcta520_county20_natl.txt"
+
+log() {
+  printf '[%s] %s\n' "$SCRIPT_NAME" "$*"
+}
+
+err() {
+  printf '[%s] error: %s\n' "$SCRIPT_NAME" "$*" >&2
+}
+
+for cmd in curl awk iconv; do
+  if ! command -v "$cmd" >/dev/null 2>&1; then
+    err "$cmd is required"
+    exit 1
+  fi
+done
+
+work_dir="$(mktemp -d)"
+trap 'rm -rf "$work_dir"' EXIT INT TERM
+
+fetch() {
+  log "Downloading $1"
+  curl --fail --silent --show-error --location --retry 3 -o "$2" "$1"
+}
+
+fetch "$POPEST_URL" "$work_dir/popest.csv"
+fetch "$COUNTY_CODES_URL" "$work_dir/county_codes.txt"
+fetch "$ZCTA_COUNTY_URL" "$work_dir/zcta_county.txt"
+
+# The estimates file is Latin-1 (e.g. "Doña Ana County").
+iconv -f LATIN1 -t UTF-8 "$work_dir/popest.csv" >"$work_dir/popest.utf8.csv"
+
+mkdir -p "$OUT_DIR"
+
+log "Writing $OUT_DIR/counties.csv"
+awk '
+  BEGIN { FS = "|" }
+  NR == FNR {
+    if (FNR > 1) abbr[$2] = $1
+    next
+  }
+  FNR == 1 {
+    FS = ","
+    $0 = $0
+    for (i = 1; i <= NF; i++) col[$i] = i
+    print "fips,name,state,population"
+    next
+  }
+  $col["SUMLEV"] == "050" {
+    state = $col["STATE"]
+    name = $col["CTYNAME"]
+    gsub(/"/, "\"\"", name)
+    printf "%s%s,\"%s\",%s,%s\n", state, $col["COUNTY"], name, abbr[state], $col["POPESTIMATE2023"]
+  }
+' "$work_dir/county_codes.txt" "$work_dir/popest.utf8.csv" >"$OUT_DIR/counties.csv"
+
+log "Writing $OUT_DIR/zip_counties.csv"
+awk -F'|' '
+  FNR == 1 {
+    for (i = 1; i <= NF; i++) col[$i] = i
+    next
+  }
+  $col["GEOID_ZCTA5_20"] != "" {
+    zip = $col["GEOID_ZCTA5_20"]
+    part = $col["AREALAND_PART"] + 0
+    key = zip "," $col["GEOID_COUNTY_20"]
+    if (!(key in land)) order[++n] = key
+    land[key] += part
+    total[zip] += part
+  }
+  END {
+    print "zip,county_fips,res_ratio"
+    for (i = 1; i <= n; i++) {
+      split(order[i], k, ",")
+      ratio = total[k[1]] > 0 ? land[order[i]] / total[k[1]] : 0
+      printf "%s,%s,%.6f\n", k[1], k[2], ratio
+    }
+  }
+' "$work_dir/zcta_county.txt" >"$OUT_DIR/zip_counties.csv"
+
+log "Done: $(($(wc -l <"$OUT_DIR/counties.csv") - 1)) counties, $(($(wc -l <"$OUT_DIR/zip_counties.csv") - 1)) ZIP→county rows"